@@ -23,11 +23,19 @@ fn ints_json(bench: &mut Bencher) {
     Python::with_gil(|py| {
         let validator = build_schema_validator(py, "{'type': 'int'}");
 
-        let result = validator.validate_json(py, json(py, "123"), None, None).unwrap();
+        let result = validator
+            .validate_json(py, json(py, "123"), None, None, None, None)
+            .unwrap();
         let result_int: i64 = result.extract(py).unwrap();
         assert_eq!(result_int, 123);
 
-        bench.iter(|| black_box(validator.validate_json(py, json(py, "123"), None, None).unwrap()))
+        bench.iter(|| {
+            black_box(
+                validator
+                    .validate_json(py, json(py, "123"), None, None, None, None)
+                    .unwrap(),
+            )
+        })
     })
 }
 
@@ -38,12 +46,20 @@ fn ints_python(bench: &mut Bencher) {
 
         let input = 123_i64.into_py(py);
         let input = input.as_ref(py);
-        let result = validator.validate_python(py, input, None, None).unwrap();
+        let result = validator
+            .validate_python(py, input, None, None, None, None, None, None, None, None)
+            .unwrap();
         let result_int: i64 = result.extract(py).unwrap();
         assert_eq!(result_int, 123);
 
         let input = black_box(input);
-        bench.iter(|| black_box(validator.validate_python(py, input, None, None).unwrap()))
+        bench.iter(|| {
+            black_box(
+                validator
+                    .validate_python(py, input, None, None, None, None, None, None, None, None)
+                    .unwrap(),
+            )
+        })
     })
 }
 
@@ -56,7 +72,13 @@ fn list_int_json(bench: &mut Bencher) {
             (0..100).map(|x| x.to_string()).collect::<Vec<String>>().join(",")
         );
 
-        bench.iter(|| black_box(validator.validate_json(py, json(py, &code), None, None).unwrap()))
+        bench.iter(|| {
+            black_box(
+                validator
+                    .validate_json(py, json(py, &code), None, None, None, None)
+                    .unwrap(),
+            )
+        })
     })
 }
 
@@ -77,7 +99,9 @@ fn list_int_python(bench: &mut Bencher) {
         let (validator, input) = list_int_input(py);
         let input = black_box(input.as_ref(py));
         bench.iter(|| {
-            let v = validator.validate_python(py, input, None, None).unwrap();
+            let v = validator
+                .validate_python(py, input, None, None, None, None, None, None, None, None)
+                .unwrap();
             black_box(v)
         })
     })
@@ -110,7 +134,7 @@ fn list_error_json(bench: &mut Bencher) {
                 .join(", ")
         );
 
-        match validator.validate_json(py, json(py, &code), None, None) {
+        match validator.validate_json(py, json(py, &code), None, None, None, None) {
             Ok(_) => panic!("unexpectedly valid"),
             Err(e) => {
                 let v = e.value(py);
@@ -121,10 +145,12 @@ fn list_error_json(bench: &mut Bencher) {
             }
         };
 
-        bench.iter(|| match validator.validate_json(py, json(py, &code), None, None) {
-            Ok(_) => panic!("unexpectedly valid"),
-            Err(e) => black_box(e),
-        })
+        bench.iter(
+            || match validator.validate_json(py, json(py, &code), None, None, None, None) {
+                Ok(_) => panic!("unexpectedly valid"),
+                Err(e) => black_box(e),
+            },
+        )
     })
 }
 
@@ -140,7 +166,7 @@ fn list_error_python_input(py: Python<'_>) -> (SchemaValidator, PyObject) {
 
     let input = py.eval(&code, None, None).unwrap();
 
-    match validator.validate_python(py, input, None, None) {
+    match validator.validate_python(py, input, None, None, None, None, None, None, None, None) {
         Ok(_) => panic!("unexpectedly valid"),
         Err(e) => {
             let v = e.value(py);
@@ -160,7 +186,7 @@ fn list_error_python(bench: &mut Bencher) {
 
         let input = black_box(input.as_ref(py));
         bench.iter(|| {
-            let result = validator.validate_python(py, input, None, None);
+            let result = validator.validate_python(py, input, None, None, None, None, None, None, None, None);
 
             match result {
                 Ok(_) => panic!("unexpectedly valid"),
@@ -195,7 +221,13 @@ fn list_any_json(bench: &mut Bencher) {
             (0..100).map(|x| x.to_string()).collect::<Vec<String>>().join(",")
         );
 
-        bench.iter(|| black_box(validator.validate_json(py, json(py, &code), None, None).unwrap()))
+        bench.iter(|| {
+            black_box(
+                validator
+                    .validate_json(py, json(py, &code), None, None, None, None)
+                    .unwrap(),
+            )
+        })
     })
 }
 
@@ -210,7 +242,9 @@ fn list_any_python(bench: &mut Bencher) {
         let input = py.eval(&code, None, None).unwrap();
         let input = black_box(input);
         bench.iter(|| {
-            let v = validator.validate_python(py, input, None, None).unwrap();
+            let v = validator
+                .validate_python(py, input, None, None, None, None, None, None, None, None)
+                .unwrap();
             black_box(v)
         })
     })
@@ -240,7 +274,13 @@ fn dict_json(bench: &mut Bencher) {
                 .join(", ")
         );
 
-        bench.iter(|| black_box(validator.validate_json(py, json(py, &code), None, None).unwrap()))
+        bench.iter(|| {
+            black_box(
+                validator
+                    .validate_json(py, json(py, &code), None, None, None, None)
+                    .unwrap(),
+            )
+        })
     })
 }
 
@@ -262,7 +302,9 @@ fn dict_python(bench: &mut Bencher) {
         let input = py.eval(&code, None, None).unwrap();
         let input = black_box(input);
         bench.iter(|| {
-            let v = validator.validate_python(py, input, None, None).unwrap();
+            let v = validator
+                .validate_python(py, input, None, None, None, None, None, None, None, None)
+                .unwrap();
             black_box(v)
         })
     })
@@ -290,7 +332,7 @@ fn dict_value_error(bench: &mut Bencher) {
 
         let input = py.eval(&code, None, None).unwrap();
 
-        match validator.validate_python(py, input, None, None) {
+        match validator.validate_python(py, input, None, None, None, None, None, None, None, None) {
             Ok(_) => panic!("unexpectedly valid"),
             Err(e) => {
                 let v = e.value(py);
@@ -303,7 +345,7 @@ fn dict_value_error(bench: &mut Bencher) {
 
         let input = black_box(input);
         bench.iter(|| {
-            let result = validator.validate_python(py, input, None, None);
+            let result = validator.validate_python(py, input, None, None, None, None, None, None, None, None);
 
             match result {
                 Ok(_) => panic!("unexpectedly valid"),
@@ -338,7 +380,13 @@ fn typed_dict_json(bench: &mut Bencher) {
 
         let code = r#"{"a": 1, "b": 2, "c": 3, "d": 4, "e": 5, "f": 6, "g": 7, "h": 8, "i": 9, "j": 0}"#.to_string();
 
-        bench.iter(|| black_box(validator.validate_json(py, json(py, &code), None, None).unwrap()))
+        bench.iter(|| {
+            black_box(
+                validator
+                    .validate_json(py, json(py, &code), None, None, None, None)
+                    .unwrap(),
+            )
+        })
     })
 }
 
@@ -369,7 +417,9 @@ fn typed_dict_python(bench: &mut Bencher) {
         let input = py.eval(&code, None, None).unwrap();
         let input = black_box(input);
         bench.iter(|| {
-            let v = validator.validate_python(py, input, None, None).unwrap();
+            let v = validator
+                .validate_python(py, input, None, None, None, None, None, None, None, None)
+                .unwrap();
             black_box(v)
         })
     })
@@ -407,7 +457,7 @@ fn typed_dict_deep_error(bench: &mut Bencher) {
         let input = py.eval(code, None, None).unwrap();
         let input = black_box(input);
 
-        match validator.validate_python(py, input, None, None) {
+        match validator.validate_python(py, input, None, None, None, None, None, None, None, None) {
             Ok(_) => panic!("unexpectedly valid"),
             Err(e) => {
                 let v = e.value(py);
@@ -419,7 +469,57 @@ fn typed_dict_deep_error(bench: &mut Bencher) {
         };
 
         bench.iter(|| {
-            let result = validator.validate_python(py, input, None, None);
+            let result = validator.validate_python(py, input, None, None, None, None, None, None, None, None);
+
+            match result {
+                Ok(_) => panic!("unexpectedly valid"),
+                Err(e) => black_box(e),
+            }
+        })
+    })
+}
+
+#[bench]
+fn list_typed_dict_some_errors_json(bench: &mut Bencher) {
+    Python::with_gil(|py| {
+        let validator = build_schema_validator(
+            py,
+            r#"{
+            'type': 'list',
+            'items_schema': {
+                'type': 'typed-dict',
+                'fields': {
+                    'id': {'schema': {'type': 'int'}},
+                    'name': {'schema': {'type': 'str'}},
+                    'score': {'schema': {'type': 'float'}},
+                },
+            },
+        }"#,
+        );
+
+        // 10k rows, 1% of which have an invalid `id`
+        let code = format!(
+            "[{}]",
+            (0..10_000)
+                .map(|i| match i % 100 {
+                    0 => format!(r#"{{"id": "x{i}", "name": "row {i}", "score": 1.5}}"#),
+                    _ => format!(r#"{{"id": {i}, "name": "row {i}", "score": 1.5}}"#),
+                })
+                .collect::<Vec<String>>()
+                .join(",")
+        );
+
+        match validator.validate_json(py, json(py, &code), None, None, None, None) {
+            Ok(_) => panic!("unexpectedly valid"),
+            Err(e) => {
+                let v = e.value(py);
+                let error_count: i64 = v.call_method0("error_count").unwrap().extract().unwrap();
+                assert_eq!(error_count, 100);
+            }
+        };
+
+        bench.iter(|| {
+            let result = validator.validate_json(py, json(py, &code), None, None, None, None);
 
             match result {
                 Ok(_) => panic!("unexpectedly valid"),
@@ -443,7 +543,11 @@ fn complete_model(bench: &mut Bencher) {
         let input = black_box(input);
 
         bench.iter(|| {
-            black_box(validator.validate_python(py, input, None, None).unwrap());
+            black_box(
+                validator
+                    .validate_python(py, input, None, None, None, None, None, None, None, None)
+                    .unwrap(),
+            );
         })
     })
 }
@@ -4,6 +4,8 @@ from ._pydantic_core import (
     PydanticKnownError,
     PydanticOmit,
     PydanticSerializationError,
+    PydanticUndefined,
+    PydanticUndefinedType,
     SchemaError,
     SchemaSerializer,
     SchemaValidator,
@@ -27,4 +29,6 @@ __all__ = (
     'PydanticKnownError',
     'PydanticOmit',
     'PydanticSerializationError',
+    'PydanticUndefined',
+    'PydanticUndefinedType',
 )
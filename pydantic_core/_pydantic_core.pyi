@@ -1,6 +1,6 @@
 import decimal
 import sys
-from typing import Any, TypedDict
+from typing import Any, Callable, Iterable, Iterator, Literal, TypedDict
 
 from pydantic_core.core_schema import CoreConfig, CoreSchema, ErrorType
 
@@ -22,7 +22,10 @@ __all__ = (
     'PydanticKnownError',
     'PydanticOmit',
     'PydanticSerializationError',
+    'PydanticUndefined',
+    'PydanticUndefinedType',
     'list_all_errors',
+    'validate_core_schema',
 )
 __version__: str
 build_profile: str
@@ -30,22 +33,137 @@ build_profile: str
 class SchemaValidator:
     title: str
     def __init__(self, schema: CoreSchema, config: 'CoreConfig | None' = None) -> None: ...
-    def validate_python(self, input: Any, strict: 'bool | None' = None, context: Any = None) -> Any: ...
+    def get_schema(self) -> CoreSchema: ...
+    def get_name(self) -> str: ...
+    def validate_python(
+        self,
+        input: Any,
+        strict: 'bool | None' = None,
+        context: Any = None,
+        collect_warnings: bool = False,
+        self_instance: 'Any | None' = None,
+        return_fields_set: bool = False,
+        allow_partial: bool = False,
+        include: 'IncEx' = None,
+        exclude: 'IncEx' = None,
+    ) -> Any:
+        """
+        With `collect_warnings=True`, returns a tuple of the output and a list of `WarningDetails`.
+
+        With `self_instance`, a `new-class` schema populates `__dict__` and `__fields_set__` of that instance
+        (e.g. `self` within `__init__`) and returns it, instead of creating a new instance.
+
+        With `return_fields_set=True`, the schema must be a `typed-dict` schema, returns a tuple of the output dict
+        and the set of fields which were present in the input, e.g. for partial updates.
+
+        With `allow_partial=True`, the input is treated as possibly incomplete: missing required fields aren't
+        errors, the last item of lists, tuples, dicts and typed dicts is left out if it's invalid, and a string
+        at the tail of the input isn't checked against `min_length` or `pattern`.
+
+        `include` and `exclude` pick the typed dict fields to validate, as with `SchemaSerializer.to_python`:
+        fields which are left out aren't validated or returned, as if they were unset, e.g. for a PATCH endpoint
+        validating a subset of fields. Items of lists, tuples and dicts are picked by index or key, and `'__all__'`
+        applies to every item, e.g. `exclude={'items': {'__all__': {'password'}}}`.
+        """
     def isinstance_python(self, input: Any, strict: 'bool | None' = None, context: Any = None) -> bool: ...
     def validate_json(
-        self, input: 'str | bytes | bytearray', strict: 'bool | None' = None, context: Any = None
+        self,
+        input: 'str | bytes | bytearray | memoryview',
+        strict: 'bool | None' = None,
+        context: Any = None,
+        collect_warnings: bool = False,
+        allow_partial: bool = False,
+    ) -> Any:
+        """
+        With `allow_partial=True`, JSON which was cut off, e.g. from an incomplete stream, is completed before it's
+        validated as with `validate_python(..., allow_partial=True)`: an open string is closed, and a trailing key,
+        number or literal which may be incomplete is dropped.
+        """
+    def validate_msgpack(
+        self,
+        input: 'bytes | bytearray | memoryview',
+        strict: 'bool | None' = None,
+        context: Any = None,
+        collect_warnings: bool = False,
+    ) -> Any: ...
+    def validate_strings(
+        self,
+        input: 'str | dict[str, Any]',
+        strict: 'bool | None' = None,
+        context: Any = None,
+        collect_warnings: bool = False,
+    ) -> Any: ...
+    def validate_cbor(
+        self,
+        input: 'bytes | bytearray | memoryview',
+        strict: 'bool | None' = None,
+        context: Any = None,
+        collect_warnings: bool = False,
+    ) -> Any: ...
+    def validate_urlencoded(
+        self,
+        input: 'str | bytes | bytearray | memoryview',
+        strict: 'bool | None' = None,
+        context: Any = None,
+        collect_warnings: bool = False,
+    ) -> Any: ...
+    # `validate_toml` and `validate_yaml` are only available when built with the `toml` and `yaml` cargo features
+    def validate_toml(
+        self, input: str, strict: 'bool | None' = None, context: Any = None, collect_warnings: bool = False
+    ) -> Any: ...
+    def validate_yaml(
+        self, input: str, strict: 'bool | None' = None, context: Any = None, collect_warnings: bool = False
     ) -> Any: ...
+    def validate(
+        self,
+        input: Any,
+        *,
+        format: Literal['python', 'json', 'msgpack', 'strings', 'cbor', 'urlencoded', 'toml', 'yaml'] = 'python',
+        strict: 'bool | None' = None,
+        context: Any = None,
+        collect_warnings: bool = False,
+    ) -> Any:
+        """
+        Calls the `validate_<format>` method, `format` defaults to `'python'`.
+        """
+    def validate_json_lines(
+        self, input: 'str | bytes | bytearray | memoryview', strict: 'bool | None' = None, context: Any = None
+    ) -> 'list[Any]': ...
+    def validate_many(
+        self, inputs: Iterable[Any], strict: 'bool | None' = None, context: Any = None
+    ) -> 'tuple[list[Any], list[tuple[int, ValidationError]]]':
+        """
+        Validates each python object in `inputs`, returns the outputs of valid items, and the index and error
+        of invalid items.
+        """
+    def validate_json_stream(
+        self, readable: Any, strict: 'bool | None' = None, context: Any = None, ndjson: bool = False
+    ) -> Iterator[Any]:
+        """
+        `readable` should have a `read(size)` method returning `str` or `bytes`, items of the top-level JSON array
+        (or with `ndjson=True` each newline delimited value) are validated one at a time as the iterator advances.
+        """
     def isinstance_json(
-        self, input: 'str | bytes | bytearray', strict: 'bool | None' = None, context: Any = None
+        self, input: 'str | bytes | bytearray | memoryview', strict: 'bool | None' = None, context: Any = None
     ) -> bool: ...
     def validate_assignment(
-        self, field: str, input: Any, data: 'dict[str, Any]', strict: 'bool | None' = None, context: Any = None
-    ) -> 'dict[str, Any]': ...
+        self, obj: Any, field_name: str, field_value: Any, strict: 'bool | None' = None, context: Any = None
+    ) -> Any:
+        """
+        `obj` is the data dict for a `typed-dict` schema, which is updated and returned, or the instance for a
+        `new-class` schema, whose `__dict__` and `__fields_set__` are updated and which is returned.
+        """
+    def profile_stats(self) -> 'list[ProfileStats]':
+        """
+        Requires `profile=True` in config, `time_ns` for a field includes the time spent on any fields nested in it.
+        """
 
 IncEx: TypeAlias = 'set[int] | set[str] | dict[int, IncEx] | dict[str, IncEx] | None'
 
 class SchemaSerializer:
+    title: str
     def __init__(self, schema: CoreSchema, config: 'CoreConfig | None' = None) -> None: ...
+    def get_schema(self) -> CoreSchema: ...
     def to_python(
         self,
         value: Any,
@@ -58,6 +176,9 @@ class SchemaSerializer:
         exclude_defaults: bool = False,
         exclude_none: bool = False,
         round_trip: bool = False,
+        ser_json_timedelta: Literal['iso8601', 'float'] | None = None,
+        ser_json_datetime: Literal['iso8601', 'seconds', 'milliseconds', 'float'] | None = None,
+        ser_json_bytes: Literal['utf8', 'base64'] | None = None,
     ) -> Any: ...
     def to_json(
         self,
@@ -71,6 +192,9 @@ class SchemaSerializer:
         exclude_defaults: bool = False,
         exclude_none: bool = False,
         round_trip: bool = False,
+        ser_json_timedelta: Literal['iso8601', 'float'] | None = None,
+        ser_json_datetime: Literal['iso8601', 'seconds', 'milliseconds', 'float'] | None = None,
+        ser_json_bytes: Literal['utf8', 'base64'] | None = None,
     ) -> bytes: ...
 
 class Url:
@@ -108,20 +232,48 @@ class MultiHostUrl:
     def __repr__(self) -> str: ...
 
 class SchemaError(Exception):
-    pass
+    def errors(self) -> 'list[ErrorDetails]': ...
 
 class ErrorDetails(TypedDict):
     type: str
+    code: NotRequired[int]
     loc: 'tuple[int | str, ...]'
     msg: str
-    input: Any
+    input: NotRequired[Any]
     ctx: NotRequired['dict[str, str | int | float]']
+    url: NotRequired[str]
+
+class WarningDetails(TypedDict):
+    type: str
+    loc: 'tuple[int | str, ...]'
+    msg: str
+
+class ProfileStats(TypedDict):
+    loc: 'tuple[str, ...]'
+    calls: int
+    time_ns: int
 
 class ValidationError(ValueError):
     title: str
 
     def error_count(self) -> int: ...
-    def errors(self, include_context: bool = True) -> 'list[ErrorDetails]': ...
+    def errors(
+        self,
+        include_context: bool = True,
+        include_input: bool = True,
+        include_url: bool = False,
+        translator: 'Callable[[str, dict[str, Any] | None], str | None] | None' = None,
+        include_code: bool = False,
+    ) -> 'list[ErrorDetails]': ...
+    def json(
+        self,
+        indent: 'int | None' = None,
+        include_context: bool = True,
+        include_input: bool = True,
+        include_url: bool = False,
+        translator: 'Callable[[str, dict[str, Any] | None], str | None] | None' = None,
+        include_code: bool = False,
+    ) -> str: ...
 
 class PydanticCustomError(ValueError):
     type: str
@@ -147,13 +299,26 @@ class PydanticOmit(Exception):
 class PydanticSerializationError(ValueError):
     def __init__(self, message: str) -> None: ...
 
+class PydanticUndefinedType:
+    def __copy__(self) -> PydanticUndefinedType: ...
+    def __deepcopy__(self, memo: Any) -> PydanticUndefinedType: ...
+
+PydanticUndefined: PydanticUndefinedType
+
 class ErrorTypeInfo(TypedDict):
     type: ErrorType
+    error_code: int
     message_template: str
     example_message: str
     example_context: 'dict[str, str | int | float] | None'
+    context_fields: 'list[str]'
 
 def list_all_errors() -> 'list[ErrorTypeInfo]':
     """
     Get information about all built-in errors.
     """
+
+def validate_core_schema(schema: CoreSchema) -> CoreSchema:
+    """
+    Validate a core schema without building a validator, raises `SchemaError` if it's invalid.
+    """
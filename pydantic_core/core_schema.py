@@ -19,6 +19,9 @@ def dict_not_none(**kwargs: Any) -> Any:
     return {k: v for k, v in kwargs.items() if v is not None}
 
 
+RevalidateInstances = Literal['never', 'always', 'subclass-instances', 'forbid-subclass-instances']
+
+
 class CoreConfig(TypedDict, total=False):
     title: str
     strict: bool
@@ -28,23 +31,80 @@ class CoreConfig(TypedDict, total=False):
     config_merge_priority: int
     # settings related to typed_dicts only
     typed_dict_extra_behavior: Literal['allow', 'forbid', 'ignore']
+    typed_dict_extra_attr: str
     typed_dict_total: bool  # default: True
+    typed_dict_serialization_order: Literal['input', 'schema', 'alphabetical']  # default: 'input'
+    typed_dict_key_transform: Literal['camel', 'pascal', 'kebab']
     # used on typed-dicts and tagged union keys
     from_attributes: bool
     revalidate_models: bool
+    revalidate_instances: RevalidateInstances  # used on models, default: 'never'
     # used on typed-dicts and arguments
     populate_by_name: bool  # replaces `allow_population_by_field_name` in pydantic v1
+    loc_by_alias: bool  # use aliases rather than names in error locations, default: False
     # fields related to string fields only
     str_max_length: int
     str_min_length: int
+    str_length_unit: Literal['chars', 'bytes', 'graphemes']  # default: 'chars'
+    str_unicode_normalize: Literal['NFC', 'NFKC']
+    str_subclasses: Literal['keep', 'coerce', 'reject']
     str_strip_whitespace: bool
     str_to_lower: bool
     str_to_upper: bool
     # fields related to float fields only
     allow_inf_nan: bool  # default: True
+    # validate items of large JSON arrays on multiple threads, see `parallel` on `list_schema`
+    list_parallel: bool  # default: False
+    # whether bools are accepted by int validators in lax mode
+    coerce_bools: bool  # default: False
+    # the strings accepted by bool validators in lax mode, see `bool_schema(true_values=..., false_values=...)`
+    bool_true_values: List[str]
+    bool_false_values: List[str]
+    # which other types list, tuple, set and frozenset validators accept in lax mode, JSON arrays are always accepted
+    list_from_tuple: bool  # default: True
+    list_from_set: bool  # default: False
+    tuple_from_list: bool  # default: True
+    tuple_from_set: bool  # default: False
+    set_from_list: bool  # lists and tuples, default: True
+    # whether a set built from another type, e.g. a list, with duplicate items is an error
+    set_duplicates: Literal['collapse', 'error']  # default: 'collapse'
+    collection_from_iter: bool  # iterators, generators, deques and dict views, default: True
+    collection_from_str: bool  # str as a sequence of characters and bytes as a sequence of ints, default: False
+    coerce_json_keys: bool  # default: False
+    # how ambiguous datetimes are resolved, sets `fold` on the output and is used when checking constraints
+    datetime_fold_mode: Literal['respect', 'earliest', 'latest']  # default: 'respect'
+    # how `:60` leap seconds are handled when parsing times and datetimes
+    leap_second_mode: Literal['error', 'clamp', 'carry']  # default: 'error'
+    # only accept RFC 3339 strings for dates and datetimes, rather than the superset of ISO 8601 parsed by default
+    rfc3339: bool  # default: False
+    # in lax mode, accept datetime strings followed by a timezone name, e.g. `2024-07-01 12:00:00 Europe/Paris`
+    named_timezones: bool  # default: False
+    # limits on the errors raised, `max_errors` stops validating sequences and dicts early,
+    # `group_errors` merges identical errors from different members of a sequence
+    max_errors: int
+    group_errors: bool  # default: False
+    # omit input values from errors, e.g. for passwords and tokens
+    hide_input_in_errors: bool  # default: False
+    # set the exceptions raised by validator functions as the `__cause__` of `ValidationError`, as an `ExceptionGroup`
+    validation_error_cause: bool  # default: False
+    # validate default values through the schema they're a default for
+    validate_default: bool  # default: False
+    # the maximum depth of recursive validators, deeper input raises a `recursion_loop` error, default: 255
+    recursion_limit: int
+    # reuse python strings for repeated values within a single validation, e.g. the keys of a JSON array of objects
+    cache_strings: Literal['all', 'keys', 'none']  # default: 'none'
+    # count calls and time taken validating each typed dict field, see `SchemaValidator.profile_stats()`
+    profile: bool  # default: False
+    # build the choices of unions when they're first used rather than with the validator, see `union_schema(lazy=...)`
+    union_lazy: bool  # default: False
     # the config options are used to customise serialization to JSON
     ser_json_timedelta: Literal['iso8601', 'float']  # default: 'iso8601'
+    ser_json_datetime: Literal['iso8601', 'seconds', 'milliseconds', 'float']  # default: 'iso8601'
     ser_json_bytes: Literal['utf8', 'base64']  # default: 'utf8'
+    ser_json_decimal: Literal['str', 'float']  # default: 'str'
+    # log validation and serialization decisions to the `pydantic_core` logger, default: False unless the
+    # `PYDANTIC_CORE_TRACE` environment variable is set
+    trace: bool
 
 
 IncExCall: TypeAlias = 'set[int | str] | dict[int | str, IncExCall] | None'
@@ -93,17 +153,28 @@ class FormatSerSchema(TypedDict, total=False):
     formatting_string: Required[str]
 
 
+class PrecisionSerSchema(TypedDict, total=False):
+    type: Required[Literal['precision']]
+    mode: Required[Literal['fixed', 'significant', 'repr']]
+    digits: int  # required for `fixed` and `significant`, not allowed for `repr`
+
+
 class NewClassSerSchema(TypedDict, total=False):
     type: Required[Literal['new-class']]
     schema: Required[CoreSchema]
 
 
-SerSchema = Union[AltTypeSerSchema, FunctionSerSchema, FormatSerSchema, NewClassSerSchema]
+SerSchema = Union[AltTypeSerSchema, FunctionSerSchema, FormatSerSchema, PrecisionSerSchema, NewClassSerSchema]
 
 
 class AnySchema(TypedDict, total=False):
     type: Required[Literal['any']]
+    custom_error_type: str
+    custom_error_message: str
+    custom_error_context: Dict[str, Union[str, int, float]]
     ref: str
+    title: str
+    metadata: Any
     extra: Any
     serialization: SerSchema
 
@@ -129,7 +200,12 @@ def any_schema(*, ref: str | None = None, extra: Any = None, serialization: SerS
 
 class NoneSchema(TypedDict, total=False):
     type: Required[Literal['none']]
+    custom_error_type: str
+    custom_error_message: str
+    custom_error_context: Dict[str, Union[str, int, float]]
     ref: str
+    title: str
+    metadata: Any
     extra: Any
     serialization: SerSchema
 
@@ -156,13 +232,25 @@ def none_schema(*, ref: str | None = None, extra: Any = None, serialization: Ser
 class BoolSchema(TypedDict, total=False):
     type: Required[Literal['bool']]
     strict: bool
+    true_values: List[str]
+    false_values: List[str]
+    custom_error_type: str
+    custom_error_message: str
+    custom_error_context: Dict[str, Union[str, int, float]]
     ref: str
+    title: str
+    metadata: Any
     extra: Any
     serialization: SerSchema
 
 
 def bool_schema(
-    strict: bool | None = None, ref: str | None = None, extra: Any = None, serialization: SerSchema | None = None
+    strict: bool | None = None,
+    true_values: list[str] | None = None,
+    false_values: list[str] | None = None,
+    ref: str | None = None,
+    extra: Any = None,
+    serialization: SerSchema | None = None,
 ) -> BoolSchema:
     """
     Returns a schema that matches a bool value, e.g.:
@@ -176,11 +264,23 @@ def bool_schema(
 
     Args:
         strict: Whether the value should be a bool or a value that can be converted to a bool
+        true_values: The strings accepted as `True` in lax mode, compared ignoring case,
+            default: `'1'`, `'t'`, `'y'`, `'on'`, `'yes'` and `'true'`
+        false_values: The strings accepted as `False` in lax mode, compared ignoring case,
+            default: `'0'`, `'f'`, `'n'`, `'no'`, `'off'` and `'false'`
         ref: See [TODO] for details
         extra: See [TODO] for details
         serialization: Custom serialization schema
     """
-    return dict_not_none(type='bool', strict=strict, ref=ref, extra=extra, serialization=serialization)
+    return dict_not_none(
+        type='bool',
+        strict=strict,
+        true_values=true_values,
+        false_values=false_values,
+        ref=ref,
+        extra=extra,
+        serialization=serialization,
+    )
 
 
 class IntSchema(TypedDict, total=False):
@@ -191,7 +291,13 @@ class IntSchema(TypedDict, total=False):
     lt: int
     gt: int
     strict: bool
+    coerce_bools: bool
+    custom_error_type: str
+    custom_error_message: str
+    custom_error_context: Dict[str, Union[str, int, float]]
     ref: str
+    title: str
+    metadata: Any
     extra: Any
     serialization: SerSchema
 
@@ -204,6 +310,7 @@ def int_schema(
     lt: int | None = None,
     gt: int | None = None,
     strict: bool | None = None,
+    coerce_bools: bool | None = None,
     ref: str | None = None,
     extra: Any = None,
     serialization: SerSchema | None = None,
@@ -225,6 +332,7 @@ def int_schema(
         lt: The value must be strictly less than this number
         gt: The value must be strictly greater than this number
         strict: Whether the value should be a int or a value that can be converted to a int
+        coerce_bools: Whether `True` and `False` are accepted as `1` and `0` in lax mode
         ref: See [TODO] for details
         extra: See [TODO] for details
         serialization: Custom serialization schema
@@ -237,6 +345,7 @@ def int_schema(
         lt=lt,
         gt=gt,
         strict=strict,
+        coerce_bools=coerce_bools,
         ref=ref,
         extra=extra,
         serialization=serialization,
@@ -252,7 +361,12 @@ class FloatSchema(TypedDict, total=False):
     lt: float
     gt: float
     strict: bool
+    custom_error_type: str
+    custom_error_message: str
+    custom_error_context: Dict[str, Union[str, int, float]]
     ref: str
+    title: str
+    metadata: Any
     extra: Any
     serialization: SerSchema
 
@@ -312,11 +426,19 @@ class StringSchema(TypedDict, total=False):
     pattern: str
     max_length: int
     min_length: int
+    length_unit: Literal['chars', 'bytes', 'graphemes']  # default: 'chars'
+    unicode_normalize: Literal['NFC', 'NFKC']
+    subclasses: Literal['keep', 'coerce', 'reject']
     strip_whitespace: bool
     to_lower: bool
     to_upper: bool
     strict: bool
+    custom_error_type: str
+    custom_error_message: str
+    custom_error_context: Dict[str, Union[str, int, float]]
     ref: str
+    title: str
+    metadata: Any
     extra: Any
     serialization: SerSchema
 
@@ -326,6 +448,9 @@ def string_schema(
     pattern: str | None = None,
     max_length: int | None = None,
     min_length: int | None = None,
+    length_unit: Literal['chars', 'bytes', 'graphemes'] | None = None,
+    unicode_normalize: Literal['NFC', 'NFKC'] | None = None,
+    subclasses: Literal['keep', 'coerce', 'reject'] | None = None,
     strip_whitespace: bool | None = None,
     to_lower: bool | None = None,
     to_upper: bool | None = None,
@@ -348,6 +473,12 @@ def string_schema(
         pattern: A regex pattern that the value must match
         max_length: The value must be at most this length
         min_length: The value must be at least this length
+        length_unit: How the length is measured, `'chars'` counts code points like `len()`, `'bytes'` counts
+            bytes of the UTF-8 encoding and `'graphemes'` counts user-perceived characters
+        unicode_normalize: The unicode normalization form applied to the value before it's checked
+        subclasses: How instances of `str` subclasses such as `StrEnum` members are validated in both strict
+            and lax mode, `'keep'` returns them unchanged, `'coerce'` converts them to `str` and `'reject'` raises
+            an error, by default they're rejected in strict mode and coerced in lax mode
         strip_whitespace: Whether to strip whitespace from the value
         to_lower: Whether to convert the value to lowercase
         to_upper: Whether to convert the value to uppercase
@@ -361,6 +492,9 @@ def string_schema(
         pattern=pattern,
         max_length=max_length,
         min_length=min_length,
+        length_unit=length_unit,
+        unicode_normalize=unicode_normalize,
+        subclasses=subclasses,
         strip_whitespace=strip_whitespace,
         to_lower=to_lower,
         to_upper=to_upper,
@@ -376,7 +510,12 @@ class BytesSchema(TypedDict, total=False):
     max_length: int
     min_length: int
     strict: bool
+    custom_error_type: str
+    custom_error_message: str
+    custom_error_context: Dict[str, Union[str, int, float]]
     ref: str
+    title: str
+    metadata: Any
     extra: Any
     serialization: SerSchema
 
@@ -430,7 +569,14 @@ class DateSchema(TypedDict, total=False):
     # defaults to current local utc offset from `time.localtime().tm_gmtoff`
     # value is restricted to -86_400 < offset < 86_400 by bounds in generate_self_schema.py
     now_utc_offset: int
+    input_formats: List[str]
+    rfc3339: bool  # default: False
+    custom_error_type: str
+    custom_error_message: str
+    custom_error_context: Dict[str, Union[str, int, float]]
     ref: str
+    title: str
+    metadata: Any
     extra: Any
     serialization: SerSchema
 
@@ -444,6 +590,8 @@ def date_schema(
     gt: date | None = None,
     now_op: Literal['past', 'future'] | None = None,
     now_utc_offset: int | None = None,
+    input_formats: List[str] | None = None,
+    rfc3339: bool | None = None,
     ref: str | None = None,
     extra: Any = None,
     serialization: SerSchema | None = None,
@@ -467,6 +615,8 @@ def date_schema(
         gt: The value must be strictly greater than this date
         now_op: The value must be in the past or future relative to the current date
         now_utc_offset: The value must be in the past or future relative to the current date with this utc offset
+        input_formats: Additional `strftime` style formats, e.g. `%d/%m/%Y`, accepted for strings in lax mode
+        rfc3339: Whether string inputs must be RFC 3339 `full-date`s, e.g. `2020-01-01`, other formats are rejected
         ref: See [TODO] for details
         extra: See [TODO] for details
         serialization: Custom serialization schema
@@ -480,6 +630,8 @@ def date_schema(
         gt=gt,
         now_op=now_op,
         now_utc_offset=now_utc_offset,
+        input_formats=input_formats,
+        rfc3339=rfc3339,
         ref=ref,
         extra=extra,
         serialization=serialization,
@@ -493,7 +645,15 @@ class TimeSchema(TypedDict, total=False):
     ge: time
     lt: time
     gt: time
+    tz_constraint: Literal['aware', 'naive']
+    leap_second_mode: Literal['error', 'clamp', 'carry']
+    input_formats: List[str]
+    custom_error_type: str
+    custom_error_message: str
+    custom_error_context: Dict[str, Union[str, int, float]]
     ref: str
+    title: str
+    metadata: Any
     extra: Any
     serialization: SerSchema
 
@@ -505,6 +665,9 @@ def time_schema(
     ge: time | None = None,
     lt: time | None = None,
     gt: time | None = None,
+    tz_constraint: Literal['aware', 'naive'] | None = None,
+    leap_second_mode: Literal['error', 'clamp', 'carry'] | None = None,
+    input_formats: List[str] | None = None,
     ref: str | None = None,
     extra: Any = None,
     serialization: SerSchema | None = None,
@@ -526,12 +689,27 @@ def time_schema(
         ge: The value must be greater than or equal to this time
         lt: The value must be strictly less than this time
         gt: The value must be strictly greater than this time
+        tz_constraint: The value must be timezone aware or naive
+        leap_second_mode: How `:60` leap seconds are handled, either an error, clamped to `:59.999999` or
+            carried into the next minute
+        input_formats: Additional `strftime` style formats, e.g. `%I:%M %p`, accepted for strings in lax mode
         ref: See [TODO] for details
         extra: See [TODO] for details
         serialization: Custom serialization schema
     """
     return dict_not_none(
-        type='time', strict=strict, le=le, ge=ge, lt=lt, gt=gt, ref=ref, extra=extra, serialization=serialization
+        type='time',
+        strict=strict,
+        le=le,
+        ge=ge,
+        lt=lt,
+        gt=gt,
+        tz_constraint=tz_constraint,
+        leap_second_mode=leap_second_mode,
+        input_formats=input_formats,
+        ref=ref,
+        extra=extra,
+        serialization=serialization,
     )
 
 
@@ -544,10 +722,20 @@ class DatetimeSchema(TypedDict, total=False):
     gt: datetime
     now_op: Literal['past', 'future']
     tz_constraint: Literal['aware', 'naive']
+    fold_mode: Literal['respect', 'earliest', 'latest']
+    leap_second_mode: Literal['error', 'clamp', 'carry']
     # defaults to current local utc offset from `time.localtime().tm_gmtoff`
     # value is restricted to -86_400 < offset < 86_400 by bounds in generate_self_schema.py
     now_utc_offset: int
+    input_formats: List[str]
+    rfc3339: bool  # default: False
+    named_timezones: bool  # default: False
+    custom_error_type: str
+    custom_error_message: str
+    custom_error_context: Dict[str, Union[str, int, float]]
     ref: str
+    title: str
+    metadata: Any
     extra: Any
     serialization: SerSchema
 
@@ -561,7 +749,12 @@ def datetime_schema(
     gt: datetime | None = None,
     now_op: Literal['past', 'future'] | None = None,
     tz_constraint: Literal['aware', 'naive'] | None = None,
+    fold_mode: Literal['respect', 'earliest', 'latest'] | None = None,
+    leap_second_mode: Literal['error', 'clamp', 'carry'] | None = None,
     now_utc_offset: int | None = None,
+    input_formats: List[str] | None = None,
+    rfc3339: bool | None = None,
+    named_timezones: bool | None = None,
     ref: str | None = None,
     extra: Any = None,
     serialization: SerSchema | None = None,
@@ -586,7 +779,17 @@ def datetime_schema(
         gt: The value must be strictly greater than this datetime
         now_op: The value must be in the past or future relative to the current datetime
         tz_constraint: The value must be timezone aware or naive
+        fold_mode: How ambiguous wall-clock times are resolved, sets `fold` on the output and is used when checking
+            constraints, see PEP 495
+        leap_second_mode: How `:60` leap seconds are handled, either an error, clamped to `:59.999999` or
+            carried into the next minute
         now_utc_offset: The value must be in the past or future relative to the current datetime with this utc offset
+        input_formats: Additional `strftime` style formats, e.g. `%d/%m/%y %H:%M`, accepted for strings in lax mode
+        rfc3339: Whether string inputs must be RFC 3339 `date-time`s, e.g. `2020-01-01T12:00:00Z`, a space
+            separator, missing seconds or a missing timezone offset are rejected
+        named_timezones: Whether strings in lax mode may end with a timezone name instead of an offset, e.g.
+            `2024-07-01 12:00:00 Europe/Paris`, RFC 2822 abbreviations like `EST` are always supported,
+            IANA names require pydantic-core to be built with the `tz` feature
         ref: See [TODO] for details
         extra: See [TODO] for details
         serialization: Custom serialization schema
@@ -600,7 +803,12 @@ def datetime_schema(
         gt=gt,
         now_op=now_op,
         tz_constraint=tz_constraint,
+        fold_mode=fold_mode,
+        leap_second_mode=leap_second_mode,
         now_utc_offset=now_utc_offset,
+        input_formats=input_formats,
+        rfc3339=rfc3339,
+        named_timezones=named_timezones,
         ref=ref,
         extra=extra,
         serialization=serialization,
@@ -614,7 +822,12 @@ class TimedeltaSchema(TypedDict, total=False):
     ge: timedelta
     lt: timedelta
     gt: timedelta
+    custom_error_type: str
+    custom_error_message: str
+    custom_error_context: Dict[str, Union[str, int, float]]
     ref: str
+    title: str
+    metadata: Any
     extra: Any
     serialization: SerSchema
 
@@ -659,7 +872,12 @@ def timedelta_schema(
 class LiteralSchema(TypedDict, total=False):
     type: Required[Literal['literal']]
     expected: Required[List[Any]]
+    custom_error_type: str
+    custom_error_message: str
+    custom_error_context: Dict[str, Union[str, int, float]]
     ref: str
+    title: str
+    metadata: Any
     extra: Any
     serialization: SerSchema
 
@@ -696,7 +914,12 @@ class IsInstanceSchema(TypedDict, total=False):
     cls_repr: str
     json_types: Set[JsonType]
     json_function: Callable[[Any], Any]
+    custom_error_type: str
+    custom_error_message: str
+    custom_error_context: Dict[str, Union[str, int, float]]
     ref: str
+    title: str
+    metadata: Any
     extra: Any
     serialization: SerSchema
 
@@ -751,7 +974,12 @@ class IsSubclassSchema(TypedDict, total=False):
     type: Required[Literal['is-subclass']]
     cls: Required[Type[Any]]
     cls_repr: str
+    custom_error_type: str
+    custom_error_message: str
+    custom_error_context: Dict[str, Union[str, int, float]]
     ref: str
+    title: str
+    metadata: Any
     extra: Any
     serialization: SerSchema
 
@@ -795,7 +1023,12 @@ def is_subclass_schema(
 
 class CallableSchema(TypedDict, total=False):
     type: Required[Literal['callable']]
+    custom_error_type: str
+    custom_error_message: str
+    custom_error_context: Dict[str, Union[str, int, float]]
     ref: str
+    title: str
+    metadata: Any
     extra: Any
     serialization: SerSchema
 
@@ -841,7 +1074,13 @@ class ListSchema(TypedDict, total=False):
     max_length: int
     strict: bool
     allow_any_iter: bool
+    parallel: bool
+    custom_error_type: str
+    custom_error_message: str
+    custom_error_context: Dict[str, Union[str, int, float]]
     ref: str
+    title: str
+    metadata: Any
     extra: Any
     serialization: IncExSeqOrElseSerSchema
 
@@ -853,6 +1092,7 @@ def list_schema(
     max_length: int | None = None,
     strict: bool | None = None,
     allow_any_iter: bool | None = None,
+    parallel: bool | None = None,
     ref: str | None = None,
     extra: Any = None,
     serialization: IncExSeqOrElseSerSchema | None = None,
@@ -873,6 +1113,8 @@ def list_schema(
         max_length: The value must be a list with at most this many items
         strict: The value must be a list with exactly this many items
         allow_any_iter: Whether the value can be any iterable
+        parallel: Whether to validate the items of large JSON arrays on multiple threads with the GIL released,
+            only used when items are validated by a plain `int`, `float`, `bool` or `str` schema
         ref: See [TODO] for details
         extra: See [TODO] for details
         serialization: Custom serialization schema
@@ -884,6 +1126,7 @@ def list_schema(
         max_length=max_length,
         strict=strict,
         allow_any_iter=allow_any_iter,
+        parallel=parallel,
         ref=ref,
         extra=extra,
         serialization=serialization,
@@ -896,7 +1139,12 @@ class TuplePositionalSchema(TypedDict, total=False):
     items_schema: Required[List[CoreSchema]]
     extra_schema: CoreSchema
     strict: bool
+    custom_error_type: str
+    custom_error_message: str
+    custom_error_context: Dict[str, Union[str, int, float]]
     ref: str
+    title: str
+    metadata: Any
     extra: Any
     serialization: IncExSeqOrElseSerSchema
 
@@ -946,7 +1194,12 @@ class TupleVariableSchema(TypedDict, total=False):
     min_length: int
     max_length: int
     strict: bool
+    custom_error_type: str
+    custom_error_message: str
+    custom_error_context: Dict[str, Union[str, int, float]]
     ref: str
+    title: str
+    metadata: Any
     extra: Any
     serialization: IncExSeqOrElseSerSchema
 
@@ -1000,7 +1253,12 @@ class SetSchema(TypedDict, total=False):
     max_length: int
     generator_max_length: int
     strict: bool
+    custom_error_type: str
+    custom_error_message: str
+    custom_error_context: Dict[str, Union[str, int, float]]
     ref: str
+    title: str
+    metadata: Any
     extra: Any
     serialization: SerSchema
 
@@ -1056,7 +1314,12 @@ class FrozenSetSchema(TypedDict, total=False):
     max_length: int
     generator_max_length: int
     strict: bool
+    custom_error_type: str
+    custom_error_message: str
+    custom_error_context: Dict[str, Union[str, int, float]]
     ref: str
+    title: str
+    metadata: Any
     extra: Any
     serialization: SerSchema
 
@@ -1109,7 +1372,12 @@ class GeneratorSchema(TypedDict, total=False):
     type: Required[Literal['generator']]
     items_schema: CoreSchema
     max_length: int
+    custom_error_type: str
+    custom_error_message: str
+    custom_error_context: Dict[str, Union[str, int, float]]
     ref: str
+    title: str
+    metadata: Any
     extra: Any
     serialization: IncExSeqOrElseSerSchema
 
@@ -1177,7 +1445,14 @@ class DictSchema(TypedDict, total=False):
     min_length: int
     max_length: int
     strict: bool
+    from_attributes: bool
+    coerce_json_keys: bool
+    custom_error_type: str
+    custom_error_message: str
+    custom_error_context: Dict[str, Union[str, int, float]]
     ref: str
+    title: str
+    metadata: Any
     extra: Any
     serialization: IncExDictOrElseSerSchema
 
@@ -1189,6 +1464,8 @@ def dict_schema(
     min_length: int | None = None,
     max_length: int | None = None,
     strict: bool | None = None,
+    from_attributes: bool | None = None,
+    coerce_json_keys: bool | None = None,
     ref: str | None = None,
     extra: Any = None,
     serialization: SerSchema | None = None,
@@ -1211,6 +1488,9 @@ def dict_schema(
         min_length: The value must be a dict with at least this many items
         max_length: The value must be a dict with at most this many items
         strict: Whether the keys and values should be validated with strict mode
+        from_attributes: Whether objects which aren't mappings should be validated from their public attributes
+        coerce_json_keys: Whether JSON object keys (which are always strings) should be coerced to the keys schema
+            even in strict mode, e.g. to validate `dict[int, X]` from JSON
         ref: See [TODO] for details
         extra: See [TODO] for details
         serialization: Custom serialization schema
@@ -1222,6 +1502,8 @@ def dict_schema(
         min_length=min_length,
         max_length=max_length,
         strict=strict,
+        from_attributes=from_attributes,
+        coerce_json_keys=coerce_json_keys,
         ref=ref,
         extra=extra,
         serialization=serialization,
@@ -1230,7 +1512,15 @@ def dict_schema(
 
 class ValidatorFunction(Protocol):
     def __call__(
-        self, __input_value: Any, *, data: Any, config: CoreConfig | None, context: Any, **future_kwargs: Any
+        self,
+        __input_value: Any,
+        *,
+        data: Any,
+        config: CoreConfig | None,
+        context: Any,
+        field_name: str | None,
+        mode: Literal['python', 'json', 'string'],
+        **future_kwargs: Any,
     ) -> Any:  # pragma: no cover
         ...
 
@@ -1240,7 +1530,14 @@ class FunctionSchema(TypedDict, total=False):
     mode: Required[Literal['before', 'after']]
     function: Required[ValidatorFunction]
     schema: Required[CoreSchema]
+    skip_on_assignment: bool  # default: False
+    skip_from_attributes: bool  # default: False
+    custom_error_type: str
+    custom_error_message: str
+    custom_error_context: Dict[str, Union[str, int, float]]
     ref: str
+    title: str
+    metadata: Any
     extra: Any
     serialization: SerSchema
 
@@ -1249,6 +1546,8 @@ def function_before_schema(
     function: ValidatorFunction,
     schema: CoreSchema,
     *,
+    skip_on_assignment: bool | None = None,
+    skip_from_attributes: bool | None = None,
     ref: str | None = None,
     extra: Any = None,
     serialization: SerSchema | None = None,
@@ -1273,6 +1572,8 @@ def function_before_schema(
     Args:
         function: The validator function to call
         schema: The schema to validate the output of the validator function
+        skip_on_assignment: Whether to skip the function when validating an assignment to a model or typed dict
+        skip_from_attributes: Whether to skip the function when a model or typed dict is validated from attributes
         ref: See [TODO] for details
         extra: See [TODO] for details
         serialization: Custom serialization schema
@@ -1282,6 +1583,8 @@ def function_before_schema(
         mode='before',
         function=function,
         schema=schema,
+        skip_on_assignment=skip_on_assignment,
+        skip_from_attributes=skip_from_attributes,
         ref=ref,
         extra=extra,
         serialization=serialization,
@@ -1292,6 +1595,8 @@ def function_after_schema(
     schema: CoreSchema,
     function: ValidatorFunction,
     *,
+    skip_on_assignment: bool | None = None,
+    skip_from_attributes: bool | None = None,
     ref: str | None = None,
     extra: Any = None,
     serialization: SerSchema | None = None,
@@ -1314,6 +1619,8 @@ def function_after_schema(
     Args:
         schema: The schema to validate before the validator function
         function: The validator function to call after the schema is validated
+        skip_on_assignment: Whether to skip the function when validating an assignment to a model or typed dict
+        skip_from_attributes: Whether to skip the function when a model or typed dict is validated from attributes
         ref: See [TODO] for details
         extra: See [TODO] for details
         serialization: Custom serialization schema
@@ -1323,6 +1630,8 @@ def function_after_schema(
         mode='after',
         function=function,
         schema=schema,
+        skip_on_assignment=skip_on_assignment,
+        skip_from_attributes=skip_from_attributes,
         ref=ref,
         extra=extra,
         serialization=serialization,
@@ -1343,6 +1652,8 @@ class WrapValidatorFunction(Protocol):
         data: Any,
         config: CoreConfig | None,
         context: Any,
+        field_name: str | None,
+        mode: Literal['python', 'json', 'string'],
         **future_kwargs: Any,
     ) -> Any:  # pragma: no cover
         ...
@@ -1353,7 +1664,14 @@ class FunctionWrapSchema(TypedDict, total=False):
     mode: Required[Literal['wrap']]
     function: Required[WrapValidatorFunction]
     schema: Required[CoreSchema]
+    skip_on_assignment: bool  # default: False
+    skip_from_attributes: bool  # default: False
+    custom_error_type: str
+    custom_error_message: str
+    custom_error_context: Dict[str, Union[str, int, float]]
     ref: str
+    title: str
+    metadata: Any
     extra: Any
     serialization: SerSchema
 
@@ -1362,6 +1680,8 @@ def function_wrap_schema(
     function: WrapValidatorFunction,
     schema: CoreSchema,
     *,
+    skip_on_assignment: bool | None = None,
+    skip_from_attributes: bool | None = None,
     ref: str | None = None,
     extra: Any = None,
     serialization: SerSchema | None = None,
@@ -1385,6 +1705,8 @@ def function_wrap_schema(
     Args:
         function: The validator function to call
         schema: The schema to validate the output of the validator function
+        skip_on_assignment: Whether to skip the function when validating an assignment to a model or typed dict
+        skip_from_attributes: Whether to skip the function when a model or typed dict is validated from attributes
         ref: See [TODO] for details
         extra: See [TODO] for details
         serialization: Custom serialization schema
@@ -1394,6 +1716,8 @@ def function_wrap_schema(
         mode='wrap',
         function=function,
         schema=schema,
+        skip_on_assignment=skip_on_assignment,
+        skip_from_attributes=skip_from_attributes,
         ref=ref,
         extra=extra,
         serialization=serialization,
@@ -1404,7 +1728,12 @@ class FunctionPlainSchema(TypedDict, total=False):
     type: Required[Literal['function']]
     mode: Required[Literal['plain']]
     function: Required[ValidatorFunction]
+    custom_error_type: str
+    custom_error_message: str
+    custom_error_context: Dict[str, Union[str, int, float]]
     ref: str
+    title: str
+    metadata: Any
     extra: Any
     serialization: SerSchema
 
@@ -1444,8 +1773,14 @@ class WithDefaultSchema(TypedDict, total=False):
     default: Any
     default_factory: Callable[[], Any]
     on_error: Literal['raise', 'omit', 'default']  # default: 'raise'
+    validate_default: bool  # default: False
     strict: bool
+    custom_error_type: str
+    custom_error_message: str
+    custom_error_context: Dict[str, Union[str, int, float]]
     ref: str
+    title: str
+    metadata: Any
     extra: Any
     serialization: SerSchema
 
@@ -1459,6 +1794,7 @@ def with_default_schema(
     default: Any = Omitted,
     default_factory: Callable[[], Any] | None = None,
     on_error: Literal['raise', 'omit', 'default'] | None = None,
+    validate_default: bool | None = None,
     strict: bool | None = None,
     ref: str | None = None,
     extra: Any = None,
@@ -1482,6 +1818,7 @@ def with_default_schema(
         default: The default value to use
         default_factory: A function that returns the default value to use
         on_error: What to do if the schema validation fails. One of 'raise', 'omit', 'default'
+        validate_default: Whether the default value should be validated by the schema
         strict: Whether the underlying schema should be validated with strict mode
         ref: See [TODO] for details
         extra: See [TODO] for details
@@ -1492,6 +1829,7 @@ def with_default_schema(
         schema=schema,
         default_factory=default_factory,
         on_error=on_error,
+        validate_default=validate_default,
         strict=strict,
         ref=ref,
         extra=extra,
@@ -1506,7 +1844,12 @@ class NullableSchema(TypedDict, total=False):
     type: Required[Literal['nullable']]
     schema: Required[CoreSchema]
     strict: bool
+    custom_error_type: str
+    custom_error_message: str
+    custom_error_context: Dict[str, Union[str, int, float]]
     ref: str
+    title: str
+    metadata: Any
     extra: Any
     serialization: SerSchema
 
@@ -1547,8 +1890,12 @@ class UnionSchema(TypedDict, total=False):
     custom_error_type: str
     custom_error_message: str
     custom_error_context: Dict[str, Union[str, int, float]]
+    error_mode: Literal['all', 'best', 'summary']  # default: 'all'
     strict: bool
+    lazy: bool
     ref: str
+    title: str
+    metadata: Any
     extra: Any
     serialization: SerSchema
 
@@ -1558,7 +1905,9 @@ def union_schema(
     custom_error_type: str | None = None,
     custom_error_message: str | None = None,
     custom_error_context: dict[str, str | int] | None = None,
+    error_mode: Literal['all', 'best', 'summary'] | None = None,
     strict: bool | None = None,
+    lazy: bool | None = None,
     ref: str | None = None,
     extra: Any = None,
     serialization: SerSchema | None = None,
@@ -1579,7 +1928,11 @@ def union_schema(
         custom_error_type: The custom error type to use if the validation fails
         custom_error_message: The custom error message to use if the validation fails
         custom_error_context: The custom error context to use if the validation fails
+        error_mode: How errors are reported if no choice matches, `'all'` for the errors from every choice,
+            `'best'` for the errors from the choice with the fewest errors or `'summary'` for a single error
         strict: Whether the underlying schemas should be validated with strict mode
+        lazy: Whether choices are only built when first used, this speeds up building large schemas,
+            but errors in a choice's schema are only raised when it's used
         ref: See [TODO] for details
         extra: See [TODO] for details
         serialization: Custom serialization schema
@@ -1590,7 +1943,9 @@ def union_schema(
         custom_error_type=custom_error_type,
         custom_error_message=custom_error_message,
         custom_error_context=custom_error_context,
+        error_mode=error_mode,
         strict=strict,
+        lazy=lazy,
         ref=ref,
         extra=extra,
         serialization=serialization,
@@ -1608,6 +1963,8 @@ class TaggedUnionSchema(TypedDict, total=False):
     custom_error_context: Dict[str, Union[str, int, float]]
     strict: bool
     ref: str
+    title: str
+    metadata: Any
     extra: Any
     serialization: SerSchema
 
@@ -1681,7 +2038,12 @@ def tagged_union_schema(
 class ChainSchema(TypedDict, total=False):
     type: Required[Literal['chain']]
     steps: Required[List[CoreSchema]]
+    custom_error_type: str
+    custom_error_message: str
+    custom_error_context: Dict[str, Union[str, int, float]]
     ref: str
+    title: str
+    metadata: Any
     extra: Any
     serialization: SerSchema
 
@@ -1719,7 +2081,12 @@ class LaxOrStrictSchema(TypedDict, total=False):
     lax_schema: Required[CoreSchema]
     strict_schema: Required[CoreSchema]
     strict: bool
+    custom_error_type: str
+    custom_error_message: str
+    custom_error_context: Dict[str, Union[str, int, float]]
     ref: str
+    title: str
+    metadata: Any
     extra: Any
 
 
@@ -1766,23 +2133,86 @@ def lax_or_strict_schema(
     )
 
 
+class ConditionalSchema(TypedDict, total=False):
+    type: Required[Literal['conditional']]
+    if_schema: Required[CoreSchema]
+    then_schema: Required[CoreSchema]
+    else_schema: Required[CoreSchema]
+    ref: str
+    title: str
+    metadata: Any
+    extra: Any
+    serialization: SerSchema
+
+
+def conditional_schema(
+    if_schema: CoreSchema,
+    then_schema: CoreSchema,
+    else_schema: CoreSchema,
+    *,
+    ref: str | None = None,
+    extra: Any = None,
+    serialization: SerSchema | None = None,
+) -> ConditionalSchema:
+    """
+    Returns a schema that validates the input against `then_schema` if it's valid against `if_schema`,
+    otherwise against `else_schema`, e.g.:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.conditional_schema(
+        if_schema=core_schema.string_schema(strict=True),
+        then_schema=core_schema.string_schema(to_upper=True),
+        else_schema=core_schema.int_schema(),
+    )
+    v = SchemaValidator(schema)
+    assert v.validate_python('abc') == 'ABC'
+    assert v.validate_python(1.0) == 1
+    ```
+
+    Args:
+        if_schema: The schema the input is checked against, its output is discarded
+        then_schema: The schema used if the input is valid against `if_schema`
+        else_schema: The schema used otherwise
+        ref: See [TODO] for details
+        extra: See [TODO] for details
+        serialization: Custom serialization schema
+    """
+    return dict_not_none(
+        type='conditional',
+        if_schema=if_schema,
+        then_schema=then_schema,
+        else_schema=else_schema,
+        ref=ref,
+        extra=extra,
+        serialization=serialization,
+    )
+
+
 class TypedDictField(TypedDict, total=False):
     schema: Required[CoreSchema]
     required: bool
+    strict: bool
     validation_alias: Union[str, List[Union[str, int]], List[List[Union[str, int]]]]
     serialization_alias: str
-    serialization_exclude: bool  # default: False
+    serialization_exclude: Union[bool, Literal['python', 'json']]  # default: False
     frozen: bool
+    hide_input_in_errors: bool  # default: False
+    deprecated: Union[bool, str]
 
 
 def typed_dict_field(
     schema: CoreSchema,
     *,
     required: bool | None = None,
+    strict: bool | None = None,
     validation_alias: str | list[str | int] | list[list[str | int]] | None = None,
     serialization_alias: str | None = None,
-    serialization_exclude: bool | None = None,
+    serialization_exclude: bool | Literal['python', 'json'] | None = None,
     frozen: bool | None = None,
+    hide_input_in_errors: bool | None = None,
+    deprecated: bool | str | None = None,
 ) -> TypedDictField:
     """
     Returns a schema that matches a typed dict field, e.g.:
@@ -1795,16 +2225,25 @@ def typed_dict_field(
     Args:
         schema: The schema to use for the field
         required: Whether the field is required
+        strict: Whether the field's schema should be validated in strict mode, overriding config
         alias: The alias(es) to use for the field
+        serialization_exclude: Whether to exclude the field when serializing, `'python'` or `'json'` to exclude
+            it only from output in that mode
         frozen: Whether the field is frozen
+        hide_input_in_errors: Whether to omit the field's input value from validation errors
+        deprecated: Whether the field is deprecated, if so a warning is collected when it's used, a string
+            is used as the warning message
     """
     return dict_not_none(
         schema=schema,
         required=required,
+        strict=strict,
         validation_alias=validation_alias,
         serialization_alias=serialization_alias,
         serialization_exclude=serialization_exclude,
         frozen=frozen,
+        hide_input_in_errors=hide_input_in_errors,
+        deprecated=deprecated,
     )
 
 
@@ -1816,10 +2255,19 @@ class TypedDictSchema(TypedDict, total=False):
     return_fields_set: bool
     # all these values can be set via config, equivalent fields have `typed_dict_` prefix
     extra_behavior: Literal['allow', 'forbid', 'ignore']
+    extra_attr: str  # only with extra_behavior='allow'
     total: bool  # default: True
+    serialization_order: Literal['input', 'schema', 'alphabetical']  # default: 'input'
+    key_transform: Literal['camel', 'pascal', 'kebab']
     populate_by_name: bool  # replaces `allow_population_by_field_name` in pydantic v1
+    loc_by_alias: bool
     from_attributes: bool
+    custom_error_type: str
+    custom_error_message: str
+    custom_error_context: Dict[str, Union[str, int, float]]
     ref: str
+    title: str
+    metadata: Any
     extra: Any
     serialization: SerSchema
 
@@ -1831,8 +2279,12 @@ def typed_dict_schema(
     extra_validator: CoreSchema | None = None,
     return_fields_set: bool | None = None,
     extra_behavior: Literal['allow', 'forbid', 'ignore'] | None = None,
+    extra_attr: str | None = None,
     total: bool | None = None,
+    serialization_order: Literal['input', 'schema', 'alphabetical'] | None = None,
+    key_transform: Literal['camel', 'pascal', 'kebab'] | None = None,
     populate_by_name: bool | None = None,
+    loc_by_alias: bool | None = None,
     from_attributes: bool | None = None,
     ref: str | None = None,
     extra: Any = None,
@@ -1858,8 +2310,14 @@ def typed_dict_schema(
         ref: See [TODO] for details
         extra: See [TODO] for details
         extra_behavior: The extra behavior to use for the typed dict
+        extra_attr: With `extra_behavior='allow'`, the key extra inputs are collected under, e.g. a model attribute
         total: Whether the typed dict is total
-        populate_by_name: Whether the typed dict should populate by name
+        serialization_order: The order of fields when serializing: as in the input dict, as declared in the schema,
+            or alphabetical; with the latter two extra items come after fields
+        key_transform: With `by_alias`, the casing field names are converted to when serializing fields without a
+            `serialization_alias`, e.g. `'camel'` outputs `foo_bar` as `fooBar`
+        populate_by_name: Whether the typed dict should populate by name as well as by alias, providing both is an error
+        loc_by_alias: Whether error locations should use field aliases rather than names
         from_attributes: Whether the typed dict should be populated from attributes
     """
     return dict_not_none(
@@ -1869,8 +2327,12 @@ def typed_dict_schema(
         extra_validator=extra_validator,
         return_fields_set=return_fields_set,
         extra_behavior=extra_behavior,
+        extra_attr=extra_attr,
         total=total,
+        serialization_order=serialization_order,
+        key_transform=key_transform,
         populate_by_name=populate_by_name,
+        loc_by_alias=loc_by_alias,
         from_attributes=from_attributes,
         ref=ref,
         extra=extra,
@@ -1884,8 +2346,14 @@ class NewClassSchema(TypedDict, total=False):
     schema: Required[CoreSchema]
     call_after_init: str
     strict: bool
+    root_model: bool
     config: CoreConfig
+    custom_error_type: str
+    custom_error_message: str
+    custom_error_context: Dict[str, Union[str, int, float]]
     ref: str
+    title: str
+    metadata: Any
     extra: Any
     serialization: SerSchema
 
@@ -1896,6 +2364,7 @@ def new_class_schema(
     *,
     call_after_init: str | None = None,
     strict: bool | None = None,
+    root_model: bool | None = None,
     config: CoreConfig | None = None,
     ref: str | None = None,
     extra: Any = None,
@@ -1927,6 +2396,8 @@ def new_class_schema(
         schema: The schema to use for the new class
         call_after_init: The call after init to use for the new class
         strict: Whether the new class is strict
+        root_model: Whether `schema` validates a bare value (e.g. a list) which is stored as the single field
+            `__root__`, serialization then returns that value rather than a dict of fields
         ref: See [TODO] for details
         extra: See [TODO] for details
         config: The config to use for the new class
@@ -1937,6 +2408,86 @@ def new_class_schema(
         schema=schema,
         call_after_init=call_after_init,
         strict=strict,
+        root_model=root_model,
+        config=config,
+        ref=ref,
+        extra=extra,
+        serialization=serialization,
+    )
+
+
+class ModelSchema(TypedDict, total=False):
+    type: Required[Literal['model']]
+    cls: Required[Type[Any]]
+    schema: Required[CoreSchema]
+    post_init: str
+    revalidate_instances: RevalidateInstances  # default: 'never'
+    strict: bool
+    config: CoreConfig
+    custom_error_type: str
+    custom_error_message: str
+    custom_error_context: Dict[str, Union[str, int, float]]
+    ref: str
+    title: str
+    metadata: Any
+    extra: Any
+    serialization: SerSchema
+
+
+def model_schema(
+    cls: Type[Any],
+    schema: CoreSchema,
+    *,
+    post_init: str | None = None,
+    revalidate_instances: RevalidateInstances | None = None,
+    strict: bool | None = None,
+    config: CoreConfig | None = None,
+    ref: str | None = None,
+    extra: Any = None,
+    serialization: SerSchema | None = None,
+) -> ModelSchema:
+    """
+    Returns a schema for a model class, instances are created without calling `__init__`, e.g.:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    class MyModel:
+        __slots__ = '__dict__', '__fields_set__'
+
+    schema = core_schema.model_schema(
+        cls=MyModel,
+        schema=core_schema.typed_dict_schema(
+            fields={'a': core_schema.typed_dict_field(core_schema.string_schema())},
+            return_fields_set=True,
+        ),
+    )
+    v = SchemaValidator(schema)
+    m = v.validate_python({'a': 'hello'})
+    assert m.__dict__ == {'a': 'hello'}
+    assert m.__fields_set__ == {'a'}
+    ```
+
+    Args:
+        cls: The model class, instances are created with `cls.__new__` and have `__dict__` and `__fields_set__` set
+        schema: The schema used to validate the model's fields, usually a typed dict schema
+        post_init: The name of a method called with the validation context after an instance is created
+        revalidate_instances: Whether instances of the model are revalidated, `'subclass-instances'` means only
+            instances of subclasses are revalidated, `'forbid-subclass-instances'` means instances of subclasses
+            are a `model_class_type` error, default `'never'` returns instances as is
+        strict: Whether the model is strict, only instances of the model are then valid
+        config: The config to use for the model
+        ref: See [TODO] for details
+        extra: See [TODO] for details
+        serialization: Custom serialization schema
+    """
+    return dict_not_none(
+        type='model',
+        cls=cls,
+        schema=schema,
+        post_init=post_init,
+        revalidate_instances=revalidate_instances,
+        strict=strict,
         config=config,
         ref=ref,
         extra=extra,
@@ -1979,9 +2530,16 @@ class ArgumentsSchema(TypedDict, total=False):
     type: Required[Literal['arguments']]
     arguments_schema: Required[List[ArgumentsParameter]]
     populate_by_name: bool
+    loc_by_alias: bool
     var_args_schema: CoreSchema
     var_kwargs_schema: CoreSchema
+    var_kwargs_mode: Literal['uniform', 'unpacked-typed-dict']  # default: 'uniform'
+    custom_error_type: str
+    custom_error_message: str
+    custom_error_context: Dict[str, Union[str, int, float]]
     ref: str
+    title: str
+    metadata: Any
     extra: Any
     serialization: SerSchema
 
@@ -1989,8 +2547,10 @@ class ArgumentsSchema(TypedDict, total=False):
 def arguments_schema(
     *arguments: ArgumentsParameter,
     populate_by_name: bool | None = None,
+    loc_by_alias: bool | None = None,
     var_args_schema: CoreSchema | None = None,
     var_kwargs_schema: CoreSchema | None = None,
+    var_kwargs_mode: Literal['uniform', 'unpacked-typed-dict'] | None = None,
     ref: str | None = None,
     extra: Any = None,
     serialization: SerSchema | None = None,
@@ -2010,8 +2570,11 @@ def arguments_schema(
     Args:
         arguments: The arguments to use for the arguments schema
         populate_by_name: Whether to populate by name
+        loc_by_alias: Whether error locations should use parameter aliases rather than names
         var_args_schema: The variable args schema to use for the arguments schema
         var_kwargs_schema: The variable kwargs schema to use for the arguments schema
+        var_kwargs_mode: With `'unpacked-typed-dict'`, `var_kwargs_schema` is a typed dict schema which validates
+            all keyword arguments not matching a parameter together, like `**kwargs: Unpack[TypedDict]`
         ref: See [TODO] for details
         extra: See [TODO] for details
         serialization: Custom serialization schema
@@ -2020,8 +2583,10 @@ def arguments_schema(
         type='arguments',
         arguments_schema=arguments,
         populate_by_name=populate_by_name,
+        loc_by_alias=loc_by_alias,
         var_args_schema=var_args_schema,
         var_kwargs_schema=var_kwargs_schema,
+        var_kwargs_mode=var_kwargs_mode,
         ref=ref,
         extra=extra,
         serialization=serialization,
@@ -2033,7 +2598,12 @@ class CallSchema(TypedDict, total=False):
     arguments_schema: Required[CoreSchema]
     function: Required[Callable[..., Any]]
     return_schema: CoreSchema
+    custom_error_type: str
+    custom_error_message: str
+    custom_error_context: Dict[str, Union[str, int, float]]
     ref: str
+    title: str
+    metadata: Any
     extra: Any
     serialization: SerSchema
 
@@ -2107,6 +2677,65 @@ def recursive_reference_schema(schema_ref: str) -> RecursiveReferenceSchema:
     return {'type': 'recursive-ref', 'schema_ref': schema_ref}
 
 
+class DefinitionsSchema(TypedDict, total=False):
+    type: Required[Literal['definitions']]
+    schema: Required[CoreSchema]
+    definitions: Required[List[CoreSchema]]
+
+
+def definitions_schema(schema: CoreSchema, definitions: List[CoreSchema]) -> DefinitionsSchema:
+    """
+    Returns a schema which validates with `schema`, where `definitions` are schemas which can be referenced by
+    their `ref` from `schema` or from each other using `definition_reference_schema`, e.g.:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+    schema = core_schema.definitions_schema(
+        core_schema.definition_reference_schema('node'),
+        [
+            core_schema.typed_dict_schema(
+                {
+                    'value': core_schema.typed_dict_field(core_schema.int_schema()),
+                    'next': core_schema.typed_dict_field(
+                        core_schema.nullable_schema(core_schema.definition_reference_schema('node'))
+                    ),
+                },
+                ref='node',
+            )
+        ],
+    )
+    v = SchemaValidator(schema)
+    assert v.validate_python({'value': 1, 'next': {'value': 2, 'next': None}}) == {
+        'value': 1,
+        'next': {'value': 2, 'next': None},
+    }
+    ```
+
+    Args:
+        schema: The schema to validate with
+        definitions: Schemas which can be referenced, each must have a unique `ref`
+    """
+    return {'type': 'definitions', 'schema': schema, 'definitions': definitions}
+
+
+class DefinitionReferenceSchema(TypedDict, total=False):
+    type: Required[Literal['definition-ref']]
+    schema_ref: Required[str]
+    ref: str
+
+
+def definition_reference_schema(schema_ref: str, *, ref: str | None = None) -> DefinitionReferenceSchema:
+    """
+    Returns a schema which references one of the definitions of an enclosing `definitions_schema` by its `ref`,
+    the definition is looked up when validating so it may be referenced before it's defined or from within itself.
+
+    Args:
+        schema_ref: The `ref` of the definition to reference
+        ref: See [TODO] for details
+    """
+    return dict_not_none(type='definition-ref', schema_ref=schema_ref, ref=ref)
+
+
 class CustomErrorSchema(TypedDict, total=False):
     type: Required[Literal['custom_error']]
     schema: Required[CoreSchema]
@@ -2114,6 +2743,8 @@ class CustomErrorSchema(TypedDict, total=False):
     custom_error_message: str
     custom_error_context: Dict[str, Union[str, int, float]]
     ref: str
+    title: str
+    metadata: Any
     extra: Any
     serialization: SerSchema
 
@@ -2140,6 +2771,9 @@ def custom_error_schema(
     v.validate_python(1)
     ```
 
+    The `custom_error_type`, `custom_error_message` and `custom_error_context` keys may also be set directly
+    on any other schema, which is equivalent to wrapping that schema with `custom_error_schema`.
+
     Args:
         schema: The schema to use for the custom error schema
         custom_error_type: The custom error type to use for the custom error schema
@@ -2164,7 +2798,12 @@ def custom_error_schema(
 class JsonSchema(TypedDict, total=False):
     type: Required[Literal['json']]
     schema: CoreSchema
+    custom_error_type: str
+    custom_error_message: str
+    custom_error_context: Dict[str, Union[str, int, float]]
     ref: str
+    title: str
+    metadata: Any
     extra: Any
     serialization: SerSchema
 
@@ -2217,8 +2856,16 @@ class UrlSchema(TypedDict, total=False):
     default_host: str
     default_port: int
     default_path: str
+    normalize_host: bool  # default False
+    normalize_percent_encoding: bool  # default False
+    trailing_slash: Literal['keep', 'strip', 'add']  # default 'keep'
     strict: bool
+    custom_error_type: str
+    custom_error_message: str
+    custom_error_context: Dict[str, Union[str, int, float]]
     ref: str
+    title: str
+    metadata: Any
     extra: Any
     serialization: SerSchema
 
@@ -2231,6 +2878,9 @@ def url_schema(
     default_host: str | None = None,
     default_port: int | None = None,
     default_path: str | None = None,
+    normalize_host: bool | None = None,
+    normalize_percent_encoding: bool | None = None,
+    trailing_slash: Literal['keep', 'strip', 'add'] | None = None,
     strict: bool | None = None,
     ref: str | None = None,
     extra: Any = None,
@@ -2254,6 +2904,11 @@ def url_schema(
         default_host: The default host to use if the URL does not have a host
         default_port: The default port to use if the URL does not have a port
         default_path: The default path to use if the URL does not have a path
+        normalize_host: Whether to lowercase and IDNA (punycode) encode the host of URLs with non-special schemes,
+            hosts of URLs with special schemes like `http` are always normalized
+        normalize_percent_encoding: Whether to uppercase percent escapes and decode escaped unreserved characters
+            in the path, query and fragment
+        trailing_slash: Whether to `'keep'`, `'strip'` or `'add'` a trailing slash on the path
         strict: Whether to use strict URL parsing
         ref: See [TODO] for details
         extra: See [TODO] for details
@@ -2267,6 +2922,9 @@ def url_schema(
         default_host=default_host,
         default_port=default_port,
         default_path=default_path,
+        normalize_host=normalize_host,
+        normalize_percent_encoding=normalize_percent_encoding,
+        trailing_slash=trailing_slash,
         strict=strict,
         ref=ref,
         extra=extra,
@@ -2282,8 +2940,16 @@ class MultiHostUrlSchema(TypedDict, total=False):
     default_host: str
     default_port: int
     default_path: str
+    normalize_host: bool  # default False
+    normalize_percent_encoding: bool  # default False
+    trailing_slash: Literal['keep', 'strip', 'add']  # default 'keep'
     strict: bool
+    custom_error_type: str
+    custom_error_message: str
+    custom_error_context: Dict[str, Union[str, int, float]]
     ref: str
+    title: str
+    metadata: Any
     extra: Any
     serialization: SerSchema
 
@@ -2296,6 +2962,9 @@ def multi_host_url_schema(
     default_host: str | None = None,
     default_port: int | None = None,
     default_path: str | None = None,
+    normalize_host: bool | None = None,
+    normalize_percent_encoding: bool | None = None,
+    trailing_slash: Literal['keep', 'strip', 'add'] | None = None,
     strict: bool | None = None,
     ref: str | None = None,
     extra: Any = None,
@@ -2319,6 +2988,11 @@ def multi_host_url_schema(
         default_host: The default host to use if the URL does not have a host
         default_port: The default port to use if the URL does not have a port
         default_path: The default path to use if the URL does not have a path
+        normalize_host: Whether to lowercase and IDNA (punycode) encode the host of URLs with non-special schemes,
+            hosts of URLs with special schemes like `http` are always normalized
+        normalize_percent_encoding: Whether to uppercase percent escapes and decode escaped unreserved characters
+            in the path, query and fragment
+        trailing_slash: Whether to `'keep'`, `'strip'` or `'add'` a trailing slash on the path
         strict: Whether to use strict URL parsing
         ref: See [TODO] for details
         extra: See [TODO] for details
@@ -2332,6 +3006,9 @@ def multi_host_url_schema(
         default_host=default_host,
         default_port=default_port,
         default_path=default_path,
+        normalize_host=normalize_host,
+        normalize_percent_encoding=normalize_percent_encoding,
+        trailing_slash=trailing_slash,
         strict=strict,
         ref=ref,
         extra=extra,
@@ -2339,6 +3016,10 @@ def multi_host_url_schema(
     )
 
 
+# most schemas may also have:
+# * `title`, used in place of the validator's name, e.g. as the title of `ValidationError`s and to locate errors
+#   from the choices of a union
+# * `metadata`, which is ignored by pydantic-core but kept on the schema for use by other tools
 CoreSchema = Union[
     AnySchema,
     NoneSchema,
@@ -2371,11 +3052,15 @@ CoreSchema = Union[
     TaggedUnionSchema,
     ChainSchema,
     LaxOrStrictSchema,
+    ConditionalSchema,
     TypedDictSchema,
     NewClassSchema,
+    ModelSchema,
     ArgumentsSchema,
     CallSchema,
     RecursiveReferenceSchema,
+    DefinitionsSchema,
+    DefinitionReferenceSchema,
     CustomErrorSchema,
     JsonSchema,
     UrlSchema,
@@ -2388,14 +3073,17 @@ ErrorType = Literal[
     'json_invalid',
     'json_type',
     'recursion_loop',
+    'too_many_errors',
     'dict_attributes_type',
     'missing',
-    'frozen',
+    'frozen_field',
     'extra_forbidden',
+    'alias_and_name_provided',
     'invalid_key',
     'get_attribute_error',
     'model_class_type',
     'none_required',
+    'none_not_allowed',
     'bool',
     'greater_than',
     'greater_than_equal',
@@ -2418,11 +3106,12 @@ ErrorType = Literal[
     'list_type',
     'tuple_type',
     'set_type',
+    'set_duplicate_item',
     'bool_type',
     'bool_parsing',
     'int_type',
     'int_parsing',
-    'int_from_float',
+    'int_from_float_inexact',
     'float_type',
     'float_parsing',
     'bytes_type',
@@ -2439,6 +3128,8 @@ ErrorType = Literal[
     'date_future',
     'time_type',
     'time_parsing',
+    'time_aware',
+    'time_naive',
     'datetime_type',
     'datetime_parsing',
     'datetime_object_invalid',
@@ -2454,6 +3145,7 @@ ErrorType = Literal[
     'callable_type',
     'union_tag_invalid',
     'union_tag_not_found',
+    'union_no_match',
     'arguments_type',
     'positional_arguments_type',
     'keyword_arguments_type',
@@ -2467,4 +3159,14 @@ ErrorType = Literal[
     'url_syntax_violation',
     'url_too_long',
     'url_scheme',
+    'msgpack_invalid',
+    'msgpack_type',
+    'cbor_invalid',
+    'cbor_type',
+    'toml_invalid',
+    'toml_type',
+    'yaml_invalid',
+    'yaml_type',
+    'urlencoded_invalid',
+    'urlencoded_type',
 ]
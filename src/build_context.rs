@@ -1,10 +1,15 @@
-use pyo3::intern;
+use std::sync::Arc;
+
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyList};
+use pyo3::types::{PyBool, PyDict, PyFloat, PyList, PyLong, PyString, PyTuple};
+use pyo3::{intern, AsPyPointer, PyTypeInfo};
 
-use ahash::AHashSet;
+use ahash::{AHashMap, AHashSet};
+use indexmap::IndexMap;
 
-use crate::build_tools::{py_err, py_error_type, SchemaDict};
+use crate::build_tools::{py_err, py_error_type, SchemaDict, SchemaError};
+use crate::errors::LocItem;
+use crate::profile::{ProfileCounter, Profiler};
 use crate::questions::Answers;
 use crate::serializers::CombinedSerializer;
 use crate::validators::{CombinedValidator, Validator};
@@ -17,12 +22,22 @@ struct Slot<T> {
 }
 
 /// `BuildContext` is used to store extra information while building validators and type_serializers,
-/// currently it just holds a vec "slots" which holds validators/type_serializers which need to be accessed from
+/// mostly it holds a vec "slots" which holds validators/type_serializers which need to be accessed from
 /// multiple other validators/type_serializers and therefore can't be owned by them directly.
+///
+/// Schemas which appear more than once are also built once into a slot and shared, see `find_shared`.
 #[derive(Clone)]
 pub struct BuildContext<T> {
     used_refs: AHashSet<String>,
     slots: Vec<Slot<T>>,
+    // structural keys of schemas which are repeated, by the address of the schema dict
+    shared_keys: AHashMap<usize, String>,
+    // references to the slots of repeated schemas which have been built, by structural key
+    shared: AHashMap<String, T>,
+    // the address of the innermost schema which failed to build, and its error message
+    error_schema: Option<(usize, String)>,
+    // counters for typed dict fields, only set when the `profile` config key is used
+    profiler: Option<Profiler>,
 }
 
 impl<T: Clone> BuildContext<T> {
@@ -30,15 +45,27 @@ impl<T: Clone> BuildContext<T> {
         Self {
             used_refs,
             slots: Vec::new(),
+            shared_keys: AHashMap::new(),
+            shared: AHashMap::new(),
+            error_schema: None,
+            profiler: None,
         }
     }
 
     pub fn for_schema(schema: &PyAny) -> PyResult<Self> {
         let mut used_refs = AHashSet::new();
         extract_used_refs(schema, &mut used_refs)?;
+        let mut shared_keys = AHashMap::new();
+        let mut key_counts = AHashMap::new();
+        structural_key(schema, &mut shared_keys, &mut key_counts)?;
+        shared_keys.retain(|_, key| key_counts[key] > 1);
         Ok(Self {
             used_refs,
             slots: Vec::new(),
+            shared_keys,
+            shared: AHashMap::new(),
+            error_schema: None,
+            profiler: None,
         })
     }
 
@@ -49,12 +76,30 @@ impl<T: Clone> BuildContext<T> {
         used_refs.insert("root-schema".to_string());
         used_refs.insert("ser-schema".to_string());
         used_refs.insert("inc-ex-type".to_string());
-        Self {
-            used_refs,
-            slots: Vec::new(),
+        Self::new(used_refs)
+    }
+
+    /// count calls and time taken for each typed dict field built from now on, see `Profiler`
+    pub fn enable_profiling(&mut self) {
+        self.profiler = Some(Profiler::default());
+    }
+
+    /// the counter for a typed dict field which is about to be built, `None` unless profiling is enabled,
+    /// `profile_end_field` must be called once the field's validator is built
+    pub fn profile_start_field(&mut self, field_name: &str) -> Option<Arc<ProfileCounter>> {
+        self.profiler.as_mut().map(|profiler| profiler.start_field(field_name))
+    }
+
+    pub fn profile_end_field(&mut self) {
+        if let Some(ref mut profiler) = self.profiler {
+            profiler.end_field();
         }
     }
 
+    pub fn take_profiler(&mut self) -> Option<Profiler> {
+        self.profiler.take()
+    }
+
     /// check if a ref is used elsewhere in the schema
     pub fn ref_used(&self, ref_: &str) -> bool {
         self.used_refs.contains(ref_)
@@ -89,6 +134,59 @@ impl<T: Clone> BuildContext<T> {
         }
     }
 
+    /// Prepare a slot for each definition of a `definitions` schema before any of them are built, so definitions
+    /// can reference each other in any order. Returns the slot id for each definition along with a copy of the
+    /// definition without its `ref`, which is what should be built to complete the slot.
+    ///
+    /// A definition which is only a reference to another definition is built from that definition, so the same
+    /// value isn't passed through two references (and caught by the recursion guard) when it's used.
+    pub fn prepare_definitions<'py>(&mut self, definitions: &'py PyList) -> PyResult<Vec<(usize, &'py PyDict)>> {
+        let py = definitions.py();
+        let ref_key = intern!(py, "ref");
+        let mut schemas: IndexMap<String, &PyDict> = IndexMap::with_capacity(definitions.len());
+        // definitions which are just a reference to another schema
+        let mut aliases: IndexMap<String, String> = IndexMap::new();
+        for definition in definitions.iter() {
+            let definition: &PyDict = definition.cast_as()?;
+            let definition_ref: String = match definition.get_as(ref_key)? {
+                Some(definition_ref) => definition_ref,
+                None => return py_err!("Definitions error: definitions must have a `ref`"),
+            };
+            if schemas.contains_key(&definition_ref) {
+                return py_err!("Definitions error: duplicate definition `{}`", definition_ref);
+            }
+            if let Some("definition-ref" | "recursive-ref") = definition.get_as(intern!(py, "type"))? {
+                aliases.insert(
+                    definition_ref.clone(),
+                    definition.get_as_req(intern!(py, "schema_ref"))?,
+                );
+            }
+            let definition = definition.copy()?;
+            definition.del_item(ref_key)?;
+            schemas.insert(definition_ref, definition);
+        }
+        check_reference_cycles(&aliases)?;
+
+        let mut prepared = Vec::with_capacity(schemas.len());
+        for (definition_ref, schema) in &schemas {
+            // follow references to other definitions, a reference to a schema outside these definitions is kept
+            let mut schema = *schema;
+            let mut target = definition_ref;
+            while let Some(next) = aliases.get(target) {
+                match schemas.get(next) {
+                    Some(next_schema) => {
+                        schema = next_schema;
+                        target = next;
+                    }
+                    None => break,
+                }
+            }
+            let slot_id = self.prepare_slot(definition_ref.clone(), Some(Answers::new(schema)?))?;
+            prepared.push((slot_id, schema));
+        }
+        Ok(prepared)
+    }
+
     /// find a slot by `slot_ref` - iterate over the slots until we find a matching reference - return the index
     pub fn find_slot_id_answer(&self, slot_ref: &str) -> PyResult<(usize, Option<Answers>)> {
         let is_match = |slot: &Slot<T>| slot.slot_ref == slot_ref;
@@ -101,6 +199,61 @@ impl<T: Clone> BuildContext<T> {
         }
     }
 
+    /// The structural key of `schema` if an identical schema appears elsewhere, so the validator/serializer
+    /// can be built once and shared. Wrappers can build the schemas inside them with different config
+    /// (see `inner_config`) so the key includes the identity of `config`.
+    pub fn shared_key(&self, schema: &PyDict, config: Option<&PyDict>) -> Option<String> {
+        let key = self.shared_keys.get(&(schema.as_ptr() as usize))?;
+        let config_id = config.map_or(0, |c| c.as_ptr() as usize);
+        Some(format!("{key}:{config_id}"))
+    }
+
+    /// Find a reference to the validator/serializer already built from a schema with this key.
+    pub fn find_shared(&self, key: &str) -> Option<T> {
+        self.shared.get(key).cloned()
+    }
+
+    /// Move a validator/serializer built from a repeated schema into a new slot, `ref_from_id` creates the
+    /// reference to that slot which is used wherever the schema appears.
+    pub fn add_shared(&mut self, key: String, val_ser: T, ref_from_id: impl FnOnce(usize, &T) -> T) -> T {
+        let slot_id = self.slots.len();
+        let reference = ref_from_id(slot_id, &val_ser);
+        self.slots.push(Slot {
+            slot_ref: String::new(),
+            op_val_ser: Some(val_ser),
+            answers: None,
+        });
+        self.shared.insert(key, reference.clone());
+        reference
+    }
+
+    /// Record the result of building `schema`, errors are raised through every enclosing schema so only the
+    /// first (innermost) schema to fail is kept, a successful build means any earlier error was handled.
+    pub fn record_build<R>(&mut self, schema: &PyDict, result: &PyResult<R>) {
+        match result {
+            Ok(_) => self.error_schema = None,
+            Err(err) => {
+                if self.error_schema.is_none() {
+                    let message = SchemaError::message(schema.py(), err);
+                    self.error_schema = Some((schema.as_ptr() as usize, message));
+                }
+            }
+        }
+    }
+
+    /// Add the path from `schema` to the schema which failed to build to a `SchemaError`
+    pub fn locate_error(&self, err: PyErr, schema: &PyAny) -> PyErr {
+        let mut location = Vec::new();
+        let message = match self.error_schema {
+            Some((error_schema, ref message)) => {
+                find_location(schema, error_schema, &mut location);
+                Some(message.clone())
+            }
+            None => None,
+        };
+        SchemaError::with_location(schema.py(), err, location, message)
+    }
+
     /// find a validator/serializer by `slot_id` - this used in `Validator.complete`,
     /// specifically `RecursiveRefValidator` to set its name
     pub fn find_validator(&self, slot_id: usize) -> PyResult<&T> {
@@ -145,10 +298,160 @@ impl BuildContext<CombinedSerializer> {
     }
 }
 
+/// a definition which is only a reference to another definition (or to itself) can never be resolved
+fn check_reference_cycles(aliases: &IndexMap<String, String>) -> PyResult<()> {
+    for start in aliases.keys() {
+        let mut path = vec![start];
+        while let Some(target) = aliases.get(*path.last().unwrap()) {
+            let cycle = path.contains(&target);
+            path.push(target);
+            if cycle {
+                let path: Vec<String> = path.iter().map(|r| format!("`{r}`")).collect();
+                return py_err!("Definitions error: circular reference {}", path.join(" -> "));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Build a key for `value` such that values with the same key are the same schema, the key of every schema
+/// (with more than just a `type`) is recorded in `keys` by its address and counted in `counts`.
+///
+/// Keys are built bottom up so each value is only visited once, strings, numbers, booleans and `None` are keyed
+/// by value and other objects (functions, classes etc.) by identity.
+/// Schemas containing references aren't keyed, `None` is returned for them as sharing them would change how
+/// the references are resolved.
+fn structural_key(
+    value: &PyAny,
+    keys: &mut AHashMap<usize, String>,
+    counts: &mut AHashMap<String, usize>,
+) -> PyResult<Option<String>> {
+    let key = if PyString::is_exact_type_of(value) {
+        let s: &str = value.extract()?;
+        format!("s{}:{}", s.len(), s)
+    } else if PyBool::is_exact_type_of(value) {
+        format!("b{}", value.is_true()?)
+    } else if PyLong::is_exact_type_of(value) || PyFloat::is_exact_type_of(value) {
+        format!("n{}", value.repr()?)
+    } else if value.is_none() {
+        "N".to_string()
+    } else if let Ok(dict) = value.cast_as::<PyDict>() {
+        let py = value.py();
+        let type_: Option<&str> = match dict.get_item(intern!(py, "type")) {
+            Some(t) => t.extract().ok(),
+            None => None,
+        };
+        let mut key = "{".to_string();
+        let mut shareable = !matches!(type_, Some("recursive-ref" | "definition-ref" | "definitions"))
+            && !dict.contains(intern!(py, "ref"))?;
+        for (k, v) in dict.iter() {
+            // keep going after finding a reference so the keys of sub-schemas are still recorded
+            match (structural_key(k, keys, counts)?, structural_key(v, keys, counts)?) {
+                (Some(k_key), Some(v_key)) => {
+                    key.push_str(&k_key);
+                    key.push_str(&v_key);
+                }
+                _ => shareable = false,
+            }
+        }
+        if !shareable {
+            return Ok(None);
+        }
+        key.push('}');
+        if type_.is_some() && dict.len() > 1 {
+            keys.insert(dict.as_ptr() as usize, key.clone());
+            *counts.entry(key.clone()).or_default() += 1;
+        }
+        key
+    } else if PyList::is_exact_type_of(value) || PyTuple::is_exact_type_of(value) {
+        let mut key = match PyList::is_exact_type_of(value) {
+            true => "[".to_string(),
+            false => "(".to_string(),
+        };
+        let mut shareable = true;
+        for item in value.iter()? {
+            match structural_key(item?, keys, counts)? {
+                Some(item_key) => {
+                    key.push_str(&item_key);
+                    key.push(',');
+                }
+                None => shareable = false,
+            }
+        }
+        if !shareable {
+            return Ok(None);
+        }
+        key.push(']');
+        key
+    } else {
+        format!("o{:x}", value.as_ptr() as usize)
+    };
+    Ok(Some(key))
+}
+
+/// find the path to the object at address `target` within `value`, `location` is left empty if it's not found
+fn find_location(value: &PyAny, target: usize, location: &mut Vec<LocItem>) -> bool {
+    if value.as_ptr() as usize == target {
+        return true;
+    }
+    if let Ok(dict) = value.cast_as::<PyDict>() {
+        for (key, item) in dict.iter() {
+            if let Ok(key) = key.extract::<String>() {
+                location.push(key.into());
+                if find_location(item, target, location) {
+                    return true;
+                }
+                location.pop();
+            }
+        }
+    } else if let Ok(list) = value.cast_as::<PyList>() {
+        for (index, item) in list.iter().enumerate() {
+            location.push(index.into());
+            if find_location(item, target, location) {
+                return true;
+            }
+            location.pop();
+        }
+    }
+    false
+}
+
+/// whether a schema defines or uses references, they have to be built into slots of the outer validator,
+/// so the schema can't be built on its own, see `LazyValidator`
+pub fn schema_uses_refs(schema: &PyAny) -> PyResult<bool> {
+    if let Ok(dict) = schema.cast_as::<PyDict>() {
+        let py = schema.py();
+        if dict.contains(intern!(py, "ref"))? {
+            return Ok(true);
+        }
+        if matches!(
+            dict.get_as(intern!(py, "type")),
+            Ok(Some("recursive-ref" | "definition-ref" | "definitions"))
+        ) {
+            return Ok(true);
+        }
+        for (_, value) in dict.iter() {
+            if schema_uses_refs(value)? {
+                return Ok(true);
+            }
+        }
+    } else if let Ok(list) = schema.cast_as::<PyList>() {
+        for item in list.iter() {
+            if schema_uses_refs(item)? {
+                return Ok(true);
+            }
+        }
+    }
+    Ok(false)
+}
+
 fn extract_used_refs(schema: &PyAny, refs: &mut AHashSet<String>) -> PyResult<()> {
     if let Ok(dict) = schema.cast_as::<PyDict>() {
         let py = schema.py();
-        if matches!(dict.get_as(intern!(py, "type")), Ok(Some("recursive-ref"))) {
+        if matches!(
+            dict.get_as(intern!(py, "type")),
+            Ok(Some("recursive-ref" | "definition-ref"))
+        ) {
             refs.insert(dict.get_as_req(intern!(py, "schema_ref"))?);
         } else {
             for (_, value) in dict.iter() {
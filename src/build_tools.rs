@@ -4,10 +4,10 @@ use std::fmt;
 
 use pyo3::exceptions::{PyException, PyKeyError};
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyString};
+use pyo3::types::{PyDict, PyList, PyString, PyTuple};
 use pyo3::{intern, FromPyObject, PyErrArguments};
 
-use crate::errors::{pretty_line_errors, ValError};
+use crate::errors::{pretty_py_line_errors, py_err_string, LocItem, PyLineError, ValError};
 
 pub trait SchemaDict<'py> {
     fn get_as<T>(&'py self, key: &PyString) -> PyResult<Option<T>>
@@ -98,11 +98,37 @@ pub fn is_strict(schema: &PyDict, config: Option<&PyDict>) -> PyResult<bool> {
     Ok(schema_or_config_same(schema, config, intern!(py, "strict"))?.unwrap_or(false))
 }
 
-// we could perhaps do clever things here to store each schema error, or have different types for the top
-// level error group, and other errors, we could perhaps also support error groups!?
+/// Config for the schemas inside a wrapper (or field), if the wrapper sets `strict` it overrides `strict` from
+/// config for everything it wraps, in either direction. Inner schemas which set `strict` themselves still win.
+pub fn inner_config<'py>(schema: &'py PyDict, config: Option<&'py PyDict>) -> PyResult<Option<&'py PyDict>> {
+    let py = schema.py();
+    let strict = match schema.get_item(intern!(py, "strict")) {
+        Some(strict) => strict,
+        None => return Ok(config),
+    };
+    let inner_config = match config {
+        Some(config) => config.copy()?,
+        None => PyDict::new(py),
+    };
+    inner_config.set_item(intern!(py, "strict"), strict)?;
+    Ok(Some(inner_config))
+}
+
 #[pyclass(extends=PyException, module="pydantic_core._pydantic_core")]
 pub struct SchemaError {
     message: String,
+    details: SchemaErrorDetails,
+}
+
+/// what's returned by `SchemaError.errors()`
+#[derive(Clone)]
+enum SchemaErrorDetails {
+    // e.g. raised from python
+    None,
+    // the schema failed validation against the self schema
+    Validation(Vec<PyLineError>),
+    // building a validator or serializer failed, `location` is the path to the schema which failed
+    Build { location: Vec<LocItem>, message: String },
 }
 
 impl fmt::Debug for SchemaError {
@@ -135,20 +161,84 @@ impl SchemaError {
     pub fn from_val_error(py: Python, error: ValError) -> PyErr {
         match error {
             ValError::LineErrors(line_errors) => {
-                let details = pretty_line_errors(py, line_errors);
-                SchemaError::new_err(format!("Invalid Schema:\n{details}"))
+                let line_errors: Vec<PyLineError> = line_errors.into_iter().map(|e| e.into_py(py)).collect();
+                let details = pretty_py_line_errors(py, line_errors.iter());
+                let err = SchemaError::new_err(format!("Invalid Schema:\n{details}"));
+                Self::set_details(py, &err, SchemaErrorDetails::Validation(line_errors));
+                err
             }
             ValError::InternalErr(py_err) => py_err,
             ValError::Omit => unreachable!(),
         }
     }
+
+    /// the message of a `SchemaError`, or the type and message of other errors
+    pub fn message(py: Python, err: &PyErr) -> String {
+        match err.value(py).cast_as::<PyCell<SchemaError>>() {
+            Ok(cell) => cell.borrow().message.clone(),
+            Err(_) => py_err_string(py, err.clone_ref(py)),
+        }
+    }
+
+    /// Add the location of the schema which failed to build to a `SchemaError`, other errors are returned as is.
+    /// `inner_message` is the error from the schema which failed, before it was wrapped by enclosing schemas.
+    pub fn with_location(py: Python, err: PyErr, location: Vec<LocItem>, inner_message: Option<String>) -> PyErr {
+        let message = match err.value(py).cast_as::<PyCell<SchemaError>>() {
+            Ok(cell) => cell.borrow().message.clone(),
+            Err(_) => return err,
+        };
+        let err = match location.is_empty() {
+            true => err,
+            false => {
+                let loc_str: Vec<String> = location.iter().map(|i| i.to_string()).collect();
+                SchemaError::new_err(format!(
+                    "Error building schema at {}:\n  {}",
+                    loc_str.join(" -> "),
+                    message
+                ))
+            }
+        };
+        let message = inner_message.unwrap_or(message);
+        Self::set_details(py, &err, SchemaErrorDetails::Build { location, message });
+        err
+    }
+
+    fn set_details(py: Python, err: &PyErr, details: SchemaErrorDetails) {
+        if let Ok(cell) = err.value(py).cast_as::<PyCell<SchemaError>>() {
+            cell.borrow_mut().details = details;
+        }
+    }
 }
 
 #[pymethods]
 impl SchemaError {
     #[new]
     fn py_new(message: String) -> Self {
-        Self { message }
+        Self {
+            message,
+            details: SchemaErrorDetails::None,
+        }
+    }
+
+    fn errors(&self, py: Python) -> PyResult<Py<PyList>> {
+        let errors = match self.details {
+            SchemaErrorDetails::None => Vec::new(),
+            SchemaErrorDetails::Validation(ref line_errors) => line_errors
+                .iter()
+                .map(|e| e.as_dict(py, true, true, false, None, false))
+                .collect::<PyResult<_>>()?,
+            SchemaErrorDetails::Build {
+                ref location,
+                ref message,
+            } => {
+                let dict = PyDict::new(py);
+                dict.set_item("type", "schema_build_error")?;
+                dict.set_item("loc", PyTuple::new(py, location))?;
+                dict.set_item("msg", message)?;
+                vec![dict.into_py(py)]
+            }
+        };
+        Ok(PyList::new(py, errors).into_py(py))
     }
 
     fn __repr__(&self) -> String {
@@ -0,0 +1,81 @@
+use ahash::AHashMap;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::build_tools::SchemaDict;
+use crate::input::Input;
+
+use super::line_error::{ValError, ValLineError};
+use super::types::ErrorType;
+
+/// Limits applied to the line errors of a `ValidationError` before it's raised, set via the `max_errors`
+/// and `group_errors` config keys.
+#[derive(Debug, Clone, Default)]
+pub struct ErrorLimits {
+    pub max_errors: Option<usize>,
+    group_errors: bool,
+}
+
+impl ErrorLimits {
+    pub fn from_config(config: Option<&PyDict>) -> PyResult<Self> {
+        match config {
+            Some(config) => {
+                let py = config.py();
+                Ok(Self {
+                    max_errors: config.get_as(intern!(py, "max_errors"))?,
+                    group_errors: config.get_as(intern!(py, "group_errors"))?.unwrap_or(false),
+                })
+            }
+            None => Ok(Self::default()),
+        }
+    }
+
+    pub fn apply<'a>(&self, py: Python, error: ValError<'a>, input: &'a impl Input<'a>) -> PyResult<ValError<'a>> {
+        match error {
+            ValError::LineErrors(mut line_errors) => {
+                if self.group_errors {
+                    line_errors = group_line_errors(py, line_errors)?;
+                }
+                if let Some(max_errors) = self.max_errors {
+                    if line_errors.len() > max_errors {
+                        line_errors.truncate(max_errors);
+                        line_errors.push(ValLineError::new(ErrorType::TooManyErrors { max_errors }, input));
+                    }
+                }
+                Ok(ValError::LineErrors(line_errors))
+            }
+            other => Ok(other),
+        }
+    }
+}
+
+/// Errors with the same type and message whose locations only differ by sequence indices are merged
+/// into the first of them, with indices in the location replaced by `"*"`.
+fn group_line_errors<'a>(py: Python, line_errors: Vec<ValLineError<'a>>) -> PyResult<Vec<ValLineError<'a>>> {
+    let mut grouped: Vec<(ValLineError<'a>, usize)> = Vec::with_capacity(line_errors.len());
+    let mut lookup: AHashMap<(String, String, String), usize> = AHashMap::with_capacity(line_errors.len());
+    for line_error in line_errors {
+        let key = (
+            line_error.error_type.type_string(),
+            line_error.error_type.render_message(py)?,
+            line_error.location.with_wildcard_indices().to_string(),
+        );
+        match lookup.get(&key) {
+            Some(index) => grouped[*index].1 += 1,
+            None => {
+                lookup.insert(key, grouped.len());
+                grouped.push((line_error, 1));
+            }
+        }
+    }
+    Ok(grouped
+        .into_iter()
+        .map(|(mut line_error, count)| {
+            if count > 1 {
+                line_error.location = line_error.location.with_wildcard_indices();
+            }
+            line_error
+        })
+        .collect())
+}
@@ -2,11 +2,10 @@ use pyo3::exceptions::PyTypeError;
 use pyo3::prelude::*;
 use pyo3::PyDowncastError;
 
-use crate::input::{Input, JsonInput};
+use crate::input::{Input, JsonInput, StringInput};
 
 use super::location::{LocItem, Location};
 use super::types::ErrorType;
-use super::validation_exception::{pretty_py_line_errors, PyLineError};
 
 pub type ValResult<'a, T> = Result<T, ValError<'a>>;
 
@@ -52,8 +51,12 @@ impl<'a> ValError<'a> {
     pub fn with_outer_location(self, loc_item: LocItem) -> Self {
         match self {
             Self::LineErrors(mut line_errors) => {
-                for line_error in line_errors.iter_mut() {
-                    line_error.location.with_outer(loc_item.clone());
+                // the last error takes `loc_item` itself, so the common case of a single error doesn't clone it
+                if let Some((last, rest)) = line_errors.split_last_mut() {
+                    for line_error in rest {
+                        line_error.location.with_outer(loc_item.clone());
+                    }
+                    last.location.with_outer(loc_item);
                 }
                 Self::LineErrors(line_errors)
             }
@@ -61,6 +64,16 @@ impl<'a> ValError<'a> {
         }
     }
 
+    /// drop the input value from all line errors so it can't appear in the resulting `ValidationError`
+    pub fn hide_input(self) -> Self {
+        match self {
+            Self::LineErrors(line_errors) => {
+                Self::LineErrors(line_errors.into_iter().map(ValLineError::hide_input).collect())
+            }
+            other => other,
+        }
+    }
+
     /// a bit like clone but change the lifetime to match py
     pub fn duplicate<'py>(&self, py: Python<'py>) -> ValError<'py> {
         match self {
@@ -71,11 +84,6 @@ impl<'a> ValError<'a> {
     }
 }
 
-pub fn pretty_line_errors(py: Python, line_errors: Vec<ValLineError>) -> String {
-    let py_line_errors: Vec<PyLineError> = line_errors.into_iter().map(|e| e.into_py(py)).collect();
-    pretty_py_line_errors(py, py_line_errors.iter())
-}
-
 /// A `ValLineError` is a single error that occurred during validation which is converted to a `PyLineError`
 /// to eventually form a `ValidationError`.
 /// I don't like the name `ValLineError`, but it's the best I could come up with (for now).
@@ -85,6 +93,8 @@ pub struct ValLineError<'a> {
     // location is reversed so that adding an "outer" location item is pushing, it's reversed before showing to the user
     pub location: Location,
     pub input_value: InputValue<'a>,
+    // the exception raised by a validator function, used as the `__cause__` of the `ValidationError`
+    pub cause: Option<PyObject>,
 }
 
 impl<'a> ValLineError<'a> {
@@ -93,6 +103,7 @@ impl<'a> ValLineError<'a> {
             error_type,
             input_value: input.as_error_value(),
             location: Location::default(),
+            cause: None,
         }
     }
 
@@ -101,6 +112,7 @@ impl<'a> ValLineError<'a> {
             error_type,
             input_value: input.as_error_value(),
             location: Location::new_some(loc.into()),
+            cause: None,
         }
     }
 
@@ -109,6 +121,7 @@ impl<'a> ValLineError<'a> {
             error_type,
             input_value,
             location: Location::default(),
+            cause: None,
         }
     }
 
@@ -125,12 +138,37 @@ impl<'a> ValLineError<'a> {
         self
     }
 
+    /// add several "outer" items at once, `loc_items` are in their natural (outermost first) order
+    pub fn with_outer_location_items(mut self, loc_items: &[LocItem]) -> Self {
+        for loc_item in loc_items.iter().rev() {
+            self.location.with_outer(loc_item.clone());
+        }
+        self
+    }
+
+    /// record the exception which caused this error, see `validation_error_cause`
+    pub fn with_cause(mut self, cause: PyObject) -> Self {
+        self.cause = Some(cause);
+        self
+    }
+
+    /// drop the input value, used for `hide_input_in_errors`
+    pub fn hide_input(mut self) -> Self {
+        self.input_value = InputValue::Hidden;
+        self
+    }
+
     /// a bit like clone but change the lifetime to match py, used by ValError.duplicate above
     pub fn duplicate<'py>(&'a self, py: Python<'py>) -> ValLineError<'py> {
+        let input_value = match self.input_value {
+            InputValue::Hidden => InputValue::Hidden,
+            ref input_value => InputValue::<'py>::from(input_value.to_object(py)),
+        };
         ValLineError {
             error_type: self.error_type.clone(),
-            input_value: InputValue::<'py>::from(self.input_value.to_object(py)),
+            input_value,
             location: self.location.clone(),
+            cause: self.cause.as_ref().map(|cause| cause.clone_ref(py)),
         }
     }
 }
@@ -138,9 +176,12 @@ impl<'a> ValLineError<'a> {
 #[cfg_attr(debug_assertions, derive(Debug))]
 pub enum InputValue<'a> {
     PyAny(&'a PyAny),
-    JsonInput(&'a JsonInput),
+    JsonInput(&'a JsonInput<'a>),
+    StringInput(&'a StringInput),
     String(&'a str),
     PyObject(PyObject),
+    /// the input was dropped when the error was created because `hide_input_in_errors` is set
+    Hidden,
 }
 
 impl<'a> From<PyObject> for InputValue<'a> {
@@ -154,8 +195,10 @@ impl<'a> ToPyObject for InputValue<'a> {
         match self {
             Self::PyAny(input) => input.into_py(py),
             Self::JsonInput(input) => input.to_object(py),
+            Self::StringInput(input) => input.to_object(py),
             Self::String(input) => input.into_py(py),
             Self::PyObject(py_obj) => py_obj.into_py(py),
+            Self::Hidden => py.None(),
         }
     }
 }
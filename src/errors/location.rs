@@ -3,11 +3,13 @@ use std::fmt;
 
 use pyo3::prelude::*;
 use pyo3::types::PyTuple;
+use serde::ser::SerializeSeq;
+use serde::{Serialize, Serializer};
+use smallvec::SmallVec;
 
 /// Used to store individual items of the error location, e.g. a string for key/field names
 /// or a number for array indices.
-#[derive(Clone)]
-#[cfg_attr(debug_assertions, derive(Debug))]
+#[derive(Clone, Debug)]
 pub enum LocItem {
     /// string type key, used to identify items from a dict or anything that implements `__getitem__`
     S(String),
@@ -51,6 +53,15 @@ impl ToPyObject for LocItem {
     }
 }
 
+impl Serialize for LocItem {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::S(s) => serializer.serialize_str(s),
+            Self::I(i) => serializer.serialize_u64(*i as u64),
+        }
+    }
+}
+
 impl TryFrom<&PyAny> for LocItem {
     type Error = PyErr;
 
@@ -64,20 +75,22 @@ impl TryFrom<&PyAny> for LocItem {
     }
 }
 
+/// Items of a `Location`, locations up to this depth are stored inline without allocating.
+pub type LocList = SmallVec<[LocItem; 3]>;
+
 /// Error locations are represented by a vector of `LocItem`s.
 /// e.g. if the error occurred in the third member of a list called `foo`,
 /// the location would be `["foo", 2]`.
 /// Note: location in List is stored in **REVERSE** so adding an "outer" item to location involves
 /// pushing to the vec which is faster than inserting and shifting everything along.
 /// Then when "using" location in `Display` and `ToPyObject` order has to be reversed
-#[derive(Clone)]
-#[cfg_attr(debug_assertions, derive(Debug))]
+#[derive(Clone, Debug)]
 pub enum Location {
     // no location, avoid creating an unnecessary vec
     Empty,
     // store the in a vec of LocItems, Note: this is the REVERSE of location, see above
-    // we could perhaps use a smallvec or similar here, probably only worth it if we store a Cow in LocItem
-    List(Vec<LocItem>),
+    // most locations are shallow, so they're kept inline to avoid a heap allocation per error
+    List(LocList),
 }
 
 impl Default for Location {
@@ -99,6 +112,21 @@ impl ToPyObject for Location {
     }
 }
 
+impl Serialize for Location {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::List(loc) => {
+                let mut seq = serializer.serialize_seq(Some(loc.len()))?;
+                for item in loc.iter().rev() {
+                    seq.serialize_element(item)?;
+                }
+                seq.end()
+            }
+            Self::Empty => serializer.serialize_seq(Some(0))?.end(),
+        }
+    }
+}
+
 impl fmt::Display for Location {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -112,13 +140,37 @@ impl fmt::Display for Location {
 }
 
 impl Location {
-    /// create a new location vec with a value, 3 is plucked out of thin air, should it just be 1?
+    /// create a new location with a single value, stored inline
     pub fn new_some(item: LocItem) -> Self {
-        let mut loc = Vec::with_capacity(3);
+        let mut loc = LocList::new();
         loc.push(item);
         Self::List(loc)
     }
 
+    /// a copy of the location with all integer indices replaced by `"*"`, used to group errors
+    /// from different members of a sequence
+    pub fn with_wildcard_indices(&self) -> Self {
+        match self {
+            Self::List(loc) => Self::List(
+                loc.iter()
+                    .map(|item| match item {
+                        LocItem::I(_) => LocItem::S("*".to_string()),
+                        other => other.clone(),
+                    })
+                    .collect(),
+            ),
+            Self::Empty => Self::Empty,
+        }
+    }
+
+    /// the number of items in the location, i.e. how deep into the input the error is
+    pub fn depth(&self) -> usize {
+        match self {
+            Self::List(loc) => loc.len(),
+            Self::Empty => 0,
+        }
+    }
+
     pub fn with_outer(&mut self, loc_item: LocItem) {
         match self {
             Self::List(ref mut loc) => loc.push(loc_item),
@@ -1,16 +1,20 @@
 use pyo3::prelude::*;
 
+mod limits;
 mod line_error;
 mod location;
 mod types;
 mod validation_exception;
 mod value_exception;
+mod warnings;
 
-pub use self::line_error::{pretty_line_errors, InputValue, ValError, ValLineError, ValResult};
+pub use self::limits::ErrorLimits;
+pub use self::line_error::{InputValue, ValError, ValLineError, ValResult};
 pub use self::location::LocItem;
-pub use self::types::{list_all_errors, ErrorType};
-pub use self::validation_exception::ValidationError;
+pub use self::types::{list_all_errors, ErrorType, JsonErrorPosition};
+pub use self::validation_exception::{pretty_py_line_errors, PyLineError, ValidationError};
 pub use self::value_exception::{PydanticCustomError, PydanticKnownError, PydanticOmit, PydanticSerializationError};
+pub use self::warnings::ValidationWarnings;
 
 pub fn py_err_string(py: Python, err: PyErr) -> String {
     let value = err.value(py);
@@ -2,17 +2,31 @@ use std::borrow::Cow;
 use std::fmt;
 
 use ahash::AHashMap;
+use num_bigint::BigInt;
 use pyo3::exceptions::{PyKeyError, PyTypeError};
 use pyo3::once_cell::GILOnceCell;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyList};
+use pyo3::types::{PyDict, PyList, PyLong};
 
 use crate::build_tools::{py_err, py_error_type};
+use crate::get_version;
+use crate::input::EitherInt;
 use strum::{Display, EnumMessage, IntoEnumIterator};
 use strum_macros::EnumIter;
 
 use super::PydanticCustomError;
 
+/// Errors are documented per minor version, e.g. `https://errors.pydantic.dev/0.7/v/int_parsing`.
+static URL_PREFIX: GILOnceCell<String> = GILOnceCell::new();
+
+fn documentation_url_prefix(py: Python) -> &'static str {
+    URL_PREFIX.get_or_init(py, || {
+        let version = get_version();
+        let minor_version = version.splitn(3, '.').take(2).collect::<Vec<_>>().join(".");
+        format!("https://errors.pydantic.dev/{minor_version}/v/")
+    })
+}
+
 #[pyfunction]
 pub fn list_all_errors(py: Python) -> PyResult<&PyList> {
     let mut errors: Vec<&PyDict> = Vec::with_capacity(100);
@@ -20,9 +34,16 @@ pub fn list_all_errors(py: Python) -> PyResult<&PyList> {
         if !matches!(error_type, ErrorType::CustomError { .. }) {
             let d = PyDict::new(py);
             d.set_item("type", error_type.to_string())?;
+            d.set_item("error_code", error_type.error_code())?;
             d.set_item("message_template", error_type.message_template())?;
             d.set_item("example_message", error_type.render_message(py)?)?;
-            d.set_item("example_context", error_type.py_dict(py)?)?;
+            let example_context = error_type.py_dict(py)?;
+            let context_fields: Vec<&PyAny> = match example_context {
+                Some(ref context) => context.as_ref(py).keys().iter().collect(),
+                None => Vec::new(),
+            };
+            d.set_item("context_fields", context_fields)?;
+            d.set_item("example_context", example_context)?;
             errors.push(d);
         }
     }
@@ -40,23 +61,32 @@ pub enum ErrorType {
     #[strum(message = "Invalid JSON: {error}")]
     JsonInvalid {
         error: String,
+        position: Option<JsonErrorPosition>,
     },
-    #[strum(message = "JSON input should be string, bytes or bytearray")]
+    #[strum(message = "JSON input should be string, bytes, bytearray or memoryview")]
     JsonType,
     // ---------------------
     // recursion error
     #[strum(message = "Recursion error - cyclic reference detected")]
     RecursionLoop,
     // ---------------------
+    // error limits
+    #[strum(message = "Too many errors, only the first {max_errors} errors are shown")]
+    TooManyErrors {
+        max_errors: usize,
+    },
+    // ---------------------
     // typed dict specific errors
     #[strum(message = "Input should be a valid dictionary or instance to extract fields from")]
     DictAttributesType,
     #[strum(message = "Field required")]
     Missing,
     #[strum(message = "Field is frozen")]
-    Frozen,
+    FrozenField,
     #[strum(message = "Extra inputs are not permitted")]
     ExtraForbidden,
+    #[strum(message = "Field provided by both its alias and its name")]
+    AliasAndNameProvided,
     #[strum(message = "Keys should be strings")]
     InvalidKey,
     #[strum(message = "Error extracting attribute: {error}")]
@@ -73,6 +103,8 @@ pub enum ErrorType {
     // None errors
     #[strum(message = "Input should be None/null")]
     NoneRequired,
+    #[strum(message = "Input should not be None/null")]
+    NoneNotAllowed,
     // boolean errors
     #[strum(message = "Input should be a valid boolean")]
     Bool,
@@ -137,11 +169,15 @@ pub enum ErrorType {
     StringUnicode,
     #[strum(message = "String should have at least {min_length} characters")]
     StringTooShort {
+        field_type: String,
         min_length: usize,
+        actual_length: usize,
     },
     #[strum(message = "String should have at most {max_length} characters")]
     StringTooLong {
+        field_type: String,
         max_length: usize,
+        actual_length: usize,
     },
     #[strum(message = "String should match pattern '{pattern}'")]
     StringPatternMismatch {
@@ -167,6 +203,8 @@ pub enum ErrorType {
     // set errors
     #[strum(message = "Input should be a valid set")]
     SetType,
+    #[strum(message = "Input should not contain duplicate items")]
+    SetDuplicateItem,
     // ---------------------
     // bool errors
     #[strum(message = "Input should be a valid boolean")]
@@ -180,7 +218,7 @@ pub enum ErrorType {
     #[strum(message = "Input should be a valid integer, unable to parse string as an integer")]
     IntParsing,
     #[strum(message = "Input should be a valid integer, got a number with a fractional part")]
-    IntFromFloat,
+    IntFromFloatInexact,
     // ---------------------
     // float errors
     #[strum(message = "Input should be a valid number")]
@@ -193,11 +231,15 @@ pub enum ErrorType {
     BytesType,
     #[strum(message = "Data should have at least {min_length} bytes")]
     BytesTooShort {
+        field_type: String,
         min_length: usize,
+        actual_length: usize,
     },
     #[strum(message = "Data should have at most {max_length} bytes")]
     BytesTooLong {
+        field_type: String,
         max_length: usize,
+        actual_length: usize,
     },
     // ---------------------
     // python errors from functions
@@ -245,6 +287,10 @@ pub enum ErrorType {
     TimeParsing {
         error: Cow<'static, str>,
     },
+    #[strum(message = "Time should have timezone info")]
+    TimeAware,
+    #[strum(message = "Time should not have timezone info")]
+    TimeNaive,
     // ---------------------
     // datetime errors
     #[strum(message = "Input should be a valid datetime")]
@@ -303,6 +349,10 @@ pub enum ErrorType {
     UnionTagNotFound {
         discriminator: String,
     },
+    #[strum(message = "Input does not match any union member, expected {expected}")]
+    UnionNoMatch {
+        expected: String,
+    },
     // ---------------------
     // argument errors
     #[strum(message = "Arguments must be a tuple, list or a dictionary")]
@@ -342,6 +392,44 @@ pub enum ErrorType {
     UrlScheme {
         expected_schemes: String,
     },
+    // ---------------------
+    // MessagePack errors
+    #[strum(message = "Invalid MessagePack: {error}")]
+    MsgpackInvalid {
+        error: String,
+    },
+    #[strum(message = "MessagePack input should be bytes, bytearray or memoryview")]
+    MsgpackType,
+    // ---------------------
+    // CBOR errors
+    #[strum(message = "Invalid CBOR: {error}")]
+    CborInvalid {
+        error: String,
+    },
+    #[strum(message = "CBOR input should be bytes, bytearray or memoryview")]
+    CborType,
+    // ---------------------
+    // TOML and YAML errors
+    #[strum(message = "Invalid TOML: {error}")]
+    TomlInvalid {
+        error: String,
+    },
+    #[strum(message = "TOML input should be a string")]
+    TomlType,
+    #[strum(message = "Invalid YAML: {error}")]
+    YamlInvalid {
+        error: String,
+    },
+    #[strum(message = "YAML input should be a string")]
+    YamlType,
+    // ---------------------
+    // URL encoded form data errors
+    #[strum(message = "Invalid URL encoded data: {error}")]
+    UrlencodedInvalid {
+        error: String,
+    },
+    #[strum(message = "URL encoded input should be string, bytes, bytearray or memoryview")]
+    UrlencodedType,
 }
 
 macro_rules! render {
@@ -414,6 +502,34 @@ fn plural_s(value: &usize) -> &'static str {
     }
 }
 
+/// Where the JSON parser failed, `line` and `column` are 1-based as reported by `serde_json`, `offset` is the
+/// 0-based index of the byte in the input.
+#[derive(Clone, Debug)]
+pub struct JsonErrorPosition {
+    pub line: usize,
+    pub column: usize,
+    pub offset: usize,
+}
+
+/// `json_invalid` errors may be created from python with or without a position, so `extract_context!` isn't used
+fn json_invalid_from_context(ctx: Option<&PyDict>) -> PyResult<ErrorType> {
+    let ctx = match ctx {
+        Some(ctx) => ctx,
+        None => return py_err!(PyTypeError; "JsonInvalid requires context: {{error: String}}"),
+    };
+    let error = ctx
+        .get_item("error")
+        .ok_or(py_error_type!(PyTypeError; "JsonInvalid: 'error' required in context"))?
+        .extract::<String>()
+        .map_err(|_| py_error_type!(PyTypeError; "JsonInvalid: 'error' context value must be a String"))?;
+    let get_usize = |key: &str| -> PyResult<Option<usize>> { ctx.get_item(key).map(|v| v.extract()).transpose() };
+    let position = match (get_usize("line")?, get_usize("column")?, get_usize("offset")?) {
+        (Some(line), Some(column), Some(offset)) => Some(JsonErrorPosition { line, column, offset }),
+        _ => None,
+    };
+    Ok(ErrorType::JsonInvalid { error, position })
+}
+
 static ERROR_TYPE_LOOKUP: GILOnceCell<AHashMap<String, ErrorType>> = GILOnceCell::new();
 
 impl ErrorType {
@@ -426,9 +542,10 @@ impl ErrorType {
             None => return py_err!(PyKeyError; "Invalid error type: '{}'", value),
         };
         match error_type {
-            Self::JsonInvalid { .. } => extract_context!(JsonInvalid, ctx, error: String),
+            Self::JsonInvalid { .. } => json_invalid_from_context(ctx),
             Self::GetAttributeError { .. } => extract_context!(GetAttributeError, ctx, error: String),
             Self::ModelClassType { .. } => extract_context!(ModelClassType, ctx, class_name: String),
+            Self::TooManyErrors { .. } => extract_context!(TooManyErrors, ctx, max_errors: usize),
             Self::GreaterThan { .. } => extract_context!(GreaterThan, ctx, gt: Number),
             Self::GreaterThanEqual { .. } => extract_context!(GreaterThanEqual, ctx, ge: Number),
             Self::LessThan { .. } => extract_context!(LessThan, ctx, lt: Number),
@@ -449,12 +566,36 @@ impl ErrorType {
                 actual_length: usize
             ),
             Self::IterationError { .. } => extract_context!(IterationError, ctx, error: String),
-            Self::StringTooShort { .. } => extract_context!(StringTooShort, ctx, min_length: usize),
-            Self::StringTooLong { .. } => extract_context!(StringTooLong, ctx, max_length: usize),
+            Self::StringTooShort { .. } => extract_context!(
+                StringTooShort,
+                ctx,
+                field_type: String,
+                min_length: usize,
+                actual_length: usize
+            ),
+            Self::StringTooLong { .. } => extract_context!(
+                StringTooLong,
+                ctx,
+                field_type: String,
+                max_length: usize,
+                actual_length: usize
+            ),
             Self::StringPatternMismatch { .. } => extract_context!(StringPatternMismatch, ctx, pattern: String),
             Self::MappingType { .. } => extract_context!(Cow::Owned, MappingType, ctx, error: String),
-            Self::BytesTooShort { .. } => extract_context!(BytesTooShort, ctx, min_length: usize),
-            Self::BytesTooLong { .. } => extract_context!(BytesTooLong, ctx, max_length: usize),
+            Self::BytesTooShort { .. } => extract_context!(
+                BytesTooShort,
+                ctx,
+                field_type: String,
+                min_length: usize,
+                actual_length: usize
+            ),
+            Self::BytesTooLong { .. } => extract_context!(
+                BytesTooLong,
+                ctx,
+                field_type: String,
+                max_length: usize,
+                actual_length: usize
+            ),
             Self::ValueError { .. } => extract_context!(ValueError, ctx, error: String),
             Self::AssertionError { .. } => extract_context!(AssertionError, ctx, error: String),
             Self::LiteralError { .. } => extract_context!(LiteralError, ctx, expected: String),
@@ -474,10 +615,16 @@ impl ErrorType {
                 expected_tags: String
             ),
             Self::UnionTagNotFound { .. } => extract_context!(UnionTagNotFound, ctx, discriminator: String),
+            Self::UnionNoMatch { .. } => extract_context!(UnionNoMatch, ctx, expected: String),
             Self::UrlParsing { .. } => extract_context!(UrlParsing, ctx, error: String),
             Self::UrlSyntaxViolation { .. } => extract_context!(Cow::Owned, UrlSyntaxViolation, ctx, error: String),
             Self::UrlTooLong { .. } => extract_context!(UrlTooLong, ctx, max_length: usize),
             Self::UrlScheme { .. } => extract_context!(UrlScheme, ctx, expected_schemes: String),
+            Self::MsgpackInvalid { .. } => extract_context!(MsgpackInvalid, ctx, error: String),
+            Self::CborInvalid { .. } => extract_context!(CborInvalid, ctx, error: String),
+            Self::TomlInvalid { .. } => extract_context!(TomlInvalid, ctx, error: String),
+            Self::YamlInvalid { .. } => extract_context!(YamlInvalid, ctx, error: String),
+            Self::UrlencodedInvalid { .. } => extract_context!(UrlencodedInvalid, ctx, error: String),
             _ => {
                 if ctx.is_some() {
                     py_err!(PyTypeError; "'{}' errors do not require context", value)
@@ -514,9 +661,125 @@ impl ErrorType {
         }
     }
 
+    /// Link to the documentation for this error, custom errors have no documentation so return `None`.
+    pub fn documentation_url(&self, py: Python) -> Option<String> {
+        match self {
+            Self::CustomError { .. } => None,
+            _ => Some(format!("{}{}", documentation_url_prefix(py), self)),
+        }
+    }
+
+    /// A numeric code for each error type which is stable across versions, so clients can rely on codes rather
+    /// than messages. Codes must never be changed or reused, new error types take the next unused code.
+    /// Custom errors have no code.
+    pub fn error_code(&self) -> Option<u16> {
+        match self {
+            Self::JsonInvalid { .. } => Some(1),
+            Self::JsonType { .. } => Some(2),
+            Self::RecursionLoop { .. } => Some(3),
+            Self::TooManyErrors { .. } => Some(4),
+            Self::DictAttributesType { .. } => Some(5),
+            Self::Missing { .. } => Some(6),
+            Self::FrozenField { .. } => Some(7),
+            Self::ExtraForbidden { .. } => Some(8),
+            Self::InvalidKey { .. } => Some(9),
+            Self::GetAttributeError { .. } => Some(10),
+            Self::ModelClassType { .. } => Some(11),
+            Self::NoneRequired { .. } => Some(12),
+            Self::Bool { .. } => Some(13),
+            Self::GreaterThan { .. } => Some(14),
+            Self::GreaterThanEqual { .. } => Some(15),
+            Self::LessThan { .. } => Some(16),
+            Self::LessThanEqual { .. } => Some(17),
+            Self::MultipleOf { .. } => Some(18),
+            Self::FiniteNumber { .. } => Some(19),
+            Self::TooShort { .. } => Some(20),
+            Self::TooLong { .. } => Some(21),
+            Self::IterableType { .. } => Some(22),
+            Self::IterationError { .. } => Some(23),
+            Self::StringType { .. } => Some(24),
+            Self::StringSubType { .. } => Some(25),
+            Self::StringUnicode { .. } => Some(26),
+            Self::StringTooShort { .. } => Some(27),
+            Self::StringTooLong { .. } => Some(28),
+            Self::StringPatternMismatch { .. } => Some(29),
+            Self::DictType { .. } => Some(30),
+            Self::MappingType { .. } => Some(31),
+            Self::ListType { .. } => Some(32),
+            Self::TupleType { .. } => Some(33),
+            Self::SetType { .. } => Some(34),
+            Self::BoolType { .. } => Some(35),
+            Self::BoolParsing { .. } => Some(36),
+            Self::IntType { .. } => Some(37),
+            Self::IntParsing { .. } => Some(38),
+            Self::IntFromFloatInexact { .. } => Some(39),
+            Self::FloatType { .. } => Some(40),
+            Self::FloatParsing { .. } => Some(41),
+            Self::BytesType { .. } => Some(42),
+            Self::BytesTooShort { .. } => Some(43),
+            Self::BytesTooLong { .. } => Some(44),
+            Self::ValueError { .. } => Some(45),
+            Self::AssertionError { .. } => Some(46),
+            Self::CustomError { .. } => None,
+            Self::LiteralError { .. } => Some(47),
+            Self::DateType { .. } => Some(48),
+            Self::DateParsing { .. } => Some(49),
+            Self::DateFromDatetimeParsing { .. } => Some(50),
+            Self::DateFromDatetimeInexact { .. } => Some(51),
+            Self::DatePast { .. } => Some(52),
+            Self::DateFuture { .. } => Some(53),
+            Self::TimeType { .. } => Some(54),
+            Self::TimeParsing { .. } => Some(55),
+            Self::TimeAware { .. } => Some(56),
+            Self::TimeNaive { .. } => Some(57),
+            Self::DatetimeType { .. } => Some(58),
+            Self::DatetimeParsing { .. } => Some(59),
+            Self::DatetimeObjectInvalid { .. } => Some(60),
+            Self::DatetimePast { .. } => Some(61),
+            Self::DatetimeFuture { .. } => Some(62),
+            Self::DatetimeAware { .. } => Some(63),
+            Self::DatetimeNaive { .. } => Some(64),
+            Self::TimeDeltaType { .. } => Some(65),
+            Self::TimeDeltaParsing { .. } => Some(66),
+            Self::FrozenSetType { .. } => Some(67),
+            Self::IsInstanceOf { .. } => Some(68),
+            Self::IsSubclassOf { .. } => Some(69),
+            Self::CallableType { .. } => Some(70),
+            Self::UnionTagInvalid { .. } => Some(71),
+            Self::UnionTagNotFound { .. } => Some(72),
+            Self::ArgumentsType { .. } => Some(73),
+            Self::PositionalArgumentsType { .. } => Some(74),
+            Self::KeywordArgumentsType { .. } => Some(75),
+            Self::UnexpectedKeywordArgument { .. } => Some(76),
+            Self::MissingKeywordArgument { .. } => Some(77),
+            Self::UnexpectedPositionalArgument { .. } => Some(78),
+            Self::MissingPositionalArgument { .. } => Some(79),
+            Self::MultipleArgumentValues { .. } => Some(80),
+            Self::UrlType { .. } => Some(81),
+            Self::UrlParsing { .. } => Some(82),
+            Self::UrlSyntaxViolation { .. } => Some(83),
+            Self::UrlTooLong { .. } => Some(84),
+            Self::UrlScheme { .. } => Some(85),
+            Self::MsgpackInvalid { .. } => Some(86),
+            Self::MsgpackType { .. } => Some(87),
+            Self::CborInvalid { .. } => Some(88),
+            Self::CborType { .. } => Some(89),
+            Self::TomlInvalid { .. } => Some(90),
+            Self::TomlType { .. } => Some(91),
+            Self::YamlInvalid { .. } => Some(92),
+            Self::YamlType { .. } => Some(93),
+            Self::UrlencodedInvalid { .. } => Some(94),
+            Self::UrlencodedType { .. } => Some(95),
+            Self::AliasAndNameProvided { .. } => Some(96),
+            Self::UnionNoMatch { .. } => Some(97),
+            Self::NoneNotAllowed { .. } => Some(98),
+            Self::SetDuplicateItem { .. } => Some(99),
+        }
+    }
+
     pub fn render_message(&self, py: Python) -> PyResult<String> {
         match self {
-            Self::JsonInvalid { error } => render!(self, error),
+            Self::JsonInvalid { error, .. } => render!(self, error),
             Self::GetAttributeError { error } => render!(self, error),
             Self::ModelClassType { class_name } => render!(self, class_name),
             Self::GreaterThan { gt } => to_string_render!(self, gt),
@@ -541,12 +804,13 @@ impl ErrorType {
                 to_string_render!(self, field_type, max_length, actual_length, expected_plural)
             }
             Self::IterationError { error } => render!(self, error),
-            Self::StringTooShort { min_length } => to_string_render!(self, min_length),
-            Self::StringTooLong { max_length } => to_string_render!(self, max_length),
+            Self::StringTooShort { min_length, .. } => to_string_render!(self, min_length),
+            Self::StringTooLong { max_length, .. } => to_string_render!(self, max_length),
+            Self::TooManyErrors { max_errors } => to_string_render!(self, max_errors),
             Self::StringPatternMismatch { pattern } => render!(self, pattern),
             Self::MappingType { error } => render!(self, error),
-            Self::BytesTooShort { min_length } => to_string_render!(self, min_length),
-            Self::BytesTooLong { max_length } => to_string_render!(self, max_length),
+            Self::BytesTooShort { min_length, .. } => to_string_render!(self, min_length),
+            Self::BytesTooLong { max_length, .. } => to_string_render!(self, max_length),
             Self::ValueError { error } => render!(self, error),
             Self::AssertionError { error } => render!(self, error),
             Self::CustomError { value_error } => value_error.message(py),
@@ -565,17 +829,27 @@ impl ErrorType {
                 expected_tags,
             } => render!(self, discriminator, tag, expected_tags),
             Self::UnionTagNotFound { discriminator } => render!(self, discriminator),
+            Self::UnionNoMatch { expected } => render!(self, expected),
             Self::UrlParsing { error } => render!(self, error),
             Self::UrlSyntaxViolation { error } => render!(self, error),
             Self::UrlTooLong { max_length } => to_string_render!(self, max_length),
             Self::UrlScheme { expected_schemes } => render!(self, expected_schemes),
+            Self::MsgpackInvalid { error } => render!(self, error),
+            Self::CborInvalid { error } => render!(self, error),
+            Self::TomlInvalid { error } => render!(self, error),
+            Self::YamlInvalid { error } => render!(self, error),
+            Self::UrlencodedInvalid { error } => render!(self, error),
             _ => Ok(self.message_template().to_string()),
         }
     }
 
     pub fn py_dict(&self, py: Python) -> PyResult<Option<Py<PyDict>>> {
         match self {
-            Self::JsonInvalid { error } => py_dict!(py, error),
+            Self::JsonInvalid { error, position: None } => py_dict!(py, error),
+            Self::JsonInvalid {
+                error,
+                position: Some(JsonErrorPosition { line, column, offset }),
+            } => py_dict!(py, error, line, column, offset),
             Self::GetAttributeError { error } => py_dict!(py, error),
             Self::ModelClassType { class_name } => py_dict!(py, class_name),
             Self::GreaterThan { gt } => py_dict!(py, gt),
@@ -594,12 +868,29 @@ impl ErrorType {
                 actual_length,
             } => py_dict!(py, field_type, max_length, actual_length),
             Self::IterationError { error } => py_dict!(py, error),
-            Self::StringTooShort { min_length } => py_dict!(py, min_length),
-            Self::StringTooLong { max_length } => py_dict!(py, max_length),
+            Self::StringTooShort {
+                field_type,
+                min_length,
+                actual_length,
+            } => py_dict!(py, field_type, min_length, actual_length),
+            Self::StringTooLong {
+                field_type,
+                max_length,
+                actual_length,
+            } => py_dict!(py, field_type, max_length, actual_length),
+            Self::TooManyErrors { max_errors } => py_dict!(py, max_errors),
             Self::StringPatternMismatch { pattern } => py_dict!(py, pattern),
             Self::MappingType { error } => py_dict!(py, error),
-            Self::BytesTooShort { min_length } => py_dict!(py, min_length),
-            Self::BytesTooLong { max_length } => py_dict!(py, max_length),
+            Self::BytesTooShort {
+                field_type,
+                min_length,
+                actual_length,
+            } => py_dict!(py, field_type, min_length, actual_length),
+            Self::BytesTooLong {
+                field_type,
+                max_length,
+                actual_length,
+            } => py_dict!(py, field_type, max_length, actual_length),
             Self::ValueError { error } => py_dict!(py, error),
             Self::AssertionError { error } => py_dict!(py, error),
             Self::CustomError { value_error } => Ok(value_error.context(py)),
@@ -618,10 +909,16 @@ impl ErrorType {
                 expected_tags,
             } => py_dict!(py, discriminator, tag, expected_tags),
             Self::UnionTagNotFound { discriminator } => py_dict!(py, discriminator),
+            Self::UnionNoMatch { expected } => py_dict!(py, expected),
             Self::UrlParsing { error } => py_dict!(py, error),
             Self::UrlSyntaxViolation { error } => py_dict!(py, error),
             Self::UrlTooLong { max_length } => py_dict!(py, max_length),
             Self::UrlScheme { expected_schemes } => py_dict!(py, expected_schemes),
+            Self::MsgpackInvalid { error } => py_dict!(py, error),
+            Self::CborInvalid { error } => py_dict!(py, error),
+            Self::TomlInvalid { error } => py_dict!(py, error),
+            Self::YamlInvalid { error } => py_dict!(py, error),
+            Self::UrlencodedInvalid { error } => py_dict!(py, error),
             _ => Ok(None),
         }
     }
@@ -630,6 +927,7 @@ impl ErrorType {
 #[derive(Clone, Debug)]
 pub enum Number {
     Int(i64),
+    BigInt(BigInt),
     Float(f64),
     String(String),
 }
@@ -652,6 +950,15 @@ impl From<f64> for Number {
     }
 }
 
+impl From<EitherInt> for Number {
+    fn from(i: EitherInt) -> Self {
+        match i {
+            EitherInt::I64(i) => Self::Int(i),
+            EitherInt::BigInt(b) => Self::BigInt(b),
+        }
+    }
+}
+
 impl From<String> for Number {
     fn from(s: String) -> Self {
         Self::String(s)
@@ -662,6 +969,8 @@ impl FromPyObject<'_> for Number {
     fn extract(obj: &PyAny) -> PyResult<Self> {
         if let Ok(int) = obj.extract::<i64>() {
             Ok(Number::Int(int))
+        } else if let Some(big_int) = obj.cast_as::<PyLong>().ok().and_then(|i| i.extract::<BigInt>().ok()) {
+            Ok(Number::BigInt(big_int))
         } else if let Ok(float) = obj.extract::<f64>() {
             Ok(Number::Float(float))
         } else if let Ok(string) = obj.extract::<String>() {
@@ -677,6 +986,7 @@ impl fmt::Display for Number {
         match self {
             Self::Float(s) => write!(f, "{s}"),
             Self::Int(i) => write!(f, "{i}"),
+            Self::BigInt(b) => write!(f, "{b}"),
             Self::String(s) => write!(f, "{s}"),
         }
     }
@@ -685,6 +995,7 @@ impl ToPyObject for Number {
     fn to_object(&self, py: Python<'_>) -> PyObject {
         match self {
             Self::Int(i) => i.into_py(py),
+            Self::BigInt(b) => b.to_object(py),
             Self::Float(f) => f.into_py(py),
             Self::String(s) => s.into_py(py),
         }
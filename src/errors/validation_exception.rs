@@ -7,10 +7,14 @@ use pyo3::ffi;
 use pyo3::ffi::Py_ssize_t;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList};
+use serde::ser::{Error, SerializeMap, SerializeSeq};
+use serde::{Serialize, Serializer};
+use serde_json::ser::PrettyFormatter;
 
 use crate::build_tools::{py_error_type, safe_repr};
+use crate::serializers::{Extra, SerMode, SerializationConfig, SerializeInfer};
 
-use super::line_error::ValLineError;
+use super::line_error::{InputValue, ValLineError};
 use super::location::Location;
 use super::types::ErrorType;
 use super::ValError;
@@ -34,7 +38,16 @@ impl ValidationError {
                         .collect(),
                     None => raw_errors.into_iter().map(|e| e.into_py(py)).collect(),
                 };
-                PyErr::new::<ValidationError, _>((line_errors, title))
+                let causes: Vec<&PyObject> = line_errors.iter().filter_map(|e| e.cause.as_ref()).collect();
+                let cause = match causes.is_empty() {
+                    true => None,
+                    false => exception_group(py, causes),
+                };
+                let err = PyErr::new::<ValidationError, _>((line_errors, title));
+                if cause.is_some() {
+                    err.set_cause(py, cause);
+                }
+                err
             }
             ValError::InternalErr(err) => err,
             ValError::Omit => Self::omit_error(),
@@ -54,6 +67,21 @@ impl ValidationError {
     }
 }
 
+/// Group the exceptions raised by validator functions into an `ExceptionGroup`, this requires python 3.11 or the
+/// `exceptiongroup` backport, without either no cause is set.
+fn exception_group(py: Python, causes: Vec<&PyObject>) -> Option<PyErr> {
+    let exception_group_cls = match py.import("builtins").and_then(|m| m.getattr("ExceptionGroup")) {
+        Ok(cls) => cls,
+        Err(_) => py
+            .import("exceptiongroup")
+            .and_then(|m| m.getattr("ExceptionGroup"))
+            .ok()?,
+    };
+    let message = format!("{} error(s) raised in validator functions", causes.len());
+    let group = exception_group_cls.call1((message, causes)).ok()?;
+    Some(PyErr::from_value(group))
+}
+
 // used to convert a validation error back to ValError for wrap functions
 impl<'a> IntoPy<ValError<'a>> for ValidationError {
     fn into_py(self, py: Python) -> ValError<'a> {
@@ -81,7 +109,19 @@ impl ValidationError {
         self.line_errors.len()
     }
 
-    fn errors(&self, py: Python, include_context: Option<bool>) -> PyResult<Py<PyList>> {
+    fn errors(
+        &self,
+        py: Python,
+        include_context: Option<bool>,
+        include_input: Option<bool>,
+        include_url: Option<bool>,
+        translator: Option<&PyAny>,
+        include_code: Option<bool>,
+    ) -> PyResult<Py<PyList>> {
+        let include_context = include_context.unwrap_or(true);
+        let include_input = include_input.unwrap_or(true);
+        let include_url = include_url.unwrap_or(false);
+        let include_code = include_code.unwrap_or(false);
         // taken approximately from the pyo3, but modified to return the error during iteration
         // https://github.com/PyO3/pyo3/blob/a3edbf4fcd595f0e234c87d4705eb600a9779130/src/types/list.rs#L27-L55
         unsafe {
@@ -93,7 +133,14 @@ impl ValidationError {
             let list: Py<PyList> = Py::from_owned_ptr(py, ptr);
 
             for (index, line_error) in (0_isize..).zip(&self.line_errors) {
-                let item = line_error.as_dict(py, include_context)?;
+                let item = line_error.as_dict(
+                    py,
+                    include_context,
+                    include_input,
+                    include_url,
+                    translator,
+                    include_code,
+                )?;
                 ffi::PyList_SET_ITEM(ptr, index, item.into_ptr());
             }
 
@@ -101,6 +148,49 @@ impl ValidationError {
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
+    fn json(
+        &self,
+        py: Python,
+        indent: Option<usize>,
+        include_context: Option<bool>,
+        include_input: Option<bool>,
+        include_url: Option<bool>,
+        translator: Option<&PyAny>,
+        include_code: Option<bool>,
+    ) -> PyResult<String> {
+        let config = SerializationConfig::from_config(None)?;
+        let extra = Extra::new(py, &SerMode::Json, &[], None, None, None, None, None, &config);
+        let serializer = ValidationErrorSerializer {
+            py,
+            line_errors: &self.line_errors,
+            extra: &extra,
+            include_context: include_context.unwrap_or(true),
+            include_input: include_input.unwrap_or(true),
+            include_url: include_url.unwrap_or(false),
+            translator,
+            include_code: include_code.unwrap_or(false),
+        };
+
+        let writer: Vec<u8> = Vec::with_capacity(self.line_errors.len() * 200);
+        let bytes = match indent {
+            Some(indent) => {
+                let indent = vec![b' '; indent];
+                let formatter = PrettyFormatter::with_indent(&indent);
+                let mut ser = serde_json::Serializer::with_formatter(writer, formatter);
+                serializer.serialize(&mut ser).map_err(json_py_err)?;
+                ser.into_inner()
+            }
+            None => {
+                let mut ser = serde_json::Serializer::new(writer);
+                serializer.serialize(&mut ser).map_err(json_py_err)?;
+                ser.into_inner()
+            }
+        };
+        // the serializer only ever writes valid utf-8
+        Ok(String::from_utf8(bytes).unwrap())
+    }
+
     fn __repr__(&self, py: Python) -> String {
         self.display(py)
     }
@@ -140,7 +230,9 @@ pub fn pretty_py_line_errors<'a>(py: Python, line_errors_iter: impl Iterator<Ite
 pub struct PyLineError {
     error_type: ErrorType,
     location: Location,
-    input_value: PyObject,
+    // `None` if the input was hidden via `hide_input_in_errors`
+    input_value: Option<PyObject>,
+    cause: Option<PyObject>,
 }
 
 impl<'a> IntoPy<PyLineError> for ValLineError<'a> {
@@ -148,7 +240,11 @@ impl<'a> IntoPy<PyLineError> for ValLineError<'a> {
         PyLineError {
             error_type: self.error_type,
             location: self.location,
-            input_value: self.input_value.to_object(py),
+            input_value: match self.input_value {
+                InputValue::Hidden => None,
+                input_value => Some(input_value.to_object(py)),
+            },
+            cause: self.cause,
         }
     }
 }
@@ -159,26 +255,65 @@ impl<'a> IntoPy<ValLineError<'a>> for PyLineError {
         ValLineError {
             error_type: self.error_type,
             location: self.location,
-            input_value: self.input_value.into(),
+            input_value: match self.input_value {
+                Some(input_value) => input_value.into(),
+                None => InputValue::Hidden,
+            },
+            cause: self.cause,
         }
     }
 }
 
 impl PyLineError {
-    pub fn as_dict(&self, py: Python, include_context: Option<bool>) -> PyResult<PyObject> {
+    pub fn as_dict(
+        &self,
+        py: Python,
+        include_context: bool,
+        include_input: bool,
+        include_url: bool,
+        translator: Option<&PyAny>,
+        include_code: bool,
+    ) -> PyResult<PyObject> {
         let dict = PyDict::new(py);
         dict.set_item("type", self.error_type.type_string())?;
+        if include_code {
+            if let Some(code) = self.error_type.error_code() {
+                dict.set_item("code", code)?;
+            }
+        }
         dict.set_item("loc", self.location.to_object(py))?;
-        dict.set_item("msg", self.error_type.render_message(py)?)?;
-        dict.set_item("input", &self.input_value)?;
-        if include_context.unwrap_or(true) {
+        dict.set_item("msg", self.message(py, translator)?)?;
+        if include_input {
+            if let Some(ref input_value) = self.input_value {
+                dict.set_item("input", input_value)?;
+            }
+        }
+        if include_context {
             if let Some(context) = self.error_type.py_dict(py)? {
                 dict.set_item("ctx", context)?;
             }
         }
+        if include_url {
+            if let Some(url) = self.error_type.documentation_url(py) {
+                dict.set_item("url", url)?;
+            }
+        }
         Ok(dict.into_py(py))
     }
 
+    /// The error message, if a translator is given it's called with the error type and context and may return
+    /// a localized message, or `None` to use the default message
+    fn message(&self, py: Python, translator: Option<&PyAny>) -> PyResult<String> {
+        if let Some(translator) = translator {
+            let context = self.error_type.py_dict(py)?;
+            let message = translator.call1((self.error_type.type_string(), context))?;
+            if !message.is_none() {
+                return message.extract();
+            }
+        }
+        self.error_type.render_message(py)
+    }
+
     fn pretty(&self, py: Python) -> Result<String, fmt::Error> {
         let mut output = String::with_capacity(200);
         write!(output, "{}", self.location)?;
@@ -189,14 +324,107 @@ impl PyLineError {
         };
         write!(output, "  {message} [type={}", self.error_type.type_string())?;
 
-        let input_value = self.input_value.as_ref(py);
-        let input_str = safe_repr(input_value);
-        truncate_input_value!(output, input_str);
+        if let Some(ref input_value) = self.input_value {
+            let input_value = input_value.as_ref(py);
+            let input_str = safe_repr(input_value);
+            truncate_input_value!(output, input_str);
 
-        if let Ok(type_) = input_value.get_type().name() {
-            write!(output, ", input_type={type_}")?;
+            if let Ok(type_) = input_value.get_type().name() {
+                write!(output, ", input_type={type_}")?;
+            }
         }
         output.push(']');
         Ok(output)
     }
 }
+
+fn json_py_err(error: serde_json::Error) -> PyErr {
+    py_error_type!(PyValueError; "Error serializing ValidationError to JSON: {}", error)
+}
+
+struct ValidationErrorSerializer<'py> {
+    py: Python<'py>,
+    line_errors: &'py [PyLineError],
+    extra: &'py Extra<'py>,
+    include_context: bool,
+    include_input: bool,
+    include_url: bool,
+    translator: Option<&'py PyAny>,
+    include_code: bool,
+}
+
+impl<'py> Serialize for ValidationErrorSerializer<'py> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.line_errors.len()))?;
+        for line_error in self.line_errors {
+            let line_s = PyLineErrorSerializer {
+                py: self.py,
+                line_error,
+                extra: self.extra,
+                include_context: self.include_context,
+                include_input: self.include_input,
+                include_url: self.include_url,
+                translator: self.translator,
+                include_code: self.include_code,
+            };
+            seq.serialize_element(&line_s)?;
+        }
+        seq.end()
+    }
+}
+
+struct PyLineErrorSerializer<'py> {
+    py: Python<'py>,
+    line_error: &'py PyLineError,
+    extra: &'py Extra<'py>,
+    include_context: bool,
+    include_input: bool,
+    include_url: bool,
+    translator: Option<&'py PyAny>,
+    include_code: bool,
+}
+
+impl<'py> PyLineErrorSerializer<'py> {
+    /// Inputs and context values can be anything, if a value can't be serialized to JSON, use its repr instead
+    fn jsonable(&self, value: &PyAny) -> serde_json::Value {
+        match serde_json::to_value(SerializeInfer::new(value, None, None, self.extra)) {
+            Ok(json_value) => json_value,
+            Err(_) => serde_json::Value::String(safe_repr(value).to_string()),
+        }
+    }
+}
+
+impl<'py> Serialize for PyLineErrorSerializer<'py> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let py = self.py;
+        let error_type = &self.line_error.error_type;
+        let mut map = serializer.serialize_map(None)?;
+
+        map.serialize_entry("type", &error_type.type_string())?;
+        if self.include_code {
+            if let Some(code) = error_type.error_code() {
+                map.serialize_entry("code", &code)?;
+            }
+        }
+        map.serialize_entry("loc", &self.line_error.location)?;
+        let msg = self.line_error.message(py, self.translator).map_err(S::Error::custom)?;
+        map.serialize_entry("msg", &msg)?;
+
+        if self.include_input {
+            if let Some(ref input_value) = self.line_error.input_value {
+                map.serialize_entry("input", &self.jsonable(input_value.as_ref(py)))?;
+            }
+        }
+        if self.include_context {
+            if let Some(context) = error_type.py_dict(py).map_err(S::Error::custom)? {
+                map.serialize_entry("ctx", &self.jsonable(context.as_ref(py)))?;
+            }
+        }
+        if self.include_url {
+            if let Some(url) = error_type.documentation_url(py) {
+                map.serialize_entry("url", &url)?;
+            }
+        }
+        map.end()
+    }
+}
@@ -3,6 +3,7 @@ use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyString};
 
 use crate::input::Input;
+use crate::py_gc::impl_py_gc_traverse;
 
 use super::{ErrorType, ValError};
 
@@ -67,6 +68,8 @@ pub struct PydanticCustomError {
     context: Option<Py<PyDict>>,
 }
 
+impl_py_gc_traverse!(PydanticCustomError { context });
+
 #[pymethods]
 impl PydanticCustomError {
     #[new]
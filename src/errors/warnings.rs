@@ -0,0 +1,70 @@
+use std::cell::RefCell;
+
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+
+use super::location::{LocItem, Location};
+
+/// A non-fatal diagnostic recorded during validation, e.g. a deprecated field was used.
+#[derive(Debug)]
+struct ValidationWarning {
+    warning_type: &'static str,
+    message: String,
+    // location is reversed as with `ValLineError`
+    location: Location,
+}
+
+/// Warnings collected while validating a single input, only created when `collect_warnings=True`
+/// is passed to `validate_python` or `validate_json`.
+///
+/// Validators record warnings without a location, collections then add their index or key to any
+/// warnings recorded while validating each item, see `Extra::warnings_with_outer_location`.
+#[derive(Debug, Default)]
+pub struct ValidationWarnings {
+    warnings: RefCell<Vec<ValidationWarning>>,
+}
+
+impl ValidationWarnings {
+    pub fn record(&self, warning_type: &'static str, message: String) {
+        self.warnings.borrow_mut().push(ValidationWarning {
+            warning_type,
+            message,
+            location: Location::default(),
+        });
+    }
+
+    pub fn len(&self) -> usize {
+        self.warnings.borrow().len()
+    }
+
+    /// drop warnings recorded since `start`, used when a union choice fails and its output is discarded
+    pub fn truncate(&self, start: usize) {
+        self.warnings.borrow_mut().truncate(start);
+    }
+
+    /// add outer location items to warnings recorded since `start`, `loc_items` are in their natural order
+    pub fn with_outer_location(&self, start: usize, loc_items: &[LocItem]) {
+        let mut warnings = self.warnings.borrow_mut();
+        for warning in warnings.iter_mut().skip(start) {
+            for loc_item in loc_items.iter().rev() {
+                warning.location.with_outer(loc_item.clone());
+            }
+        }
+    }
+
+    pub fn to_py(&self, py: Python) -> PyResult<PyObject> {
+        let warnings = self
+            .warnings
+            .borrow()
+            .iter()
+            .map(|warning| {
+                let dict = PyDict::new(py);
+                dict.set_item("type", warning.warning_type)?;
+                dict.set_item("loc", warning.location.to_object(py))?;
+                dict.set_item("msg", &warning.message)?;
+                Ok(dict)
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(PyList::new(py, warnings).into_py(py))
+    }
+}
@@ -1,10 +1,11 @@
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyDate, PyDateTime, PyDelta, PyDeltaAccess, PyTime, PyTzInfo};
+use pyo3::types::{PyDate, PyDateTime, PyDelta, PyDeltaAccess, PyDict, PyTime, PyTimeAccess, PyTzInfo};
 use speedate::{Date, DateTime, Duration, ParseError, Time};
 use std::borrow::Cow;
 use strum::EnumMessage;
 
+use crate::build_tools::{kwargs, py_err, schema_or_config_same};
 use crate::errors::{ErrorType, ValError, ValResult};
 
 use super::Input;
@@ -57,13 +58,14 @@ impl<'a> EitherDate<'a> {
 
 #[cfg_attr(debug_assertions, derive(Debug))]
 pub enum EitherTime<'a> {
-    Raw(Time),
+    /// `speedate::Time` has no timezone offset, so we carry it alongside
+    Raw(Time, Option<i32>),
     Py(&'a PyTime),
 }
 
 impl<'a> From<Time> for EitherTime<'a> {
     fn from(time: Time) -> Self {
-        Self::Raw(time)
+        Self::Raw(time, None)
     }
 }
 
@@ -94,24 +96,26 @@ impl<'a> From<&'a PyDelta> for EitherTimedelta<'a> {
 pub fn pytimedelta_as_duration(py_timedelta: &PyDelta) -> Duration {
     // see https://docs.python.org/3/c-api/datetime.html#c.PyDateTime_DELTA_GET_DAYS
     // days can be negative, but seconds and microseconds are always positive.
-    let mut days = py_timedelta.get_days(); // -999999999 to 999999999
-    let mut seconds = py_timedelta.get_seconds(); // 0 through 86399
-    let mut microseconds = py_timedelta.get_microseconds(); // 0 through 999999
-    let positive = days >= 0;
-    if !positive {
-        // negative timedelta, we need to adjust values to match duration logic
-        if microseconds != 0 {
-            seconds += 1;
-            microseconds = (microseconds - 1_000_000).abs();
-        }
-        if seconds != 0 {
-            days += 1;
-            seconds = (seconds - 86400).abs();
-        }
-        days = days.abs();
+    let days = py_timedelta.get_days() as i128; // -999999999 to 999999999
+    let seconds = py_timedelta.get_seconds() as i128; // 0 through 86399
+    let microseconds = py_timedelta.get_microseconds() as i128; // 0 through 999999
+    let total_microseconds = (days * 86_400 + seconds) * 1_000_000 + microseconds;
+    // we can safely "unwrap" since python guarantees the total is within the range of `Duration`
+    microseconds_as_duration(total_microseconds).unwrap()
+}
+
+/// Build a `Duration` from a signed total number of microseconds, this is the single point of conversion
+/// so that mixed-sign and sub-second negative values (e.g. `-0.5s`) are handled consistently.
+pub fn microseconds_as_duration(total_microseconds: i128) -> Result<Duration, ParseError> {
+    let positive = total_microseconds >= 0;
+    let total_microseconds = total_microseconds.unsigned_abs();
+    let day = total_microseconds / 86_400_000_000;
+    if day > 999_999_999 {
+        return Err(ParseError::DurationDaysTooLarge);
     }
-    // we can safely "unwrap" since the methods above guarantee values are in the correct ranges.
-    Duration::new(positive, days as u32, seconds as u32, microseconds as u32).unwrap()
+    let second = (total_microseconds / 1_000_000) % 86_400;
+    let microsecond = total_microseconds % 1_000_000;
+    Duration::new(positive, day as u32, second as u32, microsecond as u32)
 }
 
 impl<'a> EitherTimedelta<'a> {
@@ -154,32 +158,79 @@ macro_rules! pytime_as_time {
 }
 pub(crate) use pytime_as_time;
 
+/// Get the UTC offset of a python `time` in seconds, `None` if the time is naive.
+pub fn pytime_offset(py_time: &PyTime) -> PyResult<Option<i32>> {
+    let py = py_time.py();
+    let tzinfo = py_time.getattr(intern!(py, "tzinfo"))?;
+    if tzinfo.is_none() {
+        return Ok(None);
+    }
+    // as per the docs, `time.utcoffset()` calls `tzinfo.utcoffset(None)` which can return None
+    let offset_delta = py_time.call_method0(intern!(py, "utcoffset"))?;
+    if offset_delta.is_none() {
+        Ok(None)
+    } else {
+        let offset_seconds: f64 = offset_delta.call_method0(intern!(py, "total_seconds"))?.extract()?;
+        Ok(Some(offset_seconds.round() as i32))
+    }
+}
+
+/// Format a UTC offset in seconds the same way as `speedate::DateTime`, e.g. `Z` or `+05:30`.
+pub fn offset_to_string(offset: i32) -> String {
+    if offset == 0 {
+        "Z".to_string()
+    } else {
+        let mins = offset / 60;
+        let sign = if mins < 0 { '-' } else { '+' };
+        format!("{}{:02}:{:02}", sign, (mins / 60).abs(), (mins % 60).abs())
+    }
+}
+
 impl<'a> EitherTime<'a> {
     pub fn as_raw(&self) -> PyResult<Time> {
         match self {
-            Self::Raw(time) => Ok(time.clone()),
+            Self::Raw(time, _) => Ok(time.clone()),
             Self::Py(py_time) => Ok(pytime_as_time!(py_time)),
         }
     }
 
+    pub fn offset(&self) -> PyResult<Option<i32>> {
+        match self {
+            Self::Raw(_, offset) => Ok(*offset),
+            Self::Py(py_time) => pytime_offset(py_time),
+        }
+    }
+
     pub fn try_into_py(self, py: Python<'_>) -> PyResult<PyObject> {
         let time = match self {
-            Self::Py(time) => Ok(time),
-            Self::Raw(time) => PyTime::new(py, time.hour, time.minute, time.second, time.microsecond, None),
-        }?;
+            Self::Py(time) => time,
+            Self::Raw(time, Some(offset)) => {
+                let tz_info = TzInfo::new(offset);
+                PyTime::new(
+                    py,
+                    time.hour,
+                    time.minute,
+                    time.second,
+                    time.microsecond,
+                    Some(Py::new(py, tz_info)?.to_object(py).extract(py)?),
+                )?
+            }
+            Self::Raw(time, None) => PyTime::new(py, time.hour, time.minute, time.second, time.microsecond, None)?,
+        };
         Ok(time.into_py(py))
     }
 }
 
 #[cfg_attr(debug_assertions, derive(Debug))]
 pub enum EitherDateTime<'a> {
-    Raw(DateTime),
+    // the `fold` attribute (PEP 495) of the python datetime to build, 0 or 1
+    Raw(DateTime, u8),
     Py(&'a PyDateTime),
 }
 
 impl<'a> From<DateTime> for EitherDateTime<'a> {
     fn from(dt: DateTime) -> Self {
-        Self::Raw(dt)
+        Self::Raw(dt, 0)
     }
 }
 
@@ -213,17 +264,31 @@ pub fn pydatetime_as_datetime(py_dt: &PyDateTime) -> PyResult<DateTime> {
 impl<'a> EitherDateTime<'a> {
     pub fn as_raw(&self) -> PyResult<DateTime> {
         match self {
-            Self::Raw(dt) => Ok(dt.clone()),
+            Self::Raw(dt, _) => Ok(dt.clone()),
             Self::Py(py_dt) => pydatetime_as_datetime(py_dt),
         }
     }
 
+    /// Set `fold` on the datetime, python datetimes are replaced (keeping their `tzinfo`) only if `fold` differs,
+    /// for python datetimes this decides which UTC offset is used for ambiguous wall-clock times.
+    pub fn with_fold(self, fold: u8) -> PyResult<Self> {
+        match self {
+            Self::Raw(dt, _) => Ok(Self::Raw(dt, fold)),
+            Self::Py(py_dt) if py_dt.get_fold() as u8 != fold => {
+                let py = py_dt.py();
+                let folded_dt = py_dt.call_method(intern!(py, "replace"), (), kwargs!(py, fold: fold))?;
+                Ok(Self::Py(folded_dt.cast_as()?))
+            }
+            py_dt => Ok(py_dt),
+        }
+    }
+
     pub fn try_into_py(self, py: Python<'a>) -> PyResult<PyObject> {
         let dt = match self {
-            Self::Raw(datetime) => match datetime.offset {
+            Self::Raw(datetime, fold) => match datetime.offset {
                 Some(offset) => {
                     let tz_info = TzInfo::new(offset);
-                    PyDateTime::new(
+                    PyDateTime::new_with_fold(
                         py,
                         datetime.date.year as i32,
                         datetime.date.month,
@@ -233,9 +298,10 @@ impl<'a> EitherDateTime<'a> {
                         datetime.time.second,
                         datetime.time.microsecond,
                         Some(Py::new(py, tz_info)?.to_object(py).extract(py)?),
+                        fold == 1,
                     )?
                 }
-                None => PyDateTime::new(
+                None => PyDateTime::new_with_fold(
                     py,
                     datetime.date.year as i32,
                     datetime.date.month,
@@ -245,6 +311,7 @@ impl<'a> EitherDateTime<'a> {
                     datetime.time.second,
                     datetime.time.microsecond,
                     None,
+                    fold == 1,
                 )?,
             },
             Self::Py(dt) => dt,
@@ -253,6 +320,59 @@ impl<'a> EitherDateTime<'a> {
     }
 }
 
+/// Seconds since the unix epoch plus microseconds, the inverse of `int_as_datetime` and `float_as_datetime`,
+/// naive datetimes are treated as UTC.
+pub fn datetime_as_timestamp(dt: &DateTime) -> (i64, u32) {
+    (dt.timestamp_tz(), dt.time.microsecond)
+}
+
+/// Seconds since the unix epoch of midnight (UTC) at the start of the date.
+pub fn date_as_timestamp(date: &Date) -> (i64, u32) {
+    (date.timestamp(), 0)
+}
+
+/// Seconds since midnight plus microseconds, the inverse of `int_as_time` and `float_as_time`,
+/// any timezone offset is ignored.
+pub fn time_as_timestamp(time: &Time) -> (i64, u32) {
+    (time.total_seconds() as i64, time.microsecond)
+}
+
+/// A UTC datetime from seconds since the unix epoch plus microseconds, used when decoding binary formats.
+/// Unlike `DateTime::from_timestamp`, large values are never taken to be milliseconds, `None` if the datetime
+/// is outside the range of python's `datetime`.
+pub(super) fn utc_datetime_from_timestamp(seconds: i64, microsecond: u32) -> Option<DateTime> {
+    // 0001-01-01T00:00:00 and 9999-12-31T23:59:59
+    const MIN_SECONDS: i64 = -62_135_596_800;
+    const MAX_SECONDS: i64 = 253_402_300_799;
+    let seconds = seconds.checked_add((microsecond / 1_000_000) as i64)?;
+    if !(MIN_SECONDS..=MAX_SECONDS).contains(&seconds) {
+        return None;
+    }
+    // days to a civil date, see http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let days = seconds.div_euclid(86_400) + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let date = Date {
+        year: (era * 400 + year_of_era + i64::from(month <= 2)) as u16,
+        month: month as u8,
+        day: (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u8,
+    };
+    let time = Time::from_timestamp(seconds.rem_euclid(86_400) as u32, microsecond % 1_000_000).ok()?;
+    Some(DateTime {
+        date,
+        time,
+        offset: Some(0),
+    })
+}
+
 pub fn bytes_as_date<'a>(input: &'a impl Input<'a>, bytes: &[u8]) -> ValResult<'a, EitherDate<'a>> {
     match Date::parse_bytes(bytes) {
         Ok(date) => Ok(date.into()),
@@ -265,9 +385,373 @@ pub fn bytes_as_date<'a>(input: &'a impl Input<'a>, bytes: &[u8]) -> ValResult<'
     }
 }
 
-pub fn bytes_as_time<'a>(input: &'a impl Input<'a>, bytes: &[u8]) -> ValResult<'a, EitherTime<'a>> {
-    match Time::parse_bytes(bytes) {
-        Ok(date) => Ok(date.into()),
+/// With `rfc3339` set on date and datetime validators, string inputs must be RFC 3339 `full-date`s or
+/// `date-time`s rather than anything in the superset of ISO 8601 speedate accepts, other inputs aren't checked.
+pub fn check_rfc3339_date<'a>(input: &'a impl Input<'a>) -> ValResult<'a, ()> {
+    match input.validate_str(false) {
+        Ok(either_str) if !matches_pattern(either_str.as_cow()?.as_bytes(), b"dddd-dd-dd") => Err(ValError::new(
+            ErrorType::DateParsing {
+                error: Cow::Borrowed("input is not an RFC 3339 date"),
+            },
+            input,
+        )),
+        _ => Ok(()),
+    }
+}
+
+pub fn check_rfc3339_datetime<'a>(input: &'a impl Input<'a>) -> ValResult<'a, ()> {
+    match input.validate_str(false) {
+        Ok(either_str) if !is_rfc3339_datetime(either_str.as_cow()?.as_bytes()) => Err(ValError::new(
+            ErrorType::DatetimeParsing {
+                error: Cow::Borrowed("input is not an RFC 3339 date-time"),
+            },
+            input,
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// `date-time` from RFC 3339 section 5.6: seconds and a timezone offset are required, fractional seconds are
+/// optional, `T` and `Z` may be lowercase as per the note in the RFC but a space separator isn't allowed.
+fn is_rfc3339_datetime(bytes: &[u8]) -> bool {
+    if bytes.len() < 20
+        || !matches_pattern(&bytes[..10], b"dddd-dd-dd")
+        || !matches!(bytes[10], b'T' | b't')
+        || !matches_pattern(&bytes[11..19], b"dd:dd:dd")
+    {
+        return false;
+    }
+    let mut offset = &bytes[19..];
+    if offset[0] == b'.' {
+        let digits = offset[1..].iter().take_while(|c| c.is_ascii_digit()).count();
+        if digits == 0 {
+            return false;
+        }
+        offset = &offset[1 + digits..];
+    }
+    match offset {
+        b"Z" | b"z" => true,
+        [b'+' | b'-', rest @ ..] => matches_pattern(rest, b"dd:dd"),
+        _ => false,
+    }
+}
+
+/// `d` in the pattern matches any ASCII digit, all other characters must match exactly
+fn matches_pattern(bytes: &[u8], pattern: &[u8]) -> bool {
+    bytes.len() == pattern.len()
+        && bytes.iter().zip(pattern).all(|(c, p)| match p {
+            b'd' => c.is_ascii_digit(),
+            _ => c == p,
+        })
+}
+
+/// Parse a string datetime without an offset followed by a space and a named timezone, e.g.
+/// `2024-07-01 12:00:00 Europe/Paris` or `2024-07-01 12:00:00 EST`, returns `None` if the input isn't of that form.
+/// IANA timezone names are only available with the `tz` feature, RFC 2822 abbreviations are always available.
+pub fn named_timezone_datetime<'a>(
+    input: &'a impl Input<'a>,
+    leap_second_mode: LeapSecondMode,
+) -> ValResult<'a, Option<EitherDateTime<'a>>> {
+    let either_str = match input.validate_str(false) {
+        Ok(either_str) => either_str,
+        Err(_) => return Ok(None),
+    };
+    let cow = either_str.as_cow()?;
+    let (dt_str, zone) = match cow.trim().rsplit_once(' ') {
+        Some((dt_str, zone)) if !zone.is_empty() => (dt_str.trim_end(), zone),
+        _ => return Ok(None),
+    };
+    let dt = match parse_datetime(dt_str.as_bytes(), leap_second_mode) {
+        Ok(dt) if dt.offset.is_none() => dt,
+        _ => return Ok(None),
+    };
+    let offset = match rfc2822_zone_offset(zone) {
+        Some(offset) => offset,
+        None => tz_database_offset(zone, &dt).map_err(|error| {
+            ValError::new(
+                ErrorType::DatetimeParsing {
+                    error: Cow::Borrowed(error),
+                },
+                input,
+            )
+        })?,
+    };
+    Ok(Some(
+        DateTime {
+            offset: Some(offset),
+            ..dt
+        }
+        .into(),
+    ))
+}
+
+/// Zone abbreviations from RFC 2822 section 4.3, military zones aren't supported since the RFC notes their meaning
+/// can't be relied on.
+fn rfc2822_zone_offset(zone: &str) -> Option<i32> {
+    let hours = match zone.to_ascii_uppercase().as_str() {
+        "UT" | "UTC" | "GMT" => 0,
+        "EST" => -5,
+        "EDT" => -4,
+        "CST" => -6,
+        "CDT" => -5,
+        "MST" => -7,
+        "MDT" => -6,
+        "PST" => -8,
+        "PDT" => -7,
+        _ => return None,
+    };
+    Some(hours * 3600)
+}
+
+/// The UTC offset in seconds of `zone` at local time `dt`, accounting for DST, where a local time is ambiguous
+/// the earlier offset is used as with `fold=0` in python.
+#[cfg(feature = "tz")]
+fn tz_database_offset(zone: &str, dt: &DateTime) -> Result<i32, &'static str> {
+    use chrono::{LocalResult, NaiveDate, Offset, TimeZone};
+
+    let tz: chrono_tz::Tz = zone.parse().map_err(|_| "unknown timezone")?;
+    let naive = NaiveDate::from_ymd_opt(dt.date.year as i32, dt.date.month as u32, dt.date.day as u32)
+        .and_then(|date| {
+            date.and_hms_micro_opt(
+                dt.time.hour as u32,
+                dt.time.minute as u32,
+                dt.time.second as u32,
+                dt.time.microsecond,
+            )
+        })
+        .ok_or("invalid datetime")?;
+    match tz.offset_from_local_datetime(&naive) {
+        LocalResult::Single(offset) | LocalResult::Ambiguous(offset, _) => Ok(offset.fix().local_minus_utc()),
+        LocalResult::None => Err("local time does not exist in the timezone due to a DST transition"),
+    }
+}
+
+#[cfg(not(feature = "tz"))]
+fn tz_database_offset(_zone: &str, _dt: &DateTime) -> Result<i32, &'static str> {
+    Err("unknown timezone, IANA timezone names require pydantic-core to be built with the \"tz\" feature")
+}
+
+/// Parse a timezone offset such as `Z`, `+05:00`, `-0130` or `+02`, returning the offset in seconds,
+/// this follows the offset logic in `speedate::DateTime::parse_bytes`.
+pub(super) fn parse_offset(bytes: &[u8]) -> Result<i32, ParseError> {
+    macro_rules! get_digit {
+        ($index:expr, $error:ident) => {
+            match bytes.get($index) {
+                Some(c) if c.is_ascii_digit() => (c - b'0') as i32,
+                _ => return Err(ParseError::$error),
+            }
+        };
+    }
+
+    let (sign, mut position) = match bytes.first().copied() {
+        Some(b'Z') | Some(b'z') => {
+            return match bytes.len() {
+                1 => Ok(0),
+                _ => Err(ParseError::ExtraCharacters),
+            }
+        }
+        Some(b'+') => (1, 1),
+        Some(b'-') => (-1, 1),
+        // U+2212 MINUS "−" is allowed under ISO 8601 for negative timezones
+        Some(226) if bytes.get(1..3) == Some(&[136, 146]) => (-1, 3),
+        _ => return Err(ParseError::InvalidCharTzSign),
+    };
+
+    let h1 = get_digit!(position, InvalidCharTzHour);
+    let h2 = get_digit!(position + 1, InvalidCharTzHour);
+    position += 2;
+
+    let minute_seconds = match bytes.get(position) {
+        None => 0,
+        Some(b':') => {
+            let m1 = get_digit!(position + 1, InvalidCharTzMinute);
+            let m2 = get_digit!(position + 2, InvalidCharTzMinute);
+            position += 3;
+            m1 * 600 + m2 * 60
+        }
+        Some(_) => {
+            let m1 = get_digit!(position, InvalidCharTzMinute);
+            let m2 = get_digit!(position + 1, InvalidCharTzMinute);
+            position += 2;
+            m1 * 600 + m2 * 60
+        }
+    };
+    if minute_seconds >= 3600 {
+        return Err(ParseError::OutOfRangeTzMinute);
+    }
+    if bytes.len() > position {
+        return Err(ParseError::ExtraCharacters);
+    }
+
+    let offset = sign * (h1 * 36000 + h2 * 3600 + minute_seconds);
+    // TZ must be less than 24 hours to match python
+    if offset.abs() >= 24 * 3600 {
+        Err(ParseError::OutOfRangeTz)
+    } else {
+        Ok(offset)
+    }
+}
+
+/// `speedate::Time` doesn't support timezone offsets, so we split any offset off the end before parsing the time.
+fn parse_time_with_offset(bytes: &[u8]) -> Result<(Time, Option<i32>), ParseError> {
+    match bytes.iter().position(|c| matches!(c, b'Z' | b'z' | b'+' | b'-' | 226)) {
+        Some(tz_start) => {
+            let time = Time::parse_bytes(&bytes[..tz_start])?;
+            let offset = parse_offset(&bytes[tz_start..])?;
+            Ok((time, Some(offset)))
+        }
+        None => Ok((Time::parse_bytes(bytes)?, None)),
+    }
+}
+
+/// How a leap second, e.g. `23:59:60`, is handled when parsing times and datetimes from strings.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LeapSecondMode {
+    /// leap seconds are invalid, this is the default
+    #[default]
+    Error,
+    /// `:60` becomes `:59.999999`
+    Clamp,
+    /// `:60` becomes `:00` of the next minute, carrying into the hour and day as required
+    Carry,
+}
+
+impl LeapSecondMode {
+    pub fn from_str(s: &str) -> PyResult<Self> {
+        match s {
+            "error" => Ok(Self::Error),
+            "clamp" => Ok(Self::Clamp),
+            "carry" => Ok(Self::Carry),
+            _ => py_err!("Invalid leap_second_mode {:?}", s),
+        }
+    }
+
+    pub fn from_py(schema: &PyDict, config: Option<&PyDict>) -> PyResult<Self> {
+        match schema_or_config_same(schema, config, intern!(schema.py(), "leap_second_mode"))? {
+            Some(mode) => Self::from_str(mode),
+            None => Ok(Self::Error),
+        }
+    }
+
+    /// If leap seconds are allowed and `bytes` has `:60` seconds starting at `index`, return a copy
+    /// with the seconds replaced by `59` so speedate can parse it.
+    fn replace_leap_second(&self, bytes: &[u8], index: usize) -> Option<Vec<u8>> {
+        if *self == Self::Error || index == 0 {
+            return None;
+        }
+        match (bytes.get(index - 1), bytes.get(index..index + 2)) {
+            (Some(b':'), Some(b"60")) => {
+                let mut replaced = bytes.to_vec();
+                replaced[index + 1] = b'9';
+                replaced[index] = b'5';
+                Some(replaced)
+            }
+            _ => None,
+        }
+    }
+
+    /// Adjust a time parsed from a patched `:59` string, returns `true` if the time wrapped past midnight.
+    fn adjust_time(&self, time: &mut Time) -> bool {
+        match self {
+            Self::Error => false,
+            Self::Clamp => {
+                time.microsecond = 999_999;
+                false
+            }
+            Self::Carry => {
+                time.second = 0;
+                if time.minute < 59 {
+                    time.minute += 1;
+                    return false;
+                }
+                time.minute = 0;
+                if time.hour < 23 {
+                    time.hour += 1;
+                    return false;
+                }
+                time.hour = 0;
+                true
+            }
+        }
+    }
+}
+
+pub(super) fn days_in_month(year: u16, month: u8) -> u8 {
+    let leap_year = match (year % 4, year % 100, year % 400) {
+        (_, _, 0) => true,
+        (_, 0, _) => false,
+        (0, _, _) => true,
+        _ => false,
+    };
+    match month {
+        4 | 6 | 9 | 11 => 30,
+        2 if leap_year => 29,
+        2 => 28,
+        _ => 31,
+    }
+}
+
+fn next_day(date: &Date) -> Result<Date, ParseError> {
+    if date.day < days_in_month(date.year, date.month) {
+        Ok(Date {
+            day: date.day + 1,
+            ..date.clone()
+        })
+    } else if date.month < 12 {
+        Ok(Date {
+            month: date.month + 1,
+            day: 1,
+            ..date.clone()
+        })
+    } else if date.year < 9999 {
+        Ok(Date {
+            year: date.year + 1,
+            month: 1,
+            day: 1,
+        })
+    } else {
+        Err(ParseError::DateTooLarge)
+    }
+}
+
+fn parse_time(bytes: &[u8], leap_second_mode: LeapSecondMode) -> Result<(Time, Option<i32>), ParseError> {
+    match parse_time_with_offset(bytes) {
+        Err(ParseError::OutOfRangeSecond) => match leap_second_mode.replace_leap_second(bytes, 6) {
+            Some(replaced) => {
+                let (mut time, offset) = parse_time_with_offset(&replaced)?;
+                // a time on its own has no day to carry into, so it simply wraps to midnight
+                leap_second_mode.adjust_time(&mut time);
+                Ok((time, offset))
+            }
+            None => Err(ParseError::OutOfRangeSecond),
+        },
+        result => result,
+    }
+}
+
+fn parse_datetime(bytes: &[u8], leap_second_mode: LeapSecondMode) -> Result<DateTime, ParseError> {
+    match DateTime::parse_bytes(bytes) {
+        Err(ParseError::OutOfRangeSecond) => match leap_second_mode.replace_leap_second(bytes, 17) {
+            Some(replaced) => {
+                let mut dt = DateTime::parse_bytes(&replaced)?;
+                if leap_second_mode.adjust_time(&mut dt.time) {
+                    dt.date = next_day(&dt.date)?;
+                }
+                Ok(dt)
+            }
+            None => Err(ParseError::OutOfRangeSecond),
+        },
+        result => result,
+    }
+}
+
+pub fn bytes_as_time<'a>(
+    input: &'a impl Input<'a>,
+    bytes: &[u8],
+    leap_second_mode: LeapSecondMode,
+) -> ValResult<'a, EitherTime<'a>> {
+    match parse_time(bytes, leap_second_mode) {
+        Ok((time, offset)) => Ok(EitherTime::Raw(time, offset)),
         Err(err) => Err(ValError::new(
             ErrorType::TimeParsing {
                 error: Cow::Borrowed(err.get_documentation().unwrap_or_default()),
@@ -277,8 +761,12 @@ pub fn bytes_as_time<'a>(input: &'a impl Input<'a>, bytes: &[u8]) -> ValResult<'
     }
 }
 
-pub fn bytes_as_datetime<'a, 'b>(input: &'a impl Input<'a>, bytes: &'b [u8]) -> ValResult<'a, EitherDateTime<'a>> {
-    match DateTime::parse_bytes(bytes) {
+pub fn bytes_as_datetime<'a, 'b>(
+    input: &'a impl Input<'a>,
+    bytes: &'b [u8],
+    leap_second_mode: LeapSecondMode,
+) -> ValResult<'a, EitherDateTime<'a>> {
+    match parse_datetime(bytes, leap_second_mode) {
         Ok(dt) => Ok(dt.into()),
         Err(err) => Err(ValError::new(
             ErrorType::DatetimeParsing {
@@ -398,23 +886,17 @@ pub fn bytes_as_timedelta<'a, 'b>(input: &'a impl Input<'a>, bytes: &'b [u8]) ->
 }
 
 pub fn int_as_duration<'a>(input: &'a impl Input<'a>, total_seconds: i64) -> ValResult<Duration> {
-    let positive = total_seconds >= 0;
-    let total_seconds = total_seconds.unsigned_abs();
-    // we can safely unwrap here since we've guaranteed seconds and microseconds can't cause overflow
-    let days = (total_seconds / 86400) as u32;
-    let seconds = (total_seconds % 86400) as u32;
-    Duration::new(positive, days, seconds, 0).map_err(|err| map_timedelta_err(input, err))
+    microseconds_as_duration(total_seconds as i128 * 1_000_000).map_err(|err| map_timedelta_err(input, err))
 }
 
 pub fn float_as_duration<'a>(input: &'a impl Input<'a>, total_seconds: f64) -> ValResult<Duration> {
     nan_check!(input, total_seconds, TimeDeltaParsing);
-    let positive = total_seconds >= 0_f64;
-    let total_seconds = total_seconds.abs();
-    let microsecond = total_seconds.fract() * 1_000_000.0;
-    let days = (total_seconds / 86400f64) as u32;
-    let seconds = total_seconds as u64 % 86400;
-    Duration::new(positive, days, seconds as u32, microsecond.round() as u32)
-        .map_err(|err| map_timedelta_err(input, err))
+    // split whole and fractional seconds to avoid losing precision when multiplying large values,
+    // `as i128` saturates for infinite values, these are then rejected as too large
+    let whole_seconds = total_seconds.trunc();
+    let microseconds = ((total_seconds - whole_seconds) * 1_000_000.0).round();
+    let total_microseconds = (whole_seconds as i128).saturating_mul(1_000_000) + microseconds as i128;
+    microseconds_as_duration(total_microseconds).map_err(|err| map_timedelta_err(input, err))
 }
 
 #[pyclass(module = "pydantic_core._pydantic_core", extends = PyTzInfo)]
@@ -431,15 +913,16 @@ impl TzInfo {
         Self { seconds }
     }
 
-    fn utcoffset<'p>(&self, py: Python<'p>, _dt: &PyDateTime) -> PyResult<&'p PyDelta> {
+    // `_dt` is `None` when called via `time.utcoffset()` etc., hence `&PyAny` rather than `&PyDateTime`
+    fn utcoffset<'p>(&self, py: Python<'p>, _dt: &PyAny) -> PyResult<&'p PyDelta> {
         PyDelta::new(py, 0, self.seconds, 0, true)
     }
 
-    fn tzname(&self, _dt: &PyDateTime) -> String {
+    fn tzname(&self, _dt: &PyAny) -> String {
         self.__str__()
     }
 
-    fn dst(&self, _dt: &PyDateTime) -> Option<&PyDelta> {
+    fn dst(&self, _dt: &PyAny) -> Option<&PyDelta> {
         None
     }
 
@@ -6,8 +6,8 @@ use pyo3::types::{PyString, PyType};
 use crate::errors::{InputValue, LocItem, ValResult};
 use crate::{PyMultiHostUrl, PyUrl};
 
-use super::datetime::{EitherDate, EitherDateTime, EitherTime, EitherTimedelta};
-use super::return_enums::{EitherBytes, EitherString};
+use super::datetime::{EitherDate, EitherDateTime, EitherTime, EitherTimedelta, LeapSecondMode};
+use super::return_enums::{EitherBytes, EitherInt, EitherString};
 use super::{GenericArguments, GenericCollection, GenericIterator, GenericMapping, JsonInput};
 
 pub enum InputType {
@@ -20,6 +20,14 @@ impl InputType {
     pub fn is_json(&self) -> bool {
         matches!(self, Self::Json)
     }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Python => "python",
+            Self::Json => "json",
+            Self::String => "string",
+        }
+    }
 }
 
 /// all types have three methods: `validate_*`, `strict_*`, `lax_*`
@@ -39,6 +47,12 @@ pub trait Input<'a>: fmt::Debug + ToPyObject {
 
     fn is_none(&self) -> bool;
 
+    /// whether the input is `PydanticUndefined`, which is treated as a missing value
+    #[cfg_attr(has_no_coverage, no_coverage)]
+    fn is_undefined(&self) -> bool {
+        false
+    }
+
     #[cfg_attr(has_no_coverage, no_coverage)]
     fn get_attr(&self, _name: &PyString) -> Option<&PyAny> {
         None
@@ -59,6 +73,11 @@ pub trait Input<'a>: fmt::Debug + ToPyObject {
         None
     }
 
+    /// instances of subclasses of `str`, e.g. `StrEnum` members, but not `str` itself
+    fn input_as_str_subclass(&'a self) -> Option<&'a PyString> {
+        None
+    }
+
     fn input_as_multi_host_url(&self) -> Option<PyMultiHostUrl> {
         None
     }
@@ -69,7 +88,7 @@ pub trait Input<'a>: fmt::Debug + ToPyObject {
 
     fn validate_args(&'a self) -> ValResult<'a, GenericArguments<'a>>;
 
-    fn parse_json(&'a self) -> ValResult<'a, JsonInput>;
+    fn parse_json(&'a self) -> ValResult<'a, JsonInput<'a>>;
 
     fn validate_str(&'a self, strict: bool) -> ValResult<EitherString<'a>> {
         if strict {
@@ -110,16 +129,17 @@ pub trait Input<'a>: fmt::Debug + ToPyObject {
         self.strict_bool()
     }
 
-    fn validate_int(&self, strict: bool) -> ValResult<i64> {
+    /// in lax mode bools are only accepted with `coerce_bools`
+    fn validate_int(&self, strict: bool, coerce_bools: bool) -> ValResult<EitherInt> {
         if strict {
             self.strict_int()
         } else {
-            self.lax_int()
+            self.lax_int(coerce_bools)
         }
     }
-    fn strict_int(&self) -> ValResult<i64>;
+    fn strict_int(&self) -> ValResult<EitherInt>;
     #[cfg_attr(has_no_coverage, no_coverage)]
-    fn lax_int(&self) -> ValResult<i64> {
+    fn lax_int(&self, _coerce_bools: bool) -> ValResult<EitherInt> {
         self.strict_int()
     }
 
@@ -220,30 +240,30 @@ pub trait Input<'a>: fmt::Debug + ToPyObject {
         self.strict_date()
     }
 
-    fn validate_time(&self, strict: bool) -> ValResult<EitherTime> {
+    fn validate_time(&self, strict: bool, leap_second_mode: LeapSecondMode) -> ValResult<EitherTime> {
         if strict {
-            self.strict_time()
+            self.strict_time(leap_second_mode)
         } else {
-            self.lax_time()
+            self.lax_time(leap_second_mode)
         }
     }
-    fn strict_time(&self) -> ValResult<EitherTime>;
+    fn strict_time(&self, leap_second_mode: LeapSecondMode) -> ValResult<EitherTime>;
     #[cfg_attr(has_no_coverage, no_coverage)]
-    fn lax_time(&self) -> ValResult<EitherTime> {
-        self.strict_time()
+    fn lax_time(&self, leap_second_mode: LeapSecondMode) -> ValResult<EitherTime> {
+        self.strict_time(leap_second_mode)
     }
 
-    fn validate_datetime(&self, strict: bool) -> ValResult<EitherDateTime> {
+    fn validate_datetime(&self, strict: bool, leap_second_mode: LeapSecondMode) -> ValResult<EitherDateTime> {
         if strict {
-            self.strict_datetime()
+            self.strict_datetime(leap_second_mode)
         } else {
-            self.lax_datetime()
+            self.lax_datetime(leap_second_mode)
         }
     }
-    fn strict_datetime(&self) -> ValResult<EitherDateTime>;
+    fn strict_datetime(&self, leap_second_mode: LeapSecondMode) -> ValResult<EitherDateTime>;
     #[cfg_attr(has_no_coverage, no_coverage)]
-    fn lax_datetime(&self) -> ValResult<EitherDateTime> {
-        self.strict_datetime()
+    fn lax_datetime(&self, leap_second_mode: LeapSecondMode) -> ValResult<EitherDateTime> {
+        self.strict_datetime(leap_second_mode)
     }
 
     fn validate_timedelta(&self, strict: bool) -> ValResult<EitherTimedelta> {
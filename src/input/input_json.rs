@@ -1,3 +1,8 @@
+use std::borrow::Cow;
+use std::str::from_utf8;
+
+use num_bigint::BigInt;
+use num_traits::ToPrimitive;
 use pyo3::prelude::*;
 
 use crate::errors::{ErrorType, InputValue, LocItem, ValError, ValLineError, ValResult};
@@ -5,16 +10,17 @@ use crate::errors::{ErrorType, InputValue, LocItem, ValError, ValLineError, ValR
 use super::datetime::{
     bytes_as_date, bytes_as_datetime, bytes_as_time, bytes_as_timedelta, float_as_datetime, float_as_duration,
     float_as_time, int_as_datetime, int_as_duration, int_as_time, EitherDate, EitherDateTime, EitherTime,
+    LeapSecondMode,
 };
 use super::input_abstract::InputType;
 use super::parse_json::JsonArray;
 use super::shared::{float_as_int, int_as_bool, map_json_err, str_as_bool, str_as_int};
 use super::{
-    EitherBytes, EitherString, EitherTimedelta, GenericArguments, GenericCollection, GenericIterator, GenericMapping,
-    Input, JsonArgs, JsonInput, JsonType,
+    EitherBytes, EitherInt, EitherString, EitherTimedelta, GenericArguments, GenericCollection, GenericIterator,
+    GenericMapping, Input, JsonArgs, JsonInput, JsonType,
 };
 
-impl<'a> Input<'a> for JsonInput {
+impl<'a> Input<'a> for JsonInput<'a> {
     fn get_type(&self) -> &'static InputType {
         &InputType::Json
     }
@@ -24,7 +30,8 @@ impl<'a> Input<'a> for JsonInput {
     fn as_loc_item(&self) -> LocItem {
         match self {
             JsonInput::Int(i) => LocItem::I(*i as usize),
-            JsonInput::String(s) => s.as_str().into(),
+            JsonInput::BigInt(b) => b.to_string().into(),
+            JsonInput::String(s) => s.as_ref().into(),
             v => format!("{v:?}").into(),
         }
     }
@@ -44,9 +51,9 @@ impl<'a> Input<'a> for JsonInput {
             let json_type: JsonType = match self {
                 JsonInput::Null => JsonType::Null,
                 JsonInput::Bool(_) => JsonType::Bool,
-                JsonInput::Int(_) => JsonType::Int,
+                JsonInput::Int(_) | JsonInput::BigInt(_) => JsonType::Int,
                 JsonInput::Float(_) => JsonType::Float,
-                JsonInput::String(_) => JsonType::String,
+                JsonInput::String(_) | JsonInput::Bytes(_) | JsonInput::DateTime(_) => JsonType::String,
                 JsonInput::Array(_) => JsonType::Array,
                 JsonInput::Object(_) => JsonType::Object,
             };
@@ -98,22 +105,26 @@ impl<'a> Input<'a> for JsonInput {
         }
     }
 
-    fn parse_json(&'a self) -> ValResult<'a, JsonInput> {
+    fn parse_json(&'a self) -> ValResult<'a, JsonInput<'a>> {
         match self {
-            JsonInput::String(s) => serde_json::from_str(s.as_str()).map_err(|e| map_json_err(self, e)),
+            JsonInput::String(s) => serde_json::from_str(s).map_err(|e| map_json_err(self, e, s.as_bytes())),
             _ => Err(ValError::new(ErrorType::JsonType, self)),
         }
     }
 
     fn strict_str(&'a self) -> ValResult<EitherString<'a>> {
         match self {
-            JsonInput::String(s) => Ok(s.as_str().into()),
+            JsonInput::String(s) => Ok(s.as_ref().into()),
             _ => Err(ValError::new(ErrorType::StringType, self)),
         }
     }
     fn lax_str(&'a self) -> ValResult<EitherString<'a>> {
         match self {
-            JsonInput::String(s) => Ok(s.as_str().into()),
+            JsonInput::String(s) => Ok(s.as_ref().into()),
+            JsonInput::Bytes(b) => match from_utf8(b) {
+                Ok(s) => Ok(s.into()),
+                Err(_) => Err(ValError::new(ErrorType::StringUnicode, self)),
+            },
             _ => Err(ValError::new(ErrorType::StringType, self)),
         }
     }
@@ -121,6 +132,7 @@ impl<'a> Input<'a> for JsonInput {
     fn validate_bytes(&'a self, _strict: bool) -> ValResult<EitherBytes<'a>> {
         match self {
             JsonInput::String(s) => Ok(s.as_bytes().into()),
+            JsonInput::Bytes(b) => Ok(b.as_slice().into()),
             _ => Err(ValError::new(ErrorType::BytesType, self)),
         }
     }
@@ -141,26 +153,29 @@ impl<'a> Input<'a> for JsonInput {
             JsonInput::String(s) => str_as_bool(self, s),
             JsonInput::Int(int) => int_as_bool(self, *int),
             JsonInput::Float(float) => match float_as_int(self, *float) {
-                Ok(int) => int_as_bool(self, int),
+                Ok(EitherInt::I64(int)) => int_as_bool(self, int),
+                Ok(EitherInt::BigInt(_)) => Err(ValError::new(ErrorType::BoolParsing, self)),
                 _ => Err(ValError::new(ErrorType::BoolType, self)),
             },
             _ => Err(ValError::new(ErrorType::BoolType, self)),
         }
     }
 
-    fn strict_int(&self) -> ValResult<i64> {
+    fn strict_int(&self) -> ValResult<EitherInt> {
         match self {
-            JsonInput::Int(i) => Ok(*i),
+            JsonInput::Int(i) => Ok(EitherInt::I64(*i)),
+            JsonInput::BigInt(b) => Ok(EitherInt::BigInt(b.clone())),
             _ => Err(ValError::new(ErrorType::IntType, self)),
         }
     }
-    fn lax_int(&self) -> ValResult<i64> {
+    fn lax_int(&self, coerce_bools: bool) -> ValResult<EitherInt> {
         match self {
-            JsonInput::Bool(b) => match *b {
-                true => Ok(1),
-                false => Ok(0),
+            JsonInput::Bool(b) if coerce_bools => match *b {
+                true => Ok(EitherInt::I64(1)),
+                false => Ok(EitherInt::I64(0)),
             },
-            JsonInput::Int(i) => Ok(*i),
+            JsonInput::Int(i) => Ok(EitherInt::I64(*i)),
+            JsonInput::BigInt(b) => Ok(EitherInt::BigInt(b.clone())),
             JsonInput::Float(f) => float_as_int(self, *f),
             JsonInput::String(str) => str_as_int(self, str),
             _ => Err(ValError::new(ErrorType::IntType, self)),
@@ -171,6 +186,7 @@ impl<'a> Input<'a> for JsonInput {
         match self {
             JsonInput::Float(f) => Ok(*f),
             JsonInput::Int(i) => Ok(*i as f64),
+            JsonInput::BigInt(b) => big_int_as_float(self, b),
             _ => Err(ValError::new(ErrorType::FloatType, self)),
         }
     }
@@ -182,6 +198,7 @@ impl<'a> Input<'a> for JsonInput {
             },
             JsonInput::Float(f) => Ok(*f),
             JsonInput::Int(i) => Ok(*i as f64),
+            JsonInput::BigInt(b) => big_int_as_float(self, b),
             JsonInput::String(str) => match str.parse::<f64>() {
                 Ok(i) => Ok(i),
                 Err(_) => Err(ValError::new(ErrorType::FloatParsing, self)),
@@ -250,11 +267,11 @@ impl<'a> Input<'a> for JsonInput {
 
     fn validate_iter(&self) -> ValResult<GenericIterator> {
         match self {
-            JsonInput::Array(a) => Ok(a.clone().into()),
+            JsonInput::Array(a) => Ok(a.iter().map(|v| v.clone().into_static()).collect::<JsonArray>().into()),
             JsonInput::String(s) => Ok(string_to_vec(s).into()),
             JsonInput::Object(object) => {
                 // return keys iterator to match python's behavior
-                let keys: Vec<JsonInput> = object.keys().map(|k| JsonInput::String(k.clone())).collect();
+                let keys: JsonArray = object.keys().map(|k| JsonInput::String(k.to_string().into())).collect();
                 Ok(keys.into())
             }
             _ => Err(ValError::new(ErrorType::IterableType, self)),
@@ -274,32 +291,36 @@ impl<'a> Input<'a> for JsonInput {
         self.validate_date(false)
     }
 
-    fn strict_time(&self) -> ValResult<EitherTime> {
+    fn strict_time(&self, leap_second_mode: LeapSecondMode) -> ValResult<EitherTime> {
         match self {
-            JsonInput::String(v) => bytes_as_time(self, v.as_bytes()),
+            JsonInput::String(v) => bytes_as_time(self, v.as_bytes(), leap_second_mode),
             _ => Err(ValError::new(ErrorType::TimeType, self)),
         }
     }
-    fn lax_time(&self) -> ValResult<EitherTime> {
+    fn lax_time(&self, leap_second_mode: LeapSecondMode) -> ValResult<EitherTime> {
         match self {
-            JsonInput::String(v) => bytes_as_time(self, v.as_bytes()),
+            JsonInput::String(v) => bytes_as_time(self, v.as_bytes(), leap_second_mode),
             JsonInput::Int(v) => int_as_time(self, *v, 0),
             JsonInput::Float(v) => float_as_time(self, *v),
+            JsonInput::BigInt(v) => float_as_time(self, big_int_as_float(self, v)?),
             _ => Err(ValError::new(ErrorType::TimeType, self)),
         }
     }
 
-    fn strict_datetime(&self) -> ValResult<EitherDateTime> {
+    fn strict_datetime(&self, leap_second_mode: LeapSecondMode) -> ValResult<EitherDateTime> {
         match self {
-            JsonInput::String(v) => bytes_as_datetime(self, v.as_bytes()),
+            JsonInput::String(v) => bytes_as_datetime(self, v.as_bytes(), leap_second_mode),
+            JsonInput::DateTime(dt) => Ok(dt.clone().into()),
             _ => Err(ValError::new(ErrorType::DatetimeType, self)),
         }
     }
-    fn lax_datetime(&self) -> ValResult<EitherDateTime> {
+    fn lax_datetime(&self, leap_second_mode: LeapSecondMode) -> ValResult<EitherDateTime> {
         match self {
-            JsonInput::String(v) => bytes_as_datetime(self, v.as_bytes()),
+            JsonInput::String(v) => bytes_as_datetime(self, v.as_bytes(), leap_second_mode),
+            JsonInput::DateTime(dt) => Ok(dt.clone().into()),
             JsonInput::Int(v) => int_as_datetime(self, *v, 0),
             JsonInput::Float(v) => float_as_datetime(self, *v),
+            JsonInput::BigInt(v) => float_as_datetime(self, big_int_as_float(self, v)?),
             _ => Err(ValError::new(ErrorType::DatetimeType, self)),
         }
     }
@@ -315,13 +336,14 @@ impl<'a> Input<'a> for JsonInput {
             JsonInput::String(v) => bytes_as_timedelta(self, v.as_bytes()),
             JsonInput::Int(v) => Ok(int_as_duration(self, *v)?.into()),
             JsonInput::Float(v) => Ok(float_as_duration(self, *v)?.into()),
+            JsonInput::BigInt(v) => Ok(float_as_duration(self, big_int_as_float(self, v)?)?.into()),
             _ => Err(ValError::new(ErrorType::TimeDeltaType, self)),
         }
     }
 }
 
 /// Required for Dict keys so the string can behave like an Input
-impl<'a> Input<'a> for String {
+impl<'a> Input<'a> for Cow<'a, str> {
     fn get_type(&self) -> &'static InputType {
         &InputType::String
     }
@@ -352,12 +374,12 @@ impl<'a> Input<'a> for String {
         Err(ValError::new(ErrorType::ArgumentsType, self))
     }
 
-    fn parse_json(&'a self) -> ValResult<'a, JsonInput> {
-        serde_json::from_str(self.as_str()).map_err(|e| map_json_err(self, e))
+    fn parse_json(&'a self) -> ValResult<'a, JsonInput<'a>> {
+        serde_json::from_str(self).map_err(|e| map_json_err(self, e, self.as_bytes()))
     }
 
     fn validate_str(&'a self, _strict: bool) -> ValResult<EitherString<'a>> {
-        Ok(self.as_str().into())
+        Ok(self.as_ref().into())
     }
     fn strict_str(&'a self) -> ValResult<EitherString<'a>> {
         self.validate_str(false)
@@ -378,12 +400,12 @@ impl<'a> Input<'a> for String {
         str_as_bool(self, self)
     }
 
-    fn strict_int(&self) -> ValResult<i64> {
+    fn strict_int(&self) -> ValResult<EitherInt> {
         Err(ValError::new(ErrorType::IntType, self))
     }
-    fn lax_int(&self) -> ValResult<i64> {
-        match self.parse() {
-            Ok(i) => Ok(i),
+    fn lax_int(&self, _coerce_bools: bool) -> ValResult<EitherInt> {
+        match self.parse::<i64>() {
+            Ok(i) => Ok(i.into()),
             Err(_) => Err(ValError::new(ErrorType::IntParsing, self)),
         }
     }
@@ -456,20 +478,20 @@ impl<'a> Input<'a> for String {
         self.validate_date(false)
     }
 
-    fn validate_time(&self, _strict: bool) -> ValResult<EitherTime> {
-        bytes_as_time(self, self.as_bytes())
+    fn validate_time(&self, _strict: bool, leap_second_mode: LeapSecondMode) -> ValResult<EitherTime> {
+        bytes_as_time(self, self.as_bytes(), leap_second_mode)
     }
     #[cfg_attr(has_no_coverage, no_coverage)]
-    fn strict_time(&self) -> ValResult<EitherTime> {
-        self.validate_time(false)
+    fn strict_time(&self, leap_second_mode: LeapSecondMode) -> ValResult<EitherTime> {
+        self.validate_time(false, leap_second_mode)
     }
 
-    fn validate_datetime(&self, _strict: bool) -> ValResult<EitherDateTime> {
-        bytes_as_datetime(self, self.as_bytes())
+    fn validate_datetime(&self, _strict: bool, leap_second_mode: LeapSecondMode) -> ValResult<EitherDateTime> {
+        bytes_as_datetime(self, self.as_bytes(), leap_second_mode)
     }
     #[cfg_attr(has_no_coverage, no_coverage)]
-    fn strict_datetime(&self) -> ValResult<EitherDateTime> {
-        self.validate_datetime(false)
+    fn strict_datetime(&self, leap_second_mode: LeapSecondMode) -> ValResult<EitherDateTime> {
+        self.validate_datetime(false, leap_second_mode)
     }
 
     fn validate_timedelta(&self, _strict: bool) -> ValResult<EitherTimedelta> {
@@ -481,6 +503,13 @@ impl<'a> Input<'a> for String {
     }
 }
 
-fn string_to_vec(s: &str) -> JsonArray {
-    s.chars().map(|c| JsonInput::String(c.to_string())).collect()
+fn string_to_vec(s: &str) -> JsonArray<'static> {
+    s.chars().map(|c| JsonInput::String(c.to_string().into())).collect()
+}
+
+fn big_int_as_float<'a>(input: &'a impl Input<'a>, big_int: &BigInt) -> ValResult<'a, f64> {
+    match big_int.to_f64() {
+        Some(f) if f.is_finite() => Ok(f),
+        _ => Err(ValError::new(ErrorType::FloatType, input)),
+    }
 }
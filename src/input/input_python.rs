@@ -1,11 +1,12 @@
 use std::borrow::Cow;
 use std::str::from_utf8;
 
+use pyo3::buffer::PyBuffer;
 use pyo3::once_cell::GILOnceCell;
 use pyo3::prelude::*;
 use pyo3::types::{
-    PyBool, PyByteArray, PyBytes, PyDate, PyDateTime, PyDelta, PyDict, PyFrozenSet, PyIterator, PyList, PyMapping,
-    PySet, PyString, PyTime, PyTuple, PyType,
+    PyBool, PyByteArray, PyBytes, PyDate, PyDateTime, PyDelta, PyDict, PyFrozenSet, PyIterator, PyList, PyLong,
+    PyMapping, PySet, PyString, PyTime, PyTuple, PyType,
 };
 #[cfg(not(PyPy))]
 use pyo3::types::{PyDictItems, PyDictKeys, PyDictValues};
@@ -13,17 +14,17 @@ use pyo3::{ffi, intern, AsPyPointer, PyTypeInfo};
 
 use crate::build_tools::safe_repr;
 use crate::errors::{ErrorType, InputValue, LocItem, ValError, ValLineError, ValResult};
-use crate::{PyMultiHostUrl, PyUrl};
+use crate::{PyMultiHostUrl, PyUrl, PydanticUndefinedType};
 
 use super::datetime::{
     bytes_as_date, bytes_as_datetime, bytes_as_time, bytes_as_timedelta, date_as_datetime, float_as_datetime,
     float_as_duration, float_as_time, int_as_datetime, int_as_duration, int_as_time, EitherDate, EitherDateTime,
-    EitherTime,
+    EitherTime, LeapSecondMode,
 };
 use super::input_abstract::InputType;
 use super::shared::{float_as_int, int_as_bool, map_json_err, str_as_bool, str_as_int};
 use super::{
-    py_error_on_minusone, py_string_str, EitherBytes, EitherString, EitherTimedelta, GenericArguments,
+    py_error_on_minusone, py_string_str, EitherBytes, EitherInt, EitherString, EitherTimedelta, GenericArguments,
     GenericCollection, GenericIterator, GenericMapping, Input, JsonInput, PyArgs,
 };
 
@@ -83,6 +84,10 @@ impl<'a> Input<'a> for PyAny {
         self.is_none()
     }
 
+    fn is_undefined(&self) -> bool {
+        PydanticUndefinedType::is_undefined(self)
+    }
+
     fn get_attr(&self, name: &PyString) -> Option<&PyAny> {
         self.getattr(name).ok()
     }
@@ -110,6 +115,13 @@ impl<'a> Input<'a> for PyAny {
         self.extract::<PyUrl>().ok()
     }
 
+    fn input_as_str_subclass(&'a self) -> Option<&'a PyString> {
+        match self.cast_as::<PyString>() {
+            Ok(py_str) if !is_builtin_str(py_str) => Some(py_str),
+            _ => None,
+        }
+    }
+
     fn input_as_multi_host_url(&self) -> Option<PyMultiHostUrl> {
         self.extract::<PyMultiHostUrl>().ok()
     }
@@ -172,16 +184,13 @@ impl<'a> Input<'a> for PyAny {
         }
     }
 
-    fn parse_json(&'a self) -> ValResult<'a, JsonInput> {
-        if let Ok(py_bytes) = self.cast_as::<PyBytes>() {
-            serde_json::from_slice(py_bytes.as_bytes()).map_err(|e| map_json_err(self, e))
-        } else if let Ok(py_str) = self.cast_as::<PyString>() {
-            let str = py_str.to_str()?;
-            serde_json::from_str(str).map_err(|e| map_json_err(self, e))
-        } else if let Ok(py_byte_array) = self.cast_as::<PyByteArray>() {
-            serde_json::from_slice(unsafe { py_byte_array.as_bytes() }).map_err(|e| map_json_err(self, e))
-        } else {
-            Err(ValError::new(ErrorType::JsonType, self))
+    fn parse_json(&'a self) -> ValResult<'a, JsonInput<'a>> {
+        match py_json_bytes(self)? {
+            Cow::Borrowed(bytes) => serde_json::from_slice(bytes).map_err(|e| map_json_err(self, e, bytes)),
+            Cow::Owned(bytes) => match serde_json::from_slice::<JsonInput>(&bytes) {
+                Ok(value) => Ok(value.into_static()),
+                Err(e) => Err(map_json_err(self, e, &bytes)),
+            },
         }
     }
 
@@ -241,6 +250,13 @@ impl<'a> Input<'a> for PyAny {
             Ok(str.as_bytes().into())
         } else if let Ok(py_byte_array) = self.cast_as::<PyByteArray>() {
             Ok(py_byte_array.to_vec().into())
+        } else if let Ok(buffer) = PyBuffer::<u8>::get(self) {
+            // any other object supporting the buffer protocol with unsigned bytes, e.g. `memoryview`,
+            // `array.array('B')` or a numpy `uint8` array, contiguous data is only copied into the output
+            match buffer.is_c_contiguous() {
+                true => Ok(buffer.into()),
+                false => Ok(buffer.to_vec(self.py())?.into()),
+            }
         } else {
             Err(ValError::new(ErrorType::BytesType, self))
         }
@@ -263,7 +279,8 @@ impl<'a> Input<'a> for PyAny {
             int_as_bool(self, int)
         } else if let Ok(float) = self.extract::<f64>() {
             match float_as_int(self, float) {
-                Ok(int) => int_as_bool(self, int),
+                Ok(EitherInt::I64(int)) => int_as_bool(self, int),
+                Ok(EitherInt::BigInt(_)) => Err(ValError::new(ErrorType::BoolParsing, self)),
                 _ => Err(ValError::new(ErrorType::BoolType, self)),
             }
         } else {
@@ -271,22 +288,33 @@ impl<'a> Input<'a> for PyAny {
         }
     }
 
-    fn strict_int(&self) -> ValResult<i64> {
+    fn strict_int(&self) -> ValResult<EitherInt> {
         // bool check has to come before int check as bools would be cast to ints below
         if self.extract::<bool>().is_ok() {
             Err(ValError::new(ErrorType::IntType, self))
         } else if let Ok(int) = self.extract::<i64>() {
-            Ok(int)
+            Ok(int.into())
+        } else if let Ok(int) = self.cast_as::<PyLong>() {
+            Ok(EitherInt::BigInt(int.extract()?))
         } else {
             Err(ValError::new(ErrorType::IntType, self))
         }
     }
 
-    fn lax_int(&self) -> ValResult<i64> {
-        if let Ok(int) = self.extract::<i64>() {
-            Ok(int)
+    fn lax_int(&self, coerce_bools: bool) -> ValResult<EitherInt> {
+        if let Ok(bool) = self.cast_as::<PyBool>() {
+            match coerce_bools {
+                true => Ok(EitherInt::I64(bool.is_true().into())),
+                false => Err(ValError::new(ErrorType::IntType, self)),
+            }
+        } else if let Ok(int) = self.extract::<i64>() {
+            Ok(int.into())
+        } else if let Ok(int) = self.cast_as::<PyLong>() {
+            Ok(EitherInt::BigInt(int.extract()?))
         } else if let Some(cow_str) = maybe_as_string(self, ErrorType::IntParsing)? {
             str_as_int(self, &cow_str)
+        } else if is_decimal(self) {
+            decimal_as_int(self)
         } else if let Ok(float) = self.extract::<f64>() {
             float_as_int(self, float)
         } else {
@@ -559,7 +587,7 @@ impl<'a> Input<'a> for PyAny {
         }
     }
 
-    fn strict_time(&self) -> ValResult<EitherTime> {
+    fn strict_time(&self, _leap_second_mode: LeapSecondMode) -> ValResult<EitherTime> {
         if let Ok(time) = self.cast_as::<PyTime>() {
             Ok(time.into())
         } else {
@@ -567,14 +595,14 @@ impl<'a> Input<'a> for PyAny {
         }
     }
 
-    fn lax_time(&self) -> ValResult<EitherTime> {
+    fn lax_time(&self, leap_second_mode: LeapSecondMode) -> ValResult<EitherTime> {
         if let Ok(time) = self.cast_as::<PyTime>() {
             Ok(time.into())
         } else if let Ok(py_str) = self.cast_as::<PyString>() {
             let str = py_string_str(py_str)?;
-            bytes_as_time(self, str.as_bytes())
+            bytes_as_time(self, str.as_bytes(), leap_second_mode)
         } else if let Ok(py_bytes) = self.cast_as::<PyBytes>() {
-            bytes_as_time(self, py_bytes.as_bytes())
+            bytes_as_time(self, py_bytes.as_bytes(), leap_second_mode)
         } else if self.cast_as::<PyBool>().is_ok() {
             Err(ValError::new(ErrorType::TimeType, self))
         } else if let Ok(int) = self.extract::<i64>() {
@@ -586,7 +614,7 @@ impl<'a> Input<'a> for PyAny {
         }
     }
 
-    fn strict_datetime(&self) -> ValResult<EitherDateTime> {
+    fn strict_datetime(&self, _leap_second_mode: LeapSecondMode) -> ValResult<EitherDateTime> {
         if let Ok(dt) = self.cast_as::<PyDateTime>() {
             Ok(dt.into())
         } else {
@@ -594,14 +622,14 @@ impl<'a> Input<'a> for PyAny {
         }
     }
 
-    fn lax_datetime(&self) -> ValResult<EitherDateTime> {
+    fn lax_datetime(&self, leap_second_mode: LeapSecondMode) -> ValResult<EitherDateTime> {
         if let Ok(dt) = self.cast_as::<PyDateTime>() {
             Ok(dt.into())
         } else if let Ok(py_str) = self.cast_as::<PyString>() {
             let str = py_string_str(py_str)?;
-            bytes_as_datetime(self, str.as_bytes())
+            bytes_as_datetime(self, str.as_bytes(), leap_second_mode)
         } else if let Ok(py_bytes) = self.cast_as::<PyBytes>() {
-            bytes_as_datetime(self, py_bytes.as_bytes())
+            bytes_as_datetime(self, py_bytes.as_bytes(), leap_second_mode)
         } else if self.cast_as::<PyBool>().is_ok() {
             Err(ValError::new(ErrorType::DatetimeType, self))
         } else if let Ok(int) = self.extract::<i64>() {
@@ -654,7 +682,6 @@ fn from_attributes_applicable(obj: &PyAny) -> bool {
     // I don't think it's a very good list at all! But it doesn't have to be at perfect, it just needs to avoid
     // the most egregious foot guns, it's mostly just to catch "builtins"
     // still happy to add more or do something completely different if anyone has a better idea???
-    // dbg!(obj, module_name);
     !matches!(module_name, "builtins" | "datetime" | "collections")
 }
 
@@ -673,6 +700,33 @@ fn maybe_as_string(v: &PyAny, unicode_error: ErrorType) -> ValResult<Option<Cow<
     }
 }
 
+/// `None` if `decimal` can't be imported, e.g. in an embedded interpreter without the module, no value is then
+/// treated as a `Decimal`
+static DECIMAL_TYPE: GILOnceCell<Option<Py<PyType>>> = GILOnceCell::new();
+
+fn is_decimal(v: &PyAny) -> bool {
+    let py = v.py();
+    match DECIMAL_TYPE.get_or_init(py, || import_type(py, "decimal", "Decimal").ok()) {
+        Some(decimal_type) => v.is_instance(decimal_type.as_ref(py)).unwrap_or(false),
+        None => false,
+    }
+}
+
+/// `Decimal`s are checked with `as_integer_ratio` which is exact, unlike converting them to a float
+fn decimal_as_int(decimal: &PyAny) -> ValResult<EitherInt> {
+    let py = decimal.py();
+    if !decimal.call_method0(intern!(py, "is_finite"))?.is_true()? {
+        return Err(ValError::new(ErrorType::FiniteNumber, decimal));
+    }
+    let (numerator, denominator): (&PyAny, &PyAny) =
+        decimal.call_method0(intern!(py, "as_integer_ratio"))?.extract()?;
+    if matches!(denominator.extract::<i64>(), Ok(1)) {
+        Ok(numerator.extract()?)
+    } else {
+        Err(ValError::new(ErrorType::IntFromFloatInexact, decimal))
+    }
+}
+
 static DEQUE_TYPE: GILOnceCell<Py<PyType>> = GILOnceCell::new();
 
 fn is_deque(v: &PyAny) -> bool {
@@ -691,3 +745,43 @@ fn import_type(py: Python, module: &str, attr: &str) -> PyResult<Py<PyType>> {
 fn is_builtin_str(py_str: &PyString) -> bool {
     py_str.get_type().is(PyString::type_object(py_str.py()))
 }
+
+/// The bytes of a JSON input, these are borrowed from the input where possible rather than copied
+pub fn py_json_bytes<'a>(input: &'a PyAny) -> ValResult<'a, Cow<'a, [u8]>> {
+    if let Ok(py_str) = input.cast_as::<PyString>() {
+        Ok(Cow::Borrowed(py_str.to_str()?.as_bytes()))
+    } else if let Ok(py_bytes) = input.cast_as::<PyBytes>() {
+        Ok(Cow::Borrowed(py_bytes.as_bytes()))
+    } else {
+        // parsed strings borrow from the data, `bytearray` and `memoryview` data is copied since it could be
+        // modified while the value is being validated
+        match py_bytes_like(input)? {
+            Some(bytes) => Ok(Cow::Owned(bytes.into_owned())),
+            None => Err(ValError::new(ErrorType::JsonType, input)),
+        }
+    }
+}
+
+/// The data of `bytes`, `bytearray` or `memoryview` inputs, or `None` for any other type
+pub fn py_bytes_like<'a>(input: &'a PyAny) -> PyResult<Option<Cow<'a, [u8]>>> {
+    if let Ok(py_bytes) = input.cast_as::<PyBytes>() {
+        Ok(Some(Cow::Borrowed(py_bytes.as_bytes())))
+    } else if let Ok(py_byte_array) = input.cast_as::<PyByteArray>() {
+        Ok(Some(Cow::Borrowed(unsafe { py_byte_array.as_bytes() })))
+    } else if unsafe { ffi::PyMemoryView_Check(input.as_ptr()) } == 1 {
+        // only memoryviews of bytes are supported, e.g. not a memoryview of an `array('i')`
+        let buffer: PyBuffer<u8> = match PyBuffer::get(input) {
+            Ok(buffer) => buffer,
+            Err(_) => return Ok(None),
+        };
+        if buffer.is_c_contiguous() {
+            // read directly from the buffer, the memoryview keeps it alive while `input` is borrowed
+            let bytes = unsafe { std::slice::from_raw_parts(buffer.buf_ptr() as *const u8, buffer.len_bytes()) };
+            Ok(Some(Cow::Borrowed(bytes)))
+        } else {
+            Ok(Some(Cow::Owned(buffer.to_vec(input.py())?)))
+        }
+    } else {
+        Ok(None)
+    }
+}
@@ -0,0 +1,313 @@
+use std::borrow::Cow;
+
+use indexmap::IndexMap;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyString};
+use pyo3::AsPyPointer;
+
+use crate::errors::{ErrorType, InputValue, LocItem, ValError, ValLineError, ValResult};
+use crate::recursion_guard::RecursionGuard;
+
+use super::datetime::{
+    bytes_as_date, bytes_as_datetime, bytes_as_time, bytes_as_timedelta, EitherDate, EitherDateTime, EitherTime,
+    EitherTimedelta, LeapSecondMode,
+};
+use super::input_abstract::InputType;
+use super::shared::{map_json_err, str_as_bool, str_as_int};
+use super::{
+    EitherBytes, EitherInt, EitherString, GenericArguments, GenericCollection, GenericIterator, GenericMapping, Input,
+    JsonInput, JsonType,
+};
+
+pub type StringMapping = IndexMap<Cow<'static, str>, StringInput>;
+
+/// Input for `SchemaValidator.validate_strings`, a string or a (possibly nested) dict of strings, e.g. as read from
+/// environment variables or command line arguments.
+///
+/// Every scalar type is parsed from strings, in strict mode as well as lax mode, and containers are parsed from
+/// strings holding a JSON array or object.
+#[derive(Debug)]
+pub enum StringInput {
+    String(String),
+    /// a string holding a JSON array or object, it's parsed up front so collections can borrow from it
+    Json(String, JsonInput<'static>),
+    Mapping(StringMapping),
+}
+
+impl StringInput {
+    pub fn from_py(input: &PyAny) -> ValResult<'_, Self> {
+        Self::from_py_guarded(input, &mut RecursionGuard::default())
+    }
+
+    /// `recursion_guard` stops a dict which contains itself, or very deeply nested dicts, exhausting the stack
+    fn from_py_guarded<'a>(input: &'a PyAny, recursion_guard: &mut RecursionGuard) -> ValResult<'a, Self> {
+        if let Ok(py_str) = input.cast_as::<PyString>() {
+            Ok(Self::from_string(py_str.to_str()?.to_string()))
+        } else if let Ok(py_dict) = input.cast_as::<PyDict>() {
+            let id = input.as_ptr() as usize;
+            if recursion_guard.contains_or_insert(id, 0) {
+                return Err(ValError::new(ErrorType::RecursionLoop, input));
+            }
+            let output = match recursion_guard.incr_depth() {
+                true => Err(ValError::new(ErrorType::RecursionLoop, input)),
+                false => Self::from_py_dict(py_dict, recursion_guard),
+            };
+            recursion_guard.remove(id, 0);
+            recursion_guard.decr_depth();
+            output
+        } else {
+            Err(ValError::new(ErrorType::StringType, input))
+        }
+    }
+
+    fn from_py_dict<'a>(py_dict: &'a PyDict, recursion_guard: &mut RecursionGuard) -> ValResult<'a, Self> {
+        let mut mapping = StringMapping::with_capacity(py_dict.len());
+        let mut errors: Vec<ValLineError> = Vec::new();
+        for (key, value) in py_dict {
+            let key: String = match key.cast_as::<PyString>() {
+                Ok(py_key) => py_key.to_str()?.to_string(),
+                Err(_) => {
+                    errors.push(ValLineError::new_with_loc(ErrorType::StringType, key, "[key]"));
+                    continue;
+                }
+            };
+            match Self::from_py_guarded(value, recursion_guard) {
+                Ok(value) => {
+                    mapping.insert(key.into(), value);
+                }
+                Err(ValError::LineErrors(line_errors)) => {
+                    let loc_item: LocItem = key.into();
+                    errors.extend(line_errors.into_iter().map(|e| e.with_outer_location(loc_item.clone())));
+                }
+                Err(err) => return Err(err),
+            }
+        }
+        match errors.is_empty() {
+            true => Ok(Self::Mapping(mapping)),
+            false => Err(ValError::LineErrors(errors)),
+        }
+    }
+
+    fn from_string(s: String) -> Self {
+        if s.trim_start().starts_with(['[', '{']) {
+            if let Ok(json @ (JsonInput::Array(_) | JsonInput::Object(_))) = serde_json::from_str(&s) {
+                let json = json.into_static();
+                return Self::Json(s, json);
+            }
+        }
+        Self::String(s)
+    }
+
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(s) | Self::Json(s, _) => Some(s),
+            Self::Mapping(_) => None,
+        }
+    }
+}
+
+impl ToPyObject for StringInput {
+    fn to_object(&self, py: Python<'_>) -> PyObject {
+        match self {
+            Self::String(s) | Self::Json(s, _) => s.into_py(py),
+            Self::Mapping(m) => {
+                let dict = PyDict::new(py);
+                for (k, v) in m.iter() {
+                    dict.set_item(k, v.to_object(py)).unwrap();
+                }
+                dict.into_py(py)
+            }
+        }
+    }
+}
+
+impl<'a> Input<'a> for StringInput {
+    fn get_type(&self) -> &'static InputType {
+        &InputType::String
+    }
+
+    fn as_loc_item(&self) -> LocItem {
+        match self.as_str() {
+            Some(s) => s.into(),
+            None => format!("{self:?}").into(),
+        }
+    }
+
+    fn as_error_value(&'a self) -> InputValue<'a> {
+        InputValue::StringInput(self)
+    }
+
+    fn is_none(&self) -> bool {
+        false
+    }
+
+    fn input_is_instance(&self, _class: &PyAny, json_mask: u8) -> PyResult<bool> {
+        let json_type = match self {
+            Self::String(_) | Self::Json(..) => JsonType::String,
+            Self::Mapping(_) => JsonType::Object,
+        };
+        Ok(json_type.matches(json_mask))
+    }
+
+    fn validate_args(&'a self) -> ValResult<'a, GenericArguments<'a>> {
+        Err(ValError::new(ErrorType::ArgumentsType, self))
+    }
+
+    fn parse_json(&'a self) -> ValResult<'a, JsonInput<'a>> {
+        match self.as_str() {
+            Some(s) => serde_json::from_str(s).map_err(|e| map_json_err(self, e, s.as_bytes())),
+            None => Err(ValError::new(ErrorType::JsonType, self)),
+        }
+    }
+
+    fn validate_str(&'a self, _strict: bool) -> ValResult<'a, EitherString<'a>> {
+        match self.as_str() {
+            Some(s) => Ok(s.into()),
+            None => Err(ValError::new(ErrorType::StringType, self)),
+        }
+    }
+    fn strict_str(&'a self) -> ValResult<'a, EitherString<'a>> {
+        self.validate_str(false)
+    }
+
+    fn validate_bytes(&'a self, _strict: bool) -> ValResult<'a, EitherBytes<'a>> {
+        match self.as_str() {
+            Some(s) => Ok(s.as_bytes().into()),
+            None => Err(ValError::new(ErrorType::BytesType, self)),
+        }
+    }
+    fn strict_bytes(&'a self) -> ValResult<'a, EitherBytes<'a>> {
+        self.validate_bytes(false)
+    }
+
+    fn validate_bool(&self, _strict: bool) -> ValResult<'_, bool> {
+        match self.as_str() {
+            Some(s) => str_as_bool(self, s.trim()),
+            None => Err(ValError::new(ErrorType::BoolType, self)),
+        }
+    }
+    fn strict_bool(&self) -> ValResult<'_, bool> {
+        self.validate_bool(false)
+    }
+
+    fn validate_int(&self, _strict: bool, _coerce_bools: bool) -> ValResult<'_, EitherInt> {
+        match self.as_str() {
+            Some(s) => str_as_int(self, s.trim()),
+            None => Err(ValError::new(ErrorType::IntType, self)),
+        }
+    }
+    fn strict_int(&self) -> ValResult<'_, EitherInt> {
+        self.validate_int(false, false)
+    }
+
+    fn validate_float(&self, _strict: bool) -> ValResult<'_, f64> {
+        match self.as_str() {
+            Some(s) => match s.trim().parse::<f64>() {
+                Ok(f) => Ok(f),
+                Err(_) => Err(ValError::new(ErrorType::FloatParsing, self)),
+            },
+            None => Err(ValError::new(ErrorType::FloatType, self)),
+        }
+    }
+    fn strict_float(&self) -> ValResult<'_, f64> {
+        self.validate_float(false)
+    }
+
+    fn validate_dict(&'a self, _strict: bool) -> ValResult<'a, GenericMapping<'a>> {
+        match self {
+            Self::Mapping(m) => Ok(m.into()),
+            Self::Json(_, JsonInput::Object(o)) => Ok(o.into()),
+            _ => Err(ValError::new(ErrorType::DictType, self)),
+        }
+    }
+    fn strict_dict(&'a self) -> ValResult<'a, GenericMapping<'a>> {
+        self.validate_dict(false)
+    }
+
+    fn validate_list(&'a self, _strict: bool, _allow_any_iter: bool) -> ValResult<'a, GenericCollection<'a>> {
+        match self {
+            Self::Json(_, JsonInput::Array(a)) => Ok(a.into()),
+            _ => Err(ValError::new(ErrorType::ListType, self)),
+        }
+    }
+    fn strict_list(&'a self) -> ValResult<'a, GenericCollection<'a>> {
+        self.validate_list(false, false)
+    }
+
+    fn validate_tuple(&'a self, _strict: bool) -> ValResult<'a, GenericCollection<'a>> {
+        match self {
+            Self::Json(_, JsonInput::Array(a)) => Ok(a.into()),
+            _ => Err(ValError::new(ErrorType::TupleType, self)),
+        }
+    }
+    fn strict_tuple(&'a self) -> ValResult<'a, GenericCollection<'a>> {
+        self.validate_tuple(false)
+    }
+
+    fn validate_set(&'a self, _strict: bool) -> ValResult<'a, GenericCollection<'a>> {
+        match self {
+            Self::Json(_, JsonInput::Array(a)) => Ok(a.into()),
+            _ => Err(ValError::new(ErrorType::SetType, self)),
+        }
+    }
+    fn strict_set(&'a self) -> ValResult<'a, GenericCollection<'a>> {
+        self.validate_set(false)
+    }
+
+    fn validate_frozenset(&'a self, _strict: bool) -> ValResult<'a, GenericCollection<'a>> {
+        match self {
+            Self::Json(_, JsonInput::Array(a)) => Ok(a.into()),
+            _ => Err(ValError::new(ErrorType::FrozenSetType, self)),
+        }
+    }
+    fn strict_frozenset(&'a self) -> ValResult<'a, GenericCollection<'a>> {
+        self.validate_frozenset(false)
+    }
+
+    fn validate_iter(&self) -> ValResult<'_, GenericIterator> {
+        match self {
+            Self::Json(_, JsonInput::Array(a)) => Ok(a.clone().into()),
+            _ => Err(ValError::new(ErrorType::IterableType, self)),
+        }
+    }
+
+    fn validate_date(&self, _strict: bool) -> ValResult<'_, EitherDate<'_>> {
+        match self.as_str() {
+            Some(s) => bytes_as_date(self, s.trim().as_bytes()),
+            None => Err(ValError::new(ErrorType::DateType, self)),
+        }
+    }
+    fn strict_date(&self) -> ValResult<'_, EitherDate<'_>> {
+        self.validate_date(false)
+    }
+
+    fn validate_time(&self, _strict: bool, leap_second_mode: LeapSecondMode) -> ValResult<'_, EitherTime<'_>> {
+        match self.as_str() {
+            Some(s) => bytes_as_time(self, s.trim().as_bytes(), leap_second_mode),
+            None => Err(ValError::new(ErrorType::TimeType, self)),
+        }
+    }
+    fn strict_time(&self, leap_second_mode: LeapSecondMode) -> ValResult<'_, EitherTime<'_>> {
+        self.validate_time(false, leap_second_mode)
+    }
+
+    fn validate_datetime(&self, _strict: bool, leap_second_mode: LeapSecondMode) -> ValResult<'_, EitherDateTime<'_>> {
+        match self.as_str() {
+            Some(s) => bytes_as_datetime(self, s.trim().as_bytes(), leap_second_mode),
+            None => Err(ValError::new(ErrorType::DatetimeType, self)),
+        }
+    }
+    fn strict_datetime(&self, leap_second_mode: LeapSecondMode) -> ValResult<'_, EitherDateTime<'_>> {
+        self.validate_datetime(false, leap_second_mode)
+    }
+
+    fn validate_timedelta(&self, _strict: bool) -> ValResult<'_, EitherTimedelta<'_>> {
+        match self.as_str() {
+            Some(s) => bytes_as_timedelta(self, s.trim().as_bytes()),
+            None => Err(ValError::new(ErrorType::TimeDeltaType, self)),
+        }
+    }
+    fn strict_timedelta(&self) -> ValResult<'_, EitherTimedelta<'_>> {
+        self.validate_timedelta(false)
+    }
+}
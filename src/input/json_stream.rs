@@ -0,0 +1,255 @@
+use pyo3::exceptions::PyTypeError;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyString};
+
+use crate::errors::ErrorType;
+
+use super::parse_json::JsonInput;
+use super::shared::json_invalid;
+
+/// Number of bytes requested from the readable on each call to `read()`
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// `Value` borrows from the stream's buffer, so it must be validated before the next item is read
+pub enum JsonStreamItem<'s> {
+    Value(JsonInput<'s>),
+    /// a single item couldn't be parsed, `raw` is the item's source
+    InvalidItem {
+        error: ErrorType,
+        raw: String,
+    },
+    /// the stream itself isn't valid, no more items are read after this
+    InvalidStream(ErrorType),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StreamState {
+    Start,
+    FirstItem,
+    NextItem,
+    End,
+    Done,
+}
+
+/// Reads a top-level JSON array (or newline delimited JSON values) from a python file-like object, one item at
+/// a time. Only the item currently being parsed is held in memory.
+///
+/// The stream is only scanned to find where each item ends, items are then parsed individually with `serde_json`.
+#[derive(Debug)]
+pub struct JsonStream {
+    readable: PyObject,
+    ndjson: bool,
+    buffer: Vec<u8>,
+    position: usize,
+    eof: bool,
+    state: StreamState,
+    index: usize,
+}
+
+impl JsonStream {
+    pub fn new(readable: &PyAny, ndjson: bool) -> Self {
+        Self {
+            readable: readable.into(),
+            ndjson,
+            buffer: Vec::new(),
+            position: 0,
+            eof: false,
+            state: match ndjson {
+                true => StreamState::NextItem,
+                false => StreamState::Start,
+            },
+            index: 0,
+        }
+    }
+
+    pub fn readable<'py>(&'py self, py: Python<'py>) -> &'py PyAny {
+        self.readable.as_ref(py)
+    }
+
+    /// the number of items read so far
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    pub fn next_item(&mut self, py: Python) -> PyResult<Option<JsonStreamItem<'_>>> {
+        // drop bytes from previous items, so memory use is bounded by the largest item
+        self.buffer.drain(..self.position);
+        self.position = 0;
+
+        let item = match self.state {
+            StreamState::Start => {
+                self.state = StreamState::FirstItem;
+                match self.next_non_whitespace(py)? {
+                    Some(b'[') => {
+                        self.position += 1;
+                        return self.next_item(py);
+                    }
+                    Some(_) => Some(self.invalid_stream("expected `[` at the start of the stream")),
+                    None => Some(self.invalid_stream("EOF while parsing a list")),
+                }
+            }
+            StreamState::FirstItem => match self.next_non_whitespace(py)? {
+                Some(b']') => {
+                    self.position += 1;
+                    self.state = StreamState::End;
+                    return self.next_item(py);
+                }
+                Some(_) => Some(self.read_value(py)?),
+                None => Some(self.invalid_stream("EOF while parsing a list")),
+            },
+            StreamState::NextItem if self.ndjson => match self.next_non_whitespace(py)? {
+                Some(_) => Some(self.read_value(py)?),
+                None => {
+                    self.state = StreamState::Done;
+                    None
+                }
+            },
+            StreamState::NextItem => match self.next_non_whitespace(py)? {
+                Some(b',') => {
+                    self.position += 1;
+                    match self.next_non_whitespace(py)? {
+                        Some(b']') => Some(self.invalid_stream("trailing comma")),
+                        Some(_) => Some(self.read_value(py)?),
+                        None => Some(self.invalid_stream("EOF while parsing a list")),
+                    }
+                }
+                Some(b']') => {
+                    self.position += 1;
+                    self.state = StreamState::End;
+                    return self.next_item(py);
+                }
+                Some(_) => Some(self.invalid_stream("expected `,` or `]`")),
+                None => Some(self.invalid_stream("EOF while parsing a list")),
+            },
+            StreamState::End => match self.next_non_whitespace(py)? {
+                Some(_) => Some(self.invalid_stream("trailing characters")),
+                None => {
+                    self.state = StreamState::Done;
+                    None
+                }
+            },
+            StreamState::Done => None,
+        };
+        Ok(item)
+    }
+
+    fn invalid_stream(&mut self, error: &str) -> JsonStreamItem<'static> {
+        self.state = StreamState::Done;
+        JsonStreamItem::InvalidStream(ErrorType::JsonInvalid {
+            error: error.to_string(),
+            position: None,
+        })
+    }
+
+    /// read a single value starting at `self.position`, then parse it
+    fn read_value(&mut self, py: Python) -> PyResult<JsonStreamItem<'_>> {
+        let start = self.position;
+        let end = self.find_value_end(py)?;
+        self.position = end;
+        if matches!(self.state, StreamState::FirstItem) {
+            self.state = StreamState::NextItem;
+        }
+        self.index += 1;
+
+        if end == start {
+            return Ok(self.invalid_stream("expected value"));
+        }
+        // checked before parsing as the value borrows from the buffer, which may need to be extended to check
+        let at_end = self.byte_at(py, end)?.is_none();
+        let bytes = &self.buffer[start..end];
+        match serde_json::from_slice(bytes) {
+            Ok(value) => Ok(JsonStreamItem::Value(value)),
+            Err(error) => {
+                let error = json_invalid(error, bytes);
+                let raw = String::from_utf8_lossy(bytes).into_owned();
+                // if the value was cut short by the end of the stream, there's nothing more to read
+                if at_end {
+                    self.state = StreamState::Done;
+                }
+                Ok(JsonStreamItem::InvalidItem { error, raw })
+            }
+        }
+    }
+
+    /// find the index after the end of the value starting at `self.position`, strings, objects and arrays are
+    /// matched up to their closing character, anything else runs until a delimiter
+    fn find_value_end(&mut self, py: Python) -> PyResult<usize> {
+        let mut index = self.position;
+        let mut depth = 0_usize;
+        let mut in_string = false;
+        while let Some(b) = self.byte_at(py, index)? {
+            if in_string {
+                match b {
+                    b'\\' => index += 1,
+                    b'"' => {
+                        in_string = false;
+                        if depth == 0 {
+                            return Ok(index + 1);
+                        }
+                    }
+                    _ => (),
+                }
+            } else {
+                match b {
+                    b'"' => in_string = true,
+                    b'[' | b'{' => depth += 1,
+                    b']' | b'}' if depth == 0 => return Ok(index),
+                    b']' | b'}' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            return Ok(index + 1);
+                        }
+                    }
+                    b',' | b' ' | b'\t' | b'\n' | b'\r' if depth == 0 => return Ok(index),
+                    _ => (),
+                }
+            }
+            index += 1;
+        }
+        Ok(index.min(self.buffer.len()))
+    }
+
+    fn next_non_whitespace(&mut self, py: Python) -> PyResult<Option<u8>> {
+        while let Some(b) = self.byte_at(py, self.position)? {
+            match b {
+                b' ' | b'\t' | b'\n' | b'\r' => self.position += 1,
+                _ => return Ok(Some(b)),
+            }
+        }
+        Ok(None)
+    }
+
+    fn byte_at(&mut self, py: Python, index: usize) -> PyResult<Option<u8>> {
+        while index >= self.buffer.len() {
+            if !self.fill(py)? {
+                return Ok(None);
+            }
+        }
+        Ok(Some(self.buffer[index]))
+    }
+
+    /// read the next chunk from the readable, returns false once it's exhausted
+    fn fill(&mut self, py: Python) -> PyResult<bool> {
+        if self.eof {
+            return Ok(false);
+        }
+        let chunk = self
+            .readable
+            .call_method1(py, intern!(py, "read"), (CHUNK_SIZE,))?
+            .into_ref(py);
+        let length = self.buffer.len();
+        if let Ok(py_bytes) = chunk.cast_as::<PyBytes>() {
+            self.buffer.extend_from_slice(py_bytes.as_bytes());
+        } else if let Ok(py_str) = chunk.cast_as::<PyString>() {
+            self.buffer.extend_from_slice(py_str.to_str()?.as_bytes());
+        } else {
+            return Err(PyTypeError::new_err(format!(
+                "read() should return str or bytes, not {}",
+                chunk.get_type().name()?
+            )));
+        }
+        self.eof = self.buffer.len() == length;
+        Ok(!self.eof)
+    }
+}
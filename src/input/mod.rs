@@ -6,21 +6,44 @@ mod datetime;
 mod input_abstract;
 mod input_json;
 mod input_python;
+mod input_string;
+mod json_stream;
+mod parse_cbor;
 mod parse_json;
+mod parse_msgpack;
+#[cfg(feature = "toml")]
+mod parse_toml;
+mod parse_urlencoded;
+#[cfg(feature = "yaml")]
+mod parse_yaml;
 mod return_enums;
 mod shared;
+mod strftime;
 
 pub(crate) use datetime::{
-    pydate_as_date, pydatetime_as_datetime, pytime_as_time, pytimedelta_as_duration, EitherDate, EitherDateTime,
-    EitherTime, EitherTimedelta,
+    check_rfc3339_date, check_rfc3339_datetime, date_as_timestamp, datetime_as_timestamp, named_timezone_datetime,
+    offset_to_string, pydate_as_date, pydatetime_as_datetime, pytime_as_time, pytime_offset, pytimedelta_as_duration,
+    time_as_timestamp, EitherDate, EitherDateTime, EitherTime, EitherTimedelta, LeapSecondMode,
 };
 pub(crate) use input_abstract::Input;
-pub(crate) use parse_json::{JsonInput, JsonObject, JsonType};
+pub(crate) use input_python::py_json_bytes;
+pub(crate) use input_string::{StringInput, StringMapping};
+pub(crate) use json_stream::{JsonStream, JsonStreamItem};
+pub(crate) use parse_cbor::py_parse_cbor;
+pub(crate) use parse_json::{complete_partial_json, JsonInput, JsonObject, JsonType};
+pub(crate) use parse_msgpack::py_parse_msgpack;
+#[cfg(feature = "toml")]
+pub(crate) use parse_toml::py_parse_toml;
+pub(crate) use parse_urlencoded::py_parse_urlencoded;
+#[cfg(feature = "yaml")]
+pub(crate) use parse_yaml::py_parse_yaml;
 pub(crate) use return_enums::{
-    py_string_str, AttributesGenericIterator, DictGenericIterator, EitherBytes, EitherString, GenericArguments,
-    GenericCollection, GenericIterator, GenericMapping, JsonArgs, JsonObjectGenericIterator, MappingGenericIterator,
-    PyArgs,
+    py_string_str, AttributesGenericIterator, DictGenericIterator, EitherBytes, EitherInt, EitherString,
+    GenericArguments, GenericCollection, GenericIterator, GenericMapping, JsonArgs, JsonObjectGenericIterator,
+    MappingGenericIterator, PyArgs, StringMappingGenericIterator,
 };
+pub(crate) use shared::json_invalid;
+pub(crate) use strftime::{FormatKind, InputFormats};
 
 // Defined here as it's not exported by pyo3
 pub fn py_error_on_minusone(py: Python<'_>, result: c_int) -> PyResult<()> {
@@ -0,0 +1,307 @@
+use indexmap::IndexMap;
+use num_bigint::{BigInt, Sign};
+use pyo3::prelude::*;
+use speedate::DateTime;
+
+use crate::errors::{ErrorType, ValError, ValResult};
+
+use super::datetime::utc_datetime_from_timestamp;
+use super::input_python::py_bytes_like;
+use super::parse_json::{JsonArray, JsonInput, JsonObject};
+
+/// same as serde_json's default recursion limit
+const RECURSION_LIMIT: usize = 128;
+
+/// the "break" stop code which ends indefinite length items
+const BREAK: u8 = 0xff;
+
+const TAG_DATETIME_STRING: u64 = 0;
+const TAG_EPOCH_DATETIME: u64 = 1;
+const TAG_POSITIVE_BIGNUM: u64 = 2;
+const TAG_NEGATIVE_BIGNUM: u64 = 3;
+
+/// Decode CBOR from a bytes-like python object.
+pub fn py_parse_cbor<'a>(input: &'a PyAny) -> ValResult<'a, JsonInput<'static>> {
+    let bytes = match py_bytes_like(input)? {
+        Some(bytes) => bytes,
+        None => return Err(ValError::new(ErrorType::CborType, input)),
+    };
+    parse_cbor(&bytes).map_err(|error| ValError::new(ErrorType::CborInvalid { error }, input))
+}
+
+/// Decode CBOR directly into `JsonInput`, byte strings become `JsonInput::Bytes`, date/time tags (0 and 1) become
+/// `JsonInput::DateTime` and bignum tags (2 and 3) become integers. Other tags are ignored and their content used
+/// as is, `undefined` is treated as `null`.
+pub fn parse_cbor(data: &[u8]) -> Result<JsonInput<'static>, String> {
+    let mut decoder = Decoder { data, position: 0 };
+    let value = decoder.value(0)?;
+    match decoder.position == data.len() {
+        true => Ok(value),
+        false => Err(format!("trailing data at offset {}", decoder.position)),
+    }
+}
+
+struct Decoder<'b> {
+    data: &'b [u8],
+    position: usize,
+}
+
+impl<'b> Decoder<'b> {
+    fn value(&mut self, depth: usize) -> Result<JsonInput<'static>, String> {
+        if depth > RECURSION_LIMIT {
+            return Err("recursion limit exceeded".to_string());
+        }
+        let start = self.position;
+        let initial = self.read_u8()?;
+        let major_type = initial >> 5;
+        let info = initial & 0x1f;
+        let value = match major_type {
+            0 => match self.argument(info, start)? {
+                Some(value) => uint(value),
+                None => return Err(format!("invalid indefinite length integer at offset {start}")),
+            },
+            1 => match self.argument(info, start)? {
+                Some(value) => negative_int(value),
+                None => return Err(format!("invalid indefinite length integer at offset {start}")),
+            },
+            2 => JsonInput::Bytes(self.bytes(major_type, info, start)?),
+            3 => {
+                let bytes = self.bytes(major_type, info, start)?;
+                match String::from_utf8(bytes) {
+                    Ok(s) => JsonInput::String(s.into()),
+                    Err(_) => return Err(format!("invalid UTF-8 in string at offset {start}")),
+                }
+            }
+            4 => self.array(info, start, depth)?,
+            5 => self.map(info, start, depth)?,
+            6 => match self.argument(info, start)? {
+                Some(tag) => self.tagged(tag, start, depth)?,
+                None => return Err(format!("invalid indefinite length tag at offset {start}")),
+            },
+            _ => match info {
+                20 => JsonInput::Bool(false),
+                21 => JsonInput::Bool(true),
+                22 | 23 => JsonInput::Null,
+                25 => JsonInput::Float(f16_to_f64(self.read_u16()?)),
+                26 => JsonInput::Float(f32::from_be_bytes(self.read_array()?) as f64),
+                27 => JsonInput::Float(f64::from_be_bytes(self.read_array()?)),
+                31 => return Err(format!("unexpected break at offset {start}")),
+                _ => return Err(format!("unsupported simple value at offset {start}")),
+            },
+        };
+        Ok(value)
+    }
+
+    /// the argument of a data item head, `None` for indefinite length items
+    fn argument(&mut self, info: u8, start: usize) -> Result<Option<u64>, String> {
+        let value = match info {
+            0..=23 => info as u64,
+            24 => self.read_u8()? as u64,
+            25 => self.read_u16()? as u64,
+            26 => u32::from_be_bytes(self.read_array()?) as u64,
+            27 => u64::from_be_bytes(self.read_array()?),
+            31 => return Ok(None),
+            _ => return Err(format!("invalid additional information {info} at offset {start}")),
+        };
+        Ok(Some(value))
+    }
+
+    fn length(&mut self, info: u8, start: usize) -> Result<Option<usize>, String> {
+        match self.argument(info, start)? {
+            Some(length) => match usize::try_from(length) {
+                Ok(length) => Ok(Some(length)),
+                Err(_) => Err(format!("length too large at offset {start}")),
+            },
+            None => Ok(None),
+        }
+    }
+
+    /// the content of a byte or text string, indefinite length strings are concatenated from their chunks
+    fn bytes(&mut self, major_type: u8, info: u8, start: usize) -> Result<Vec<u8>, String> {
+        match self.length(info, start)? {
+            Some(length) => Ok(self.read_bytes(length)?.to_vec()),
+            None => {
+                let mut bytes = Vec::new();
+                while !self.at_break()? {
+                    let chunk_start = self.position;
+                    let initial = self.read_u8()?;
+                    if initial >> 5 != major_type {
+                        return Err(format!(
+                            "invalid chunk in indefinite length string at offset {chunk_start}"
+                        ));
+                    }
+                    match self.length(initial & 0x1f, chunk_start)? {
+                        Some(length) => bytes.extend_from_slice(self.read_bytes(length)?),
+                        None => return Err(format!("nested indefinite length string at offset {chunk_start}")),
+                    }
+                }
+                Ok(bytes)
+            }
+        }
+    }
+
+    fn array(&mut self, info: u8, start: usize, depth: usize) -> Result<JsonInput<'static>, String> {
+        let array: JsonArray = match self.length(info, start)? {
+            Some(length) => {
+                // don't trust the length for the allocation, it may be far larger than the data
+                let mut array = Vec::with_capacity(length.min(self.remaining()));
+                for _ in 0..length {
+                    array.push(self.value(depth + 1)?);
+                }
+                array
+            }
+            None => {
+                let mut array = Vec::new();
+                while !self.at_break()? {
+                    array.push(self.value(depth + 1)?);
+                }
+                array
+            }
+        };
+        Ok(JsonInput::Array(array))
+    }
+
+    fn map(&mut self, info: u8, start: usize, depth: usize) -> Result<JsonInput<'static>, String> {
+        let mut object: JsonObject = IndexMap::new();
+        match self.length(info, start)? {
+            Some(length) => {
+                object.reserve(length.min(self.remaining()));
+                for _ in 0..length {
+                    self.map_item(&mut object, depth)?;
+                }
+            }
+            None => {
+                while !self.at_break()? {
+                    self.map_item(&mut object, depth)?;
+                }
+            }
+        }
+        Ok(JsonInput::Object(object))
+    }
+
+    fn map_item(&mut self, object: &mut JsonObject<'static>, depth: usize) -> Result<(), String> {
+        let key_start = self.position;
+        // integer keys are common in CBOR, they're kept as strings as they would be in JSON
+        let key = match self.value(depth + 1)? {
+            JsonInput::String(s) => s,
+            JsonInput::Int(i) => i.to_string().into(),
+            JsonInput::BigInt(i) => i.to_string().into(),
+            _ => return Err(format!("map keys must be strings or integers, at offset {key_start}")),
+        };
+        let value = self.value(depth + 1)?;
+        object.insert(key, value);
+        Ok(())
+    }
+
+    fn tagged(&mut self, tag: u64, start: usize, depth: usize) -> Result<JsonInput<'static>, String> {
+        let content = self.value(depth + 1)?;
+        let value = match (tag, content) {
+            (TAG_DATETIME_STRING, JsonInput::String(s)) => match DateTime::parse_str(&s) {
+                // python's `datetime` has no year 0
+                Ok(dt) if dt.date.year == 0 => return Err(format!("date/time string out of range at offset {start}")),
+                Ok(dt) => JsonInput::DateTime(dt),
+                Err(_) => return Err(format!("invalid date/time string at offset {start}")),
+            },
+            (TAG_EPOCH_DATETIME, JsonInput::Int(seconds)) => epoch_datetime(seconds, 0, start)?,
+            (TAG_EPOCH_DATETIME, JsonInput::Float(timestamp)) if timestamp.is_finite() => {
+                let seconds = timestamp.floor();
+                let microseconds = ((timestamp - seconds) * 1_000_000.0).round() as u32;
+                epoch_datetime(seconds as i64, microseconds, start)?
+            }
+            (TAG_POSITIVE_BIGNUM, JsonInput::Bytes(bytes)) => big_int_input(BigInt::from_bytes_be(Sign::Plus, &bytes)),
+            (TAG_NEGATIVE_BIGNUM, JsonInput::Bytes(bytes)) => {
+                big_int_input(-1 - BigInt::from_bytes_be(Sign::Plus, &bytes))
+            }
+            (TAG_DATETIME_STRING | TAG_EPOCH_DATETIME | TAG_POSITIVE_BIGNUM | TAG_NEGATIVE_BIGNUM, _) => {
+                return Err(format!("invalid content for tag {tag} at offset {start}"))
+            }
+            (_, content) => content,
+        };
+        Ok(value)
+    }
+
+    /// consume the break stop code if it's next, errors at the end of the data as indefinite length items
+    /// must be terminated
+    fn at_break(&mut self) -> Result<bool, String> {
+        match self.data.get(self.position) {
+            Some(&BREAK) => {
+                self.position += 1;
+                Ok(true)
+            }
+            Some(_) => Ok(false),
+            None => Err(format!("unexpected end of data at offset {}", self.data.len())),
+        }
+    }
+
+    fn remaining(&self) -> usize {
+        self.data.len() - self.position
+    }
+
+    fn read_bytes(&mut self, length: usize) -> Result<&'b [u8], String> {
+        let end = match self.position.checked_add(length) {
+            Some(end) if end <= self.data.len() => end,
+            _ => return Err(format!("unexpected end of data at offset {}", self.data.len())),
+        };
+        let bytes = &self.data[self.position..end];
+        self.position = end;
+        Ok(bytes)
+    }
+
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N], String> {
+        Ok(self.read_bytes(N)?.try_into().unwrap())
+    }
+
+    fn read_u8(&mut self) -> Result<u8, String> {
+        Ok(self.read_bytes(1)?[0])
+    }
+
+    fn read_u16(&mut self) -> Result<u16, String> {
+        Ok(u16::from_be_bytes(self.read_array()?))
+    }
+}
+
+fn uint(value: u64) -> JsonInput<'static> {
+    match i64::try_from(value) {
+        Ok(i) => JsonInput::Int(i),
+        Err(_) => JsonInput::BigInt(value.into()),
+    }
+}
+
+/// CBOR negative integers are encoded as `-1 - value`
+fn negative_int(value: u64) -> JsonInput<'static> {
+    match i64::try_from(value) {
+        Ok(i) => JsonInput::Int(-1 - i),
+        Err(_) => JsonInput::BigInt(-1 - BigInt::from(value)),
+    }
+}
+
+fn big_int_input(value: BigInt) -> JsonInput<'static> {
+    match i64::try_from(&value) {
+        Ok(i) => JsonInput::Int(i),
+        Err(_) => JsonInput::BigInt(value),
+    }
+}
+
+fn epoch_datetime(seconds: i64, microseconds: u32, start: usize) -> Result<JsonInput<'static>, String> {
+    // a float's fraction can round up to 1_000_000 microseconds, `utc_datetime_from_timestamp` carries it into seconds
+    match utc_datetime_from_timestamp(seconds, microseconds) {
+        Some(dt) => Ok(JsonInput::DateTime(dt)),
+        None => Err(format!("timestamp out of range at offset {start}")),
+    }
+}
+
+/// decode an IEEE 754 half precision float
+fn f16_to_f64(half: u16) -> f64 {
+    let exponent = (half >> 10) & 0x1f;
+    let mantissa = (half & 0x3ff) as f64;
+    let value = match exponent {
+        0 => mantissa * 2f64.powi(-24),
+        31 if mantissa == 0.0 => f64::INFINITY,
+        31 => f64::NAN,
+        _ => (mantissa + 1024.0) * 2f64.powi(exponent as i32 - 25),
+    };
+    match half & 0x8000 {
+        0 => value,
+        _ => -value,
+    }
+}
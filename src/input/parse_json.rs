@@ -1,12 +1,17 @@
+use std::borrow::Cow;
 use std::fmt;
 
 use indexmap::IndexMap;
+use num_bigint::BigInt;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyList, PySet};
+use pyo3::types::{PyBytes, PyDict, PyList, PySet, PyString};
 use serde::de::{Deserialize, DeserializeSeed, Error as SerdeError, MapAccess, SeqAccess, Visitor};
+use speedate::DateTime;
 
 use crate::build_tools::py_err;
 
+use super::EitherDateTime;
+
 #[derive(Copy, Clone, Debug)]
 pub enum JsonType {
     Null = 0b10000000,
@@ -46,101 +51,147 @@ impl TryFrom<&PyAny> for JsonType {
     }
 }
 
-/// similar to serde `Value` but with int and float split
+/// similar to serde `Value` but with int and float split, `Bytes` and `DateTime` can't come from JSON but are
+/// created when decoding MessagePack, decoders only create a `DateTime` within the range of python's `datetime`
+///
+/// Strings and object keys borrow from the data being parsed where they can (i.e. where they contain no escape
+/// sequences), so they're only copied when they're converted to python objects.
 #[derive(Clone, Debug)]
-pub enum JsonInput {
+pub enum JsonInput<'j> {
     Null,
     Bool(bool),
     Int(i64),
+    /// integers too large for `i64`, only used when they can't be represented exactly otherwise
+    BigInt(BigInt),
     Float(f64),
-    String(String),
-    Array(JsonArray),
-    Object(JsonObject),
+    String(Cow<'j, str>),
+    Array(JsonArray<'j>),
+    Object(JsonObject<'j>),
+    Bytes(Vec<u8>),
+    DateTime(DateTime),
 }
-pub type JsonArray = Vec<JsonInput>;
-pub type JsonObject = IndexMap<String, JsonInput>;
+pub type JsonArray<'j> = Vec<JsonInput<'j>>;
+pub type JsonObject<'j> = IndexMap<Cow<'j, str>, JsonInput<'j>>;
 
-impl ToPyObject for JsonInput {
+impl<'j> JsonInput<'j> {
+    /// Copy any borrowed strings so the value no longer depends on the data it was parsed from.
+    pub fn into_static(self) -> JsonInput<'static> {
+        match self {
+            Self::Null => JsonInput::Null,
+            Self::Bool(b) => JsonInput::Bool(b),
+            Self::Int(i) => JsonInput::Int(i),
+            Self::BigInt(b) => JsonInput::BigInt(b),
+            Self::Float(f) => JsonInput::Float(f),
+            Self::String(s) => JsonInput::String(Cow::Owned(s.into_owned())),
+            Self::Array(v) => JsonInput::Array(v.into_iter().map(Self::into_static).collect()),
+            Self::Object(o) => JsonInput::Object(
+                o.into_iter()
+                    .map(|(k, v)| (Cow::Owned(k.into_owned()), v.into_static()))
+                    .collect(),
+            ),
+            Self::Bytes(b) => JsonInput::Bytes(b),
+            Self::DateTime(dt) => JsonInput::DateTime(dt),
+        }
+    }
+}
+
+impl<'j> ToPyObject for JsonInput<'j> {
     fn to_object(&self, py: Python<'_>) -> PyObject {
         match self {
             Self::Null => py.None(),
             Self::Bool(b) => b.into_py(py),
             Self::Int(i) => i.into_py(py),
+            Self::BigInt(b) => b.to_object(py),
             Self::Float(f) => f.into_py(py),
-            Self::String(s) => s.into_py(py),
+            Self::String(s) => s.as_ref().into_py(py),
             Self::Array(v) => PyList::new(py, v.iter().map(|v| v.to_object(py))).into_py(py),
             Self::Object(o) => {
                 let dict = PyDict::new(py);
                 for (k, v) in o.iter() {
-                    dict.set_item(k, v.to_object(py)).unwrap();
+                    // keys are interned, so keys repeated across many objects share a single python string
+                    dict.set_item(PyString::intern(py, k), v.to_object(py)).unwrap();
                 }
                 dict.into_py(py)
             }
+            Self::Bytes(b) => PyBytes::new(py, b).into_py(py),
+            // can't fail as decoders check the datetime is in range
+            Self::DateTime(dt) => EitherDateTime::from(dt.clone()).try_into_py(py).unwrap(),
         }
     }
 }
 
-impl<'de> Deserialize<'de> for JsonInput {
-    fn deserialize<D>(deserializer: D) -> Result<JsonInput, D::Error>
+impl<'de> Deserialize<'de> for JsonInput<'de> {
+    fn deserialize<D>(deserializer: D) -> Result<JsonInput<'de>, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         struct JsonVisitor;
 
         impl<'de> Visitor<'de> for JsonVisitor {
-            type Value = JsonInput;
+            type Value = JsonInput<'de>;
 
             #[cfg_attr(has_no_coverage, no_coverage)]
             fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
                 formatter.write_str("any valid JSON value")
             }
 
-            fn visit_bool<E>(self, value: bool) -> Result<JsonInput, E> {
+            fn visit_bool<E>(self, value: bool) -> Result<JsonInput<'de>, E> {
                 Ok(JsonInput::Bool(value))
             }
 
-            fn visit_i64<E>(self, value: i64) -> Result<JsonInput, E> {
+            fn visit_i64<E>(self, value: i64) -> Result<JsonInput<'de>, E> {
                 Ok(JsonInput::Int(value))
             }
 
-            fn visit_u64<E>(self, value: u64) -> Result<JsonInput, E> {
-                Ok(JsonInput::Int(value as i64))
+            fn visit_u64<E>(self, value: u64) -> Result<JsonInput<'de>, E> {
+                match i64::try_from(value) {
+                    Ok(i) => Ok(JsonInput::Int(i)),
+                    Err(_) => Ok(JsonInput::BigInt(value.into())),
+                }
             }
 
-            fn visit_f64<E>(self, value: f64) -> Result<JsonInput, E> {
+            fn visit_f64<E>(self, value: f64) -> Result<JsonInput<'de>, E> {
                 Ok(JsonInput::Float(value))
             }
 
-            fn visit_str<E>(self, value: &str) -> Result<JsonInput, E>
+            fn visit_borrowed_str<E>(self, value: &'de str) -> Result<JsonInput<'de>, E>
             where
                 E: SerdeError,
             {
-                Ok(JsonInput::String(value.to_string()))
+                Ok(JsonInput::String(Cow::Borrowed(value)))
             }
 
-            #[cfg_attr(has_no_coverage, no_coverage)]
-            fn visit_string<E>(self, _: String) -> Result<JsonInput, E> {
-                unreachable!()
+            // used for strings containing escape sequences, which can't be borrowed
+            fn visit_str<E>(self, value: &str) -> Result<JsonInput<'de>, E>
+            where
+                E: SerdeError,
+            {
+                Ok(JsonInput::String(Cow::Owned(value.to_string())))
+            }
+
+            // used for the string value of numbers with `arbitrary_precision`
+            fn visit_string<E>(self, value: String) -> Result<JsonInput<'de>, E> {
+                Ok(JsonInput::String(Cow::Owned(value)))
             }
 
             #[cfg_attr(has_no_coverage, no_coverage)]
-            fn visit_none<E>(self) -> Result<JsonInput, E> {
+            fn visit_none<E>(self) -> Result<JsonInput<'de>, E> {
                 unreachable!()
             }
 
             #[cfg_attr(has_no_coverage, no_coverage)]
-            fn visit_some<D>(self, _: D) -> Result<JsonInput, D::Error>
+            fn visit_some<D>(self, _: D) -> Result<JsonInput<'de>, D::Error>
             where
                 D: serde::Deserializer<'de>,
             {
                 unreachable!()
             }
 
-            fn visit_unit<E>(self) -> Result<JsonInput, E> {
+            fn visit_unit<E>(self) -> Result<JsonInput<'de>, E> {
                 Ok(JsonInput::Null)
             }
 
-            fn visit_seq<V>(self, mut visitor: V) -> Result<JsonInput, V::Error>
+            fn visit_seq<V>(self, mut visitor: V) -> Result<JsonInput<'de>, V::Error>
             where
                 V: SeqAccess<'de>,
             {
@@ -153,15 +204,25 @@ impl<'de> Deserialize<'de> for JsonInput {
                 Ok(JsonInput::Array(vec))
             }
 
-            fn visit_map<V>(self, mut visitor: V) -> Result<JsonInput, V::Error>
+            fn visit_map<V>(self, mut visitor: V) -> Result<JsonInput<'de>, V::Error>
             where
                 V: MapAccess<'de>,
             {
                 match visitor.next_key_seed(KeyDeserializer)? {
                     Some(first_key) => {
-                        let mut values = IndexMap::new();
+                        let first_value: JsonInput<'de> = visitor.next_value()?;
+                        // with `arbitrary_precision`, serde_json passes every number as a map with this single key
+                        // and the number's original string as its value
+                        if first_key == SERDE_JSON_NUMBER {
+                            if let JsonInput::String(number) = &first_value {
+                                if let Some(value) = parse_number(number) {
+                                    return Ok(value);
+                                }
+                            }
+                        }
 
-                        values.insert(first_key, visitor.next_value()?);
+                        let mut values = IndexMap::new();
+                        values.insert(first_key, first_value);
                         while let Some((key, value)) = visitor.next_entry()? {
                             values.insert(key, value);
                         }
@@ -176,10 +237,22 @@ impl<'de> Deserialize<'de> for JsonInput {
     }
 }
 
+const SERDE_JSON_NUMBER: &str = "$serde_json::private::Number";
+
+fn parse_number(number: &str) -> Option<JsonInput<'static>> {
+    if number.contains(['.', 'e', 'E']) {
+        number.parse().ok().map(JsonInput::Float)
+    } else if let Ok(int) = number.parse() {
+        Some(JsonInput::Int(int))
+    } else {
+        number.parse().ok().map(JsonInput::BigInt)
+    }
+}
+
 struct KeyDeserializer;
 
 impl<'de> DeserializeSeed<'de> for KeyDeserializer {
-    type Value = String;
+    type Value = Cow<'de, str>;
 
     fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
     where
@@ -190,18 +263,25 @@ impl<'de> DeserializeSeed<'de> for KeyDeserializer {
 }
 
 impl<'de> Visitor<'de> for KeyDeserializer {
-    type Value = String;
+    type Value = Cow<'de, str>;
 
     #[cfg_attr(has_no_coverage, no_coverage)]
     fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
         formatter.write_str("a string key")
     }
 
+    fn visit_borrowed_str<E>(self, s: &'de str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(Cow::Borrowed(s))
+    }
+
     fn visit_str<E>(self, s: &str) -> Result<Self::Value, E>
     where
         E: serde::de::Error,
     {
-        Ok(s.to_string())
+        Ok(Cow::Owned(s.to_string()))
     }
 
     #[cfg_attr(has_no_coverage, no_coverage)]
@@ -212,3 +292,114 @@ impl<'de> Visitor<'de> for KeyDeserializer {
         unreachable!()
     }
 }
+
+#[derive(Debug, Clone, Copy)]
+enum PartialFrame {
+    Array,
+    /// `awaiting_value` is set once a key has been read, until its value is complete
+    Object {
+        awaiting_value: bool,
+    },
+}
+
+/// Complete JSON which was cut off part way through, e.g. from an incomplete stream: a string value which is still
+/// open is closed, then any open arrays and objects. A trailing key, number or literal which may be incomplete is
+/// dropped, along with anything after the last complete value. `None` if there's no complete prefix to use.
+pub fn complete_partial_json(data: &[u8]) -> Option<Vec<u8>> {
+    let mut stack: Vec<PartialFrame> = Vec::new();
+    // the end of the longest prefix which is valid once the frames open at that point are closed
+    let mut complete: Option<(usize, Vec<PartialFrame>)> = None;
+    // where the current string started, and whether it's an object key
+    let mut string: Option<(usize, bool)> = None;
+    // the start of an escape sequence in the current string, and how many more bytes it needs
+    let mut escape: Option<(usize, u8)> = None;
+    let mut in_scalar = false;
+
+    macro_rules! value_complete {
+        ($end:expr) => {{
+            if let Some(PartialFrame::Object { awaiting_value }) = stack.last_mut() {
+                *awaiting_value = false;
+            }
+            complete = Some(($end, stack.clone()));
+        }};
+    }
+
+    for (index, byte) in data.iter().copied().enumerate() {
+        if let Some((_, is_key)) = string {
+            match escape {
+                Some((start, 0)) => escape = (byte == b'u').then_some((start, 4)),
+                Some((start, remaining)) => escape = (remaining > 1).then_some((start, remaining - 1)),
+                None if byte == b'\\' => escape = Some((index, 0)),
+                None if byte == b'"' => {
+                    string = None;
+                    if !is_key {
+                        value_complete!(index + 1);
+                    }
+                }
+                None => (),
+            }
+            continue;
+        }
+        if in_scalar {
+            if !matches!(byte, b',' | b':' | b']' | b'}' | b' ' | b'\t' | b'\n' | b'\r') {
+                continue;
+            }
+            in_scalar = false;
+            value_complete!(index);
+        }
+        let is_whitespace = matches!(byte, b' ' | b'\t' | b'\n' | b'\r');
+        if stack.is_empty() && complete.is_some() && !is_whitespace {
+            // there's more after a complete top-level value, so this isn't just incomplete JSON
+            return None;
+        }
+        match byte {
+            b'"' => {
+                let is_key = matches!(stack.last(), Some(PartialFrame::Object { awaiting_value: false }));
+                string = Some((index, is_key));
+            }
+            b'[' | b'{' => {
+                stack.push(match byte {
+                    b'[' => PartialFrame::Array,
+                    _ => PartialFrame::Object { awaiting_value: false },
+                });
+                complete = Some((index + 1, stack.clone()));
+            }
+            b']' | b'}' => {
+                stack.pop()?;
+                value_complete!(index + 1);
+            }
+            b':' => {
+                if let Some(PartialFrame::Object { awaiting_value }) = stack.last_mut() {
+                    *awaiting_value = true;
+                }
+            }
+            b',' => (),
+            _ if is_whitespace => (),
+            _ => in_scalar = true,
+        }
+    }
+
+    let (mut output, frames) = match string {
+        // a string value which was cut off is kept, without any incomplete escape sequence or character
+        Some((start, false)) => {
+            let end = escape.map_or(data.len(), |(escape_start, _)| escape_start);
+            let end = match std::str::from_utf8(&data[start..end]) {
+                Ok(_) => end,
+                Err(e) if e.error_len().is_none() => start + e.valid_up_to(),
+                Err(_) => return None,
+            };
+            let mut output = data[..end].to_vec();
+            output.push(b'"');
+            (output, stack)
+        }
+        _ => {
+            let (end, frames) = complete?;
+            (data[..end].to_vec(), frames)
+        }
+    };
+    output.extend(frames.iter().rev().map(|frame| match frame {
+        PartialFrame::Array => b']',
+        PartialFrame::Object { .. } => b'}',
+    }));
+    Some(output)
+}
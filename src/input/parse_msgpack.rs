@@ -0,0 +1,226 @@
+use indexmap::IndexMap;
+use pyo3::prelude::*;
+
+use crate::errors::{ErrorType, ValError, ValResult};
+
+use super::datetime::utc_datetime_from_timestamp;
+use super::input_python::py_bytes_like;
+use super::parse_json::{JsonArray, JsonInput, JsonObject};
+
+/// same as serde_json's default recursion limit
+const RECURSION_LIMIT: usize = 128;
+
+/// the MessagePack extension type reserved for timestamps
+const TIMESTAMP_EXT_TYPE: i8 = -1;
+
+/// Decode MessagePack from a bytes-like python object.
+pub fn py_parse_msgpack<'a>(input: &'a PyAny) -> ValResult<'a, JsonInput<'static>> {
+    let bytes = match py_bytes_like(input)? {
+        Some(bytes) => bytes,
+        None => return Err(ValError::new(ErrorType::MsgpackType, input)),
+    };
+    parse_msgpack(&bytes).map_err(|error| ValError::new(ErrorType::MsgpackInvalid { error }, input))
+}
+
+/// Decode MessagePack directly into `JsonInput`, `bin` values become `JsonInput::Bytes` and timestamp extension
+/// values become `JsonInput::DateTime` in UTC, other extension types aren't supported.
+pub fn parse_msgpack(data: &[u8]) -> Result<JsonInput<'static>, String> {
+    let mut decoder = Decoder { data, position: 0 };
+    let value = decoder.value(0)?;
+    match decoder.position == data.len() {
+        true => Ok(value),
+        false => Err(format!("trailing data at offset {}", decoder.position)),
+    }
+}
+
+struct Decoder<'b> {
+    data: &'b [u8],
+    position: usize,
+}
+
+impl<'b> Decoder<'b> {
+    fn value(&mut self, depth: usize) -> Result<JsonInput<'static>, String> {
+        if depth > RECURSION_LIMIT {
+            return Err("recursion limit exceeded".to_string());
+        }
+        let start = self.position;
+        let marker = self.read_u8()?;
+        let value = match marker {
+            0x00..=0x7f => JsonInput::Int(marker as i64),
+            0x80..=0x8f => self.map((marker & 0x0f) as usize, depth)?,
+            0x90..=0x9f => self.array((marker & 0x0f) as usize, depth)?,
+            0xa0..=0xbf => self.str((marker & 0x1f) as usize)?,
+            0xc0 => JsonInput::Null,
+            0xc2 => JsonInput::Bool(false),
+            0xc3 => JsonInput::Bool(true),
+            0xc4 => {
+                let length = self.read_u8()? as usize;
+                JsonInput::Bytes(self.read_bytes(length)?.to_vec())
+            }
+            0xc5 => {
+                let length = self.read_u16()? as usize;
+                JsonInput::Bytes(self.read_bytes(length)?.to_vec())
+            }
+            0xc6 => {
+                let length = self.read_u32()? as usize;
+                JsonInput::Bytes(self.read_bytes(length)?.to_vec())
+            }
+            0xc7 => {
+                let length = self.read_u8()? as usize;
+                self.ext(length)?
+            }
+            0xc8 => {
+                let length = self.read_u16()? as usize;
+                self.ext(length)?
+            }
+            0xc9 => {
+                let length = self.read_u32()? as usize;
+                self.ext(length)?
+            }
+            0xca => JsonInput::Float(f32::from_be_bytes(self.read_array()?) as f64),
+            0xcb => JsonInput::Float(f64::from_be_bytes(self.read_array()?)),
+            0xcc => JsonInput::Int(self.read_u8()? as i64),
+            0xcd => JsonInput::Int(self.read_u16()? as i64),
+            0xce => JsonInput::Int(self.read_u32()? as i64),
+            0xcf => {
+                let value = u64::from_be_bytes(self.read_array()?);
+                match i64::try_from(value) {
+                    Ok(i) => JsonInput::Int(i),
+                    Err(_) => JsonInput::BigInt(value.into()),
+                }
+            }
+            0xd0 => JsonInput::Int(i8::from_be_bytes(self.read_array()?) as i64),
+            0xd1 => JsonInput::Int(i16::from_be_bytes(self.read_array()?) as i64),
+            0xd2 => JsonInput::Int(i32::from_be_bytes(self.read_array()?) as i64),
+            0xd3 => JsonInput::Int(i64::from_be_bytes(self.read_array()?)),
+            0xd4 => self.ext(1)?,
+            0xd5 => self.ext(2)?,
+            0xd6 => self.ext(4)?,
+            0xd7 => self.ext(8)?,
+            0xd8 => self.ext(16)?,
+            0xd9 => {
+                let length = self.read_u8()? as usize;
+                self.str(length)?
+            }
+            0xda => {
+                let length = self.read_u16()? as usize;
+                self.str(length)?
+            }
+            0xdb => {
+                let length = self.read_u32()? as usize;
+                self.str(length)?
+            }
+            0xdc => {
+                let length = self.read_u16()? as usize;
+                self.array(length, depth)?
+            }
+            0xdd => {
+                let length = self.read_u32()? as usize;
+                self.array(length, depth)?
+            }
+            0xde => {
+                let length = self.read_u16()? as usize;
+                self.map(length, depth)?
+            }
+            0xdf => {
+                let length = self.read_u32()? as usize;
+                self.map(length, depth)?
+            }
+            0xe0..=0xff => JsonInput::Int((marker as i8) as i64),
+            0xc1 => return Err(format!("invalid marker 0xc1 at offset {start}")),
+        };
+        Ok(value)
+    }
+
+    fn str(&mut self, length: usize) -> Result<JsonInput<'static>, String> {
+        let start = self.position;
+        match std::str::from_utf8(self.read_bytes(length)?) {
+            Ok(s) => Ok(JsonInput::String(s.to_string().into())),
+            Err(_) => Err(format!("invalid UTF-8 in string at offset {start}")),
+        }
+    }
+
+    fn array(&mut self, length: usize, depth: usize) -> Result<JsonInput<'static>, String> {
+        // don't trust the length for the allocation, it may be far larger than the data
+        let mut array: JsonArray = Vec::with_capacity(length.min(self.remaining()));
+        for _ in 0..length {
+            array.push(self.value(depth + 1)?);
+        }
+        Ok(JsonInput::Array(array))
+    }
+
+    fn map(&mut self, length: usize, depth: usize) -> Result<JsonInput<'static>, String> {
+        let mut object: JsonObject = IndexMap::with_capacity(length.min(self.remaining()));
+        for _ in 0..length {
+            let key_start = self.position;
+            // integer keys are common in MessagePack, they're kept as strings as they would be in JSON
+            let key = match self.value(depth + 1)? {
+                JsonInput::String(s) => s,
+                JsonInput::Int(i) => i.to_string().into(),
+                JsonInput::BigInt(i) => i.to_string().into(),
+                _ => return Err(format!("map keys must be strings or integers, at offset {key_start}")),
+            };
+            let value = self.value(depth + 1)?;
+            object.insert(key, value);
+        }
+        Ok(JsonInput::Object(object))
+    }
+
+    fn ext(&mut self, length: usize) -> Result<JsonInput<'static>, String> {
+        let start = self.position;
+        let ext_type = self.read_u8()? as i8;
+        let data = self.read_bytes(length)?;
+        if ext_type != TIMESTAMP_EXT_TYPE {
+            return Err(format!("unsupported extension type {ext_type} at offset {start}"));
+        }
+        let (seconds, nanoseconds) = match data.len() {
+            4 => (u32::from_be_bytes(data.try_into().unwrap()) as i64, 0),
+            8 => {
+                let value = u64::from_be_bytes(data.try_into().unwrap());
+                ((value & 0x3_ffff_ffff) as i64, (value >> 34) as u32)
+            }
+            12 => (
+                i64::from_be_bytes(data[4..].try_into().unwrap()),
+                u32::from_be_bytes(data[..4].try_into().unwrap()),
+            ),
+            _ => return Err(format!("invalid timestamp length {} at offset {start}", data.len())),
+        };
+        if nanoseconds >= 1_000_000_000 {
+            return Err(format!("invalid timestamp nanoseconds at offset {start}"));
+        }
+        match utc_datetime_from_timestamp(seconds, nanoseconds / 1_000) {
+            Some(dt) => Ok(JsonInput::DateTime(dt)),
+            None => Err(format!("timestamp out of range at offset {start}")),
+        }
+    }
+
+    fn remaining(&self) -> usize {
+        self.data.len() - self.position
+    }
+
+    fn read_bytes(&mut self, length: usize) -> Result<&'b [u8], String> {
+        let end = match self.position.checked_add(length) {
+            Some(end) if end <= self.data.len() => end,
+            _ => return Err(format!("unexpected end of data at offset {}", self.data.len())),
+        };
+        let bytes = &self.data[self.position..end];
+        self.position = end;
+        Ok(bytes)
+    }
+
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N], String> {
+        Ok(self.read_bytes(N)?.try_into().unwrap())
+    }
+
+    fn read_u8(&mut self) -> Result<u8, String> {
+        Ok(self.read_bytes(1)?[0])
+    }
+
+    fn read_u16(&mut self) -> Result<u16, String> {
+        Ok(u16::from_be_bytes(self.read_array()?))
+    }
+
+    fn read_u32(&mut self) -> Result<u32, String> {
+        Ok(u32::from_be_bytes(self.read_array()?))
+    }
+}
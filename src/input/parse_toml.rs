@@ -0,0 +1,46 @@
+use pyo3::prelude::*;
+use pyo3::types::PyString;
+use speedate::DateTime;
+use toml::Value;
+
+use crate::errors::{ErrorType, ValError, ValResult};
+
+use super::parse_json::{JsonInput, JsonObject};
+
+/// Parse a TOML document from a python string.
+pub fn py_parse_toml<'a>(input: &'a PyAny) -> ValResult<'a, JsonInput<'static>> {
+    let py_str = match input.cast_as::<PyString>() {
+        Ok(py_str) => py_str,
+        Err(_) => return Err(ValError::new(ErrorType::TomlType, input)),
+    };
+    match toml::from_str::<Value>(py_str.to_str()?) {
+        Ok(value) => Ok(toml_as_input(value)),
+        Err(e) => Err(ValError::new(ErrorType::TomlInvalid { error: e.to_string() }, input)),
+    }
+}
+
+/// Offset and local date-times become `JsonInput::DateTime`, local dates and times are kept as strings which
+/// are parsed by the date and time validators as they would be from JSON.
+fn toml_as_input(value: Value) -> JsonInput<'static> {
+    match value {
+        Value::String(s) => JsonInput::String(s.into()),
+        Value::Integer(i) => JsonInput::Int(i),
+        Value::Float(f) => JsonInput::Float(f),
+        Value::Boolean(b) => JsonInput::Bool(b),
+        Value::Datetime(dt) => {
+            let s = dt.to_string();
+            match (dt.date, dt.time) {
+                (Some(_), Some(_)) => match DateTime::parse_str(&s) {
+                    Ok(dt) => JsonInput::DateTime(dt),
+                    Err(_) => JsonInput::String(s.into()),
+                },
+                _ => JsonInput::String(s.into()),
+            }
+        }
+        Value::Array(array) => JsonInput::Array(array.into_iter().map(toml_as_input).collect()),
+        Value::Table(table) => {
+            let object: JsonObject = table.into_iter().map(|(k, v)| (k.into(), toml_as_input(v))).collect();
+            JsonInput::Object(object)
+        }
+    }
+}
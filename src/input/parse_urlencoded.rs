@@ -0,0 +1,143 @@
+use pyo3::prelude::*;
+use pyo3::types::PyString;
+use url::form_urlencoded;
+
+use crate::errors::{ErrorType, ValError, ValResult};
+
+use super::input_python::py_bytes_like;
+use super::parse_json::{JsonArray, JsonInput, JsonObject};
+
+/// same as the msgpack and CBOR decoders, limits how deeply bracketed keys can nest
+const RECURSION_LIMIT: usize = 128;
+
+/// Parse an `application/x-www-form-urlencoded` body or query string from a python `str` or bytes-like object.
+pub fn py_parse_urlencoded<'a>(input: &'a PyAny) -> ValResult<'a, JsonInput<'static>> {
+    let result = if let Ok(py_str) = input.cast_as::<PyString>() {
+        parse_urlencoded(py_str.to_str()?.as_bytes())
+    } else {
+        match py_bytes_like(input)? {
+            Some(bytes) => parse_urlencoded(&bytes),
+            None => return Err(ValError::new(ErrorType::UrlencodedType, input)),
+        }
+    };
+    result.map_err(|error| ValError::new(ErrorType::UrlencodedInvalid { error }, input))
+}
+
+/// Values are always strings, keys which are repeated collect their values in a list, e.g. `a=1&a=2`.
+///
+/// Bracketed keys build nested structures:
+/// * `a[b]=1` sets key `b` of object `a`
+/// * `a[]=1` appends to list `a`, even if there's only one value
+/// * `a[][b]=1` sets key `b` of the last object in list `a`, a new object is started if `b` is already set
+pub fn parse_urlencoded(data: &[u8]) -> Result<JsonInput<'static>, String> {
+    let mut object = JsonObject::new();
+    for (key, value) in form_urlencoded::parse(data) {
+        let (name, path) = split_key(&key);
+        if path.len() > RECURSION_LIMIT {
+            return Err("recursion limit exceeded".to_string());
+        }
+        insert(&mut object, name, &path, value.into_owned())?;
+    }
+    Ok(JsonInput::Object(object))
+}
+
+#[derive(Debug)]
+enum Segment<'k> {
+    Key(&'k str),
+    Append,
+}
+
+/// split `a[b][]` into `a` and `[Key("b"), Append]`, keys which aren't of that form are used as is
+fn split_key(key: &str) -> (&str, Vec<Segment<'_>>) {
+    let start = match key.find('[') {
+        Some(start) if start > 0 => start,
+        _ => return (key, Vec::new()),
+    };
+    let mut path = Vec::new();
+    let mut rest = &key[start..];
+    while let Some(tail) = rest.strip_prefix('[') {
+        let end = match tail.find(']') {
+            Some(end) => end,
+            None => return (key, Vec::new()),
+        };
+        path.push(match &tail[..end] {
+            "" => Segment::Append,
+            k => Segment::Key(k),
+        });
+        rest = &tail[end + 1..];
+    }
+    match rest.is_empty() {
+        true => (&key[..start], path),
+        false => (key, Vec::new()),
+    }
+}
+
+fn insert(object: &mut JsonObject<'static>, key: &str, path: &[Segment], value: String) -> Result<(), String> {
+    match path.split_first() {
+        None => match object.get_mut(key) {
+            None => {
+                object.insert(key.to_string().into(), JsonInput::String(value.into()));
+            }
+            Some(JsonInput::Array(array)) => array.push(JsonInput::String(value.into())),
+            Some(existing @ JsonInput::String(_)) => {
+                let first = std::mem::replace(existing, JsonInput::Null);
+                *existing = JsonInput::Array(vec![first, JsonInput::String(value.into())]);
+            }
+            Some(_) => return Err(conflict(key)),
+        },
+        Some((Segment::Append, rest)) => {
+            let existing = object
+                .entry(key.to_string().into())
+                .or_insert_with(|| JsonInput::Array(JsonArray::new()));
+            // a previous plain value is kept as the first item, as it is for repeated plain keys
+            if let JsonInput::String(_) = existing {
+                let first = std::mem::replace(existing, JsonInput::Null);
+                *existing = JsonInput::Array(vec![first]);
+            }
+            match existing {
+                JsonInput::Array(array) => append(array, key, rest, value)?,
+                _ => return Err(conflict(key)),
+            }
+        }
+        Some((Segment::Key(k), rest)) => {
+            match object
+                .entry(key.to_string().into())
+                .or_insert_with(|| JsonInput::Object(JsonObject::new()))
+            {
+                JsonInput::Object(inner) => insert(inner, k, rest, value)?,
+                _ => return Err(conflict(key)),
+            }
+        }
+    }
+    Ok(())
+}
+
+fn append(array: &mut JsonArray<'static>, key: &str, path: &[Segment], value: String) -> Result<(), String> {
+    match path.split_first() {
+        None => array.push(JsonInput::String(value.into())),
+        Some((Segment::Append, rest)) => {
+            let mut inner = JsonArray::new();
+            append(&mut inner, key, rest, value)?;
+            array.push(JsonInput::Array(inner));
+        }
+        Some((Segment::Key(k), rest)) => {
+            // add to the last object unless it already has this key
+            let start_new = match array.last() {
+                Some(JsonInput::Object(last)) => last.contains_key(*k),
+                _ => true,
+            };
+            if start_new {
+                array.push(JsonInput::Object(JsonObject::new()));
+            }
+            match array.last_mut() {
+                Some(JsonInput::Object(last)) => insert(last, k, rest, value)?,
+                _ => return Err(conflict(key)),
+            }
+        }
+    }
+    Ok(())
+}
+
+fn conflict(key: &str) -> String {
+    format!("conflicting values for key `{key}`")
+}
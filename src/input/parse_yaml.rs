@@ -0,0 +1,57 @@
+use pyo3::prelude::*;
+use pyo3::types::PyString;
+use serde_yaml::Value;
+
+use crate::errors::{ErrorType, ValError, ValResult};
+
+use super::parse_json::{JsonInput, JsonObject};
+
+/// Parse a single YAML document from a python string.
+pub fn py_parse_yaml<'a>(input: &'a PyAny) -> ValResult<'a, JsonInput<'static>> {
+    let py_str = match input.cast_as::<PyString>() {
+        Ok(py_str) => py_str,
+        Err(_) => return Err(ValError::new(ErrorType::YamlType, input)),
+    };
+    serde_yaml::from_str::<Value>(py_str.to_str()?)
+        .map_err(|e| e.to_string())
+        .and_then(yaml_as_input)
+        .map_err(|error| ValError::new(ErrorType::YamlInvalid { error }, input))
+}
+
+/// Tags are ignored and the tagged value used as is, mapping keys which are numbers or booleans are converted to
+/// strings as they would be in JSON.
+fn yaml_as_input(value: Value) -> Result<JsonInput<'static>, String> {
+    let input = match value {
+        Value::Null => JsonInput::Null,
+        Value::Bool(b) => JsonInput::Bool(b),
+        Value::Number(n) => {
+            if let Some(i) = n.as_i64() {
+                JsonInput::Int(i)
+            } else if let Some(u) = n.as_u64() {
+                JsonInput::BigInt(u.into())
+            } else {
+                // `as_f64` is always `Some` for numbers
+                JsonInput::Float(n.as_f64().unwrap_or(f64::NAN))
+            }
+        }
+        Value::String(s) => JsonInput::String(s.into()),
+        Value::Sequence(sequence) => {
+            JsonInput::Array(sequence.into_iter().map(yaml_as_input).collect::<Result<_, _>>()?)
+        }
+        Value::Mapping(mapping) => {
+            let mut object = JsonObject::with_capacity(mapping.len());
+            for (key, value) in mapping {
+                let key = match key {
+                    Value::String(s) => s,
+                    Value::Number(n) => n.to_string(),
+                    Value::Bool(b) => b.to_string(),
+                    _ => return Err("mapping keys must be strings, numbers or booleans".to_string()),
+                };
+                object.insert(key.into(), yaml_as_input(value)?);
+            }
+            JsonInput::Object(object)
+        }
+        Value::Tagged(tagged) => yaml_as_input(tagged.value)?,
+    };
+    Ok(input)
+}
@@ -1,5 +1,7 @@
 use std::borrow::Cow;
+use std::cmp::Ordering;
 
+use pyo3::buffer::PyBuffer;
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::iter::PyDictIterator;
@@ -11,11 +13,15 @@ use pyo3::types::PyFunction;
 use pyo3::PyTypeInfo;
 
 use indexmap::map::Iter;
+use num_bigint::BigInt;
+use num_traits::Zero;
 
 use crate::errors::{py_err_string, ErrorType, InputValue, ValError, ValLineError, ValResult};
 use crate::recursion_guard::RecursionGuard;
+use crate::serializers::AllItems;
 use crate::validators::{CombinedValidator, Extra, Validator};
 
+use super::input_string::{StringInput, StringMapping};
 use super::parse_json::{JsonArray, JsonInput, JsonObject};
 use super::Input;
 
@@ -29,7 +35,7 @@ pub enum GenericCollection<'a> {
     Set(&'a PySet),
     FrozenSet(&'a PyFrozenSet),
     PyAny(&'a PyAny),
-    JsonArray(&'a [JsonInput]),
+    JsonArray(&'a [JsonInput<'a>]),
 }
 
 macro_rules! derive_from {
@@ -46,13 +52,19 @@ derive_from!(GenericCollection, Tuple, PyTuple);
 derive_from!(GenericCollection, Set, PySet);
 derive_from!(GenericCollection, FrozenSet, PyFrozenSet);
 derive_from!(GenericCollection, PyAny, PyAny);
-derive_from!(GenericCollection, JsonArray, JsonArray);
-derive_from!(GenericCollection, JsonArray, [JsonInput]);
 
+impl<'a> From<&'a JsonArray<'a>> for GenericCollection<'a> {
+    fn from(s: &'a JsonArray<'a>) -> GenericCollection<'a> {
+        Self::JsonArray(s)
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 fn validate_iter_to_vec<'a, 's>(
     py: Python<'a>,
     iter: impl Iterator<Item = &'a (impl Input<'a> + 'a)>,
     capacity: usize,
+    tail: Option<usize>,
     validator: &'s CombinedValidator,
     extra: &Extra,
     slots: &'a [CombinedValidator],
@@ -60,11 +72,31 @@ fn validate_iter_to_vec<'a, 's>(
 ) -> ValResult<'a, Vec<PyObject>> {
     let mut output: Vec<PyObject> = Vec::with_capacity(capacity);
     let mut errors: Vec<ValLineError> = Vec::new();
+    let all_items = AllItems::new(py, extra.include, extra.exclude)?;
     for (index, item) in iter.enumerate() {
-        match validator.validate(py, item, extra, slots, recursion_guard) {
+        let (include, exclude) = match extra.item_filter(index, all_items)? {
+            Some(item_include_exclude) => item_include_exclude,
+            // items left out by `include`/`exclude` aren't validated or returned
+            None => continue,
+        };
+        let warnings_start = extra.warnings_count();
+        let is_tail = tail == Some(index);
+        let item_extra = Extra {
+            partial: is_tail,
+            include,
+            exclude,
+            ..*extra
+        };
+        let result = validator.validate_item(py, item, &item_extra, slots, recursion_guard);
+        extra.warnings_with_outer_location(warnings_start, || vec![index.into()]);
+        match result {
             Ok(item) => output.push(item),
+            Err(ValError::LineErrors(_)) if is_tail => (),
             Err(ValError::LineErrors(line_errors)) => {
                 errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index.into())));
+                if extra.too_many_errors(errors.len()) {
+                    break;
+                }
             }
             Err(ValError::Omit) => (),
             Err(err) => return Err(err),
@@ -138,11 +170,17 @@ impl<'a> GenericCollection<'a> {
         let capacity = self
             .generic_len()
             .unwrap_or_else(|_| max_length.unwrap_or(DEFAULT_CAPACITY));
+        // with partial input, the last item may be incomplete and is left out if it's invalid
+        let tail = match extra.partial {
+            true => self.generic_len().ok().and_then(|len| len.checked_sub(1)),
+            false => None,
+        };
         match self {
             Self::List(collection) => validate_iter_to_vec(
                 py,
                 collection.iter(),
                 capacity,
+                tail,
                 validator,
                 extra,
                 slots,
@@ -152,6 +190,7 @@ impl<'a> GenericCollection<'a> {
                 py,
                 collection.iter(),
                 capacity,
+                tail,
                 validator,
                 extra,
                 slots,
@@ -161,6 +200,7 @@ impl<'a> GenericCollection<'a> {
                 py,
                 collection.iter(),
                 capacity,
+                tail,
                 validator,
                 extra,
                 slots,
@@ -170,6 +210,7 @@ impl<'a> GenericCollection<'a> {
                 py,
                 collection.iter(),
                 capacity,
+                tail,
                 validator,
                 extra,
                 slots,
@@ -179,15 +220,36 @@ impl<'a> GenericCollection<'a> {
                 let iter = collection.iter()?;
                 let mut output: Vec<PyObject> = Vec::with_capacity(capacity);
                 let mut errors: Vec<ValLineError> = Vec::new();
+                // the length of an iterator isn't known, so none of its items are treated as the tail
+                let item_extra = Extra {
+                    partial: false,
+                    ..*extra
+                };
+                let all_items = AllItems::new(py, extra.include, extra.exclude)?;
                 for (index, item_result) in iter.enumerate() {
                     let item = item_result.map_err(|e| any_next_error!(collection.py(), e, input, index))?;
-                    match validator.validate(py, item, extra, slots, recursion_guard) {
+                    let (include, exclude) = match extra.item_filter(index, all_items)? {
+                        Some(item_include_exclude) => item_include_exclude,
+                        None => continue,
+                    };
+                    let item_extra = Extra {
+                        include,
+                        exclude,
+                        ..item_extra
+                    };
+                    let warnings_start = extra.warnings_count();
+                    let result = validator.validate_item(py, item, &item_extra, slots, recursion_guard);
+                    extra.warnings_with_outer_location(warnings_start, || vec![index.into()]);
+                    match result {
                         Ok(item) => {
                             generator_too_long!(input, index, generator_max_length, field_type);
                             output.push(item);
                         }
                         Err(ValError::LineErrors(line_errors)) => {
                             errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index.into())));
+                            if extra.too_many_errors(errors.len()) {
+                                break;
+                            }
                         }
                         Err(ValError::Omit) => (),
                         Err(err) => return Err(err),
@@ -205,6 +267,7 @@ impl<'a> GenericCollection<'a> {
                 py,
                 collection.iter(),
                 capacity,
+                tail,
                 validator,
                 extra,
                 slots,
@@ -244,13 +307,20 @@ pub enum GenericMapping<'a> {
     PyDict(&'a PyDict),
     PyMapping(&'a PyMapping),
     PyGetAttr(&'a PyAny),
-    JsonObject(&'a JsonObject),
+    JsonObject(&'a JsonObject<'a>),
+    StringMapping(&'a StringMapping),
 }
 
 derive_from!(GenericMapping, PyDict, PyDict);
 derive_from!(GenericMapping, PyMapping, PyMapping);
 derive_from!(GenericMapping, PyGetAttr, PyAny);
-derive_from!(GenericMapping, JsonObject, JsonObject);
+derive_from!(GenericMapping, StringMapping, StringMapping);
+
+impl<'a> From<&'a JsonObject<'a>> for GenericMapping<'a> {
+    fn from(s: &'a JsonObject<'a>) -> GenericMapping<'a> {
+        Self::JsonObject(s)
+    }
+}
 
 pub struct DictGenericIterator<'py> {
     dict_iter: PyDictIterator<'py>,
@@ -403,11 +473,11 @@ impl<'py> Iterator for AttributesGenericIterator<'py> {
 }
 
 pub struct JsonObjectGenericIterator<'py> {
-    object_iter: Iter<'py, String, JsonInput>,
+    object_iter: Iter<'py, Cow<'py, str>, JsonInput<'py>>,
 }
 
 impl<'py> JsonObjectGenericIterator<'py> {
-    pub fn new(json_object: &'py JsonObject) -> ValResult<'py, Self> {
+    pub fn new(json_object: &'py JsonObject<'py>) -> ValResult<'py, Self> {
         Ok(Self {
             object_iter: json_object.iter(),
         })
@@ -415,7 +485,7 @@ impl<'py> JsonObjectGenericIterator<'py> {
 }
 
 impl<'py> Iterator for JsonObjectGenericIterator<'py> {
-    type Item = ValResult<'py, (&'py String, &'py JsonInput)>;
+    type Item = ValResult<'py, (&'py Cow<'py, str>, &'py JsonInput<'py>)>;
 
     fn next(&mut self) -> Option<Self::Item> {
         self.object_iter.next().map(Ok)
@@ -423,14 +493,35 @@ impl<'py> Iterator for JsonObjectGenericIterator<'py> {
     // size_hint is omitted as it isn't needed
 }
 
+pub struct StringMappingGenericIterator<'py> {
+    mapping_iter: Iter<'py, Cow<'static, str>, StringInput>,
+}
+
+impl<'py> StringMappingGenericIterator<'py> {
+    pub fn new(string_mapping: &'py StringMapping) -> ValResult<'py, Self> {
+        Ok(Self {
+            mapping_iter: string_mapping.iter(),
+        })
+    }
+}
+
+impl<'py> Iterator for StringMappingGenericIterator<'py> {
+    type Item = ValResult<'py, (&'py Cow<'static, str>, &'py StringInput)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.mapping_iter.next().map(Ok)
+    }
+    // size_hint is omitted as it isn't needed
+}
+
 #[derive(Debug, Clone)]
 pub enum GenericIterator {
     PyIterator(GenericPyIterator),
     JsonArray(GenericJsonIterator),
 }
 
-impl From<JsonArray> for GenericIterator {
-    fn from(array: JsonArray) -> Self {
+impl From<JsonArray<'static>> for GenericIterator {
+    fn from(array: JsonArray<'static>) -> Self {
         let length = array.len();
         let json_iter = GenericJsonIterator {
             array,
@@ -483,13 +574,13 @@ impl GenericPyIterator {
 
 #[derive(Debug, Clone)]
 pub struct GenericJsonIterator {
-    array: JsonArray,
+    array: JsonArray<'static>,
     length: usize,
     index: usize,
 }
 
 impl GenericJsonIterator {
-    pub fn next(&mut self, _py: Python) -> PyResult<Option<(&JsonInput, usize)>> {
+    pub fn next(&mut self, _py: Python) -> PyResult<Option<(&JsonInput<'static>, usize)>> {
         if self.index < self.length {
             let next = unsafe { self.array.get_unchecked(self.index) };
             let a = (next, self.index);
@@ -524,12 +615,12 @@ impl<'a> PyArgs<'a> {
 
 #[cfg_attr(debug_assertions, derive(Debug))]
 pub struct JsonArgs<'a> {
-    pub args: Option<&'a [JsonInput]>,
-    pub kwargs: Option<&'a JsonObject>,
+    pub args: Option<&'a [JsonInput<'a>]>,
+    pub kwargs: Option<&'a JsonObject<'a>>,
 }
 
 impl<'a> JsonArgs<'a> {
-    pub fn new(args: Option<&'a [JsonInput]>, kwargs: Option<&'a JsonObject>) -> Self {
+    pub fn new(args: Option<&'a [JsonInput<'a>]>, kwargs: Option<&'a JsonObject<'a>>) -> Self {
         Self { args, kwargs }
     }
 }
@@ -602,6 +693,8 @@ pub fn py_string_str(py_str: &PyString) -> ValResult<&str> {
 pub enum EitherBytes<'a> {
     Cow(Cow<'a, [u8]>),
     Py(&'a PyBytes),
+    /// a C-contiguous buffer, it's held so the data can't be reallocated before it's copied into the output
+    Buffer(PyBuffer<u8>),
 }
 
 impl<'a> From<Vec<u8>> for EitherBytes<'a> {
@@ -622,11 +715,18 @@ impl<'a> From<&'a PyBytes> for EitherBytes<'a> {
     }
 }
 
+impl<'a> From<PyBuffer<u8>> for EitherBytes<'a> {
+    fn from(buffer: PyBuffer<u8>) -> Self {
+        Self::Buffer(buffer)
+    }
+}
+
 impl<'a> EitherBytes<'a> {
     pub fn len(&'a self) -> PyResult<usize> {
         match self {
             EitherBytes::Cow(bytes) => Ok(bytes.len()),
             EitherBytes::Py(py_bytes) => py_bytes.len(),
+            EitherBytes::Buffer(buffer) => Ok(buffer.len_bytes()),
         }
     }
 }
@@ -636,6 +736,103 @@ impl<'a> IntoPy<PyObject> for EitherBytes<'a> {
         match self {
             EitherBytes::Cow(bytes) => PyBytes::new(py, &bytes).into_py(py),
             EitherBytes::Py(py_bytes) => py_bytes.into_py(py),
+            EitherBytes::Buffer(buffer) => {
+                // `Buffer` is only created for C-contiguous buffers, which are held until here
+                let bytes = unsafe { std::slice::from_raw_parts(buffer.buf_ptr() as *const u8, buffer.len_bytes()) };
+                PyBytes::new(py, bytes).into_py(py)
+            }
+        }
+    }
+}
+
+/// Integers are held as `i64` where possible, larger values are kept exactly as a `BigInt`.
+#[derive(Debug, Clone)]
+pub enum EitherInt {
+    I64(i64),
+    BigInt(BigInt),
+}
+
+impl EitherInt {
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            EitherInt::I64(i) => Some(*i),
+            EitherInt::BigInt(_) => None,
+        }
+    }
+
+    pub fn is_multiple_of(&self, multiple_of: &EitherInt) -> bool {
+        match (self, multiple_of) {
+            // `checked_rem` only fails for `i64::MIN % -1` (which is a multiple) and division by zero
+            (EitherInt::I64(i), EitherInt::I64(m)) => i.checked_rem(*m).map_or(*m == -1, |r| r == 0),
+            (EitherInt::I64(i), EitherInt::BigInt(m)) => (BigInt::from(*i) % m).is_zero(),
+            (EitherInt::BigInt(b), EitherInt::I64(m)) => (b % m).is_zero(),
+            (EitherInt::BigInt(b), EitherInt::BigInt(m)) => (b % m).is_zero(),
+        }
+    }
+}
+
+impl From<i64> for EitherInt {
+    fn from(i: i64) -> Self {
+        Self::I64(i)
+    }
+}
+
+impl From<BigInt> for EitherInt {
+    /// values which fit are held as `i64` so comparisons with them don't allocate
+    fn from(b: BigInt) -> Self {
+        match i64::try_from(&b) {
+            Ok(i) => Self::I64(i),
+            Err(_) => Self::BigInt(b),
+        }
+    }
+}
+
+impl FromPyObject<'_> for EitherInt {
+    fn extract(obj: &PyAny) -> PyResult<Self> {
+        match obj.extract::<i64>() {
+            Ok(i) => Ok(Self::I64(i)),
+            Err(_) => Ok(Self::BigInt(obj.extract()?)),
+        }
+    }
+}
+
+impl PartialEq<i64> for EitherInt {
+    fn eq(&self, other: &i64) -> bool {
+        self.as_i64() == Some(*other)
+    }
+}
+
+impl PartialOrd<i64> for EitherInt {
+    fn partial_cmp(&self, other: &i64) -> Option<Ordering> {
+        match self {
+            EitherInt::I64(i) => i.partial_cmp(other),
+            EitherInt::BigInt(b) => b.partial_cmp(&BigInt::from(*other)),
+        }
+    }
+}
+
+impl PartialEq for EitherInt {
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for EitherInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (EitherInt::I64(a), EitherInt::I64(b)) => a.partial_cmp(b),
+            (EitherInt::I64(a), EitherInt::BigInt(b)) => BigInt::from(*a).partial_cmp(b),
+            (EitherInt::BigInt(a), EitherInt::I64(b)) => a.partial_cmp(&BigInt::from(*b)),
+            (EitherInt::BigInt(a), EitherInt::BigInt(b)) => a.partial_cmp(b),
+        }
+    }
+}
+
+impl IntoPy<PyObject> for EitherInt {
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        match self {
+            EitherInt::I64(i) => i.into_py(py),
+            EitherInt::BigInt(b) => b.into_py(py),
         }
     }
 }
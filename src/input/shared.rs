@@ -1,14 +1,39 @@
-use crate::errors::{ErrorType, ValError, ValResult};
+use num_bigint::BigInt;
+use num_traits::FromPrimitive;
 
-use super::Input;
+use crate::errors::{ErrorType, JsonErrorPosition, ValError, ValResult};
 
-pub fn map_json_err<'a>(input: &'a impl Input<'a>, error: serde_json::Error) -> ValError<'a> {
-    ValError::new(
-        ErrorType::JsonInvalid {
-            error: error.to_string(),
-        },
-        input,
-    )
+use super::{EitherInt, Input};
+
+pub fn map_json_err<'a>(input: &'a impl Input<'a>, error: serde_json::Error, json_bytes: &[u8]) -> ValError<'a> {
+    ValError::new(json_invalid(error, json_bytes), input)
+}
+
+pub fn json_invalid(error: serde_json::Error, json_bytes: &[u8]) -> ErrorType {
+    ErrorType::JsonInvalid {
+        error: error.to_string(),
+        position: json_error_position(&error, json_bytes),
+    }
+}
+
+/// `serde_json` reports the line and column of errors, convert them into a byte offset in the input too
+fn json_error_position(error: &serde_json::Error, json_bytes: &[u8]) -> Option<JsonErrorPosition> {
+    let (line, column) = (error.line(), error.column());
+    // errors not related to the input data have no position
+    if line == 0 {
+        return None;
+    }
+    let line_start = match line {
+        1 => 0,
+        _ => json_bytes
+            .iter()
+            .enumerate()
+            .filter(|(_, b)| **b == b'\n')
+            .nth(line - 2)
+            .map_or(json_bytes.len(), |(index, _)| index + 1),
+    };
+    let offset = (line_start + column.saturating_sub(1)).min(json_bytes.len().saturating_sub(1));
+    Some(JsonErrorPosition { line, column, offset })
 }
 
 #[inline]
@@ -46,9 +71,9 @@ pub fn int_as_bool<'a>(input: &'a impl Input<'a>, int: i64) -> ValResult<'a, boo
 }
 
 #[inline]
-pub fn str_as_int<'s, 'l>(input: &'s impl Input<'s>, str: &'l str) -> ValResult<'s, i64> {
+pub fn str_as_int<'s, 'l>(input: &'s impl Input<'s>, str: &'l str) -> ValResult<'s, EitherInt> {
     if let Ok(i) = str.parse::<i64>() {
-        Ok(i)
+        Ok(EitherInt::I64(i))
     } else if let Ok(f) = str.parse::<f64>() {
         float_as_int(input, f)
     } else {
@@ -56,12 +81,17 @@ pub fn str_as_int<'s, 'l>(input: &'s impl Input<'s>, str: &'l str) -> ValResult<
     }
 }
 
-pub fn float_as_int<'a>(input: &'a impl Input<'a>, float: f64) -> ValResult<'a, i64> {
+/// Floats are only accepted as ints when they're integral, those outside the range of `i64` are converted
+/// exactly to a `BigInt` rather than saturating
+pub fn float_as_int<'a>(input: &'a impl Input<'a>, float: f64) -> ValResult<'a, EitherInt> {
     if float == f64::INFINITY || float == f64::NEG_INFINITY || float.is_nan() {
         Err(ValError::new(ErrorType::FiniteNumber, input))
     } else if float % 1.0 != 0.0 {
-        Err(ValError::new(ErrorType::IntFromFloat, input))
+        Err(ValError::new(ErrorType::IntFromFloatInexact, input))
+    } else if float >= i64::MIN as f64 && float < i64::MAX as f64 {
+        Ok(EitherInt::I64(float as i64))
     } else {
-        Ok(float as i64)
+        // all floats which aren't infinite or NaN can be converted
+        Ok(EitherInt::BigInt(BigInt::from_f64(float).unwrap()))
     }
 }
@@ -0,0 +1,338 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+use speedate::{Date, DateTime, Time};
+
+use crate::build_tools::{py_err, SchemaDict};
+
+use super::datetime::{days_in_month, parse_offset, EitherDate, EitherDateTime, EitherTime};
+use super::Input;
+
+const MONTH_NAMES: [&str; 12] = [
+    "january",
+    "february",
+    "march",
+    "april",
+    "may",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+];
+
+/// Which components a format may contain, date formats can't contain time directives and vice versa.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormatKind {
+    Date,
+    Time,
+    DateTime,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum FormatItem {
+    Literal(u8),
+    /// `%Y` - four digit year
+    Year,
+    /// `%y` - two digit year, 69-99 are 1969-1999, 00-68 are 2000-2068, matching POSIX `strptime`
+    ShortYear,
+    /// `%m` - month as a number
+    Month,
+    /// `%b` - abbreviated month name, e.g. `Jan`
+    MonthAbbr,
+    /// `%B` - full month name, e.g. `January`
+    MonthName,
+    /// `%d` - day of the month
+    Day,
+    /// `%H` - hour on a 24 hour clock
+    Hour,
+    /// `%I` - hour on a 12 hour clock
+    Hour12,
+    /// `%p` - `AM` or `PM`
+    AmPm,
+    /// `%M` - minute
+    Minute,
+    /// `%S` - second
+    Second,
+    /// `%f` - fraction of a second, up to 6 digits
+    Microsecond,
+    /// `%z` - UTC offset, e.g. `Z`, `+05:00` or `-0130`
+    Offset,
+}
+
+impl FormatItem {
+    fn is_date(&self) -> bool {
+        matches!(
+            self,
+            Self::Year | Self::ShortYear | Self::Month | Self::MonthAbbr | Self::MonthName | Self::Day
+        )
+    }
+
+    fn is_time(&self) -> bool {
+        matches!(
+            self,
+            Self::Hour | Self::Hour12 | Self::AmPm | Self::Minute | Self::Second | Self::Microsecond | Self::Offset
+        )
+    }
+}
+
+/// The values extracted from an input by a single format.
+#[derive(Debug, Default)]
+struct Parsed {
+    year: u16,
+    month: u8,
+    day: u8,
+    hour: u8,
+    pm: Option<bool>,
+    minute: u8,
+    second: u8,
+    microsecond: u32,
+    offset: Option<i32>,
+}
+
+impl Parsed {
+    fn date(&self) -> Option<Date> {
+        if self.year == 0
+            || self.month < 1
+            || self.month > 12
+            || self.day < 1
+            || self.day > days_in_month(self.year, self.month)
+        {
+            return None;
+        }
+        Some(Date {
+            year: self.year,
+            month: self.month,
+            day: self.day,
+        })
+    }
+
+    fn time(&self) -> Option<Time> {
+        let hour = match self.pm {
+            Some(pm) if (1..=12).contains(&self.hour) => self.hour % 12 + if pm { 12 } else { 0 },
+            Some(_) => return None,
+            None => self.hour,
+        };
+        if hour > 23 || self.minute > 59 || self.second > 59 {
+            return None;
+        }
+        Some(Time {
+            hour,
+            minute: self.minute,
+            second: self.second,
+            microsecond: self.microsecond,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct InputFormat {
+    items: Vec<FormatItem>,
+}
+
+impl InputFormat {
+    fn compile(format: &str, kind: FormatKind) -> PyResult<Self> {
+        let mut items = Vec::with_capacity(format.len());
+        let mut bytes = format.bytes();
+        while let Some(b) = bytes.next() {
+            if b != b'%' {
+                items.push(FormatItem::Literal(b));
+                continue;
+            }
+            let item = match bytes.next() {
+                Some(b'%') => FormatItem::Literal(b'%'),
+                Some(b'Y') => FormatItem::Year,
+                Some(b'y') => FormatItem::ShortYear,
+                Some(b'm') => FormatItem::Month,
+                Some(b'b') => FormatItem::MonthAbbr,
+                Some(b'B') => FormatItem::MonthName,
+                Some(b'd') => FormatItem::Day,
+                Some(b'H') => FormatItem::Hour,
+                Some(b'I') => FormatItem::Hour12,
+                Some(b'p') => FormatItem::AmPm,
+                Some(b'M') => FormatItem::Minute,
+                Some(b'S') => FormatItem::Second,
+                Some(b'f') => FormatItem::Microsecond,
+                Some(b'z') => FormatItem::Offset,
+                Some(c) => {
+                    return py_err!(
+                        "Invalid input format {:?}: unknown directive \"%{}\"",
+                        format,
+                        c as char
+                    )
+                }
+                None => return py_err!("Invalid input format {:?}: trailing \"%\"", format),
+            };
+            items.push(item);
+        }
+
+        let has = |check: fn(&FormatItem) -> bool| items.iter().any(check);
+        if kind == FormatKind::Date && has(FormatItem::is_time) {
+            return py_err!(
+                "Invalid input format {:?}: date formats may not contain time directives",
+                format
+            );
+        }
+        if kind == FormatKind::Time && has(FormatItem::is_date) {
+            return py_err!(
+                "Invalid input format {:?}: time formats may not contain date directives",
+                format
+            );
+        }
+        if kind != FormatKind::Time
+            && !(has(|i| matches!(i, FormatItem::Year | FormatItem::ShortYear))
+                && has(|i| matches!(i, FormatItem::Month | FormatItem::MonthAbbr | FormatItem::MonthName))
+                && has(|i| matches!(i, FormatItem::Day)))
+        {
+            return py_err!("Invalid input format {:?}: a year, month and day are required", format);
+        }
+        if kind == FormatKind::Time && !has(|i| matches!(i, FormatItem::Hour | FormatItem::Hour12)) {
+            return py_err!("Invalid input format {:?}: an hour is required", format);
+        }
+        Ok(Self { items })
+    }
+
+    fn parse(&self, bytes: &[u8]) -> Option<Parsed> {
+        let mut parsed = Parsed::default();
+        let mut position = 0;
+        for item in &self.items {
+            match item {
+                FormatItem::Literal(b) => {
+                    if bytes.get(position) != Some(b) {
+                        return None;
+                    }
+                    position += 1;
+                }
+                FormatItem::Year => parsed.year = digits(bytes, &mut position, 4, 4)? as u16,
+                FormatItem::ShortYear => {
+                    let year = digits(bytes, &mut position, 2, 2)? as u16;
+                    parsed.year = if year >= 69 { 1900 + year } else { 2000 + year };
+                }
+                FormatItem::Month => parsed.month = digits(bytes, &mut position, 1, 2)? as u8,
+                FormatItem::MonthAbbr => parsed.month = month_name(bytes, &mut position, true)?,
+                FormatItem::MonthName => parsed.month = month_name(bytes, &mut position, false)?,
+                FormatItem::Day => parsed.day = digits(bytes, &mut position, 1, 2)? as u8,
+                FormatItem::Hour | FormatItem::Hour12 => parsed.hour = digits(bytes, &mut position, 1, 2)? as u8,
+                FormatItem::AmPm => {
+                    let am_pm = bytes.get(position..position + 2)?.to_ascii_lowercase();
+                    parsed.pm = match am_pm.as_slice() {
+                        b"am" => Some(false),
+                        b"pm" => Some(true),
+                        _ => return None,
+                    };
+                    position += 2;
+                }
+                FormatItem::Minute => parsed.minute = digits(bytes, &mut position, 1, 2)? as u8,
+                FormatItem::Second => parsed.second = digits(bytes, &mut position, 1, 2)? as u8,
+                FormatItem::Microsecond => {
+                    let start = position;
+                    let value = digits(bytes, &mut position, 1, 6)?;
+                    parsed.microsecond = value * 10_u32.pow(6 - (position - start) as u32);
+                }
+                FormatItem::Offset => {
+                    let length = bytes[position..]
+                        .iter()
+                        .position(|c| !matches!(c, b'0'..=b'9' | b':' | b'+' | b'-' | b'Z' | b'z' | 226 | 136 | 146))
+                        .unwrap_or(bytes.len() - position);
+                    parsed.offset = Some(parse_offset(&bytes[position..position + length]).ok()?);
+                    position += length;
+                }
+            }
+        }
+        match position == bytes.len() {
+            true => Some(parsed),
+            false => None,
+        }
+    }
+}
+
+/// Read between `min` and `max` ASCII digits, advancing `position`.
+fn digits(bytes: &[u8], position: &mut usize, min: usize, max: usize) -> Option<u32> {
+    let mut value: u32 = 0;
+    let mut count = 0;
+    while count < max {
+        match bytes.get(*position + count) {
+            Some(c) if c.is_ascii_digit() => value = value * 10 + (c - b'0') as u32,
+            _ => break,
+        }
+        count += 1;
+    }
+    if count < min {
+        return None;
+    }
+    *position += count;
+    Some(value)
+}
+
+fn month_name(bytes: &[u8], position: &mut usize, abbreviated: bool) -> Option<u8> {
+    let rest = &bytes[*position..];
+    for (index, name) in MONTH_NAMES.iter().enumerate() {
+        let name = match abbreviated {
+            true => &name[..3],
+            false => name,
+        };
+        if rest.len() >= name.len() && rest[..name.len()].eq_ignore_ascii_case(name.as_bytes()) {
+            *position += name.len();
+            return Some(index as u8 + 1);
+        }
+    }
+    None
+}
+
+/// Additional `strftime` style formats accepted for string inputs in lax mode, compiled when the
+/// validator is built.
+#[derive(Debug, Clone)]
+pub struct InputFormats {
+    formats: Vec<InputFormat>,
+}
+
+impl InputFormats {
+    pub fn from_py(schema: &PyDict, kind: FormatKind) -> PyResult<Option<Self>> {
+        let py_formats: &PyList = match schema.get_as(intern!(schema.py(), "input_formats"))? {
+            Some(formats) => formats,
+            None => return Ok(None),
+        };
+        let formats = py_formats
+            .iter()
+            .map(|format| InputFormat::compile(format.extract()?, kind))
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(Some(Self { formats }))
+    }
+
+    /// Try each format in turn against a string input, returning the date from the first which matches.
+    pub fn parse_date<'a>(&self, input: &'a impl Input<'a>) -> Option<EitherDate<'a>> {
+        with_str_bytes(input, |bytes| self.formats.iter().find_map(|f| f.parse(bytes)?.date())).map(Into::into)
+    }
+
+    pub fn parse_time<'a>(&self, input: &'a impl Input<'a>) -> Option<EitherTime<'a>> {
+        with_str_bytes(input, |bytes| {
+            self.formats.iter().find_map(|f| {
+                let parsed = f.parse(bytes)?;
+                Some(EitherTime::Raw(parsed.time()?, parsed.offset))
+            })
+        })
+    }
+
+    pub fn parse_datetime<'a>(&self, input: &'a impl Input<'a>) -> Option<EitherDateTime<'a>> {
+        with_str_bytes(input, |bytes| {
+            self.formats.iter().find_map(|f| {
+                let parsed = f.parse(bytes)?;
+                Some(DateTime {
+                    date: parsed.date()?,
+                    time: parsed.time()?,
+                    offset: parsed.offset,
+                })
+            })
+        })
+        .map(Into::into)
+    }
+}
+
+/// Input formats only apply to strings, other inputs are never matched.
+fn with_str_bytes<'a, T>(input: &'a impl Input<'a>, f: impl FnOnce(&[u8]) -> Option<T>) -> Option<T> {
+    let either_str = input.strict_str().ok()?;
+    let str = either_str.as_cow().ok()?;
+    f(str.as_bytes())
+}
@@ -14,9 +14,14 @@ mod build_tools;
 mod errors;
 mod input;
 mod lookup_key;
+mod profile;
+mod py_gc;
 mod questions;
 mod recursion_guard;
 mod serializers;
+mod string_cache;
+mod tracing;
+mod undefined;
 mod url;
 mod validators;
 
@@ -27,7 +32,8 @@ pub use errors::{
     list_all_errors, PydanticCustomError, PydanticKnownError, PydanticOmit, PydanticSerializationError, ValidationError,
 };
 pub use serializers::SchemaSerializer;
-pub use validators::SchemaValidator;
+pub use undefined::PydanticUndefinedType;
+pub use validators::{validate_core_schema, SchemaValidator};
 
 pub fn get_version() -> String {
     let version = env!("CARGO_PKG_VERSION").to_string();
@@ -40,7 +46,7 @@ pub fn get_version() -> String {
 }
 
 #[pymodule]
-fn _pydantic_core(_py: Python, m: &PyModule) -> PyResult<()> {
+fn _pydantic_core(py: Python, m: &PyModule) -> PyResult<()> {
     m.add("__version__", get_version())?;
     m.add("build_profile", env!("PROFILE"))?;
     m.add_class::<SchemaValidator>()?;
@@ -53,6 +59,9 @@ fn _pydantic_core(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<PyUrl>()?;
     m.add_class::<PyMultiHostUrl>()?;
     m.add_class::<SchemaSerializer>()?;
+    m.add_class::<PydanticUndefinedType>()?;
+    m.add("PydanticUndefined", PydanticUndefinedType::get(py))?;
     m.add_function(wrap_pyfunction!(list_all_errors, m)?)?;
+    m.add_function(wrap_pyfunction!(validate_core_schema, m)?)?;
     Ok(())
 }
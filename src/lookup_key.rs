@@ -5,7 +5,8 @@ use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList, PyMapping, PyString};
 
 use crate::build_tools::py_err;
-use crate::input::{JsonInput, JsonObject};
+use crate::errors::LocItem;
+use crate::input::{JsonInput, JsonObject, StringInput, StringMapping};
 
 /// Used got getting items from python dicts, python objects, or JSON objects, in different ways
 #[derive(Debug, Clone)]
@@ -80,6 +81,20 @@ impl LookupKey {
         LookupKey::Simple(key.to_string(), py_string!(py, key))
     }
 
+    /// The location used in errors when `loc_by_alias` is set: the alias, or the first path if there are several
+    pub fn error_loc(&self) -> Vec<LocItem> {
+        match self {
+            Self::Simple(key, _) | Self::Choice(key, _, _, _) => vec![key.as_str().into()],
+            Self::PathChoices(paths) => paths[0]
+                .iter()
+                .map(|path_item| match path_item {
+                    PathItem::S(key, _) => key.as_str().into(),
+                    PathItem::I(index) => (*index).into(),
+                })
+                .collect(),
+        }
+    }
+
     fn path_choice(obj: &PyAny) -> PyResult<Path> {
         let path = obj
             .extract::<&PyList>()?
@@ -193,15 +208,18 @@ impl LookupKey {
         }
     }
 
-    pub fn json_get<'data, 's>(&'s self, dict: &'data JsonObject) -> PyResult<Option<(&'s str, &'data JsonInput)>> {
+    pub fn json_get<'data, 's>(
+        &'s self,
+        dict: &'data JsonObject<'data>,
+    ) -> PyResult<Option<(&'s str, &'data JsonInput<'data>)>> {
         match self {
-            LookupKey::Simple(key, _) => match dict.get(key) {
+            LookupKey::Simple(key, _) => match dict.get(key.as_str()) {
                 Some(value) => Ok(Some((key, value))),
                 None => Ok(None),
             },
-            LookupKey::Choice(key1, key2, _, _) => match dict.get(key1) {
+            LookupKey::Choice(key1, key2, _, _) => match dict.get(key1.as_str()) {
                 Some(value) => Ok(Some((key1, value))),
-                None => match dict.get(key2) {
+                None => match dict.get(key2.as_str()) {
                     Some(value) => Ok(Some((key2, value))),
                     None => Ok(None),
                 },
@@ -212,7 +230,7 @@ impl LookupKey {
 
                     // first step is different from the rest as we already know dict is JsonObject
                     // because of above checks, we know that path should have at least one element, hence unwrap
-                    let v: &JsonInput = match path_iter.next().unwrap().json_obj_get(dict) {
+                    let v: &JsonInput<'data> = match path_iter.next().unwrap().json_obj_get(dict) {
                         Some(v) => v,
                         None => continue,
                     };
@@ -231,6 +249,44 @@ impl LookupKey {
             }
         }
     }
+
+    pub fn string_mapping_get<'data, 's>(
+        &'s self,
+        dict: &'data StringMapping,
+    ) -> PyResult<Option<(&'s str, &'data StringInput)>> {
+        match self {
+            LookupKey::Simple(key, _) => match dict.get(key.as_str()) {
+                Some(value) => Ok(Some((key, value))),
+                None => Ok(None),
+            },
+            LookupKey::Choice(key1, key2, _, _) => match dict.get(key1.as_str()) {
+                Some(value) => Ok(Some((key1, value))),
+                None => match dict.get(key2.as_str()) {
+                    Some(value) => Ok(Some((key2, value))),
+                    None => Ok(None),
+                },
+            },
+            LookupKey::PathChoices(path_choices) => {
+                for path in path_choices {
+                    // string mappings can only be nested by string keys
+                    let mut path_iter = path.iter();
+                    let v = match path_iter.next().unwrap().string_mapping_get(dict) {
+                        Some(v) => v,
+                        None => continue,
+                    };
+                    let found = path_iter.try_fold(v, |d, loc| match d {
+                        StringInput::Mapping(m) => loc.string_mapping_get(m),
+                        _ => None,
+                    });
+                    if let Some(v) = found {
+                        let key = path.first().unwrap().get_key();
+                        return Ok(Some((key, v)));
+                    }
+                }
+                Ok(None)
+            }
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -313,7 +369,7 @@ impl PathItem {
         }
     }
 
-    pub fn json_get<'a>(&self, any_json: &'a JsonInput) -> Option<&'a JsonInput> {
+    pub fn json_get<'a>(&self, any_json: &'a JsonInput<'a>) -> Option<&'a JsonInput<'a>> {
         match any_json {
             JsonInput::Object(v_obj) => self.json_obj_get(v_obj),
             JsonInput::Array(v_array) => match self {
@@ -324,9 +380,16 @@ impl PathItem {
         }
     }
 
-    pub fn json_obj_get<'a>(&self, json_obj: &'a JsonObject) -> Option<&'a JsonInput> {
+    pub fn json_obj_get<'a>(&self, json_obj: &'a JsonObject<'a>) -> Option<&'a JsonInput<'a>> {
+        match self {
+            Self::S(key, _) => json_obj.get(key.as_str()),
+            _ => None,
+        }
+    }
+
+    pub fn string_mapping_get<'a>(&self, string_mapping: &'a StringMapping) -> Option<&'a StringInput> {
         match self {
-            Self::S(key, _) => json_obj.get(key),
+            Self::S(key, _) => string_mapping.get(key.as_str()),
             _ => None,
         }
     }
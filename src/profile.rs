@@ -0,0 +1,63 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyTuple};
+
+/// The number of times a typed dict field has been validated and the total time taken,
+/// only created when the `profile` config key is set.
+#[derive(Debug, Default)]
+pub struct ProfileCounter {
+    calls: AtomicU64,
+    time_ns: AtomicU64,
+}
+
+impl ProfileCounter {
+    /// record a call which started at `start`
+    pub fn record(&self, start: Instant) {
+        let elapsed = u64::try_from(start.elapsed().as_nanos()).unwrap_or(u64::MAX);
+        self.calls.fetch_add(1, Ordering::Relaxed);
+        self.time_ns.fetch_add(elapsed, Ordering::Relaxed);
+    }
+}
+
+/// Built by `BuildContext` while building a validator with `profile` set, holds a counter for each field,
+/// along with the location of the field, e.g. `("user", "address", "city")` for nested typed dicts.
+#[derive(Debug, Clone, Default)]
+pub struct Profiler {
+    // field names of the typed dict fields currently being built
+    loc: Vec<String>,
+    counters: Vec<(Vec<String>, Arc<ProfileCounter>)>,
+}
+
+impl Profiler {
+    /// add a counter for a field which is about to be built, `end_field` must be called once it's built
+    pub fn start_field(&mut self, field_name: &str) -> Arc<ProfileCounter> {
+        self.loc.push(field_name.to_string());
+        let counter = Arc::new(ProfileCounter::default());
+        self.counters.push((self.loc.clone(), counter.clone()));
+        counter
+    }
+
+    pub fn end_field(&mut self) {
+        self.loc.pop();
+    }
+
+    /// stats for each field in the order the fields were built, so fields of nested typed dicts come
+    /// straight after the field containing them, `time_ns` includes the time spent validating nested fields
+    pub fn stats(&self, py: Python) -> PyResult<PyObject> {
+        let stats = self
+            .counters
+            .iter()
+            .map(|(loc, counter)| {
+                let d = PyDict::new(py);
+                d.set_item("loc", PyTuple::new(py, loc))?;
+                d.set_item("calls", counter.calls.load(Ordering::Relaxed))?;
+                d.set_item("time_ns", counter.time_ns.load(Ordering::Relaxed))?;
+                Ok(d)
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(stats.into_py(py))
+    }
+}
@@ -0,0 +1,79 @@
+use ahash::AHashMap;
+use enum_dispatch::enum_dispatch;
+use pyo3::class::gc::{PyTraverseError, PyVisit};
+use pyo3::Py;
+
+/// Used to implement `__traverse__` on `SchemaValidator` and `SchemaSerializer`, so the garbage collector can
+/// find cycles through the python objects (functions, classes, defaults etc.) they hold references to.
+///
+/// Every python object owned by a validator or serializer must be visited, but no others.
+#[enum_dispatch]
+pub trait PyGcTraverse {
+    fn py_gc_traverse(&self, visit: &pyo3::class::gc::PyVisit<'_>) -> Result<(), pyo3::class::gc::PyTraverseError>;
+}
+
+impl<T> PyGcTraverse for Py<T> {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        visit.call(self)
+    }
+}
+
+impl<T: PyGcTraverse> PyGcTraverse for Vec<T> {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        for item in self {
+            item.py_gc_traverse(visit)?;
+        }
+        Ok(())
+    }
+}
+
+impl<K, T: PyGcTraverse> PyGcTraverse for AHashMap<K, T> {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        for item in self.values() {
+            item.py_gc_traverse(visit)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: PyGcTraverse> PyGcTraverse for Option<T> {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        match self {
+            Some(item) => item.py_gc_traverse(visit),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T: PyGcTraverse> PyGcTraverse for Box<T> {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        T::py_gc_traverse(self, visit)
+    }
+}
+
+/// Implement `PyGcTraverse` for a struct by visiting the listed fields, structs with no python objects
+/// are listed with no fields, e.g. `impl_py_gc_traverse!(IntValidator {})`
+macro_rules! impl_py_gc_traverse {
+    ($name:ident { }) => {
+        impl crate::py_gc::PyGcTraverse for $name {
+            fn py_gc_traverse(
+                &self,
+                _visit: &pyo3::class::gc::PyVisit<'_>,
+            ) -> Result<(), pyo3::class::gc::PyTraverseError> {
+                Ok(())
+            }
+        }
+    };
+    ($name:ident { $($fields:ident),* }) => {
+        impl crate::py_gc::PyGcTraverse for $name {
+            fn py_gc_traverse(
+                &self,
+                visit: &pyo3::class::gc::PyVisit<'_>,
+            ) -> Result<(), pyo3::class::gc::PyTraverseError> {
+                $(crate::py_gc::PyGcTraverse::py_gc_traverse(&self.$fields, visit)?;)*
+                Ok(())
+            }
+        }
+    };
+}
+pub(crate) use impl_py_gc_traverse;
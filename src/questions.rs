@@ -3,27 +3,45 @@ use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
 use crate::build_tools::SchemaDict;
+use crate::validators::{CombinedValidator, Validator};
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum Question {
     ReturnFieldsSet,
+    /// whether the validator never accepts `None`, so containers can report `none_not_allowed` without calling it,
+    /// validators which might accept `None`, e.g. `nullable`, functions and references, must answer `false`
+    RejectsNone,
 }
 
 #[derive(Debug, Clone)]
 pub struct Answers {
     return_fields_set: bool,
+    rejects_none: bool,
 }
 
 impl Answers {
     pub fn new(schema: &PyDict) -> PyResult<Self> {
         let key = intern!(schema.py(), "return_fields_set");
         let return_fields_set = schema.get_as(key)?.unwrap_or(false);
-        Ok(Self { return_fields_set })
+        // the referenced validator isn't built yet, so assume it might accept `None`
+        Ok(Self {
+            return_fields_set,
+            rejects_none: false,
+        })
+    }
+
+    /// record the answers of a built validator, for validators which refer to it
+    pub fn from_validator(py: Python, validator: &CombinedValidator) -> Self {
+        Self {
+            return_fields_set: validator.ask(py, &Question::ReturnFieldsSet),
+            rejects_none: validator.ask(py, &Question::RejectsNone),
+        }
     }
 
     pub fn ask(&self, question: &Question) -> bool {
         match question {
             Question::ReturnFieldsSet => self.return_fields_set,
+            Question::RejectsNone => self.rejects_none,
         }
     }
 }
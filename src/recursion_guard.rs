@@ -1,48 +1,74 @@
-use std::hash::BuildHasherDefault;
+use ahash::AHashSet;
 
-use nohash_hasher::IntSet;
+/// (identity of the input object, id of the validator slot) - the input's `__hash__` is never used
+type RecursionKey = (usize, usize);
 
 /// This is used to avoid cyclic references in input data causing recursive validation and a nasty segmentation fault.
-/// It's used in `validators/recursive.rs` to detect when a reference is reused within itself.
-#[derive(Debug, Clone, Default)]
+/// It's used in `validators/recursive.rs` to detect when a reference is reused within itself, the same object
+/// validated by different recursive validators isn't a cycle.
+#[derive(Debug, Clone)]
 pub struct RecursionGuard {
-    ids: Option<IntSet<usize>>,
-    // see validators/recursive.rs::BACKUP_GUARD_LIMIT for details
+    ids: Option<AHashSet<RecursionKey>>,
     // depth could be a hashmap {validator_id => depth} but for simplicity and performance it's easier to just
     // use one number for all validators
     depth: u16,
+    // the maximum depth, set by the `recursion_limit` config key
+    limit: u16,
+}
+
+// see #143 this is a backup in case the identity check recursion guard fails, and it stops deeply nested input
+// exhausting the stack: if a recursive validator's "depth" (how many times it's called inside itself) exceeds
+// the limit, we raise a recursion error.
+pub const DEFAULT_RECURSION_LIMIT: u16 = if cfg!(PyPy) || cfg!(target_family = "wasm") {
+    123
+} else {
+    255
+};
+
+impl Default for RecursionGuard {
+    fn default() -> Self {
+        Self::new(DEFAULT_RECURSION_LIMIT)
+    }
 }
 
 impl RecursionGuard {
-    // insert a new id into the set, return whether the set already had the id in it
-    pub fn contains_or_insert(&mut self, id: usize) -> bool {
+    pub fn new(limit: u16) -> Self {
+        Self {
+            ids: None,
+            depth: 0,
+            limit,
+        }
+    }
+
+    // insert a new (object id, slot id) pair into the set, return whether the set already had it
+    pub fn contains_or_insert(&mut self, obj_id: usize, slot_id: usize) -> bool {
         match self.ids {
             // https://doc.rust-lang.org/std/collections/struct.HashSet.html#method.insert
             // "If the set did not have this value present, `true` is returned."
-            Some(ref mut set) => !set.insert(id),
+            Some(ref mut set) => !set.insert((obj_id, slot_id)),
             None => {
-                let mut set: IntSet<usize> = IntSet::with_capacity_and_hasher(10, BuildHasherDefault::default());
-                set.insert(id);
+                let mut set: AHashSet<RecursionKey> = AHashSet::with_capacity(10);
+                set.insert((obj_id, slot_id));
                 self.ids = Some(set);
                 false
             }
         }
     }
 
-    // see #143 this is used as a backup in case the identity check recursion guard fails
-    pub fn incr_depth(&mut self) -> u16 {
+    // increment the depth, return whether it's now over the limit, `decr_depth` must still be called either way
+    pub fn incr_depth(&mut self) -> bool {
         self.depth += 1;
-        self.depth
+        self.depth > self.limit
     }
 
     pub fn decr_depth(&mut self) {
         self.depth -= 1;
     }
 
-    pub fn remove(&mut self, id: &usize) {
+    pub fn remove(&mut self, obj_id: usize, slot_id: usize) {
         match self.ids {
             Some(ref mut set) => {
-                set.remove(id);
+                set.remove(&(obj_id, slot_id));
             }
             None => unreachable!(),
         };
@@ -9,24 +9,57 @@ use serde::ser::Error;
 
 use crate::build_tools::{py_err, SchemaDict};
 use crate::input::pytimedelta_as_duration;
+use crate::tracing::trace_enabled;
 
 use super::shared::py_err_se_err;
 
 #[derive(Debug, Clone)]
 pub(crate) struct SerializationConfig {
     pub timedelta_mode: TimedeltaMode,
+    pub datetime_mode: DatetimeMode,
     pub bytes_mode: BytesMode,
+    pub decimal_mode: DecimalMode,
+    pub trace: bool,
 }
 
 impl SerializationConfig {
     pub fn from_config(config: Option<&PyDict>) -> PyResult<Self> {
         let timedelta_mode = TimedeltaMode::from_config(config)?;
+        let datetime_mode = DatetimeMode::from_config(config)?;
         let bytes_mode = BytesMode::from_config(config)?;
+        let decimal_mode = DecimalMode::from_config(config)?;
         Ok(Self {
             timedelta_mode,
+            datetime_mode,
             bytes_mode,
+            decimal_mode,
+            trace: trace_enabled(config)?,
         })
     }
+
+    /// the config with any modes given as arguments to `to_python` or `to_json` replacing those from config,
+    /// borrowed if there are none so the common case doesn't clone
+    pub fn with_overrides(
+        &self,
+        timedelta_mode: Option<&str>,
+        datetime_mode: Option<&str>,
+        bytes_mode: Option<&str>,
+    ) -> PyResult<Cow<Self>> {
+        if timedelta_mode.is_none() && datetime_mode.is_none() && bytes_mode.is_none() {
+            return Ok(Cow::Borrowed(self));
+        }
+        let mut config = self.clone();
+        if timedelta_mode.is_some() {
+            config.timedelta_mode = TimedeltaMode::new(timedelta_mode)?;
+        }
+        if datetime_mode.is_some() {
+            config.datetime_mode = DatetimeMode::new(datetime_mode)?;
+        }
+        if bytes_mode.is_some() {
+            config.bytes_mode = BytesMode::new(bytes_mode)?;
+        }
+        Ok(Cow::Owned(config))
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -41,6 +74,10 @@ impl TimedeltaMode {
             Some(c) => c.get_as::<&str>(intern!(c.py(), "ser_json_timedelta"))?,
             None => None,
         };
+        Self::new(raw_mode)
+    }
+
+    pub fn new(raw_mode: Option<&str>) -> PyResult<Self> {
         match raw_mode {
             Some("iso8601") => Ok(Self::Iso8601),
             Some("float") => Ok(Self::Float),
@@ -102,6 +139,102 @@ impl TimedeltaMode {
     }
 }
 
+/// How `datetime`, `date` and `time` values are serialized to JSON, timestamps are `(seconds, microseconds)`
+/// as returned by the `*_as_timestamp` functions in `src/input/datetime.rs`.
+#[derive(Debug, Clone)]
+pub(crate) enum DatetimeMode {
+    Iso8601,
+    Seconds,
+    Milliseconds,
+    Float,
+}
+
+type ToStringFn<T> = fn(&T) -> PyResult<String>;
+type ToTimestampFn<T> = fn(&T) -> PyResult<(i64, u32)>;
+
+impl DatetimeMode {
+    pub fn from_config(config: Option<&PyDict>) -> PyResult<Self> {
+        let raw_mode: Option<&str> = match config {
+            Some(c) => c.get_as::<&str>(intern!(c.py(), "ser_json_datetime"))?,
+            None => None,
+        };
+        Self::new(raw_mode)
+    }
+
+    pub fn new(raw_mode: Option<&str>) -> PyResult<Self> {
+        match raw_mode {
+            Some("iso8601") => Ok(Self::Iso8601),
+            Some("seconds") => Ok(Self::Seconds),
+            Some("milliseconds") => Ok(Self::Milliseconds),
+            Some("float") => Ok(Self::Float),
+            Some(s) => py_err!(
+                "Invalid datetime serialization mode: `{}`, expected `iso8601`, `seconds`, `milliseconds` or `float`",
+                s
+            ),
+            None => Ok(Self::Iso8601),
+        }
+    }
+
+    fn milliseconds((seconds, microsecond): (i64, u32)) -> i64 {
+        seconds * 1_000 + (microsecond / 1_000) as i64
+    }
+
+    fn float_seconds((seconds, microsecond): (i64, u32)) -> f64 {
+        seconds as f64 + microsecond as f64 / 1_000_000.0
+    }
+
+    pub fn to_json<T>(
+        &self,
+        py: Python,
+        value: &T,
+        to_string: ToStringFn<T>,
+        to_timestamp: ToTimestampFn<T>,
+    ) -> PyResult<PyObject> {
+        match self {
+            Self::Iso8601 => Ok(to_string(value)?.into_py(py)),
+            Self::Seconds => Ok(to_timestamp(value)?.0.into_py(py)),
+            Self::Milliseconds => Ok(Self::milliseconds(to_timestamp(value)?).into_py(py)),
+            Self::Float => Ok(Self::float_seconds(to_timestamp(value)?).into_py(py)),
+        }
+    }
+
+    pub fn json_key<T>(&self, value: &T, to_string: ToStringFn<T>, to_timestamp: ToTimestampFn<T>) -> PyResult<String> {
+        match self {
+            Self::Iso8601 => to_string(value),
+            Self::Seconds => Ok(to_timestamp(value)?.0.to_string()),
+            Self::Milliseconds => Ok(Self::milliseconds(to_timestamp(value)?).to_string()),
+            Self::Float => Ok(Self::float_seconds(to_timestamp(value)?).to_string()),
+        }
+    }
+
+    pub fn serialize<T, S: serde::ser::Serializer>(
+        &self,
+        value: &T,
+        to_string: ToStringFn<T>,
+        to_timestamp: ToTimestampFn<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Iso8601 => {
+                let s = to_string(value).map_err(py_err_se_err)?;
+                serializer.serialize_str(&s)
+            }
+            Self::Seconds => {
+                let timestamp = to_timestamp(value).map_err(py_err_se_err)?;
+                serializer.serialize_i64(timestamp.0)
+            }
+            Self::Milliseconds => {
+                let timestamp = to_timestamp(value).map_err(py_err_se_err)?;
+                serializer.serialize_i64(Self::milliseconds(timestamp))
+            }
+            Self::Float => {
+                let timestamp = to_timestamp(value).map_err(py_err_se_err)?;
+                serializer.serialize_f64(Self::float_seconds(timestamp))
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub(crate) struct BytesMode {
     base64_config: Option<base64::Config>,
@@ -113,6 +246,10 @@ impl BytesMode {
             Some(c) => c.get_as::<&str>(intern!(c.py(), "ser_json_bytes"))?,
             None => None,
         };
+        Self::new(raw_mode)
+    }
+
+    pub fn new(raw_mode: Option<&str>) -> PyResult<Self> {
         let base64_config = match raw_mode {
             Some("utf8") => None,
             Some("base64") => Some(base64::Config::new(base64::CharacterSet::UrlSafe, true)),
@@ -146,6 +283,53 @@ impl BytesMode {
     }
 }
 
+/// How `Decimal` values are serialized to JSON, as a string by default so no precision is lost
+#[derive(Debug, Clone)]
+pub(crate) enum DecimalMode {
+    Str,
+    Float,
+}
+
+impl DecimalMode {
+    pub fn from_config(config: Option<&PyDict>) -> PyResult<Self> {
+        let raw_mode: Option<&str> = match config {
+            Some(c) => c.get_as::<&str>(intern!(c.py(), "ser_json_decimal"))?,
+            None => None,
+        };
+        match raw_mode {
+            Some("str") => Ok(Self::Str),
+            Some("float") => Ok(Self::Float),
+            Some(s) => py_err!("Invalid decimal serialization mode: `{}`, expected `str` or `float`", s),
+            None => Ok(Self::Str),
+        }
+    }
+
+    pub fn decimal_to_json(&self, py_decimal: &PyAny) -> PyResult<PyObject> {
+        let py = py_decimal.py();
+        match self {
+            Self::Str => Ok(py_decimal.str()?.into_py(py)),
+            Self::Float => Ok(py_decimal.extract::<f64>()?.into_py(py)),
+        }
+    }
+
+    pub fn decimal_serialize<S: serde::ser::Serializer>(
+        &self,
+        py_decimal: &PyAny,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Str => {
+                let s = py_decimal.str().map_err(py_err_se_err)?;
+                serializer.serialize_str(&s.to_string_lossy())
+            }
+            Self::Float => {
+                let f: f64 = py_decimal.extract().map_err(py_err_se_err)?;
+                serializer.serialize_f64(f)
+            }
+        }
+    }
+}
+
 pub fn utf8_py_error(py: Python, err: Utf8Error, data: &[u8]) -> PyErr {
     #[cfg(not(PyPy))]
     return match pyo3::exceptions::PyUnicodeDecodeError::new_utf8(py, data, err) {
@@ -8,6 +8,7 @@ use pyo3::{intern, AsPyPointer};
 use nohash_hasher::IntSet;
 
 use crate::build_tools::py_err;
+use crate::tracing::trace;
 
 use super::config::SerializationConfig;
 use super::ob_type::ObTypeLookup;
@@ -45,7 +46,7 @@ impl<'a> Extra<'a> {
             mode,
             slots,
             ob_type_lookup: ObTypeLookup::cached(py),
-            warnings: CollectWarnings::new(true),
+            warnings: CollectWarnings::new(true, config.trace),
             by_alias: by_alias.unwrap_or(true),
             exclude_unset: exclude_unset.unwrap_or(false),
             exclude_defaults: exclude_defaults.unwrap_or(false),
@@ -139,32 +140,42 @@ impl ToPyObject for SerMode {
 pub(crate) struct CollectWarnings {
     active: bool,
     warnings: RefCell<Option<Vec<String>>>,
+    trace: bool,
+    traces: RefCell<Vec<String>>,
 }
 
 impl CollectWarnings {
-    pub(crate) fn new(active: bool) -> Self {
+    pub(crate) fn new(active: bool, trace: bool) -> Self {
         Self {
             active,
             warnings: RefCell::new(None),
+            trace,
+            traces: RefCell::new(Vec::new()),
         }
     }
 
     pub(crate) fn fallback_slow(&self, field_type: &str, value: &PyAny) {
-        if self.active {
+        if self.active || self.trace {
             self.fallback(field_type, value, "slight slowdown possible");
         }
     }
 
     pub(crate) fn fallback_filtering(&self, field_type: &str, value: &PyAny) {
-        if self.active {
+        if self.active || self.trace {
             self.fallback(field_type, value, "filtering via include/exclude unavailable");
         }
     }
 
     fn fallback(&self, field_type: &str, value: &PyAny, reason: &str) {
+        let type_name = value.get_type().name().unwrap_or("<unknown python object>");
+        let message = format!("Expected `{field_type}` but got `{type_name}` - {reason}");
+        if self.trace {
+            self.traces
+                .borrow_mut()
+                .push(format!("{message}, falling back to type inference"));
+        }
         if self.active {
-            let type_name = value.get_type().name().unwrap_or("<unknown python object>");
-            self.add_warning(format!("Expected `{field_type}` but got `{type_name}` - {reason}"));
+            self.add_warning(message);
         }
     }
 
@@ -178,6 +189,9 @@ impl CollectWarnings {
     }
 
     pub(crate) fn final_check(&self, py: Python) -> PyResult<()> {
+        for message in self.traces.borrow_mut().drain(..) {
+            trace(py, &message)?;
+        }
         if self.active {
             match *self.warnings.borrow() {
                 Some(ref warnings) => {
@@ -53,8 +53,9 @@ impl SchemaFilter<usize> {
         index: usize,
         include: Option<&'py PyAny>,
         exclude: Option<&'py PyAny>,
+        all_items: AllItems<'py>,
     ) -> PyResult<Option<(Option<&'py PyAny>, Option<&'py PyAny>)>> {
-        self.filter(index, index, include, exclude)
+        self.filter(index, index, include, exclude, all_items)
     }
 }
 
@@ -105,7 +106,87 @@ impl SchemaFilter<isize> {
         exclude: Option<&'py PyAny>,
     ) -> PyResult<Option<(Option<&'py PyAny>, Option<&'py PyAny>)>> {
         let hash = key.hash()?;
-        self.filter(key, hash, include, exclude)
+        self.filter(key, hash, include, exclude, AllItems::default())
+    }
+}
+
+/// The `"__all__"` entries of `include` and `exclude`, which apply to every item of a sequence, e.g.
+/// `exclude={'__all__': {'password'}}` excludes the `password` field of every item. An item's own entry is merged
+/// with them, see `merge_all_items`.
+/// These are looked up once per call to the sequence's serializer rather than for each item.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct AllItems<'py> {
+    include: Option<&'py PyAny>,
+    exclude: Option<&'py PyAny>,
+}
+
+impl<'py> AllItems<'py> {
+    pub fn new(py: Python<'py>, include: Option<&'py PyAny>, exclude: Option<&'py PyAny>) -> PyResult<Self> {
+        Ok(Self {
+            include: Self::lookup(py, include)?,
+            exclude: Self::lookup(py, exclude)?,
+        })
+    }
+
+    /// in a dict this is the value of `"__all__"`, in a set `"__all__"` is equivalent to `{"__all__": None}`
+    fn lookup(py: Python<'py>, value: Option<&'py PyAny>) -> PyResult<Option<&'py PyAny>> {
+        let all_key = intern!(py, "__all__");
+        match value {
+            Some(value) => {
+                if let Ok(dict) = value.cast_as::<PyDict>() {
+                    Ok(dict.get_item(all_key))
+                } else if let Ok(set) = value.cast_as::<PySet>() {
+                    match set.contains(all_key)? {
+                        true => Ok(Some(py.None().into_ref(py))),
+                        false => Ok(None),
+                    }
+                } else {
+                    Ok(None)
+                }
+            }
+            None => Ok(None),
+        }
+    }
+}
+
+/// merge the `"__all__"` value with an item's own value, as pydantic v1 did: if either is the whole item (`None`)
+/// the item's own value is used, otherwise their keys are combined, merging the values of keys in both
+fn merge_all_items<'py>(all_value: &'py PyAny, item_value: &'py PyAny) -> PyResult<&'py PyAny> {
+    let py = item_value.py();
+    let is_nested = |v: &PyAny| v.cast_as::<PyDict>().is_ok() || v.cast_as::<PySet>().is_ok();
+    if !is_nested(all_value) || !is_nested(item_value) {
+        return Ok(item_value);
+    }
+    let merged = PyDict::new(py);
+    for value in [all_value, item_value] {
+        let items: Vec<(&PyAny, &PyAny)> = match value.cast_as::<PyDict>() {
+            Ok(dict) => dict.iter().collect(),
+            // a key in a set is the whole of that key
+            Err(_) => value
+                .cast_as::<PySet>()?
+                .iter()
+                .map(|k| (k, py.None().into_ref(py)))
+                .collect(),
+        };
+        for (key, value) in items {
+            match merged.get_item(key) {
+                Some(existing) => merged.set_item(key, merge_all_items(existing, value)?)?,
+                None => merged.set_item(key, value)?,
+            }
+        }
+    }
+    Ok(merged)
+}
+
+/// the value for an item in a dict `include` or `exclude`, merged with the `"__all__"` value if there's one
+fn item_value<'py>(
+    dict: &'py PyDict,
+    py_key: impl ToPyObject,
+    all_value: Option<&'py PyAny>,
+) -> PyResult<Option<&'py PyAny>> {
+    match (dict.get_item(py_key), all_value) {
+        (Some(item_value), Some(all_value)) => merge_all_items(all_value, item_value).map(Some),
+        (item_value, all_value) => Ok(item_value.or(all_value)),
     }
 }
 
@@ -118,17 +199,20 @@ trait FilterLogic<T: Eq + Copy> {
     /// this is the somewhat hellish logic for deciding:
     /// 1. whether we should omit a value at a particular index/key - returning `Ok(None)` here
     /// 2. or include it, in which case, what values of `include` and `exclude` should be passed to it
+    ///
+    /// `all_items` is merged with the entry in `include` or `exclude` for the index/key
     fn filter<'py>(
         &self,
         py_key: impl ToPyObject + Copy,
         int_key: T,
         include: Option<&'py PyAny>,
         exclude: Option<&'py PyAny>,
+        all_items: AllItems<'py>,
     ) -> PyResult<Option<(Option<&'py PyAny>, Option<&'py PyAny>)>> {
         let mut next_exclude: Option<&PyAny> = None;
         if let Some(exclude) = exclude {
             if let Ok(exclude_dict) = exclude.cast_as::<PyDict>() {
-                if let Some(exc_value) = exclude_dict.get_item(py_key) {
+                if let Some(exc_value) = item_value(exclude_dict, py_key, all_items.exclude)? {
                     if exc_value.is_none() {
                         // if the index is in exclude, and the exclude value is `None`, we want to omit this index
                         return Ok(None);
@@ -140,7 +224,7 @@ trait FilterLogic<T: Eq + Copy> {
                 }
             } else if let Ok(exclude_set) = exclude.cast_as::<PySet>() {
                 // question: should we `unwrap_or(false)` instead of raise an error here?
-                if exclude_set.contains(py_key)? {
+                if exclude_set.contains(py_key)? || all_items.exclude.is_some() {
                     // index is in the exclude set, we return Ok(None) to omit this index
                     return Ok(None);
                 }
@@ -151,7 +235,7 @@ trait FilterLogic<T: Eq + Copy> {
 
         if let Some(include) = include {
             if let Ok(include_dict) = include.cast_as::<PyDict>() {
-                if let Some(inc_value) = include_dict.get_item(py_key) {
+                if let Some(inc_value) = item_value(include_dict, py_key, all_items.include)? {
                     // if the index is in include, we definitely want to include this index
                     return if inc_value.is_none() {
                         Ok(Some((None, next_exclude)))
@@ -165,7 +249,7 @@ trait FilterLogic<T: Eq + Copy> {
                 }
             } else if let Ok(include_set) = include.cast_as::<PySet>() {
                 // question: as above
-                if include_set.contains(py_key)? {
+                if include_set.contains(py_key)? || all_items.include.is_some() {
                     return Ok(Some((None, next_exclude)));
                 } else if !self.explicit_include(int_key) {
                     // if the index is not in include, include exists, AND it's not in schema include,
@@ -210,7 +294,7 @@ where
 }
 
 #[derive(Debug, Clone)]
-pub(super) struct AnyFilter;
+pub(crate) struct AnyFilter;
 
 impl AnyFilter {
     pub fn new() -> Self {
@@ -224,16 +308,19 @@ impl AnyFilter {
         exclude: Option<&'py PyAny>,
     ) -> PyResult<Option<(Option<&'py PyAny>, Option<&'py PyAny>)>> {
         // just use 0 for the int_key, it's always ignored in the implementation here
-        self.filter(key, 0, include, exclude)
+        self.filter(key, 0, include, exclude, AllItems::default())
     }
 
+    /// `key` is the index of a sequence's item, or when validating, also the key of a dict's value
     pub fn value_filter<'py>(
         &self,
-        index: usize,
+        key: impl ToPyObject + Copy,
         include: Option<&'py PyAny>,
         exclude: Option<&'py PyAny>,
+        all_items: AllItems<'py>,
     ) -> PyResult<Option<(Option<&'py PyAny>, Option<&'py PyAny>)>> {
-        self.filter(index, index, include, exclude)
+        // just use 0 for the int_key, as in `key_filter`
+        self.filter(key, 0, include, exclude, all_items)
     }
 }
 
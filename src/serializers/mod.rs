@@ -1,15 +1,21 @@
+use std::cell::Cell;
 use std::fmt::Debug;
 
+use pyo3::class::gc::{PyTraverseError, PyVisit};
+use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyBytes, PyDict};
 
 use crate::build_context::BuildContext;
+use crate::py_gc::PyGcTraverse;
 use crate::SchemaValidator;
 
-use config::SerializationConfig;
-use extra::{Extra, SerMode};
+pub(crate) use config::SerializationConfig;
+pub(crate) use extra::{Extra, SerMode};
+pub(crate) use filter::{AllItems, AnyFilter};
 pub use shared::CombinedSerializer;
 use shared::{to_json_bytes, BuildSerializer, TypeSerializer};
+pub(crate) use type_serializers::any::SerializeInfer;
 
 mod config;
 mod extra;
@@ -23,8 +29,15 @@ mod type_serializers;
 pub struct SchemaSerializer {
     serializer: CombinedSerializer,
     slots: Vec<CombinedSerializer>,
-    json_size: usize,
+    // the size of the last JSON output, used as the initial capacity of the next, a `Cell` so `to_json` doesn't
+    // need `&mut self` which would fail if the serializer is used from another thread during serialization
+    json_size: Cell<usize>,
     config: SerializationConfig,
+    // the schema and config are kept so the serializer can be rebuilt when it's unpickled
+    schema: PyObject,
+    py_config: Option<PyObject>,
+    #[pyo3(get)]
+    title: PyObject,
 }
 
 #[pymethods]
@@ -33,15 +46,57 @@ impl SchemaSerializer {
     pub fn py_new(py: Python, schema: &PyDict, config: Option<&PyDict>) -> PyResult<Self> {
         let schema = SchemaValidator::validate_schema(py, schema)?;
         let mut build_context = BuildContext::for_schema(schema)?;
-        let serializer = CombinedSerializer::build(schema.cast_as()?, config, &mut build_context)?;
+        let serializer = CombinedSerializer::build(schema.cast_as()?, config, &mut build_context)
+            .map_err(|e| build_context.locate_error(e, schema))?;
+        let title = match config
+            .and_then(|c| c.get_item(intern!(py, "title")))
+            .or_else(|| schema.cast_as::<PyDict>().ok()?.get_item(intern!(py, "title")))
+        {
+            Some(t) => t.into_py(py),
+            None => schema.get_item(intern!(py, "type"))?.into_py(py),
+        };
         Ok(Self {
             serializer,
             slots: build_context.into_slots_ser()?,
-            json_size: 1024,
+            json_size: Cell::new(1024),
             config: SerializationConfig::from_config(config)?,
+            schema: schema.into_py(py),
+            py_config: config.map(|c| c.into_py(py)),
+            title,
         })
     }
 
+    pub fn __reduce__(&self, py: Python) -> PyObject {
+        let args = (self.schema.clone_ref(py), self.py_config.clone());
+        (py.get_type::<Self>(), args).into_py(py)
+    }
+
+    /// as with `SchemaValidator`, serializers are immutable once built so a copy can share them
+    pub fn __deepcopy__(&self, py: Python, _memo: &PyDict) -> PyResult<Py<Self>> {
+        Py::new(py, self.clone())
+    }
+
+    /// as with `SchemaValidator`, lets the garbage collector find cycles through serializer functions
+    fn __traverse__(&self, visit: PyVisit<'_>) -> Result<(), PyTraverseError> {
+        self.serializer.py_gc_traverse(&visit)?;
+        self.slots.py_gc_traverse(&visit)?;
+        visit.call(&self.schema)?;
+        self.py_config.py_gc_traverse(&visit)?;
+        visit.call(&self.title)
+    }
+
+    /// only called by the garbage collector to break a cycle, the serializer can't be used afterwards
+    fn __clear__(&mut self) {
+        self.serializer = type_serializers::any::AnySerializer.into();
+        self.slots.clear();
+        self.py_config = None;
+    }
+
+    /// the schema after validation against the self schema, which is what the serializer was built from
+    pub fn get_schema(&self, py: Python) -> PyObject {
+        self.schema.clone_ref(py)
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn to_python(
         &self,
@@ -55,7 +110,13 @@ impl SchemaSerializer {
         exclude_defaults: Option<bool>,
         exclude_none: Option<bool>,
         round_trip: Option<bool>,
+        ser_json_timedelta: Option<&str>,
+        ser_json_datetime: Option<&str>,
+        ser_json_bytes: Option<&str>,
     ) -> PyResult<PyObject> {
+        let config = self
+            .config
+            .with_overrides(ser_json_timedelta, ser_json_datetime, ser_json_bytes)?;
         let mode: SerMode = mode.into();
         let extra = Extra::new(
             py,
@@ -66,7 +127,7 @@ impl SchemaSerializer {
             exclude_defaults,
             exclude_none,
             round_trip,
-            &self.config,
+            &config,
         );
         let v = self.serializer.to_python(value, include, exclude, &extra)?;
         extra.warnings.final_check(py)?;
@@ -75,7 +136,7 @@ impl SchemaSerializer {
 
     #[allow(clippy::too_many_arguments)]
     pub fn to_json(
-        &mut self,
+        &self,
         py: Python,
         value: &PyAny,
         indent: Option<usize>,
@@ -86,7 +147,13 @@ impl SchemaSerializer {
         exclude_defaults: Option<bool>,
         exclude_none: Option<bool>,
         round_trip: Option<bool>,
+        ser_json_timedelta: Option<&str>,
+        ser_json_datetime: Option<&str>,
+        ser_json_bytes: Option<&str>,
     ) -> PyResult<PyObject> {
+        let config = self
+            .config
+            .with_overrides(ser_json_timedelta, ser_json_datetime, ser_json_bytes)?;
         let mode = SerMode::Json;
         let extra = Extra::new(
             py,
@@ -97,7 +164,7 @@ impl SchemaSerializer {
             exclude_defaults,
             exclude_none,
             round_trip,
-            &self.config,
+            &config,
         );
         let bytes = to_json_bytes(
             value,
@@ -106,20 +173,24 @@ impl SchemaSerializer {
             exclude,
             &extra,
             indent,
-            self.json_size,
+            self.json_size.get(),
         )?;
 
         extra.warnings.final_check(py)?;
 
-        self.json_size = bytes.len();
+        self.json_size.set(bytes.len());
         let py_bytes = PyBytes::new(py, &bytes);
         Ok(py_bytes.into())
     }
 
     pub fn __repr__(&self) -> String {
+        let config = match self.py_config {
+            Some(_) => format!(", config={:?}", self.config),
+            None => String::new(),
+        };
         format!(
-            "SchemaSerializer(serializer={:#?}, slots={:#?})",
-            self.serializer, self.slots
+            "SchemaSerializer(serializer={:#?}, slots={:#?}{})",
+            self.serializer, self.slots, config
         )
     }
 }
@@ -1,10 +1,10 @@
 use pyo3::ffi::PyTypeObject;
-use pyo3::intern;
 use pyo3::once_cell::GILOnceCell;
 use pyo3::prelude::*;
 use pyo3::types::{
     PyByteArray, PyBytes, PyDate, PyDateTime, PyDelta, PyDict, PyFrozenSet, PyList, PySet, PyString, PyTime, PyTuple,
 };
+use pyo3::{intern, AsPyPointer};
 
 use strum_macros::EnumString;
 
@@ -36,6 +36,9 @@ pub struct ObTypeLookup {
     // types from this package
     url: usize,
     multi_host_url: usize,
+    // types from the standard library
+    decimal: usize,
+    enum_object: usize,
 }
 
 static TYPE_LOOKUP: GILOnceCell<ObTypeLookup> = GILOnceCell::new();
@@ -76,6 +79,9 @@ impl ObTypeLookup {
             // types from this package
             url: PyUrl::new(lib_url.clone()).into_py(py).as_ref(py).get_type_ptr() as usize,
             multi_host_url: PyMultiHostUrl::new(lib_url, None).into_py(py).as_ref(py).get_type_ptr() as usize,
+            // types from the standard library
+            decimal: std_type_ptr(py, "decimal", "Decimal"),
+            enum_object: std_type_ptr(py, "enum", "Enum"),
         }
     }
 
@@ -113,6 +119,8 @@ impl ObTypeLookup {
             ObType::Bytearray => self.bytearray == ob_type,
             ObType::Url => self.url == ob_type,
             ObType::MultiHostUrl => self.multi_host_url == ob_type,
+            ObType::Decimal => self.decimal == ob_type,
+            ObType::Enum => self.enum_object == ob_type,
             ObType::Dataclass => is_dataclass(op_value),
             ObType::PydanticModel => is_pydantic_model(op_value),
             ObType::Unknown => false,
@@ -181,6 +189,10 @@ impl ObTypeLookup {
             ObType::Url
         } else if ob_type == self.multi_host_url {
             ObType::MultiHostUrl
+        } else if ob_type == self.decimal {
+            ObType::Decimal
+        } else if ob_type == self.enum_object {
+            ObType::Enum
         } else if is_dataclass(op_value) {
             ObType::Dataclass
         } else if is_pydantic_model(op_value) {
@@ -199,6 +211,15 @@ impl ObTypeLookup {
     }
 }
 
+/// The address of a type from the standard library, or 0 if it can't be imported, e.g. in an embedded interpreter
+/// without the module, 0 never matches a type so values of the type are then treated as unknown
+fn std_type_ptr(py: Python, module: &str, name: &str) -> usize {
+    match py.import(module).and_then(|m| m.getattr(name)) {
+        Ok(py_type) => py_type.as_ptr() as usize,
+        Err(_) => 0,
+    }
+}
+
 fn is_dataclass(op_value: Option<&PyAny>) -> bool {
     if let Some(value) = op_value {
         value
@@ -246,6 +267,9 @@ pub enum ObType {
     // types from this package
     Url,
     MultiHostUrl,
+    // types from the standard library
+    Decimal,
+    Enum,
     // dataclasses and pydantic models
     Dataclass,
     PydanticModel,
@@ -2,6 +2,7 @@ use std::borrow::Cow;
 use std::fmt;
 use std::fmt::Debug;
 
+use pyo3::class::gc::{PyTraverseError, PyVisit};
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
@@ -12,6 +13,7 @@ use serde_json::ser::PrettyFormatter;
 
 use crate::build_context::BuildContext;
 use crate::build_tools::{py_err, py_error_type, SchemaDict};
+use crate::py_gc::PyGcTraverse;
 use crate::PydanticSerializationError;
 
 use super::extra::Extra;
@@ -41,6 +43,16 @@ macro_rules! combined_serializer {
             $($b_key($b_serializer),)*
         }
 
+        // implemented by hand as `enum_dispatch` can't find the variant bindings when used inside `macro_rules!`
+        impl PyGcTraverse for CombinedSerializer {
+            fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+                match self {
+                    $(CombinedSerializer::$e_key(serializer) => serializer.py_gc_traverse(visit),)*
+                    $(CombinedSerializer::$b_key(serializer) => serializer.py_gc_traverse(visit),)*
+                }
+            }
+        }
+
         impl CombinedSerializer {
             fn find_serializer(
                 lookup_type: &str,
@@ -80,6 +92,8 @@ combined_serializer! {
         // `TupleBuilder` based on the `mode` parameter.
         TuplePositional: super::type_serializers::tuple::TuplePositionalSerializer;
         TupleVariable: super::type_serializers::tuple::TupleVariableSerializer;
+        // schemas which appear more than once are built once and referenced from a slot
+        SharedRef: super::type_serializers::shared_ref::SharedRefSerializer;
     }
     // `find_only` is for type_serializers which are built directly via the `type` key and `find_serializer`
     // but aren't actually used for serialization, e.g. their `build` method must return another serializer
@@ -89,6 +103,9 @@ combined_serializer! {
         super::type_serializers::other::FunctionBuilder;
         super::type_serializers::other::CustomErrorBuilder;
         super::type_serializers::literal::LiteralBuildSerializer;
+        super::type_serializers::definitions::DefinitionsBuilder;
+        super::type_serializers::definitions::DefinitionRefBuilder;
+        super::type_serializers::new_class::ModelBuilder;
     }
     // `both` means the struct is added to both the `CombinedSerializer` enum and the match statement in
     // `find_serializer` so they can be used via a `type` str.
@@ -115,6 +132,7 @@ combined_serializer! {
         MultiHostUrl: super::type_serializers::url::MultiHostUrlSerializer;
         Any: super::type_serializers::any::AnySerializer;
         Format: super::type_serializers::format::FunctionSerializer;
+        Precision: super::type_serializers::precision::PrecisionSerializer;
         WithDefault: super::type_serializers::with_default::WithDefaultSerializer;
         Json: super::type_serializers::json::JsonSerializer;
         Recursive: super::type_serializers::recursive::RecursiveRefSerializer;
@@ -189,7 +207,28 @@ impl BuildSerializer for CombinedSerializer {
             }
         }
 
-        Self::_build(schema, config, build_context)
+        let shared_key = build_context.shared_key(schema, config);
+        if let Some(shared) = shared_key.as_deref().and_then(|key| build_context.find_shared(key)) {
+            return Ok(shared);
+        }
+        let serializer = Self::_build(schema, config, build_context);
+        build_context.record_build(schema, &serializer);
+        let serializer = serializer?;
+        match shared_key {
+            // other serializers look inside these, so they're never shared
+            Some(_)
+                if matches!(
+                    serializer,
+                    CombinedSerializer::Any(_) | CombinedSerializer::WithDefault(_)
+                ) =>
+            {
+                Ok(serializer)
+            }
+            Some(key) => Ok(build_context.add_shared(key, serializer, |id, _| {
+                super::type_serializers::shared_ref::SharedRefSerializer::from_id(id)
+            })),
+            None => Ok(serializer),
+        }
     }
 }
 
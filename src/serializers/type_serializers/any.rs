@@ -1,6 +1,7 @@
 use std::borrow::Cow;
 use std::str::from_utf8;
 
+use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{
     PyByteArray, PyBytes, PyDate, PyDateTime, PyDelta, PyDict, PyFrozenSet, PyList, PySet, PyString, PyTime, PyTuple,
@@ -11,17 +12,20 @@ use serde::ser::{Serialize, SerializeMap, SerializeSeq, Serializer};
 use crate::build_context::BuildContext;
 use crate::build_tools::safe_repr;
 use crate::errors::PydanticSerializationError;
+use crate::py_gc::impl_py_gc_traverse;
 use crate::url::{PyMultiHostUrl, PyUrl};
 
 use super::new_class::object_to_dict;
 use super::{
-    py_err_se_err, utf8_py_error, AnyFilter, BuildSerializer, CombinedSerializer, Extra, ObType, SerMode,
+    py_err_se_err, utf8_py_error, AllItems, AnyFilter, BuildSerializer, CombinedSerializer, Extra, ObType, SerMode,
     TypeSerializer,
 };
 
 #[derive(Debug, Clone)]
 pub struct AnySerializer;
 
+impl_py_gc_traverse!(AnySerializer {});
+
 impl BuildSerializer for AnySerializer {
     const EXPECTED_TYPE: &'static str = "any";
 
@@ -97,9 +101,10 @@ pub(crate) fn fallback_to_python_known(
             let py_seq: &$t = value.cast_as()?;
             let mut items = Vec::with_capacity(py_seq.len());
             let filter = AnyFilter::new();
+            let all_items = AllItems::new(py, include, exclude)?;
 
             for (index, element) in py_seq.iter().enumerate() {
-                let op_next = filter.value_filter(index, include, exclude)?;
+                let op_next = filter.value_filter(index, include, exclude, all_items)?;
                 if let Some((next_include, next_exclude)) = op_next {
                     items.push(fallback_to_python(element, next_include, next_exclude, extra)?);
                 }
@@ -166,18 +171,30 @@ pub(crate) fn fallback_to_python_known(
             ObType::Dict => serialize_dict(value.cast_as()?)?,
             ObType::Datetime => {
                 let py_dt: &PyDateTime = value.cast_as()?;
-                let iso_dt = super::datetime_etc::datetime_to_string(py_dt)?;
-                iso_dt.into_py(py)
+                extra.config.datetime_mode.to_json(
+                    py,
+                    py_dt,
+                    super::datetime_etc::datetime_to_string,
+                    super::datetime_etc::datetime_to_timestamp,
+                )?
             }
             ObType::Date => {
                 let py_date: &PyDate = value.cast_as()?;
-                let iso_date = super::datetime_etc::date_to_string(py_date)?;
-                iso_date.into_py(py)
+                extra.config.datetime_mode.to_json(
+                    py,
+                    py_date,
+                    super::datetime_etc::date_to_string,
+                    super::datetime_etc::date_to_timestamp,
+                )?
             }
             ObType::Time => {
                 let py_time: &PyTime = value.cast_as()?;
-                let iso_time = super::datetime_etc::time_to_string(py_time)?;
-                iso_time.into_py(py)
+                extra.config.datetime_mode.to_json(
+                    py,
+                    py_time,
+                    super::datetime_etc::time_to_string,
+                    super::datetime_etc::time_to_timestamp,
+                )?
             }
             ObType::Timedelta => {
                 let py_timedelta: &PyDelta = value.cast_as()?;
@@ -191,6 +208,11 @@ pub(crate) fn fallback_to_python_known(
                 let py_url: PyMultiHostUrl = value.extract()?;
                 py_url.__str__().into_py(py)
             }
+            ObType::Decimal => extra.config.decimal_mode.decimal_to_json(value)?,
+            ObType::Enum => {
+                let enum_value = value.getattr(intern!(py, "value"))?;
+                fallback_to_python(enum_value, include, exclude, extra)?
+            }
             ObType::Dataclass => serialize_dict(object_to_dict(value, false, extra)?)?,
             ObType::PydanticModel => serialize_dict(object_to_dict(value, true, extra)?)?,
             ObType::Unknown => return Err(unknown_type_error(value)),
@@ -318,8 +340,11 @@ pub(crate) fn fallback_serialize_known<S: Serializer>(
             let py_seq: &$t = value.cast_as().map_err(py_err_se_err)?;
             let mut seq = serializer.serialize_seq(Some(py_seq.len()))?;
             let filter = AnyFilter::new();
+            let all_items = AllItems::new(value.py(), include, exclude).map_err(py_err_se_err)?;
             for (index, element) in py_seq.iter().enumerate() {
-                let op_next = filter.value_filter(index, include, exclude).map_err(py_err_se_err)?;
+                let op_next = filter
+                    .value_filter(index, include, exclude, all_items)
+                    .map_err(py_err_se_err)?;
                 if let Some((next_include, next_exclude)) = op_next {
                     let item_serializer = SerializeInfer::new(element, next_include, next_exclude, extra);
                     seq.serialize_element(&item_serializer)?
@@ -374,18 +399,30 @@ pub(crate) fn fallback_serialize_known<S: Serializer>(
         ObType::Frozenset => serialize_seq!(PyFrozenSet),
         ObType::Datetime => {
             let py_dt: &PyDateTime = value.cast_as().map_err(py_err_se_err)?;
-            let iso_dt = super::datetime_etc::datetime_to_string(py_dt).map_err(py_err_se_err)?;
-            serializer.serialize_str(&iso_dt)
+            extra.config.datetime_mode.serialize(
+                py_dt,
+                super::datetime_etc::datetime_to_string,
+                super::datetime_etc::datetime_to_timestamp,
+                serializer,
+            )
         }
         ObType::Date => {
             let py_date: &PyDate = value.cast_as().map_err(py_err_se_err)?;
-            let iso_date = super::datetime_etc::date_to_string(py_date).map_err(py_err_se_err)?;
-            serializer.serialize_str(&iso_date)
+            extra.config.datetime_mode.serialize(
+                py_date,
+                super::datetime_etc::date_to_string,
+                super::datetime_etc::date_to_timestamp,
+                serializer,
+            )
         }
         ObType::Time => {
             let py_time: &PyTime = value.cast_as().map_err(py_err_se_err)?;
-            let iso_time = super::datetime_etc::time_to_string(py_time).map_err(py_err_se_err)?;
-            serializer.serialize_str(&iso_time)
+            extra.config.datetime_mode.serialize(
+                py_time,
+                super::datetime_etc::time_to_string,
+                super::datetime_etc::time_to_timestamp,
+                serializer,
+            )
         }
         ObType::Timedelta => {
             let py_timedelta: &PyDelta = value.cast_as().map_err(py_err_se_err)?;
@@ -402,6 +439,11 @@ pub(crate) fn fallback_serialize_known<S: Serializer>(
             let py_url: PyMultiHostUrl = value.extract().map_err(py_err_se_err)?;
             serializer.serialize_str(&py_url.__str__())
         }
+        ObType::Decimal => extra.config.decimal_mode.decimal_serialize(value, serializer),
+        ObType::Enum => {
+            let enum_value = value.getattr(intern!(value.py(), "value")).map_err(py_err_se_err)?;
+            fallback_serialize(enum_value, serializer, include, exclude, extra)
+        }
         ObType::Dataclass => serialize_dict!(object_to_dict(value, false, extra).map_err(py_err_se_err)?),
         ObType::PydanticModel => serialize_dict!(object_to_dict(value, true, extra).map_err(py_err_se_err)?),
         ObType::Unknown => return Err(py_err_se_err(unknown_type_error(value))),
@@ -435,18 +477,30 @@ pub(crate) fn fallback_json_key<'py>(key: &'py PyAny, extra: &Extra) -> PyResult
         // perhaps we could do something faster for things like ints and floats?
         ObType::Datetime => {
             let py_dt: &PyDateTime = key.cast_as()?;
-            let iso_dt = super::datetime_etc::datetime_to_string(py_dt)?;
-            Ok(Cow::Owned(iso_dt))
+            let key = extra.config.datetime_mode.json_key(
+                py_dt,
+                super::datetime_etc::datetime_to_string,
+                super::datetime_etc::datetime_to_timestamp,
+            )?;
+            Ok(Cow::Owned(key))
         }
         ObType::Date => {
             let py_date: &PyDate = key.cast_as()?;
-            let iso_date = super::datetime_etc::date_to_string(py_date)?;
-            Ok(Cow::Owned(iso_date))
+            let key = extra.config.datetime_mode.json_key(
+                py_date,
+                super::datetime_etc::date_to_string,
+                super::datetime_etc::date_to_timestamp,
+            )?;
+            Ok(Cow::Owned(key))
         }
         ObType::Time => {
             let py_time: &PyTime = key.cast_as()?;
-            let iso_time = super::datetime_etc::time_to_string(py_time)?;
-            Ok(Cow::Owned(iso_time))
+            let key = extra.config.datetime_mode.json_key(
+                py_time,
+                super::datetime_etc::time_to_string,
+                super::datetime_etc::time_to_timestamp,
+            )?;
+            Ok(Cow::Owned(key))
         }
         ObType::Timedelta => {
             let py_timedelta: &PyDelta = key.cast_as()?;
@@ -460,6 +514,10 @@ pub(crate) fn fallback_json_key<'py>(key: &'py PyAny, extra: &Extra) -> PyResult
             let py_url: PyMultiHostUrl = key.extract()?;
             Ok(Cow::Owned(py_url.__str__()))
         }
+        ObType::Enum => {
+            let enum_value = key.getattr(intern!(key.py(), "value"))?;
+            fallback_json_key(enum_value, extra)
+        }
         _ => Ok(key.str()?.to_string_lossy()),
     }
 }
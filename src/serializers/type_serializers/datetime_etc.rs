@@ -4,31 +4,55 @@ use pyo3::prelude::*;
 use pyo3::types::{PyDate, PyDateTime, PyDict, PyTime};
 
 use crate::build_context::BuildContext;
-use crate::input::{pydate_as_date, pydatetime_as_datetime, pytime_as_time};
+use crate::input::{
+    date_as_timestamp, datetime_as_timestamp, offset_to_string, pydate_as_date, pydatetime_as_datetime, pytime_as_time,
+    pytime_offset, time_as_timestamp,
+};
+use crate::py_gc::impl_py_gc_traverse;
 
 use super::any::{fallback_json_key, fallback_serialize, fallback_to_python};
-use super::{py_err_se_err, BuildSerializer, CombinedSerializer, Extra, SerMode, TypeSerializer};
+use super::{BuildSerializer, CombinedSerializer, Extra, SerMode, TypeSerializer};
 
 pub(crate) fn datetime_to_string(py_dt: &PyDateTime) -> PyResult<String> {
     let dt = pydatetime_as_datetime(py_dt)?;
     Ok(dt.to_string())
 }
 
+pub(crate) fn datetime_to_timestamp(py_dt: &PyDateTime) -> PyResult<(i64, u32)> {
+    let dt = pydatetime_as_datetime(py_dt)?;
+    Ok(datetime_as_timestamp(&dt))
+}
+
 pub(crate) fn date_to_string(py_date: &PyDate) -> PyResult<String> {
     let date = pydate_as_date!(py_date);
     Ok(date.to_string())
 }
 
+pub(crate) fn date_to_timestamp(py_date: &PyDate) -> PyResult<(i64, u32)> {
+    let date = pydate_as_date!(py_date);
+    Ok(date_as_timestamp(&date))
+}
+
 pub(crate) fn time_to_string(py_time: &PyTime) -> PyResult<String> {
     let time = pytime_as_time!(py_time);
-    Ok(time.to_string())
+    match pytime_offset(py_time)? {
+        Some(offset) => Ok(format!("{}{}", time, offset_to_string(offset))),
+        None => Ok(time.to_string()),
+    }
+}
+
+pub(crate) fn time_to_timestamp(py_time: &PyTime) -> PyResult<(i64, u32)> {
+    let time = pytime_as_time!(py_time);
+    Ok(time_as_timestamp(&time))
 }
 
 macro_rules! build_serializer {
-    ($struct_name:ident, $expected_type:literal, $cast_as:ty, $convert_func:ident) => {
+    ($struct_name:ident, $expected_type:literal, $cast_as:ty, $convert_func:ident, $timestamp_func:ident) => {
         #[derive(Debug, Clone)]
         pub struct $struct_name;
 
+        impl_py_gc_traverse!($struct_name {});
+
         impl BuildSerializer for $struct_name {
             const EXPECTED_TYPE: &'static str = $expected_type;
 
@@ -53,8 +77,10 @@ macro_rules! build_serializer {
                 match value.cast_as::<$cast_as>() {
                     Ok(py_value) => match extra.mode {
                         SerMode::Json => {
-                            let s = $convert_func(py_value)?;
-                            Ok(s.into_py(py))
+                            extra
+                                .config
+                                .datetime_mode
+                                .to_json(py, py_value, $convert_func, $timestamp_func)
                         }
                         _ => Ok(value.into_py(py)),
                     },
@@ -67,7 +93,13 @@ macro_rules! build_serializer {
 
             fn json_key<'py>(&self, key: &'py PyAny, extra: &Extra) -> PyResult<Cow<'py, str>> {
                 match key.cast_as::<$cast_as>() {
-                    Ok(py_value) => Ok(Cow::Owned($convert_func(py_value)?)),
+                    Ok(py_value) => {
+                        let key = extra
+                            .config
+                            .datetime_mode
+                            .json_key(py_value, $convert_func, $timestamp_func)?;
+                        Ok(Cow::Owned(key))
+                    }
                     Err(_) => {
                         extra.warnings.fallback_slow(Self::EXPECTED_TYPE, key);
                         fallback_json_key(key, extra)
@@ -85,8 +117,10 @@ macro_rules! build_serializer {
             ) -> Result<S::Ok, S::Error> {
                 match value.cast_as::<$cast_as>() {
                     Ok(py_value) => {
-                        let s = $convert_func(py_value).map_err(py_err_se_err)?;
-                        serializer.serialize_str(&s)
+                        extra
+                            .config
+                            .datetime_mode
+                            .serialize(py_value, $convert_func, $timestamp_func, serializer)
                     }
                     Err(_) => {
                         extra.warnings.fallback_slow(Self::EXPECTED_TYPE, value);
@@ -98,6 +132,12 @@ macro_rules! build_serializer {
     };
 }
 
-build_serializer!(DatetimeSerializer, "datetime", PyDateTime, datetime_to_string);
-build_serializer!(DateSerializer, "date", PyDate, date_to_string);
-build_serializer!(TimeSerializer, "time", PyTime, time_to_string);
+build_serializer!(
+    DatetimeSerializer,
+    "datetime",
+    PyDateTime,
+    datetime_to_string,
+    datetime_to_timestamp
+);
+build_serializer!(DateSerializer, "date", PyDate, date_to_string, date_to_timestamp);
+build_serializer!(TimeSerializer, "time", PyTime, time_to_string, time_to_timestamp);
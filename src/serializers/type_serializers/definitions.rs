@@ -0,0 +1,45 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+
+use crate::build_context::BuildContext;
+use crate::build_tools::SchemaDict;
+
+use super::recursive::RecursiveRefSerializer;
+use super::{BuildSerializer, CombinedSerializer};
+
+pub struct DefinitionsBuilder;
+
+impl BuildSerializer for DefinitionsBuilder {
+    const EXPECTED_TYPE: &'static str = "definitions";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        build_context: &mut BuildContext<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        let py = schema.py();
+        let definitions: &PyList = schema.get_as_req(intern!(py, "definitions"))?;
+        for (slot_id, definition) in build_context.prepare_definitions(definitions)? {
+            let serializer = CombinedSerializer::build(definition, config, build_context)?;
+            build_context.complete_slot(slot_id, serializer)?;
+        }
+        CombinedSerializer::build(schema.get_as_req(intern!(py, "schema"))?, config, build_context)
+    }
+}
+
+pub struct DefinitionRefBuilder;
+
+impl BuildSerializer for DefinitionRefBuilder {
+    const EXPECTED_TYPE: &'static str = "definition-ref";
+
+    fn build(
+        schema: &PyDict,
+        _config: Option<&PyDict>,
+        build_context: &mut BuildContext<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        let schema_ref: String = schema.get_as_req(intern!(schema.py(), "schema_ref"))?;
+        let (serializer_id, _) = build_context.find_slot_id_answer(&schema_ref)?;
+        Ok(RecursiveRefSerializer::from_id(serializer_id))
+    }
+}
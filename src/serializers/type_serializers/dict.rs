@@ -6,6 +6,7 @@ use serde::ser::SerializeMap;
 
 use crate::build_context::BuildContext;
 use crate::build_tools::SchemaDict;
+use crate::py_gc::impl_py_gc_traverse;
 
 use super::any::{fallback_serialize, fallback_to_python, AnySerializer};
 use super::{
@@ -21,6 +22,11 @@ pub struct DictSerializer {
     filter: SchemaFilter<isize>,
 }
 
+impl_py_gc_traverse!(DictSerializer {
+    key_serializer,
+    value_serializer
+});
+
 impl BuildSerializer for DictSerializer {
     const EXPECTED_TYPE: &'static str = "dict";
 
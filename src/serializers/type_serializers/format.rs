@@ -9,6 +9,7 @@ use serde::ser::Error;
 use crate::build_context::BuildContext;
 use crate::build_tools::SchemaDict;
 use crate::errors::PydanticSerializationError;
+use crate::py_gc::impl_py_gc_traverse;
 
 use super::any::fallback_json_key;
 use super::string::serialize_py_str;
@@ -20,6 +21,11 @@ pub struct FunctionSerializer {
     formatting_string: Py<PyString>,
 }
 
+impl_py_gc_traverse!(FunctionSerializer {
+    format_func,
+    formatting_string
+});
+
 impl BuildSerializer for FunctionSerializer {
     const EXPECTED_TYPE: &'static str = "format";
 
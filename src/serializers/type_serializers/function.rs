@@ -10,6 +10,7 @@ use serde::ser::Error;
 use crate::build_context::BuildContext;
 use crate::build_tools::{function_name, kwargs, py_error_type, SchemaDict};
 use crate::errors::PydanticSerializationError;
+use crate::py_gc::impl_py_gc_traverse;
 
 use super::any::{
     fallback_json_key, fallback_serialize, fallback_serialize_known, fallback_to_python, fallback_to_python_known,
@@ -23,6 +24,8 @@ pub struct FunctionSerializer {
     return_ob_type: Option<ObType>,
 }
 
+impl_py_gc_traverse!(FunctionSerializer { func });
+
 impl BuildSerializer for FunctionSerializer {
     // this value is never used, it's just here to satisfy the trait
     const EXPECTED_TYPE: &'static str = "";
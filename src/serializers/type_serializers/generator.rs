@@ -6,11 +6,12 @@ use serde::ser::SerializeSeq;
 
 use crate::build_context::BuildContext;
 use crate::build_tools::SchemaDict;
+use crate::py_gc::impl_py_gc_traverse;
 
 use super::any::{fallback_serialize, fallback_to_python, AnySerializer};
 use super::{
-    py_err_se_err, BuildSerializer, CombinedSerializer, Extra, ExtraOwned, PydanticSerializer, SchemaFilter, SerMode,
-    TypeSerializer,
+    py_err_se_err, AllItems, BuildSerializer, CombinedSerializer, Extra, ExtraOwned, PydanticSerializer, SchemaFilter,
+    SerMode, TypeSerializer,
 };
 
 #[derive(Debug, Clone)]
@@ -19,6 +20,8 @@ pub struct GeneratorSerializer {
     filter: SchemaFilter<usize>,
 }
 
+impl_py_gc_traverse!(GeneratorSerializer { item_serializer });
+
 impl BuildSerializer for GeneratorSerializer {
     const EXPECTED_TYPE: &'static str = "generator";
 
@@ -54,6 +57,7 @@ impl TypeSerializer for GeneratorSerializer {
                 match extra.mode {
                     SerMode::Json => {
                         let item_serializer = self.item_serializer.as_ref();
+                        let all_items = AllItems::new(py, include, exclude)?;
 
                         let mut items = match value.len() {
                             Ok(len) => Vec::with_capacity(len),
@@ -61,7 +65,7 @@ impl TypeSerializer for GeneratorSerializer {
                         };
                         for (index, iter_result) in py_iter.enumerate() {
                             let element = iter_result?;
-                            let op_next = self.filter.value_filter(index, include, exclude)?;
+                            let op_next = self.filter.value_filter(index, include, exclude, all_items)?;
                             if let Some((next_include, next_exclude)) = op_next {
                                 items.push(item_serializer.to_python(element, next_include, next_exclude, extra)?);
                             }
@@ -105,12 +109,13 @@ impl TypeSerializer for GeneratorSerializer {
                 };
                 let mut seq = serializer.serialize_seq(len)?;
                 let item_serializer = self.item_serializer.as_ref();
+                let all_items = AllItems::new(value.py(), include, exclude).map_err(py_err_se_err)?;
 
                 for (index, iter_result) in py_iter.enumerate() {
                     let element = iter_result.map_err(py_err_se_err)?;
                     let op_next = self
                         .filter
-                        .value_filter(index, include, exclude)
+                        .value_filter(index, include, exclude, all_items)
                         .map_err(py_err_se_err)?;
                     if let Some((next_include, next_exclude)) = op_next {
                         let item_serialize =
@@ -153,10 +158,11 @@ impl SerializationIterator {
         let include = self.include_arg.as_ref().map(|o| o.as_ref(py));
         let exclude = self.exclude_arg.as_ref().map(|o| o.as_ref(py));
         let extra = self.extra_owned.to_extra(py);
+        let all_items = AllItems::new(py, include, exclude)?;
 
         for iter_result in iterator {
             let element = iter_result?;
-            let filter = self.filter.value_filter(self.index, include, exclude)?;
+            let filter = self.filter.value_filter(self.index, include, exclude, all_items)?;
             self.index += 1;
             if let Some((next_include, next_exclude)) = filter {
                 let v = self
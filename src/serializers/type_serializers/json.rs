@@ -9,6 +9,7 @@ use serde::ser::Error;
 
 use crate::build_context::BuildContext;
 use crate::build_tools::SchemaDict;
+use crate::py_gc::impl_py_gc_traverse;
 
 use super::any::{fallback_json_key, AnySerializer};
 use super::{py_err_se_err, to_json_bytes, utf8_py_error, BuildSerializer, CombinedSerializer, Extra, TypeSerializer};
@@ -18,6 +19,8 @@ pub struct JsonSerializer {
     serializer: Box<CombinedSerializer>,
 }
 
+impl_py_gc_traverse!(JsonSerializer { serializer });
+
 impl BuildSerializer for JsonSerializer {
     const EXPECTED_TYPE: &'static str = "json";
 
@@ -6,10 +6,12 @@ use serde::ser::SerializeSeq;
 
 use crate::build_context::BuildContext;
 use crate::build_tools::SchemaDict;
+use crate::py_gc::impl_py_gc_traverse;
 
 use super::any::{fallback_serialize, fallback_to_python, AnySerializer};
 use super::{
-    py_err_se_err, BuildSerializer, CombinedSerializer, Extra, PydanticSerializer, SchemaFilter, TypeSerializer,
+    py_err_se_err, AllItems, BuildSerializer, CombinedSerializer, Extra, PydanticSerializer, SchemaFilter,
+    TypeSerializer,
 };
 
 #[derive(Debug, Clone)]
@@ -18,6 +20,8 @@ pub struct ListSerializer {
     filter: SchemaFilter<usize>,
 }
 
+impl_py_gc_traverse!(ListSerializer { item_serializer });
+
 impl BuildSerializer for ListSerializer {
     const EXPECTED_TYPE: &'static str = "list";
 
@@ -51,10 +55,11 @@ impl TypeSerializer for ListSerializer {
             Ok(py_list) => {
                 let py = value.py();
                 let item_serializer = self.item_serializer.as_ref();
+                let all_items = AllItems::new(py, include, exclude)?;
 
                 let mut items = Vec::with_capacity(py_list.len());
                 for (index, element) in py_list.iter().enumerate() {
-                    let op_next = self.filter.value_filter(index, include, exclude)?;
+                    let op_next = self.filter.value_filter(index, include, exclude, all_items)?;
                     if let Some((next_include, next_exclude)) = op_next {
                         items.push(item_serializer.to_python(element, next_include, next_exclude, extra)?);
                     }
@@ -80,11 +85,12 @@ impl TypeSerializer for ListSerializer {
             Ok(py_list) => {
                 let mut seq = serializer.serialize_seq(Some(py_list.len()))?;
                 let item_serializer = self.item_serializer.as_ref();
+                let all_items = AllItems::new(value.py(), include, exclude).map_err(py_err_se_err)?;
 
                 for (index, element) in py_list.iter().enumerate() {
                     let op_next = self
                         .filter
-                        .value_filter(index, include, exclude)
+                        .value_filter(index, include, exclude, all_items)
                         .map_err(py_err_se_err)?;
                     if let Some((next_include, next_exclude)) = op_next {
                         let item_serialize =
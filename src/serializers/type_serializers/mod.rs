@@ -1,6 +1,7 @@
 pub mod any;
 pub mod bytes;
 pub mod datetime_etc;
+pub mod definitions;
 pub mod dict;
 pub mod format;
 pub mod function;
@@ -11,8 +12,10 @@ pub mod literal;
 pub mod new_class;
 pub mod nullable;
 pub mod other;
+pub mod precision;
 pub mod recursive;
 pub mod set_frozenset;
+pub mod shared_ref;
 pub mod simple;
 pub mod string;
 pub mod timedelta;
@@ -21,9 +24,9 @@ pub mod typed_dict;
 pub mod url;
 pub mod with_default;
 
-pub(self) use super::config::utf8_py_error;
+pub(self) use super::config::{utf8_py_error, DecimalMode};
 pub(self) use super::extra::{Extra, ExtraOwned, SerMode};
-pub(self) use super::filter::{AnyFilter, SchemaFilter};
+pub(self) use super::filter::{AllItems, AnyFilter, SchemaFilter};
 pub(self) use super::ob_type::{IsType, ObType};
 pub(self) use super::shared::{
     py_err_se_err, to_json_bytes, BuildSerializer, CombinedSerializer, PydanticSerializer, TypeSerializer,
@@ -4,14 +4,19 @@ use pyo3::types::{PyDict, PySet};
 
 use crate::build_context::BuildContext;
 use crate::build_tools::SchemaDict;
+use crate::py_gc::impl_py_gc_traverse;
 
 use super::{py_err_se_err, BuildSerializer, CombinedSerializer, Extra, TypeSerializer};
 
 #[derive(Debug, Clone)]
 pub struct NewClassSerializer {
     serializer: Box<CombinedSerializer>,
+    // with `root_model`, the value of the single `__root__` field is serialized rather than a dict of fields
+    root_model: bool,
 }
 
+impl_py_gc_traverse!(NewClassSerializer { serializer });
+
 impl BuildSerializer for NewClassSerializer {
     const EXPECTED_TYPE: &'static str = "new-class";
 
@@ -23,8 +28,24 @@ impl BuildSerializer for NewClassSerializer {
         let py = schema.py();
         let sub_schema: &PyDict = schema.get_as_req(intern!(py, "schema"))?;
         let serializer = Box::new(CombinedSerializer::build(sub_schema, config, build_context)?);
+        let root_model = schema.get_as(intern!(py, "root_model"))?.unwrap_or(false);
 
-        Ok(Self { serializer }.into())
+        Ok(Self { serializer, root_model }.into())
+    }
+}
+
+/// `model` schemas are serialized the same way as `new-class` schemas, from the instance's `__dict__`
+pub struct ModelBuilder;
+
+impl BuildSerializer for ModelBuilder {
+    const EXPECTED_TYPE: &'static str = "model";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        build_context: &mut BuildContext<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        NewClassSerializer::build(schema, config, build_context)
     }
 }
 
@@ -36,8 +57,8 @@ impl TypeSerializer for NewClassSerializer {
         exclude: Option<&PyAny>,
         extra: &Extra,
     ) -> PyResult<PyObject> {
-        let dict = object_to_dict(value, true, extra)?;
-        self.serializer.to_python(dict, include, exclude, extra)
+        let inner_value = self.get_inner_value(value, extra)?;
+        self.serializer.to_python(inner_value, include, exclude, extra)
     }
 
     fn serde_serialize<S: serde::ser::Serializer>(
@@ -48,9 +69,18 @@ impl TypeSerializer for NewClassSerializer {
         exclude: Option<&PyAny>,
         extra: &Extra,
     ) -> Result<S::Ok, S::Error> {
-        let dict = object_to_dict(value, true, extra).map_err(py_err_se_err)?;
+        let inner_value = self.get_inner_value(value, extra).map_err(py_err_se_err)?;
         self.serializer
-            .serde_serialize(dict, serializer, include, exclude, extra)
+            .serde_serialize(inner_value, serializer, include, exclude, extra)
+    }
+}
+
+impl NewClassSerializer {
+    fn get_inner_value<'py>(&self, value: &'py PyAny, extra: &Extra) -> PyResult<&'py PyAny> {
+        match self.root_model {
+            true => value.getattr(intern!(value.py(), "__root__")),
+            false => Ok(object_to_dict(value, true, extra)?),
+        }
     }
 }
 
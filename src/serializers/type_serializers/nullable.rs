@@ -4,6 +4,7 @@ use pyo3::types::PyDict;
 
 use crate::build_context::BuildContext;
 use crate::build_tools::SchemaDict;
+use crate::py_gc::impl_py_gc_traverse;
 
 use super::{BuildSerializer, CombinedSerializer, Extra, IsType, ObType, TypeSerializer};
 
@@ -12,6 +13,8 @@ pub struct NullableSerializer {
     serializer: Box<CombinedSerializer>,
 }
 
+impl_py_gc_traverse!(NullableSerializer { serializer });
+
 impl BuildSerializer for NullableSerializer {
     const EXPECTED_TYPE: &'static str = "nullable";
 
@@ -21,8 +24,13 @@ impl BuildSerializer for NullableSerializer {
         build_context: &mut BuildContext<CombinedSerializer>,
     ) -> PyResult<CombinedSerializer> {
         let sub_schema = schema.get_as_req::<&PyDict>(intern!(schema.py(), "schema"))?;
+        let serializer = CombinedSerializer::build(sub_schema, config, build_context)?;
+        // `any` already serializes `None`
+        if let CombinedSerializer::Any(_) = serializer {
+            return Ok(serializer);
+        }
         Ok(Self {
-            serializer: Box::new(CombinedSerializer::build(sub_schema, config, build_context)?),
+            serializer: Box::new(serializer),
         }
         .into())
     }
@@ -0,0 +1,150 @@
+use std::borrow::Cow;
+use std::str::FromStr;
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyString};
+
+use serde::Serialize;
+
+use crate::build_context::BuildContext;
+use crate::build_tools::{py_err, SchemaDict};
+use crate::errors::PydanticSerializationError;
+use crate::py_gc::impl_py_gc_traverse;
+
+use super::any::{fallback_json_key, fallback_serialize_known, fallback_to_python_known};
+use super::{py_err_se_err, BuildSerializer, CombinedSerializer, DecimalMode, Extra, ObType, SerMode, TypeSerializer};
+
+/// Rounds ints, floats and `Decimal`s when serializing, in JSON the formatted value is written as is, so trailing zeros
+/// are kept, e.g. `12.30` with `{'type': 'precision', 'mode': 'fixed', 'digits': 2}`
+#[derive(Debug, Clone)]
+pub struct PrecisionSerializer {
+    // the format spec passed to `__format__`, `None` for `repr` mode where `str(value)` is used
+    format_spec: Option<Py<PyString>>,
+}
+
+impl_py_gc_traverse!(PrecisionSerializer { format_spec });
+
+impl BuildSerializer for PrecisionSerializer {
+    const EXPECTED_TYPE: &'static str = "precision";
+
+    fn build(
+        schema: &PyDict,
+        _config: Option<&PyDict>,
+        _build_context: &mut BuildContext<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        let py = schema.py();
+        let mode: &str = schema.get_as_req(intern!(py, "mode"))?;
+        let digits: Option<usize> = schema.get_as(intern!(py, "digits"))?;
+        let format_spec = match (mode, digits) {
+            ("fixed", Some(digits)) => Some(format!(".{digits}f")),
+            ("significant", Some(0)) => return py_err!("`digits` must be greater than 0 with `significant` precision"),
+            ("significant", Some(digits)) => Some(format!(".{digits}g")),
+            ("repr", None) => None,
+            ("fixed" | "significant", None) => return py_err!("`digits` is required with `{}` precision", mode),
+            ("repr", Some(_)) => return py_err!("`digits` is not allowed with `repr` precision"),
+            (s, _) => {
+                return py_err!(
+                    "Invalid precision mode: `{}`, expected `fixed`, `significant` or `repr`",
+                    s
+                )
+            }
+        };
+        Ok(Self {
+            format_spec: format_spec.map(|s| PyString::new(py, &s).into_py(py)),
+        }
+        .into())
+    }
+}
+
+impl PrecisionSerializer {
+    fn format<'py>(&self, value: &'py PyAny) -> PyResult<&'py PyString> {
+        let py = value.py();
+        match self.format_spec {
+            Some(ref spec) => value
+                .call_method1(intern!(py, "__format__"), (spec.as_ref(py),))?
+                .cast_as()
+                .map_err(Into::into),
+            None => value.str(),
+        }
+    }
+
+    /// `value` rounded, as a float for ints and floats, as a `Decimal` for decimals
+    fn round(&self, value: &PyAny, ob_type: &ObType) -> PyResult<PyObject> {
+        let py = value.py();
+        let py_str = self.format(value)?;
+        match ob_type {
+            ObType::Decimal => Ok(value.get_type().call1((py_str,))?.into_py(py)),
+            _ => match py_str.to_str()?.parse::<f64>() {
+                Ok(f) => Ok(f.into_py(py)),
+                Err(e) => Err(PydanticSerializationError::new_err(e.to_string())),
+            },
+        }
+    }
+}
+
+impl TypeSerializer for PrecisionSerializer {
+    fn to_python(
+        &self,
+        value: &PyAny,
+        include: Option<&PyAny>,
+        exclude: Option<&PyAny>,
+        extra: &Extra,
+    ) -> PyResult<PyObject> {
+        let py = value.py();
+        match extra.ob_type_lookup.get_type(value) {
+            ob_type @ (ObType::Int | ObType::Float) => self.round(value, &ob_type),
+            ObType::Decimal => {
+                let rounded = self.round(value, &ObType::Decimal)?;
+                match extra.mode {
+                    SerMode::Json => extra.config.decimal_mode.decimal_to_json(rounded.as_ref(py)),
+                    _ => Ok(rounded),
+                }
+            }
+            ob_type => {
+                extra.warnings.fallback_slow(Self::EXPECTED_TYPE, value);
+                fallback_to_python_known(&ob_type, value, include, exclude, extra)
+            }
+        }
+    }
+
+    fn json_key<'py>(&self, key: &'py PyAny, extra: &Extra) -> PyResult<Cow<'py, str>> {
+        match extra.ob_type_lookup.get_type(key) {
+            ObType::Int | ObType::Float | ObType::Decimal => Ok(Cow::Borrowed(self.format(key)?.to_str()?)),
+            _ => {
+                extra.warnings.fallback_slow(Self::EXPECTED_TYPE, key);
+                fallback_json_key(key, extra)
+            }
+        }
+    }
+
+    fn serde_serialize<S: serde::ser::Serializer>(
+        &self,
+        value: &PyAny,
+        serializer: S,
+        include: Option<&PyAny>,
+        exclude: Option<&PyAny>,
+        extra: &Extra,
+    ) -> Result<S::Ok, S::Error> {
+        let ob_type = extra.ob_type_lookup.get_type(value);
+        match ob_type {
+            ObType::Int | ObType::Float | ObType::Decimal => {
+                let py_str = self.format(value).map_err(py_err_se_err)?;
+                let s = py_str.to_str().map_err(py_err_se_err)?;
+                if matches!(ob_type, ObType::Decimal) && matches!(extra.config.decimal_mode, DecimalMode::Str) {
+                    return serializer.serialize_str(s);
+                }
+                // `serde_json` is built with `arbitrary_precision` so the number is written exactly as formatted,
+                // `nan` and `inf` aren't valid numbers and are serialized as usual
+                match serde_json::Number::from_str(s) {
+                    Ok(number) => number.serialize(serializer),
+                    Err(_) => fallback_serialize_known(&ob_type, value, serializer, include, exclude, extra),
+                }
+            }
+            _ => {
+                extra.warnings.fallback_slow(Self::EXPECTED_TYPE, value);
+                fallback_serialize_known(&ob_type, value, serializer, include, exclude, extra)
+            }
+        }
+    }
+}
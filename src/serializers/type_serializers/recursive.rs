@@ -4,6 +4,7 @@ use pyo3::types::PyDict;
 
 use crate::build_context::BuildContext;
 use crate::build_tools::SchemaDict;
+use crate::py_gc::impl_py_gc_traverse;
 
 use super::{py_err_se_err, BuildSerializer, CombinedSerializer, Extra, TypeSerializer};
 
@@ -12,6 +13,8 @@ pub struct RecursiveRefSerializer {
     serializer_id: usize,
 }
 
+impl_py_gc_traverse!(RecursiveRefSerializer {});
+
 impl RecursiveRefSerializer {
     pub fn from_id(serializer_id: usize) -> CombinedSerializer {
         Self { serializer_id }.into()
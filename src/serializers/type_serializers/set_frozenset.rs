@@ -6,6 +6,7 @@ use serde::ser::SerializeSeq;
 
 use crate::build_context::BuildContext;
 use crate::build_tools::SchemaDict;
+use crate::py_gc::impl_py_gc_traverse;
 
 use super::any::{fallback_serialize, fallback_to_python, AnySerializer};
 use super::{BuildSerializer, CombinedSerializer, Extra, PydanticSerializer, SerMode, TypeSerializer};
@@ -17,6 +18,8 @@ macro_rules! build_serializer {
             item_serializer: Box<CombinedSerializer>,
         }
 
+        impl_py_gc_traverse!($struct_name { item_serializer });
+
         impl BuildSerializer for $struct_name {
             const EXPECTED_TYPE: &'static str = $expected_type;
 
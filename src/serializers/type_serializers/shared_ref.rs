@@ -0,0 +1,52 @@
+use std::borrow::Cow;
+
+use pyo3::prelude::*;
+
+use super::{CombinedSerializer, Extra, TypeSerializer};
+use crate::py_gc::impl_py_gc_traverse;
+
+/// Serializer for a schema which appears more than once, see `SharedRefValidator`.
+#[derive(Debug, Clone)]
+pub struct SharedRefSerializer {
+    serializer_id: usize,
+}
+
+impl_py_gc_traverse!(SharedRefSerializer {});
+
+impl SharedRefSerializer {
+    pub fn from_id(serializer_id: usize) -> CombinedSerializer {
+        Self { serializer_id }.into()
+    }
+
+    fn serializer<'e>(&self, extra: &'e Extra) -> &'e CombinedSerializer {
+        unsafe { extra.slots.get_unchecked(self.serializer_id) }
+    }
+}
+
+impl TypeSerializer for SharedRefSerializer {
+    fn to_python(
+        &self,
+        value: &PyAny,
+        include: Option<&PyAny>,
+        exclude: Option<&PyAny>,
+        extra: &Extra,
+    ) -> PyResult<PyObject> {
+        self.serializer(extra).to_python(value, include, exclude, extra)
+    }
+
+    fn json_key<'py>(&self, key: &'py PyAny, extra: &Extra) -> PyResult<Cow<'py, str>> {
+        self.serializer(extra).json_key(key, extra)
+    }
+
+    fn serde_serialize<S: serde::ser::Serializer>(
+        &self,
+        value: &PyAny,
+        serializer: S,
+        include: Option<&PyAny>,
+        exclude: Option<&PyAny>,
+        extra: &Extra,
+    ) -> Result<S::Ok, S::Error> {
+        self.serializer(extra)
+            .serde_serialize(value, serializer, include, exclude, extra)
+    }
+}
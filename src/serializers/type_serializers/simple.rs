@@ -6,10 +6,13 @@ use serde::Serialize;
 use super::any::{fallback_serialize, fallback_to_python};
 use super::{BuildSerializer, CombinedSerializer, Extra, IsType, ObType, SerMode, TypeSerializer};
 use crate::build_context::BuildContext;
+use crate::py_gc::impl_py_gc_traverse;
 
 #[derive(Debug, Clone)]
 pub struct NoneSerializer;
 
+impl_py_gc_traverse!(NoneSerializer {});
+
 impl BuildSerializer for NoneSerializer {
     const EXPECTED_TYPE: &'static str = "none";
 
@@ -64,6 +67,8 @@ macro_rules! build_simple_serializer {
         #[derive(Debug, Clone)]
         pub struct $struct_name;
 
+        impl_py_gc_traverse!($struct_name {});
+
         impl BuildSerializer for $struct_name {
             const EXPECTED_TYPE: &'static str = $expected_type;
 
@@ -4,6 +4,7 @@ use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyString};
 
 use crate::build_context::BuildContext;
+use crate::py_gc::impl_py_gc_traverse;
 
 use super::any::{fallback_json_key, fallback_serialize, fallback_to_python};
 use super::{py_err_se_err, BuildSerializer, CombinedSerializer, Extra, IsType, ObType, SerMode, TypeSerializer};
@@ -11,6 +12,8 @@ use super::{py_err_se_err, BuildSerializer, CombinedSerializer, Extra, IsType, O
 #[derive(Debug, Clone)]
 pub struct StrSerializer;
 
+impl_py_gc_traverse!(StrSerializer {});
+
 impl BuildSerializer for StrSerializer {
     const EXPECTED_TYPE: &'static str = "str";
 
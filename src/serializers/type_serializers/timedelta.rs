@@ -4,6 +4,7 @@ use pyo3::prelude::*;
 use pyo3::types::{PyDelta, PyDict};
 
 use crate::build_context::BuildContext;
+use crate::py_gc::impl_py_gc_traverse;
 
 use super::any::{fallback_json_key, fallback_serialize, fallback_to_python};
 use super::{BuildSerializer, CombinedSerializer, Extra, SerMode, TypeSerializer};
@@ -11,6 +12,8 @@ use super::{BuildSerializer, CombinedSerializer, Extra, SerMode, TypeSerializer}
 #[derive(Debug, Clone)]
 pub struct TimeDeltaSerializer;
 
+impl_py_gc_traverse!(TimeDeltaSerializer {});
+
 impl BuildSerializer for TimeDeltaSerializer {
     const EXPECTED_TYPE: &'static str = "timedelta";
 
@@ -6,10 +6,11 @@ use serde::ser::SerializeSeq;
 
 use crate::build_context::BuildContext;
 use crate::build_tools::SchemaDict;
+use crate::py_gc::impl_py_gc_traverse;
 
 use super::any::{fallback_serialize, fallback_to_python, AnySerializer};
 use super::{
-    py_err_se_err, BuildSerializer, CombinedSerializer, Extra, PydanticSerializer, SchemaFilter, SerMode,
+    py_err_se_err, AllItems, BuildSerializer, CombinedSerializer, Extra, PydanticSerializer, SchemaFilter, SerMode,
     TypeSerializer,
 };
 
@@ -36,6 +37,8 @@ pub struct TupleVariableSerializer {
     filter: SchemaFilter<usize>,
 }
 
+impl_py_gc_traverse!(TupleVariableSerializer { item_serializer });
+
 impl TupleVariableSerializer {
     fn build(
         schema: &PyDict,
@@ -70,10 +73,11 @@ impl TypeSerializer for TupleVariableSerializer {
             Ok(py_tuple) => {
                 let py = value.py();
                 let item_serializer = self.item_serializer.as_ref();
+                let all_items = AllItems::new(py, include, exclude)?;
 
                 let mut items = Vec::with_capacity(py_tuple.len());
                 for (index, element) in py_tuple.iter().enumerate() {
-                    let op_next = self.filter.value_filter(index, include, exclude)?;
+                    let op_next = self.filter.value_filter(index, include, exclude, all_items)?;
                     if let Some((next_include, next_exclude)) = op_next {
                         items.push(item_serializer.to_python(element, next_include, next_exclude, extra)?);
                     }
@@ -102,12 +106,13 @@ impl TypeSerializer for TupleVariableSerializer {
             Ok(py_tuple) => {
                 let py_tuple: &PyTuple = py_tuple.cast_as().map_err(py_err_se_err)?;
                 let item_serializer = self.item_serializer.as_ref();
+                let all_items = AllItems::new(value.py(), include, exclude).map_err(py_err_se_err)?;
 
                 let mut seq = serializer.serialize_seq(Some(py_tuple.len()))?;
                 for (index, element) in py_tuple.iter().enumerate() {
                     let op_next = self
                         .filter
-                        .value_filter(index, include, exclude)
+                        .value_filter(index, include, exclude, all_items)
                         .map_err(py_err_se_err)?;
                     if let Some((next_include, next_exclude)) = op_next {
                         let item_serialize =
@@ -132,6 +137,11 @@ pub struct TuplePositionalSerializer {
     filter: SchemaFilter<usize>,
 }
 
+impl_py_gc_traverse!(TuplePositionalSerializer {
+    items_serializers,
+    extra_serializer
+});
+
 impl TuplePositionalSerializer {
     fn build(
         schema: &PyDict,
@@ -168,6 +178,7 @@ impl TypeSerializer for TuplePositionalSerializer {
         match value.cast_as::<PyTuple>() {
             Ok(py_tuple) => {
                 let py = value.py();
+                let all_items = AllItems::new(py, include, exclude)?;
 
                 let mut py_tuple_iter = py_tuple.iter();
                 let mut items = Vec::with_capacity(py_tuple.len());
@@ -176,7 +187,7 @@ impl TypeSerializer for TuplePositionalSerializer {
                         Some(value) => value,
                         None => break,
                     };
-                    let op_next = self.filter.value_filter(index, include, exclude)?;
+                    let op_next = self.filter.value_filter(index, include, exclude, all_items)?;
                     if let Some((next_include, next_exclude)) = op_next {
                         items.push(serializer.to_python(element, next_include, next_exclude, extra)?);
                     }
@@ -185,7 +196,7 @@ impl TypeSerializer for TuplePositionalSerializer {
                 let extra_serializer = self.extra_serializer.as_ref();
                 for (index2, element) in py_tuple_iter.enumerate() {
                     let index = index2 + expected_length;
-                    let op_next = self.filter.value_filter(index, include, exclude)?;
+                    let op_next = self.filter.value_filter(index, include, exclude, all_items)?;
                     if let Some((next_include, next_exclude)) = op_next {
                         items.push(extra_serializer.to_python(element, next_include, next_exclude, extra)?);
                     }
@@ -214,6 +225,7 @@ impl TypeSerializer for TuplePositionalSerializer {
         match value.cast_as::<PyTuple>() {
             Ok(py_tuple) => {
                 let py_tuple: &PyTuple = py_tuple.cast_as().map_err(py_err_se_err)?;
+                let all_items = AllItems::new(value.py(), include, exclude).map_err(py_err_se_err)?;
 
                 let mut py_tuple_iter = py_tuple.iter();
                 let mut seq = serializer.serialize_seq(Some(py_tuple.len()))?;
@@ -224,7 +236,7 @@ impl TypeSerializer for TuplePositionalSerializer {
                     };
                     let op_next = self
                         .filter
-                        .value_filter(index, include, exclude)
+                        .value_filter(index, include, exclude, all_items)
                         .map_err(py_err_se_err)?;
                     if let Some((next_include, next_exclude)) = op_next {
                         let item_serialize =
@@ -239,7 +251,7 @@ impl TypeSerializer for TuplePositionalSerializer {
                     let index = index2 + expected_length;
                     let op_next = self
                         .filter
-                        .value_filter(index, include, exclude)
+                        .value_filter(index, include, exclude, all_items)
                         .map_err(py_err_se_err)?;
                     if let Some((next_include, next_exclude)) = op_next {
                         let item_serialize =
@@ -7,14 +7,64 @@ use ahash::AHashMap;
 use serde::ser::SerializeMap;
 
 use crate::build_context::BuildContext;
-use crate::build_tools::{py_error_type, schema_or_config, SchemaDict};
+use crate::build_tools::{py_err, py_error_type, schema_or_config, SchemaDict};
+use crate::py_gc::impl_py_gc_traverse;
+use crate::PydanticUndefinedType;
 
 use super::any::{fallback_json_key, fallback_serialize, fallback_to_python, SerializeInfer};
 use super::with_default::get_default;
 use super::{
-    py_err_se_err, BuildSerializer, CombinedSerializer, Extra, PydanticSerializer, SchemaFilter, TypeSerializer,
+    py_err_se_err, BuildSerializer, CombinedSerializer, Extra, PydanticSerializer, SchemaFilter, SerMode,
+    TypeSerializer,
 };
 
+/// how field names are converted to output keys for fields without a `serialization_alias`, used like an alias
+#[derive(Debug, Clone, Copy)]
+enum KeyTransform {
+    Camel,
+    Pascal,
+    Kebab,
+}
+
+impl KeyTransform {
+    fn new(raw: Option<&str>) -> PyResult<Option<Self>> {
+        match raw {
+            None => Ok(None),
+            Some("camel") => Ok(Some(Self::Camel)),
+            Some("pascal") => Ok(Some(Self::Pascal)),
+            Some("kebab") => Ok(Some(Self::Kebab)),
+            Some(s) => py_err!(
+                r#"Invalid key_transform: "{}", expected "camel", "pascal" or "kebab""#,
+                s
+            ),
+        }
+    }
+
+    /// `key` is assumed to be snake_case, leading underscores are kept, e.g. `_foo_bar` -> `_fooBar`
+    fn apply(self, key: &str) -> String {
+        let name = key.trim_start_matches('_');
+        let mut output = key[..key.len() - name.len()].to_string();
+        let words = name.split('_').filter(|word| !word.is_empty());
+        match self {
+            Self::Kebab => output.push_str(&words.collect::<Vec<_>>().join("-")),
+            Self::Camel | Self::Pascal => {
+                for (index, word) in words.enumerate() {
+                    if index == 0 && matches!(self, Self::Camel) {
+                        output.push_str(word);
+                    } else {
+                        let mut chars = word.chars();
+                        if let Some(first) = chars.next() {
+                            output.extend(first.to_uppercase());
+                            output.push_str(chars.as_str());
+                        }
+                    }
+                }
+            }
+        }
+        output
+    }
+}
+
 #[derive(Debug, Clone)]
 struct TypedDictField {
     key_py: Py<PyString>,
@@ -23,6 +73,12 @@ struct TypedDictField {
     serializer: CombinedSerializer,
 }
 
+impl_py_gc_traverse!(TypedDictField {
+    key_py,
+    alias_py,
+    serializer
+});
+
 impl TypedDictField {
     fn get_key_py<'py>(&'py self, py: Python<'py>, extra: &Extra) -> &'py PyAny {
         if extra.by_alias {
@@ -46,11 +102,17 @@ impl TypedDictField {
 #[derive(Debug, Clone)]
 pub struct TypedDictSerializer {
     fields: AHashMap<String, TypedDictField>,
+    // with `serialization_order` other than "input", the order fields are output in, extras come after fields
+    field_order: Option<Vec<Py<PyString>>>,
     include_extra: bool,
-    // isize because we look up include exclude via `.hash()` which returns an isize
-    filter: SchemaFilter<isize>,
+    // isize because we look up include exclude via `.hash()` which returns an isize,
+    // separate filters so fields excluded in only one mode cost nothing extra to check
+    python_filter: SchemaFilter<isize>,
+    json_filter: SchemaFilter<isize>,
 }
 
+impl_py_gc_traverse!(TypedDictSerializer { fields, field_order });
+
 impl BuildSerializer for TypedDictSerializer {
     const EXPECTED_TYPE: &'static str = "typed-dict";
 
@@ -70,9 +132,18 @@ impl BuildSerializer for TypedDictSerializer {
 
         let include_extra = extra_behavior == Some("allow");
 
+        let key_transform = KeyTransform::new(schema_or_config::<&str>(
+            schema,
+            config,
+            intern!(py, "key_transform"),
+            intern!(py, "typed_dict_key_transform"),
+        )?)?;
+
         let fields_dict: &PyDict = schema.get_as_req(intern!(py, "fields"))?;
         let mut fields: AHashMap<String, TypedDictField> = AHashMap::with_capacity(fields_dict.len());
-        let mut exclude: Vec<Py<PyString>> = Vec::with_capacity(fields_dict.len());
+        let mut python_exclude: Vec<Py<PyString>> = Vec::new();
+        let mut json_exclude: Vec<Py<PyString>> = Vec::new();
+        let mut schema_order: Vec<String> = Vec::with_capacity(fields_dict.len());
 
         for (key, value) in fields_dict.iter() {
             let key: String = key.extract()?;
@@ -83,20 +154,36 @@ impl BuildSerializer for TypedDictSerializer {
             let serializer = CombinedSerializer::build(schema, config, build_context)
                 .map_err(|e| py_error_type!("Field `{}`:\n  {}", key, e))?;
 
-            let (alias, alias_py) = match field_info.get_as::<&PyString>(intern!(py, "serialization_alias"))? {
-                Some(alias_py) => {
-                    let alias: String = alias_py.extract()?;
-                    let alias_py = PyString::intern(py, &alias);
-                    (Some(alias), Some(alias_py.into_py(py)))
-                }
-                None => (None, None),
+            let alias = match field_info.get_as::<String>(intern!(py, "serialization_alias"))? {
+                Some(alias) => Some(alias),
+                None => key_transform.map(|transform| transform.apply(&key)),
             };
+            let alias_py = alias.as_ref().map(|alias| PyString::intern(py, alias).into_py(py));
 
             let key_py: Py<PyString> = PyString::intern(py, &key).into_py(py);
 
-            if field_info.get_as(intern!(py, "serialization_exclude"))? == Some(true) {
-                exclude.push(key_py.clone_ref(py));
+            // `serialization_exclude` is a bool, or the mode the field is excluded in
+            let (exclude_python, exclude_json) = match field_info.get_item(intern!(py, "serialization_exclude")) {
+                Some(exclude) => match exclude.cast_as::<PyString>() {
+                    Ok(exclude_mode) => match exclude_mode.to_str()? {
+                        "python" => (true, false),
+                        "json" => (false, true),
+                        s => return py_err!(r#"Field `{}`: invalid serialization_exclude: "{}""#, key, s),
+                    },
+                    Err(_) => {
+                        let exclude: bool = exclude.extract()?;
+                        (exclude, exclude)
+                    }
+                },
+                None => (false, false),
+            };
+            if exclude_python {
+                python_exclude.push(key_py.clone_ref(py));
+            }
+            if exclude_json {
+                json_exclude.push(key_py.clone_ref(py));
             }
+            schema_order.push(key.clone());
             fields.insert(
                 key,
                 TypedDictField {
@@ -108,12 +195,47 @@ impl BuildSerializer for TypedDictSerializer {
             );
         }
 
-        let filter = SchemaFilter::from_vec_hash(py, exclude)?;
+        // with `by_alias`, fields are output under their alias if they have one, otherwise their name, so these
+        // must be unique or one field's value would overwrite another's
+        let mut output_keys: AHashMap<&str, &str> = AHashMap::with_capacity(fields.len());
+        for key in &schema_order {
+            let output_key = fields[key].alias.as_deref().unwrap_or(key);
+            if let Some(other_key) = output_keys.insert(output_key, key) {
+                return py_err!(
+                    r#"Fields `{}` and `{}` would both be serialized as "{}" with by_alias"#,
+                    other_key,
+                    key,
+                    output_key
+                );
+            }
+        }
+
+        let python_filter = SchemaFilter::from_vec_hash(py, python_exclude)?;
+        let json_filter = SchemaFilter::from_vec_hash(py, json_exclude)?;
+
+        let serialization_order = schema_or_config::<&str>(
+            schema,
+            config,
+            intern!(py, "serialization_order"),
+            intern!(py, "typed_dict_serialization_order"),
+        )?;
+        let field_order = match serialization_order {
+            None | Some("input") => None,
+            Some("schema") => Some(schema_order),
+            Some("alphabetical") => {
+                schema_order.sort();
+                Some(schema_order)
+            }
+            Some(s) => return py_err!(r#"Invalid serialization_order: "{}""#, s),
+        };
+        let field_order = field_order.map(|order| order.iter().map(|key| fields[key].key_py.clone_ref(py)).collect());
 
         Ok(Self {
             fields,
+            field_order,
             include_extra,
-            filter,
+            python_filter,
+            json_filter,
         }
         .into())
     }
@@ -130,6 +252,38 @@ impl TypedDictSerializer {
         }
         Ok(false)
     }
+
+    /// With a fixed `field_order`, the items of `py_dict` in that order followed by extras in input order,
+    /// otherwise the items in input order.
+    fn dict_items<'py>(
+        &'py self,
+        py_dict: &'py PyDict,
+    ) -> PyResult<Box<dyn Iterator<Item = (&'py PyAny, &'py PyAny)> + 'py>> {
+        let field_order = match self.field_order {
+            Some(ref field_order) => field_order,
+            None => return Ok(Box::new(py_dict.iter())),
+        };
+        let py = py_dict.py();
+        let mut items = Vec::with_capacity(py_dict.len());
+        for key_py in field_order {
+            let key = key_py.as_ref(py);
+            if let Some(value) = py_dict.get_item(key) {
+                items.push((key as &PyAny, value));
+            }
+        }
+        if self.include_extra {
+            for (key, value) in py_dict {
+                let is_field = match key.cast_as::<PyString>() {
+                    Ok(key_py_str) => self.fields.contains_key(key_py_str.to_str()?),
+                    Err(_) => false,
+                };
+                if !is_field {
+                    items.push((key, value));
+                }
+            }
+        }
+        Ok(Box::new(items.into_iter()))
+    }
 }
 
 impl TypeSerializer for TypedDictSerializer {
@@ -143,14 +297,18 @@ impl TypeSerializer for TypedDictSerializer {
         let py = value.py();
         match value.cast_as::<PyDict>() {
             Ok(py_dict) => {
-                // NOTE! we maintain the order of the input dict assuming that's right
+                // NOTE! unless `serialization_order` is set, we maintain the order of the input dict
                 let new_dict = PyDict::new(py);
+                let filter = match extra.mode {
+                    SerMode::Json => &self.json_filter,
+                    _ => &self.python_filter,
+                };
 
-                for (key, value) in py_dict {
-                    if extra.exclude_none && value.is_none() {
+                for (key, value) in self.dict_items(py_dict)? {
+                    if (extra.exclude_none && value.is_none()) || PydanticUndefinedType::is_undefined(value) {
                         continue;
                     }
-                    if let Some((next_include, next_exclude)) = self.filter.key_filter(key, include, exclude)? {
+                    if let Some((next_include, next_exclude)) = filter.key_filter(key, include, exclude)? {
                         if let Ok(key_py_str) = key.cast_as::<PyString>() {
                             if let Some(field) = self.fields.get(key_py_str.to_str()?) {
                                 if self.exclude_default(value, extra, field)? {
@@ -191,15 +349,17 @@ impl TypeSerializer for TypedDictSerializer {
                     true => py_dict.len(),
                     false => self.fields.len(),
                 };
-                // NOTE! As above, we maintain the order of the input dict assuming that's right
+                // NOTE! As above, unless `serialization_order` is set, we maintain the order of the input dict
                 let mut map = serializer.serialize_map(Some(expected_len))?;
 
-                for (key, value) in py_dict {
-                    if extra.exclude_none && value.is_none() {
+                for (key, value) in self.dict_items(py_dict).map_err(py_err_se_err)? {
+                    if (extra.exclude_none && value.is_none()) || PydanticUndefinedType::is_undefined(value) {
                         continue;
                     }
-                    if let Some((next_include, next_exclude)) =
-                        self.filter.key_filter(key, include, exclude).map_err(py_err_se_err)?
+                    if let Some((next_include, next_exclude)) = self
+                        .json_filter
+                        .key_filter(key, include, exclude)
+                        .map_err(py_err_se_err)?
                     {
                         if let Ok(key_py_str) = key.cast_as::<PyString>() {
                             let key_str = key_py_str.to_str().map_err(py_err_se_err)?;
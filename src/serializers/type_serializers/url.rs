@@ -4,16 +4,21 @@ use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
 use crate::build_context::BuildContext;
+use crate::py_gc::impl_py_gc_traverse;
 use crate::url::{PyMultiHostUrl, PyUrl};
 
 use super::any::{fallback_json_key, fallback_serialize, fallback_to_python};
 use super::{BuildSerializer, CombinedSerializer, Extra, SerMode, TypeSerializer};
 
+// the string form of a URL is what the validator produces, so JSON output is already valid input and
+// `round_trip` needs no special handling
 macro_rules! build_serializer {
     ($struct_name:ident, $expected_type:literal, $extract:ty) => {
         #[derive(Debug, Clone)]
         pub struct $struct_name;
 
+        impl_py_gc_traverse!($struct_name {});
+
         impl BuildSerializer for $struct_name {
             const EXPECTED_TYPE: &'static str = $expected_type;
 
@@ -6,6 +6,7 @@ use pyo3::types::PyDict;
 
 use crate::build_context::BuildContext;
 use crate::build_tools::SchemaDict;
+use crate::py_gc::impl_py_gc_traverse;
 use crate::validators::DefaultType;
 
 use super::{BuildSerializer, CombinedSerializer, Extra, TypeSerializer};
@@ -16,6 +17,8 @@ pub struct WithDefaultSerializer {
     serializer: Box<CombinedSerializer>,
 }
 
+impl_py_gc_traverse!(WithDefaultSerializer { default, serializer });
+
 impl BuildSerializer for WithDefaultSerializer {
     const EXPECTED_TYPE: &'static str = "default";
 
@@ -28,7 +31,12 @@ impl BuildSerializer for WithDefaultSerializer {
         let default = DefaultType::new(schema)?;
 
         let sub_schema: &PyDict = schema.get_as_req(intern!(py, "schema"))?;
-        let serializer = Box::new(CombinedSerializer::build(sub_schema, config, build_context)?);
+        let serializer = CombinedSerializer::build(sub_schema, config, build_context)?;
+        // without a default, values can't be excluded as defaults so the wrapper has no effect
+        if let DefaultType::None = default {
+            return Ok(serializer);
+        }
+        let serializer = Box::new(serializer);
 
         Ok(Self { default, serializer }.into())
     }
@@ -0,0 +1,81 @@
+use std::cell::RefCell;
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyString};
+
+use ahash::AHashMap;
+
+use crate::build_tools::{py_err, SchemaDict};
+
+/// Which strings are reused during a validation run, set by the `cache_strings` config key
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheStrings {
+    /// every string validated from non-python input, e.g. JSON string values and object keys
+    All,
+    /// only the keys of dicts
+    Keys,
+    /// no caching, every string is a new python object
+    None,
+}
+
+impl CacheStrings {
+    pub fn from_config(config: Option<&PyDict>) -> PyResult<Self> {
+        let config = match config {
+            Some(config) => config,
+            None => return Ok(Self::None),
+        };
+        match config.get_as::<&str>(intern!(config.py(), "cache_strings"))? {
+            None | Some("none") => Ok(Self::None),
+            Some("all") => Ok(Self::All),
+            Some("keys") => Ok(Self::Keys),
+            Some(s) => py_err!(r#"Invalid cache_strings: "{}""#, s),
+        }
+    }
+}
+
+/// Python strings created while validating a single input, so repeated values (e.g. the keys of every
+/// row of a JSON array of objects) share one python object rather than each allocating its own.
+#[derive(Debug)]
+pub struct StringCache {
+    mode: CacheStrings,
+    strings: RefCell<AHashMap<String, Py<PyString>>>,
+}
+
+impl StringCache {
+    pub fn new(mode: CacheStrings) -> Option<Self> {
+        match mode {
+            CacheStrings::None => None,
+            _ => Some(Self {
+                mode,
+                strings: RefCell::new(AHashMap::new()),
+            }),
+        }
+    }
+
+    /// whether string values, not just keys, should be cached
+    pub fn cache_values(&self) -> bool {
+        self.mode == CacheStrings::All
+    }
+
+    /// the cached python string equal to `s`, creating and caching it if it's not been seen before
+    pub fn get_or_insert<'py>(&self, py: Python<'py>, s: &str) -> &'py PyString {
+        // end the borrow before inserting, the cached string is returned as a new reference
+        let cached = self.strings.borrow().get(s).map(|py_string| py_string.clone_ref(py));
+        if let Some(py_string) = cached {
+            return py_string.into_ref(py);
+        }
+        let py_string = PyString::new(py, s);
+        self.strings.borrow_mut().insert(s.to_string(), py_string.into());
+        py_string
+    }
+
+    /// if `key` is a string, replace it with the cached string of the same value, used by dict validators
+    /// where the key validator might not be a string validator, or strings values aren't cached
+    pub fn cache_key(&self, py: Python, key: PyObject) -> PyObject {
+        match key.cast_as::<PyString>(py).ok().and_then(|s| s.to_str().ok()) {
+            Some(s) => self.get_or_insert(py, s).into_py(py),
+            None => key,
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use std::env;
+
+use pyo3::intern;
+use pyo3::once_cell::GILOnceCell;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::build_tools::SchemaDict;
+
+/// Name of the environment variable which switches on tracing for all validators and serializers.
+const TRACE_ENV_VAR: &str = "PYDANTIC_CORE_TRACE";
+
+static LOGGER: GILOnceCell<PyObject> = GILOnceCell::new();
+
+fn get_logger(py: Python) -> PyResult<PyObject> {
+    let logging = py.import("logging")?;
+    Ok(logging
+        .call_method1(intern!(py, "getLogger"), ("pydantic_core",))?
+        .into_py(py))
+}
+
+/// Whether tracing should be enabled for a validator or serializer, either via the `trace` config key
+/// or the `PYDANTIC_CORE_TRACE` environment variable, config takes precedence.
+pub fn trace_enabled(config: Option<&PyDict>) -> PyResult<bool> {
+    let from_config: Option<bool> = match config {
+        Some(c) => c.get_as(intern!(c.py(), "trace"))?,
+        None => None,
+    };
+    match from_config {
+        Some(trace) => Ok(trace),
+        None => Ok(match env::var(TRACE_ENV_VAR) {
+            Ok(value) => !matches!(value.to_lowercase().as_str(), "" | "0" | "false"),
+            Err(_) => false,
+        }),
+    }
+}
+
+/// Log a message at `DEBUG` level to the `pydantic_core` logger from python's `logging` module.
+pub fn trace(py: Python, message: &str) -> PyResult<()> {
+    // the logger is only cached once it's been got successfully, so a failure is returned as an error every time
+    let logger = match LOGGER.get(py) {
+        Some(logger) => logger.clone_ref(py),
+        None => {
+            let logger = get_logger(py)?;
+            // `set` only fails if the logger has already been cached, in which case this one is just as good
+            let _ = LOGGER.set(py, logger.clone_ref(py));
+            logger
+        }
+    };
+    logger.call_method1(py, intern!(py, "debug"), (message,))?;
+    Ok(())
+}
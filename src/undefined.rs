@@ -0,0 +1,40 @@
+use pyo3::once_cell::GILOnceCell;
+use pyo3::prelude::*;
+
+static UNDEFINED_CELL: GILOnceCell<Py<PydanticUndefinedType>> = GILOnceCell::new();
+
+/// The type of `PydanticUndefined`, a singleton for values which are absent rather than `None`, e.g. the fields not
+/// set in a partial update. Validators treat a field with this value as missing, serializers omit it.
+#[pyclass(module = "pydantic_core._pydantic_core")]
+#[derive(Debug)]
+pub struct PydanticUndefinedType {}
+
+#[pymethods]
+impl PydanticUndefinedType {
+    fn __repr__(&self) -> &'static str {
+        "PydanticUndefined"
+    }
+
+    fn __copy__(&self, py: Python) -> Py<Self> {
+        Self::get(py).clone_ref(py)
+    }
+
+    fn __deepcopy__(&self, py: Python, _memo: &PyAny) -> Py<Self> {
+        Self::get(py).clone_ref(py)
+    }
+
+    /// pickled as a reference to the `PydanticUndefined` module attribute, so unpickling gives the singleton
+    fn __reduce__(&self) -> &'static str {
+        "PydanticUndefined"
+    }
+}
+
+impl PydanticUndefinedType {
+    pub fn get(py: Python) -> &'static Py<Self> {
+        UNDEFINED_CELL.get_or_init(py, || Py::new(py, PydanticUndefinedType {}).unwrap())
+    }
+
+    pub fn is_undefined(value: &PyAny) -> bool {
+        value.is(Self::get(value.py()))
+    }
+}
@@ -118,6 +118,10 @@ impl PyMultiHostUrl {
     pub fn mut_lib_url(&mut self) -> &mut Url {
         &mut self.ref_url.lib_url
     }
+
+    pub fn mut_extra_urls(&mut self) -> Option<&mut Vec<Url>> {
+        self.extra_urls.as_mut()
+    }
 }
 
 #[pymethods]
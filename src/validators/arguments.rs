@@ -4,9 +4,10 @@ use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList, PyString, PyTuple};
 
 use crate::build_tools::{py_err, schema_or_config_same, SchemaDict};
-use crate::errors::{ErrorType, ValError, ValLineError, ValResult};
-use crate::input::{GenericArguments, Input};
+use crate::errors::{ErrorType, LocItem, ValError, ValLineError, ValResult};
+use crate::input::{GenericArguments, Input, JsonInput, JsonObject};
 use crate::lookup_key::LookupKey;
+use crate::py_gc::impl_py_gc_traverse;
 use crate::recursion_guard::RecursionGuard;
 
 use super::with_default::get_default;
@@ -15,20 +16,39 @@ use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Ex
 #[derive(Debug, Clone)]
 struct Parameter {
     positional: bool,
-    name: String,
     kw_lookup_key: Option<LookupKey>,
     kwarg_key: Option<Py<PyString>>,
+    // the location of errors for keyword values, either the name or alias depending on `loc_by_alias`
+    kw_error_loc: Vec<LocItem>,
     validator: CombinedValidator,
 }
 
+impl_py_gc_traverse!(Parameter { kwarg_key, validator });
+
+/// how keyword arguments which don't match a parameter are validated by `var_kwargs_schema`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum VarKwargsMode {
+    /// each value is validated separately, like `**kwargs: int`
+    Uniform,
+    /// the keyword arguments are validated together by a typed dict, like `**kwargs: Unpack[TypedDict]`
+    UnpackedTypedDict,
+}
+
 #[derive(Debug, Clone)]
 pub struct ArgumentsValidator {
     parameters: Vec<Parameter>,
     positional_params_count: usize,
     var_args_validator: Option<Box<CombinedValidator>>,
     var_kwargs_validator: Option<Box<CombinedValidator>>,
+    var_kwargs_mode: VarKwargsMode,
 }
 
+impl_py_gc_traverse!(ArgumentsValidator {
+    parameters,
+    var_args_validator,
+    var_kwargs_validator
+});
+
 impl BuildValidator for ArgumentsValidator {
     const EXPECTED_TYPE: &'static str = "arguments";
 
@@ -40,6 +60,7 @@ impl BuildValidator for ArgumentsValidator {
         let py = schema.py();
 
         let populate_by_name = schema_or_config_same(schema, config, intern!(py, "populate_by_name"))?.unwrap_or(false);
+        let loc_by_alias = schema_or_config_same(schema, config, intern!(py, "loc_by_alias"))?.unwrap_or(false);
 
         let arguments_schema: &PyList = schema.get_as_req(intern!(py, "arguments_schema"))?;
         let mut parameters: Vec<Parameter> = Vec::with_capacity(arguments_schema.len());
@@ -72,6 +93,11 @@ impl BuildValidator for ArgumentsValidator {
                 kwarg_key = Some(PyString::intern(py, &name).into());
             }
 
+            let kw_error_loc = match (loc_by_alias, &kw_lookup_key) {
+                (true, Some(lookup_key)) => lookup_key.error_loc(),
+                _ => vec![name.as_str().into()],
+            };
+
             let schema: &PyAny = arg.get_as_req(intern!(py, "schema"))?;
 
             let validator = match build_validator(schema, config, build_context) {
@@ -97,12 +123,24 @@ impl BuildValidator for ArgumentsValidator {
             parameters.push(Parameter {
                 positional,
                 kw_lookup_key,
-                name,
                 kwarg_key,
+                kw_error_loc,
                 validator,
             });
         }
 
+        let var_kwargs_schema: Option<&PyDict> = schema.get_as(intern!(py, "var_kwargs_schema"))?;
+        let var_kwargs_mode = match schema.get_as::<&str>(intern!(py, "var_kwargs_mode"))? {
+            None | Some("uniform") => VarKwargsMode::Uniform,
+            Some("unpacked-typed-dict") => match var_kwargs_schema {
+                Some(s) if s.get_as::<&str>(intern!(py, "type"))? == Some("typed-dict") => {
+                    VarKwargsMode::UnpackedTypedDict
+                }
+                _ => return py_err!("var_kwargs_mode 'unpacked-typed-dict' requires a typed-dict var_kwargs_schema"),
+            },
+            Some(s) => return py_err!("Invalid var_kwargs_mode: '{}'", s),
+        };
+
         Ok(Self {
             parameters,
             positional_params_count,
@@ -110,10 +148,11 @@ impl BuildValidator for ArgumentsValidator {
                 Some(v) => Some(Box::new(build_validator(v, config, build_context)?)),
                 None => None,
             },
-            var_kwargs_validator: match schema.get_item(intern!(py, "var_kwargs_schema")) {
+            var_kwargs_validator: match var_kwargs_schema {
                 Some(v) => Some(Box::new(build_validator(v, config, build_context)?)),
                 None => None,
             },
+            var_kwargs_mode,
         }
         .into())
     }
@@ -143,6 +182,28 @@ macro_rules! json_slice {
     };
 }
 
+// validate unmatched keyword arguments together as a dict with `VarKwargsMode::UnpackedTypedDict`
+macro_rules! py_unpacked_kwargs {
+    ($py:ident, $items:ident, $validator:ident, $extra:ident, $slots:ident, $recursion_guard:ident) => {{
+        let dict = PyDict::new($py);
+        for (key, value) in $items {
+            dict.set_item(key, value)?;
+        }
+        $validator.validate($py, dict.as_ref(), $extra, $slots, $recursion_guard)
+    }};
+}
+
+macro_rules! json_unpacked_kwargs {
+    ($py:ident, $items:ident, $validator:ident, $extra:ident, $slots:ident, $recursion_guard:ident) => {{
+        let object: JsonObject = $items.into_iter().map(|(k, v)| (k.clone(), v.clone())).collect();
+        let input = JsonInput::Object(object);
+        // errors reference the new input, so must be copied
+        $validator
+            .validate($py, &input, $extra, $slots, $recursion_guard)
+            .map_err(|err| err.duplicate($py))
+    }};
+}
+
 impl Validator for ArgumentsValidator {
     fn validate<'s, 'data>(
         &'s self,
@@ -160,7 +221,7 @@ impl Validator for ArgumentsValidator {
         let mut used_kwargs: AHashSet<&str> = AHashSet::with_capacity(self.parameters.len());
 
         macro_rules! process {
-            ($args:ident, $get_method:ident, $get_macro:ident, $slice_macro:ident) => {{
+            ($args:ident, $get_method:ident, $get_macro:ident, $slice_macro:ident, $unpacked_macro:ident) => {{
                 // go through arguments getting the value from args or kwargs and validating it
                 for (index, parameter) in self.parameters.iter().enumerate() {
                     let mut pos_value = None;
@@ -181,11 +242,10 @@ impl Validator for ArgumentsValidator {
 
                     match (pos_value, kw_value) {
                         (Some(_), Some(kw_value)) => {
-                            errors.push(ValLineError::new_with_loc(
-                                ErrorType::MultipleArgumentValues,
-                                kw_value,
-                                parameter.name.clone(),
-                            ));
+                            errors.push(
+                                ValLineError::new(ErrorType::MultipleArgumentValues, kw_value)
+                                    .with_outer_location_items(&parameter.kw_error_loc),
+                            );
                         }
                         (Some(pos_value), None) => {
                             match parameter
@@ -209,28 +269,34 @@ impl Validator for ArgumentsValidator {
                                     errors.extend(
                                         line_errors
                                             .into_iter()
-                                            .map(|err| err.with_outer_location(parameter.name.clone().into())),
+                                            .map(|err| err.with_outer_location_items(&parameter.kw_error_loc)),
                                     );
                                 }
                                 Err(err) => return Err(err),
                             }
                         }
                         (None, None) => {
-                            if let Some(value) = get_default(py, &parameter.validator)? {
-                                if let Some(ref kwarg_key) = parameter.kwarg_key {
-                                    output_kwargs.set_item(kwarg_key, value.as_ref())?;
-                                } else {
-                                    output_args.push(value.as_ref().clone_ref(py));
-                                }
-                            } else if parameter.kwarg_key.is_some() {
-                                errors.push(ValLineError::new_with_loc(
-                                    ErrorType::MissingKeywordArgument,
-                                    input,
-                                    parameter.name.clone(),
-                                ));
-                            } else {
-                                errors.push(ValLineError::new_with_loc(ErrorType::MissingPositionalArgument, input, index));
+                            let error_loc = match parameter.kwarg_key {
+                                Some(_) => parameter.kw_error_loc.clone(),
+                                None => vec![index.into()],
                             };
+                            match get_default(py, &parameter.validator, &error_loc, extra, slots, recursion_guard) {
+                                Ok(Some(value)) => match parameter.kwarg_key {
+                                    Some(ref kwarg_key) => output_kwargs.set_item(kwarg_key, value)?,
+                                    None => output_args.push(value),
+                                },
+                                Ok(None) if parameter.kwarg_key.is_some() => errors.push(
+                                    ValLineError::new(ErrorType::MissingKeywordArgument, input)
+                                        .with_outer_location_items(&parameter.kw_error_loc),
+                                ),
+                                Ok(None) => errors.push(ValLineError::new_with_loc(
+                                    ErrorType::MissingPositionalArgument,
+                                    input,
+                                    index,
+                                )),
+                                Err(ValError::LineErrors(line_errors)) => errors.extend(line_errors),
+                                Err(err) => return Err(err),
+                            }
                         }
                     }
                 }
@@ -263,6 +329,7 @@ impl Validator for ArgumentsValidator {
                         }
                     }
                 }
+                let mut unpacked_kwargs = Vec::new();
                 // if there are kwargs check any that haven't been processed yet
                 if let Some(kwargs) = $args.kwargs {
                     for (raw_key, value) in kwargs.iter() {
@@ -281,6 +348,10 @@ impl Validator for ArgumentsValidator {
                         };
                         if !used_kwargs.contains(either_str.as_cow()?.as_ref()) {
                             match self.var_kwargs_validator {
+                                // validated together below
+                                Some(_) if self.var_kwargs_mode == VarKwargsMode::UnpackedTypedDict => {
+                                    unpacked_kwargs.push((raw_key, value));
+                                }
                                 Some(ref validator) => match validator.validate(py, value, extra, slots, recursion_guard) {
                                     Ok(value) => output_kwargs.set_item(either_str.as_py_string(py), value)?,
                                     Err(ValError::LineErrors(line_errors)) => {
@@ -301,11 +372,33 @@ impl Validator for ArgumentsValidator {
                         }
                     }
                 }
+                // the typed dict is validated even without unmatched kwargs since it may have required keys,
+                // its errors are already located by key
+                if let (Some(validator), VarKwargsMode::UnpackedTypedDict) =
+                    (&self.var_kwargs_validator, self.var_kwargs_mode)
+                {
+                    match $unpacked_macro!(py, unpacked_kwargs, validator, extra, slots, recursion_guard) {
+                        Ok(value) => {
+                            let validated_kwargs: &PyDict = value.as_ref(py).cast_as()?;
+                            for (key, value) in validated_kwargs {
+                                output_kwargs.set_item(key, value)?;
+                            }
+                        }
+                        Err(ValError::LineErrors(line_errors)) => {
+                            errors.extend(line_errors.into_iter().map(|err| match err.error_type {
+                                ErrorType::Missing => err.with_type(ErrorType::MissingKeywordArgument),
+                                ErrorType::ExtraForbidden => err.with_type(ErrorType::UnexpectedKeywordArgument),
+                                _ => err,
+                            }));
+                        }
+                        Err(err) => return Err(err),
+                    }
+                }
             }};
         }
         match args {
-            GenericArguments::Py(a) => process!(a, py_get_dict_item, py_get, py_slice),
-            GenericArguments::Json(a) => process!(a, json_get, json_get, json_slice),
+            GenericArguments::Py(a) => process!(a, py_get_dict_item, py_get, py_slice, py_unpacked_kwargs),
+            GenericArguments::Json(a) => process!(a, json_get, json_get, json_slice, json_unpacked_kwargs),
         }
         if !errors.is_empty() {
             Err(ValError::LineErrors(errors))
@@ -1,16 +1,84 @@
+use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
-use crate::build_tools::is_strict;
-use crate::errors::ValResult;
+use crate::build_tools::{is_strict, py_err, schema_or_config};
+use crate::errors::{ErrorType, ValError, ValResult};
 use crate::input::Input;
+use crate::py_gc::impl_py_gc_traverse;
+use crate::questions::Question;
 use crate::recursion_guard::RecursionGuard;
 
 use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
 
+const DEFAULT_TRUE_VALUES: [&str; 6] = ["1", "t", "y", "on", "yes", "true"];
+const DEFAULT_FALSE_VALUES: [&str; 6] = ["0", "f", "n", "no", "off", "false"];
+
+/// Strings accepted as bools in lax mode when `true_values` or `false_values` are set, values are compared
+/// ignoring ASCII case as with the default values.
+#[derive(Debug, Clone)]
+struct BoolStrings {
+    true_values: Vec<String>,
+    false_values: Vec<String>,
+}
+
+impl BoolStrings {
+    fn build(schema: &PyDict, config: Option<&PyDict>) -> PyResult<Option<Self>> {
+        let py = schema.py();
+        let true_values: Option<Vec<String>> = schema_or_config(
+            schema,
+            config,
+            intern!(py, "true_values"),
+            intern!(py, "bool_true_values"),
+        )?;
+        let false_values: Option<Vec<String>> = schema_or_config(
+            schema,
+            config,
+            intern!(py, "false_values"),
+            intern!(py, "bool_false_values"),
+        )?;
+        if true_values.is_none() && false_values.is_none() {
+            return Ok(None);
+        }
+        let normalize = |values: Option<Vec<String>>, default: [&str; 6]| match values {
+            Some(values) => values.iter().map(|v| v.to_ascii_lowercase()).collect::<Vec<_>>(),
+            None => default.iter().map(|v| v.to_string()).collect(),
+        };
+        let true_values = normalize(true_values, DEFAULT_TRUE_VALUES);
+        let false_values = normalize(false_values, DEFAULT_FALSE_VALUES);
+        if let Some(value) = true_values.iter().find(|v| false_values.contains(v)) {
+            return py_err!("'{}' can't be in both `true_values` and `false_values`", value);
+        }
+        Ok(Some(Self {
+            true_values,
+            false_values,
+        }))
+    }
+
+    fn str_as_bool<'a>(&self, input: &'a impl Input<'a>, str: &str) -> ValResult<'a, bool> {
+        if self.true_values.iter().any(|v| v.eq_ignore_ascii_case(str)) {
+            Ok(true)
+        } else if self.false_values.iter().any(|v| v.eq_ignore_ascii_case(str)) {
+            Ok(false)
+        } else {
+            Err(ValError::new(ErrorType::BoolParsing, input))
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct BoolValidator {
-    strict: bool,
+    pub(crate) strict: bool,
+    strings: Option<BoolStrings>,
+}
+
+impl_py_gc_traverse!(BoolValidator {});
+
+impl BoolValidator {
+    /// true unless `true_values` or `false_values` are set, used to decide if lax strings can be validated without python
+    pub(crate) fn default_strings(&self) -> bool {
+        self.strings.is_none()
+    }
 }
 
 impl BuildValidator for BoolValidator {
@@ -23,6 +91,7 @@ impl BuildValidator for BoolValidator {
     ) -> PyResult<CombinedValidator> {
         Ok(Self {
             strict: is_strict(schema, config)?,
+            strings: BoolStrings::build(schema, config)?,
         }
         .into())
     }
@@ -37,12 +106,22 @@ impl Validator for BoolValidator {
         _slots: &'data [CombinedValidator],
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
+        let strict = extra.strict.unwrap_or(self.strict);
+        if let (false, Some(strings)) = (strict, &self.strings) {
+            if let Ok(either_str) = input.strict_str() {
+                return Ok(strings.str_as_bool(input, &either_str.as_cow()?)?.into_py(py));
+            }
+        }
         // TODO in theory this could be quicker if we used PyBool rather than going to a bool
         // and back again, might be worth profiling?
-        Ok(input.validate_bool(extra.strict.unwrap_or(self.strict))?.into_py(py))
+        Ok(input.validate_bool(strict)?.into_py(py))
     }
 
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
+
+    fn ask(&self, _py: Python, question: &Question) -> bool {
+        matches!(question, Question::RejectsNone)
+    }
 }
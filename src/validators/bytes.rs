@@ -5,6 +5,8 @@ use pyo3::types::PyDict;
 use crate::build_tools::{is_strict, SchemaDict};
 use crate::errors::{ErrorType, ValError, ValResult};
 use crate::input::Input;
+use crate::py_gc::impl_py_gc_traverse;
+use crate::questions::Question;
 use crate::recursion_guard::RecursionGuard;
 
 use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
@@ -14,6 +16,8 @@ pub struct BytesValidator {
     strict: bool,
 }
 
+impl_py_gc_traverse!(BytesValidator {});
+
 impl BuildValidator for BytesValidator {
     const EXPECTED_TYPE: &'static str = "bytes";
 
@@ -52,6 +56,10 @@ impl Validator for BytesValidator {
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
+
+    fn ask(&self, _py: Python, question: &Question) -> bool {
+        matches!(question, Question::RejectsNone)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -61,6 +69,8 @@ pub struct BytesConstrainedValidator {
     min_length: Option<usize>,
 }
 
+impl_py_gc_traverse!(BytesConstrainedValidator {});
+
 impl Validator for BytesConstrainedValidator {
     fn validate<'s, 'data>(
         &'s self,
@@ -75,12 +85,26 @@ impl Validator for BytesConstrainedValidator {
 
         if let Some(min_length) = self.min_length {
             if len < min_length {
-                return Err(ValError::new(ErrorType::BytesTooShort { min_length }, input));
+                return Err(ValError::new(
+                    ErrorType::BytesTooShort {
+                        field_type: "Bytes".to_string(),
+                        min_length,
+                        actual_length: len,
+                    },
+                    input,
+                ));
             }
         }
         if let Some(max_length) = self.max_length {
             if len > max_length {
-                return Err(ValError::new(ErrorType::BytesTooLong { max_length }, input));
+                return Err(ValError::new(
+                    ErrorType::BytesTooLong {
+                        field_type: "Bytes".to_string(),
+                        max_length,
+                        actual_length: len,
+                    },
+                    input,
+                ));
             }
         }
 
@@ -90,6 +114,10 @@ impl Validator for BytesConstrainedValidator {
     fn get_name(&self) -> &str {
         "constrained-bytes"
     }
+
+    fn ask(&self, _py: Python, question: &Question) -> bool {
+        matches!(question, Question::RejectsNone)
+    }
 }
 
 impl BytesConstrainedValidator {
@@ -6,6 +6,7 @@ use pyo3::types::{PyDict, PyTuple};
 use crate::build_tools::SchemaDict;
 use crate::errors::ValResult;
 use crate::input::Input;
+use crate::py_gc::impl_py_gc_traverse;
 use crate::recursion_guard::RecursionGuard;
 
 use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
@@ -18,6 +19,12 @@ pub struct CallValidator {
     name: String,
 }
 
+impl_py_gc_traverse!(CallValidator {
+    function,
+    arguments_validator,
+    return_validator
+});
+
 impl BuildValidator for CallValidator {
     const EXPECTED_TYPE: &'static str = "call";
 
@@ -3,6 +3,8 @@ use pyo3::types::PyDict;
 
 use crate::errors::{ErrorType, ValError, ValResult};
 use crate::input::Input;
+use crate::py_gc::impl_py_gc_traverse;
+use crate::questions::Question;
 use crate::recursion_guard::RecursionGuard;
 
 use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
@@ -10,6 +12,8 @@ use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
 #[derive(Debug, Clone)]
 pub struct CallableValidator;
 
+impl_py_gc_traverse!(CallableValidator {});
+
 impl BuildValidator for CallableValidator {
     const EXPECTED_TYPE: &'static str = "callable";
 
@@ -40,4 +44,8 @@ impl Validator for CallableValidator {
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
+
+    fn ask(&self, _py: Python, question: &Question) -> bool {
+        matches!(question, Question::RejectsNone)
+    }
 }
@@ -5,6 +5,7 @@ use pyo3::types::{PyDict, PyList};
 use crate::build_tools::{py_err, SchemaDict};
 use crate::errors::ValResult;
 use crate::input::Input;
+use crate::py_gc::impl_py_gc_traverse;
 use crate::questions::Question;
 use crate::recursion_guard::RecursionGuard;
 
@@ -16,6 +17,8 @@ pub struct ChainValidator {
     name: String,
 }
 
+impl_py_gc_traverse!(ChainValidator { steps });
+
 impl BuildValidator for ChainValidator {
     const EXPECTED_TYPE: &'static str = "chain";
 
@@ -89,10 +92,13 @@ impl Validator for ChainValidator {
         &self.name
     }
 
-    fn ask(&self, question: &Question) -> bool {
-        // any makes more sense since at the moment we only use ask for "return_fields_set", might need
-        // more complex logic in future
-        self.steps.iter().any(|v| v.ask(question))
+    fn ask(&self, py: Python, question: &Question) -> bool {
+        match question {
+            // only the first step sees the input
+            Question::RejectsNone => self.steps[0].ask(py, question),
+            // any makes more sense for "return_fields_set"
+            Question::ReturnFieldsSet => self.steps.iter().any(|v| v.ask(py, question)),
+        }
     }
 
     fn complete(&mut self, build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
@@ -0,0 +1,95 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::build_tools::SchemaDict;
+use crate::errors::{ValError, ValResult};
+use crate::input::Input;
+use crate::py_gc::impl_py_gc_traverse;
+use crate::recursion_guard::RecursionGuard;
+
+use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+
+#[derive(Debug, Clone)]
+pub struct ConditionalValidator {
+    if_validator: Box<CombinedValidator>,
+    then_validator: Box<CombinedValidator>,
+    else_validator: Box<CombinedValidator>,
+    name: String,
+}
+
+impl_py_gc_traverse!(ConditionalValidator {
+    if_validator,
+    then_validator,
+    else_validator
+});
+
+impl BuildValidator for ConditionalValidator {
+    const EXPECTED_TYPE: &'static str = "conditional";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        build_context: &mut BuildContext<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let if_schema = schema.get_as_req(intern!(py, "if_schema"))?;
+        let if_validator = Box::new(build_validator(if_schema, config, build_context)?);
+
+        let then_schema = schema.get_as_req(intern!(py, "then_schema"))?;
+        let then_validator = Box::new(build_validator(then_schema, config, build_context)?);
+
+        let else_schema = schema.get_as_req(intern!(py, "else_schema"))?;
+        let else_validator = Box::new(build_validator(else_schema, config, build_context)?);
+
+        let name = format!(
+            "{}[if={},then={},else={}]",
+            Self::EXPECTED_TYPE,
+            if_validator.get_name(),
+            then_validator.get_name(),
+            else_validator.get_name()
+        );
+        Ok(Self {
+            if_validator,
+            then_validator,
+            else_validator,
+            name,
+        }
+        .into())
+    }
+}
+
+impl Validator for ConditionalValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        // the output of the `if` schema is discarded, only whether the input is valid matters
+        let warnings_start = extra.warnings_count();
+        let matched = match self.if_validator.validate(py, input, extra, slots, recursion_guard) {
+            Ok(_) => true,
+            Err(ValError::LineErrors(_) | ValError::Omit) => false,
+            Err(err) => return Err(err),
+        };
+        extra.discard_warnings(warnings_start);
+
+        match matched {
+            true => self.then_validator.validate(py, input, extra, slots, recursion_guard),
+            false => self.else_validator.validate(py, input, extra, slots, recursion_guard),
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    fn complete(&mut self, build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
+        self.if_validator.complete(build_context)?;
+        self.then_validator.complete(build_context)?;
+        self.else_validator.complete(build_context)
+    }
+}
@@ -1,3 +1,4 @@
+use pyo3::class::gc::{PyTraverseError, PyVisit};
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
@@ -5,6 +6,7 @@ use pyo3::types::PyDict;
 use crate::build_tools::{py_err, SchemaDict};
 use crate::errors::{ErrorType, PydanticCustomError, PydanticKnownError, ValError, ValResult};
 use crate::input::Input;
+use crate::py_gc::{impl_py_gc_traverse, PyGcTraverse};
 use crate::questions::Question;
 use crate::recursion_guard::RecursionGuard;
 
@@ -16,6 +18,15 @@ pub enum CustomError {
     KnownError(PydanticKnownError),
 }
 
+impl PyGcTraverse for CustomError {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        match self {
+            Self::Custom(custom_error) => custom_error.py_gc_traverse(visit),
+            Self::KnownError(_) => Ok(()),
+        }
+    }
+}
+
 impl CustomError {
     pub fn build(schema: &PyDict) -> PyResult<Option<Self>> {
         let py = schema.py();
@@ -58,6 +69,30 @@ pub struct CustomErrorValidator {
     name: String,
 }
 
+impl_py_gc_traverse!(CustomErrorValidator {
+    validator,
+    custom_error
+});
+
+impl CustomErrorValidator {
+    /// If `custom_error_type` is set directly on a schema, wrap the validator so its errors are replaced,
+    /// the name of the inner validator is kept.
+    pub fn wrap_if_set(validator: CombinedValidator, schema: &PyDict) -> PyResult<CombinedValidator> {
+        match CustomError::build(schema)? {
+            Some(custom_error) => {
+                let name = validator.get_name().to_string();
+                Ok(Self {
+                    validator: Box::new(validator),
+                    custom_error,
+                    name,
+                }
+                .into())
+            }
+            None => Ok(validator),
+        }
+    }
+}
+
 impl BuildValidator for CustomErrorValidator {
     const EXPECTED_TYPE: &'static str = "custom_error";
 
@@ -97,8 +132,12 @@ impl Validator for CustomErrorValidator {
         &self.name
     }
 
-    fn ask(&self, question: &Question) -> bool {
-        self.validator.ask(question)
+    fn ask(&self, py: Python, question: &Question) -> bool {
+        match question {
+            // the custom error is raised for `None` too
+            Question::RejectsNone => false,
+            _ => self.validator.ask(py, question),
+        }
     }
 
     fn complete(&mut self, build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
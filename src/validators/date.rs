@@ -4,9 +4,11 @@ use pyo3::types::{PyDate, PyDict, PyString};
 use speedate::{Date, Time};
 use strum::EnumMessage;
 
-use crate::build_tools::{is_strict, py_error_type, SchemaDict};
+use crate::build_tools::{is_strict, py_error_type, schema_or_config_same, SchemaDict};
 use crate::errors::{ErrorType, ValError, ValResult};
-use crate::input::{EitherDate, Input};
+use crate::input::{check_rfc3339_date, EitherDate, FormatKind, Input, InputFormats, LeapSecondMode};
+use crate::py_gc::impl_py_gc_traverse;
+use crate::questions::Question;
 use crate::recursion_guard::RecursionGuard;
 use crate::validators::datetime::{NowConstraint, NowOp};
 
@@ -15,9 +17,13 @@ use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
 #[derive(Debug, Clone)]
 pub struct DateValidator {
     strict: bool,
+    rfc3339: bool,
+    input_formats: Option<InputFormats>,
     constraints: Option<DateConstraints>,
 }
 
+impl_py_gc_traverse!(DateValidator {});
+
 impl BuildValidator for DateValidator {
     const EXPECTED_TYPE: &'static str = "date";
 
@@ -28,6 +34,8 @@ impl BuildValidator for DateValidator {
     ) -> PyResult<CombinedValidator> {
         Ok(Self {
             strict: is_strict(schema, config)?,
+            rfc3339: schema_or_config_same(schema, config, intern!(schema.py(), "rfc3339"))?.unwrap_or(false),
+            input_formats: InputFormats::from_py(schema, FormatKind::Date)?,
             constraints: DateConstraints::from_py(schema)?,
         }
         .into())
@@ -43,15 +51,22 @@ impl Validator for DateValidator {
         _slots: &'data [CombinedValidator],
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let date = match input.validate_date(extra.strict.unwrap_or(self.strict)) {
+        let strict = extra.strict.unwrap_or(self.strict);
+        if self.rfc3339 {
+            check_rfc3339_date(input)?;
+        }
+        let date = match input.validate_date(strict) {
             Ok(date) => date,
             // if the date error was an internal error, return that immediately
             Err(ValError::InternalErr(internal_err)) => return Err(ValError::InternalErr(internal_err)),
-            Err(date_err) => match self.strict {
+            Err(date_err) => match strict {
                 // if we're in strict mode, we doing try coercing from a date
                 true => return Err(date_err),
-                // otherwise, try creating a date from a datetime input
-                false => date_from_datetime(input, date_err),
+                // otherwise, try any additional input formats, then creating a date from a datetime input
+                false => match self.input_formats.as_ref().and_then(|f| f.parse_date(input)) {
+                    Some(date) => Ok(date),
+                    None => date_from_datetime(input, date_err),
+                },
             }?,
         };
         if let Some(constraints) = &self.constraints {
@@ -101,6 +116,10 @@ impl Validator for DateValidator {
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
+
+    fn ask(&self, _py: Python, question: &Question) -> bool {
+        matches!(question, Question::RejectsNone)
+    }
 }
 
 /// In lax mode, if the input is not a date, we try parsing the input as a datetime, then check it is an
@@ -109,7 +128,7 @@ fn date_from_datetime<'data>(
     input: &'data impl Input<'data>,
     date_err: ValError<'data>,
 ) -> ValResult<'data, EitherDate<'data>> {
-    let either_dt = match input.validate_datetime(false) {
+    let either_dt = match input.validate_datetime(false, LeapSecondMode::default()) {
         Ok(dt) => dt,
         Err(dt_err) => {
             return match dt_err {
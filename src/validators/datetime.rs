@@ -6,9 +6,13 @@ use speedate::DateTime;
 use std::cmp::Ordering;
 use strum::EnumMessage;
 
-use crate::build_tools::{is_strict, py_err, py_error_type, SchemaDict};
+use crate::build_tools::{is_strict, py_err, py_error_type, schema_or_config, schema_or_config_same, SchemaDict};
 use crate::errors::{py_err_string, ErrorType, ValError, ValResult};
-use crate::input::{EitherDateTime, Input};
+use crate::input::{
+    check_rfc3339_datetime, named_timezone_datetime, EitherDateTime, FormatKind, Input, InputFormats, LeapSecondMode,
+};
+use crate::py_gc::impl_py_gc_traverse;
+use crate::questions::Question;
 use crate::recursion_guard::RecursionGuard;
 
 use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
@@ -16,9 +20,16 @@ use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
 #[derive(Debug, Clone)]
 pub struct DateTimeValidator {
     strict: bool,
+    fold_mode: FoldMode,
+    leap_second_mode: LeapSecondMode,
+    rfc3339: bool,
+    named_timezones: bool,
+    input_formats: Option<InputFormats>,
     constraints: Option<DateTimeConstraints>,
 }
 
+impl_py_gc_traverse!(DateTimeValidator {});
+
 impl BuildValidator for DateTimeValidator {
     const EXPECTED_TYPE: &'static str = "datetime";
 
@@ -29,6 +40,12 @@ impl BuildValidator for DateTimeValidator {
     ) -> PyResult<CombinedValidator> {
         Ok(Self {
             strict: is_strict(schema, config)?,
+            fold_mode: FoldMode::from_py(schema, config)?,
+            leap_second_mode: LeapSecondMode::from_py(schema, config)?,
+            rfc3339: schema_or_config_same(schema, config, intern!(schema.py(), "rfc3339"))?.unwrap_or(false),
+            named_timezones: schema_or_config_same(schema, config, intern!(schema.py(), "named_timezones"))?
+                .unwrap_or(false),
+            input_formats: InputFormats::from_py(schema, FormatKind::DateTime)?,
             constraints: DateTimeConstraints::from_py(schema)?,
         }
         .into())
@@ -44,7 +61,27 @@ impl Validator for DateTimeValidator {
         _slots: &'data [CombinedValidator],
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let datetime = input.validate_datetime(extra.strict.unwrap_or(self.strict))?;
+        let strict = extra.strict.unwrap_or(self.strict);
+        if self.rfc3339 {
+            check_rfc3339_datetime(input)?;
+        }
+        let mut datetime = match input.validate_datetime(strict, self.leap_second_mode) {
+            Ok(datetime) => datetime,
+            Err(ValError::LineErrors(line_errors)) if !strict => {
+                match self.input_formats.as_ref().and_then(|f| f.parse_datetime(input)) {
+                    Some(datetime) => datetime,
+                    None if self.named_timezones => match named_timezone_datetime(input, self.leap_second_mode)? {
+                        Some(datetime) => datetime,
+                        None => return Err(ValError::LineErrors(line_errors)),
+                    },
+                    None => return Err(ValError::LineErrors(line_errors)),
+                }
+            }
+            Err(err) => return Err(err),
+        };
+        if let Some(fold) = self.fold_mode.fold() {
+            datetime = datetime.with_fold(fold)?;
+        }
         if let Some(constraints) = &self.constraints {
             // if we get an error from as_speedate, it's probably because the input datetime was invalid
             // specifically had an invalid tzinfo, hence here we return a validation error
@@ -105,6 +142,10 @@ impl Validator for DateTimeValidator {
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
+
+    fn ask(&self, _py: Python, question: &Question) -> bool {
+        matches!(question, Question::RejectsNone)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -231,3 +272,48 @@ impl TZConstraint {
         }
     }
 }
+
+/// How ambiguous wall-clock times (e.g. during a DST transition) are resolved, see PEP 495 for details of `fold`.
+/// The `fold` chosen is used when comparing against `le`, `lt`, `ge`, `gt` and `now_op` constraints
+/// and set on the output datetime, so the output is the time which was checked.
+#[derive(Debug, Clone)]
+pub enum FoldMode {
+    /// use the `fold` attribute of the datetime
+    Respect,
+    /// always use the earlier of the two possible times, i.e. `fold=0`
+    Earliest,
+    /// always use the later of the two possible times, i.e. `fold=1`
+    Latest,
+}
+
+impl FoldMode {
+    pub fn from_str(s: &str) -> PyResult<Self> {
+        match s {
+            "respect" => Ok(FoldMode::Respect),
+            "earliest" => Ok(FoldMode::Earliest),
+            "latest" => Ok(FoldMode::Latest),
+            _ => py_err!("Invalid fold_mode {:?}", s),
+        }
+    }
+
+    pub fn from_py(schema: &PyDict, config: Option<&PyDict>) -> PyResult<Self> {
+        let py = schema.py();
+        match schema_or_config(
+            schema,
+            config,
+            intern!(py, "fold_mode"),
+            intern!(py, "datetime_fold_mode"),
+        )? {
+            Some(mode) => Self::from_str(mode),
+            None => Ok(FoldMode::Respect),
+        }
+    }
+
+    pub fn fold(&self) -> Option<u8> {
+        match self {
+            Self::Respect => None,
+            Self::Earliest => Some(0),
+            Self::Latest => Some(1),
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+
+use crate::build_tools::SchemaDict;
+
+use super::recursive::RecursiveRefValidator;
+use super::{build_validator, BuildContext, BuildValidator, CombinedValidator};
+
+/// `definitions` schemas hold named schemas which can be referenced from anywhere in `schema` (and from each
+/// other) with `definition-ref`, the validator built is simply that of `schema`.
+#[derive(Debug, Clone)]
+pub struct DefinitionsBuilder;
+
+impl BuildValidator for DefinitionsBuilder {
+    const EXPECTED_TYPE: &'static str = "definitions";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        build_context: &mut BuildContext<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let definitions: &PyList = schema.get_as_req(intern!(py, "definitions"))?;
+        for (slot_id, definition) in build_context.prepare_definitions(definitions)? {
+            let validator = build_validator(definition, config, build_context)?;
+            build_context.complete_slot(slot_id, validator)?;
+        }
+        build_validator(schema.get_as_req(intern!(py, "schema"))?, config, build_context)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DefinitionRefBuilder;
+
+impl BuildValidator for DefinitionRefBuilder {
+    const EXPECTED_TYPE: &'static str = "definition-ref";
+
+    fn build(
+        schema: &PyDict,
+        _config: Option<&PyDict>,
+        build_context: &mut BuildContext<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        let schema_ref: String = schema.get_as_req(intern!(schema.py(), "schema_ref"))?;
+        // the slot is looked up when validating, so it may not have been built yet
+        let (validator_id, answers) = build_context.find_slot_id_answer(&schema_ref)?;
+        Ok(RecursiveRefValidator::from_id(
+            validator_id,
+            "...".to_string(),
+            answers.unwrap(),
+        ))
+    }
+}
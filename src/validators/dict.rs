@@ -2,12 +2,16 @@ use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyMapping};
 
-use crate::build_tools::{is_strict, SchemaDict};
+use crate::build_tools::{is_strict, schema_or_config_same, SchemaDict};
 use crate::errors::{ValError, ValLineError, ValResult};
 use crate::input::{
-    DictGenericIterator, GenericMapping, Input, JsonObject, JsonObjectGenericIterator, MappingGenericIterator,
+    AttributesGenericIterator, DictGenericIterator, GenericMapping, Input, JsonObject, JsonObjectGenericIterator,
+    MappingGenericIterator, StringMapping, StringMappingGenericIterator,
 };
+use crate::py_gc::impl_py_gc_traverse;
+use crate::questions::Question;
 use crate::recursion_guard::RecursionGuard;
+use crate::serializers::AllItems;
 
 use super::any::AnyValidator;
 use super::list::length_check;
@@ -16,13 +20,21 @@ use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Ex
 #[derive(Debug, Clone)]
 pub struct DictValidator {
     strict: bool,
+    from_attributes: bool,
     key_validator: Box<CombinedValidator>,
     value_validator: Box<CombinedValidator>,
     min_length: Option<usize>,
     max_length: Option<usize>,
+    // JSON object keys are always strings, so with this they're validated in lax mode even when strict
+    coerce_json_keys: bool,
     name: String,
 }
 
+impl_py_gc_traverse!(DictValidator {
+    key_validator,
+    value_validator
+});
+
 impl BuildValidator for DictValidator {
     const EXPECTED_TYPE: &'static str = "dict";
 
@@ -48,10 +60,12 @@ impl BuildValidator for DictValidator {
         );
         Ok(Self {
             strict: is_strict(schema, config)?,
+            from_attributes: schema.get_as(intern!(py, "from_attributes"))?.unwrap_or(false),
             key_validator,
             value_validator,
             min_length: schema.get_as(intern!(py, "min_length"))?,
             max_length: schema.get_as(intern!(py, "max_length"))?,
+            coerce_json_keys: schema_or_config_same(schema, config, intern!(py, "coerce_json_keys"))?.unwrap_or(false),
             name,
         }
         .into())
@@ -67,16 +81,20 @@ impl Validator for DictValidator {
         slots: &'data [CombinedValidator],
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let dict = input.validate_dict(extra.strict.unwrap_or(self.strict))?;
+        // with `from_attributes`, objects which aren't mappings are validated from their attributes
+        let dict = input.validate_typed_dict(extra.strict.unwrap_or(self.strict), self.from_attributes)?;
         match dict {
             GenericMapping::PyDict(py_dict) => self.validate_dict(py, input, py_dict, extra, slots, recursion_guard),
             GenericMapping::PyMapping(mapping) => {
                 self.validate_mapping(py, input, mapping, extra, slots, recursion_guard)
             }
-            GenericMapping::PyGetAttr(_) => unreachable!(),
+            GenericMapping::PyGetAttr(obj) => self.validate_attributes(py, input, obj, extra, slots, recursion_guard),
             GenericMapping::JsonObject(json_object) => {
                 self.validate_json_object(py, input, json_object, extra, slots, recursion_guard)
             }
+            GenericMapping::StringMapping(string_mapping) => {
+                self.validate_string_mapping(py, input, string_mapping, extra, slots, recursion_guard)
+            }
         }
     }
 
@@ -84,6 +102,10 @@ impl Validator for DictValidator {
         &self.name
     }
 
+    fn ask(&self, _py: Python, question: &Question) -> bool {
+        matches!(question, Question::RejectsNone)
+    }
+
     fn complete(&mut self, build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
         self.key_validator.complete(build_context)?;
         self.value_validator.complete(build_context)
@@ -106,10 +128,32 @@ macro_rules! build_validate {
 
             let key_validator = self.key_validator.as_ref();
             let value_validator = self.value_validator.as_ref();
-            for item_result in <$iter>::new(dict)? {
+            // with partial input, only the last value may be incomplete
+            let key_extra = Extra {
+                strict: match self.coerce_json_keys && input.get_type().is_json() {
+                    true => Some(false),
+                    false => extra.strict,
+                },
+                partial: false,
+                include: None,
+                exclude: None,
+                ..*extra
+            };
+            let all_items = AllItems::new(py, extra.include, extra.exclude)?;
+            let mut iter = <$iter>::new(dict)?.peekable();
+            while let Some(item_result) = iter.next() {
                 let (key, value) = item_result?;
-                let output_key = match key_validator.validate(py, key, extra, slots, recursion_guard) {
-                    Ok(value) => Some(value),
+                let (include, exclude) = match extra.item_filter(key, all_items)? {
+                    Some(value_include_exclude) => value_include_exclude,
+                    // items left out by `include`/`exclude` aren't validated or returned
+                    None => continue,
+                };
+                let is_tail = extra.partial && iter.peek().is_none();
+                let output_key = match key_validator.validate_item(py, key, &key_extra, slots, recursion_guard) {
+                    Ok(value) => match extra.string_cache {
+                        Some(cache) => Some(cache.cache_key(py, value)),
+                        None => Some(value),
+                    },
                     Err(ValError::LineErrors(line_errors)) => {
                         for err in line_errors {
                             // these are added in reverse order so [key] is shunted along by the second call
@@ -123,8 +167,18 @@ macro_rules! build_validate {
                     Err(ValError::Omit) => continue,
                     Err(err) => return Err(err),
                 };
-                let output_value = match value_validator.validate(py, value, extra, slots, recursion_guard) {
+                let warnings_start = extra.warnings_count();
+                let value_extra = Extra {
+                    partial: is_tail,
+                    include,
+                    exclude,
+                    ..*extra
+                };
+                let result = value_validator.validate_item(py, value, &value_extra, slots, recursion_guard);
+                extra.warnings_with_outer_location(warnings_start, || vec![key.as_loc_item()]);
+                let output_value = match result {
                     Ok(value) => Some(value),
+                    Err(ValError::LineErrors(_)) if is_tail => continue,
                     Err(ValError::LineErrors(line_errors)) => {
                         for err in line_errors {
                             errors.push(err.with_outer_location(key.as_loc_item()));
@@ -136,6 +190,8 @@ macro_rules! build_validate {
                 };
                 if let (Some(key), Some(value)) = (output_key, output_value) {
                     output.set_item(key, value)?;
+                } else if extra.too_many_errors(errors.len()) {
+                    break;
                 }
             }
 
@@ -152,5 +208,7 @@ macro_rules! build_validate {
 impl DictValidator {
     build_validate!(validate_dict, PyDict, DictGenericIterator);
     build_validate!(validate_mapping, PyMapping, MappingGenericIterator);
+    build_validate!(validate_attributes, PyAny, AttributesGenericIterator);
     build_validate!(validate_json_object, JsonObject, JsonObjectGenericIterator);
+    build_validate!(validate_string_mapping, StringMapping, StringMappingGenericIterator);
 }
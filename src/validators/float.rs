@@ -5,16 +5,20 @@ use pyo3::types::PyDict;
 use crate::build_tools::{is_strict, schema_or_config_same, SchemaDict};
 use crate::errors::{ErrorType, ValError, ValResult};
 use crate::input::Input;
+use crate::py_gc::impl_py_gc_traverse;
+use crate::questions::Question;
 use crate::recursion_guard::RecursionGuard;
 
 use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
 
 #[derive(Debug, Clone)]
 pub struct FloatValidator {
-    strict: bool,
-    allow_inf_nan: bool,
+    pub(crate) strict: bool,
+    pub(crate) allow_inf_nan: bool,
 }
 
+impl_py_gc_traverse!(FloatValidator {});
+
 impl BuildValidator for FloatValidator {
     const EXPECTED_TYPE: &'static str = "float";
 
@@ -60,6 +64,10 @@ impl Validator for FloatValidator {
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
+
+    fn ask(&self, _py: Python, question: &Question) -> bool {
+        matches!(question, Question::RejectsNone)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -73,6 +81,8 @@ pub struct ConstrainedFloatValidator {
     gt: Option<f64>,
 }
 
+impl_py_gc_traverse!(ConstrainedFloatValidator {});
+
 impl Validator for ConstrainedFloatValidator {
     fn validate<'s, 'data>(
         &'s self,
@@ -123,6 +133,10 @@ impl Validator for ConstrainedFloatValidator {
     fn get_name(&self) -> &str {
         "constrained-float"
     }
+
+    fn ask(&self, _py: Python, question: &Question) -> bool {
+        matches!(question, Question::RejectsNone)
+    }
 }
 
 impl ConstrainedFloatValidator {
@@ -4,9 +4,11 @@ use pyo3::types::{PyDict, PyFrozenSet};
 use crate::build_tools::SchemaDict;
 use crate::errors::ValResult;
 use crate::input::{GenericCollection, Input};
+use crate::py_gc::impl_py_gc_traverse;
+use crate::questions::Question;
 use crate::recursion_guard::RecursionGuard;
 
-use super::list::{get_items_schema, length_check};
+use super::list::{get_items_schema, length_check, CollectionCoercion, CollectionType};
 use super::set::set_build;
 use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
 
@@ -17,9 +19,12 @@ pub struct FrozenSetValidator {
     min_length: Option<usize>,
     max_length: Option<usize>,
     generator_max_length: Option<usize>,
+    coercion: CollectionCoercion,
     name: String,
 }
 
+impl_py_gc_traverse!(FrozenSetValidator { item_validator });
+
 impl BuildValidator for FrozenSetValidator {
     const EXPECTED_TYPE: &'static str = "frozenset";
     set_build!();
@@ -34,12 +39,14 @@ impl Validator for FrozenSetValidator {
         slots: &'data [CombinedValidator],
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let seq = input.validate_frozenset(extra.strict.unwrap_or(self.strict))?;
+        let strict = extra.strict.unwrap_or(self.strict);
+        let seq = self
+            .coercion
+            .validate(py, input, CollectionType::FrozenSet, strict, false)?;
 
         let f_set = match self.item_validator {
-            Some(ref v) => PyFrozenSet::new(
-                py,
-                &seq.validate_to_vec(
+            Some(ref v) => {
+                let items = seq.validate_to_vec(
                     py,
                     input,
                     self.max_length,
@@ -49,11 +56,19 @@ impl Validator for FrozenSetValidator {
                     extra,
                     slots,
                     recursion_guard,
-                )?,
-            )?,
+                )?;
+                let f_set = PyFrozenSet::new(py, &items)?;
+                self.coercion.check_duplicates(input, &seq, items.len(), f_set.len())?;
+                f_set
+            }
             None => match seq {
                 GenericCollection::FrozenSet(f_set) => f_set,
-                _ => PyFrozenSet::new(py, &seq.to_vec(py, input, "Frozenset", self.generator_max_length)?)?,
+                _ => {
+                    let items = seq.to_vec(py, input, "Frozenset", self.generator_max_length)?;
+                    let f_set = PyFrozenSet::new(py, &items)?;
+                    self.coercion.check_duplicates(input, &seq, items.len(), f_set.len())?;
+                    f_set
+                }
             },
         };
         length_check!(input, "Frozenset", self.min_length, self.max_length, f_set);
@@ -64,6 +79,10 @@ impl Validator for FrozenSetValidator {
         &self.name
     }
 
+    fn ask(&self, _py: Python, question: &Question) -> bool {
+        matches!(question, Question::RejectsNone)
+    }
+
     fn complete(&mut self, build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
         match self.item_validator {
             Some(ref mut v) => v.complete(build_context),
@@ -7,7 +7,8 @@ use crate::build_tools::{function_name, kwargs, py_err, SchemaDict};
 use crate::errors::{
     ErrorType, LocItem, PydanticCustomError, PydanticKnownError, PydanticOmit, ValError, ValResult, ValidationError,
 };
-use crate::input::Input;
+use crate::input::{GenericMapping, Input};
+use crate::py_gc::impl_py_gc_traverse;
 use crate::questions::Question;
 use crate::recursion_guard::RecursionGuard;
 
@@ -60,9 +61,27 @@ macro_rules! impl_build {
                         None => py.None(),
                     },
                     name,
+                    validation_error_cause: config
+                        .get_as(intern!(py, "validation_error_cause"))?
+                        .unwrap_or(false),
+                    skip_on_assignment: schema.get_as(intern!(py, "skip_on_assignment"))?.unwrap_or(false),
+                    skip_from_attributes: schema
+                        .get_as(intern!(py, "skip_from_attributes"))?
+                        .unwrap_or(false),
                 }
                 .into())
             }
+
+            /// whether to call the inner validator directly without the function, this is for functions around
+            /// a model or typed dict which should only see some inputs
+            fn skip<'data>(&self, input: &'data impl Input<'data>, extra: &Extra) -> bool {
+                (self.skip_on_assignment && extra.field.is_some())
+                    || (self.skip_from_attributes
+                        && matches!(
+                            input.validate_typed_dict(false, true),
+                            Ok(GenericMapping::PyGetAttr(_))
+                        ))
+            }
         }
     };
 }
@@ -73,8 +92,17 @@ pub struct FunctionBeforeValidator {
     func: PyObject,
     config: PyObject,
     name: String,
+    validation_error_cause: bool,
+    skip_on_assignment: bool,
+    skip_from_attributes: bool,
 }
 
+impl_py_gc_traverse!(FunctionBeforeValidator {
+    validator,
+    func,
+    config
+});
+
 impl_build!(FunctionBeforeValidator, "function-before");
 
 impl Validator for FunctionBeforeValidator {
@@ -86,11 +114,25 @@ impl Validator for FunctionBeforeValidator {
         slots: &'data [CombinedValidator],
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let kwargs = kwargs!(py, data: extra.data, config: self.config.clone_ref(py), context: extra.context);
-        let value = self
-            .func
-            .call(py, (input.to_object(py),), kwargs)
-            .map_err(|e| convert_err(py, e, input))?;
+        if self.skip(input, extra) {
+            return self.validator.validate(py, input, extra, slots, recursion_guard);
+        }
+        let kwargs = kwargs!(
+            py,
+            data: extra.data,
+            config: self.config.clone_ref(py),
+            context: extra.context,
+            field_name: extra.field_name,
+            mode: input.get_type().as_str(),
+        );
+        let value = self.func.call(py, (input.to_object(py),), kwargs).map_err(|e| {
+            let err = convert_err_with_cause(py, e, input, self.validation_error_cause);
+            // on assignment the function is called with the new value of a field, so errors belong to the field
+            match extra.field {
+                Some(field) => err.with_outer_location(field.into()),
+                None => err,
+            }
+        })?;
 
         self.validator
             .validate(py, value.into_ref(py), extra, slots, recursion_guard)
@@ -100,8 +142,12 @@ impl Validator for FunctionBeforeValidator {
         &self.name
     }
 
-    fn ask(&self, question: &Question) -> bool {
-        self.validator.ask(question)
+    fn ask(&self, py: Python, question: &Question) -> bool {
+        match question {
+            // the function sees the input before the validator
+            Question::RejectsNone => false,
+            _ => self.validator.ask(py, question),
+        }
     }
 
     fn complete(&mut self, build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
@@ -115,8 +161,17 @@ pub struct FunctionAfterValidator {
     func: PyObject,
     config: PyObject,
     name: String,
+    validation_error_cause: bool,
+    skip_on_assignment: bool,
+    skip_from_attributes: bool,
 }
 
+impl_py_gc_traverse!(FunctionAfterValidator {
+    validator,
+    func,
+    config
+});
+
 impl_build!(FunctionAfterValidator, "function-after");
 
 impl Validator for FunctionAfterValidator {
@@ -128,17 +183,29 @@ impl Validator for FunctionAfterValidator {
         slots: &'data [CombinedValidator],
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
+        if self.skip(input, extra) {
+            return self.validator.validate(py, input, extra, slots, recursion_guard);
+        }
         let v = self.validator.validate(py, input, extra, slots, recursion_guard)?;
-        let kwargs = kwargs!(py, data: extra.data, config: self.config.clone_ref(py), context: extra.context);
-        self.func.call(py, (v,), kwargs).map_err(|e| convert_err(py, e, input))
+        let kwargs = kwargs!(
+            py,
+            data: extra.data,
+            config: self.config.clone_ref(py),
+            context: extra.context,
+            field_name: extra.field_name,
+            mode: input.get_type().as_str(),
+        );
+        self.func
+            .call(py, (v,), kwargs)
+            .map_err(|e| convert_err_with_cause(py, e, input, self.validation_error_cause))
     }
 
     fn get_name(&self) -> &str {
         &self.name
     }
 
-    fn ask(&self, question: &Question) -> bool {
-        self.validator.ask(question)
+    fn ask(&self, py: Python, question: &Question) -> bool {
+        self.validator.ask(py, question)
     }
 
     fn complete(&mut self, build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
@@ -151,8 +218,11 @@ pub struct FunctionPlainValidator {
     func: PyObject,
     config: PyObject,
     name: String,
+    validation_error_cause: bool,
 }
 
+impl_py_gc_traverse!(FunctionPlainValidator { func, config });
+
 impl FunctionPlainValidator {
     pub fn build(schema: &PyDict, config: Option<&PyDict>) -> PyResult<CombinedValidator> {
         let py = schema.py();
@@ -164,6 +234,7 @@ impl FunctionPlainValidator {
                 None => py.None(),
             },
             name: format!("function-plain[{}()]", function_name(function)?),
+            validation_error_cause: config.get_as(intern!(py, "validation_error_cause"))?.unwrap_or(false),
         }
         .into())
     }
@@ -178,10 +249,17 @@ impl Validator for FunctionPlainValidator {
         _slots: &'data [CombinedValidator],
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let kwargs = kwargs!(py, data: extra.data, config: self.config.clone_ref(py), context: extra.context);
+        let kwargs = kwargs!(
+            py,
+            data: extra.data,
+            config: self.config.clone_ref(py),
+            context: extra.context,
+            field_name: extra.field_name,
+            mode: input.get_type().as_str(),
+        );
         self.func
             .call(py, (input.to_object(py),), kwargs)
-            .map_err(|e| convert_err(py, e, input))
+            .map_err(|e| convert_err_with_cause(py, e, input, self.validation_error_cause))
     }
 
     fn get_name(&self) -> &str {
@@ -195,8 +273,17 @@ pub struct FunctionWrapValidator {
     func: PyObject,
     config: PyObject,
     name: String,
+    validation_error_cause: bool,
+    skip_on_assignment: bool,
+    skip_from_attributes: bool,
 }
 
+impl_py_gc_traverse!(FunctionWrapValidator {
+    validator,
+    func,
+    config
+});
+
 impl_build!(FunctionWrapValidator, "function-wrap");
 
 impl Validator for FunctionWrapValidator {
@@ -208,6 +295,9 @@ impl Validator for FunctionWrapValidator {
         slots: &'data [CombinedValidator],
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
+        if self.skip(input, extra) {
+            return self.validator.validate(py, input, extra, slots, recursion_guard);
+        }
         let validator_kwarg = ValidatorCallable {
             validator: InternalValidator::new(py, "ValidatorCallable", &self.validator, slots, extra, recursion_guard),
         };
@@ -217,18 +307,24 @@ impl Validator for FunctionWrapValidator {
             data: extra.data,
             config: self.config.clone_ref(py),
             context: extra.context,
+            field_name: extra.field_name,
+            mode: input.get_type().as_str(),
         );
         self.func
             .call(py, (input.to_object(py),), kwargs)
-            .map_err(|e| convert_err(py, e, input))
+            .map_err(|e| convert_err_with_cause(py, e, input, self.validation_error_cause))
     }
 
     fn get_name(&self) -> &str {
         &self.name
     }
 
-    fn ask(&self, question: &Question) -> bool {
-        self.validator.ask(question)
+    fn ask(&self, py: Python, question: &Question) -> bool {
+        match question {
+            // the function sees the input before the validator
+            Question::RejectsNone => false,
+            _ => self.validator.ask(py, question),
+        }
     }
 
     fn complete(&mut self, build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
@@ -303,3 +399,38 @@ pub fn convert_err<'a>(py: Python<'a>, err: PyErr, input: &'a impl Input<'a>) ->
         ValError::InternalErr(err)
     }
 }
+
+/// Like `convert_err`, but if `validation_error_cause` is set, the exception raised by the validator function is
+/// kept on the resulting line errors so it can be attached as the `__cause__` of the `ValidationError`
+fn convert_err_with_cause<'a>(
+    py: Python<'a>,
+    err: PyErr,
+    input: &'a impl Input<'a>,
+    validation_error_cause: bool,
+) -> ValError<'a> {
+    let cause: PyObject = match validation_error_cause
+        && (err.is_instance_of::<PyValueError>(py) || err.is_instance_of::<PyAssertionError>(py))
+        && !err.is_instance_of::<ValidationError>(py)
+    {
+        true => {
+            let exception = err.value(py);
+            // the traceback is held separately by `PyErr`, put it back on the exception for debugging
+            if let Some(traceback) = err.traceback(py) {
+                if let Err(e) = exception.setattr(intern!(py, "__traceback__"), traceback) {
+                    return ValError::InternalErr(e);
+                }
+            }
+            exception.into_py(py)
+        }
+        false => return convert_err(py, err, input),
+    };
+    match convert_err(py, err, input) {
+        ValError::LineErrors(line_errors) => ValError::LineErrors(
+            line_errors
+                .into_iter()
+                .map(|line_error| line_error.with_cause(cause.clone_ref(py)))
+                .collect(),
+        ),
+        other => other,
+    }
+}
@@ -6,6 +6,7 @@ use pyo3::types::PyDict;
 use crate::build_tools::SchemaDict;
 use crate::errors::{ErrorType, LocItem, ValError, ValResult};
 use crate::input::{GenericIterator, Input};
+use crate::py_gc::impl_py_gc_traverse;
 use crate::questions::Question;
 use crate::recursion_guard::RecursionGuard;
 use crate::ValidationError;
@@ -20,6 +21,8 @@ pub struct GeneratorValidator {
     name: String,
 }
 
+impl_py_gc_traverse!(GeneratorValidator { item_validator });
+
 impl BuildValidator for GeneratorValidator {
     const EXPECTED_TYPE: &'static str = "generator";
 
@@ -69,10 +72,12 @@ impl Validator for GeneratorValidator {
         &self.name
     }
 
-    fn ask(&self, question: &Question) -> bool {
-        match self.item_validator {
-            Some(ref v) => v.ask(question),
-            None => false,
+    fn ask(&self, py: Python, question: &Question) -> bool {
+        match (question, &self.item_validator) {
+            // the item validator is applied to the items, not the input
+            (Question::RejectsNone, _) => false,
+            (_, Some(v)) => v.ask(py, question),
+            (_, None) => false,
         }
     }
 
@@ -168,6 +173,7 @@ pub struct InternalValidator {
     // TODO, do we need data?
     data: Option<Py<PyDict>>,
     field: Option<String>,
+    field_name: Option<String>,
     strict: Option<bool>,
     context: Option<PyObject>,
     recursion_guard: RecursionGuard,
@@ -194,6 +200,7 @@ impl InternalValidator {
             slots: slots.to_vec(),
             data: extra.data.map(|d| d.into_py(py)),
             field: extra.field.map(|f| f.to_string()),
+            field_name: extra.field_name.map(|f| f.to_string()),
             strict: extra.strict,
             context: extra.context.map(|d| d.into_py(py)),
             recursion_guard: recursion_guard.clone(),
@@ -212,8 +219,16 @@ impl InternalValidator {
         let extra = Extra {
             data: self.data.as_ref().map(|data| data.as_ref(py)),
             field: self.field.as_deref(),
+            field_name: self.field_name.as_deref(),
             strict: self.strict,
             context: self.context.as_ref().map(|data| data.as_ref(py)),
+            max_errors: None,
+            warnings: None,
+            string_cache: None,
+            self_instance: None,
+            partial: false,
+            include: None,
+            exclude: None,
         };
         self.validator
             .validate(py, input, &extra, &self.slots, &mut self.recursion_guard)
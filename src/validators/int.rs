@@ -2,18 +2,23 @@ use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
-use crate::build_tools::{is_strict, SchemaDict};
+use crate::build_tools::{is_strict, py_err, schema_or_config_same, SchemaDict};
 use crate::errors::{ErrorType, ValError, ValResult};
-use crate::input::Input;
+use crate::input::{EitherInt, Input};
+use crate::py_gc::impl_py_gc_traverse;
+use crate::questions::Question;
 use crate::recursion_guard::RecursionGuard;
 
 use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
 
 #[derive(Debug, Clone)]
 pub struct IntValidator {
-    strict: bool,
+    pub(crate) strict: bool,
+    pub(crate) coerce_bools: bool,
 }
 
+impl_py_gc_traverse!(IntValidator {});
+
 impl BuildValidator for IntValidator {
     const EXPECTED_TYPE: &'static str = "int";
 
@@ -33,6 +38,7 @@ impl BuildValidator for IntValidator {
         } else {
             Ok(Self {
                 strict: is_strict(schema, config)?,
+                coerce_bools: coerce_bools(schema, config)?,
             }
             .into())
         }
@@ -48,24 +54,33 @@ impl Validator for IntValidator {
         _slots: &'data [CombinedValidator],
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        Ok(input.validate_int(extra.strict.unwrap_or(self.strict))?.into_py(py))
+        Ok(input
+            .validate_int(extra.strict.unwrap_or(self.strict), self.coerce_bools)?
+            .into_py(py))
     }
 
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
+
+    fn ask(&self, _py: Python, question: &Question) -> bool {
+        matches!(question, Question::RejectsNone)
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct ConstrainedIntValidator {
     strict: bool,
-    multiple_of: Option<i64>,
-    le: Option<i64>,
-    lt: Option<i64>,
-    ge: Option<i64>,
-    gt: Option<i64>,
+    coerce_bools: bool,
+    multiple_of: Option<EitherInt>,
+    le: Option<EitherInt>,
+    lt: Option<EitherInt>,
+    ge: Option<EitherInt>,
+    gt: Option<EitherInt>,
 }
 
+impl_py_gc_traverse!(ConstrainedIntValidator {});
+
 impl Validator for ConstrainedIntValidator {
     fn validate<'s, 'data>(
         &'s self,
@@ -75,35 +90,38 @@ impl Validator for ConstrainedIntValidator {
         _slots: &'data [CombinedValidator],
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let int = input.validate_int(extra.strict.unwrap_or(self.strict))?;
-        if let Some(multiple_of) = self.multiple_of {
-            if int % multiple_of != 0 {
+        let int = input.validate_int(extra.strict.unwrap_or(self.strict), self.coerce_bools)?;
+        if let Some(ref multiple_of) = self.multiple_of {
+            if !int.is_multiple_of(multiple_of) {
                 return Err(ValError::new(
                     ErrorType::MultipleOf {
-                        multiple_of: multiple_of.into(),
+                        multiple_of: multiple_of.clone().into(),
                     },
                     input,
                 ));
             }
         }
-        if let Some(le) = self.le {
-            if int > le {
-                return Err(ValError::new(ErrorType::LessThanEqual { le: le.into() }, input));
+        if let Some(ref le) = self.le {
+            if int > *le {
+                return Err(ValError::new(ErrorType::LessThanEqual { le: le.clone().into() }, input));
             }
         }
-        if let Some(lt) = self.lt {
-            if int >= lt {
-                return Err(ValError::new(ErrorType::LessThan { lt: lt.into() }, input));
+        if let Some(ref lt) = self.lt {
+            if int >= *lt {
+                return Err(ValError::new(ErrorType::LessThan { lt: lt.clone().into() }, input));
             }
         }
-        if let Some(ge) = self.ge {
-            if int < ge {
-                return Err(ValError::new(ErrorType::GreaterThanEqual { ge: ge.into() }, input));
+        if let Some(ref ge) = self.ge {
+            if int < *ge {
+                return Err(ValError::new(
+                    ErrorType::GreaterThanEqual { ge: ge.clone().into() },
+                    input,
+                ));
             }
         }
-        if let Some(gt) = self.gt {
-            if int <= gt {
-                return Err(ValError::new(ErrorType::GreaterThan { gt: gt.into() }, input));
+        if let Some(ref gt) = self.gt {
+            if int <= *gt {
+                return Err(ValError::new(ErrorType::GreaterThan { gt: gt.clone().into() }, input));
             }
         }
         Ok(int.into_py(py))
@@ -112,14 +130,23 @@ impl Validator for ConstrainedIntValidator {
     fn get_name(&self) -> &str {
         "constrained-int"
     }
+
+    fn ask(&self, _py: Python, question: &Question) -> bool {
+        matches!(question, Question::RejectsNone)
+    }
 }
 
 impl ConstrainedIntValidator {
     fn build(schema: &PyDict, config: Option<&PyDict>) -> PyResult<CombinedValidator> {
         let py = schema.py();
+        let multiple_of: Option<EitherInt> = schema.get_as(intern!(py, "multiple_of"))?;
+        if matches!(multiple_of, Some(ref m) if *m == 0) {
+            return py_err!("'multiple_of' must not be zero");
+        }
         Ok(Self {
             strict: is_strict(schema, config)?,
-            multiple_of: schema.get_as(intern!(py, "multiple_of"))?,
+            coerce_bools: coerce_bools(schema, config)?,
+            multiple_of,
             le: schema.get_as(intern!(py, "le"))?,
             lt: schema.get_as(intern!(py, "lt"))?,
             ge: schema.get_as(intern!(py, "ge"))?,
@@ -128,3 +155,8 @@ impl ConstrainedIntValidator {
         .into())
     }
 }
+
+/// in lax mode, bools are only accepted as ints with `coerce_bools` set on the schema or in config
+fn coerce_bools(schema: &PyDict, config: Option<&PyDict>) -> PyResult<bool> {
+    Ok(schema_or_config_same(schema, config, intern!(schema.py(), "coerce_bools"))?.unwrap_or(false))
+}
@@ -5,6 +5,7 @@ use pyo3::types::{PyDict, PySet, PyType};
 use crate::build_tools::{py_err, SchemaDict};
 use crate::errors::{ErrorType, ValError, ValResult};
 use crate::input::{Input, JsonType};
+use crate::py_gc::impl_py_gc_traverse;
 use crate::recursion_guard::RecursionGuard;
 
 use super::function::convert_err;
@@ -19,6 +20,8 @@ pub struct IsInstanceValidator {
     name: String,
 }
 
+impl_py_gc_traverse!(IsInstanceValidator { class, json_function });
+
 impl BuildValidator for IsInstanceValidator {
     const EXPECTED_TYPE: &'static str = "is-instance";
 
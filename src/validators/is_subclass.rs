@@ -5,6 +5,7 @@ use pyo3::types::{PyDict, PyType};
 use crate::build_tools::SchemaDict;
 use crate::errors::{ErrorType, ValError, ValResult};
 use crate::input::Input;
+use crate::py_gc::impl_py_gc_traverse;
 use crate::recursion_guard::RecursionGuard;
 
 use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
@@ -16,6 +17,8 @@ pub struct IsSubclassValidator {
     name: String,
 }
 
+impl_py_gc_traverse!(IsSubclassValidator { class });
+
 impl BuildValidator for IsSubclassValidator {
     const EXPECTED_TYPE: &'static str = "is-subclass";
 
@@ -5,6 +5,7 @@ use pyo3::types::PyDict;
 use crate::build_tools::SchemaDict;
 use crate::errors::ValResult;
 use crate::input::Input;
+use crate::py_gc::impl_py_gc_traverse;
 use crate::questions::Question;
 use crate::recursion_guard::RecursionGuard;
 
@@ -16,6 +17,8 @@ pub struct JsonValidator {
     name: String,
 }
 
+impl_py_gc_traverse!(JsonValidator { validator });
+
 impl BuildValidator for JsonValidator {
     const EXPECTED_TYPE: &'static str = "json";
 
@@ -66,8 +69,12 @@ impl Validator for JsonValidator {
         &self.name
     }
 
-    fn ask(&self, question: &Question) -> bool {
-        self.validator.as_ref().map(|v| v.ask(question)).unwrap_or(false)
+    fn ask(&self, py: Python, question: &Question) -> bool {
+        match question {
+            // the inner validator is applied to the parsed JSON, not the input
+            Question::RejectsNone => false,
+            _ => self.validator.as_ref().map(|v| v.ask(py, question)).unwrap_or(false),
+        }
     }
 
     fn complete(&mut self, build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
@@ -0,0 +1,85 @@
+use std::borrow::Cow;
+
+use pyo3::prelude::*;
+
+use crate::errors::ValError;
+use crate::input::{JsonStream, JsonStreamItem};
+
+use super::{SchemaValidator, Validator};
+
+/// Iterator returned by `SchemaValidator.validate_json_stream`, each item of the stream is parsed and validated
+/// as the iterator is advanced.
+#[pyclass(module = "pydantic_core._pydantic_core")]
+#[derive(Debug)]
+pub struct JsonStreamIterator {
+    schema_validator: Py<SchemaValidator>,
+    stream: JsonStream,
+    strict: Option<bool>,
+    context: Option<PyObject>,
+}
+
+impl JsonStreamIterator {
+    pub fn new(
+        schema_validator: Py<SchemaValidator>,
+        stream: JsonStream,
+        strict: Option<bool>,
+        context: Option<&PyAny>,
+    ) -> Self {
+        Self {
+            schema_validator,
+            stream,
+            strict,
+            context: context.map(Into::into),
+        }
+    }
+}
+
+#[pymethods]
+impl JsonStreamIterator {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python) -> PyResult<Option<PyObject>> {
+        let index = self.stream.index();
+        let item = match self.stream.next_item(py)? {
+            Some(item) => item,
+            None => return Ok(None),
+        };
+        let schema_validator = self.schema_validator.borrow(py);
+        match item {
+            JsonStreamItem::Value(input) => {
+                let context = self.context.as_ref().map(|c| c.as_ref(py));
+                let r = schema_validator.validator.validate(
+                    py,
+                    &input,
+                    &schema_validator.extra(self.strict, context, None, None),
+                    &schema_validator.slots,
+                    &mut schema_validator.recursion_guard(),
+                );
+                r.map(Some).map_err(|e| {
+                    schema_validator.prepare_validation_err(py, e.with_outer_location(index.into()), &input)
+                })
+            }
+            JsonStreamItem::InvalidItem { error, raw } => {
+                let raw: Cow<str> = raw.into();
+                let error = ValError::new_with_loc(error, &raw, index);
+                Err(schema_validator.prepare_validation_err(py, error, &raw))
+            }
+            JsonStreamItem::InvalidStream(error) => {
+                let readable = self.stream.readable(py);
+                Err(schema_validator.prepare_validation_err(py, ValError::new(error, readable), readable))
+            }
+        }
+    }
+
+    /// the number of items read from the stream so far
+    #[getter]
+    fn index(&self) -> usize {
+        self.stream.index()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("JsonStreamIterator(index={})", self.stream.index())
+    }
+}
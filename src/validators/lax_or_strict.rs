@@ -5,6 +5,7 @@ use pyo3::types::PyDict;
 use crate::build_tools::{is_strict, SchemaDict};
 use crate::errors::ValResult;
 use crate::input::Input;
+use crate::py_gc::impl_py_gc_traverse;
 use crate::recursion_guard::RecursionGuard;
 
 use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
@@ -17,6 +18,11 @@ pub struct LaxOrStrictValidator {
     name: String,
 }
 
+impl_py_gc_traverse!(LaxOrStrictValidator {
+    lax_validator,
+    strict_validator
+});
+
 impl BuildValidator for LaxOrStrictValidator {
     const EXPECTED_TYPE: &'static str = "lax-or-strict";
 
@@ -0,0 +1,128 @@
+use std::fmt;
+
+use pyo3::class::gc::{PyTraverseError, PyVisit};
+use pyo3::intern;
+use pyo3::once_cell::GILOnceCell;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use ahash::AHashSet;
+
+use crate::build_context::schema_uses_refs;
+use crate::build_tools::SchemaDict;
+use crate::errors::ValResult;
+use crate::input::Input;
+use crate::py_gc::PyGcTraverse;
+use crate::questions::Question;
+use crate::recursion_guard::RecursionGuard;
+
+use super::{build_validator, BuildContext, CombinedValidator, Extra, Validator};
+
+/// A validator which is built from its schema the first time it's used, used for union choices with
+/// `lazy` set to speed up building huge schemas where most choices are rarely used.
+///
+/// Errors in the schema are only raised when the validator is first used, and the name is the schema's `type`
+/// rather than the name of the validator, e.g. in the location of union errors.
+pub struct LazyValidator {
+    schema: Py<PyDict>,
+    config: Option<Py<PyDict>>,
+    validator: GILOnceCell<Box<CombinedValidator>>,
+    name: String,
+}
+
+/// the built validator can't be read without the GIL, so a clone builds its own validator when it's first used
+impl Clone for LazyValidator {
+    fn clone(&self) -> Self {
+        Self {
+            schema: self.schema.clone(),
+            config: self.config.clone(),
+            validator: GILOnceCell::new(),
+            name: self.name.clone(),
+        }
+    }
+}
+
+impl fmt::Debug for LazyValidator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LazyValidator")
+            .field("schema", &self.schema)
+            .field("config", &self.config)
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}
+
+impl LazyValidator {
+    /// defer building `schema` until it's used, schemas which contain references need the outer `build_context`
+    /// so are built straight away
+    pub fn build_or_defer(
+        schema: &PyAny,
+        config: Option<&PyDict>,
+        build_context: &mut BuildContext<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        let schema = match schema.cast_as::<PyDict>() {
+            Ok(schema) if !schema_uses_refs(schema)? => schema,
+            _ => return build_validator(schema, config, build_context),
+        };
+        let name = schema.get_as_req::<String>(intern!(schema.py(), "type"))?;
+        Ok(Self {
+            schema: schema.into(),
+            config: config.map(Into::into),
+            validator: GILOnceCell::new(),
+            name,
+        }
+        .into())
+    }
+
+    fn get_validator(&self, py: Python) -> PyResult<&CombinedValidator> {
+        if let Some(validator) = self.validator.get(py) {
+            return Ok(validator);
+        }
+        let schema = self.schema.as_ref(py);
+        let config = self.config.as_ref().map(|c| c.as_ref(py));
+        let mut build_context = BuildContext::new(AHashSet::new());
+        let mut validator = build_validator(schema, config, &mut build_context)?;
+        validator.complete(&build_context)?;
+        // building can release the GIL, if another thread set the validator in the meantime that one is used
+        Ok(self.validator.get_or_init(py, || Box::new(validator)))
+    }
+}
+
+impl PyGcTraverse for LazyValidator {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        self.schema.py_gc_traverse(visit)?;
+        self.config.py_gc_traverse(visit)?;
+        // `__traverse__` is only called by python's garbage collector, which holds the GIL
+        let py = unsafe { Python::assume_gil_acquired() };
+        match self.validator.get(py) {
+            Some(validator) => validator.py_gc_traverse(visit),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Validator for LazyValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        self.get_validator(py)?
+            .validate(py, input, extra, slots, recursion_guard)
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// the validator has to be built to answer questions, if it can't be built the error is raised when it's used
+    fn ask(&self, py: Python, question: &Question) -> bool {
+        match self.get_validator(py) {
+            Ok(validator) => validator.ask(py, question),
+            Err(_) => false,
+        }
+    }
+}
@@ -1,11 +1,18 @@
+use std::borrow::Cow;
+
+use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyList, PyString};
+use rayon::prelude::*;
 
-use crate::build_tools::SchemaDict;
-use crate::errors::ValResult;
-use crate::input::{GenericCollection, Input};
+use crate::build_tools::{py_err, schema_or_config, SchemaDict};
+use crate::errors::{ErrorType, ValError, ValResult};
+use crate::input::{EitherInt, EitherString, GenericCollection, Input, JsonInput};
+use crate::py_gc::impl_py_gc_traverse;
+use crate::questions::Question;
 use crate::recursion_guard::RecursionGuard;
 
+use super::string::str_to_py;
 use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
 
 #[derive(Debug, Clone)]
@@ -15,9 +22,13 @@ pub struct ListValidator {
     item_validator: Option<Box<CombinedValidator>>,
     min_length: Option<usize>,
     max_length: Option<usize>,
+    parallel: Option<ParallelItems>,
+    coercion: CollectionCoercion,
     name: String,
 }
 
+impl_py_gc_traverse!(ListValidator { item_validator });
+
 pub fn get_items_schema(
     schema: &PyDict,
     config: Option<&PyDict>,
@@ -69,6 +80,154 @@ macro_rules! length_check {
 }
 pub(crate) use length_check;
 
+#[derive(Debug, Clone, Copy)]
+pub enum CollectionType {
+    List,
+    Tuple,
+    Set,
+    FrozenSet,
+}
+
+impl CollectionType {
+    fn error_type(self) -> ErrorType {
+        match self {
+            Self::List => ErrorType::ListType,
+            Self::Tuple => ErrorType::TupleType,
+            Self::Set => ErrorType::SetType,
+            Self::FrozenSet => ErrorType::FrozenSetType,
+        }
+    }
+}
+
+/// Which other types list, tuple, set and frozenset validators accept in lax mode, set with config keys.
+///
+/// JSON has no tuples or sets, so JSON arrays are always accepted.
+#[derive(Debug, Clone, Copy)]
+pub struct CollectionCoercion {
+    list_from_tuple: bool,
+    list_from_set: bool,
+    tuple_from_list: bool,
+    tuple_from_set: bool,
+    // lists and tuples
+    set_from_list: bool,
+    // whether a set built from another type is an error if it has fewer items because of duplicates
+    set_duplicates_error: bool,
+    // iterators, generators, deques and dict views
+    from_iter: bool,
+    // str as a sequence of characters and bytes as a sequence of ints
+    from_str: bool,
+}
+
+impl CollectionCoercion {
+    pub fn from_config(py: Python, config: Option<&PyDict>) -> PyResult<Self> {
+        let flag = |key: &PyString, default: bool| -> PyResult<bool> {
+            match config {
+                Some(config) => Ok(config.get_as(key)?.unwrap_or(default)),
+                None => Ok(default),
+            }
+        };
+        let set_duplicates_error = match config {
+            Some(config) => match config.get_as::<&str>(intern!(py, "set_duplicates"))? {
+                None | Some("collapse") => false,
+                Some("error") => true,
+                Some(s) => return py_err!(r#"Invalid set_duplicates: "{}""#, s),
+            },
+            None => false,
+        };
+        Ok(Self {
+            list_from_tuple: flag(intern!(py, "list_from_tuple"), true)?,
+            list_from_set: flag(intern!(py, "list_from_set"), false)?,
+            tuple_from_list: flag(intern!(py, "tuple_from_list"), true)?,
+            tuple_from_set: flag(intern!(py, "tuple_from_set"), false)?,
+            set_from_list: flag(intern!(py, "set_from_list"), true)?,
+            set_duplicates_error,
+            from_iter: flag(intern!(py, "collection_from_iter"), true)?,
+            from_str: flag(intern!(py, "collection_from_str"), false)?,
+        })
+    }
+
+    /// validate `input` as `collection_type`, in strict mode only the type itself is accepted,
+    /// except where `allow_any_iter` is set
+    pub fn validate<'data>(
+        &self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        collection_type: CollectionType,
+        strict: bool,
+        allow_any_iter: bool,
+    ) -> ValResult<'data, GenericCollection<'data>> {
+        let validate = || match collection_type {
+            CollectionType::List => input.validate_list(strict, allow_any_iter),
+            CollectionType::Tuple => input.validate_tuple(strict),
+            CollectionType::Set => input.validate_set(strict),
+            CollectionType::FrozenSet => input.validate_frozenset(strict),
+        };
+        if strict {
+            return validate();
+        }
+
+        if self.from_str {
+            if let Ok(either_str) = input.strict_str() {
+                let chars: Vec<String> = either_str.as_cow()?.chars().map(String::from).collect();
+                return Ok(PyList::new(py, chars).into());
+            } else if let Ok(either_bytes) = input.strict_bytes() {
+                let ints = either_bytes
+                    .into_py(py)
+                    .into_ref(py)
+                    .iter()?
+                    .collect::<PyResult<Vec<_>>>()?;
+                return Ok(PyList::new(py, ints).into());
+            }
+        }
+        let from_set = match collection_type {
+            CollectionType::List => self.list_from_set,
+            CollectionType::Tuple => self.tuple_from_set,
+            _ => false,
+        };
+        if from_set {
+            if let Ok(set) = input.strict_set() {
+                return Ok(set);
+            } else if let Ok(frozen_set) = input.strict_frozenset() {
+                return Ok(frozen_set);
+            }
+        }
+
+        let collection = validate()?;
+        let allowed = match (collection_type, &collection) {
+            (CollectionType::List, GenericCollection::Tuple(_)) => self.list_from_tuple,
+            (CollectionType::Tuple, GenericCollection::List(_)) => self.tuple_from_list,
+            (
+                CollectionType::Set | CollectionType::FrozenSet,
+                GenericCollection::List(_) | GenericCollection::Tuple(_),
+            ) => self.set_from_list,
+            (CollectionType::List, GenericCollection::PyAny(_)) if allow_any_iter => true,
+            (_, GenericCollection::PyAny(_)) => self.from_iter,
+            _ => true,
+        };
+        match allowed {
+            true => Ok(collection),
+            false => Err(ValError::new(collection_type.error_type(), input)),
+        }
+    }
+
+    /// with `set_duplicates='error'`, a set built from `item_count` items of another type must have the
+    /// same number of items
+    pub fn check_duplicates<'data>(
+        &self,
+        input: &'data impl Input<'data>,
+        collection: &GenericCollection,
+        item_count: usize,
+        set_len: usize,
+    ) -> ValResult<'data, ()> {
+        let from_set = matches!(collection, GenericCollection::Set(_) | GenericCollection::FrozenSet(_));
+        if self.set_duplicates_error && !from_set && set_len < item_count {
+            Err(ValError::new(ErrorType::SetDuplicateItem, input))
+        } else {
+            Ok(())
+        }
+    }
+}
+
 impl BuildValidator for ListValidator {
     const EXPECTED_TYPE: &'static str = "list";
 
@@ -81,12 +240,24 @@ impl BuildValidator for ListValidator {
         let item_validator = get_items_schema(schema, config, build_context)?;
         let inner_name = item_validator.as_ref().map(|v| v.get_name()).unwrap_or("any");
         let name = format!("{}[{inner_name}]", Self::EXPECTED_TYPE);
+        let parallel: bool = schema_or_config(
+            schema,
+            config,
+            pyo3::intern!(py, "parallel"),
+            pyo3::intern!(py, "list_parallel"),
+        )?
+        .unwrap_or(false);
         Ok(Self {
             strict: crate::build_tools::is_strict(schema, config)?,
             allow_any_iter: schema.get_as(pyo3::intern!(py, "allow_any_iter"))?.unwrap_or(false),
-            item_validator,
             min_length: schema.get_as(pyo3::intern!(py, "min_length"))?,
             max_length: schema.get_as(pyo3::intern!(py, "max_length"))?,
+            parallel: match parallel {
+                true => item_validator.as_deref().and_then(ParallelItems::from_validator),
+                false => None,
+            },
+            item_validator,
+            coercion: CollectionCoercion::from_config(py, config)?,
             name,
         }
         .into())
@@ -102,7 +273,21 @@ impl Validator for ListValidator {
         slots: &'data [CombinedValidator],
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let seq = input.validate_list(extra.strict.unwrap_or(self.strict), self.allow_any_iter)?;
+        let strict = extra.strict.unwrap_or(self.strict);
+        let seq = self
+            .coercion
+            .validate(py, input, CollectionType::List, strict, self.allow_any_iter)?;
+
+        if let (Some(parallel), GenericCollection::JsonArray(items)) = (self.parallel, &seq) {
+            // `include`/`exclude` are only applied by validating items one by one
+            if items.len() >= PARALLEL_MIN_LENGTH && extra.include.is_none() && extra.exclude.is_none() {
+                if let Some(values) = parallel.validate(py, items, extra.strict) {
+                    let output: Vec<PyObject> = values.into_iter().map(|v| v.into_output(py, extra)).collect();
+                    length_check!(input, "List", self.min_length, self.max_length, output);
+                    return Ok(output.into_py(py));
+                }
+            }
+        }
 
         let output = match self.item_validator {
             Some(ref v) => seq.validate_to_vec(
@@ -132,6 +317,10 @@ impl Validator for ListValidator {
         &self.name
     }
 
+    fn ask(&self, _py: Python, question: &Question) -> bool {
+        matches!(question, Question::RejectsNone)
+    }
+
     fn complete(&mut self, build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
         match self.item_validator {
             Some(ref mut v) => v.complete(build_context),
@@ -139,3 +328,97 @@ impl Validator for ListValidator {
         }
     }
 }
+
+/// JSON arrays shorter than this are always validated on the current thread, for small arrays the cost of
+/// releasing the GIL and handing items to rayon's threads outweighs any gain
+const PARALLEL_MIN_LENGTH: usize = 1_000;
+
+/// Item validators which never call into Python, with `parallel` set, items of large JSON arrays are validated
+/// on rayon's threads with the GIL released and the GIL is only held again to build the output objects
+#[derive(Debug, Clone, Copy)]
+enum ParallelItems {
+    Int { strict: bool, coerce_bools: bool },
+    Float { strict: bool, allow_inf_nan: bool },
+    Bool { strict: bool },
+    Str { strict: bool },
+}
+
+impl ParallelItems {
+    /// `None` for item validators which might need the GIL, these lists are always validated on the current thread
+    fn from_validator(validator: &CombinedValidator) -> Option<Self> {
+        match validator {
+            CombinedValidator::Int(v) => Some(Self::Int {
+                strict: v.strict,
+                coerce_bools: v.coerce_bools,
+            }),
+            CombinedValidator::Float(v) => Some(Self::Float {
+                strict: v.strict,
+                allow_inf_nan: v.allow_inf_nan,
+            }),
+            CombinedValidator::Bool(v) if v.default_strings() => Some(Self::Bool { strict: v.strict }),
+            CombinedValidator::Str(v) => Some(Self::Str { strict: v.strict }),
+            _ => None,
+        }
+    }
+
+    /// Returns `None` if any item is invalid, the caller then validates the items again on the current thread
+    /// to build the errors, so errors are exactly the same as without `parallel`
+    fn validate<'a>(
+        self,
+        py: Python,
+        items: &'a [JsonInput<'a>],
+        strict: Option<bool>,
+    ) -> Option<Vec<ParallelValue<'a>>> {
+        py.allow_threads(|| {
+            items
+                .par_iter()
+                .with_min_len(PARALLEL_MIN_LENGTH)
+                .map(|item| self.validate_item(item, strict))
+                .collect()
+        })
+    }
+
+    fn validate_item<'a>(self, item: &'a JsonInput<'a>, strict: Option<bool>) -> Option<ParallelValue<'a>> {
+        match self {
+            Self::Int {
+                strict: s,
+                coerce_bools,
+            } => item
+                .validate_int(strict.unwrap_or(s), coerce_bools)
+                .ok()
+                .map(ParallelValue::Int),
+            Self::Float {
+                strict: s,
+                allow_inf_nan,
+            } => match item.validate_float(strict.unwrap_or(s)) {
+                Ok(float) if allow_inf_nan || float.is_finite() => Some(ParallelValue::Float(float)),
+                _ => None,
+            },
+            Self::Bool { strict: s } => item.validate_bool(strict.unwrap_or(s)).ok().map(ParallelValue::Bool),
+            Self::Str { strict: s } => match item.validate_str(strict.unwrap_or(s)) {
+                Ok(EitherString::Cow(str)) => Some(ParallelValue::Str(str)),
+                _ => None,
+            },
+        }
+    }
+}
+
+/// An item validated without the GIL, converted to a python object once the GIL is held again
+enum ParallelValue<'a> {
+    Int(EitherInt),
+    Float(f64),
+    Bool(bool),
+    Str(Cow<'a, str>),
+}
+
+impl<'a> ParallelValue<'a> {
+    fn into_output(self, py: Python, extra: &Extra) -> PyObject {
+        match self {
+            Self::Int(int) => int.into_py(py),
+            Self::Float(float) => float.into_py(py),
+            Self::Bool(bool) => bool.into_py(py),
+            // strings go through the string cache as they would without `parallel`
+            Self::Str(str) => str_to_py(py, &str, extra),
+        }
+    }
+}
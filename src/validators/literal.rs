@@ -10,6 +10,7 @@ use nohash_hasher::IntSet;
 use crate::build_tools::{py_err, SchemaDict};
 use crate::errors::{ErrorType, ValError, ValResult};
 use crate::input::Input;
+use crate::py_gc::impl_py_gc_traverse;
 use crate::recursion_guard::RecursionGuard;
 
 use super::none::NoneValidator;
@@ -57,6 +58,8 @@ pub struct LiteralSingleStringValidator {
     name: String,
 }
 
+impl_py_gc_traverse!(LiteralSingleStringValidator {});
+
 impl LiteralSingleStringValidator {
     fn new(expected: String) -> Self {
         let expected_repr = format!("'{expected}'");
@@ -102,6 +105,8 @@ pub struct LiteralSingleIntValidator {
     name: String,
 }
 
+impl_py_gc_traverse!(LiteralSingleIntValidator {});
+
 impl LiteralSingleIntValidator {
     fn new(expected: i64) -> Self {
         Self {
@@ -120,8 +125,8 @@ impl Validator for LiteralSingleIntValidator {
         _slots: &'data [CombinedValidator],
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let str = input.strict_int()?;
-        if str == self.expected {
+        let int = input.strict_int()?;
+        if int == self.expected {
             Ok(input.to_object(py))
         } else {
             Err(ValError::new(
@@ -145,6 +150,8 @@ pub struct LiteralMultipleStringsValidator {
     name: String,
 }
 
+impl_py_gc_traverse!(LiteralMultipleStringsValidator {});
+
 impl LiteralMultipleStringsValidator {
     fn new(expected_list: &PyList) -> Option<Self> {
         let mut expected: AHashSet<String> = AHashSet::new();
@@ -200,6 +207,8 @@ pub struct LiteralMultipleIntsValidator {
     name: String,
 }
 
+impl_py_gc_traverse!(LiteralMultipleIntsValidator {});
+
 impl LiteralMultipleIntsValidator {
     fn new(expected_list: &PyList) -> Option<Self> {
         let mut expected: IntSet<i64> = IntSet::with_hasher(BuildHasherDefault::default());
@@ -231,7 +240,7 @@ impl Validator for LiteralMultipleIntsValidator {
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
         let int = input.strict_int()?;
-        if self.expected.contains(&int) {
+        if matches!(int.as_i64(), Some(int) if self.expected.contains(&int)) {
             Ok(input.to_object(py))
         } else {
             Err(ValError::new(
@@ -257,6 +266,8 @@ pub struct LiteralGeneralValidator {
     name: String,
 }
 
+impl_py_gc_traverse!(LiteralGeneralValidator { expected_py });
+
 impl LiteralGeneralValidator {
     fn new(expected: &PyList) -> PyResult<Self> {
         let mut expected_int = AHashSet::new();
@@ -295,7 +306,7 @@ impl Validator for LiteralGeneralValidator {
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
         if !self.expected_int.is_empty() {
-            if let Ok(int) = input.strict_int() {
+            if let Some(int) = input.strict_int().ok().and_then(|int| int.as_i64()) {
                 if self.expected_int.contains(&int) {
                     return Ok(input.to_object(py));
                 }
@@ -2,17 +2,34 @@ use std::fmt::Debug;
 
 use enum_dispatch::enum_dispatch;
 
+use pyo3::class::gc::{PyTraverseError, PyVisit};
+use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::intern;
 use pyo3::once_cell::GILOnceCell;
 use pyo3::prelude::*;
-use pyo3::types::{PyAny, PyDict};
+use pyo3::types::{PyAny, PyDict, PyList};
 
 use crate::build_context::BuildContext;
 use crate::build_tools::{py_err, py_error_type, SchemaDict, SchemaError};
-use crate::errors::{ValError, ValResult, ValidationError};
-use crate::input::Input;
+use crate::errors::{
+    ErrorLimits, ErrorType, LocItem, ValError, ValLineError, ValResult, ValidationError, ValidationWarnings,
+};
+#[cfg(feature = "toml")]
+use crate::input::py_parse_toml;
+#[cfg(feature = "yaml")]
+use crate::input::py_parse_yaml;
+use crate::input::{
+    complete_partial_json, json_invalid, py_json_bytes, py_parse_cbor, py_parse_msgpack, py_parse_urlencoded, Input,
+    JsonInput, JsonStream, StringInput,
+};
+use crate::profile::Profiler;
+use crate::py_gc::PyGcTraverse;
 use crate::questions::{Answers, Question};
-use crate::recursion_guard::RecursionGuard;
+use crate::recursion_guard::{RecursionGuard, DEFAULT_RECURSION_LIMIT};
+use crate::serializers::{AllItems, AnyFilter};
+use crate::string_cache::{CacheStrings, StringCache};
+
+use self::json_stream::JsonStreamIterator;
 
 mod any;
 mod arguments;
@@ -21,9 +38,11 @@ mod bytes;
 mod call;
 mod callable;
 mod chain;
+mod conditional;
 mod custom_error;
 mod date;
 mod datetime;
+mod definitions;
 mod dict;
 mod float;
 mod frozenset;
@@ -33,14 +52,18 @@ mod int;
 mod is_instance;
 mod is_subclass;
 mod json;
+mod json_stream;
 mod lax_or_strict;
+mod lazy;
 mod list;
 mod literal;
+mod model;
 mod new_class;
 mod none;
 mod nullable;
 mod recursive;
 mod set;
+mod shared_ref;
 mod string;
 mod time;
 mod timedelta;
@@ -58,8 +81,15 @@ pub struct SchemaValidator {
     validator: CombinedValidator,
     slots: Vec<CombinedValidator>,
     schema: PyObject,
+    // the config is kept, along with the schema, so the validator can be rebuilt when it's unpickled
+    config: Option<PyObject>,
     #[pyo3(get)]
     title: PyObject,
+    error_limits: ErrorLimits,
+    hide_input_in_errors: bool,
+    recursion_limit: u16,
+    cache_strings: CacheStrings,
+    profiler: Option<Profiler>,
 }
 
 #[pymethods]
@@ -69,15 +99,21 @@ impl SchemaValidator {
         let schema = Self::validate_schema(py, schema)?;
 
         let mut build_context = BuildContext::for_schema(schema)?;
+        if config.get_as(intern!(py, "profile"))?.unwrap_or(false) {
+            build_context.enable_profiling();
+        }
 
-        let mut validator = build_validator(schema, config, &mut build_context)?;
+        let mut validator =
+            build_validator(schema, config, &mut build_context).map_err(|e| build_context.locate_error(e, schema))?;
         validator.complete(&build_context)?;
+        let profiler = build_context.take_profiler();
         let slots = build_context.into_slots_val()?;
+        // the title used in errors is from config, then the schema, then the validator's name
         let config_title = match config {
             Some(c) => c.get_item("title"),
             None => None,
         };
-        let title = match config_title {
+        let title = match config_title.or_else(|| schema.cast_as::<PyDict>().ok()?.get_item(intern!(py, "title"))) {
             Some(t) => t.into_py(py),
             None => validator.get_name().into_py(py),
         };
@@ -85,31 +121,88 @@ impl SchemaValidator {
             validator,
             slots,
             schema: schema.into_py(py),
+            config: config.map(|c| c.into_py(py)),
             title,
+            error_limits: ErrorLimits::from_config(config)?,
+            hide_input_in_errors: config.get_as(intern!(py, "hide_input_in_errors"))?.unwrap_or(false),
+            recursion_limit: config
+                .get_as(intern!(py, "recursion_limit"))?
+                .unwrap_or(DEFAULT_RECURSION_LIMIT),
+            cache_strings: CacheStrings::from_config(config)?,
+            profiler,
         })
     }
 
-    pub fn __reduce__(&self, py: Python) -> PyResult<PyObject> {
-        let args = (self.schema.as_ref(py),);
-        let cls = Py::new(py, self.to_owned())?.getattr(py, "__class__")?;
-        Ok((cls, args).into_py(py))
+    pub fn __reduce__(&self, py: Python) -> PyObject {
+        let args = (self.schema.clone_ref(py), self.config.clone());
+        (py.get_type::<Self>(), args).into_py(py)
+    }
+
+    /// validators are immutable once built, so a copy can share them rather than rebuilding from the schema
+    pub fn __deepcopy__(&self, py: Python, _memo: &PyDict) -> PyResult<Py<Self>> {
+        Py::new(py, self.clone())
+    }
+
+    /// with the `profile` config key set, the number of calls and total time taken validating each typed dict
+    /// field, as a list of dicts with keys `loc`, `calls` and `time_ns`
+    pub fn profile_stats(&self, py: Python) -> PyResult<PyObject> {
+        match self.profiler {
+            Some(ref profiler) => profiler.stats(py),
+            None => Err(PyValueError::new_err(
+                "profile_stats() requires the validator to be built with `profile=True` in config",
+            )),
+        }
+    }
+
+    /// lets the garbage collector find reference cycles through the functions, classes and defaults
+    /// held by validators, e.g. a validator function which is a bound method of an object holding the validator
+    fn __traverse__(&self, visit: PyVisit<'_>) -> Result<(), PyTraverseError> {
+        self.validator.py_gc_traverse(&visit)?;
+        self.slots.py_gc_traverse(&visit)?;
+        visit.call(&self.schema)?;
+        self.config.py_gc_traverse(&visit)?;
+        visit.call(&self.title)
+    }
+
+    /// only called by the garbage collector to break a cycle, the validator can't be used afterwards
+    fn __clear__(&mut self) {
+        self.validator = any::AnyValidator.into();
+        self.slots.clear();
+        self.config = None;
+    }
+
+    /// the schema after validation against the self schema, which is what the validator was built from
+    pub fn get_schema(&self, py: Python) -> PyObject {
+        self.schema.clone_ref(py)
+    }
+
+    /// unlike `title`, this is always the name of the root validator, it's not taken from config
+    pub fn get_name(&self) -> &str {
+        self.validator.get_name()
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn validate_python(
         &self,
         py: Python,
         input: &PyAny,
         strict: Option<bool>,
         context: Option<&PyAny>,
+        collect_warnings: Option<bool>,
+        self_instance: Option<&PyAny>,
+        return_fields_set: Option<bool>,
+        allow_partial: Option<bool>,
+        include: Option<&PyAny>,
+        exclude: Option<&PyAny>,
     ) -> PyResult<PyObject> {
-        let r = self.validator.validate(
-            py,
-            input,
-            &Extra::new(strict, context),
-            &self.slots,
-            &mut RecursionGuard::default(),
-        );
-        r.map_err(|e| self.prepare_validation_err(py, e))
+        let options = InputOptions {
+            self_instance,
+            return_fields_set: return_fields_set.unwrap_or(false),
+            partial: allow_partial.unwrap_or(false),
+            include,
+            exclude,
+        };
+        self.validate_input(py, input, strict, context, collect_warnings, options)
     }
 
     pub fn isinstance_python(
@@ -122,9 +215,9 @@ impl SchemaValidator {
         match self.validator.validate(
             py,
             input,
-            &Extra::new(strict, context),
+            &self.extra(strict, context, None, None),
             &self.slots,
-            &mut RecursionGuard::default(),
+            &mut self.recursion_guard(),
         ) {
             Ok(_) => Ok(true),
             Err(ValError::InternalErr(err)) => Err(err),
@@ -139,20 +232,273 @@ impl SchemaValidator {
         input: &PyAny,
         strict: Option<bool>,
         context: Option<&PyAny>,
+        collect_warnings: Option<bool>,
+        allow_partial: Option<bool>,
     ) -> PyResult<PyObject> {
-        match input.parse_json() {
+        let partial = allow_partial.unwrap_or(false);
+        let completed: Vec<u8>;
+        let parsed = match input.parse_json() {
+            // with `allow_partial`, JSON which was cut off is completed and parsed again
+            Err(err) if partial => match py_json_bytes(input)
+                .ok()
+                .and_then(|bytes| complete_partial_json(&bytes))
+            {
+                Some(bytes) => {
+                    completed = bytes;
+                    serde_json::from_slice::<JsonInput>(&completed).map_err(|_| err)
+                }
+                None => Err(err),
+            },
+            parsed => parsed,
+        };
+        match parsed {
             Ok(input) => {
-                let r = self.validator.validate(
-                    py,
-                    &input,
-                    &Extra::new(strict, context),
-                    &self.slots,
-                    &mut RecursionGuard::default(),
-                );
-                r.map_err(|e| self.prepare_validation_err(py, e))
+                let options = InputOptions {
+                    partial,
+                    ..Default::default()
+                };
+                self.validate_input(py, &input, strict, context, collect_warnings, options)
+            }
+            Err(err) => Err(self.prepare_validation_err(py, err, input)),
+        }
+    }
+
+    /// Validate MessagePack data, it's decoded straight to the same representation as JSON input
+    pub fn validate_msgpack(
+        &self,
+        py: Python,
+        input: &PyAny,
+        strict: Option<bool>,
+        context: Option<&PyAny>,
+        collect_warnings: Option<bool>,
+    ) -> PyResult<PyObject> {
+        match py_parse_msgpack(input) {
+            Ok(input) => self.validate_input(py, &input, strict, context, collect_warnings, InputOptions::default()),
+            Err(err) => Err(self.prepare_validation_err(py, err, input)),
+        }
+    }
+
+    /// Validate a string or dict of strings, e.g. from environment variables, every scalar type is parsed from
+    /// strings and containers from JSON strings
+    pub fn validate_strings(
+        &self,
+        py: Python,
+        input: &PyAny,
+        strict: Option<bool>,
+        context: Option<&PyAny>,
+        collect_warnings: Option<bool>,
+    ) -> PyResult<PyObject> {
+        match StringInput::from_py(input) {
+            Ok(input) => self.validate_input(py, &input, strict, context, collect_warnings, InputOptions::default()),
+            Err(err) => Err(self.prepare_validation_err(py, err, input)),
+        }
+    }
+
+    /// Validate CBOR data, like MessagePack it's decoded straight to the same representation as JSON input
+    pub fn validate_cbor(
+        &self,
+        py: Python,
+        input: &PyAny,
+        strict: Option<bool>,
+        context: Option<&PyAny>,
+        collect_warnings: Option<bool>,
+    ) -> PyResult<PyObject> {
+        match py_parse_cbor(input) {
+            Ok(input) => self.validate_input(py, &input, strict, context, collect_warnings, InputOptions::default()),
+            Err(err) => Err(self.prepare_validation_err(py, err, input)),
+        }
+    }
+
+    /// Validate `application/x-www-form-urlencoded` data, e.g. an HTML form body or a query string
+    pub fn validate_urlencoded(
+        &self,
+        py: Python,
+        input: &PyAny,
+        strict: Option<bool>,
+        context: Option<&PyAny>,
+        collect_warnings: Option<bool>,
+    ) -> PyResult<PyObject> {
+        match py_parse_urlencoded(input) {
+            Ok(input) => self.validate_input(py, &input, strict, context, collect_warnings, InputOptions::default()),
+            Err(err) => Err(self.prepare_validation_err(py, err, input)),
+        }
+    }
+
+    /// Validate a TOML document, only available with the `toml` feature
+    #[cfg(feature = "toml")]
+    pub fn validate_toml(
+        &self,
+        py: Python,
+        input: &PyAny,
+        strict: Option<bool>,
+        context: Option<&PyAny>,
+        collect_warnings: Option<bool>,
+    ) -> PyResult<PyObject> {
+        match py_parse_toml(input) {
+            Ok(input) => self.validate_input(py, &input, strict, context, collect_warnings, InputOptions::default()),
+            Err(err) => Err(self.prepare_validation_err(py, err, input)),
+        }
+    }
+
+    /// Validate a YAML document, only available with the `yaml` feature
+    #[cfg(feature = "yaml")]
+    pub fn validate_yaml(
+        &self,
+        py: Python,
+        input: &PyAny,
+        strict: Option<bool>,
+        context: Option<&PyAny>,
+        collect_warnings: Option<bool>,
+    ) -> PyResult<PyObject> {
+        match py_parse_yaml(input) {
+            Ok(input) => self.validate_input(py, &input, strict, context, collect_warnings, InputOptions::default()),
+            Err(err) => Err(self.prepare_validation_err(py, err, input)),
+        }
+    }
+
+    /// Validate `input` decoded according to `format`, so the decoding can be chosen at runtime, e.g. from a
+    /// request's content type
+    #[args("*", format = "None", strict = "None", context = "None", collect_warnings = "None")]
+    pub fn validate(
+        &self,
+        py: Python,
+        input: &PyAny,
+        format: Option<&str>,
+        strict: Option<bool>,
+        context: Option<&PyAny>,
+        collect_warnings: Option<bool>,
+    ) -> PyResult<PyObject> {
+        match format.unwrap_or("python") {
+            "python" => self.validate_python(
+                py,
+                input,
+                strict,
+                context,
+                collect_warnings,
+                None,
+                None,
+                None,
+                None,
+                None,
+            ),
+            "json" => self.validate_json(py, input, strict, context, collect_warnings, None),
+            "msgpack" => self.validate_msgpack(py, input, strict, context, collect_warnings),
+            "strings" => self.validate_strings(py, input, strict, context, collect_warnings),
+            "cbor" => self.validate_cbor(py, input, strict, context, collect_warnings),
+            "urlencoded" => self.validate_urlencoded(py, input, strict, context, collect_warnings),
+            #[cfg(feature = "toml")]
+            "toml" => self.validate_toml(py, input, strict, context, collect_warnings),
+            #[cfg(feature = "yaml")]
+            "yaml" => self.validate_yaml(py, input, strict, context, collect_warnings),
+            format => py_err!(
+                PyValueError;
+                "Invalid format '{}', expected one of: {}",
+                format,
+                input_formats().join(", ")
+            ),
+        }
+    }
+
+    /// Validate newline delimited JSON, each non-blank line is validated separately and the outputs returned as a
+    /// list, errors from all lines are raised together located by their 1-based line number
+    pub fn validate_json_lines(
+        &self,
+        py: Python,
+        input: &PyAny,
+        strict: Option<bool>,
+        context: Option<&PyAny>,
+    ) -> PyResult<PyObject> {
+        let bytes = py_json_bytes(input).map_err(|e| self.prepare_validation_err(py, e, input))?;
+        let string_cache = StringCache::new(self.cache_strings);
+        let extra = self.extra(strict, context, None, string_cache.as_ref());
+        let mut output: Vec<PyObject> = Vec::new();
+        let mut errors: Vec<ValLineError> = Vec::new();
+        for (index, line) in bytes.split(|b| *b == b'\n').enumerate() {
+            if line.iter().all(u8::is_ascii_whitespace) {
+                continue;
+            }
+            let line_number = index + 1;
+            let line_errors = match serde_json::from_slice::<JsonInput>(line) {
+                Ok(json_input) => {
+                    let r = self
+                        .validator
+                        .validate(py, &json_input, &extra, &self.slots, &mut self.recursion_guard());
+                    match r {
+                        Ok(item) => {
+                            output.push(item);
+                            continue;
+                        }
+                        Err(ValError::LineErrors(line_errors)) => {
+                            line_errors.iter().map(|e| e.duplicate(py)).collect::<Vec<_>>()
+                        }
+                        Err(ValError::InternalErr(err)) => return Err(err),
+                        Err(ValError::Omit) => continue,
+                    }
+                }
+                Err(err) => {
+                    let raw = String::from_utf8_lossy(line);
+                    vec![ValLineError::new(json_invalid(err, line), &raw).duplicate(py)]
+                }
+            };
+            errors.extend(
+                line_errors
+                    .into_iter()
+                    .map(|e| e.with_outer_location(line_number.into())),
+            );
+            if extra.too_many_errors(errors.len()) {
+                break;
+            }
+        }
+        match errors.is_empty() {
+            true => Ok(PyList::new(py, output).into_py(py)),
+            false => Err(self.prepare_validation_err(py, ValError::LineErrors(errors), input)),
+        }
+    }
+
+    /// Validate each python object from the iterable `inputs`, returns a tuple of a list of the outputs of valid items
+    /// and a list of `(index, ValidationError)` tuples for invalid items, the string cache and recursion guard
+    /// are shared by all items to reduce the overhead of validating many small inputs
+    pub fn validate_many(
+        &self,
+        py: Python,
+        inputs: &PyAny,
+        strict: Option<bool>,
+        context: Option<&PyAny>,
+    ) -> PyResult<PyObject> {
+        let string_cache = StringCache::new(self.cache_strings);
+        let extra = self.extra(strict, context, None, string_cache.as_ref());
+        let mut recursion_guard = self.recursion_guard();
+        let outputs = PyList::empty(py);
+        let errors = PyList::empty(py);
+        for (index, input) in inputs.iter()?.enumerate() {
+            let input = input?;
+            match self
+                .validator
+                .validate(py, input, &extra, &self.slots, &mut recursion_guard)
+            {
+                Ok(output) => outputs.append(output)?,
+                Err(ValError::InternalErr(err)) => return Err(err),
+                Err(ValError::Omit) => return Err(ValidationError::omit_error()),
+                Err(err) => {
+                    let err = self.prepare_validation_err(py, err, input);
+                    errors.append((index, err.into_py(py)))?;
+                }
             }
-            Err(err) => Err(self.prepare_validation_err(py, err)),
         }
+        Ok((outputs, errors).into_py(py))
+    }
+
+    /// Validate each item of a top-level JSON array read from a file-like object, without reading the whole
+    /// stream into memory, with `ndjson=True` the stream should instead contain newline delimited JSON values
+    pub fn validate_json_stream(
+        slf: PyRef<'_, Self>,
+        readable: &PyAny,
+        strict: Option<bool>,
+        context: Option<&PyAny>,
+        ndjson: Option<bool>,
+    ) -> JsonStreamIterator {
+        let stream = JsonStream::new(readable, ndjson.unwrap_or(false));
+        JsonStreamIterator::new(slf.into(), stream, strict, context)
     }
 
     pub fn isinstance_json(
@@ -167,9 +513,9 @@ impl SchemaValidator {
                 match self.validator.validate(
                     py,
                     &input,
-                    &Extra::new(strict, context),
+                    &self.extra(strict, context, None, None),
                     &self.slots,
-                    &mut RecursionGuard::default(),
+                    &mut self.recursion_guard(),
                 ) {
                     Ok(_) => Ok(true),
                     Err(ValError::InternalErr(err)) => Err(err),
@@ -181,39 +527,67 @@ impl SchemaValidator {
         }
     }
 
+    /// `obj` is the data for a `typed-dict` schema, or the instance for a `new-class` schema
     pub fn validate_assignment(
         &self,
         py: Python,
-        field: String,
-        input: &PyAny,
-        data: &PyDict,
+        obj: &PyAny,
+        field_name: &str,
+        field_value: &PyAny,
         strict: Option<bool>,
         context: Option<&PyAny>,
     ) -> PyResult<PyObject> {
         let extra = Extra {
-            data: Some(data),
-            field: Some(field.as_str()),
+            data: obj.cast_as::<PyDict>().ok(),
+            field: Some(field_name),
+            field_name: Some(field_name),
             strict,
             context,
+            max_errors: self.error_limits.max_errors,
+            warnings: None,
+            string_cache: None,
+            self_instance: Some(obj),
+            partial: false,
+            include: None,
+            exclude: None,
         };
         let r = self
             .validator
-            .validate(py, input, &extra, &self.slots, &mut RecursionGuard::default());
-        r.map_err(|e| self.prepare_validation_err(py, e))
+            .validate(py, field_value, &extra, &self.slots, &mut self.recursion_guard());
+        r.map_err(|e| self.prepare_validation_err(py, e, field_value))
     }
 
+    /// `name` is the validator's name unless a title is set in config, so when there's config the validator's
+    /// name is included along with the config values used directly by `SchemaValidator`
     pub fn __repr__(&self, py: Python) -> String {
+        let config = match self.config {
+            Some(_) => format!(
+                ", validator_name={:?}, error_limits={:?}, hide_input_in_errors={:?}",
+                self.validator.get_name(),
+                self.error_limits,
+                self.hide_input_in_errors,
+            ),
+            None => String::new(),
+        };
         format!(
-            "SchemaValidator(name={:?}, validator={:#?}, slots={:#?})",
+            "SchemaValidator(name={:?}, validator={:#?}, slots={:#?}{})",
             self.title.extract::<&str>(py).unwrap(),
             self.validator,
             self.slots,
+            config,
         )
     }
 }
 
 static SCHEMA_DEFINITION: GILOnceCell<SchemaValidator> = GILOnceCell::new();
 
+/// Validate a core schema against the schema for core schemas without building a validator, this is the same
+/// validation `SchemaValidator` and `SchemaSerializer` perform before building
+#[pyfunction]
+pub fn validate_core_schema<'py>(py: Python<'py>, schema: &'py PyAny) -> PyResult<&'py PyAny> {
+    SchemaValidator::validate_schema(py, schema)
+}
+
 impl SchemaValidator {
     pub(crate) fn validate_schema<'py>(py: Python<'py>, schema: &'py PyAny) -> PyResult<&'py PyAny> {
         let self_schema = Self::get_self_schema(py);
@@ -222,7 +596,7 @@ impl SchemaValidator {
             schema,
             &Extra::default(),
             &self_schema.slots,
-            &mut RecursionGuard::default(),
+            &mut self_schema.recursion_guard(),
         ) {
             Ok(schema_obj) => Ok(schema_obj.into_ref(py)),
             Err(e) => Err(SchemaError::from_val_error(py, e)),
@@ -254,15 +628,112 @@ impl SchemaValidator {
             validator,
             slots: build_context.into_slots_val()?,
             schema: py.None(),
+            config: None,
             title: "Self Schema".into_py(py),
+            error_limits: ErrorLimits::default(),
+            hide_input_in_errors: false,
+            recursion_limit: DEFAULT_RECURSION_LIMIT,
+            cache_strings: CacheStrings::None,
+            profiler: None,
         })
     }
 
-    fn prepare_validation_err(&self, py: Python, error: ValError) -> PyErr {
+    /// Validate already parsed input, shared by `validate_python` and the entry points which parse their input first
+    fn validate_input<'data>(
+        &'data self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        strict: Option<bool>,
+        context: Option<&'data PyAny>,
+        collect_warnings: Option<bool>,
+        options: InputOptions<'data>,
+    ) -> PyResult<PyObject> {
+        let warnings = collect_warnings.unwrap_or(false).then(ValidationWarnings::default);
+        let string_cache = StringCache::new(self.cache_strings);
+        let extra = Extra {
+            self_instance: options.self_instance,
+            partial: options.partial,
+            include: options.include,
+            exclude: options.exclude,
+            ..self.extra(strict, context, warnings.as_ref(), string_cache.as_ref())
+        };
+        let r = if options.return_fields_set {
+            // the output is a tuple of the dict and the set of fields present in the input
+            match self.validator {
+                CombinedValidator::TypedDict(ref validator) => {
+                    validator.validate_with_fields_set(py, input, &extra, &self.slots, &mut self.recursion_guard())
+                }
+                _ => return py_err!(PyTypeError; "`return_fields_set` requires a typed-dict schema"),
+            }
+        } else {
+            self.validator
+                .validate(py, input, &extra, &self.slots, &mut self.recursion_guard())
+        };
+        let output = r.map_err(|e| self.prepare_validation_err(py, e, input))?;
+        with_warnings(py, output, warnings)
+    }
+
+    fn recursion_guard(&self) -> RecursionGuard {
+        RecursionGuard::new(self.recursion_limit)
+    }
+
+    fn extra<'a>(
+        &self,
+        strict: Option<bool>,
+        context: Option<&'a PyAny>,
+        warnings: Option<&'a ValidationWarnings>,
+        string_cache: Option<&'a StringCache>,
+    ) -> Extra<'a> {
+        Extra {
+            max_errors: self.error_limits.max_errors,
+            warnings,
+            string_cache,
+            ..Extra::new(strict, context)
+        }
+    }
+
+    fn prepare_validation_err<'a>(&self, py: Python, error: ValError<'a>, input: &'a impl Input<'a>) -> PyErr {
+        let error = match self.error_limits.apply(py, error, input) {
+            Ok(error) => error,
+            Err(err) => return err,
+        };
+        let error = match self.hide_input_in_errors {
+            true => error.hide_input(),
+            false => error,
+        };
         ValidationError::from_val_error(py, self.title.clone_ref(py), error, None)
     }
 }
 
+/// with `collect_warnings=True`, validation returns a tuple of the output and a list of warnings
+fn with_warnings(py: Python, output: PyObject, warnings: Option<ValidationWarnings>) -> PyResult<PyObject> {
+    match warnings {
+        Some(warnings) => Ok((output, warnings.to_py(py)?).into_py(py)),
+        None => Ok(output),
+    }
+}
+
+/// options which only some of the validate methods accept, see `SchemaValidator::validate_input`
+#[derive(Default)]
+struct InputOptions<'a> {
+    self_instance: Option<&'a PyAny>,
+    return_fields_set: bool,
+    partial: bool,
+    include: Option<&'a PyAny>,
+    exclude: Option<&'a PyAny>,
+}
+
+/// the formats accepted by `SchemaValidator.validate`
+fn input_formats() -> Vec<&'static str> {
+    #[allow(unused_mut)]
+    let mut formats = vec!["python", "json", "msgpack", "strings", "cbor", "urlencoded"];
+    #[cfg(feature = "toml")]
+    formats.push("toml");
+    #[cfg(feature = "yaml")]
+    formats.push("yaml");
+    formats
+}
+
 pub trait BuildValidator: Sized {
     const EXPECTED_TYPE: &'static str;
 
@@ -290,17 +761,37 @@ fn build_specific_validator<'a, T: BuildValidator>(
         if build_context.ref_used(&schema_ref) {
             let answers = Answers::new(schema_dict)?;
             let slot_id = build_context.prepare_slot(schema_ref, Some(answers.clone()))?;
-            let inner_val = T::build(schema_dict, config, build_context)?;
+            let inner_val = build_with_custom_error::<T>(schema_dict, config, build_context)?;
             let name = inner_val.get_name().to_string();
             build_context.complete_slot(slot_id, inner_val)?;
             return Ok(recursive::RecursiveRefValidator::from_id(slot_id, name, answers));
         }
     }
 
-    T::build(schema_dict, config, build_context)
+    build_with_custom_error::<T>(schema_dict, config, build_context)
         .map_err(|err| py_error_type!("Error building \"{}\" validator:\n  {}", val_type, err))
 }
 
+/// Validators which read `custom_error_type` themselves, for all others the key is handled
+/// by wrapping the validator in a `CustomErrorValidator`
+const OWN_CUSTOM_ERROR: [&str; 3] = [
+    custom_error::CustomErrorValidator::EXPECTED_TYPE,
+    union::UnionValidator::EXPECTED_TYPE,
+    union::TaggedUnionValidator::EXPECTED_TYPE,
+];
+
+fn build_with_custom_error<'a, T: BuildValidator>(
+    schema_dict: &'a PyDict,
+    config: Option<&'a PyDict>,
+    build_context: &mut BuildContext<CombinedValidator>,
+) -> PyResult<CombinedValidator> {
+    let validator = T::build(schema_dict, config, build_context)?;
+    match OWN_CUSTOM_ERROR.contains(&T::EXPECTED_TYPE) {
+        true => Ok(validator),
+        false => custom_error::CustomErrorValidator::wrap_if_set(validator, schema_dict),
+    }
+}
+
 // macro to build the match statement for validator selection
 macro_rules! validator_match {
     ($type:ident, $dict:ident, $config:ident, $build_context:ident, $($validator:path,)+) => {
@@ -320,7 +811,11 @@ pub fn build_validator<'a>(
 ) -> PyResult<CombinedValidator> {
     let dict: &PyDict = schema.cast_as()?;
     let type_: &str = dict.get_as_req(intern!(schema.py(), "type"))?;
-    validator_match!(
+    let shared_key = build_context.shared_key(dict, config);
+    if let Some(shared) = shared_key.as_deref().and_then(|key| build_context.find_shared(key)) {
+        return Ok(shared);
+    }
+    let validator = validator_match!(
         type_,
         dict,
         config,
@@ -334,6 +829,7 @@ pub fn build_validator<'a>(
         nullable::NullableValidator,
         // model classes
         new_class::NewClassValidator,
+        model::ModelValidator,
         // strings
         string::StrValidator,
         // integers
@@ -358,6 +854,9 @@ pub fn build_validator<'a>(
         call::CallValidator,
         // recursive (self-referencing) models
         recursive::RecursiveRefValidator,
+        // named schemas and references to them
+        definitions::DefinitionsBuilder,
+        definitions::DefinitionRefBuilder,
         // literals
         literal::LiteralBuilder,
         // any
@@ -386,6 +885,8 @@ pub fn build_validator<'a>(
         chain::ChainValidator,
         // lax or strict
         lax_or_strict::LaxOrStrictValidator,
+        // if/then/else
+        conditional::ConditionalValidator,
         // generator validators
         generator::GeneratorValidator,
         // custom error
@@ -395,7 +896,24 @@ pub fn build_validator<'a>(
         // url types
         url::UrlValidator,
         url::MultiHostUrlValidator,
-    )
+    );
+    build_context.record_build(dict, &validator);
+    let validator = validator?;
+    match shared_key {
+        // other validators look inside these, so they're never shared
+        Some(_)
+            if matches!(
+                validator,
+                CombinedValidator::Any(_) | CombinedValidator::WithDefault(_) | CombinedValidator::Chain(_)
+            ) =>
+        {
+            Ok(validator)
+        }
+        Some(key) => Ok(build_context.add_shared(key, validator, |id, v| {
+            shared_ref::SharedRefValidator::from_validator(dict.py(), id, v)
+        })),
+        None => Ok(validator),
+    }
 }
 
 /// More (mostly immutable) data to pass between validators, should probably be class `Context`,
@@ -407,10 +925,28 @@ pub struct Extra<'a> {
     pub data: Option<&'a PyDict>,
     /// The field being assigned to when validating assignment
     pub field: Option<&'a str>,
+    /// The name of the typed dict field being validated, passed to validator functions as `field_name`
+    pub field_name: Option<&'a str>,
     /// whether we're in strict or lax mode
     pub strict: Option<bool>,
     /// context used in validator functions
     pub context: Option<&'a PyAny>,
+    /// stop validating sequences and dicts once they have more than this many errors
+    pub max_errors: Option<usize>,
+    /// non-fatal warnings, only set when `collect_warnings=True`
+    pub warnings: Option<&'a ValidationWarnings>,
+    /// python strings reused within a validation run, only set when the `cache_strings` config is used
+    pub string_cache: Option<&'a StringCache>,
+    /// an existing instance for the outermost `new-class` validator to validate into, e.g. from `__init__`,
+    /// or the instance being assigned to when validating assignment
+    pub self_instance: Option<&'a PyAny>,
+    /// whether the value being validated is at the tail of incomplete input, see `allow_partial`, containers
+    /// only pass this on to their last item
+    pub partial: bool,
+    /// per-call `include` and `exclude` from `validate_python`, typed dicts, sequences and dicts skip validating
+    /// fields and items they leave out and pass the nested values on to the rest, as with serialization
+    pub include: Option<&'a PyAny>,
+    pub exclude: Option<&'a PyAny>,
 }
 
 impl<'a> Extra<'a> {
@@ -428,14 +964,68 @@ impl<'a> Extra<'a> {
         Self {
             data: self.data,
             field: self.field,
+            field_name: self.field_name,
             strict: Some(true),
             context: self.context,
+            max_errors: self.max_errors,
+            warnings: self.warnings,
+            string_cache: self.string_cache,
+            self_instance: self.self_instance,
+            partial: self.partial,
+            include: self.include,
+            exclude: self.exclude,
+        }
+    }
+
+    /// the `include` and `exclude` for an item of a sequence at `key`, or the value of a dict, `None` if they leave
+    /// the item out, `all_items` should be looked up once per container
+    pub(crate) fn item_filter(
+        &self,
+        key: impl ToPyObject + Copy,
+        all_items: AllItems<'a>,
+    ) -> PyResult<Option<(Option<&'a PyAny>, Option<&'a PyAny>)>> {
+        match (self.include, self.exclude) {
+            (None, None) => Ok(Some((None, None))),
+            (include, exclude) => AnyFilter::new().value_filter(key, include, exclude, all_items),
+        }
+    }
+
+    /// whether a collection with `error_count` errors so far should stop validating further items
+    pub fn too_many_errors(&self, error_count: usize) -> bool {
+        matches!(self.max_errors, Some(max_errors) if error_count > max_errors)
+    }
+
+    pub fn record_warning(&self, warning_type: &'static str, message: impl Into<String>) {
+        if let Some(warnings) = self.warnings {
+            warnings.record(warning_type, message.into());
+        }
+    }
+
+    /// the number of warnings recorded so far, used with `warnings_with_outer_location` and `discard_warnings`
+    pub fn warnings_count(&self) -> usize {
+        self.warnings.map_or(0, ValidationWarnings::len)
+    }
+
+    /// add the location of an item to warnings recorded while validating it, `loc_items` is only called
+    /// if there are such warnings
+    pub fn warnings_with_outer_location(&self, start: usize, loc_items: impl FnOnce() -> Vec<LocItem>) {
+        if let Some(warnings) = self.warnings {
+            if warnings.len() > start {
+                warnings.with_outer_location(start, &loc_items());
+            }
+        }
+    }
+
+    /// drop warnings recorded since `start`, e.g. by a union choice which failed
+    pub fn discard_warnings(&self, start: usize) {
+        if let Some(warnings) = self.warnings {
+            warnings.truncate(start);
         }
     }
 }
 
 #[derive(Debug, Clone)]
-#[enum_dispatch]
+#[enum_dispatch(PyGcTraverse)]
 pub enum CombinedValidator {
     // typed dict e.g. heterogeneous dicts or simply a model
     TypedDict(typed_dict::TypedDictValidator),
@@ -446,6 +1036,8 @@ pub enum CombinedValidator {
     Nullable(nullable::NullableValidator),
     // create new model classes
     NewClass(new_class::NewClassValidator),
+    // model classes created without calling `__init__`, with `post_init` and `revalidate_instances`
+    Model(model::ModelValidator),
     // strings
     Str(string::StrValidator),
     StrConstrained(string::StrConstrainedValidator),
@@ -477,6 +1069,8 @@ pub enum CombinedValidator {
     FunctionCall(call::CallValidator),
     // recursive (self-referencing) models
     RecursiveRef(recursive::RecursiveRefValidator),
+    // schemas which appear more than once
+    SharedRef(shared_ref::SharedRefValidator),
     // literals
     LiteralSingleString(literal::LiteralSingleStringValidator),
     LiteralSingleInt(literal::LiteralSingleIntValidator),
@@ -510,6 +1104,8 @@ pub enum CombinedValidator {
     Chain(chain::ChainValidator),
     // lax or strict
     LaxOrStrict(lax_or_strict::LaxOrStrictValidator),
+    // if/then/else
+    Conditional(conditional::ConditionalValidator),
     // generator validators
     Generator(generator::GeneratorValidator),
     // custom error
@@ -519,6 +1115,8 @@ pub enum CombinedValidator {
     // url types
     Url(url::UrlValidator),
     MultiHostUrl(url::MultiHostUrlValidator),
+    // validators built on first use
+    Lazy(lazy::LazyValidator),
 }
 
 /// This trait must be implemented by all validators, it allows various validators to be accessed consistently,
@@ -539,11 +1137,28 @@ pub trait Validator: Send + Sync + Clone + Debug {
     /// this is used in the error location in unions, and in the top level message in `ValidationError`
     fn get_name(&self) -> &str;
 
+    /// Validate an item of a container, e.g. a list item or a typed dict field, `None` for a validator which never
+    /// accepts it gets a `none_not_allowed` error without calling the validator, rather than its type error
+    fn validate_item<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        if input.is_none() && self.ask(py, &Question::RejectsNone) {
+            Err(ValError::new(ErrorType::NoneNotAllowed, input))
+        } else {
+            self.validate(py, input, extra, slots, recursion_guard)
+        }
+    }
+
     /// allows validators to ask specific questions of sub-validators in a general way, could be extended
     /// to do more, validators which don't know the question and have sub-validators
-    /// should return the result them in an `...iter().all(|v| v.ask(question))` way, ONLY
+    /// should return the result them in an `...iter().all(|v| v.ask(py, question))` way, ONLY
     /// if they return the value of the sub-validator, e.g. functions, unions
-    fn ask(&self, _question: &Question) -> bool {
+    fn ask(&self, _py: Python, _question: &Question) -> bool {
         false
     }
 
@@ -0,0 +1,261 @@
+use pyo3::exceptions::PyTypeError;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PySet, PyString, PyType};
+
+use crate::build_tools::{py_err, py_error_type, schema_or_config_same, SchemaDict};
+use crate::errors::{ErrorType, ValError, ValResult};
+use crate::input::Input;
+use crate::py_gc::impl_py_gc_traverse;
+use crate::questions::Question;
+use crate::recursion_guard::RecursionGuard;
+
+use super::function::convert_err;
+use super::new_class::{build_config, create_class, set_model_attrs};
+use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+
+/// which instances of the model class are revalidated, rather than returned as is
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Revalidate {
+    Always,
+    Never,
+    SubclassInstances,
+    // instances of the class itself are returned as is, instances of subclasses are an error
+    ForbidSubclassInstances,
+}
+
+impl Revalidate {
+    fn new(s: Option<&str>) -> PyResult<Self> {
+        match s {
+            None | Some("never") => Ok(Self::Never),
+            Some("always") => Ok(Self::Always),
+            Some("subclass-instances") => Ok(Self::SubclassInstances),
+            Some("forbid-subclass-instances") => Ok(Self::ForbidSubclassInstances),
+            Some(s) => py_err!(
+                "Invalid revalidate_instances value: {}, expected 'never', 'always', 'subclass-instances' or \
+                 'forbid-subclass-instances'",
+                s
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ModelValidator {
+    strict: bool,
+    revalidate: Revalidate,
+    validator: Box<CombinedValidator>,
+    class: Py<PyType>,
+    post_init: Option<Py<PyString>>,
+    name: String,
+    expect_fields_set: bool,
+}
+
+impl_py_gc_traverse!(ModelValidator {
+    validator,
+    class,
+    post_init
+});
+
+impl BuildValidator for ModelValidator {
+    const EXPECTED_TYPE: &'static str = "model";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        build_context: &mut BuildContext<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        // as with `new-class`, models use their own config in preference to the parent config
+        let config = build_config(py, schema, config)?;
+
+        let class: &PyType = schema.get_as_req(intern!(py, "cls"))?;
+        let sub_schema: &PyAny = schema.get_as_req(intern!(py, "schema"))?;
+        let validator = build_validator(sub_schema, config, build_context)?;
+
+        let expect_fields_set = validator.ask(py, &Question::ReturnFieldsSet);
+        let revalidate = schema_or_config_same(schema, config, intern!(py, "revalidate_instances"))?;
+
+        Ok(Self {
+            strict: schema.get_as(intern!(py, "strict"))?.unwrap_or(false),
+            revalidate: Revalidate::new(revalidate)?,
+            validator: Box::new(validator),
+            class: class.into(),
+            post_init: schema
+                .get_as::<&str>(intern!(py, "post_init"))?
+                .map(|s| PyString::intern(py, s).into_py(py)),
+            name: class.getattr(intern!(py, "__name__"))?.extract()?,
+            expect_fields_set,
+        }
+        .into())
+    }
+}
+
+impl Validator for ModelValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        if let Some(field) = extra.field {
+            // we're validating assignment to a field of an existing instance
+            return self.validate_assignment(py, field, input, extra, slots, recursion_guard);
+        }
+        if let Some(self_instance) = extra.self_instance {
+            // we're being called from within the model's `__init__`, with the instance to populate
+            return self.validate_init(py, self_instance, input, extra, slots, recursion_guard);
+        }
+        let class = self.class.as_ref(py);
+        if input.input_is_instance(class, 0)? {
+            let revalidate = match self.revalidate {
+                Revalidate::Always => true,
+                Revalidate::Never => false,
+                Revalidate::SubclassInstances => !input.is_exact_instance(class)?,
+                Revalidate::ForbidSubclassInstances => match input.is_exact_instance(class)? {
+                    true => false,
+                    false => return Err(self.class_type_error(input)),
+                },
+            };
+            if !revalidate {
+                return Ok(input.to_object(py));
+            }
+            // the instance's `__dict__` is validated, `__fields_set__` is kept if the instance has it
+            let fields_set = input.get_attr(intern!(py, "__fields_set__"));
+            let output = match input.get_attr(intern!(py, "__dict__")) {
+                Some(model_dict) => self.validator.validate(py, model_dict, extra, slots, recursion_guard)?,
+                None => self.validator.validate(py, input, extra, slots, recursion_guard)?,
+            };
+            let instance = if self.expect_fields_set {
+                let (model_dict, validation_fields_set): (&PyAny, &PyAny) = output.extract(py)?;
+                create_class(class, model_dict, Some(fields_set.unwrap_or(validation_fields_set)))?
+            } else {
+                create_class(class, output.as_ref(py), fields_set)?
+            };
+            self.call_post_init(py, instance.as_ref(py), input, extra)?;
+            Ok(instance)
+        } else if extra.strict.unwrap_or(self.strict) {
+            Err(self.class_type_error(input))
+        } else {
+            let output = self.validator.validate(py, input, extra, slots, recursion_guard)?;
+            let instance = if self.expect_fields_set {
+                let (model_dict, fields_set): (&PyAny, &PyAny) = output.extract(py)?;
+                create_class(class, model_dict, Some(fields_set))?
+            } else {
+                create_class(class, output.as_ref(py), None)?
+            };
+            self.call_post_init(py, instance.as_ref(py), input, extra)?;
+            Ok(instance)
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl ModelValidator {
+    /// validate `input` into the existing `self_instance` rather than a new instance, then call `post_init`
+    fn validate_init<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        self_instance: &'s PyAny,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        // only this validator uses `self_instance`, models within it are validated as normal
+        let inner_extra = Extra {
+            self_instance: None,
+            ..*extra
+        };
+        let output = self
+            .validator
+            .validate(py, input, &inner_extra, slots, recursion_guard)?;
+        if self.expect_fields_set {
+            let (model_dict, fields_set): (&PyAny, &PyAny) = output.extract(py)?;
+            set_model_attrs(py, self_instance, model_dict, Some(fields_set))?;
+        } else {
+            set_model_attrs(py, self_instance, output.as_ref(py), None)?;
+        }
+        self.call_post_init(py, self_instance, input, extra)?;
+        Ok(self_instance.into_py(py))
+    }
+
+    /// as with `new-class`, the model's data is copied so it's unchanged if validation fails,
+    /// `post_init` isn't called
+    fn validate_assignment<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        field: &str,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        let self_instance = match extra.self_instance {
+            Some(self_instance) if self_instance.is_instance(self.class.as_ref(py))? => self_instance,
+            _ => {
+                let msg = format!("validate_assignment expects an instance of {}", self.name);
+                return Err(ValError::InternalErr(py_error_type!(PyTypeError; msg)));
+            }
+        };
+        let model_dict: &PyDict = self_instance.getattr(intern!(py, "__dict__"))?.cast_as()?;
+        let inner_extra = Extra {
+            data: Some(model_dict.copy()?),
+            field: Some(field),
+            self_instance: None,
+            partial: false,
+            include: None,
+            exclude: None,
+            ..*extra
+        };
+        let output = self
+            .validator
+            .validate(py, input, &inner_extra, slots, recursion_guard)?;
+        if self.expect_fields_set {
+            let (model_dict, validation_fields_set): (&PyAny, &PySet) = output.extract(py)?;
+            // a new set rather than mutating the instance's `__fields_set__` in case it's shared
+            let fields_set = PySet::empty(py)?;
+            if let Ok(existing_fields_set) = self_instance.getattr(intern!(py, "__fields_set__")) {
+                for field_name in existing_fields_set.iter()? {
+                    fields_set.add(field_name?)?;
+                }
+            }
+            for field_name in validation_fields_set {
+                fields_set.add(field_name)?;
+            }
+            set_model_attrs(py, self_instance, model_dict, Some(fields_set))?;
+        } else {
+            set_model_attrs(py, self_instance, output.as_ref(py), None)?;
+        }
+        Ok(self_instance.into_py(py))
+    }
+
+    fn class_type_error<'data>(&self, input: &'data impl Input<'data>) -> ValError<'data> {
+        ValError::new(
+            ErrorType::ModelClassType {
+                class_name: self.get_name().to_string(),
+            },
+            input,
+        )
+    }
+
+    fn call_post_init<'data>(
+        &self,
+        py: Python<'data>,
+        instance: &PyAny,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+    ) -> ValResult<'data, ()> {
+        if let Some(ref post_init) = self.post_init {
+            instance
+                .call_method1(post_init.as_ref(py), (extra.context,))
+                .map_err(|e| convert_err(py, e, input))?;
+        }
+        Ok(())
+    }
+}
@@ -4,18 +4,22 @@ use std::ptr::null_mut;
 use pyo3::conversion::AsPyPointer;
 use pyo3::exceptions::PyTypeError;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyString, PyTuple, PyType};
+use pyo3::types::{PyDict, PySet, PyString, PyTuple, PyType};
 use pyo3::{ffi, intern};
 
-use crate::build_tools::{py_err, SchemaDict};
+use crate::build_tools::{py_err, py_error_type, SchemaDict};
 use crate::errors::{ErrorType, ValError, ValResult};
 use crate::input::{py_error_on_minusone, Input};
+use crate::py_gc::impl_py_gc_traverse;
 use crate::questions::Question;
 use crate::recursion_guard::RecursionGuard;
 
 use super::function::convert_err;
 use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
 
+/// with `root_model`, the single field the validated value is stored under
+const ROOT_FIELD: &str = "__root__";
+
 #[derive(Debug, Clone)]
 pub struct NewClassValidator {
     strict: bool,
@@ -25,8 +29,16 @@ pub struct NewClassValidator {
     call_after_init: Option<Py<PyString>>,
     name: String,
     expect_fields_set: bool,
+    // the inner schema validates a bare value rather than a dict of fields, it's stored as `ROOT_FIELD`
+    root_model: bool,
 }
 
+impl_py_gc_traverse!(NewClassValidator {
+    validator,
+    class,
+    call_after_init
+});
+
 impl BuildValidator for NewClassValidator {
     const EXPECTED_TYPE: &'static str = "new-class";
 
@@ -43,7 +55,9 @@ impl BuildValidator for NewClassValidator {
         let sub_schema: &PyAny = schema.get_as_req(intern!(py, "schema"))?;
         let validator = build_validator(sub_schema, config, build_context)?;
 
-        let expect_fields_set = validator.ask(&Question::ReturnFieldsSet);
+        let root_model = schema.get_as(intern!(py, "root_model"))?.unwrap_or(false);
+        // root models always set `__fields_set__`, see `validate_inner`
+        let expect_fields_set = root_model || validator.ask(py, &Question::ReturnFieldsSet);
 
         Ok(Self {
             // we don't use is_strict here since we don't want validation to be strict in this case if
@@ -59,6 +73,7 @@ impl BuildValidator for NewClassValidator {
             // which is not what we want here
             name: class.getattr(intern!(py, "__name__"))?.extract()?,
             expect_fields_set,
+            root_model,
         }
         .into())
     }
@@ -73,11 +88,26 @@ impl Validator for NewClassValidator {
         slots: &'data [CombinedValidator],
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
+        if let Some(field) = extra.field {
+            // we're validating assignment to a field of an existing instance
+            return self.validate_assignment(py, field, input, extra, slots, recursion_guard);
+        }
+        if let Some(self_instance) = extra.self_instance {
+            // we're being called from within the model's `__init__`, with the instance to populate
+            return self.validate_init(py, self_instance, input, extra, slots, recursion_guard);
+        }
         let class = self.class.as_ref(py);
         if input.is_exact_instance(class)? {
             if self.revalidate {
                 let fields_set = input.get_attr(intern!(py, "__fields_set__"));
-                let output = self.validator.validate(py, input, extra, slots, recursion_guard)?;
+                let root = match self.root_model {
+                    true => input.get_attr(intern!(py, ROOT_FIELD)),
+                    false => None,
+                };
+                let output = match root {
+                    Some(root) => self.validate_inner(py, root, extra, slots, recursion_guard)?,
+                    None => self.validate_inner(py, input, extra, slots, recursion_guard)?,
+                };
                 if self.expect_fields_set {
                     let (model_dict, validation_fields_set): (&PyAny, &PyAny) = output.extract(py)?;
                     let fields_set = fields_set.unwrap_or(validation_fields_set);
@@ -96,21 +126,14 @@ impl Validator for NewClassValidator {
                 input,
             ))
         } else {
-            let output = self.validator.validate(py, input, extra, slots, recursion_guard)?;
+            let output = self.validate_inner(py, input, extra, slots, recursion_guard)?;
             let instance = if self.expect_fields_set {
                 let (model_dict, fields_set): (&PyAny, &PyAny) = output.extract(py)?;
                 self.create_class(py, model_dict, Some(fields_set))?
             } else {
                 self.create_class(py, output.as_ref(py), None)?
             };
-
-            if let Some(ref call_after_init) = self.call_after_init {
-                let kwargs = PyDict::new(py);
-                kwargs.set_item("context", extra.context)?;
-                instance
-                    .call_method(py, call_after_init.as_ref(py), (), Some(kwargs))
-                    .map_err(|e| convert_err(py, e, input))?;
-            }
+            self.call_after_init(py, instance.as_ref(py), input, extra)?;
             Ok(instance)
         }
     }
@@ -121,33 +144,187 @@ impl Validator for NewClassValidator {
 }
 
 impl NewClassValidator {
-    fn create_class(&self, py: Python, model_dict: &PyAny, fields_set: Option<&PyAny>) -> PyResult<PyObject> {
-        // based on the following but with the second argument of new_func set to an empty tuple as required
-        // https://github.com/PyO3/pyo3/blob/d2caa056e9aacc46374139ef491d112cb8af1a25/src/pyclass_init.rs#L35-L77
-        let args = PyTuple::empty(py);
-        let raw_type = self.class.as_ref(py).as_type_ptr();
-        let instance = unsafe {
-            // Safety: raw_type is known to be a non-null type object pointer
-            match (*raw_type).tp_new {
-                // Safety: the result of new_func is guaranteed to be either an owned pointer or null on error returns.
-                Some(new_func) => PyObject::from_owned_ptr_or_err(
-                    py,
-                    // Safety: the non-null pointers are known to be valid, and it's allowed to call tp_new with a
-                    // null kwargs dict.
-                    new_func(raw_type, args.as_ptr(), null_mut()),
-                )?,
-                None => return py_err!(PyTypeError; "base type without tp_new"),
+    /// validate `input` into the existing `self_instance` rather than a new instance, strictness doesn't apply
+    /// since `input` is the data for the model rather than a possible instance of it
+    fn validate_init<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        self_instance: &'s PyAny,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        // only this validator uses `self_instance`, models within it are validated as normal
+        let inner_extra = Extra {
+            data: extra.data,
+            field: extra.field,
+            field_name: extra.field_name,
+            strict: extra.strict,
+            context: extra.context,
+            max_errors: extra.max_errors,
+            warnings: extra.warnings,
+            string_cache: extra.string_cache,
+            self_instance: None,
+            partial: extra.partial,
+            include: extra.include,
+            exclude: extra.exclude,
+        };
+        let output = self.validate_inner(py, input, &inner_extra, slots, recursion_guard)?;
+        if self.expect_fields_set {
+            let (model_dict, fields_set): (&PyAny, &PyAny) = output.extract(py)?;
+            set_model_attrs(py, self_instance, model_dict, Some(fields_set))?;
+        } else {
+            set_model_attrs(py, self_instance, output.as_ref(py), None)?;
+        }
+        self.call_after_init(py, self_instance, input, extra)?;
+        Ok(self_instance.into_py(py))
+    }
+
+    /// the model's data is copied so it's unchanged if validation fails, fields set by validation are added to
+    /// `__fields_set__`, `call_after_init` isn't called
+    fn validate_assignment<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        field: &str,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        let self_instance = match extra.self_instance {
+            Some(self_instance) if self_instance.is_instance(self.class.as_ref(py))? => self_instance,
+            _ => {
+                let msg = format!("validate_assignment expects an instance of {}", self.name);
+                return Err(ValError::InternalErr(py_error_type!(PyTypeError; msg)));
             }
         };
+        if self.root_model && field != ROOT_FIELD {
+            let msg = format!(
+                "validate_assignment on a root model expects the field \"{}\"",
+                ROOT_FIELD
+            );
+            return Err(ValError::InternalErr(py_error_type!(PyTypeError; msg)));
+        }
+        let model_dict: &PyDict = self_instance.getattr(intern!(py, "__dict__"))?.cast_as()?;
+        let inner_extra = Extra {
+            data: Some(model_dict.copy()?),
+            // a root model's inner validator validates the new value as a whole
+            field: match self.root_model {
+                true => None,
+                false => Some(field),
+            },
+            field_name: extra.field_name,
+            strict: extra.strict,
+            context: extra.context,
+            max_errors: extra.max_errors,
+            warnings: extra.warnings,
+            string_cache: extra.string_cache,
+            self_instance: None,
+            partial: false,
+            include: None,
+            exclude: None,
+        };
+        let output = self.validate_inner(py, input, &inner_extra, slots, recursion_guard)?;
+        if self.expect_fields_set {
+            let (model_dict, validation_fields_set): (&PyAny, &PySet) = output.extract(py)?;
+            // a new set rather than mutating the instance's `__fields_set__` in case it's shared
+            let fields_set = PySet::empty(py)?;
+            if let Ok(existing_fields_set) = self_instance.getattr(intern!(py, "__fields_set__")) {
+                for field_name in existing_fields_set.iter()? {
+                    fields_set.add(field_name?)?;
+                }
+            }
+            for field_name in validation_fields_set {
+                fields_set.add(field_name)?;
+            }
+            set_model_attrs(py, self_instance, model_dict, Some(fields_set))?;
+        } else {
+            set_model_attrs(py, self_instance, output.as_ref(py), None)?;
+        }
+        Ok(self_instance.into_py(py))
+    }
+
+    /// validate with the inner validator, for root models the value is wrapped as a dict with the single field
+    /// `ROOT_FIELD` and a fields set, as returned by a typed dict with `return_fields_set`
+    fn validate_inner<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        let output = self.validator.validate(py, input, extra, slots, recursion_guard)?;
+        if self.root_model {
+            let root_field = PyString::intern(py, ROOT_FIELD);
+            let model_dict = PyDict::new(py);
+            model_dict.set_item(root_field, output)?;
+            let fields_set = PySet::new(py, &[root_field])?;
+            Ok((model_dict, fields_set).into_py(py))
+        } else {
+            Ok(output)
+        }
+    }
+
+    fn call_after_init<'data>(
+        &self,
+        py: Python<'data>,
+        instance: &PyAny,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+    ) -> ValResult<'data, ()> {
+        if let Some(ref call_after_init) = self.call_after_init {
+            let kwargs = PyDict::new(py);
+            kwargs.set_item("context", extra.context)?;
+            instance
+                .call_method(call_after_init.as_ref(py), (), Some(kwargs))
+                .map_err(|e| convert_err(py, e, input))?;
+        }
+        Ok(())
+    }
 
-        let instance_ref = instance.as_ref(py);
-        force_setattr(py, instance_ref, intern!(py, "__dict__"), model_dict)?;
-        if let Some(fields_set) = fields_set {
-            force_setattr(py, instance_ref, intern!(py, "__fields_set__"), fields_set)?;
+    fn create_class(&self, py: Python, model_dict: &PyAny, fields_set: Option<&PyAny>) -> PyResult<PyObject> {
+        create_class(self.class.as_ref(py), model_dict, fields_set)
+    }
+}
+
+/// create an instance of `class` without calling `__init__`, then set `__dict__` and `__fields_set__`
+pub(super) fn create_class(class: &PyType, model_dict: &PyAny, fields_set: Option<&PyAny>) -> PyResult<PyObject> {
+    let py = class.py();
+    // based on the following but with the second argument of new_func set to an empty tuple as required
+    // https://github.com/PyO3/pyo3/blob/d2caa056e9aacc46374139ef491d112cb8af1a25/src/pyclass_init.rs#L35-L77
+    let args = PyTuple::empty(py);
+    let raw_type = class.as_type_ptr();
+    let instance = unsafe {
+        // Safety: raw_type is known to be a non-null type object pointer
+        match (*raw_type).tp_new {
+            // Safety: the result of new_func is guaranteed to be either an owned pointer or null on error returns.
+            Some(new_func) => PyObject::from_owned_ptr_or_err(
+                py,
+                // Safety: the non-null pointers are known to be valid, and it's allowed to call tp_new with a
+                // null kwargs dict.
+                new_func(raw_type, args.as_ptr(), null_mut()),
+            )?,
+            None => return py_err!(PyTypeError; "base type without tp_new"),
         }
+    };
+
+    set_model_attrs(py, instance.as_ref(py), model_dict, fields_set)?;
+    Ok(instance)
+}
 
-        Ok(instance)
+pub(super) fn set_model_attrs(
+    py: Python,
+    instance: &PyAny,
+    model_dict: &PyAny,
+    fields_set: Option<&PyAny>,
+) -> PyResult<()> {
+    force_setattr(py, instance, intern!(py, "__dict__"), model_dict)?;
+    if let Some(fields_set) = fields_set {
+        force_setattr(py, instance, intern!(py, "__fields_set__"), fields_set)?;
     }
+    Ok(())
 }
 
 pub fn force_setattr<N, V>(py: Python<'_>, obj: &PyAny, attr_name: N, value: V) -> PyResult<()>
@@ -165,7 +342,7 @@ where
     }
 }
 
-fn build_config<'a>(
+pub(super) fn build_config<'a>(
     py: Python<'a>,
     schema: &'a PyDict,
     parent_config: Option<&'a PyDict>,
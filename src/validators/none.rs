@@ -3,6 +3,7 @@ use pyo3::types::PyDict;
 
 use crate::errors::{ErrorType, ValError, ValResult};
 use crate::input::Input;
+use crate::py_gc::impl_py_gc_traverse;
 use crate::recursion_guard::RecursionGuard;
 
 use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
@@ -10,6 +11,8 @@ use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
 #[derive(Debug, Clone)]
 pub struct NoneValidator;
 
+impl_py_gc_traverse!(NoneValidator {});
+
 impl BuildValidator for NoneValidator {
     const EXPECTED_TYPE: &'static str = "none";
 
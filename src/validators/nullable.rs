@@ -2,9 +2,10 @@ use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
-use crate::build_tools::SchemaDict;
+use crate::build_tools::{inner_config, SchemaDict};
 use crate::errors::ValResult;
 use crate::input::Input;
+use crate::py_gc::impl_py_gc_traverse;
 use crate::questions::Question;
 use crate::recursion_guard::RecursionGuard;
 
@@ -16,6 +17,8 @@ pub struct NullableValidator {
     name: String,
 }
 
+impl_py_gc_traverse!(NullableValidator { validator });
+
 impl BuildValidator for NullableValidator {
     const EXPECTED_TYPE: &'static str = "nullable";
 
@@ -24,8 +27,13 @@ impl BuildValidator for NullableValidator {
         config: Option<&PyDict>,
         build_context: &mut BuildContext<CombinedValidator>,
     ) -> PyResult<CombinedValidator> {
-        let schema: &PyAny = schema.get_as_req(intern!(schema.py(), "schema"))?;
-        let validator = Box::new(build_validator(schema, config, build_context)?);
+        let sub_schema: &PyAny = schema.get_as_req(intern!(schema.py(), "schema"))?;
+        let validator = build_validator(sub_schema, inner_config(schema, config)?, build_context)?;
+        // `any` already accepts `None`
+        if let CombinedValidator::Any(_) = validator {
+            return Ok(validator);
+        }
+        let validator = Box::new(validator);
         let name = format!("{}[{}]", Self::EXPECTED_TYPE, validator.get_name());
         Ok(Self { validator, name }.into())
     }
@@ -50,8 +58,11 @@ impl Validator for NullableValidator {
         &self.name
     }
 
-    fn ask(&self, question: &Question) -> bool {
-        self.validator.ask(question)
+    fn ask(&self, py: Python, question: &Question) -> bool {
+        match question {
+            Question::RejectsNone => false,
+            _ => self.validator.ask(py, question),
+        }
     }
 
     fn complete(&mut self, build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
@@ -5,6 +5,7 @@ use pyo3::types::PyDict;
 use crate::build_tools::SchemaDict;
 use crate::errors::{ErrorType, ValError, ValResult};
 use crate::input::Input;
+use crate::py_gc::impl_py_gc_traverse;
 use crate::questions::{Answers, Question};
 use crate::recursion_guard::RecursionGuard;
 
@@ -18,6 +19,8 @@ pub struct RecursiveRefValidator {
     answers: Answers,
 }
 
+impl_py_gc_traverse!(RecursiveRefValidator {});
+
 impl RecursiveRefValidator {
     pub fn from_id(validator_id: usize, inner_name: String, answers: Answers) -> CombinedValidator {
         Self {
@@ -57,29 +60,30 @@ impl Validator for RecursiveRefValidator {
         slots: &'data [CombinedValidator],
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        if let Some(id) = input.identity() {
-            if recursion_guard.contains_or_insert(id) {
+        let id = input.identity();
+        if let Some(id) = id {
+            if recursion_guard.contains_or_insert(id, self.validator_id) {
                 // we don't remove id here, we leave that to the validator which originally added id to `recursion_guard`
-                Err(ValError::new(ErrorType::RecursionLoop, input))
-            } else {
-                if recursion_guard.incr_depth() > BACKUP_GUARD_LIMIT {
-                    return Err(ValError::new(ErrorType::RecursionLoop, input));
-                }
-                let output = validate(self.validator_id, py, input, extra, slots, recursion_guard);
-                recursion_guard.remove(&id);
-                recursion_guard.decr_depth();
-                output
+                return Err(ValError::new(ErrorType::RecursionLoop, input));
             }
-        } else {
-            validate(self.validator_id, py, input, extra, slots, recursion_guard)
         }
+        // the depth is checked for all input, not just input with an identity, so deeply nested JSON is also limited
+        let output = match recursion_guard.incr_depth() {
+            true => Err(ValError::new(ErrorType::RecursionLoop, input)),
+            false => validate(self.validator_id, py, input, extra, slots, recursion_guard),
+        };
+        if let Some(id) = id {
+            recursion_guard.remove(id, self.validator_id);
+        }
+        recursion_guard.decr_depth();
+        output
     }
 
     fn get_name(&self) -> &str {
         &self.inner_name
     }
 
-    fn ask(&self, question: &Question) -> bool {
+    fn ask(&self, _py: Python, question: &Question) -> bool {
         self.answers.ask(question)
     }
 
@@ -91,15 +95,6 @@ impl Validator for RecursiveRefValidator {
     }
 }
 
-// see #143 this is a backup in case the identity check recursion guard fails
-// if a single validator "depth" (how many times it's called inside itself) exceeds the limit,
-// we raise a recursion error.
-const BACKUP_GUARD_LIMIT: u16 = if cfg!(PyPy) || cfg!(target_family = "wasm") {
-    123
-} else {
-    255
-};
-
 fn validate<'s, 'data>(
     validator_id: usize,
     py: Python<'data>,
@@ -4,9 +4,11 @@ use pyo3::types::{PyDict, PySet};
 use crate::build_tools::SchemaDict;
 use crate::errors::ValResult;
 use crate::input::{GenericCollection, Input};
+use crate::py_gc::impl_py_gc_traverse;
+use crate::questions::Question;
 use crate::recursion_guard::RecursionGuard;
 
-use super::list::{get_items_schema, length_check};
+use super::list::{get_items_schema, length_check, CollectionCoercion, CollectionType};
 use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
 
 #[derive(Debug, Clone)]
@@ -16,8 +18,11 @@ pub struct SetValidator {
     min_length: Option<usize>,
     max_length: Option<usize>,
     generator_max_length: Option<usize>,
+    coercion: CollectionCoercion,
     name: String,
 }
+
+impl_py_gc_traverse!(SetValidator { item_validator });
 pub static MAX_LENGTH_GEN_MULTIPLE: usize = 10;
 
 macro_rules! set_build {
@@ -42,6 +47,7 @@ macro_rules! set_build {
                 min_length: schema.get_as(pyo3::intern!(py, "min_length"))?,
                 max_length,
                 generator_max_length,
+                coercion: super::list::CollectionCoercion::from_config(py, config)?,
                 name,
             }
             .into())
@@ -64,12 +70,12 @@ impl Validator for SetValidator {
         slots: &'data [CombinedValidator],
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let seq = input.validate_set(extra.strict.unwrap_or(self.strict))?;
+        let strict = extra.strict.unwrap_or(self.strict);
+        let seq = self.coercion.validate(py, input, CollectionType::Set, strict, false)?;
 
         let set = match self.item_validator {
-            Some(ref v) => PySet::new(
-                py,
-                &seq.validate_to_vec(
+            Some(ref v) => {
+                let items = seq.validate_to_vec(
                     py,
                     input,
                     self.max_length,
@@ -79,11 +85,19 @@ impl Validator for SetValidator {
                     extra,
                     slots,
                     recursion_guard,
-                )?,
-            )?,
+                )?;
+                let set = PySet::new(py, &items)?;
+                self.coercion.check_duplicates(input, &seq, items.len(), set.len())?;
+                set
+            }
             None => match seq {
                 GenericCollection::Set(set) => set,
-                _ => PySet::new(py, &seq.to_vec(py, input, "Set", self.generator_max_length)?)?,
+                _ => {
+                    let items = seq.to_vec(py, input, "Set", self.generator_max_length)?;
+                    let set = PySet::new(py, &items)?;
+                    self.coercion.check_duplicates(input, &seq, items.len(), set.len())?;
+                    set
+                }
             },
         };
         length_check!(input, "Set", self.min_length, self.max_length, set);
@@ -94,6 +108,10 @@ impl Validator for SetValidator {
         &self.name
     }
 
+    fn ask(&self, _py: Python, question: &Question) -> bool {
+        matches!(question, Question::RejectsNone)
+    }
+
     fn complete(&mut self, build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
         match self.item_validator {
             Some(ref mut v) => v.complete(build_context),
@@ -0,0 +1,58 @@
+use pyo3::prelude::*;
+
+use crate::errors::ValResult;
+use crate::input::Input;
+use crate::py_gc::impl_py_gc_traverse;
+use crate::questions::{Answers, Question};
+use crate::recursion_guard::RecursionGuard;
+
+use super::{BuildContext, CombinedValidator, Extra, Validator};
+
+/// Validator for a schema which appears more than once, the validator is built once and held in a slot,
+/// unlike `RecursiveRefValidator` there's no recursion guard since schemas can't contain themselves.
+#[derive(Debug, Clone)]
+pub struct SharedRefValidator {
+    validator_id: usize,
+    name: String,
+    answers: Answers,
+}
+
+impl_py_gc_traverse!(SharedRefValidator {});
+
+impl SharedRefValidator {
+    pub fn from_validator(py: Python, validator_id: usize, validator: &CombinedValidator) -> CombinedValidator {
+        Self {
+            validator_id,
+            name: validator.get_name().to_string(),
+            answers: Answers::from_validator(py, validator),
+        }
+        .into()
+    }
+}
+
+impl Validator for SharedRefValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        let validator = unsafe { slots.get_unchecked(self.validator_id) };
+        validator.validate(py, input, extra, slots, recursion_guard)
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    fn ask(&self, _py: Python, question: &Question) -> bool {
+        self.answers.ask(question)
+    }
+
+    /// the shared validator is completed along with the other slots
+    fn complete(&mut self, _build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
+        Ok(())
+    }
+}
@@ -2,19 +2,25 @@ use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyString};
 use regex::Regex;
+use unicode_normalization::{is_nfc_quick, is_nfkc_quick, IsNormalized, UnicodeNormalization};
+use unicode_segmentation::UnicodeSegmentation;
 
-use crate::build_tools::{is_strict, py_error_type, schema_or_config, SchemaDict};
+use crate::build_tools::{is_strict, py_err, py_error_type, schema_or_config, SchemaDict};
 use crate::errors::{ErrorType, ValError, ValResult};
-use crate::input::Input;
+use crate::input::{py_string_str, EitherString, Input};
+use crate::py_gc::impl_py_gc_traverse;
+use crate::questions::Question;
 use crate::recursion_guard::RecursionGuard;
 
 use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
 
 #[derive(Debug, Clone)]
 pub struct StrValidator {
-    strict: bool,
+    pub(crate) strict: bool,
 }
 
+impl_py_gc_traverse!(StrValidator {});
+
 impl BuildValidator for StrValidator {
     const EXPECTED_TYPE: &'static str = "str";
 
@@ -45,12 +51,153 @@ impl Validator for StrValidator {
         _slots: &'data [CombinedValidator],
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        Ok(input.validate_str(extra.strict.unwrap_or(self.strict))?.into_py(py))
+        let either_str = input.validate_str(extra.strict.unwrap_or(self.strict))?;
+        Ok(either_str_to_py(py, either_str, extra))
     }
 
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
+
+    fn ask(&self, _py: Python, question: &Question) -> bool {
+        matches!(question, Question::RejectsNone)
+    }
+}
+
+/// a python string for `str`, reused from the string cache if string values are being cached
+pub(super) fn str_to_py(py: Python, str: &str, extra: &Extra) -> PyObject {
+    match extra.string_cache {
+        Some(cache) if cache.cache_values() => cache.get_or_insert(py, str).into_py(py),
+        _ => PyString::new(py, str).into_py(py),
+    }
+}
+
+/// as with `str_to_py`, but input which is already a python string is returned unchanged
+fn either_str_to_py(py: Python, either_str: EitherString, extra: &Extra) -> PyObject {
+    match either_str {
+        EitherString::Cow(cow) => str_to_py(py, &cow, extra),
+        EitherString::Py(py_string) => py_string.into_py(py),
+    }
+}
+
+/// How `min_length` and `max_length` are measured
+#[derive(Debug, Clone, Copy, Default)]
+enum LengthUnit {
+    /// unicode code points, the same as python's `len()`
+    #[default]
+    Chars,
+    /// bytes of the UTF-8 encoding, e.g. for database limits
+    Bytes,
+    /// extended grapheme clusters, what users see as single characters, e.g. emoji with modifiers
+    Graphemes,
+}
+
+impl LengthUnit {
+    fn from_schema(schema: &PyDict, config: Option<&PyDict>) -> PyResult<Self> {
+        let py = schema.py();
+        match schema_or_config(
+            schema,
+            config,
+            intern!(py, "length_unit"),
+            intern!(py, "str_length_unit"),
+        )? {
+            None | Some("chars") => Ok(Self::Chars),
+            Some("bytes") => Ok(Self::Bytes),
+            Some("graphemes") => Ok(Self::Graphemes),
+            Some(s) => py_err!(r#"Invalid length_unit: "{}""#, s),
+        }
+    }
+
+    fn length(self, str: &str) -> usize {
+        match self {
+            // ASCII strings are common and have one byte per char
+            Self::Chars if str.is_ascii() => str.len(),
+            Self::Chars => str.chars().count(),
+            Self::Bytes => str.len(),
+            Self::Graphemes => str.graphemes(true).count(),
+        }
+    }
+}
+
+/// Unicode normalization applied to strings before they're checked, so equivalent strings compare equal
+#[derive(Debug, Clone, Copy)]
+enum UnicodeNormalize {
+    Nfc,
+    Nfkc,
+}
+
+impl UnicodeNormalize {
+    fn from_schema(schema: &PyDict, config: Option<&PyDict>) -> PyResult<Option<Self>> {
+        let py = schema.py();
+        match schema_or_config(
+            schema,
+            config,
+            intern!(py, "unicode_normalize"),
+            intern!(py, "str_unicode_normalize"),
+        )? {
+            None => Ok(None),
+            Some("NFC") => Ok(Some(Self::Nfc)),
+            Some("NFKC") => Ok(Some(Self::Nfkc)),
+            Some(s) => py_err!(r#"Invalid unicode_normalize: "{}""#, s),
+        }
+    }
+
+    /// `None` if `str` is already normalized, the quick check avoids allocating for most strings
+    fn normalize(self, str: &str) -> Option<String> {
+        let quick_check = match self {
+            Self::Nfc => is_nfc_quick(str.chars()),
+            Self::Nfkc => is_nfkc_quick(str.chars()),
+        };
+        if quick_check == IsNormalized::Yes {
+            return None;
+        }
+        let normalized: String = match self {
+            Self::Nfc => str.nfc().collect(),
+            Self::Nfkc => str.nfkc().collect(),
+        };
+        if normalized == str {
+            None
+        } else {
+            Some(normalized)
+        }
+    }
+}
+
+/// How instances of `str` subclasses, e.g. `StrEnum` members, are validated, without `subclasses` they're
+/// rejected in strict mode and coerced to `str` in lax mode
+#[derive(Debug, Clone, Copy)]
+enum StrSubclasses {
+    /// returned unchanged
+    Keep,
+    /// converted to a plain `str`
+    Coerce,
+    /// a `string_sub_type` error
+    Reject,
+}
+
+impl StrSubclasses {
+    fn from_schema(schema: &PyDict, config: Option<&PyDict>) -> PyResult<Option<Self>> {
+        let py = schema.py();
+        match schema_or_config(schema, config, intern!(py, "subclasses"), intern!(py, "str_subclasses"))? {
+            None => Ok(None),
+            Some("keep") => Ok(Some(Self::Keep)),
+            Some("coerce") => Ok(Some(Self::Coerce)),
+            Some("reject") => Ok(Some(Self::Reject)),
+            Some(s) => py_err!(r#"Invalid subclasses: "{}""#, s),
+        }
+    }
+
+    fn validate<'data>(
+        self,
+        input: &'data impl Input<'data>,
+        py_str: &'data PyString,
+    ) -> ValResult<'data, EitherString<'data>> {
+        match self {
+            Self::Keep => Ok(py_str.into()),
+            Self::Coerce => Ok(py_string_str(py_str)?.into()),
+            Self::Reject => Err(ValError::new(ErrorType::StringSubType, input)),
+        }
+    }
 }
 
 /// Any new properties set here must be reflected in `has_constraints_set`
@@ -60,11 +207,16 @@ pub struct StrConstrainedValidator {
     pattern: Option<Regex>,
     max_length: Option<usize>,
     min_length: Option<usize>,
+    length_unit: LengthUnit,
+    unicode_normalize: Option<UnicodeNormalize>,
+    subclasses: Option<StrSubclasses>,
     strip_whitespace: bool,
     to_lower: bool,
     to_upper: bool,
 }
 
+impl_py_gc_traverse!(StrConstrainedValidator {});
+
 impl Validator for StrConstrainedValidator {
     fn validate<'s, 'data>(
         &'s self,
@@ -74,24 +226,48 @@ impl Validator for StrConstrainedValidator {
         _slots: &'data [CombinedValidator],
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let either_str = input.validate_str(extra.strict.unwrap_or(self.strict))?;
+        let either_str = match (self.subclasses, input.input_as_str_subclass()) {
+            (Some(subclasses), Some(py_str)) => subclasses.validate(input, py_str)?,
+            _ => input.validate_str(extra.strict.unwrap_or(self.strict))?,
+        };
         let cow = either_str.as_cow()?;
         let mut str = cow.as_ref();
         if self.strip_whitespace {
             str = str.trim();
         }
-        if let Some(min_length) = self.min_length {
-            if str.len() < min_length {
-                // return py_err!("{} is shorter than {}", str, min_length);
-                return Err(ValError::new(ErrorType::StringTooShort { min_length }, input));
+        let normalized = self.unicode_normalize.and_then(|n| n.normalize(str));
+        if let Some(ref normalized) = normalized {
+            str = normalized;
+        }
+        // a string at the tail of partial input may have been cut off, so it's only checked against constraints
+        // which a longer string couldn't satisfy
+        if let (Some(min_length), false) = (self.min_length, extra.partial) {
+            let actual_length = self.length_unit.length(str);
+            if actual_length < min_length {
+                return Err(ValError::new(
+                    ErrorType::StringTooShort {
+                        field_type: "String".to_string(),
+                        min_length,
+                        actual_length,
+                    },
+                    input,
+                ));
             }
         }
         if let Some(max_length) = self.max_length {
-            if str.len() > max_length {
-                return Err(ValError::new(ErrorType::StringTooLong { max_length }, input));
+            let actual_length = self.length_unit.length(str);
+            if actual_length > max_length {
+                return Err(ValError::new(
+                    ErrorType::StringTooLong {
+                        field_type: "String".to_string(),
+                        max_length,
+                        actual_length,
+                    },
+                    input,
+                ));
             }
         }
-        if let Some(pattern) = &self.pattern {
+        if let (Some(pattern), false) = (&self.pattern, extra.partial) {
             if !pattern.is_match(str) {
                 return Err(ValError::new(
                     ErrorType::StringPatternMismatch {
@@ -102,22 +278,25 @@ impl Validator for StrConstrainedValidator {
             }
         }
 
-        let py_string = if self.to_lower {
-            PyString::new(py, &str.to_lowercase())
+        if self.to_lower {
+            Ok(str_to_py(py, &str.to_lowercase(), extra))
         } else if self.to_upper {
-            PyString::new(py, &str.to_uppercase())
-        } else if self.strip_whitespace {
-            PyString::new(py, str)
+            Ok(str_to_py(py, &str.to_uppercase(), extra))
+        } else if self.strip_whitespace || normalized.is_some() {
+            Ok(str_to_py(py, str, extra))
         } else {
             // we haven't modified the string, return the original as it might be a PyString
-            either_str.as_py_string(py)
-        };
-        Ok(py_string.into_py(py))
+            Ok(either_str_to_py(py, either_str, extra))
+        }
     }
 
     fn get_name(&self) -> &str {
         "constrained-str"
     }
+
+    fn ask(&self, _py: Python, question: &Question) -> bool {
+        matches!(question, Question::RejectsNone)
+    }
 }
 
 impl StrConstrainedValidator {
@@ -149,6 +328,9 @@ impl StrConstrainedValidator {
             pattern,
             min_length,
             max_length,
+            length_unit: LengthUnit::from_schema(schema, config)?,
+            unicode_normalize: UnicodeNormalize::from_schema(schema, config)?,
+            subclasses: StrSubclasses::from_schema(schema, config)?,
             strip_whitespace,
             to_lower,
             to_upper,
@@ -161,6 +343,8 @@ impl StrConstrainedValidator {
         self.pattern.is_some()
             || self.max_length.is_some()
             || self.min_length.is_some()
+            || self.unicode_normalize.is_some()
+            || self.subclasses.is_some()
             || self.strip_whitespace
             || self.to_lower
             || self.to_upper
@@ -5,23 +5,31 @@ use speedate::Time;
 
 use crate::build_tools::{is_strict, SchemaDict};
 use crate::errors::{ErrorType, ValError, ValResult};
-use crate::input::{EitherTime, Input};
+use crate::input::{EitherTime, FormatKind, Input, InputFormats, LeapSecondMode};
+use crate::py_gc::impl_py_gc_traverse;
+use crate::questions::Question;
 use crate::recursion_guard::RecursionGuard;
 
+use super::datetime::TZConstraint;
 use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
 
 #[derive(Debug, Clone)]
 pub struct TimeValidator {
     strict: bool,
+    leap_second_mode: LeapSecondMode,
+    input_formats: Option<InputFormats>,
     constraints: Option<TimeConstraints>,
 }
 
+impl_py_gc_traverse!(TimeValidator {});
+
 #[derive(Debug, Clone)]
 struct TimeConstraints {
     le: Option<Time>,
     lt: Option<Time>,
     ge: Option<Time>,
     gt: Option<Time>,
+    tz: Option<TZConstraint>,
 }
 
 impl BuildValidator for TimeValidator {
@@ -36,16 +44,20 @@ impl BuildValidator for TimeValidator {
         let has_constraints = schema.get_item(intern!(py, "le")).is_some()
             || schema.get_item(intern!(py, "lt")).is_some()
             || schema.get_item(intern!(py, "ge")).is_some()
-            || schema.get_item(intern!(py, "gt")).is_some();
+            || schema.get_item(intern!(py, "gt")).is_some()
+            || schema.get_item(intern!(py, "tz_constraint")).is_some();
 
         Ok(Self {
             strict: is_strict(schema, config)?,
+            leap_second_mode: LeapSecondMode::from_py(schema, config)?,
+            input_formats: InputFormats::from_py(schema, FormatKind::Time)?,
             constraints: match has_constraints {
                 true => Some(TimeConstraints {
                     le: convert_pytime(schema, intern!(py, "le"))?,
                     lt: convert_pytime(schema, intern!(py, "lt"))?,
                     ge: convert_pytime(schema, intern!(py, "ge"))?,
                     gt: convert_pytime(schema, intern!(py, "gt"))?,
+                    tz: TZConstraint::from_py(schema)?,
                 }),
                 false => None,
             },
@@ -63,7 +75,17 @@ impl Validator for TimeValidator {
         _slots: &'data [CombinedValidator],
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let time = input.validate_time(extra.strict.unwrap_or(self.strict))?;
+        let strict = extra.strict.unwrap_or(self.strict);
+        let time = match input.validate_time(strict, self.leap_second_mode) {
+            Ok(time) => time,
+            Err(ValError::LineErrors(line_errors)) if !strict => {
+                match self.input_formats.as_ref().and_then(|f| f.parse_time(input)) {
+                    Some(time) => time,
+                    None => return Err(ValError::LineErrors(line_errors)),
+                }
+            }
+            Err(err) => return Err(err),
+        };
         if let Some(constraints) = &self.constraints {
             let raw_time = time.as_raw()?;
 
@@ -86,6 +108,12 @@ impl Validator for TimeValidator {
             check_constraint!(lt, LessThan);
             check_constraint!(ge, GreaterThanEqual);
             check_constraint!(gt, GreaterThan);
+
+            match (&constraints.tz, time.offset()?) {
+                (Some(TZConstraint::Aware), None) => return Err(ValError::new(ErrorType::TimeAware, input)),
+                (Some(TZConstraint::Naive), Some(_)) => return Err(ValError::new(ErrorType::TimeNaive, input)),
+                _ => (),
+            }
         }
         Ok(time.try_into_py(py)?)
     }
@@ -93,6 +121,10 @@ impl Validator for TimeValidator {
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
+
+    fn ask(&self, _py: Python, question: &Question) -> bool {
+        matches!(question, Question::RejectsNone)
+    }
 }
 
 fn convert_pytime(schema: &PyDict, field: &PyString) -> PyResult<Option<Time>> {
@@ -6,6 +6,8 @@ use speedate::Duration;
 use crate::build_tools::{is_strict, SchemaDict};
 use crate::errors::{ErrorType, ValError, ValResult};
 use crate::input::{EitherTimedelta, Input};
+use crate::py_gc::impl_py_gc_traverse;
+use crate::questions::Question;
 use crate::recursion_guard::RecursionGuard;
 
 use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
@@ -16,6 +18,8 @@ pub struct TimeDeltaValidator {
     constraints: Option<TimedeltaConstraints>,
 }
 
+impl_py_gc_traverse!(TimeDeltaValidator {});
+
 #[derive(Debug, Clone)]
 struct TimedeltaConstraints {
     le: Option<Duration>,
@@ -92,6 +96,10 @@ impl Validator for TimeDeltaValidator {
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
+
+    fn ask(&self, _py: Python, question: &Question) -> bool {
+        matches!(question, Question::RejectsNone)
+    }
 }
 
 fn py_timedelta_as_timedelta(schema: &PyDict, field: &PyString) -> PyResult<Option<Duration>> {
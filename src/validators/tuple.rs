@@ -5,9 +5,12 @@ use pyo3::types::{PyDict, PyList, PyTuple};
 use crate::build_tools::{is_strict, SchemaDict};
 use crate::errors::{ErrorType, ValError, ValLineError, ValResult};
 use crate::input::{GenericCollection, Input};
+use crate::py_gc::impl_py_gc_traverse;
+use crate::questions::Question;
 use crate::recursion_guard::RecursionGuard;
+use crate::serializers::AllItems;
 
-use super::list::{get_items_schema, length_check};
+use super::list::{get_items_schema, length_check, CollectionCoercion, CollectionType};
 use super::with_default::get_default;
 use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
 
@@ -35,9 +38,12 @@ pub struct TupleVariableValidator {
     item_validator: Option<Box<CombinedValidator>>,
     min_length: Option<usize>,
     max_length: Option<usize>,
+    coercion: CollectionCoercion,
     name: String,
 }
 
+impl_py_gc_traverse!(TupleVariableValidator { item_validator });
+
 impl TupleVariableValidator {
     fn build(
         schema: &PyDict,
@@ -53,6 +59,7 @@ impl TupleVariableValidator {
             item_validator,
             min_length: schema.get_as(pyo3::intern!(py, "min_length"))?,
             max_length: schema.get_as(pyo3::intern!(py, "max_length"))?,
+            coercion: CollectionCoercion::from_config(py, config)?,
             name,
         }
         .into())
@@ -68,7 +75,10 @@ impl Validator for TupleVariableValidator {
         slots: &'data [CombinedValidator],
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let seq = input.validate_tuple(extra.strict.unwrap_or(self.strict))?;
+        let strict = extra.strict.unwrap_or(self.strict);
+        let seq = self
+            .coercion
+            .validate(py, input, CollectionType::Tuple, strict, false)?;
 
         let output = match self.item_validator {
             Some(ref v) => seq.validate_to_vec(
@@ -98,6 +108,10 @@ impl Validator for TupleVariableValidator {
         &self.name
     }
 
+    fn ask(&self, _py: Python, question: &Question) -> bool {
+        matches!(question, Question::RejectsNone)
+    }
+
     fn complete(&mut self, build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
         match self.item_validator {
             Some(ref mut v) => v.complete(build_context),
@@ -111,9 +125,15 @@ pub struct TuplePositionalValidator {
     strict: bool,
     items_validators: Vec<CombinedValidator>,
     extra_validator: Option<Box<CombinedValidator>>,
+    coercion: CollectionCoercion,
     name: String,
 }
 
+impl_py_gc_traverse!(TuplePositionalValidator {
+    items_validators,
+    extra_validator
+});
+
 impl TuplePositionalValidator {
     fn build(
         schema: &PyDict,
@@ -135,6 +155,7 @@ impl TuplePositionalValidator {
                 Some(v) => Some(Box::new(build_validator(v, config, build_context)?)),
                 None => None,
             },
+            coercion: CollectionCoercion::from_config(py, config)?,
             name: format!("tuple[{descr}]"),
         }
         .into())
@@ -150,40 +171,84 @@ impl Validator for TuplePositionalValidator {
         slots: &'data [CombinedValidator],
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let collection = input.validate_tuple(extra.strict.unwrap_or(self.strict))?;
+        let strict = extra.strict.unwrap_or(self.strict);
+        let collection = self
+            .coercion
+            .validate(py, input, CollectionType::Tuple, strict, false)?;
         let expected_length = self.items_validators.len();
 
         let mut output: Vec<PyObject> = Vec::with_capacity(expected_length);
         let mut errors: Vec<ValLineError> = Vec::new();
+        // with partial input, the last item may be incomplete and later items may be missing
+        let tail = match extra.partial {
+            true => collection.generic_len().ok().and_then(|len| len.checked_sub(1)),
+            false => None,
+        };
+        let all_items = AllItems::new(py, extra.include, extra.exclude)?;
         macro_rules! iter {
             ($collection_iter:expr) => {{
                 for (index, validator) in self.items_validators.iter().enumerate() {
                     match $collection_iter.next() {
-                        Some(item) => match validator.validate(py, item, extra, slots, recursion_guard) {
-                            Ok(item) => output.push(item),
-                            Err(ValError::LineErrors(line_errors)) => {
-                                errors.extend(
-                                    line_errors
-                                        .into_iter()
-                                        .map(|err| err.with_outer_location(index.into())),
-                                );
+                        Some(item) => {
+                            let (include, exclude) = match extra.item_filter(index, all_items)? {
+                                Some(item_include_exclude) => item_include_exclude,
+                                // items left out by `include`/`exclude` aren't validated or returned
+                                None => continue,
+                            };
+                            let warnings_start = extra.warnings_count();
+                            let is_tail = tail == Some(index);
+                            let item_extra = Extra {
+                                partial: is_tail,
+                                include,
+                                exclude,
+                                ..*extra
+                            };
+                            let result = validator.validate_item(py, item, &item_extra, slots, recursion_guard);
+                            extra.warnings_with_outer_location(warnings_start, || vec![index.into()]);
+                            match result {
+                                Ok(item) => output.push(item),
+                                Err(ValError::LineErrors(_)) if is_tail => (),
+                                Err(ValError::LineErrors(line_errors)) => {
+                                    errors.extend(
+                                        line_errors
+                                            .into_iter()
+                                            .map(|err| err.with_outer_location(index.into())),
+                                    );
+                                }
+                                Err(err) => return Err(err),
                             }
+                        }
+                        None => match get_default(py, validator, &[index.into()], extra, slots, recursion_guard) {
+                            Ok(Some(value)) => output.push(value),
+                            Ok(None) if extra.partial => (),
+                            Ok(None) => errors.push(ValLineError::new_with_loc(ErrorType::Missing, input, index)),
+                            Err(ValError::LineErrors(line_errors)) => errors.extend(line_errors),
                             Err(err) => return Err(err),
                         },
-                        None => {
-                            if let Some(value) = get_default(py, &validator)? {
-                                output.push(value.as_ref().clone_ref(py));
-                            } else {
-                                errors.push(ValLineError::new_with_loc(ErrorType::Missing, input, index));
-                            }
-                        }
                     }
                 }
                 for (index, item) in $collection_iter.enumerate() {
                     match self.extra_validator {
                         Some(ref extra_validator) => {
-                            match extra_validator.validate(py, item, extra, slots, recursion_guard) {
+                            let (include, exclude) = match extra.item_filter(index + expected_length, all_items)? {
+                                Some(item_include_exclude) => item_include_exclude,
+                                None => continue,
+                            };
+                            let warnings_start = extra.warnings_count();
+                            let is_tail = tail == Some(index + expected_length);
+                            let item_extra = Extra {
+                                partial: is_tail,
+                                include,
+                                exclude,
+                                ..*extra
+                            };
+                            let result = extra_validator.validate_item(py, item, &item_extra, slots, recursion_guard);
+                            extra.warnings_with_outer_location(warnings_start, || {
+                                vec![(index + expected_length).into()]
+                            });
+                            match result {
                                 Ok(item) => output.push(item),
+                                Err(ValError::LineErrors(_)) if is_tail => (),
                                 Err(ValError::LineErrors(line_errors)) => {
                                     errors.extend(
                                         line_errors
@@ -225,11 +290,18 @@ impl Validator for TuplePositionalValidator {
                 let mut iter = vec.into_iter();
                 iter!(iter)
             }
+            GenericCollection::Set(collection) => {
+                let mut iter = collection.iter();
+                iter!(iter)
+            }
+            GenericCollection::FrozenSet(collection) => {
+                let mut iter = collection.iter();
+                iter!(iter)
+            }
             GenericCollection::JsonArray(collection) => {
                 let mut iter = collection.iter();
                 iter!(iter)
             }
-            _ => unreachable!(),
         }
         if errors.is_empty() {
             Ok(PyTuple::new(py, &output).into_py(py))
@@ -242,6 +314,10 @@ impl Validator for TuplePositionalValidator {
         &self.name
     }
 
+    fn ask(&self, _py: Python, question: &Question) -> bool {
+        matches!(question, Question::RejectsNone)
+    }
+
     fn complete(&mut self, build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
         self.items_validators
             .iter_mut()
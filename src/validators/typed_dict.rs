@@ -1,18 +1,27 @@
+use std::sync::Arc;
+use std::time::Instant;
+
+use pyo3::exceptions::PyTypeError;
 use pyo3::intern;
 use pyo3::prelude::*;
 
 use ahash::AHashSet;
 use pyo3::types::{PyDict, PySet, PyString};
 
-use crate::build_tools::{is_strict, py_err, schema_or_config, schema_or_config_same, SchemaDict};
-use crate::errors::{py_err_string, ErrorType, ValError, ValLineError, ValResult};
+use crate::build_tools::{
+    inner_config, is_strict, py_err, py_error_type, schema_or_config, schema_or_config_same, SchemaDict,
+};
+use crate::errors::{py_err_string, ErrorType, LocItem, ValError, ValLineError, ValResult};
 use crate::input::{
     AttributesGenericIterator, DictGenericIterator, GenericMapping, Input, JsonObjectGenericIterator,
-    MappingGenericIterator,
+    MappingGenericIterator, StringMappingGenericIterator,
 };
 use crate::lookup_key::LookupKey;
+use crate::profile::ProfileCounter;
+use crate::py_gc::impl_py_gc_traverse;
 use crate::questions::Question;
 use crate::recursion_guard::RecursionGuard;
+use crate::serializers::AnyFilter;
 
 use super::with_default::get_default;
 use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
@@ -21,10 +30,43 @@ use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Ex
 struct TypedDictField {
     name: String,
     lookup_key: LookupKey,
+    // with `populate_by_name`, a lookup of just the field name, used to detect values under both the alias and name
+    name_lookup_key: Option<LookupKey>,
     name_py: Py<PyString>,
     required: bool,
     validator: CombinedValidator,
     frozen: bool,
+    hide_input_in_errors: bool,
+    // the location of errors for this field, either its name or alias depending on `loc_by_alias`
+    error_loc: Vec<LocItem>,
+    // if set, a warning with this message is recorded when the field is provided
+    deprecated: Option<String>,
+    // only set with the `profile` config key
+    profile: Option<Arc<ProfileCounter>>,
+}
+
+impl_py_gc_traverse!(TypedDictField { name_py, validator });
+
+impl TypedDictField {
+    /// validate the value of this field, recording the time taken if profiling is enabled
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        match self.profile {
+            Some(ref counter) => {
+                let start = Instant::now();
+                let result = self.validator.validate_item(py, input, extra, slots, recursion_guard);
+                counter.record(start);
+                result
+            }
+            None => self.validator.validate_item(py, input, extra, slots, recursion_guard),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -33,11 +75,19 @@ pub struct TypedDictValidator {
     check_extra: bool,
     forbid_extra: bool,
     extra_validator: Option<Box<CombinedValidator>>,
+    // with extra_behavior=allow, extra inputs are collected into a dict under this key rather than alongside fields
+    extra_attr: Option<Py<PyString>>,
     strict: bool,
     from_attributes: bool,
     return_fields_set: bool,
 }
 
+impl_py_gc_traverse!(TypedDictValidator {
+    fields,
+    extra_validator,
+    extra_attr
+});
+
 impl BuildValidator for TypedDictValidator {
     const EXPECTED_TYPE: &'static str = "typed-dict";
 
@@ -59,6 +109,7 @@ impl BuildValidator for TypedDictValidator {
             schema_or_config(schema, config, intern!(py, "total"), intern!(py, "typed_dict_total"))?.unwrap_or(true);
         let from_attributes = schema_or_config_same(schema, config, intern!(py, "from_attributes"))?.unwrap_or(false);
         let populate_by_name = schema_or_config_same(schema, config, intern!(py, "populate_by_name"))?.unwrap_or(false);
+        let loc_by_alias = schema_or_config_same(schema, config, intern!(py, "loc_by_alias"))?.unwrap_or(false);
 
         let return_fields_set = schema.get_as(intern!(py, "return_fields_set"))?.unwrap_or(false);
 
@@ -84,6 +135,23 @@ impl BuildValidator for TypedDictValidator {
         };
 
         let fields_dict: &PyDict = schema.get_as_req(intern!(py, "fields"))?;
+
+        let extra_attr = match schema_or_config::<&str>(
+            schema,
+            config,
+            intern!(py, "extra_attr"),
+            intern!(py, "typed_dict_extra_attr"),
+        )? {
+            Some(extra_attr) => {
+                if !check_extra || forbid_extra {
+                    return py_err!("extra_attr can only be used if extra_behavior=allow");
+                } else if fields_dict.contains(extra_attr)? {
+                    return py_err!("extra_attr \"{}\" clashes with a field of the same name", extra_attr);
+                }
+                Some(PyString::intern(py, extra_attr).into())
+            }
+            None => None,
+        };
         let mut fields: Vec<TypedDictField> = Vec::with_capacity(fields_dict.len());
 
         for (key, value) in fields_dict.iter() {
@@ -92,10 +160,12 @@ impl BuildValidator for TypedDictValidator {
 
             let schema = field_info.get_as_req(intern!(py, "schema"))?;
 
-            let validator = match build_validator(schema, config, build_context) {
+            let profile = build_context.profile_start_field(field_name);
+            let validator = match build_validator(schema, inner_config(field_info, config)?, build_context) {
                 Ok(v) => v,
                 Err(err) => return py_err!("Field \"{}\":\n  {}", field_name, err),
             };
+            build_context.profile_end_field();
 
             let required = match field_info.get_as::<bool>(intern!(py, "required"))? {
                 Some(required) => {
@@ -122,21 +192,45 @@ impl BuildValidator for TypedDictValidator {
                 }
             }
 
-            let lookup_key = match field_info.get_item(intern!(py, "validation_alias")) {
-                Some(alias) => {
-                    let alt_alias = if populate_by_name { Some(field_name) } else { None };
-                    LookupKey::from_py(py, alias, alt_alias)?
-                }
-                None => LookupKey::from_string(py, field_name),
+            let (lookup_key, name_lookup_key) = match field_info.get_item(intern!(py, "validation_alias")) {
+                Some(alias) => match populate_by_name {
+                    true => (
+                        LookupKey::from_py(py, alias, Some(field_name))?,
+                        Some(LookupKey::from_string(py, field_name)),
+                    ),
+                    false => (LookupKey::from_py(py, alias, None)?, None),
+                },
+                None => (LookupKey::from_string(py, field_name), None),
+            };
+
+            let error_loc = match loc_by_alias {
+                true => lookup_key.error_loc(),
+                false => vec![field_name.into()],
+            };
+
+            let deprecated = match field_info.get_item(intern!(py, "deprecated")) {
+                Some(value) => match value.extract::<bool>() {
+                    Ok(true) => Some("Field is deprecated".to_string()),
+                    Ok(false) => None,
+                    Err(_) => Some(value.extract()?),
+                },
+                None => None,
             };
 
             fields.push(TypedDictField {
                 name: field_name.to_string(),
                 lookup_key,
+                name_lookup_key,
                 name_py: PyString::intern(py, field_name).into(),
                 validator,
                 required,
                 frozen: field_info.get_as::<bool>(intern!(py, "frozen"))?.unwrap_or(false),
+                hide_input_in_errors: field_info
+                    .get_as::<bool>(intern!(py, "hide_input_in_errors"))?
+                    .unwrap_or(false),
+                error_loc,
+                deprecated,
+                profile,
             });
         }
 
@@ -145,6 +239,7 @@ impl BuildValidator for TypedDictValidator {
             check_extra,
             forbid_extra,
             extra_validator,
+            extra_attr,
             strict,
             from_attributes,
             return_fields_set,
@@ -166,12 +261,56 @@ impl Validator for TypedDictValidator {
             // we're validating assignment, completely different logic
             return self.validate_assignment(py, field, input, extra, slots, recursion_guard);
         }
+        self.validate_fields(py, input, extra, slots, recursion_guard, self.return_fields_set)
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+
+    fn ask(&self, _py: Python, question: &Question) -> bool {
+        match question {
+            Question::ReturnFieldsSet => self.return_fields_set,
+            Question::RejectsNone => true,
+        }
+    }
+
+    fn complete(&mut self, build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
+        self.fields
+            .iter_mut()
+            .try_for_each(|f| f.validator.complete(build_context))
+    }
+}
+
+impl TypedDictValidator {
+    /// Validate as if `return_fields_set` was set on the schema, returning a tuple of the output dict and the set
+    /// of fields which were present in the input, used by `SchemaValidator.validate_python(return_fields_set=True)`
+    pub fn validate_with_fields_set<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        self.validate_fields(py, input, extra, slots, recursion_guard, true)
+    }
+
+    fn validate_fields<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+        return_fields_set: bool,
+    ) -> ValResult<'data, PyObject> {
         let strict = extra.strict.unwrap_or(self.strict);
         let dict = input.validate_typed_dict(strict, self.from_attributes)?;
 
         let output_dict = PyDict::new(py);
         let mut errors: Vec<ValLineError> = Vec::with_capacity(self.fields.len());
-        let mut fields_set_vec: Option<Vec<Py<PyString>>> = match self.return_fields_set {
+        let mut fields_set_vec: Option<Vec<Py<PyString>>> = match return_fields_set {
             true => Some(Vec::with_capacity(self.fields.len())),
             false => None,
         };
@@ -183,26 +322,85 @@ impl Validator for TypedDictValidator {
             false => None,
         };
 
+        // extras are either stored in their own dict, or in the output dict next to fields
+        let extras_dict = match self.extra_attr {
+            Some(_) => PyDict::new(py),
+            None => output_dict,
+        };
+
+        // with partial input, required fields may not have been reached yet
+        let partial = extra.partial;
+        let (include, exclude) = (extra.include, extra.exclude);
         let extra = Extra {
             data: Some(output_dict),
             field: None,
+            field_name: None,
             strict: extra.strict,
             context: extra.context,
+            max_errors: extra.max_errors,
+            warnings: extra.warnings,
+            string_cache: extra.string_cache,
+            // fields are validated into new instances
+            self_instance: None,
+            partial: false,
+            include: None,
+            exclude: None,
         };
 
         macro_rules! process {
             ($dict:ident, $get_method:ident, $iter:ty) => {{
+                // only the value of the last key in partial input may be incomplete
+                let tail_key: Option<String> = match partial {
+                    true => match <$iter>::new($dict)?.last() {
+                        Some(item_result) => match item_result?.0.strict_str() {
+                            Ok(key) => Some(key.as_cow()?.into_owned()),
+                            Err(_) => None,
+                        },
+                        None => None,
+                    },
+                    false => None,
+                };
                 for field in &self.fields {
+                    let (field_include, field_exclude) = match (include, exclude) {
+                        (None, None) => (None, None),
+                        _ => match AnyFilter::new().key_filter(field.name_py.as_ref(py), include, exclude)? {
+                            Some(next_include_exclude) => next_include_exclude,
+                            // fields left out by `include`/`exclude` aren't validated, as if they were unset
+                            None => {
+                                if let Some(ref mut used_keys) = used_keys {
+                                    if let Ok(Some((used_key, _))) = field.lookup_key.$get_method($dict) {
+                                        used_keys.insert(used_key);
+                                    }
+                                }
+                                continue;
+                            }
+                        },
+                    };
+                    let field_extra = Extra {
+                        field_name: Some(&field.name),
+                        include: field_include,
+                        exclude: field_exclude,
+                        ..extra
+                    };
                     let op_key_value = match field.lookup_key.$get_method($dict) {
+                        // `PydanticUndefined` is treated as if the field were missing, the key isn't an extra though
+                        Ok(Some((used_key, value))) if value.is_undefined() => {
+                            if let Some(ref mut used_keys) = used_keys {
+                                used_keys.insert(used_key);
+                            }
+                            None
+                        }
                         Ok(v) => v,
                         Err(err) => {
-                            errors.push(ValLineError::new_with_loc(
-                                ErrorType::GetAttributeError {
-                                    error: py_err_string(py, err),
-                                },
-                                input,
-                                field.name.clone(),
-                            ));
+                            errors.push(
+                                ValLineError::new(
+                                    ErrorType::GetAttributeError {
+                                        error: py_err_string(py, err),
+                                    },
+                                    input,
+                                )
+                                .with_outer_location_items(&field.error_loc),
+                            );
                             continue;
                         }
                     };
@@ -212,10 +410,36 @@ impl Validator for TypedDictValidator {
                             // extra logic either way
                             used_keys.insert(used_key);
                         }
-                        match field
-                            .validator
-                            .validate(py, value, &extra, slots, recursion_guard)
-                        {
+                        // the alias takes priority, so the name is only checked when the value came from the alias
+                        if let (Some(name_lookup_key), false) = (&field.name_lookup_key, used_key == field.name) {
+                            if let Ok(Some((name_key, _))) = name_lookup_key.$get_method($dict) {
+                                if let Some(ref mut used_keys) = used_keys {
+                                    used_keys.insert(name_key);
+                                }
+                                errors.push(
+                                    ValLineError::new(ErrorType::AliasAndNameProvided, input)
+                                        .with_outer_location_items(&field.error_loc),
+                                );
+                                continue;
+                            }
+                        }
+                        let warnings_start = extra.warnings_count();
+                        if let Some(ref message) = field.deprecated {
+                            extra.record_warning("deprecated_field", message.as_str());
+                        }
+                        let is_tail = tail_key.as_deref() == Some(used_key);
+                        let result = match is_tail {
+                            true => {
+                                let tail_extra = Extra {
+                                    partial: true,
+                                    ..field_extra
+                                };
+                                field.validate(py, value, &tail_extra, slots, recursion_guard)
+                            }
+                            false => field.validate(py, value, &field_extra, slots, recursion_guard),
+                        };
+                        extra.warnings_with_outer_location(warnings_start, || field.error_loc.clone());
+                        match result {
                             Ok(value) => {
                                 output_dict.set_item(&field.name_py, value)?;
                                 if let Some(ref mut fs) = fields_set_vec {
@@ -223,22 +447,36 @@ impl Validator for TypedDictValidator {
                                 }
                             }
                             Err(ValError::Omit) => continue,
+                            // an incomplete value at the tail of partial input is left out
+                            Err(ValError::LineErrors(_)) if is_tail => continue,
                             Err(ValError::LineErrors(line_errors)) => {
                                 for err in line_errors {
-                                    errors.push(err.with_outer_location(field.name.clone().into()));
+                                    let err = err.with_outer_location_items(&field.error_loc);
+                                    errors.push(match field.hide_input_in_errors {
+                                        true => err.hide_input(),
+                                        false => err,
+                                    });
                                 }
                             }
                             Err(err) => return Err(err),
                         }
                         continue;
-                    } else if let Some(value) = get_default(py, &field.validator)? {
-                        output_dict.set_item(&field.name_py, value.as_ref())?;
-                    } else if field.required {
-                        errors.push(ValLineError::new_with_loc(
-                            ErrorType::Missing,
-                            input,
-                            field.name.clone(),
-                        ));
+                    }
+                    match get_default(
+                        py,
+                        &field.validator,
+                        &field.error_loc,
+                        &field_extra,
+                        slots,
+                        recursion_guard,
+                    ) {
+                        Ok(Some(value)) => output_dict.set_item(&field.name_py, value)?,
+                        Ok(None) if field.required && !partial => errors.push(
+                            ValLineError::new(ErrorType::Missing, input).with_outer_location_items(&field.error_loc),
+                        ),
+                        Ok(None) => (),
+                        Err(ValError::LineErrors(line_errors)) => errors.extend(line_errors),
+                        Err(err) => return Err(err),
                     }
                 }
 
@@ -277,9 +515,12 @@ impl Validator for TypedDictValidator {
                         }
 
                         if let Some(ref validator) = self.extra_validator {
-                            match validator.validate(py, value, &extra, slots, recursion_guard) {
+                            let warnings_start = extra.warnings_count();
+                            let result = validator.validate_item(py, value, &extra, slots, recursion_guard);
+                            extra.warnings_with_outer_location(warnings_start, || vec![raw_key.as_loc_item()]);
+                            match result {
                                 Ok(value) => {
-                                    output_dict.set_item(py_key, value)?;
+                                    extras_dict.set_item(py_key, value)?;
                                     if let Some(ref mut fs) = fields_set_vec {
                                         fs.push(py_key.into_py(py));
                                     }
@@ -292,7 +533,7 @@ impl Validator for TypedDictValidator {
                                 Err(err) => return Err(err),
                             }
                         } else {
-                            output_dict.set_item(py_key, value.to_object(py))?;
+                            extras_dict.set_item(py_key, value.to_object(py))?;
                             if let Some(ref mut fs) = fields_set_vec {
                                 fs.push(py_key.into_py(py));
                             }
@@ -306,6 +547,11 @@ impl Validator for TypedDictValidator {
             GenericMapping::PyMapping(d) => process!(d, py_get_mapping_item, MappingGenericIterator),
             GenericMapping::PyGetAttr(d) => process!(d, py_get_attr, AttributesGenericIterator),
             GenericMapping::JsonObject(d) => process!(d, json_get, JsonObjectGenericIterator),
+            GenericMapping::StringMapping(d) => process!(d, string_mapping_get, StringMappingGenericIterator),
+        }
+
+        if let Some(ref extra_attr) = self.extra_attr {
+            output_dict.set_item(extra_attr, extras_dict)?;
         }
 
         if !errors.is_empty() {
@@ -318,24 +564,6 @@ impl Validator for TypedDictValidator {
         }
     }
 
-    fn get_name(&self) -> &str {
-        Self::EXPECTED_TYPE
-    }
-
-    fn ask(&self, question: &Question) -> bool {
-        match question {
-            Question::ReturnFieldsSet => self.return_fields_set,
-        }
-    }
-
-    fn complete(&mut self, build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
-        self.fields
-            .iter_mut()
-            .try_for_each(|f| f.validator.complete(build_context))
-    }
-}
-
-impl TypedDictValidator {
     fn validate_assignment<'s, 'data>(
         &'s self,
         py: Python<'data>,
@@ -351,11 +579,43 @@ impl TypedDictValidator {
         // TODO probably we should set location on errors here
         let data = match extra.data {
             Some(data) => data,
-            None => unreachable!(),
+            None => {
+                let err = py_error_type!(PyTypeError; "validate_assignment expects a dict for a typed-dict schema");
+                return Err(ValError::InternalErr(err));
+            }
+        };
+        // the value itself is validated as normal, e.g. if it's a model or typed dict
+        let extra = Extra {
+            data: Some(data),
+            field: None,
+            field_name: Some(field),
+            strict: extra.strict,
+            context: extra.context,
+            max_errors: extra.max_errors,
+            warnings: extra.warnings,
+            string_cache: extra.string_cache,
+            self_instance: None,
+            partial: false,
+            include: None,
+            exclude: None,
         };
+        let extra = &extra;
 
         let prepare_tuple = |output: PyObject| {
-            data.set_item(field, output)?;
+            match self.extra_attr {
+                // a new dict of extras rather than mutating the existing one, which may be shared with a model instance
+                Some(ref extra_attr) if !self.fields.iter().any(|f| f.name == field) => {
+                    let extras_dict = PyDict::new(py);
+                    if let Some(existing) = data.get_item(extra_attr) {
+                        for (key, value) in existing.cast_as::<PyDict>()? {
+                            extras_dict.set_item(key, value)?;
+                        }
+                    }
+                    extras_dict.set_item(field, output)?;
+                    data.set_item(extra_attr, extras_dict)?;
+                }
+                _ => data.set_item(field, output)?,
+            }
             if self.return_fields_set {
                 let fields_set = PySet::new(py, &[field])?;
                 Ok((data, fields_set).to_object(py))
@@ -364,12 +624,12 @@ impl TypedDictValidator {
             }
         };
 
-        let prepare_result = |result: ValResult<'data, PyObject>| match result {
+        let prepare_result = |result: ValResult<'data, PyObject>, error_loc: &[LocItem]| match result {
             Ok(output) => prepare_tuple(output),
             Err(ValError::LineErrors(line_errors)) => {
                 let errors = line_errors
                     .into_iter()
-                    .map(|e| e.with_outer_location(field.to_string().into()))
+                    .map(|e| e.with_outer_location_items(error_loc))
                     .collect();
                 Err(ValError::LineErrors(errors))
             }
@@ -377,15 +637,23 @@ impl TypedDictValidator {
         };
 
         if let Some(field) = self.fields.iter().find(|f| f.name == field) {
-            if field.frozen {
-                Err(ValError::new_with_loc(ErrorType::Frozen, input, field.name.to_string()))
+            let result = if field.frozen {
+                prepare_result(Err(ValError::new(ErrorType::FrozenField, input)), &field.error_loc)
             } else {
-                prepare_result(field.validator.validate(py, input, extra, slots, recursion_guard))
+                let result = field.validate(py, input, extra, slots, recursion_guard);
+                prepare_result(result, &field.error_loc)
+            };
+            match field.hide_input_in_errors {
+                true => result.map_err(ValError::hide_input),
+                false => result,
             }
         } else if self.check_extra && !self.forbid_extra {
             // this is the "allow" case of extra_behavior
             match self.extra_validator {
-                Some(ref validator) => prepare_result(validator.validate(py, input, extra, slots, recursion_guard)),
+                Some(ref validator) => prepare_result(
+                    validator.validate_item(py, input, extra, slots, recursion_guard),
+                    &[field.into()],
+                ),
                 None => prepare_tuple(input.to_object(py)),
             }
         } else {
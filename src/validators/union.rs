@@ -1,30 +1,63 @@
 use std::borrow::Cow;
+use std::cmp::Reverse;
 use std::fmt::Write;
 
+use pyo3::class::gc::{PyTraverseError, PyVisit};
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList, PyString};
 
 use ahash::AHashMap;
 
-use crate::build_tools::{is_strict, py_err, schema_or_config, SchemaDict};
+use crate::build_tools::{inner_config, is_strict, py_err, schema_or_config, SchemaDict};
 use crate::errors::{ErrorType, ValError, ValLineError, ValResult};
 use crate::input::{GenericMapping, Input};
 use crate::lookup_key::LookupKey;
+use crate::py_gc::{impl_py_gc_traverse, PyGcTraverse};
 use crate::questions::Question;
 use crate::recursion_guard::RecursionGuard;
+use crate::tracing::{trace, trace_enabled};
 
 use super::custom_error::CustomError;
+use super::lazy::LazyValidator;
 use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
 
+/// How errors are reported when no choice of a union matches the input
+#[derive(Debug, Clone, Copy)]
+enum ErrorMode {
+    /// errors from every choice, located by the choice's name
+    All,
+    /// errors from the choice which got closest to matching, the one with the fewest errors, then the deepest
+    Best,
+    /// a single `union_no_match` error
+    Summary,
+}
+
+impl ErrorMode {
+    fn from_schema(schema: &PyDict) -> PyResult<Self> {
+        match schema.get_as::<&str>(intern!(schema.py(), "error_mode"))? {
+            None | Some("all") => Ok(Self::All),
+            Some("best") => Ok(Self::Best),
+            Some("summary") => Ok(Self::Summary),
+            Some(s) => py_err!(r#"Invalid error_mode: "{}""#, s),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct UnionValidator {
     choices: Vec<CombinedValidator>,
+    // the `title` of each choice's schema, used in place of the choice validator's name
+    titles: Vec<Option<String>>,
     custom_error: Option<CustomError>,
+    error_mode: ErrorMode,
     strict: bool,
+    trace: bool,
     name: String,
 }
 
+impl_py_gc_traverse!(UnionValidator { choices, custom_error });
+
 impl BuildValidator for UnionValidator {
     const EXPECTED_TYPE: &'static str = "union";
 
@@ -34,18 +67,43 @@ impl BuildValidator for UnionValidator {
         build_context: &mut BuildContext<CombinedValidator>,
     ) -> PyResult<CombinedValidator> {
         let py = schema.py();
-        let choices: Vec<CombinedValidator> = schema
-            .get_as_req::<&PyList>(intern!(py, "choices"))?
+        let choices_config = inner_config(schema, config)?;
+        let lazy = schema_or_config(schema, config, intern!(py, "lazy"), intern!(py, "union_lazy"))?.unwrap_or(false);
+        let schema_choices: &PyList = schema.get_as_req(intern!(py, "choices"))?;
+        let choices: Vec<CombinedValidator> = schema_choices
             .iter()
-            .map(|choice| build_validator(choice, config, build_context))
+            .map(|choice| {
+                if lazy {
+                    LazyValidator::build_or_defer(choice, choices_config, build_context)
+                } else {
+                    build_validator(choice, choices_config, build_context)
+                }
+            })
             .collect::<PyResult<Vec<CombinedValidator>>>()?;
 
-        let descr = choices.iter().map(|v| v.get_name()).collect::<Vec<_>>().join(",");
+        let custom_error = CustomError::build(schema)?;
+        let error_mode = ErrorMode::from_schema(schema)?;
+        let strict = is_strict(schema, config)?;
+        let trace = trace_enabled(config)?;
+
+        let titles = schema_choices
+            .iter()
+            .map(|choice| choice.cast_as::<PyDict>()?.get_as(intern!(py, "title")))
+            .collect::<PyResult<Vec<Option<String>>>>()?;
+        let descr = choices
+            .iter()
+            .zip(&titles)
+            .map(|(v, title)| title.as_deref().unwrap_or_else(|| v.get_name()))
+            .collect::<Vec<_>>()
+            .join(",");
 
         Ok(Self {
             choices,
-            custom_error: CustomError::build(schema)?,
-            strict: is_strict(schema, config)?,
+            titles,
+            custom_error,
+            error_mode,
+            strict,
+            trace,
             name: format!("{}[{descr}]", Self::EXPECTED_TYPE),
         }
         .into())
@@ -53,15 +111,63 @@ impl BuildValidator for UnionValidator {
 }
 
 impl UnionValidator {
-    fn or_custom_error<'s, 'data>(
+    /// the choice's title if its schema has one, otherwise the choice validator's name
+    fn choice_name(&self, index: usize) -> &str {
+        match self.titles[index] {
+            Some(ref title) => title,
+            None => self.choices[index].get_name(),
+        }
+    }
+
+    fn trace_choice(&self, py: Python, index: usize, mode: &str) -> PyResult<()> {
+        if self.trace {
+            let message = format!("{}: selected `{}` in {} mode", self.name, self.choice_name(index), mode);
+            trace(py, &message)?;
+        }
+        Ok(())
+    }
+
+    /// errors are only collected if they're going to be used, e.g. not with a custom error or `error_mode=summary`
+    fn new_errors<'data>(&self) -> Option<Vec<(&str, Vec<ValLineError<'data>>)>> {
+        match (&self.custom_error, self.error_mode) {
+            (None, ErrorMode::All | ErrorMode::Best) => Some(Vec::with_capacity(self.choices.len())),
+            _ => None,
+        }
+    }
+
+    fn union_error<'s, 'data>(
         &'s self,
-        errors: Option<Vec<ValLineError<'data>>>,
+        errors: Option<Vec<(&'s str, Vec<ValLineError<'data>>)>>,
         input: &'data impl Input<'data>,
     ) -> ValError<'data> {
-        if let Some(errors) = errors {
-            ValError::LineErrors(errors)
-        } else {
-            self.custom_error.as_ref().unwrap().as_val_error(input)
+        if let Some(ref custom_error) = self.custom_error {
+            return custom_error.as_val_error(input);
+        }
+        let choice_errors = match errors {
+            Some(errors) => errors,
+            None => {
+                let expected = (0..self.choices.len())
+                    .map(|index| self.choice_name(index))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                return ValError::new(ErrorType::UnionNoMatch { expected }, input);
+            }
+        };
+        let with_location = |(choice_name, line_errors): (&'s str, Vec<ValLineError<'data>>)| {
+            line_errors
+                .into_iter()
+                .map(move |err| err.with_outer_location(choice_name.into()))
+        };
+        match self.error_mode {
+            ErrorMode::Best => {
+                // `min_by_key` returns the first of equally good choices
+                let best = choice_errors.into_iter().min_by_key(|(_, line_errors)| {
+                    let max_depth = line_errors.iter().map(|e| e.location.depth()).max().unwrap_or(0);
+                    (line_errors.len(), Reverse(max_depth))
+                });
+                ValError::LineErrors(best.into_iter().flat_map(with_location).collect())
+            }
+            _ => ValError::LineErrors(choice_errors.into_iter().flat_map(with_location).collect()),
         }
     }
 }
@@ -76,63 +182,65 @@ impl Validator for UnionValidator {
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
         if extra.strict.unwrap_or(self.strict) {
-            let mut errors: Option<Vec<ValLineError>> = match self.custom_error {
-                None => Some(Vec::with_capacity(self.choices.len())),
-                _ => None,
-            };
+            let mut errors = self.new_errors();
             let strict_extra = extra.as_strict();
+            let warnings_start = extra.warnings_count();
 
-            for validator in &self.choices {
+            for (index, validator) in self.choices.iter().enumerate() {
                 let line_errors = match validator.validate(py, input, &strict_extra, slots, recursion_guard) {
-                    Err(ValError::LineErrors(line_errors)) => line_errors,
+                    Err(ValError::LineErrors(line_errors)) => {
+                        extra.discard_warnings(warnings_start);
+                        line_errors
+                    }
+                    Ok(output) => {
+                        self.trace_choice(py, index, "strict")?;
+                        return Ok(output);
+                    }
                     otherwise => return otherwise,
                 };
 
                 if let Some(ref mut errors) = errors {
-                    errors.extend(
-                        line_errors
-                            .into_iter()
-                            .map(|err| err.with_outer_location(validator.get_name().into())),
-                    );
+                    errors.push((self.choice_name(index), line_errors));
                 }
             }
 
-            Err(self.or_custom_error(errors, input))
+            Err(self.union_error(errors, input))
         } else {
             // 1st pass: check if the value is an exact instance of one of the Union types,
             // e.g. use validate in strict mode
             let strict_extra = extra.as_strict();
-            if let Some(res) = self
-                .choices
-                .iter()
-                .map(|validator| validator.validate(py, input, &strict_extra, slots, recursion_guard))
-                .find(ValResult::is_ok)
-            {
-                return res;
+            let warnings_start = extra.warnings_count();
+            for (index, validator) in self.choices.iter().enumerate() {
+                if let Ok(output) = validator.validate(py, input, &strict_extra, slots, recursion_guard) {
+                    self.trace_choice(py, index, "strict")?;
+                    return Ok(output);
+                }
+                // warnings recorded by choices which failed don't apply to the output
+                extra.discard_warnings(warnings_start);
             }
 
-            let mut errors: Option<Vec<ValLineError>> = match self.custom_error {
-                None => Some(Vec::with_capacity(self.choices.len())),
-                _ => None,
-            };
+            let mut errors = self.new_errors();
 
             // 2nd pass: check if the value can be coerced into one of the Union types, e.g. use validate
-            for validator in &self.choices {
+            for (index, validator) in self.choices.iter().enumerate() {
                 let line_errors = match validator.validate(py, input, extra, slots, recursion_guard) {
-                    Err(ValError::LineErrors(line_errors)) => line_errors,
-                    success => return success,
+                    Err(ValError::LineErrors(line_errors)) => {
+                        extra.discard_warnings(warnings_start);
+                        line_errors
+                    }
+                    Ok(output) => {
+                        self.trace_choice(py, index, "lax")?;
+                        return Ok(output);
+                    }
+                    otherwise => return otherwise,
                 };
 
                 if let Some(ref mut errors) = errors {
-                    errors.extend(
-                        line_errors
-                            .into_iter()
-                            .map(|err| err.with_outer_location(validator.get_name().into())),
-                    );
+                    errors.push((self.choice_name(index), line_errors));
                 }
             }
 
-            Err(self.or_custom_error(errors, input))
+            Err(self.union_error(errors, input))
         }
     }
 
@@ -140,8 +248,8 @@ impl Validator for UnionValidator {
         &self.name
     }
 
-    fn ask(&self, question: &Question) -> bool {
-        self.choices.iter().all(|v| v.ask(question))
+    fn ask(&self, py: Python, question: &Question) -> bool {
+        self.choices.iter().all(|v| v.ask(py, question))
     }
 
     fn complete(&mut self, build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
@@ -159,6 +267,15 @@ enum Discriminator {
     SelfSchema,
 }
 
+impl PyGcTraverse for Discriminator {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        match self {
+            Self::Function(function) => visit.call(function),
+            Self::LookupKey(_) | Self::SelfSchema => Ok(()),
+        }
+    }
+}
+
 impl Discriminator {
     fn new(py: Python, raw: &PyAny) -> PyResult<Self> {
         if raw.is_callable() {
@@ -195,6 +312,12 @@ pub struct TaggedUnionValidator {
     name: String,
 }
 
+impl_py_gc_traverse!(TaggedUnionValidator {
+    choices,
+    discriminator,
+    custom_error
+});
+
 impl BuildValidator for TaggedUnionValidator {
     const EXPECTED_TYPE: &'static str = "tagged-union";
 
@@ -213,6 +336,7 @@ impl BuildValidator for TaggedUnionValidator {
         let mut first = true;
         let mut tags_repr = String::with_capacity(50);
         let mut descr = String::with_capacity(50);
+        let choices_config = inner_config(schema, config)?;
 
         for (key, value) in schema_choices {
             let tag: String = key.extract()?;
@@ -221,7 +345,7 @@ impl BuildValidator for TaggedUnionValidator {
                 repeat_choices_vec.push((tag, repeat_tag));
                 continue;
             }
-            let validator = build_validator(value, config, build_context)?;
+            let validator = build_validator(value, choices_config, build_context)?;
             if first {
                 first = false;
                 write!(tags_repr, "'{tag}'").unwrap();
@@ -289,6 +413,7 @@ impl Validator for TaggedUnionValidator {
         slots: &'data [CombinedValidator],
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
+        let strict = extra.strict.unwrap_or(self.strict);
         match self.discriminator {
             Discriminator::LookupKey(ref lookup_key) => {
                 macro_rules! find_validator {
@@ -297,7 +422,7 @@ impl Validator for TaggedUnionValidator {
                         // errors when getting attributes which should be "raised"
                         match lookup_key.$get_method($dict)? {
                             Some((_, value)) => {
-                                if self.strict {
+                                if strict {
                                     value.strict_str()
                                 } else {
                                     value.lax_str()
@@ -307,12 +432,13 @@ impl Validator for TaggedUnionValidator {
                         }
                     }};
                 }
-                let dict = input.validate_typed_dict(self.strict, self.from_attributes)?;
+                let dict = input.validate_typed_dict(strict, self.from_attributes)?;
                 let tag = match dict {
                     GenericMapping::PyDict(dict) => find_validator!(dict, py_get_dict_item),
                     GenericMapping::PyGetAttr(obj) => find_validator!(obj, py_get_attr),
                     GenericMapping::PyMapping(mapping) => find_validator!(mapping, py_get_mapping_item),
                     GenericMapping::JsonObject(mapping) => find_validator!(mapping, json_get),
+                    GenericMapping::StringMapping(mapping) => find_validator!(mapping, string_mapping_get),
                 }?;
                 self.find_call_validator(py, tag.as_cow()?, input, extra, slots, recursion_guard)
             }
@@ -340,8 +466,8 @@ impl Validator for TaggedUnionValidator {
         &self.name
     }
 
-    fn ask(&self, question: &Question) -> bool {
-        self.choices.values().all(|v| v.ask(question))
+    fn ask(&self, py: Python, question: &Question) -> bool {
+        self.choices.values().all(|v| v.ask(py, question))
     }
 
     fn complete(&mut self, build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
@@ -7,11 +7,13 @@ use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList};
 
 use ahash::AHashSet;
-use url::{ParseError, SyntaxViolation, Url};
+use url::{Host, ParseError, SyntaxViolation, Url};
 
 use crate::build_tools::{is_strict, py_err, SchemaDict};
 use crate::errors::{ErrorType, ValError, ValResult};
 use crate::input::Input;
+use crate::py_gc::impl_py_gc_traverse;
+use crate::questions::Question;
 use crate::recursion_guard::RecursionGuard;
 use crate::url::{schema_is_special, PyMultiHostUrl, PyUrl};
 
@@ -29,9 +31,12 @@ pub struct UrlValidator {
     default_host: Option<String>,
     default_port: Option<u16>,
     default_path: Option<String>,
+    normalization: UrlNormalization,
     name: String,
 }
 
+impl_py_gc_traverse!(UrlValidator {});
+
 impl BuildValidator for UrlValidator {
     const EXPECTED_TYPE: &'static str = "url";
 
@@ -50,6 +55,7 @@ impl BuildValidator for UrlValidator {
             default_port: schema.get_as(intern!(schema.py(), "default_port"))?,
             default_path: schema.get_as(intern!(schema.py(), "default_path"))?,
             allowed_schemes,
+            normalization: UrlNormalization::build(schema)?,
             name,
         }
         .into())
@@ -80,7 +86,9 @@ impl Validator for UrlValidator {
             &self.default_host,
             self.default_port,
             &self.default_path,
-        ) {
+        )
+        .and_then(|_| self.normalization.apply(&mut lib_url))
+        {
             Ok(()) => Ok(PyUrl::new(lib_url).into_py(py)),
             Err(error_type) => return Err(ValError::new(error_type, input)),
         }
@@ -89,6 +97,10 @@ impl Validator for UrlValidator {
     fn get_name(&self) -> &str {
         &self.name
     }
+
+    fn ask(&self, _py: Python, question: &Question) -> bool {
+        matches!(question, Question::RejectsNone)
+    }
 }
 
 impl UrlValidator {
@@ -140,9 +152,12 @@ pub struct MultiHostUrlValidator {
     default_host: Option<String>,
     default_port: Option<u16>,
     default_path: Option<String>,
+    normalization: UrlNormalization,
     name: String,
 }
 
+impl_py_gc_traverse!(MultiHostUrlValidator {});
+
 impl BuildValidator for MultiHostUrlValidator {
     const EXPECTED_TYPE: &'static str = "multi-host-url";
 
@@ -167,6 +182,7 @@ impl BuildValidator for MultiHostUrlValidator {
             default_host,
             default_port: schema.get_as(intern!(schema.py(), "default_port"))?,
             default_path: schema.get_as(intern!(schema.py(), "default_path"))?,
+            normalization: UrlNormalization::build(schema)?,
             name,
         }
         .into())
@@ -190,21 +206,47 @@ impl Validator for MultiHostUrlValidator {
                 return Err(ValError::new(ErrorType::UrlScheme { expected_schemes }, input));
             }
         }
-        match check_sub_defaults(
+        if let Err(error_type) = check_sub_defaults(
             multi_url.mut_lib_url(),
             self.host_required,
             &self.default_host,
             self.default_port,
             &self.default_path,
         ) {
-            Ok(()) => Ok(multi_url.into_py(py)),
-            Err(error_type) => return Err(ValError::new(error_type, input)),
+            return Err(ValError::new(error_type, input));
+        }
+        if let Err(error_type) = self.normalization.apply(multi_url.mut_lib_url()) {
+            return Err(ValError::new(error_type, input));
         }
+        if let Some(extra_urls) = multi_url.mut_extra_urls() {
+            for extra_url in extra_urls.iter_mut() {
+                // the other hosts need `default_port` too, e.g. `postgres://h1,h2/db` should get a port for both hosts
+                if let (Some(default_port), None) = (self.default_port, extra_url.port()) {
+                    extra_url.set_port(Some(default_port)).map_err(|_| {
+                        ValError::new(
+                            ErrorType::UrlParsing {
+                                error: ParseError::EmptyHost.to_string(),
+                            },
+                            input,
+                        )
+                    })?;
+                }
+                // only the host of the other hosts is used, the path, query and fragment come from the main URL
+                if let Err(error_type) = self.normalization.apply_host(extra_url) {
+                    return Err(ValError::new(error_type, input));
+                }
+            }
+        }
+        Ok(multi_url.into_py(py))
     }
 
     fn get_name(&self) -> &str {
         &self.name
     }
+
+    fn ask(&self, _py: Python, question: &Question) -> bool {
+        matches!(question, Question::RejectsNone)
+    }
 }
 
 impl MultiHostUrlValidator {
@@ -429,6 +471,150 @@ fn check_sub_defaults(
     Ok(())
 }
 
+/// how a trailing slash on the path of a URL is handled
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TrailingSlash {
+    Keep,
+    Strip,
+    Add,
+}
+
+/// normalisation applied to URLs after parsing so that semantically identical URLs have the same string form
+#[derive(Debug, Clone)]
+struct UrlNormalization {
+    host: bool,
+    percent_encoding: bool,
+    trailing_slash: TrailingSlash,
+}
+
+impl UrlNormalization {
+    fn build(schema: &PyDict) -> PyResult<Self> {
+        let py = schema.py();
+        let trailing_slash = match schema.get_as::<&str>(intern!(py, "trailing_slash"))? {
+            None | Some("keep") => TrailingSlash::Keep,
+            Some("strip") => TrailingSlash::Strip,
+            Some("add") => TrailingSlash::Add,
+            Some(s) => return py_err!("Invalid trailing_slash value: {}, expected 'keep', 'strip' or 'add'", s),
+        };
+        Ok(Self {
+            host: schema.get_as(intern!(py, "normalize_host"))?.unwrap_or(false),
+            percent_encoding: schema
+                .get_as(intern!(py, "normalize_percent_encoding"))?
+                .unwrap_or(false),
+            trailing_slash,
+        })
+    }
+
+    fn apply(&self, lib_url: &mut Url) -> Result<(), ErrorType> {
+        self.apply_host(lib_url)?;
+
+        if self.percent_encoding {
+            if let Some(path) = normalize_percent_encoding(lib_url.path()) {
+                lib_url.set_path(&path);
+            }
+            if let Some(query) = lib_url.query().and_then(normalize_percent_encoding) {
+                lib_url.set_query(Some(&query));
+            }
+            if let Some(fragment) = lib_url.fragment().and_then(normalize_percent_encoding) {
+                lib_url.set_fragment(Some(&fragment));
+            }
+        }
+
+        if !lib_url.cannot_be_a_base() {
+            match self.trailing_slash {
+                TrailingSlash::Keep => (),
+                // note: URLs with special schemes always have a path of at least `/`
+                TrailingSlash::Strip => {
+                    if lib_url.path().ends_with('/') {
+                        let path = lib_url.path().trim_end_matches('/').to_string();
+                        lib_url.set_path(&path);
+                    }
+                }
+                TrailingSlash::Add => {
+                    if !lib_url.path().ends_with('/') {
+                        let path = format!("{}/", lib_url.path());
+                        lib_url.set_path(&path);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// the url crate already lowercases and IDNA encodes the hosts of URLs with special schemes,
+    /// other schemes have opaque hosts which are normalised the same way here
+    fn apply_host(&self, lib_url: &mut Url) -> Result<(), ErrorType> {
+        if !self.host || schema_is_special(lib_url.scheme()) {
+            return Ok(());
+        }
+        let ascii_domain = match lib_url.host() {
+            Some(Host::Domain(domain)) if !domain.is_empty() => {
+                let idna_err = || ErrorType::UrlParsing {
+                    error: ParseError::IdnaError.to_string(),
+                };
+                let decoded = String::from_utf8(percent_decode(domain, |_| true)).map_err(|_| idna_err())?;
+                let ascii_domain = idna::domain_to_ascii(&decoded).map_err(|_| idna_err())?;
+                if ascii_domain == domain {
+                    return Ok(());
+                }
+                ascii_domain
+            }
+            _ => return Ok(()),
+        };
+        lib_url
+            .set_host(Some(&ascii_domain))
+            .map_err(|e| ErrorType::UrlParsing { error: e.to_string() })
+    }
+}
+
+/// uppercase the hex digits of percent escapes and decode escaped unreserved characters as per
+/// RFC 3986 section 6.2.2, `None` is returned if the string is already normalised
+fn normalize_percent_encoding(s: &str) -> Option<String> {
+    if !s.contains('%') {
+        return None;
+    }
+    let unreserved = |b: u8| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'.' | b'_' | b'~');
+    let normalized = String::from_utf8(percent_decode(s, unreserved)).ok()?;
+    if normalized == s {
+        None
+    } else {
+        Some(normalized)
+    }
+}
+
+/// decode percent escapes where `decode(byte)` is true, other escapes are kept with uppercase hex digits
+fn percent_decode(s: &str, decode: impl Fn(u8) -> bool) -> Vec<u8> {
+    let hex_value = |b: &u8| (*b as char).to_digit(16).map(|d| d as u8);
+    let bytes = s.as_bytes();
+    let mut output = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escape = match bytes[i] {
+            b'%' => bytes
+                .get(i + 1)
+                .and_then(hex_value)
+                .zip(bytes.get(i + 2).and_then(hex_value)),
+            _ => None,
+        };
+        match escape {
+            Some((high, low)) => {
+                let byte = (high << 4) | low;
+                if decode(byte) {
+                    output.push(byte);
+                } else {
+                    output.extend_from_slice(format!("%{byte:02X}").as_bytes());
+                }
+                i += 3;
+            }
+            None => {
+                output.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    output
+}
+
 fn get_allowed_schemas(schema: &PyDict, name: &'static str) -> PyResult<(AllowedSchemas, String)> {
     match schema.get_as::<&PyList>(intern!(schema.py(), "allowed_schemes"))? {
         Some(list) => {
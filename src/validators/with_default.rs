@@ -1,12 +1,14 @@
 use std::borrow::Cow;
 
+use pyo3::class::gc::{PyTraverseError, PyVisit};
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
-use crate::build_tools::{py_err, SchemaDict};
-use crate::errors::{ValError, ValResult};
+use crate::build_tools::{inner_config, py_err, schema_or_config_same, SchemaDict};
+use crate::errors::{LocItem, ValError, ValResult};
 use crate::input::Input;
+use crate::py_gc::{impl_py_gc_traverse, PyGcTraverse};
 use crate::questions::Question;
 use crate::recursion_guard::RecursionGuard;
 
@@ -19,6 +21,15 @@ pub enum DefaultType {
     DefaultFactory(PyObject),
 }
 
+impl PyGcTraverse for DefaultType {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        match self {
+            Self::Default(obj) | Self::DefaultFactory(obj) => visit.call(obj),
+            Self::None => Ok(()),
+        }
+    }
+}
+
 impl DefaultType {
     pub fn new(schema: &PyDict) -> PyResult<Self> {
         let py = schema.py();
@@ -54,9 +65,12 @@ pub struct WithDefaultValidator {
     default: DefaultType,
     on_error: OnError,
     validator: Box<CombinedValidator>,
+    validate_default: bool,
     name: String,
 }
 
+impl_py_gc_traverse!(WithDefaultValidator { default, validator });
+
 impl BuildValidator for WithDefaultValidator {
     const EXPECTED_TYPE: &'static str = "default";
 
@@ -82,13 +96,19 @@ impl BuildValidator for WithDefaultValidator {
         };
 
         let sub_schema: &PyAny = schema.get_as_req(intern!(schema.py(), "schema"))?;
-        let validator = Box::new(build_validator(sub_schema, config, build_context)?);
+        let validator = build_validator(sub_schema, inner_config(schema, config)?, build_context)?;
+        // without a default and with errors raised, the wrapper has no effect
+        if matches!((&default, &on_error), (DefaultType::None, OnError::Raise)) {
+            return Ok(validator);
+        }
+        let validator = Box::new(validator);
         let name = format!("{}[{}]", Self::EXPECTED_TYPE, validator.get_name());
 
         Ok(Self {
             default,
             on_error,
             validator,
+            validate_default: schema_or_config_same(schema, config, intern!(py, "validate_default"))?.unwrap_or(false),
             name,
         }
         .into())
@@ -108,7 +128,7 @@ impl Validator for WithDefaultValidator {
             Ok(v) => Ok(v),
             Err(e) => match self.on_error {
                 OnError::Raise => Err(e),
-                OnError::Default => Ok(self.default.default_value(py)?.unwrap().as_ref().clone()),
+                OnError::Default => Ok(self.default_value(py, &[], extra, slots, recursion_guard)?.unwrap()),
                 OnError::Omit => Err(ValError::Omit),
             },
         }
@@ -118,8 +138,11 @@ impl Validator for WithDefaultValidator {
         &self.name
     }
 
-    fn ask(&self, question: &Question) -> bool {
-        self.validator.ask(question)
+    fn ask(&self, py: Python, question: &Question) -> bool {
+        match question {
+            Question::RejectsNone => matches!(self.on_error, OnError::Raise) && self.validator.ask(py, question),
+            _ => self.validator.ask(py, question),
+        }
     }
 
     fn complete(&mut self, build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
@@ -128,6 +151,38 @@ impl Validator for WithDefaultValidator {
 }
 
 impl WithDefaultValidator {
+    /// The default value, with `validate_default` it's validated by the inner validator and errors are located
+    /// at `outer_loc`.
+    pub fn default_value<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        outer_loc: &[LocItem],
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, Option<PyObject>> {
+        let default = match self.default.default_value(py)? {
+            Some(default) => default.into_owned(),
+            None => return Ok(None),
+        };
+        if !self.validate_default {
+            return Ok(Some(default));
+        }
+        match self
+            .validator
+            .validate(py, default.into_ref(py), extra, slots, recursion_guard)
+        {
+            Ok(value) => Ok(Some(value)),
+            Err(ValError::LineErrors(line_errors)) => Err(ValError::LineErrors(
+                line_errors
+                    .into_iter()
+                    .map(|err| err.with_outer_location_items(outer_loc))
+                    .collect(),
+            )),
+            Err(err) => Err(err),
+        }
+    }
+
     pub fn has_default(&self) -> bool {
         !matches!(self.default, DefaultType::None)
     }
@@ -137,9 +192,17 @@ impl WithDefaultValidator {
     }
 }
 
-pub fn get_default<'a>(py: Python<'a>, validator: &'a CombinedValidator) -> PyResult<Option<Cow<'a, PyObject>>> {
+/// The default value of `validator` if it's a `WithDefaultValidator`, see `WithDefaultValidator::default_value`.
+pub fn get_default<'s, 'data>(
+    py: Python<'data>,
+    validator: &'s CombinedValidator,
+    outer_loc: &[LocItem],
+    extra: &Extra,
+    slots: &'data [CombinedValidator],
+    recursion_guard: &'s mut RecursionGuard,
+) -> ValResult<'data, Option<PyObject>> {
     if let CombinedValidator::WithDefault(validator) = validator {
-        validator.default.default_value(py)
+        validator.default_value(py, outer_loc, extra, slots, recursion_guard)
     } else {
         Ok(None)
     }
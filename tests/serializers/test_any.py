@@ -1,6 +1,8 @@
 import json
 from dataclasses import dataclass
 from datetime import date, datetime, time, timedelta
+from decimal import Decimal
+from enum import Enum
 
 import pytest
 from dirty_equals import IsList
@@ -146,6 +148,42 @@ def test_any_config_timedelta_float():
     assert s.to_json({h2: 'foo'}) == b'{"7200":"foo"}'
 
 
+class Color(Enum):
+    RED = 'red'
+    BLUE = 'blue'
+
+
+def test_any_decimal(any_serializer):
+    assert any_serializer.to_python(Decimal('1.50')) == Decimal('1.50')
+    assert any_serializer.to_python(Decimal('1.50'), mode='json') == '1.50'
+    assert any_serializer.to_json(Decimal('1.50')) == b'"1.50"'
+    assert any_serializer.to_python({Decimal('1.5'): 1}, mode='json') == {'1.5': 1}
+
+    s = SchemaSerializer(core_schema.any_schema(), config={'ser_json_decimal': 'float'})
+    assert s.to_python(Decimal('1.50'), mode='json') == 1.5
+    assert s.to_json(Decimal('1.50')) == b'1.5'
+
+
+def test_any_enum(any_serializer):
+    assert any_serializer.to_python(Color.RED) is Color.RED
+    assert any_serializer.to_python(Color.RED, mode='json') == 'red'
+    assert any_serializer.to_json(Color.RED) == b'"red"'
+    assert any_serializer.to_python({Color.BLUE: [Color.RED]}, mode='json') == {'blue': ['red']}
+    assert any_serializer.to_json({Color.BLUE: [Color.RED]}) == b'{"blue":["red"]}'
+
+
+def test_any_json_mode_is_jsonable(any_serializer):
+    value = {
+        'dt': datetime(2022, 12, 2, 12, 13, 14),
+        'bytes': b'foo',
+        'decimal': Decimal('3.14'),
+        'color': Color.BLUE,
+        'items': (timedelta(seconds=1), {Decimal('1')}),
+    }
+    output = any_serializer.to_python(value, mode='json')
+    assert json.loads(json.dumps(output)) == json.loads(any_serializer.to_json(value))
+
+
 def test_any_config_timedelta_float_faction():
     s = SchemaSerializer(core_schema.any_schema(), config={'ser_json_timedelta': 'float'})
     one_half_s = timedelta(seconds=1.5)
@@ -128,3 +128,13 @@ def test_any_bytes_base64():
     assert s.to_json(b'foobar') == b'"Zm9vYmFy"'
     assert s.to_json({b'foobar': 123}) == b'{"Zm9vYmFy":123}'
     assert s.to_python({b'foobar': 123}, mode='json') == {'Zm9vYmFy': 123}
+
+
+def test_bytes_mode_override():
+    s = SchemaSerializer(core_schema.bytes_schema())
+    assert s.to_json(b'foobar', ser_json_bytes='base64') == b'"Zm9vYmFy"'
+    assert s.to_python(b'foobar', mode='json', ser_json_bytes='base64') == 'Zm9vYmFy'
+    assert s.to_json(b'foobar') == b'"foobar"'
+
+    s = SchemaSerializer(core_schema.bytes_schema(), {'ser_json_bytes': 'base64'})
+    assert s.to_json(b'foobar', ser_json_bytes='utf8') == b'"foobar"'
@@ -1,8 +1,9 @@
+import json
 from datetime import date, datetime, time, timedelta, timezone
 
 import pytest
 
-from pydantic_core import SchemaSerializer, core_schema
+from pydantic_core import SchemaError, SchemaSerializer, SchemaValidator, core_schema
 
 
 def test_datetime():
@@ -86,6 +87,25 @@ def test_time():
     assert v.to_json(time(12, 13, 14, 123_000)) == b'"12:13:14.123"'
 
 
+@pytest.mark.parametrize(
+    'value,expected',
+    [
+        (time(12, 13, 14, tzinfo=timezone.utc), '12:13:14Z'),
+        (time(12, 13, 14, tzinfo=tz(hours=2)), '12:13:14+02:00'),
+        (time(12, 13, 14, 123_456, tzinfo=tz(hours=-2, minutes=-30)), '12:13:14.123456-02:30'),
+    ],
+)
+def test_time_json_offset(value, expected):
+    v = SchemaSerializer(core_schema.time_schema())
+    assert v.to_python(value, mode='json') == expected
+    assert v.to_json(value).decode() == f'"{expected}"'
+
+
+def test_time_round_trip_offset():
+    value = SchemaValidator(core_schema.time_schema()).validate_python('12:13:14+05:00')
+    assert SchemaSerializer(core_schema.time_schema()).to_json(value) == b'"12:13:14+05:00"'
+
+
 def test_time_key():
     v = SchemaSerializer(core_schema.dict_schema(core_schema.time_schema(), core_schema.time_schema()))
     assert v.to_python({time(12, 13, 14): time(12, 13, 14)}) == {time(12, 13, 14): time(12, 13, 14)}
@@ -99,3 +119,88 @@ def test_any_datetime_key():
     # assert v.to_python(input_value) == v
     assert v.to_python(input_value, mode='json') == {'2022-12-02T12:13:14': 1, '2022-12-02': 2, '12:13:14': 3}
     assert v.to_json(input_value) == b'{"2022-12-02T12:13:14":1,"2022-12-02":2,"12:13:14":3}'
+
+
+@pytest.mark.parametrize(
+    'mode,value,expected',
+    [
+        ('iso8601', datetime(2022, 12, 2, 12, 13, 14, 500_000), '2022-12-02T12:13:14.5'),
+        ('seconds', datetime(2022, 12, 2, 12, 13, 14, 500_000), 1669983194),
+        ('milliseconds', datetime(2022, 12, 2, 12, 13, 14, 500_000), 1669983194500),
+        ('float', datetime(2022, 12, 2, 12, 13, 14, 500_000), 1669983194.5),
+        ('seconds', datetime(2022, 12, 2, 12, 13, 14, tzinfo=timezone.utc), 1669983194),
+        ('seconds', datetime(2022, 12, 2, 14, 13, 14, tzinfo=tz(hours=2)), 1669983194),
+        ('seconds', datetime(1969, 12, 31, 23, 59, 59, 500_000), -1),
+        ('milliseconds', datetime(1969, 12, 31, 23, 59, 59, 500_000), -500),
+        ('float', datetime(1969, 12, 31, 23, 59, 59, 500_000), -0.5),
+        ('iso8601', date(2022, 12, 2), '2022-12-02'),
+        ('seconds', date(2022, 12, 2), 1669939200),
+        ('milliseconds', date(2022, 12, 2), 1669939200000),
+        ('float', date(2022, 12, 2), 1669939200.0),
+        ('iso8601', time(12, 13, 14, 500_000), '12:13:14.5'),
+        ('seconds', time(12, 13, 14, 500_000), 43994),
+        ('milliseconds', time(12, 13, 14, 500_000), 43994500),
+        ('float', time(12, 13, 14, 500_000), 43994.5),
+    ],
+)
+def test_datetime_mode(mode, value, expected):
+    schema = {datetime: core_schema.datetime_schema, date: core_schema.date_schema, time: core_schema.time_schema}[
+        type(value)
+    ]()
+    v = SchemaSerializer(schema, config={'ser_json_datetime': mode})
+    assert v.to_python(value) == value
+    assert v.to_python(value, mode='json') == expected
+    assert json.loads(v.to_json(value)) == expected
+
+    v = SchemaSerializer(core_schema.any_schema(), config={'ser_json_datetime': mode})
+    assert v.to_python(value, mode='json') == expected
+    assert json.loads(v.to_json(value)) == expected
+
+
+@pytest.mark.parametrize(
+    'mode,expected',
+    [
+        ('iso8601', {'2022-12-02T12:13:14': 1}),
+        ('seconds', {'1669983194': 1}),
+        ('milliseconds', {'1669983194000': 1}),
+        ('float', {'1669983194': 1}),
+    ],
+)
+def test_datetime_mode_key(mode, expected):
+    value = {datetime(2022, 12, 2, 12, 13, 14): 1}
+    v = SchemaSerializer(
+        core_schema.dict_schema(core_schema.datetime_schema(), core_schema.int_schema()),
+        config={'ser_json_datetime': mode},
+    )
+    assert v.to_python(value, mode='json') == expected
+    assert json.loads(v.to_json(value)) == expected
+
+    v = SchemaSerializer(core_schema.dict_schema(), config={'ser_json_datetime': mode})
+    assert v.to_python(value, mode='json') == expected
+
+
+@pytest.mark.parametrize('mode', ['seconds', 'milliseconds', 'float'])
+def test_datetime_mode_round_trip(mode):
+    value = datetime(2022, 12, 2, 12, 13, 14, 123_000)
+    v = SchemaSerializer(core_schema.datetime_schema(), config={'ser_json_datetime': mode})
+    output = SchemaValidator(core_schema.datetime_schema()).validate_json(v.to_json(value))
+    if mode == 'seconds':
+        assert output == value.replace(microsecond=0)
+    else:
+        assert output == value
+
+
+def test_datetime_mode_invalid():
+    with pytest.raises(SchemaError, match='Invalid datetime serialization mode: `foobar`'):
+        SchemaSerializer(core_schema.datetime_schema(), config={'ser_json_datetime': 'foobar'})
+
+
+def test_datetime_mode_override():
+    value = datetime(2022, 12, 2, 12, 13, 14, 500_000)
+    v = SchemaSerializer(core_schema.datetime_schema())
+    assert v.to_json(value, ser_json_datetime='milliseconds') == b'1669983194500'
+    assert v.to_python(value, mode='json', ser_json_datetime='float') == 1669983194.5
+    assert v.to_json(value) == b'"2022-12-02T12:13:14.5"'
+
+    with pytest.raises(SchemaError, match='Invalid datetime serialization mode: `foobar`'):
+        v.to_json(value, ser_json_datetime='foobar')
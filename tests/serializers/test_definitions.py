@@ -0,0 +1,37 @@
+from pydantic_core import SchemaSerializer, core_schema
+
+
+def test_linked_list():
+    s = SchemaSerializer(
+        core_schema.definitions_schema(
+            core_schema.definition_reference_schema('node'),
+            [
+                core_schema.typed_dict_schema(
+                    {
+                        'value': core_schema.typed_dict_field(core_schema.int_schema()),
+                        'next': core_schema.typed_dict_field(
+                            core_schema.nullable_schema(core_schema.definition_reference_schema('node'))
+                        ),
+                    },
+                    ref='node',
+                )
+            ],
+        )
+    )
+    value = {'value': 1, 'next': {'value': 2, 'next': None}}
+    assert s.to_python(value) == value
+    assert s.to_python(value, exclude_none=True) == {'value': 1, 'next': {'value': 2}}
+    assert s.to_json(value) == b'{"value":1,"next":{"value":2,"next":null}}'
+
+
+def test_forward_refs():
+    s = SchemaSerializer(
+        core_schema.definitions_schema(
+            core_schema.list_schema(core_schema.definition_reference_schema('b')),
+            [
+                core_schema.definition_reference_schema('c', ref='b'),
+                core_schema.bytes_schema(ref='c'),
+            ],
+        )
+    )
+    assert s.to_json([b'a', b'b']) == b'["a","b"]'
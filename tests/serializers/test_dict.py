@@ -1,4 +1,5 @@
 import json
+from datetime import date, datetime
 
 import pytest
 from dirty_equals import IsStrictDict
@@ -26,6 +27,21 @@ def test_dict_any_any():
     assert v.to_json({(1, 2): 3}) == b'{"(1, 2)":3}'
 
 
+def test_dict_typed_keys_values():
+    v = SchemaSerializer(
+        core_schema.dict_schema(core_schema.date_schema(), core_schema.datetime_schema()),
+        config={'ser_json_datetime': 'seconds'},
+    )
+    value = {date(2022, 12, 2): datetime(2022, 12, 2, 12, 13, 14)}
+    assert v.to_python(value) == value
+    assert v.to_python(value, mode='json') == {'1669939200': 1669983194}
+    assert v.to_json(value) == b'{"1669939200":1669983194}'
+
+    # values are checked against `values_schema` rather than inferred
+    with pytest.warns(UserWarning, match='Expected `datetime` but got `int` - slight slowdown possible'):
+        assert v.to_json({date(2022, 12, 2): 123}) == b'{"1669939200":123}'
+
+
 def test_include():
     s = SchemaSerializer(core_schema.dict_schema(serialization=core_schema.filter_dict_schema(include={'a', 'c'})))
 
@@ -194,6 +194,10 @@ def test_tuple_fallback():
         dict(include={0, 1}, exclude={3: {1}}, expected=['0', '1']),
         dict(include={0, 1}, exclude={1: {1}}, expected=['0', '1']),
         dict(include={0, 1}, exclude={1: None}, expected=['0']),
+        dict(include=None, exclude={'__all__'}, expected=[]),
+        dict(include=None, exclude={'__all__': None}, expected=[]),
+        dict(include={'__all__'}, exclude={1}, expected=['0', '2', '3']),
+        dict(include={'__all__': None}, exclude={1: None}, expected=['0', '2', '3']),
     ],
 )
 def test_include_exclude_args(params):
@@ -212,6 +216,11 @@ def test_include_exclude_args(params):
         dict(include=None, exclude=None, expected=[[0], [0, 1], [0, 1, 2], [0, 1, 2, 3]]),
         dict(include=None, exclude={1: {0}}, expected=[[0], [1], [0, 1, 2], [0, 1, 2, 3]]),
         dict(include={1: {0}}, exclude=None, expected=[[0]]),
+        dict(include=None, exclude={'__all__': {0}}, expected=[[], [1], [1, 2], [1, 2, 3]]),
+        # an item's own entry is merged with `__all__`
+        dict(include=None, exclude={'__all__': {0}, 1: {1}}, expected=[[], [], [1, 2], [1, 2, 3]]),
+        dict(include={'__all__': {0}}, exclude=None, expected=[[0], [0], [0], [0]]),
+        dict(include={'__all__': {0}, 3: {3}}, exclude={2: None}, expected=[[0], [0], [0, 3]]),
     ],
 )
 def test_include_exclude_args_nested(params):
@@ -224,6 +233,43 @@ def test_include_exclude_args_nested(params):
     assert json.loads(s.to_json(value, include=include, exclude=exclude)) == expected
 
 
+@pytest.mark.parametrize(
+    'params',
+    [
+        dict(include=None, exclude={'__all__': {'b'}, 0: {'a'}}, expected=[{}, {'a': 3}]),
+        dict(include={'__all__': {'b'}, 0: {'a'}}, exclude=None, expected=[{'a': 1, 'b': 2}, {'b': 4}]),
+        dict(include=None, exclude={'__all__': {'b': None}, 0: {'a': None}}, expected=[{}, {'a': 3}]),
+        # the whole item in either replaces the other
+        dict(include={'__all__': {'b'}, 0: None}, exclude=None, expected=[{'a': 1, 'b': 2}, {'b': 4}]),
+        dict(include=None, exclude={'__all__': None, 0: {'a'}}, expected=[{'b': 2}]),
+    ],
+)
+def test_include_exclude_all_merged(params):
+    s = SchemaSerializer(core_schema.list_schema(core_schema.dict_schema()))
+
+    include, exclude, expected = params['include'], params['exclude'], params['expected']
+    value = [{'a': 1, 'b': 2}, {'a': 3, 'b': 4}]
+    assert s.to_python(value, include=include, exclude=exclude) == expected
+    assert s.to_python(value, mode='json', include=include, exclude=exclude) == expected
+    assert json.loads(s.to_json(value, include=include, exclude=exclude)) == expected
+
+
+@pytest.mark.parametrize(
+    'schema_func,seq_f', [(core_schema.list_schema, as_list), (core_schema.tuple_variable_schema, as_tuple)]
+)
+def test_exclude_all_nested_field(schema_func, seq_f):
+    s = SchemaSerializer(schema_func(core_schema.dict_schema(core_schema.string_schema(), core_schema.string_schema())))
+    value = seq_f({'name': 'a', 'password': 'x'}, {'name': 'b', 'password': 'y'})
+    expected = seq_f({'name': 'a'}, {'name': 'b'})
+    assert s.to_python(value, exclude={'__all__': {'password'}}) == expected
+    assert s.to_json(value, exclude={'__all__': {'password'}}) == b'[{"name":"a"},{"name":"b"}]'
+
+    # the same applies when the items' type is inferred
+    s = SchemaSerializer(core_schema.any_schema())
+    assert s.to_python(value, exclude={'__all__': {'password'}}) == expected
+    assert s.to_json(value, exclude={'__all__': {'password'}}) == b'[{"name":"a"},{"name":"b"}]'
+
+
 def test_positional_tuple():
     s = SchemaSerializer(
         {'type': 'tuple', 'mode': 'positional', 'items_schema': [{'type': 'int'}, {'type': 'bytes'}, {'type': 'float'}]}
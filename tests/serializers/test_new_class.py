@@ -246,3 +246,60 @@ def test_exclude_unset():
     m2 = FieldsSetModel(foo=1, bar=2, spam=3, __fields_set__={'bar', 'spam', 'missing'})
     assert s.to_python(m2) == {'foo': 1, 'bar': 2, 'spam': 3}
     assert s.to_python(m2, exclude_unset=True) == {'bar': 2, 'spam': 3}
+
+
+def test_root_model():
+    class RootModel:
+        __slots__ = '__dict__', '__fields_set__'
+
+    schema = core_schema.new_class_schema(
+        RootModel, core_schema.list_schema(core_schema.bytes_schema()), root_model=True
+    )
+    v = SchemaValidator(schema)
+    s = SchemaSerializer(schema)
+    m = v.validate_python([b'a', b'b'])
+    assert s.to_python(m) == [b'a', b'b']
+    assert s.to_python(m, mode='json') == ['a', 'b']
+    assert s.to_python(m, exclude={0}) == [b'b']
+    assert s.to_json(m) == b'["a","b"]'
+    assert v.validate_json(s.to_json(m)).__dict__ == {'__root__': [b'a', b'b']}
+
+
+def test_root_model_nested():
+    class RootModel:
+        __slots__ = '__dict__', '__fields_set__'
+
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'items': core_schema.typed_dict_field(
+                    core_schema.new_class_schema(RootModel, core_schema.int_schema(), root_model=True)
+                )
+            }
+        )
+    )
+    m = RootModel()
+    m.__root__ = 123
+    assert s.to_python({'items': m}) == {'items': 123}
+    assert s.to_json({'items': m}) == b'{"items":123}'
+
+
+def test_model():
+    class MyModel:
+        __slots__ = '__dict__', '__fields_set__'
+
+    schema = core_schema.model_schema(
+        MyModel,
+        core_schema.typed_dict_schema(
+            {
+                'foo': core_schema.typed_dict_field(core_schema.int_schema()),
+                'bar': core_schema.typed_dict_field(core_schema.bytes_schema()),
+            },
+            return_fields_set=True,
+        ),
+    )
+    m = SchemaValidator(schema).validate_python({'foo': 1, 'bar': b'more'})
+    s = SchemaSerializer(schema)
+    assert s.to_python(m) == IsStrictDict(foo=1, bar=b'more')
+    assert s.to_python(m, mode='json', exclude={'foo'}) == {'bar': 'more'}
+    assert s.to_json(m) == b'{"foo":1,"bar":"more"}'
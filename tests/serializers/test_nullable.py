@@ -2,6 +2,8 @@ import pytest
 
 from pydantic_core import SchemaSerializer, core_schema
 
+from ..conftest import plain_repr
+
 
 def test_nullable():
     s = SchemaSerializer(core_schema.nullable_schema(core_schema.int_schema()))
@@ -13,3 +15,19 @@ def test_nullable():
     assert s.to_json(None) == b'null'
     with pytest.warns(UserWarning, match='Expected `int` but got `str` - slight slowdown possible'):
         assert s.to_json('aaa') == b'"aaa"'
+
+
+def test_nullable_any():
+    s = SchemaSerializer(core_schema.nullable_schema(core_schema.any_schema()))
+    assert plain_repr(s) == 'SchemaSerializer(serializer=Any(AnySerializer),slots=[])'
+    assert s.to_python(None) is None
+    assert s.to_json(None) == b'null'
+    assert s.to_json([1, 'a']) == b'[1,"a"]'
+
+
+def test_nullable_items():
+    # `None` items aren't passed to the inner serializer, so there are no fallback warnings
+    s = SchemaSerializer(core_schema.list_schema(core_schema.nullable_schema(core_schema.bytes_schema())))
+    assert s.to_python([b'a', None]) == [b'a', None]
+    assert s.to_python([b'a', None], mode='json') == ['a', None]
+    assert s.to_json([b'a', None]) == b'["a",null]'
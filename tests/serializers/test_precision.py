@@ -0,0 +1,110 @@
+import json
+from decimal import Decimal
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaSerializer, core_schema
+
+
+def precision_serializer(mode, digits=None, config=None):
+    ser_schema = {'type': 'precision', 'mode': mode}
+    if digits is not None:
+        ser_schema['digits'] = digits
+    return SchemaSerializer(core_schema.float_schema(serialization=ser_schema), config=config)
+
+
+@pytest.mark.parametrize(
+    'mode,digits,value,expected_python,expected_json',
+    [
+        ('fixed', 2, 12.300000000000001, 12.3, b'12.30'),
+        ('fixed', 2, 0.1 + 0.2, 0.3, b'0.30'),
+        ('fixed', 0, 2.5, 2.0, b'2'),
+        ('fixed', 3, -1.23456, -1.235, b'-1.235'),
+        ('fixed', 2, 5, 5.0, b'5.00'),
+        ('significant', 3, 12.3456, 12.3, b'12.3'),
+        ('significant', 2, 123456.0, 120000.0, b'1.2e+05'),
+        ('repr', None, 0.1 + 0.2, 0.30000000000000004, b'0.30000000000000004'),
+    ],
+)
+def test_float(mode, digits, value, expected_python, expected_json):
+    s = precision_serializer(mode, digits)
+    assert s.to_python(value) == expected_python
+    assert s.to_json(value) == expected_json
+    assert s.to_python(value, mode='json') == json.loads(expected_json)
+
+
+def test_float_not_finite():
+    s = precision_serializer('fixed', 2)
+    assert s.to_json(float('inf')) == b'null'
+    assert s.to_json(float('nan')) == b'null'
+
+
+@pytest.mark.parametrize(
+    'mode,digits,value,expected_python,expected_json',
+    [
+        ('fixed', 2, Decimal('12.3'), Decimal('12.30'), b'"12.30"'),
+        ('fixed', 2, Decimal('12.345'), Decimal('12.34'), b'"12.34"'),
+        ('significant', 2, Decimal('0.012345'), Decimal('0.012'), b'"0.012"'),
+        ('repr', None, Decimal('1.10'), Decimal('1.10'), b'"1.10"'),
+    ],
+)
+def test_decimal(mode, digits, value, expected_python, expected_json):
+    s = precision_serializer(mode, digits)
+    v = s.to_python(value)
+    assert isinstance(v, Decimal)
+    assert str(v) == str(expected_python)
+    assert s.to_json(value) == expected_json
+    assert s.to_python(value, mode='json') == json.loads(expected_json)
+
+
+def test_decimal_float_mode():
+    s = precision_serializer('fixed', 2, config={'ser_json_decimal': 'float'})
+    assert s.to_json(Decimal('12.3')) == b'12.30'
+    assert s.to_python(Decimal('12.3'), mode='json') == 12.3
+
+
+def test_typed_dict():
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'price': core_schema.typed_dict_field(
+                    core_schema.float_schema(serialization={'type': 'precision', 'mode': 'fixed', 'digits': 2})
+                ),
+                'ratio': core_schema.typed_dict_field(core_schema.float_schema()),
+            }
+        )
+    )
+    assert s.to_json({'price': 12.300000000000001, 'ratio': 0.5}) == b'{"price":12.30,"ratio":0.5}'
+
+
+def test_dict_keys():
+    s = SchemaSerializer(
+        core_schema.dict_schema(
+            core_schema.float_schema(serialization={'type': 'precision', 'mode': 'fixed', 'digits': 1})
+        )
+    )
+    assert s.to_json({1.23: True}) == b'{"1.2":true}'
+
+
+def test_other_types():
+    s = precision_serializer('fixed', 2)
+    with pytest.warns(UserWarning, match='Expected `precision` but got `str` - slight slowdown possible'):
+        assert s.to_json('foo') == b'"foo"'
+
+
+@pytest.mark.parametrize(
+    'mode,digits,message',
+    [
+        ('fixed', None, '`digits` is required with `fixed` precision'),
+        ('significant', 0, '`digits` must be greater than 0 with `significant` precision'),
+        ('repr', 2, '`digits` is not allowed with `repr` precision'),
+    ],
+)
+def test_invalid_schema(mode, digits, message):
+    with pytest.raises(SchemaError, match=message):
+        precision_serializer(mode, digits)
+
+
+def test_invalid_mode():
+    with pytest.raises(SchemaError):
+        precision_serializer('round', 2)
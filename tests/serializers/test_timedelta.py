@@ -32,3 +32,16 @@ def test_timedelta_key():
     assert v.to_python({timedelta(days=2, hours=3, minutes=4): 1}) == {timedelta(days=2, hours=3, minutes=4): 1}
     assert v.to_python({timedelta(days=2, hours=3, minutes=4): 1}, mode='json') == {'P2DT11040S': 1}
     assert v.to_json({timedelta(days=2, hours=3, minutes=4): 1}) == b'{"P2DT11040S":1}'
+
+
+def test_timedelta_override():
+    v = SchemaSerializer(core_schema.dict_schema(core_schema.timedelta_schema(), core_schema.timedelta_schema()))
+    value = {timedelta(seconds=4, microseconds=500_000): timedelta(minutes=1)}
+    assert v.to_json(value) == b'{"PT4.5S":"PT60S"}'
+    assert v.to_json(value, ser_json_timedelta='float') == b'{"4.5":60.0}'
+    assert v.to_python(value, mode='json', ser_json_timedelta='float') == {'4.5': 60.0}
+    # the serializer's own config is unchanged
+    assert v.to_json(value) == b'{"PT4.5S":"PT60S"}'
+
+    v = SchemaSerializer(core_schema.timedelta_schema(), config={'ser_json_timedelta': 'float'})
+    assert v.to_json(timedelta(seconds=4, microseconds=500_000), ser_json_timedelta='iso8601') == b'"PT4.5S"'
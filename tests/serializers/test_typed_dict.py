@@ -3,7 +3,7 @@ import json
 import pytest
 from dirty_equals import IsStrictDict
 
-from pydantic_core import SchemaSerializer, core_schema
+from pydantic_core import PydanticUndefined, SchemaError, SchemaSerializer, core_schema
 
 
 def test_typed_dict():
@@ -94,6 +94,39 @@ def test_include_exclude_schema():
     assert json.loads(s.to_json(value)) == {'1': 1, '3': 3}
 
 
+def test_exclude_mode():
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'handle': core_schema.typed_dict_field(core_schema.any_schema(), serialization_exclude='json'),
+                'b': core_schema.typed_dict_field(core_schema.int_schema(), serialization_exclude='python'),
+            }
+        )
+    )
+    handle = object()
+    value = {'a': 1, 'handle': handle, 'b': 2}
+    assert s.to_python(value) == {'a': 1, 'handle': handle}
+    assert s.to_python(value, mode='json') == {'a': 1, 'b': 2}
+    assert s.to_json(value) == b'{"a":1,"b":2}'
+
+
+def test_undefined_omitted():
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.nullable_schema(core_schema.int_schema())),
+                'b': core_schema.typed_dict_field(core_schema.nullable_schema(core_schema.int_schema())),
+            },
+            extra_behavior='allow',
+        )
+    )
+    value = {'a': None, 'b': PydanticUndefined, 'c': PydanticUndefined}
+    assert s.to_python(value) == {'a': None}
+    assert s.to_python(value, mode='json') == {'a': None}
+    assert s.to_json(value) == b'{"a":null}'
+
+
 def test_alias():
     s = SchemaSerializer(
         core_schema.typed_dict_schema(
@@ -118,6 +151,30 @@ def test_alias():
     assert json.loads(s.to_json(value, by_alias=False)) == IsStrictDict(cat=0, dog=1, bird=2)
 
 
+def test_alias_duplicate():
+    fields = {
+        'cat': core_schema.typed_dict_field(core_schema.int_schema(), serialization_alias='pet'),
+        'dog': core_schema.typed_dict_field(core_schema.int_schema(), serialization_alias='pet'),
+    }
+    with pytest.raises(SchemaError, match='Fields `cat` and `dog` would both be serialized as "pet" with by_alias'):
+        SchemaSerializer(core_schema.typed_dict_schema(fields))
+
+    fields = {
+        'cat': core_schema.typed_dict_field(core_schema.int_schema(), serialization_alias='dog'),
+        'dog': core_schema.typed_dict_field(core_schema.int_schema()),
+    }
+    with pytest.raises(SchemaError, match='Fields `cat` and `dog` would both be serialized as "dog" with by_alias'):
+        SchemaSerializer(core_schema.typed_dict_schema(fields))
+
+    # swapping names is fine
+    fields = {
+        'cat': core_schema.typed_dict_field(core_schema.int_schema(), serialization_alias='dog'),
+        'dog': core_schema.typed_dict_field(core_schema.int_schema(), serialization_alias='cat'),
+    }
+    s = SchemaSerializer(core_schema.typed_dict_schema(fields))
+    assert s.to_python({'cat': 1, 'dog': 2}) == {'dog': 1, 'cat': 2}
+
+
 def test_exclude_none():
     v = SchemaSerializer(
         core_schema.typed_dict_schema(
@@ -159,3 +216,125 @@ def test_exclude_default():
 
     assert v.to_json({'foo': 1, 'bar': b'[default]'}) == b'{"foo":1,"bar":"[default]"}'
     assert v.to_json({'foo': 1, 'bar': b'[default]'}, exclude_defaults=True) == b'{"foo":1}'
+
+
+@pytest.mark.parametrize(
+    'serialization_order,expected',
+    [
+        (None, ['c', 'extra', 'a', 'b']),
+        ('input', ['c', 'extra', 'a', 'b']),
+        ('schema', ['b', 'c', 'a', 'extra']),
+        ('alphabetical', ['a', 'b', 'c', 'extra']),
+    ],
+)
+def test_serialization_order(serialization_order, expected):
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'b': core_schema.typed_dict_field(core_schema.int_schema()),
+                'c': core_schema.typed_dict_field(core_schema.int_schema()),
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+            },
+            extra_behavior='allow',
+            serialization_order=serialization_order,
+        )
+    )
+    value = {'c': 3, 'extra': 4, 'a': 1, 'b': 2}
+    assert list(s.to_python(value)) == expected
+    assert list(s.to_python(value, mode='json')) == expected
+    assert list(json.loads(s.to_json(value))) == expected
+
+
+def test_serialization_order_missing_and_excluded():
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'b': core_schema.typed_dict_field(core_schema.int_schema(), serialization_alias='B'),
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'c': core_schema.typed_dict_field(core_schema.int_schema()),
+            },
+            serialization_order='alphabetical',
+        )
+    )
+    assert s.to_python({'c': 3, 'b': 2}) == IsStrictDict(B=2, c=3)
+    assert s.to_json({'c': 3, 'b': 2, 'a': 1}, exclude={'b'}) == b'{"a":1,"c":3}'
+
+
+def test_serialization_order_config():
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'b': core_schema.typed_dict_field(core_schema.int_schema()),
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+            }
+        ),
+        {'typed_dict_serialization_order': 'schema'},
+    )
+    assert s.to_json({'a': 1, 'b': 2}) == b'{"b":2,"a":1}'
+
+
+def test_serialization_order_invalid():
+    with pytest.raises(SchemaError, match='Invalid serialization_order: "random"'):
+        SchemaSerializer(core_schema.typed_dict_schema({}), {'typed_dict_serialization_order': 'random'})
+
+
+@pytest.mark.parametrize(
+    'key_transform,expected',
+    [
+        ('camel', {'fieldOne': 1, 'aLongFieldName': 2, '_private': 3, 'renamed': 4}),
+        ('pascal', {'FieldOne': 1, 'ALongFieldName': 2, '_Private': 3, 'renamed': 4}),
+        ('kebab', {'field-one': 1, 'a-long-field-name': 2, '_private': 3, 'renamed': 4}),
+    ],
+)
+def test_key_transform(key_transform, expected):
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'field_one': core_schema.typed_dict_field(core_schema.int_schema()),
+                'a_long_field_name': core_schema.typed_dict_field(core_schema.int_schema()),
+                '_private': core_schema.typed_dict_field(core_schema.int_schema()),
+                'aliased': core_schema.typed_dict_field(core_schema.int_schema(), serialization_alias='renamed'),
+            },
+            key_transform=key_transform,
+        )
+    )
+    value = {'field_one': 1, 'a_long_field_name': 2, '_private': 3, 'aliased': 4}
+    assert s.to_python(value) == expected
+    assert s.to_python(value, mode='json') == expected
+    assert json.loads(s.to_json(value)) == expected
+    # like aliases, the transform only applies with `by_alias`
+    assert s.to_python(value, by_alias=False) == value
+
+
+def test_key_transform_config():
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'field_one': core_schema.typed_dict_field(core_schema.int_schema()),
+                'extra_field': core_schema.typed_dict_field(core_schema.int_schema()),
+            },
+            extra_behavior='allow',
+        ),
+        {'typed_dict_key_transform': 'camel'},
+    )
+    # extra items aren't fields and are output as is
+    assert s.to_json({'field_one': 1, 'extra_field': 2, 'other_key': 3}) == (
+        b'{"fieldOne":1,"extraField":2,"other_key":3}'
+    )
+
+
+def test_key_transform_duplicate():
+    schema = core_schema.typed_dict_schema(
+        {
+            'foo_bar': core_schema.typed_dict_field(core_schema.int_schema()),
+            'fooBar': core_schema.typed_dict_field(core_schema.int_schema()),
+        },
+        key_transform='camel',
+    )
+    with pytest.raises(SchemaError, match='Fields `foo_bar` and `fooBar` would both be serialized as "fooBar"'):
+        SchemaSerializer(schema)
+
+
+def test_key_transform_invalid():
+    with pytest.raises(SchemaError, match='Invalid key_transform: "snake"'):
+        SchemaSerializer(core_schema.typed_dict_schema({}), {'typed_dict_key_transform': 'snake'})
@@ -78,3 +78,30 @@ def test_any():
         'https://ex.com,ex.org/path': 2,
     }
     assert s.to_json({url: 1, multi_host_url: 2}) == b'{"https://ex.com/":1,"https://ex.com,ex.org/path":2}'
+
+
+@pytest.mark.parametrize(
+    'schema,input_value',
+    [
+        (core_schema.url_schema(), 'https://example.com/foo?bar=1'),
+        (core_schema.multi_host_url_schema(), 'https://example.com,example.org/path'),
+    ],
+)
+def test_round_trip(schema, input_value):
+    v = SchemaValidator(schema)
+    s = SchemaSerializer(schema)
+
+    url = v.validate_python(input_value)
+    assert s.to_python(url, round_trip=True) is url
+    assert s.to_python(url, mode='json', round_trip=True) == str(url)
+    json_output = s.to_json(url, round_trip=True)
+    assert json_output == s.to_json(url)
+
+    url2 = v.validate_json(json_output)
+    assert type(url2) is type(url)
+    assert str(url2) == str(url)
+
+    dict_s = SchemaSerializer(core_schema.dict_schema(schema, core_schema.int_schema()))
+    dict_v = SchemaValidator(core_schema.dict_schema(schema, core_schema.int_schema()))
+    output = dict_v.validate_json(dict_s.to_json({url: 1}, round_trip=True))
+    assert [(str(k), v) for k, v in output.items()] == [(str(url), 1)]
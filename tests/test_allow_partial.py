@@ -0,0 +1,108 @@
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+@pytest.fixture(scope='module')
+def typed_dict_validator():
+    return SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.string_schema(min_length=5)),
+                'c': core_schema.typed_dict_field(core_schema.list_schema(core_schema.int_schema())),
+            }
+        )
+    )
+
+
+def test_typed_dict_python(typed_dict_validator):
+    assert typed_dict_validator.validate_python({'a': 1}, allow_partial=True) == {'a': 1}
+    assert typed_dict_validator.validate_python({'a': 1, 'b': 'x'}, allow_partial=True) == {'a': 1, 'b': 'x'}
+    assert typed_dict_validator.validate_python({'a': 1, 'c': [1, 2, 'x']}, allow_partial=True) == {'a': 1, 'c': [1, 2]}
+    # the last key is the tail, so an invalid value is left out
+    assert typed_dict_validator.validate_python({'b': 'hello', 'a': 'x'}, allow_partial=True) == {'b': 'hello'}
+
+
+def test_typed_dict_python_not_tail(typed_dict_validator):
+    with pytest.raises(ValidationError) as exc_info:
+        typed_dict_validator.validate_python({'b': 'x', 'a': 1}, allow_partial=True)
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('string_too_short', ('b',))]
+    with pytest.raises(ValidationError) as exc_info:
+        typed_dict_validator.validate_python({'a': 'x', 'b': 'hello'}, allow_partial=True)
+    assert exc_info.value.errors()[0]['loc'] == ('a',)
+
+
+def test_typed_dict_not_partial(typed_dict_validator):
+    with pytest.raises(ValidationError) as exc_info:
+        typed_dict_validator.validate_python({'a': 1})
+    assert [e['type'] for e in exc_info.value.errors()] == ['missing', 'missing']
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('{"a": 1, "b": "hel', {'a': 1, 'b': 'hel'}),
+        ('{"a": 1, "b": "hello", "c": [1, 2', {'a': 1, 'b': 'hello', 'c': [1]}),
+        ('{"a": 1, "b": "hello", "c": [1, 2]', {'a': 1, 'b': 'hello', 'c': [1, 2]}),
+        ('{"a": 1, "b', {'a': 1}),
+        ('{"a": 1, "b": ', {'a': 1}),
+        ('{"a": 12', {}),
+        ('{"a": 1, "b": "x\\u00', {'a': 1, 'b': 'x'}),
+        ('{"a": 1, "b": "x\\', {'a': 1, 'b': 'x'}),
+        ('{', {}),
+        ('{"a": 1}', {'a': 1}),
+    ],
+)
+def test_typed_dict_json(typed_dict_validator, input_value, expected):
+    assert typed_dict_validator.validate_json(input_value, allow_partial=True) == expected
+
+
+def test_json_multi_byte_char(typed_dict_validator):
+    data = '{"a": 1, "b": "café'.encode()
+    assert typed_dict_validator.validate_json(data[:-1], allow_partial=True) == {'a': 1, 'b': 'caf'}
+
+
+@pytest.mark.parametrize('input_value', ['', '{"a": 1} x', '{"a": 1}]', '{"a": tru e'])
+def test_json_invalid(typed_dict_validator, input_value):
+    with pytest.raises(ValidationError) as exc_info:
+        typed_dict_validator.validate_json(input_value, allow_partial=True)
+    assert exc_info.value.errors()[0]['type'] == 'json_invalid'
+
+
+def test_json_not_partial(typed_dict_validator):
+    with pytest.raises(ValidationError) as exc_info:
+        typed_dict_validator.validate_json('{"a": 1, "b": "hel')
+    assert exc_info.value.errors()[0]['type'] == 'json_invalid'
+
+
+def test_list():
+    v = SchemaValidator(core_schema.list_schema(core_schema.string_schema(min_length=3)))
+    assert v.validate_json('["abc", "de', allow_partial=True) == ['abc', 'de']
+    assert v.validate_python(['abc', 'de'], allow_partial=True) == ['abc', 'de']
+    with pytest.raises(ValidationError, match='String should have at least 3 characters'):
+        v.validate_python(['de', 'abc'], allow_partial=True)
+
+
+def test_nested_list():
+    v = SchemaValidator(core_schema.list_schema(core_schema.list_schema(core_schema.int_schema())))
+    assert v.validate_json('[[1, 2], [3, 4', allow_partial=True) == [[1, 2], [3]]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([[1, 'x'], [3, 'y']], allow_partial=True)
+    assert [e['loc'] for e in exc_info.value.errors()] == [(0, 1)]
+
+
+def test_dict():
+    v = SchemaValidator(core_schema.dict_schema(core_schema.string_schema(), core_schema.int_schema()))
+    assert v.validate_json('{"a": 1, "b": 2', allow_partial=True) == {'a': 1}
+    assert v.validate_python({'a': 1, 'b': 'x'}, allow_partial=True) == {'a': 1}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'x', 'b': 2}, allow_partial=True)
+    assert exc_info.value.errors()[0]['loc'] == ('a',)
+
+
+def test_tuple_positional():
+    v = SchemaValidator(core_schema.tuple_positional_schema(core_schema.int_schema(), core_schema.string_schema()))
+    assert v.validate_json('[1', allow_partial=True) == ()
+    assert v.validate_json('[1, "a', allow_partial=True) == (1, 'a')
+    assert v.validate_python([1], allow_partial=True) == (1,)
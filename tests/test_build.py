@@ -1,8 +1,12 @@
+import copy
 import pickle
 
 import pytest
 
-from pydantic_core import SchemaError, SchemaValidator
+from pydantic_core import SchemaError, SchemaSerializer, SchemaValidator, ValidationError, core_schema
+from pydantic_core._pydantic_core import validate_core_schema
+
+from .conftest import plain_repr
 
 
 def test_build_error_type():
@@ -49,6 +53,44 @@ def test_pickle(pickle_protocol: int) -> None:
     assert repr(v1) == repr(v2)
 
 
+def test_pickle_config():
+    v1 = SchemaValidator({'type': 'str'}, {'str_max_length': 3, 'title': 'Short'})
+    v2 = pickle.loads(pickle.dumps(v1))
+    assert v2.title == 'Short'
+    assert v2.validate_python('abc') == 'abc'
+    with pytest.raises(ValidationError, match='String should have at most 3 characters'):
+        v2.validate_python('abcd')
+
+
+def test_pickle_recursive():
+    v1 = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'ref': 'Branch',
+            'fields': {
+                'name': {'schema': {'type': 'str'}},
+                'sub_branch': {
+                    'schema': {'type': 'nullable', 'schema': {'type': 'recursive-ref', 'schema_ref': 'Branch'}}
+                },
+            },
+        }
+    )
+    v2 = pickle.loads(pickle.dumps(v1))
+    assert v2.validate_python({'name': 'root', 'sub_branch': {'name': 'b1', 'sub_branch': None}}) == (
+        {'name': 'root', 'sub_branch': {'name': 'b1', 'sub_branch': None}}
+    )
+    assert repr(v1) == repr(v2)
+
+
+@pytest.mark.parametrize('pickle_protocol', range(1, pickle.HIGHEST_PROTOCOL + 1))
+def test_pickle_serializer(pickle_protocol: int) -> None:
+    s1 = SchemaSerializer({'type': 'list', 'items_schema': {'type': 'bytes'}}, {'ser_json_bytes': 'base64'})
+    p = pickle.dumps(s1, protocol=pickle_protocol)
+    s2 = pickle.loads(p)
+    assert s2.to_json([b'foo']) == b'["Zm9v"]'
+    assert repr(s1) == repr(s2)
+
+
 def test_schema_recursive_error():
     schema = {'type': 'union', 'choices': []}
     schema['choices'].append({'type': 'nullable', 'schema': schema})
@@ -84,3 +126,230 @@ def test_try_self_schema_discriminator():
     """Trying to use self-schema when it shouldn't be used"""
     v = SchemaValidator({'type': 'tagged-union', 'choices': {'int': {'type': 'int'}}, 'discriminator': 'self-schema'})
     assert 'discriminator: LookupKey' in repr(v)
+
+
+def test_validate_core_schema():
+    schema = core_schema.typed_dict_schema({'x': core_schema.typed_dict_field(core_schema.int_schema())})
+    assert validate_core_schema(schema) == schema
+    # the schema is only validated, so a reference which couldn't be built is fine
+    schema = core_schema.recursive_reference_schema('missing')
+    assert validate_core_schema(schema) == schema
+    with pytest.raises(SchemaError, match="Slots Error: ref 'missing' not found"):
+        SchemaValidator(schema)
+
+
+def test_validate_core_schema_error():
+    with pytest.raises(SchemaError) as exc_info:
+        validate_core_schema({'type': 'typed-dict', 'fields': {'x': {}}})
+    assert exc_info.value.args[0] == (
+        'Invalid Schema:\n'
+        'typed-dict -> fields -> x -> schema\n'
+        '  Field required [type=missing, input_value={}, input_type=dict]'
+    )
+
+    with pytest.raises(SchemaError, match="Input tag 'foobar' found using self-schema does not match any of the"):
+        validate_core_schema({'type': 'foobar'})
+
+
+def test_repr_config():
+    v = SchemaValidator({'type': 'int'}, {'title': 'MyModel', 'max_errors': 3, 'hide_input_in_errors': True})
+    assert plain_repr(v) == (
+        'SchemaValidator(name="MyModel",validator=Int(IntValidator{strict:false,coerce_bools:false}),slots=[],'
+        'validator_name="int",'
+        'error_limits=ErrorLimits{max_errors:Some(3),group_errors:false},hide_input_in_errors=true)'
+    )
+
+
+def test_repr_config_serializer():
+    s = SchemaSerializer({'type': 'int'}, {'ser_json_timedelta': 'float'})
+    assert plain_repr(s) == (
+        'SchemaSerializer(serializer=Int(IntSerializer),slots=[],config=SerializationConfig{timedelta_mode:Float,'
+        'datetime_mode:Iso8601,bytes_mode:BytesMode{base64_config:None},decimal_mode:Str,trace:false})'
+    )
+
+
+def test_deepcopy():
+    v1 = SchemaValidator({'type': 'list', 'items_schema': {'type': 'int'}}, {'title': 'Ints'})
+    v2 = copy.deepcopy(v1)
+    assert v2 is not v1
+    assert v2.title == 'Ints'
+    assert v2.validate_python(['1', 2]) == [1, 2]
+    assert repr(v1) == repr(v2)
+
+
+def test_deepcopy_serializer():
+    s1 = SchemaSerializer({'type': 'list', 'items_schema': {'type': 'bytes'}}, {'ser_json_bytes': 'base64'})
+    s2 = copy.deepcopy(s1)
+    assert s2 is not s1
+    assert s2.to_json([b'foo']) == b'["Zm9v"]'
+    assert repr(s1) == repr(s2)
+
+
+def test_get_schema():
+    schema = {'type': 'list', 'items_schema': {'type': 'int', 'ge': 1}, 'min_length': 2}
+    v = SchemaValidator(schema, {'title': 'Ints'})
+    assert v.get_schema() == schema
+    assert v.title == 'Ints'
+    assert v.get_name() == 'list[constrained-int]'
+    v2 = SchemaValidator(v.get_schema())
+    assert v2.validate_python(['1', 2]) == [1, 2]
+    assert v2.title == 'list[constrained-int]'
+
+
+def test_get_schema_cleaned():
+    v = SchemaValidator(core_schema.nullable_schema(core_schema.int_schema(), ref='foo'))
+    assert v.get_schema() == {'type': 'nullable', 'schema': {'type': 'int'}, 'ref': 'foo'}
+    assert v.get_name() == 'nullable[int]'
+
+
+def test_get_schema_serializer():
+    schema = {'type': 'dict', 'keys_schema': {'type': 'str'}, 'values_schema': {'type': 'bytes'}}
+    s = SchemaSerializer(schema)
+    assert s.get_schema() == schema
+    assert s.title == 'dict'
+    assert SchemaSerializer(schema, {'title': 'Bytes'}).title == 'Bytes'
+
+
+def test_repeated_schema_shared():
+    name_schema = {'type': 'str', 'max_length': 5}
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'first': {'schema': dict(name_schema)},
+                'last': {'schema': dict(name_schema)},
+                'nick': {'schema': {'type': 'str', 'max_length': 6}},
+            },
+        }
+    )
+    r = plain_repr(v)
+    assert r.count('SharedRef(SharedRefValidator{validator_id:0,name:"constrained-str"') == 2
+    assert r.count('StrConstrainedValidator{') == 2
+    assert v.validate_python({'first': 'a', 'last': 'b', 'nick': 'c'}) == {'first': 'a', 'last': 'b', 'nick': 'c'}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'first': 'a', 'last': 'abcdef', 'nick': 'abcdef'})
+    assert exc_info.value.errors() == [
+        {
+            'type': 'string_too_long',
+            'loc': ('last',),
+            'msg': 'String should have at most 5 characters',
+            'input': 'abcdef',
+            'ctx': {'field_type': 'String', 'max_length': 5, 'actual_length': 6},
+        }
+    ]
+
+
+def test_nested_shared_schemas():
+    # both the outer and inner schemas are shared, and see the same input
+    inner = {'type': 'list', 'items_schema': {'type': 'int'}}
+    outer = {'type': 'nullable', 'schema': inner}
+    v = SchemaValidator({'type': 'tuple', 'mode': 'positional', 'items_schema': [outer, dict(outer), dict(inner)]})
+    assert plain_repr(v).count('SharedRef(') == 4
+    data = [1, '2']
+    assert v.validate_python((data, None, data)) == ([1, 2], None, [1, 2])
+
+
+def test_repeated_schema_with_ref_not_shared():
+    branch = {'type': 'nullable', 'schema': {'type': 'recursive-ref', 'schema_ref': 'Branch'}}
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'ref': 'Branch',
+            'fields': {'left': {'schema': branch}, 'right': {'schema': dict(branch)}},
+        }
+    )
+    assert 'SharedRef(' not in plain_repr(v)
+    assert v.validate_python({'left': {'left': None, 'right': None}, 'right': None}) == {
+        'left': {'left': None, 'right': None},
+        'right': None,
+    }
+
+
+def test_repeated_schema_shared_serializer():
+    bytes_list_schema = {'type': 'list', 'items_schema': {'type': 'bytes'}}
+    s = SchemaSerializer(
+        {
+            'type': 'typed-dict',
+            'fields': {'a': {'schema': bytes_list_schema}, 'b': {'schema': dict(bytes_list_schema)}},
+        },
+        {'ser_json_bytes': 'base64'},
+    )
+    assert plain_repr(s).count('SharedRef(SharedRefSerializer{serializer_id:0})') == 2
+    assert s.to_python({'a': [b'foo'], 'b': [b'bar']}) == {'a': [b'foo'], 'b': [b'bar']}
+    assert s.to_json({'a': [b'foo'], 'b': [b'bar']}) == b'{"a":["Zm9v"],"b":["YmFy"]}'
+
+
+def test_build_error_location():
+    schema = core_schema.typed_dict_schema(
+        {
+            'a': core_schema.typed_dict_field(core_schema.int_schema()),
+            'b': core_schema.typed_dict_field(
+                core_schema.list_schema(
+                    core_schema.union_schema(core_schema.int_schema(), core_schema.string_schema(pattern='(abc'))
+                )
+            ),
+        }
+    )
+    with pytest.raises(SchemaError) as exc_info:
+        SchemaValidator(schema)
+    assert str(exc_info.value).startswith(
+        'Error building schema at fields -> b -> schema -> items_schema -> choices -> 1:\n'
+        '  Error building "typed-dict" validator:\n'
+    )
+    assert exc_info.value.errors() == [
+        {
+            'type': 'schema_build_error',
+            'loc': ('fields', 'b', 'schema', 'items_schema', 'choices', 1),
+            'msg': (
+                'Error building "str" validator:\n'
+                '  SchemaError: regex parse error:\n    (abc\n    ^\nerror: unclosed group'
+            ),
+        }
+    ]
+
+
+def test_build_error_location_root():
+    with pytest.raises(SchemaError) as exc_info:
+        SchemaValidator(core_schema.string_schema(pattern='(abc'))
+    assert exc_info.value.errors() == [
+        {
+            'type': 'schema_build_error',
+            'loc': (),
+            'msg': (
+                'Error building "str" validator:\n'
+                '  SchemaError: regex parse error:\n    (abc\n    ^\nerror: unclosed group'
+            ),
+        }
+    ]
+
+
+def test_build_error_location_serializer():
+    schema = core_schema.dict_schema(
+        keys_schema=core_schema.string_schema(), values_schema=core_schema.literal_schema()
+    )
+    with pytest.raises(SchemaError) as exc_info:
+        SchemaSerializer(schema)
+    assert exc_info.value.errors() == [
+        {
+            'type': 'schema_build_error',
+            'loc': ('values_schema',),
+            'msg': 'Error building `literal` serializer:\n  SchemaError: "expected" should have length > 0',
+        }
+    ]
+
+
+def test_invalid_schema_errors():
+    with pytest.raises(SchemaError) as exc_info:
+        SchemaValidator({'type': 'int', 'gt': 'x'})
+    assert exc_info.value.errors() == [
+        {
+            'type': 'int_parsing',
+            'loc': ('int', 'gt'),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+
+
+def test_schema_error_from_python():
+    assert SchemaError('foobar').errors() == []
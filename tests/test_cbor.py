@@ -0,0 +1,208 @@
+import struct
+from datetime import datetime, timedelta, timezone
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+# {'id': 1, 'name': 'foo', 'tags': ['a', 'b'], 'score': 1.5, 'active': True, 'extra': None}
+USER = (
+    b'\xa6bid\x01dnamecfoodtags\x82aaabescore\xfb'
+    + struct.pack('>d', 1.5)
+    + b'factive\xf5eextra\xf6'
+)
+
+
+@pytest.mark.parametrize('input_value', [USER, bytearray(USER), memoryview(USER)])
+def test_any(input_value):
+    v = SchemaValidator(core_schema.any_schema())
+    assert v.validate_cbor(input_value) == {
+        'id': 1,
+        'name': 'foo',
+        'tags': ['a', 'b'],
+        'score': 1.5,
+        'active': True,
+        'extra': None,
+    }
+
+
+def test_typed_dict():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'id': core_schema.typed_dict_field(core_schema.int_schema()),
+                'name': core_schema.typed_dict_field(core_schema.string_schema()),
+                'tags': core_schema.typed_dict_field(core_schema.set_schema(core_schema.string_schema())),
+            }
+        )
+    )
+    assert v.validate_cbor(USER) == {'id': 1, 'name': 'foo', 'tags': {'a', 'b'}}
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (b'\x17', 23),
+        (b'\x18\xff', 255),
+        (b'\x19\xff\xff', 65535),
+        (b'\x1a\xff\xff\xff\xff', 2**32 - 1),
+        (b'\x1b\xff\xff\xff\xff\xff\xff\xff\xff', 2**64 - 1),
+        (b'\x20', -1),
+        (b'\x38\xff', -256),
+        (b'\x3b\x7f\xff\xff\xff\xff\xff\xff\xff', -(2**63)),
+        (b'\x3b\xff\xff\xff\xff\xff\xff\xff\xff', -(2**64)),
+        # bignums
+        (b'\xc2\x49\x01\x00\x00\x00\x00\x00\x00\x00\x00', 2**64),
+        (b'\xc3\x49\x01\x00\x00\x00\x00\x00\x00\x00\x00', -(2**64) - 1),
+        (b'\xc2\x41\x05', 5),
+        (b'\xc2\x40', 0),
+    ],
+)
+def test_int(input_value, expected):
+    v = SchemaValidator(core_schema.int_schema(strict=True))
+    assert v.validate_cbor(input_value) == expected
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (b'\xf9\x3e\x00', 1.5),
+        (b'\xf9\xc4\x00', -4.0),
+        (b'\xf9\x00\x01', 5.960464477539063e-08),
+        (b'\xf9\x7c\x00', float('inf')),
+        (b'\xfa' + struct.pack('>f', 0.25), 0.25),
+        (b'\xfb' + struct.pack('>d', 0.1), 0.1),
+        (b'\x03', 3.0),
+    ],
+)
+def test_float(input_value, expected):
+    v = SchemaValidator(core_schema.float_schema())
+    assert v.validate_cbor(input_value) == expected
+
+
+def test_undefined():
+    v = SchemaValidator(core_schema.nullable_schema(core_schema.int_schema()))
+    assert v.validate_cbor(b'\xf7') is None
+
+
+def test_strings():
+    v = SchemaValidator(core_schema.list_schema(core_schema.string_schema()))
+    long_str = 'x' * 300
+    data = b'\x84\x63abc\x79\x01\x2c' + long_str.encode() + b'\x62\xc3\xa9' + b'\x7f\x62ab\x61c\xff'
+    assert v.validate_cbor(data) == ['abc', long_str, 'é', 'abc']
+
+
+def test_bytes():
+    v = SchemaValidator(core_schema.any_schema())
+    assert v.validate_cbor(b'\x43a\x00b') == b'a\x00b'
+    assert v.validate_cbor(b'\x5f\x42ab\x41c\xff') == b'abc'
+
+    assert SchemaValidator(core_schema.bytes_schema()).validate_cbor(b'\x42ab') == b'ab'
+    assert SchemaValidator(core_schema.string_schema()).validate_cbor(b'\x42ab') == 'ab'
+
+
+def test_indefinite_containers():
+    v = SchemaValidator(core_schema.any_schema())
+    assert v.validate_cbor(b'\x9f\x01\x9f\x02\xff\xff') == [1, [2]]
+    assert v.validate_cbor(b'\xbf\x61a\x01\x61b\x9f\xff\xff') == {'a': 1, 'b': []}
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (b'\xc0\x74' + b'2020-09-13T12:26:40Z', datetime(2020, 9, 13, 12, 26, 40, tzinfo=timezone.utc)),
+        (
+            b'\xc0\x78\x1d' + b'2020-09-13T12:26:40.123+02:00',
+            datetime(2020, 9, 13, 12, 26, 40, 123000, tzinfo=timezone(timedelta(hours=2))),
+        ),
+        (b'\xc1\x1a' + struct.pack('>I', 1_600_000_000), datetime(2020, 9, 13, 12, 26, 40, tzinfo=timezone.utc)),
+        (
+            b'\xc1\xfb' + struct.pack('>d', 1_600_000_000.5),
+            datetime(2020, 9, 13, 12, 26, 40, 500000, tzinfo=timezone.utc),
+        ),
+        # fraction rounds up to a whole second
+        (
+            b'\xc1\xfb' + struct.pack('>d', 1_600_000_000.9999999),
+            datetime(2020, 9, 13, 12, 26, 41, tzinfo=timezone.utc),
+        ),
+        # large timestamps are seconds, not milliseconds
+        (b'\xc1\x1b' + struct.pack('>Q', 20_000_000_001), datetime(2603, 10, 11, 11, 33, 21, tzinfo=timezone.utc)),
+        (b'\xc1\x3b' + struct.pack('>Q', 62_135_596_799), datetime(1, 1, 1, tzinfo=timezone.utc)),
+    ],
+)
+def test_datetime(input_value, expected):
+    v = SchemaValidator(core_schema.datetime_schema(strict=True))
+    assert v.validate_cbor(input_value) == expected
+    assert SchemaValidator(core_schema.any_schema()).validate_cbor(input_value) == expected
+
+
+def test_unknown_tag():
+    # tag 32 (URI) is ignored and its content used as is, as is the self-described CBOR tag 55799
+    v = SchemaValidator(core_schema.string_schema())
+    assert v.validate_cbor(b'\xd8\x20\x63foo') == 'foo'
+    assert v.validate_cbor(b'\xd9\xd9\xf7\x63foo') == 'foo'
+
+
+def test_int_map_keys():
+    v = SchemaValidator(core_schema.dict_schema(core_schema.int_schema(), core_schema.string_schema()))
+    assert v.validate_cbor(b'\xa2\x01\x61a\x20\x61b') == {1: 'a', -1: 'b'}
+
+
+def test_validation_error():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_cbor(b'\x82\x01\x61x')
+    assert exc_info.value.errors() == [
+        {
+            'type': 'int_parsing',
+            'loc': (1,),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+
+
+@pytest.mark.parametrize(
+    'input_value,error',
+    [
+        (b'', 'unexpected end of data at offset 0'),
+        (b'\x82\x01', 'unexpected end of data at offset 2'),
+        (b'\x9f\x01', 'unexpected end of data at offset 2'),
+        (b'\x01\x02', 'trailing data at offset 1'),
+        (b'\x1c', 'invalid additional information 28 at offset 0'),
+        (b'\x1f', 'invalid indefinite length integer at offset 0'),
+        (b'\xff', 'unexpected break at offset 0'),
+        (b'\xf0', 'unsupported simple value at offset 0'),
+        (b'\x61\xff', 'invalid UTF-8 in string at offset 0'),
+        (b'\x7f\x41a\xff', 'invalid chunk in indefinite length string at offset 1'),
+        (b'\xa1\x80\x01', 'map keys must be strings or integers, at offset 1'),
+        (b'\xc0\x63foo', 'invalid date/time string at offset 0'),
+        (b'\xc0\x74' + b'0000-01-01T00:00:00Z', 'date/time string out of range at offset 0'),
+        (b'\xc1\x1b' + struct.pack('>Q', 253_402_300_800), 'timestamp out of range at offset 0'),
+        (b'\xc1\x3b' + struct.pack('>Q', 62_135_596_800), 'timestamp out of range at offset 0'),
+        (b'\xc2\x01', 'invalid content for tag 2 at offset 0'),
+        (b'\x81' * 200 + b'\x01', 'recursion limit exceeded'),
+    ],
+)
+def test_invalid(input_value, error):
+    v = SchemaValidator(core_schema.any_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_cbor(input_value)
+    assert exc_info.value.errors() == [
+        {
+            'type': 'cbor_invalid',
+            'loc': (),
+            'msg': f'Invalid CBOR: {error}',
+            'input': input_value,
+            'ctx': {'error': error},
+        }
+    ]
+
+
+@pytest.mark.parametrize('input_value', ['\x01', 1, [1]])
+def test_wrong_type(input_value):
+    v = SchemaValidator(core_schema.any_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_cbor(input_value)
+    assert exc_info.value.errors()[0]['type'] == 'cbor_type'
+    assert exc_info.value.errors()[0]['msg'] == 'CBOR input should be bytes, bytearray or memoryview'
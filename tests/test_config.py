@@ -1,10 +1,11 @@
+import json
 import math
 import re
 
 import pytest
 from dirty_equals import FunctionCheck, HasAttributes, IsInstance
 
-from pydantic_core import CoreConfig, SchemaValidator, ValidationError
+from pydantic_core import CoreConfig, SchemaError, SchemaValidator, ValidationError
 
 from .conftest import Err, plain_repr
 
@@ -183,14 +184,14 @@ def test_sub_model_merge():
             'loc': ('f',),
             'msg': 'String should have at most 4 characters',
             'input': 'tests',
-            'ctx': {'max_length': 4},
+            'ctx': {'field_type': 'String', 'max_length': 4, 'actual_length': 5},
         },
         {
             'type': 'string_too_short',
             'loc': ('sub_model', 'f'),
             'msg': 'String should have at least 1 characters',
             'input': '',
-            'ctx': {'min_length': 1},
+            'ctx': {'field_type': 'String', 'min_length': 1, 'actual_length': 0},
         },
     ]
 
@@ -242,3 +243,36 @@ def test_allow_inf_nan(config: CoreConfig, float_field_schema, input_value, expe
     else:
         output_dict = v.validate_python(input_value)
         assert output_dict == expected
+
+
+@pytest.mark.parametrize(
+    'cache_strings,keys_shared,values_shared',
+    [(None, False, False), ('none', False, False), ('keys', True, False), ('all', True, True)],
+)
+def test_cache_strings(cache_strings, keys_shared, values_shared):
+    config = {} if cache_strings is None else {'cache_strings': cache_strings}
+    v = SchemaValidator(
+        {
+            'type': 'list',
+            'items_schema': {'type': 'dict', 'keys_schema': {'type': 'str'}, 'values_schema': {'type': 'str'}},
+        },
+        config,
+    )
+    output = v.validate_json('[{"name": "spam"}, {"name": "spam"}]')
+    assert output == [{'name': 'spam'}, {'name': 'spam'}]
+    (first_key, first_value), (second_key, second_value) = (next(iter(d.items())) for d in output)
+    assert (first_key is second_key) == keys_shared
+    assert (first_value is second_value) == values_shared
+
+
+def test_cache_strings_invalid():
+    with pytest.raises(SchemaError, match='Invalid cache_strings: "foobar"'):
+        SchemaValidator({'type': 'str'}, {'cache_strings': 'foobar'})
+
+
+def test_cache_strings_parallel():
+    config = {'cache_strings': 'all', 'list_parallel': True}
+    v = SchemaValidator({'type': 'list', 'items_schema': {'type': 'str'}}, config)
+    output = v.validate_json(json.dumps(['spam'] * 2000))
+    assert output == ['spam'] * 2000
+    assert output[0] is output[1999]
@@ -1,8 +1,16 @@
+import json
 from decimal import Decimal
 
 import pytest
 
-from pydantic_core import PydanticCustomError, PydanticKnownError, PydanticOmit, SchemaValidator, ValidationError
+from pydantic_core import (
+    PydanticCustomError,
+    PydanticKnownError,
+    PydanticOmit,
+    SchemaValidator,
+    ValidationError,
+    core_schema,
+)
 from pydantic_core._pydantic_core import list_all_errors
 
 from .conftest import PyAndJson
@@ -160,18 +168,45 @@ def test_pydantic_error_type_raise_ctx():
     ]
 
 
+@pytest.mark.parametrize('mode', ['before', 'after', 'wrap', 'plain'])
+def test_pydantic_error_type_matches_builtin(mode):
+    def f(input_value, *args, **kwargs):
+        raise PydanticKnownError('greater_than', {'gt': 42})
+
+    if mode == 'plain':
+        function_schema = core_schema.function_plain_schema(f)
+    elif mode == 'wrap':
+        function_schema = core_schema.function_wrap_schema(f, core_schema.int_schema())
+    else:
+        function_schema = {'type': 'function', 'mode': mode, 'function': f, 'schema': core_schema.int_schema()}
+
+    v_function = SchemaValidator(core_schema.list_schema(function_schema))
+    v_builtin = SchemaValidator(core_schema.list_schema(core_schema.int_schema(gt=42)))
+
+    with pytest.raises(ValidationError) as function_exc_info:
+        v_function.validate_python([4])
+    with pytest.raises(ValidationError) as builtin_exc_info:
+        v_builtin.validate_python([4])
+
+    assert function_exc_info.value.errors() == builtin_exc_info.value.errors()
+    assert function_exc_info.value.json(include_url=True) == builtin_exc_info.value.json(include_url=True)
+
+
 all_errors = [
     ('json_invalid', 'Invalid JSON: foobar', {'error': 'foobar'}),
-    ('json_type', 'JSON input should be string, bytes or bytearray', None),
+    ('json_type', 'JSON input should be string, bytes, bytearray or memoryview', None),
     ('recursion_loop', 'Recursion error - cyclic reference detected', None),
+    ('too_many_errors', 'Too many errors, only the first 10 errors are shown', {'max_errors': 10}),
     ('dict_attributes_type', 'Input should be a valid dictionary or instance to extract fields from', None),
     ('missing', 'Field required', None),
-    ('frozen', 'Field is frozen', None),
+    ('frozen_field', 'Field is frozen', None),
     ('extra_forbidden', 'Extra inputs are not permitted', None),
+    ('alias_and_name_provided', 'Field provided by both its alias and its name', None),
     ('invalid_key', 'Keys should be strings', None),
     ('get_attribute_error', 'Error extracting attribute: foo', {'error': 'foo'}),
     ('model_class_type', 'Input should be an instance of foo', {'class_name': 'foo'}),
     ('none_required', 'Input should be None/null', None),
+    ('none_not_allowed', 'Input should not be None/null', None),
     ('bool', 'Input should be a valid boolean', None),
     ('greater_than', 'Input should be greater than 42.1', {'gt': 42.1}),
     ('greater_than', 'Input should be greater than 42.1', {'gt': '42.1'}),
@@ -194,8 +229,16 @@ all_errors = [
     ('string_sub_type', 'Input should be a string, not an instance of a subclass of str', None),
     ('string_unicode', 'Input should be a valid string, unable to parse raw data as a unicode string', None),
     ('string_pattern_mismatch', "String should match pattern 'foo'", {'pattern': 'foo'}),
-    ('string_too_short', 'String should have at least 42 characters', {'min_length': 42}),
-    ('string_too_long', 'String should have at most 42 characters', {'max_length': 42}),
+    (
+        'string_too_short',
+        'String should have at least 42 characters',
+        {'field_type': 'String', 'min_length': 42, 'actual_length': 40},
+    ),
+    (
+        'string_too_long',
+        'String should have at most 42 characters',
+        {'field_type': 'String', 'max_length': 42, 'actual_length': 50},
+    ),
     ('dict_type', 'Input should be a valid dictionary', None),
     ('mapping_type', 'Input should be a valid mapping, error: foobar', {'error': 'foobar'}),
     ('iterable_type', 'Input should be iterable', None),
@@ -203,11 +246,12 @@ all_errors = [
     ('list_type', 'Input should be a valid list/array', None),
     ('tuple_type', 'Input should be a valid tuple', None),
     ('set_type', 'Input should be a valid set', None),
+    ('set_duplicate_item', 'Input should not contain duplicate items', None),
     ('bool_type', 'Input should be a valid boolean', None),
     ('bool_parsing', 'Input should be a valid boolean, unable to interpret input', None),
     ('int_type', 'Input should be a valid integer', None),
     ('int_parsing', 'Input should be a valid integer, unable to parse string as an integer', None),
-    ('int_from_float', 'Input should be a valid integer, got a number with a fractional part', None),
+    ('int_from_float_inexact', 'Input should be a valid integer, got a number with a fractional part', None),
     ('multiple_of', 'Input should be a multiple of 42.1', {'multiple_of': 42.1}),
     ('greater_than', 'Input should be greater than 42.1', {'gt': 42.1}),
     ('greater_than_equal', 'Input should be greater than or equal to 42.1', {'ge': 42.1}),
@@ -216,8 +260,16 @@ all_errors = [
     ('float_type', 'Input should be a valid number', None),
     ('float_parsing', 'Input should be a valid number, unable to parse string as an number', None),
     ('bytes_type', 'Input should be a valid bytes', None),
-    ('bytes_too_short', 'Data should have at least 42 bytes', {'min_length': 42}),
-    ('bytes_too_long', 'Data should have at most 42 bytes', {'max_length': 42}),
+    (
+        'bytes_too_short',
+        'Data should have at least 42 bytes',
+        {'field_type': 'Bytes', 'min_length': 42, 'actual_length': 40},
+    ),
+    (
+        'bytes_too_long',
+        'Data should have at most 42 bytes',
+        {'field_type': 'Bytes', 'max_length': 42, 'actual_length': 50},
+    ),
     ('value_error', 'Value error, foobar', {'error': 'foobar'}),
     ('assertion_error', 'Assertion failed, foobar', {'error': 'foobar'}),
     ('literal_error', 'Input should be foo', {'expected': 'foo'}),
@@ -230,6 +282,8 @@ all_errors = [
     ('date_future', 'Date should be in the future', None),
     ('time_type', 'Input should be a valid time', None),
     ('time_parsing', 'Input should be in a valid time format, foobar', {'error': 'foobar'}),
+    ('time_aware', 'Time should have timezone info', None),
+    ('time_naive', 'Time should not have timezone info', None),
     ('datetime_type', 'Input should be a valid datetime', None),
     ('datetime_parsing', 'Input should be a valid datetime, foobar', {'error': 'foobar'}),
     ('datetime_object_invalid', 'Invalid datetime object, got foobar', {'error': 'foobar'}),
@@ -249,6 +303,7 @@ all_errors = [
         {'discriminator': 'bar', 'tag': 'foo', 'expected_tags': 'baz'},
     ),
     ('union_tag_not_found', 'Unable to extract tag using discriminator foo', {'discriminator': 'foo'}),
+    ('union_no_match', 'Input does not match any union member, expected int, str', {'expected': 'int, str'}),
     ('arguments_type', 'Arguments must be a tuple, list or a dictionary', None),
     ('positional_arguments_type', 'Positional arguments must be a list or tuple', None),
     ('keyword_arguments_type', 'Keyword arguments must be a dictionary', None),
@@ -262,6 +317,16 @@ all_errors = [
     ('url_syntax_violation', 'Input violated strict URL syntax rules, Foobar', {'error': 'Foobar'}),
     ('url_too_long', 'URL should have at most 42 characters', {'max_length': 42}),
     ('url_scheme', 'URL scheme should be "foo", "bar" or "spam"', {'expected_schemes': '"foo", "bar" or "spam"'}),
+    ('msgpack_invalid', 'Invalid MessagePack: foobar', {'error': 'foobar'}),
+    ('msgpack_type', 'MessagePack input should be bytes, bytearray or memoryview', None),
+    ('cbor_invalid', 'Invalid CBOR: foobar', {'error': 'foobar'}),
+    ('cbor_type', 'CBOR input should be bytes, bytearray or memoryview', None),
+    ('toml_invalid', 'Invalid TOML: foobar', {'error': 'foobar'}),
+    ('toml_type', 'TOML input should be a string', None),
+    ('yaml_invalid', 'Invalid YAML: foobar', {'error': 'foobar'}),
+    ('yaml_type', 'YAML input should be a string', None),
+    ('urlencoded_invalid', 'Invalid URL encoded data: foobar', {'error': 'foobar'}),
+    ('urlencoded_type', 'URL encoded input should be string, bytes, bytearray or memoryview', None),
 ]
 
 
@@ -279,6 +344,11 @@ def test_all_errors_covered():
     assert actual_types == listed_types
 
 
+def test_int_from_float_renamed():
+    with pytest.raises(KeyError, match="Invalid error type: 'int_from_float'"):
+        PydanticKnownError('int_from_float')
+
+
 def test_error_decimal():
     e = PydanticKnownError('greater_than', {'gt': Decimal('42.1')})
     assert e.message() == 'Input should be greater than 42.1'
@@ -332,3 +402,358 @@ def test_type_error_error():
 def test_does_not_require_context():
     with pytest.raises(TypeError, match="^'json_type' errors do not require context$"):
         PydanticKnownError('json_type', {'gt': 123})
+
+
+def test_max_errors():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()), {'max_errors': 3})
+    assert v.validate_python(['1', 2]) == [1, 2]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(['a'] * 2)
+    assert exc_info.value.error_count() == 2
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(['a'] * 10_000)
+    int_parsing_msg = 'Input should be a valid integer, unable to parse string as an integer'
+    # insert_assert(exc_info.value.errors(include_input=False))
+    assert exc_info.value.errors(include_input=False) == [
+        {'type': 'int_parsing', 'loc': (0,), 'msg': int_parsing_msg},
+        {'type': 'int_parsing', 'loc': (1,), 'msg': int_parsing_msg},
+        {'type': 'int_parsing', 'loc': (2,), 'msg': int_parsing_msg},
+        {
+            'type': 'too_many_errors',
+            'loc': (),
+            'msg': 'Too many errors, only the first 3 errors are shown',
+            'ctx': {'max_errors': 3},
+        },
+    ]
+
+
+def test_max_errors_stops_early():
+    calls = []
+
+    def f(input_value, **kwargs):
+        calls.append(input_value)
+        raise ValueError('bad')
+
+    v = SchemaValidator(core_schema.list_schema(core_schema.function_plain_schema(f)), {'max_errors': 5})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('[' + ','.join(['1'] * 100) + ']')
+    assert exc_info.value.error_count() == 6
+    # one more than max_errors is validated so we know the limit was exceeded
+    assert len(calls) == 6
+
+
+def test_max_errors_nested():
+    v = SchemaValidator(
+        core_schema.dict_schema(core_schema.string_schema(), core_schema.list_schema(core_schema.int_schema())),
+        {'max_errors': 2},
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': ['x', 'x', 'x'], 'b': ['x'], 'c': ['x']})
+    assert [e['loc'] for e in exc_info.value.errors()] == [('a', 0), ('a', 1), ()]
+
+
+def test_group_errors():
+    v = SchemaValidator(
+        core_schema.list_schema(
+            core_schema.typed_dict_schema(
+                {
+                    'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                    'b': core_schema.typed_dict_field(core_schema.int_schema(gt=0)),
+                }
+            )
+        ),
+        {'group_errors': True},
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([{'a': 'x', 'b': 0}, {'a': 'y', 'b': 1}, {'a': 'z', 'b': 0}, {'a': 1, 'b': -1}])
+    # insert_assert(exc_info.value.errors())
+    assert exc_info.value.errors() == [
+        {
+            'type': 'int_parsing',
+            'loc': ('*', 'a'),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        },
+        {
+            'type': 'greater_than',
+            'loc': ('*', 'b'),
+            'msg': 'Input should be greater than 0',
+            'input': 0,
+            'ctx': {'gt': 0},
+        },
+    ]
+
+
+def test_group_errors_single():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()), {'group_errors': True, 'max_errors': 1})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, 'x'])
+    assert exc_info.value.errors(include_input=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': (1,),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+        }
+    ]
+
+
+def test_hide_input_in_errors_config():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {'password': core_schema.typed_dict_field(core_schema.string_schema(min_length=8))}
+        ),
+        {'hide_input_in_errors': True},
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'password': 'hunter2'})
+    # insert_assert(exc_info.value.errors())
+    assert exc_info.value.errors() == [
+        {
+            'type': 'string_too_short',
+            'loc': ('password',),
+            'msg': 'String should have at least 8 characters',
+            'ctx': {'field_type': 'String', 'min_length': 8, 'actual_length': 7},
+        }
+    ]
+    assert 'hunter2' not in str(exc_info.value)
+    assert 'hunter2' not in repr(exc_info.value)
+    assert 'input_value' not in str(exc_info.value)
+    assert 'hunter2' not in exc_info.value.json()
+    assert 'input' not in exc_info.value.json()
+
+
+def test_hide_input_in_errors_json():
+    v = SchemaValidator(core_schema.int_schema(), {'hide_input_in_errors': True})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('"secret"')
+    assert exc_info.value.errors() == [
+        {
+            'type': 'int_parsing',
+            'loc': (),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+        }
+    ]
+    assert 'secret' not in str(exc_info.value)
+
+
+def test_hide_input_in_errors_field():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'username': core_schema.typed_dict_field(core_schema.string_schema(min_length=3)),
+                'token': core_schema.typed_dict_field(core_schema.int_schema(), hide_input_in_errors=True),
+            }
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'username': 'x', 'token': 'abc123'})
+    # insert_assert(exc_info.value.errors())
+    assert exc_info.value.errors() == [
+        {
+            'type': 'string_too_short',
+            'loc': ('username',),
+            'msg': 'String should have at least 3 characters',
+            'input': 'x',
+            'ctx': {'field_type': 'String', 'min_length': 3, 'actual_length': 1},
+        },
+        {
+            'type': 'int_parsing',
+            'loc': ('token',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+        },
+    ]
+    assert 'abc123' not in str(exc_info.value)
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_assignment({'username': 'foo', 'token': 1}, 'token', 'xyz789')
+    assert 'xyz789' not in str(exc_info.value)
+    assert 'input' not in exc_info.value.errors()[0]
+
+
+def test_hide_input_in_errors_wrap_validator():
+    def f(input_value, *, validator, **kwargs):
+        return validator(input_value)
+
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'token': core_schema.typed_dict_field(
+                    core_schema.function_wrap_schema(f, core_schema.int_schema()),
+                    hide_input_in_errors=True,
+                )
+            }
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'token': 'abc123'})
+    assert 'abc123' not in str(exc_info.value)
+    assert exc_info.value.errors() == [
+        {
+            'type': 'int_parsing',
+            'loc': ('token',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+        }
+    ]
+
+
+# error codes are part of the public API, existing entries must never be changed, only new entries added
+ERROR_CODES = {
+    'json_invalid': 1,
+    'json_type': 2,
+    'recursion_loop': 3,
+    'too_many_errors': 4,
+    'dict_attributes_type': 5,
+    'missing': 6,
+    'frozen_field': 7,
+    'extra_forbidden': 8,
+    'invalid_key': 9,
+    'get_attribute_error': 10,
+    'model_class_type': 11,
+    'none_required': 12,
+    'bool': 13,
+    'greater_than': 14,
+    'greater_than_equal': 15,
+    'less_than': 16,
+    'less_than_equal': 17,
+    'multiple_of': 18,
+    'finite_number': 19,
+    'too_short': 20,
+    'too_long': 21,
+    'iterable_type': 22,
+    'iteration_error': 23,
+    'string_type': 24,
+    'string_sub_type': 25,
+    'string_unicode': 26,
+    'string_too_short': 27,
+    'string_too_long': 28,
+    'string_pattern_mismatch': 29,
+    'dict_type': 30,
+    'mapping_type': 31,
+    'list_type': 32,
+    'tuple_type': 33,
+    'set_type': 34,
+    'bool_type': 35,
+    'bool_parsing': 36,
+    'int_type': 37,
+    'int_parsing': 38,
+    'int_from_float_inexact': 39,
+    'float_type': 40,
+    'float_parsing': 41,
+    'bytes_type': 42,
+    'bytes_too_short': 43,
+    'bytes_too_long': 44,
+    'value_error': 45,
+    'assertion_error': 46,
+    'literal_error': 47,
+    'date_type': 48,
+    'date_parsing': 49,
+    'date_from_datetime_parsing': 50,
+    'date_from_datetime_inexact': 51,
+    'date_past': 52,
+    'date_future': 53,
+    'time_type': 54,
+    'time_parsing': 55,
+    'time_aware': 56,
+    'time_naive': 57,
+    'datetime_type': 58,
+    'datetime_parsing': 59,
+    'datetime_object_invalid': 60,
+    'datetime_past': 61,
+    'datetime_future': 62,
+    'datetime_aware': 63,
+    'datetime_naive': 64,
+    'time_delta_type': 65,
+    'time_delta_parsing': 66,
+    'frozen_set_type': 67,
+    'is_instance_of': 68,
+    'is_subclass_of': 69,
+    'callable_type': 70,
+    'union_tag_invalid': 71,
+    'union_tag_not_found': 72,
+    'arguments_type': 73,
+    'positional_arguments_type': 74,
+    'keyword_arguments_type': 75,
+    'unexpected_keyword_argument': 76,
+    'missing_keyword_argument': 77,
+    'unexpected_positional_argument': 78,
+    'missing_positional_argument': 79,
+    'multiple_argument_values': 80,
+    'url_type': 81,
+    'url_parsing': 82,
+    'url_syntax_violation': 83,
+    'url_too_long': 84,
+    'url_scheme': 85,
+    'msgpack_invalid': 86,
+    'msgpack_type': 87,
+    'cbor_invalid': 88,
+    'cbor_type': 89,
+    'toml_invalid': 90,
+    'toml_type': 91,
+    'yaml_invalid': 92,
+    'yaml_type': 93,
+    'urlencoded_invalid': 94,
+    'urlencoded_type': 95,
+    'alias_and_name_provided': 96,
+    'union_no_match': 97,
+    'none_not_allowed': 98,
+    'set_duplicate_item': 99,
+}
+
+
+def test_error_codes_stable():
+    codes = {e['type']: e['error_code'] for e in list_all_errors()}
+    for error_type, code in ERROR_CODES.items():
+        assert codes[error_type] == code, f'the code for {error_type!r} has changed'
+    assert len(set(codes.values())) == len(codes), 'error codes are not unique'
+    new_codes = {e: c for e, c in codes.items() if e not in ERROR_CODES}
+    assert not new_codes, f'add new error codes to ERROR_CODES: {new_codes}'
+
+
+def test_error_code_in_errors():
+    def f(input_value, **kwargs):
+        raise PydanticCustomError('my_error', 'My error')
+
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.function_plain_schema(f)),
+            }
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'x', 'b': 1})
+    # insert_assert(exc_info.value.errors(include_code=True, include_input=False))
+    assert exc_info.value.errors(include_code=True, include_input=False) == [
+        {
+            'type': 'int_parsing',
+            'code': ERROR_CODES['int_parsing'],
+            'loc': ('a',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+        },
+        # custom errors have no code
+        {'type': 'my_error', 'loc': ('b',), 'msg': 'My error'},
+    ]
+    assert 'code' not in exc_info.value.errors()[0]
+    assert json.loads(exc_info.value.json(include_code=True))[0]['code'] == ERROR_CODES['int_parsing']
+
+
+@pytest.mark.parametrize(
+    'schema,input_value,error_type,field_type',
+    [
+        (core_schema.string_schema(min_length=3), 'a', 'string_too_short', 'String'),
+        (core_schema.bytes_schema(min_length=3), b'a', 'bytes_too_short', 'Bytes'),
+        (core_schema.list_schema(min_length=3), [1], 'too_short', 'List'),
+        (core_schema.set_schema(min_length=3), {1}, 'too_short', 'Set'),
+        (core_schema.dict_schema(min_length=3), {1: 1}, 'too_short', 'Dictionary'),
+    ],
+)
+def test_length_error_context(schema, input_value, error_type, field_type):
+    v = SchemaValidator(schema)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value)
+    [error] = exc_info.value.errors()
+    assert error['type'] == error_type
+    assert error['ctx'] == {'field_type': field_type, 'min_length': 3, 'actual_length': 1}
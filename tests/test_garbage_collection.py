@@ -0,0 +1,68 @@
+import gc
+import platform
+import weakref
+
+import pytest
+
+from pydantic_core import SchemaSerializer, SchemaValidator, core_schema
+
+pytestmark = pytest.mark.skipif(platform.python_implementation() == 'PyPy', reason='PyPy has a different GC')
+
+
+def test_gc_schema_validator():
+    class Model:
+        def __init__(self):
+            # the validator references a bound method of the instance which holds the validator, a reference cycle
+            self.validator = SchemaValidator(core_schema.function_plain_schema(self.validate))
+
+        def validate(self, value, **kwargs):
+            return value * 2
+
+    model = Model()
+    assert model.validator.validate_python(21) == 42
+    ref = weakref.ref(model)
+    del model
+    gc.collect()
+    assert ref() is None
+
+
+def test_gc_schema_validator_default_factory():
+    class Model:
+        def __init__(self):
+            self.validator = SchemaValidator(
+                core_schema.typed_dict_schema(
+                    {
+                        'x': core_schema.typed_dict_field(
+                            core_schema.with_default_schema(core_schema.int_schema(), default_factory=self.default)
+                        )
+                    }
+                )
+            )
+
+        def default(self):
+            return 1
+
+    model = Model()
+    assert model.validator.validate_python({}) == {'x': 1}
+    ref = weakref.ref(model)
+    del model
+    gc.collect()
+    assert ref() is None
+
+
+def test_gc_schema_serializer():
+    class Model:
+        def __init__(self):
+            self.serializer = SchemaSerializer(
+                core_schema.any_schema(serialization={'type': 'function', 'function': self.serialize})
+            )
+
+        def serialize(self, value, **kwargs):
+            return str(value)
+
+    model = Model()
+    assert model.serializer.to_python(1) == '1'
+    ref = weakref.ref(model)
+    del model
+    gc.collect()
+    assert ref() is None
@@ -1,6 +1,6 @@
 import json
 import re
-from datetime import datetime, timezone
+from datetime import datetime, timedelta, timezone
 from typing import Optional
 
 import pytest
@@ -134,6 +134,31 @@ def test_pytimedelta_as_timedelta(dt):
     assert total_seconds == pytest.approx(dt.total_seconds())
 
 
+@pytest.fixture(scope='module')
+def timedelta_schema():
+    return SchemaValidator({'type': 'timedelta'})
+
+
+@given(strategies.floats(min_value=-86_400 * 999_999_999, max_value=86_400 * 999_999_999))
+def test_timedelta_float(timedelta_schema, data):
+    expected = timedelta(seconds=data)
+    output = timedelta_schema.validate_python(data)
+    # float -> microsecond rounding may differ from python's by one microsecond
+    assert abs(output - expected) <= timedelta(microseconds=1), data
+
+
+@given(strategies.integers(min_value=-86_400 * 999_999_999, max_value=86_400 * 999_999_999))
+def test_timedelta_int(timedelta_schema, data):
+    assert timedelta_schema.validate_python(data) == timedelta(seconds=data)
+    assert timedelta_schema.validate_json(str(data)) == timedelta(seconds=data)
+
+
+@given(strategies.timedeltas())
+def test_timedelta_round_trip(timedelta_schema, data):
+    serializer = SchemaSerializer({'type': 'timedelta'})
+    assert timedelta_schema.validate_json(serializer.to_json(data)) == data
+
+
 @pytest.fixture(scope='module')
 def url_validator():
     return SchemaValidator({'type': 'url'})
@@ -1,4 +1,5 @@
 import re
+from array import array
 
 import pytest
 
@@ -16,7 +17,18 @@ def test_bool(input_value, output_value):
     assert v.validate_json(input_value) == output_value
 
 
-@pytest.mark.parametrize('input_value', ['[1, 2, 3]', b'[1, 2, 3]', bytearray(b'[1, 2, 3]')])
+@pytest.mark.parametrize(
+    'input_value',
+    [
+        '[1, 2, 3]',
+        b'[1, 2, 3]',
+        bytearray(b'[1, 2, 3]'),
+        memoryview(b'[1, 2, 3]'),
+        memoryview(bytearray(b'[1, 2, 3]')),
+        # not contiguous so can't be parsed in place
+        memoryview(b'[_1_,_2_,_3_]')[::2],
+    ],
+)
 def test_input_types(input_value):
     v = SchemaValidator({'type': 'list', 'items_schema': {'type': 'int'}})
     assert v.validate_json(input_value) == [1, 2, 3]
@@ -24,8 +36,11 @@ def test_input_types(input_value):
 
 def test_input_type_invalid():
     v = SchemaValidator({'type': 'list', 'items_schema': {'type': 'int'}})
-    with pytest.raises(ValidationError, match=r'JSON input should be string, bytes or bytearray \[type=json_type,'):
+    msg = r'JSON input should be string, bytes, bytearray or memoryview \[type=json_type,'
+    with pytest.raises(ValidationError, match=msg):
         v.validate_json([])
+    with pytest.raises(ValidationError, match=msg):
+        v.validate_json(memoryview(array('i', [1, 2, 3])))
 
 
 def test_null():
@@ -57,7 +72,7 @@ def test_bytes():
         ('"123"', 123),
         ('123.0', 123),
         ('"123.0"', 123),
-        ('123.4', Err('Input should be a valid integer, got a number with a fractional part [type=int_from_float,')),
+        ('123.4', Err('got a number with a fractional part [type=int_from_float_inexact,')),
         ('"string"', Err('Input should be a valid integer, unable to parse string as an integer [type=int_parsing,')),
     ],
 )
@@ -109,6 +124,43 @@ def test_float_no_remainder():
     assert v.validate_json('123.0') == 123
 
 
+@pytest.mark.parametrize('input_value', [12345678901234567890123, -98765432109876543210, 2**63, 2**64, -(2**63) - 1])
+@pytest.mark.parametrize('strict', [False, True])
+def test_big_int(input_value, strict):
+    v = SchemaValidator({'type': 'int', 'strict': strict})
+    output = v.validate_json(str(input_value))
+    assert output == input_value
+    assert type(output) == int
+
+
+def test_big_int_any():
+    v = SchemaValidator({'type': 'any'})
+    assert v.validate_json('{"id": 123456789012345678901234567890, "values": [9223372036854775808, 1.5]}') == {
+        'id': 123456789012345678901234567890,
+        'values': [9223372036854775808, 1.5],
+    }
+
+
+def test_big_int_constrained():
+    v = SchemaValidator({'type': 'int', 'gt': 10**18, 'multiple_of': 7})
+    assert v.validate_json(str(7 * 10**20)) == 7 * 10**20
+    with pytest.raises(ValidationError, match=r'Input should be a multiple of 7 \[type=multiple_of,'):
+        v.validate_json(str(10**21))
+    with pytest.raises(ValidationError, match=r'Input should be greater than 1000000000000000000 \[type=greater_than,'):
+        v.validate_json(str(-7 * 10**20))
+
+
+def test_big_int_float():
+    v = SchemaValidator({'type': 'float'})
+    assert v.validate_json(str(10**30)) == 1e30
+
+
+def test_big_int_literal():
+    v = SchemaValidator({'type': 'literal', 'expected': [1, 2]})
+    with pytest.raises(ValidationError, match=r'Input should be 1 or 2 \[type=literal_error,'):
+        v.validate_json(str(2**64 + 1))
+
+
 def test_error_loc():
     v = SchemaValidator(
         {
@@ -144,6 +196,46 @@ def test_dict_any_value():
     assert v.validate_json('{"1": 1, "2": "a", "3": null}') == {'1': 1, '2': 'a', '3': None}
 
 
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('{"a": "plain", "b\\u00e9": "esc\\"aped"}', {'a': 'plain', 'b\u00e9': 'esc"aped'}),
+        (b'{"a": "plain", "b\\u00e9": "esc\\"aped"}', {'a': 'plain', 'b\u00e9': 'esc"aped'}),
+        ('{"\\n": "\\ud83d\\ude00"}', {'\n': '\U0001f600'}),
+    ],
+)
+def test_escaped_strings(input_value, expected):
+    v = SchemaValidator({'type': 'dict', 'keys_schema': {'type': 'str'}, 'values_schema': {'type': 'str'}})
+    assert v.validate_json(input_value) == expected
+    assert SchemaValidator({'type': 'any'}).validate_json(input_value) == expected
+
+
+def test_repeated_keys_interned():
+    v = SchemaValidator({'type': 'any'})
+    output = v.validate_json('[{"foobar": 1, "spam": 2}, {"foobar": 3, "spam": 4}]')
+    assert output == [{'foobar': 1, 'spam': 2}, {'foobar': 3, 'spam': 4}]
+    first, second = (list(d) for d in output)
+    assert first[0] is second[0]
+    assert first[1] is second[1]
+
+
+def test_bytearray_modified_during_validation():
+    data = bytearray(b'["abc", "def"]')
+
+    def f(input_value, **kwargs):
+        data[9:12] = b'xyz'
+        return input_value
+
+    v = SchemaValidator(
+        {
+            'type': 'list',
+            'items_schema': {'type': 'function', 'mode': 'after', 'function': f, 'schema': {'type': 'str'}},
+        }
+    )
+    assert v.validate_json(data) == ['abc', 'def']
+    assert data == bytearray(b'["abc", "xyz"]')
+
+
 def test_json_invalid():
     v = SchemaValidator({'type': 'bool'})
 
@@ -155,7 +247,7 @@ def test_json_invalid():
             'loc': (),
             'msg': 'Invalid JSON: EOF while parsing a string at line 1 column 7',
             'input': '"foobar',
-            'ctx': {'error': 'EOF while parsing a string at line 1 column 7'},
+            'ctx': {'error': 'EOF while parsing a string at line 1 column 7', 'line': 1, 'column': 7, 'offset': 6},
         }
     ]
     with pytest.raises(ValidationError) as exc_info:
@@ -166,6 +258,17 @@ def test_json_invalid():
             'loc': (),
             'msg': 'Invalid JSON: trailing comma at line 3 column 3',
             'input': '[1,\n2,\n3,]',
-            'ctx': {'error': 'trailing comma at line 3 column 3'},
+            'ctx': {'error': 'trailing comma at line 3 column 3', 'line': 3, 'column': 3, 'offset': 9},
         }
     ]
+
+
+@pytest.mark.parametrize('input_value', ['{"a": 1,\n "b" 2}', b'{"a": 1,\n "b" 2}', bytearray(b'{"a": 1,\n "b" 2}')])
+def test_json_invalid_position(input_value):
+    v = SchemaValidator({'type': 'dict'})
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json(input_value)
+    ctx = exc_info.value.errors()[0]['ctx']
+    assert ctx == {'error': 'expected `:` at line 2 column 6', 'line': 2, 'column': 6, 'offset': 14}
+    assert input_value[ctx['offset'] : ctx['offset'] + 1] in ('2', b'2')
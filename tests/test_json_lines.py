@@ -0,0 +1,71 @@
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+@pytest.fixture(scope='module')
+def validator():
+    return SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'id': core_schema.typed_dict_field(core_schema.int_schema()),
+                'name': core_schema.typed_dict_field(core_schema.string_schema()),
+            }
+        )
+    )
+
+
+@pytest.mark.parametrize(
+    'input_value',
+    [
+        '{"id": 1, "name": "a"}\n{"id": "2", "name": "b"}\n',
+        b'{"id": 1, "name": "a"}\r\n{"id": "2", "name": "b"}',
+        bytearray(b'{"id": 1, "name": "a"}\n\n  \n{"id": "2", "name": "b"}\n'),
+        memoryview(b'{"id": 1, "name": "a"}\n{"id": "2", "name": "b"}'),
+    ],
+)
+def test_valid(validator: SchemaValidator, input_value):
+    assert validator.validate_json_lines(input_value) == [{'id': 1, 'name': 'a'}, {'id': 2, 'name': 'b'}]
+
+
+def test_empty(validator: SchemaValidator):
+    assert validator.validate_json_lines('') == []
+    assert validator.validate_json_lines('\n \n') == []
+
+
+def test_strict(validator: SchemaValidator):
+    with pytest.raises(ValidationError, match=r'1 -> id\n  Input should be a valid integer \[type=int_type,'):
+        validator.validate_json_lines('{"id": "1", "name": "a"}', strict=True)
+
+
+def test_line_errors(validator: SchemaValidator):
+    with pytest.raises(ValidationError) as exc_info:
+        validator.validate_json_lines('{"id": 1, "name": "a"}\n\n{"id": "x", "name": "b"}\n{"id": 3,\n{"id": 4}\n')
+    assert exc_info.value.errors() == [
+        {
+            'type': 'int_parsing',
+            'loc': (3, 'id'),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        },
+        {
+            'type': 'json_invalid',
+            'loc': (4,),
+            'msg': 'Invalid JSON: EOF while parsing a value at line 1 column 9',
+            'input': '{"id": 3,',
+            'ctx': {'error': 'EOF while parsing a value at line 1 column 9', 'line': 1, 'column': 9, 'offset': 8},
+        },
+        {'type': 'missing', 'loc': (5, 'name'), 'msg': 'Field required', 'input': {'id': 4}},
+    ]
+
+
+def test_max_errors():
+    v = SchemaValidator(core_schema.int_schema(), {'max_errors': 2})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json_lines('"a"\n"b"\n"c"\n')
+    assert [e['loc'] for e in exc_info.value.errors()] == [(1,), (2,), ()]
+
+
+def test_invalid_type(validator: SchemaValidator):
+    with pytest.raises(ValidationError, match=r'JSON input should be string, bytes, bytearray or memoryview'):
+        validator.validate_json_lines([])
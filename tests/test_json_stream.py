@@ -0,0 +1,107 @@
+import io
+import json
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+@pytest.fixture(scope='module')
+def int_validator():
+    return SchemaValidator(core_schema.int_schema())
+
+
+@pytest.mark.parametrize('readable', [io.StringIO(' [1, "2", 3 ,4]\n'), io.BytesIO(b' [1, "2", 3 ,4]\n')])
+def test_array(int_validator: SchemaValidator, readable):
+    iterator = int_validator.validate_json_stream(readable)
+    assert iterator.index == 0
+    assert list(iterator) == [1, 2, 3, 4]
+    assert iterator.index == 4
+
+
+def test_empty_array(int_validator: SchemaValidator):
+    assert list(int_validator.validate_json_stream(io.StringIO('[ ]'))) == []
+
+
+def test_strict(int_validator: SchemaValidator):
+    iterator = int_validator.validate_json_stream(io.StringIO('[1, "2"]'), strict=True)
+    assert next(iterator) == 1
+    with pytest.raises(ValidationError, match=r'1\n  Input should be a valid integer \[type=int_type,'):
+        next(iterator)
+
+
+def test_ndjson(int_validator: SchemaValidator):
+    assert list(int_validator.validate_json_stream(io.StringIO('1\n2\n\n"3"\n'), ndjson=True)) == [1, 2, 3]
+
+
+def test_nested_values():
+    v = SchemaValidator(core_schema.typed_dict_schema({'a': core_schema.typed_dict_field(core_schema.any_schema())}))
+    stream = io.StringIO('[{"a": [1, {"b": "]}"}]}, {"a": "\\"["}, {"a": null}]')
+    assert list(v.validate_json_stream(stream)) == [{'a': [1, {'b': ']}'}]}, {'a': '"['}, {'a': None}]
+
+
+def test_large_stream():
+    """Items span many reads of the stream"""
+    v = SchemaValidator(core_schema.typed_dict_schema({'a': core_schema.typed_dict_field(core_schema.int_schema())}))
+    data = json.dumps([{'a': i, 'padding': '\\"]' * i} for i in range(1000)])
+    assert sum(item['a'] for item in v.validate_json_stream(io.StringIO(data))) == sum(range(1000))
+
+
+def test_item_errors(int_validator: SchemaValidator):
+    iterator = int_validator.validate_json_stream(io.StringIO('[1, "x", {"a": 2}, 4, [5'))
+    assert next(iterator) == 1
+    with pytest.raises(ValidationError) as exc_info:
+        next(iterator)
+    assert exc_info.value.errors() == [
+        {
+            'type': 'int_parsing',
+            'loc': (1,),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+    with pytest.raises(ValidationError, match=r'2\n  Input should be a valid integer \[type=int_type,'):
+        next(iterator)
+    # iteration continues after an invalid item
+    assert next(iterator) == 4
+    with pytest.raises(ValidationError) as exc_info:
+        next(iterator)
+    assert exc_info.value.errors() == [
+        {
+            'type': 'json_invalid',
+            'loc': (4,),
+            'msg': 'Invalid JSON: EOF while parsing a list at line 1 column 2',
+            'input': '[5',
+            'ctx': {'error': 'EOF while parsing a list at line 1 column 2', 'line': 1, 'column': 2, 'offset': 1},
+        }
+    ]
+    assert list(iterator) == []
+
+
+@pytest.mark.parametrize(
+    'input_value,error',
+    [
+        ('', 'EOF while parsing a list'),
+        ('[1, 2', 'EOF while parsing a list'),
+        ('{"a": 1}', 'expected `[` at the start of the stream'),
+        ('[1 2]', 'expected `,` or `]`'),
+        ('[1,]', 'trailing comma'),
+        ('[1] x', 'trailing characters'),
+    ],
+)
+def test_invalid_stream(int_validator: SchemaValidator, input_value, error):
+    stream = io.StringIO(input_value)
+    with pytest.raises(ValidationError) as exc_info:
+        list(int_validator.validate_json_stream(stream))
+    assert exc_info.value.errors() == [
+        {'type': 'json_invalid', 'loc': (), 'msg': f'Invalid JSON: {error}', 'input': stream, 'ctx': {'error': error}}
+    ]
+
+
+def test_read_wrong_type(int_validator: SchemaValidator):
+    class BadReadable:
+        def read(self, size):
+            return [1, 2]
+
+    with pytest.raises(TypeError, match='read\\(\\) should return str or bytes, not list'):
+        list(int_validator.validate_json_stream(BadReadable()))
@@ -1,11 +1,18 @@
+import copy
+import json
+import pickle
 import re
 import sys
+from datetime import datetime
 from pathlib import Path
 
 import pytest
 
 from pydantic_core import core_schema
 from pydantic_core._pydantic_core import (
+    PydanticCustomError,
+    PydanticUndefined,
+    PydanticUndefinedType,
     SchemaError,
     SchemaValidator,
     ValidationError,
@@ -29,6 +36,16 @@ def test_build_profile():
     assert build_profile in ('debug', 'release')
 
 
+def test_undefined():
+    assert repr(PydanticUndefined) == 'PydanticUndefined'
+    assert isinstance(PydanticUndefined, PydanticUndefinedType)
+    assert copy.copy(PydanticUndefined) is PydanticUndefined
+    assert copy.deepcopy({'a': PydanticUndefined})['a'] is PydanticUndefined
+    assert pickle.loads(pickle.dumps(PydanticUndefined)) is PydanticUndefined
+    with pytest.raises(TypeError, match='No constructor defined'):
+        PydanticUndefinedType()
+
+
 def test_schema_error():
     err = SchemaError('test')
     assert isinstance(err, Exception)
@@ -48,7 +65,7 @@ def test_validation_error():
         == exc_info.value.errors(include_context=False)
         == [
             {
-                'type': 'int_from_float',
+                'type': 'int_from_float_inexact',
                 'loc': (),
                 'msg': 'Input should be a valid integer, got a number with a fractional part',
                 'input': 1.5,
@@ -85,6 +102,178 @@ def test_validation_error_include_context():
     ]
 
 
+def test_validation_error_include_input_url():
+    v = SchemaValidator({'type': 'list', 'max_length': 2})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, 2, 3])
+
+    minor_version = '.'.join(__version__.split('.')[:2])
+    # insert_assert(exc_info.value.errors(include_input=False, include_url=True))
+    assert exc_info.value.errors(include_input=False, include_url=True) == [
+        {
+            'type': 'too_long',
+            'loc': (),
+            'msg': 'List should have at most 2 items after validation, not 3',
+            'ctx': {'field_type': 'List', 'max_length': 2, 'actual_length': 3},
+            'url': f'https://errors.pydantic.dev/{minor_version}/v/too_long',
+        }
+    ]
+    # insert_assert(exc_info.value.errors(include_context=False, include_input=False))
+    assert exc_info.value.errors(include_context=False, include_input=False) == [
+        {'type': 'too_long', 'loc': (), 'msg': 'List should have at most 2 items after validation, not 3'}
+    ]
+
+
+def test_validation_error_custom_error_no_url():
+    def f(input_value, **kwargs):
+        raise PydanticCustomError('my_error', 'this is a custom error')
+
+    v = SchemaValidator(core_schema.function_plain_schema(f))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(1)
+
+    # insert_assert(exc_info.value.errors(include_url=True))
+    assert exc_info.value.errors(include_url=True) == [
+        {'type': 'my_error', 'loc': (), 'msg': 'this is a custom error', 'input': 1}
+    ]
+
+
+def test_validation_error_json():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.list_schema(core_schema.int_schema(), max_length=2)),
+                'b': core_schema.typed_dict_field(core_schema.date_schema()),
+            }
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': [1, 2, 3], 'b': datetime(2022, 1, 1, 12)})
+
+    # insert_assert(json.loads(exc_info.value.json()))
+    assert json.loads(exc_info.value.json()) == [
+        {
+            'type': 'too_long',
+            'loc': ['a'],
+            'msg': 'List should have at most 2 items after validation, not 3',
+            'input': [1, 2, 3],
+            'ctx': {'field_type': 'List', 'max_length': 2, 'actual_length': 3},
+        },
+        {
+            'type': 'date_from_datetime_inexact',
+            'loc': ['b'],
+            'msg': 'Datetimes provided to dates should have zero time - e.g. be exact dates',
+            'input': '2022-01-01T12:00:00',
+        },
+    ]
+    assert json.loads(exc_info.value.json(include_context=False, include_input=False)) == [
+        {'type': 'too_long', 'loc': ['a'], 'msg': 'List should have at most 2 items after validation, not 3'},
+        {
+            'type': 'date_from_datetime_inexact',
+            'loc': ['b'],
+            'msg': 'Datetimes provided to dates should have zero time - e.g. be exact dates',
+        },
+    ]
+
+
+def test_validation_error_json_indent_url():
+    v = SchemaValidator({'type': 'int'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('x')
+
+    minor_version = '.'.join(__version__.split('.')[:2])
+    assert exc_info.value.json(indent=2, include_url=True) == (
+        '[\n'
+        '  {\n'
+        '    "type": "int_parsing",\n'
+        '    "loc": [],\n'
+        '    "msg": "Input should be a valid integer, unable to parse string as an integer",\n'
+        '    "input": "x",\n'
+        f'    "url": "https://errors.pydantic.dev/{minor_version}/v/int_parsing"\n'
+        '  }\n'
+        ']'
+    )
+
+
+def test_validation_error_json_unknown_input():
+    class Foobar:
+        def __repr__(self):
+            return 'Foobar()'
+
+    v = SchemaValidator({'type': 'int'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(Foobar())
+
+    # insert_assert(json.loads(exc_info.value.json()))
+    assert json.loads(exc_info.value.json()) == [
+        {'type': 'int_type', 'loc': [], 'msg': 'Input should be a valid integer', 'input': 'Foobar()'}
+    ]
+
+
+def test_validation_error_translator():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.string_schema(max_length=3)),
+            }
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'x', 'b': 'abcd'})
+
+    calls = []
+    translations = {
+        'int_parsing': 'Die Eingabe sollte eine gültige Ganzzahl sein',
+        'string_too_long': 'Die Zeichenkette sollte höchstens {max_length} Zeichen haben',
+    }
+
+    def translator(error_type, context):
+        calls.append((error_type, context))
+        return translations[error_type].format(**(context or {}))
+
+    # insert_assert(exc_info.value.errors(translator=translator))
+    assert exc_info.value.errors(translator=translator) == [
+        {'type': 'int_parsing', 'loc': ('a',), 'msg': 'Die Eingabe sollte eine gültige Ganzzahl sein', 'input': 'x'},
+        {
+            'type': 'string_too_long',
+            'loc': ('b',),
+            'msg': 'Die Zeichenkette sollte höchstens 3 Zeichen haben',
+            'input': 'abcd',
+            'ctx': {'field_type': 'String', 'max_length': 3, 'actual_length': 4},
+        },
+    ]
+    assert calls == [
+        ('int_parsing', None),
+        ('string_too_long', {'field_type': 'String', 'max_length': 3, 'actual_length': 4}),
+    ]
+    assert json.loads(exc_info.value.json(include_input=False, translator=translator)) == [
+        {'type': 'int_parsing', 'loc': ['a'], 'msg': 'Die Eingabe sollte eine gültige Ganzzahl sein'},
+        {
+            'type': 'string_too_long',
+            'loc': ['b'],
+            'msg': 'Die Zeichenkette sollte höchstens 3 Zeichen haben',
+            'ctx': {'field_type': 'String', 'max_length': 3, 'actual_length': 4},
+        },
+    ]
+
+
+def test_validation_error_translator_fallback():
+    v = SchemaValidator(core_schema.int_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('x')
+    # returning None uses the default message
+    assert exc_info.value.errors(translator=lambda error_type, context: None) == exc_info.value.errors()
+
+    def bad_translator(error_type, context):
+        raise RuntimeError('translation failed')
+
+    with pytest.raises(RuntimeError, match='translation failed'):
+        exc_info.value.errors(translator=bad_translator)
+    with pytest.raises(ValueError, match='translation failed'):
+        exc_info.value.json(translator=bad_translator)
+
+
 def test_custom_title():
     v = SchemaValidator({'type': 'int'}, {'title': 'MyInt'})
     with pytest.raises(ValidationError) as exc_info:
@@ -93,6 +282,26 @@ def test_custom_title():
     assert exc_info.value.title == 'MyInt'
 
 
+def test_schema_title():
+    v = SchemaValidator({'type': 'date', 'title': 'Start Date'})
+    with pytest.raises(ValidationError, match='^1 validation error for Start Date\n'):
+        v.validate_python('foobar')
+
+    # the config title takes precedence
+    v = SchemaValidator({'type': 'date', 'title': 'Start Date'}, {'title': 'MyDate'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('foobar')
+    assert exc_info.value.title == 'MyDate'
+
+
+def test_schema_metadata():
+    metadata = {'json_schema_extra': {'examples': [1, 2]}}
+    v = SchemaValidator({'type': 'int', 'metadata': metadata})
+    assert v.validate_python('1') == 1
+    # metadata isn't used by pydantic-core, but is available to other tools
+    assert v.get_schema()['metadata'] == metadata
+
+
 def test_validation_error_multiple():
     class MyModel:
         # this is not required, but it avoids `__fields_set__` being included in `__dict__`
@@ -156,23 +365,38 @@ def test_all_errors():
     assert errors[:3] == [
         {
             'type': 'json_invalid',
+            'error_code': 1,
             'message_template': 'Invalid JSON: {error}',
             'example_message': 'Invalid JSON: ',
+            'context_fields': ['error'],
             'example_context': {'error': ''},
         },
         {
             'type': 'json_type',
-            'message_template': 'JSON input should be string, bytes or bytearray',
-            'example_message': 'JSON input should be string, bytes or bytearray',
+            'error_code': 2,
+            'message_template': 'JSON input should be string, bytes, bytearray or memoryview',
+            'example_message': 'JSON input should be string, bytes, bytearray or memoryview',
+            'context_fields': [],
             'example_context': None,
         },
         {
             'type': 'recursion_loop',
+            'error_code': 3,
             'message_template': 'Recursion error - cyclic reference detected',
             'example_message': 'Recursion error - cyclic reference detected',
+            'context_fields': [],
             'example_context': None,
         },
     ]
+    # string and bytes length errors carry extra detail which isn't in their message
+    length_errors = {'string_too_short', 'string_too_long', 'bytes_too_short', 'bytes_too_long'}
+    for error in errors:
+        # every context field is used in the message template, some placeholders like `expected_plural` are derived
+        placeholders = set(re.findall(r'{(\w+)}', error['message_template']))
+        if error['type'] in length_errors:
+            placeholders |= {'field_type', 'actual_length'}
+        assert set(error['context_fields']) <= placeholders, error['type']
+
     error_types = [e['type'] for e in errors]
     if error_types != list(core_schema.ErrorType.__args__):
         literal = ''.join(f'\n    {e!r},' for e in error_types)
@@ -0,0 +1,177 @@
+import struct
+from datetime import datetime, timezone
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+# {'id': 1, 'name': 'foo', 'tags': ['a', 'b'], 'score': 1.5, 'active': True, 'extra': None}
+USER = (
+    b'\x86\xa2id\x01\xa4name\xa3foo\xa4tags\x92\xa1a\xa1b\xa5score\xcb'
+    + struct.pack('>d', 1.5)
+    + b'\xa6active\xc3\xa5extra\xc0'
+)
+
+
+@pytest.mark.parametrize('input_value', [USER, bytearray(USER), memoryview(USER)])
+def test_any(input_value):
+    v = SchemaValidator(core_schema.any_schema())
+    assert v.validate_msgpack(input_value) == {
+        'id': 1,
+        'name': 'foo',
+        'tags': ['a', 'b'],
+        'score': 1.5,
+        'active': True,
+        'extra': None,
+    }
+
+
+def test_typed_dict():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'id': core_schema.typed_dict_field(core_schema.int_schema()),
+                'name': core_schema.typed_dict_field(core_schema.string_schema()),
+                'tags': core_schema.typed_dict_field(core_schema.set_schema(core_schema.string_schema())),
+            }
+        )
+    )
+    assert v.validate_msgpack(USER) == {'id': 1, 'name': 'foo', 'tags': {'a', 'b'}}
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (b'\x7f', 127),
+        (b'\xe0', -32),
+        (b'\xcc\xff', 255),
+        (b'\xcd\xff\xff', 65535),
+        (b'\xce\xff\xff\xff\xff', 2**32 - 1),
+        (b'\xcf\xff\xff\xff\xff\xff\xff\xff\xff', 2**64 - 1),
+        (b'\xd0\x80', -128),
+        (b'\xd1\x80\x00', -(2**15)),
+        (b'\xd2\x80\x00\x00\x00', -(2**31)),
+        (b'\xd3\x80\x00\x00\x00\x00\x00\x00\x00', -(2**63)),
+    ],
+)
+def test_int(input_value, expected):
+    v = SchemaValidator(core_schema.int_schema(strict=True))
+    assert v.validate_msgpack(input_value) == expected
+
+
+def test_float():
+    v = SchemaValidator(core_schema.float_schema())
+    assert v.validate_msgpack(b'\xca' + struct.pack('>f', 0.25)) == 0.25
+    assert v.validate_msgpack(b'\x03') == 3.0
+
+
+def test_strings():
+    v = SchemaValidator(core_schema.list_schema(core_schema.string_schema()))
+    long_str = 'x' * 300
+    data = b'\x93\xd9\x03abc\xda\x01\x2c' + long_str.encode() + b'\xdb\x00\x00\x00\x02\xc3\xa9'
+    assert v.validate_msgpack(data) == ['abc', long_str, 'é']
+
+
+def test_bin():
+    v = SchemaValidator(core_schema.any_schema())
+    assert v.validate_msgpack(b'\xc4\x03a\x00b') == b'a\x00b'
+    assert v.validate_msgpack(b'\xc5\x00\x01x') == b'x'
+
+    assert SchemaValidator(core_schema.bytes_schema()).validate_msgpack(b'\xc4\x02ab') == b'ab'
+    # bin is decoded to str as python bytes would be
+    assert SchemaValidator(core_schema.string_schema()).validate_msgpack(b'\xc4\x02ab') == 'ab'
+    with pytest.raises(ValidationError, match='Input should be a valid string, unable to parse raw data as a unicode'):
+        SchemaValidator(core_schema.string_schema()).validate_msgpack(b'\xc4\x01\xff')
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        # timestamp 32
+        (b'\xd6\xff' + struct.pack('>I', 1_600_000_000), datetime(2020, 9, 13, 12, 26, 40, tzinfo=timezone.utc)),
+        # timestamp 64
+        (
+            b'\xd7\xff' + struct.pack('>Q', (123_456_000 << 34) | 1_600_000_000),
+            datetime(2020, 9, 13, 12, 26, 40, 123_456, tzinfo=timezone.utc),
+        ),
+        # timestamp 96
+        (
+            b'\xc7\x0c\xff' + struct.pack('>Iq', 500_000, -1),
+            datetime(1969, 12, 31, 23, 59, 59, 500, tzinfo=timezone.utc),
+        ),
+        # large timestamps are still seconds, not milliseconds
+        (
+            b'\xc7\x0c\xff' + struct.pack('>Iq', 0, 20_000_000_001),
+            datetime(2603, 10, 11, 11, 33, 21, tzinfo=timezone.utc),
+        ),
+        (b'\xc7\x0c\xff' + struct.pack('>Iq', 0, -62_135_596_800), datetime(1, 1, 1, tzinfo=timezone.utc)),
+        (
+            b'\xc7\x0c\xff' + struct.pack('>Iq', 999_999_000, 253_402_300_799),
+            datetime(9999, 12, 31, 23, 59, 59, 999_999, tzinfo=timezone.utc),
+        ),
+    ],
+)
+def test_timestamp(input_value, expected):
+    v = SchemaValidator(core_schema.datetime_schema(strict=True))
+    assert v.validate_msgpack(input_value) == expected
+    assert SchemaValidator(core_schema.any_schema()).validate_msgpack(input_value) == expected
+
+
+def test_int_map_keys():
+    v = SchemaValidator(core_schema.dict_schema(core_schema.int_schema(), core_schema.string_schema()))
+    assert v.validate_msgpack(b'\x82\x01\xa1a\xff\xa1b') == {1: 'a', -1: 'b'}
+
+
+def test_validation_error():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_msgpack(b'\x92\x01\xa1x')
+    assert exc_info.value.errors() == [
+        {
+            'type': 'int_parsing',
+            'loc': (1,),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+
+
+@pytest.mark.parametrize(
+    'input_value,error',
+    [
+        (b'', 'unexpected end of data at offset 0'),
+        (b'\x92\x01', 'unexpected end of data at offset 2'),
+        (b'\xdd\xff\xff\xff\xff', 'unexpected end of data at offset 5'),
+        (b'\x01\x02', 'trailing data at offset 1'),
+        (b'\xc1', 'invalid marker 0xc1 at offset 0'),
+        (b'\xa1\xff', 'invalid UTF-8 in string at offset 1'),
+        (b'\x81\x90\x01', 'map keys must be strings or integers, at offset 1'),
+        (b'\xd4\x01\x00', 'unsupported extension type 1 at offset 1'),
+        (b'\xd5\xff\x00\x00', 'invalid timestamp length 2 at offset 1'),
+        (b'\xc7\x0c\xff' + struct.pack('>Iq', 0, 253_402_300_800), 'timestamp out of range at offset 2'),
+        (b'\xc7\x0c\xff' + struct.pack('>Iq', 0, -62_135_596_801), 'timestamp out of range at offset 2'),
+        (b'\x91' * 200 + b'\x01', 'recursion limit exceeded'),
+    ],
+)
+def test_invalid(input_value, error):
+    v = SchemaValidator(core_schema.any_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_msgpack(input_value)
+    assert exc_info.value.errors() == [
+        {
+            'type': 'msgpack_invalid',
+            'loc': (),
+            'msg': f'Invalid MessagePack: {error}',
+            'input': input_value,
+            'ctx': {'error': error},
+        }
+    ]
+
+
+@pytest.mark.parametrize('input_value', ['\x01', 1, [1]])
+def test_wrong_type(input_value):
+    v = SchemaValidator(core_schema.any_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_msgpack(input_value)
+    assert exc_info.value.errors()[0]['type'] == 'msgpack_type'
+    assert exc_info.value.errors()[0]['msg'] == 'MessagePack input should be bytes, bytearray or memoryview'
@@ -0,0 +1,51 @@
+import pytest
+from dirty_equals import IsInt
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+def test_profile_stats():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'name': core_schema.typed_dict_field(core_schema.string_schema()),
+                'address': core_schema.typed_dict_field(
+                    core_schema.typed_dict_schema(
+                        {
+                            'city': core_schema.typed_dict_field(core_schema.string_schema()),
+                            'zip': core_schema.typed_dict_field(core_schema.int_schema()),
+                        }
+                    )
+                ),
+            }
+        ),
+        {'profile': True},
+    )
+    assert v.profile_stats() == [
+        {'loc': ('name',), 'calls': 0, 'time_ns': 0},
+        {'loc': ('address',), 'calls': 0, 'time_ns': 0},
+        {'loc': ('address', 'city'), 'calls': 0, 'time_ns': 0},
+        {'loc': ('address', 'zip'), 'calls': 0, 'time_ns': 0},
+    ]
+    for _ in range(3):
+        v.validate_python({'name': 'Alice', 'address': {'city': 'London', 'zip': 123}})
+    # fields are counted whether or not validation succeeds
+    with pytest.raises(ValidationError):
+        v.validate_python({'name': 'Bob', 'address': {'city': 'Paris', 'zip': 'wrong'}})
+
+    stats = v.profile_stats()
+    assert stats == [
+        {'loc': ('name',), 'calls': 4, 'time_ns': IsInt(ge=0)},
+        {'loc': ('address',), 'calls': 4, 'time_ns': IsInt(ge=0)},
+        {'loc': ('address', 'city'), 'calls': 4, 'time_ns': IsInt(ge=0)},
+        {'loc': ('address', 'zip'), 'calls': 4, 'time_ns': IsInt(ge=0)},
+    ]
+    # time for the outer field includes the nested fields
+    assert stats[1]['time_ns'] >= stats[2]['time_ns'] + stats[3]['time_ns']
+
+
+def test_profile_stats_disabled():
+    v = SchemaValidator(core_schema.typed_dict_schema({'x': core_schema.typed_dict_field(core_schema.int_schema())}))
+    assert v.validate_python({'x': 1}) == {'x': 1}
+    with pytest.raises(ValueError, match=r'profile_stats\(\) requires the validator to be built with `profile=True`'):
+        v.profile_stats()
@@ -172,6 +172,11 @@ def args(*args, **kwargs):
             args(MyModel, {'type': 'int'}),
             {'type': 'new-class', 'cls': MyModel, 'schema': {'type': 'int'}},
         ],
+        [
+            core_schema.model_schema,
+            args(MyModel, {'type': 'int'}, revalidate_instances='always'),
+            {'type': 'model', 'cls': MyModel, 'schema': {'type': 'int'}, 'revalidate_instances': 'always'},
+        ],
         [core_schema.arguments_parameter, args('foo', {'type': 'int'}), {'name': 'foo', 'schema': {'type': 'int'}}],
         [
             core_schema.arguments_schema,
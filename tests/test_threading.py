@@ -0,0 +1,99 @@
+"""
+Validators and serializers hold no per-call state, so the same instance can be used from many threads at once,
+python functions called during validation give other threads a chance to run part way through.
+"""
+import time
+from concurrent.futures import ThreadPoolExecutor
+
+import pytest
+
+from pydantic_core import SchemaSerializer, SchemaValidator, ValidationError, core_schema
+
+THREADS = 8
+
+
+def slow_double(value, **kwargs):
+    # sleep to release the GIL so threads interleave
+    time.sleep(0)
+    return value * 2
+
+
+@pytest.fixture(scope='module')
+def validator():
+    return SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'ref': 'Branch',
+            'fields': {
+                'width': {'schema': core_schema.function_after_schema(core_schema.int_schema(), slow_double)},
+                'name': {'schema': {'type': 'str'}},
+                'sub_branch': {
+                    'schema': {
+                        'type': 'default',
+                        'schema': {'type': 'nullable', 'schema': {'type': 'recursive-ref', 'schema_ref': 'Branch'}},
+                        'default': None,
+                    }
+                },
+            },
+        },
+        {'cache_strings': 'all'},
+    )
+
+
+def validate_many(validator: SchemaValidator, thread_id: int):
+    for i in range(100):
+        input_value = {'width': i, 'name': f'thread {thread_id}', 'sub_branch': {'width': thread_id, 'name': 'x'}}
+        assert validator.validate_python(input_value) == {
+            'width': i * 2,
+            'name': f'thread {thread_id}',
+            'sub_branch': {'width': thread_id * 2, 'name': 'x', 'sub_branch': None},
+        }
+        json_input = f'{{"width": {i}, "name": "thread {thread_id}"}}'
+        output = validator.validate_json(json_input)
+        assert output == {'width': i * 2, 'name': f'thread {thread_id}', 'sub_branch': None}
+        with pytest.raises(ValidationError, match=r'width\n  Input should be a valid integer'):
+            validator.validate_python({'width': 'wrong', 'name': f'thread {thread_id}'})
+    return thread_id
+
+
+def test_validate_threads(validator):
+    with ThreadPoolExecutor(max_workers=THREADS) as pool:
+        results = list(pool.map(validate_many, [validator] * THREADS, range(THREADS)))
+    assert results == list(range(THREADS))
+
+
+def test_validate_recursion_threads(validator):
+    """
+    the recursion guard is created for each call, so cyclic input in one thread can't affect other threads
+    """
+
+    def validate_cyclic(thread_id: int):
+        data = {'width': thread_id, 'name': 'cyclic'}
+        data['sub_branch'] = data
+        for _ in range(50):
+            with pytest.raises(ValidationError, match='Recursion error - cyclic reference detected'):
+                validator.validate_python(data)
+        validate_many(validator, thread_id)
+        return thread_id
+
+    with ThreadPoolExecutor(max_workers=THREADS) as pool:
+        results = list(pool.map(validate_cyclic, range(THREADS)))
+    assert results == list(range(THREADS))
+
+
+def test_serialize_threads():
+    s = SchemaSerializer(
+        core_schema.list_schema(
+            core_schema.any_schema(serialization={'type': 'function', 'function': slow_double}),
+        )
+    )
+
+    def serialize_many(thread_id: int):
+        for i in range(100):
+            assert s.to_python([i, thread_id]) == [i * 2, thread_id * 2]
+            assert s.to_json([i, thread_id]) == f'[{i * 2},{thread_id * 2}]'.encode()
+        return thread_id
+
+    with ThreadPoolExecutor(max_workers=THREADS) as pool:
+        results = list(pool.map(serialize_many, range(THREADS)))
+    assert results == list(range(THREADS))
@@ -0,0 +1,123 @@
+from datetime import date, datetime, time, timedelta, timezone
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+pytestmark = pytest.mark.skipif(
+    not hasattr(SchemaValidator, 'validate_toml'), reason='pydantic-core built without the "toml" feature'
+)
+
+CONFIG = """\
+title = "example"
+debug = true
+ratio = 0.5
+
+[server]
+host = "localhost"
+ports = [8000, 8001]
+started = 2020-09-13T12:26:40+02:00
+
+[[users]]
+name = "alice"
+
+[[users]]
+name = "bob"
+"""
+
+
+def test_any():
+    v = SchemaValidator(core_schema.any_schema())
+    assert v.validate_toml(CONFIG) == {
+        'title': 'example',
+        'debug': True,
+        'ratio': 0.5,
+        'server': {
+            'host': 'localhost',
+            'ports': [8000, 8001],
+            'started': datetime(2020, 9, 13, 12, 26, 40, tzinfo=timezone(timedelta(hours=2))),
+        },
+        'users': [{'name': 'alice'}, {'name': 'bob'}],
+    }
+
+
+def test_typed_dict():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'title': core_schema.typed_dict_field(core_schema.string_schema()),
+                'server': core_schema.typed_dict_field(
+                    core_schema.typed_dict_schema(
+                        {
+                            'ports': core_schema.typed_dict_field(core_schema.tuple_variable_schema()),
+                            'started': core_schema.typed_dict_field(core_schema.datetime_schema(strict=True)),
+                        }
+                    )
+                ),
+            }
+        )
+    )
+    assert v.validate_toml(CONFIG) == {
+        'title': 'example',
+        'server': {
+            'ports': (8000, 8001),
+            'started': datetime(2020, 9, 13, 12, 26, 40, tzinfo=timezone(timedelta(hours=2))),
+        },
+    }
+
+
+def test_local_datetimes():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'dt': core_schema.typed_dict_field(core_schema.datetime_schema(strict=True)),
+                'd': core_schema.typed_dict_field(core_schema.date_schema(strict=True)),
+                't': core_schema.typed_dict_field(core_schema.time_schema(strict=True)),
+            }
+        )
+    )
+    assert v.validate_toml('dt = 2020-01-02T03:04:05\nd = 2020-01-02\nt = 03:04:05') == {
+        'dt': datetime(2020, 1, 2, 3, 4, 5),
+        'd': date(2020, 1, 2),
+        't': time(3, 4, 5),
+    }
+
+
+def test_validation_error():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {'server': core_schema.typed_dict_field(core_schema.dict_schema(values_schema=core_schema.int_schema()))}
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_toml('[server]\nport = "x"')
+    assert exc_info.value.errors() == [
+        {
+            'type': 'int_parsing',
+            'loc': ('server', 'port'),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+
+
+def test_invalid():
+    v = SchemaValidator(core_schema.any_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_toml('a = ')
+    errors = exc_info.value.errors()
+    assert len(errors) == 1
+    assert errors[0]['type'] == 'toml_invalid'
+    assert errors[0]['msg'].startswith('Invalid TOML: ')
+    assert 'line 1' in errors[0]['ctx']['error']
+
+
+def test_wrong_type():
+    v = SchemaValidator(core_schema.any_schema())
+    with pytest.raises(ValidationError, match='TOML input should be a string'):
+        v.validate_toml(b'a = 1')
+
+
+def test_validate_format():
+    v = SchemaValidator(core_schema.dict_schema(values_schema=core_schema.int_schema()))
+    assert v.validate('a = 1\nb = 2', format='toml') == {'a': 1, 'b': 2}
@@ -0,0 +1,103 @@
+import logging
+import os
+from contextlib import contextmanager
+
+import pytest
+
+from pydantic_core import SchemaSerializer, SchemaValidator, core_schema
+
+int_or_str_schema = core_schema.union_schema(core_schema.int_schema(), core_schema.string_schema())
+
+
+class ListHandler(logging.Handler):
+    def __init__(self):
+        super().__init__(level=logging.DEBUG)
+        self.messages = []
+
+    def emit(self, record):
+        self.messages.append((record.name, record.levelno, record.getMessage()))
+
+
+@contextmanager
+def capture_logs():
+    logger = logging.getLogger('pydantic_core')
+    handler = ListHandler()
+    old_level = logger.level
+    logger.addHandler(handler)
+    logger.setLevel(logging.DEBUG)
+    try:
+        yield handler.messages
+    finally:
+        logger.removeHandler(handler)
+        logger.setLevel(old_level)
+
+
+@contextmanager
+def trace_env(value):
+    old_value = os.environ.get('PYDANTIC_CORE_TRACE')
+    os.environ['PYDANTIC_CORE_TRACE'] = value
+    try:
+        yield
+    finally:
+        if old_value is None:
+            del os.environ['PYDANTIC_CORE_TRACE']
+        else:
+            os.environ['PYDANTIC_CORE_TRACE'] = old_value
+
+
+def test_union_trace():
+    v = SchemaValidator(int_or_str_schema, {'trace': True})
+    with capture_logs() as messages:
+        assert v.validate_python('hello') == 'hello'
+        assert v.validate_python(b'hello') == 'hello'
+        assert v.validate_python(123) == 123
+    assert messages == [
+        ('pydantic_core', logging.DEBUG, 'union[int,str]: selected `str` in strict mode'),
+        ('pydantic_core', logging.DEBUG, 'union[int,str]: selected `str` in lax mode'),
+        ('pydantic_core', logging.DEBUG, 'union[int,str]: selected `int` in strict mode'),
+    ]
+
+
+def test_union_trace_strict():
+    v = SchemaValidator(int_or_str_schema, {'trace': True})
+    with capture_logs() as messages:
+        assert v.validate_python('hello', strict=True) == 'hello'
+    assert messages == [('pydantic_core', logging.DEBUG, 'union[int,str]: selected `str` in strict mode')]
+
+
+def test_no_trace_by_default():
+    v = SchemaValidator(int_or_str_schema)
+    with capture_logs() as messages:
+        assert v.validate_python('hello') == 'hello'
+    assert messages == []
+
+
+@pytest.mark.parametrize('env_value,expected', [('1', True), ('true', True), ('0', False), ('false', False)])
+def test_trace_env_var(env_value, expected):
+    with trace_env(env_value):
+        v = SchemaValidator(int_or_str_schema)
+    with capture_logs() as messages:
+        assert v.validate_python(123) == 123
+    assert bool(messages) == expected
+
+
+def test_trace_config_overrides_env_var():
+    with trace_env('1'):
+        v = SchemaValidator(int_or_str_schema, {'trace': False})
+    with capture_logs() as messages:
+        assert v.validate_python(123) == 123
+    assert messages == []
+
+
+def test_serializer_fallback_trace():
+    s = SchemaSerializer(core_schema.int_schema(), {'trace': True})
+    with capture_logs() as messages:
+        with pytest.warns(UserWarning, match='Expected `int` but got `str` - slight slowdown possible'):
+            assert s.to_python('hello') == 'hello'
+    assert messages == [
+        (
+            'pydantic_core',
+            logging.DEBUG,
+            'Expected `int` but got `str` - slight slowdown possible, falling back to type inference',
+        )
+    ]
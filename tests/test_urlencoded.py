@@ -0,0 +1,130 @@
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('', {}),
+        ('a=1&b=2', {'a': '1', 'b': '2'}),
+        ('a=1&a=2&a=3', {'a': ['1', '2', '3']}),
+        ('a[]=1', {'a': ['1']}),
+        ('a[]=1&a[]=2', {'a': ['1', '2']}),
+        ('a=1&a[]=2', {'a': ['1', '2']}),
+        ('a[b]=1&a[c]=2', {'a': {'b': '1', 'c': '2'}}),
+        ('a[b][c]=1&a[b][d]=2', {'a': {'b': {'c': '1', 'd': '2'}}}),
+        ('a[b][]=1&a[b][]=2', {'a': {'b': ['1', '2']}}),
+        ('a[b]=1&a[b]=2', {'a': {'b': ['1', '2']}}),
+        (
+            'users[][name]=alice&users[][age]=30&users[][name]=bob',
+            {'users': [{'name': 'alice', 'age': '30'}, {'name': 'bob'}]},
+        ),
+        ('a[][]=1&a[][]=2', {'a': [['1'], ['2']]}),
+        ('name=John+Doe&email=a%40example.com&empty=', {'name': 'John Doe', 'email': 'a@example.com', 'empty': ''}),
+        ('a%5Bb%5D=1', {'a': {'b': '1'}}),
+        ('flag', {'flag': ''}),
+        # keys which aren't of the bracketed form are used as is
+        ('[a]=1&a[b=2&a[b]c=3&a]=4', {'[a]': '1', 'a[b': '2', 'a[b]c': '3', 'a]': '4'}),
+    ],
+)
+def test_any(input_value, expected):
+    v = SchemaValidator(core_schema.any_schema())
+    assert v.validate_urlencoded(input_value) == expected
+    assert v.validate_urlencoded(input_value.encode()) == expected
+
+
+@pytest.mark.parametrize('input_value', [b'a=1', bytearray(b'a=1'), memoryview(b'a=1')])
+def test_bytes_like(input_value):
+    v = SchemaValidator(core_schema.any_schema())
+    assert v.validate_urlencoded(input_value) == {'a': '1'}
+
+
+def test_typed_dict():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'name': core_schema.typed_dict_field(core_schema.string_schema()),
+                'age': core_schema.typed_dict_field(core_schema.int_schema()),
+                'tags': core_schema.typed_dict_field(core_schema.list_schema(core_schema.string_schema())),
+                'address': core_schema.typed_dict_field(
+                    core_schema.typed_dict_schema(
+                        {'city': core_schema.typed_dict_field(core_schema.string_schema())}
+                    )
+                ),
+                'subscribe': core_schema.typed_dict_field(core_schema.bool_schema()),
+            }
+        )
+    )
+    assert v.validate_urlencoded('name=alice&age=30&tags=a&tags=b&address[city]=Paris&subscribe=on') == {
+        'name': 'alice',
+        'age': 30,
+        'tags': ['a', 'b'],
+        'address': {'city': 'Paris'},
+        'subscribe': True,
+    }
+
+
+def test_validation_error():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {'ids': core_schema.typed_dict_field(core_schema.list_schema(core_schema.int_schema()))}
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_urlencoded('ids[]=1&ids[]=x')
+    assert exc_info.value.errors() == [
+        {
+            'type': 'int_parsing',
+            'loc': ('ids', 1),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+
+
+@pytest.mark.parametrize(
+    'input_value,key', [('a=1&a[b]=2', 'a'), ('a[b]=1&a=2', 'a'), ('a[]=1&a[b]=2', 'a'), ('a[b]=1&a[b][c]=2', 'b')]
+)
+def test_conflict(input_value, key):
+    v = SchemaValidator(core_schema.any_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_urlencoded(input_value)
+    assert exc_info.value.errors() == [
+        {
+            'type': 'urlencoded_invalid',
+            'loc': (),
+            'msg': f'Invalid URL encoded data: conflicting values for key `{key}`',
+            'input': input_value,
+            'ctx': {'error': f'conflicting values for key `{key}`'},
+        }
+    ]
+
+
+def test_deep_nesting():
+    v = SchemaValidator(core_schema.any_schema())
+    value = v.validate_urlencoded('a' + '[b]' * 128 + '=1')['a']
+    for _ in range(127):
+        value = value['b']
+    assert value == {'b': '1'}
+
+    for input_value in ('a' + '[b]' * 129 + '=1', 'a' + '[b]' * 200_000 + '=1', 'a' + '[]' * 200_000 + '=1'):
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_urlencoded(input_value)
+        assert exc_info.value.errors() == [
+            {
+                'type': 'urlencoded_invalid',
+                'loc': (),
+                'msg': 'Invalid URL encoded data: recursion limit exceeded',
+                'input': input_value,
+                'ctx': {'error': 'recursion limit exceeded'},
+            }
+        ]
+
+
+@pytest.mark.parametrize('input_value', [1, [('a', '1')], {'a': '1'}])
+def test_wrong_type(input_value):
+    v = SchemaValidator(core_schema.any_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_urlencoded(input_value)
+    assert exc_info.value.errors()[0]['type'] == 'urlencoded_type'
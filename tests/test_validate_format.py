@@ -0,0 +1,66 @@
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+@pytest.fixture(scope='module')
+def validator():
+    return SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.list_schema(core_schema.int_schema())),
+            }
+        )
+    )
+
+
+@pytest.mark.parametrize(
+    'format,input_value',
+    [
+        ('python', {'a': 1, 'b': [2, 3]}),
+        ('json', '{"a": 1, "b": [2, 3]}'),
+        ('json', b'{"a": 1, "b": [2, 3]}'),
+        # {"a": 1, "b": [2, 3]}
+        ('msgpack', b'\x82\xa1a\x01\xa1b\x92\x02\x03'),
+        ('cbor', b'\xa2aa\x01ab\x82\x02\x03'),
+        ('strings', {'a': '1', 'b': '[2, 3]'}),
+        ('urlencoded', 'a=1&b[]=2&b[]=3'),
+    ],
+)
+def test_validate_format(validator, format, input_value):
+    assert validator.validate(input_value, format=format) == {'a': 1, 'b': [2, 3]}
+
+
+def test_default_format(validator):
+    assert validator.validate({'a': '1', 'b': ['2']}) == {'a': 1, 'b': [2]}
+    with pytest.raises(ValidationError, match=r'a\n  Input should be a valid integer'):
+        validator.validate({'a': '1', 'b': []}, strict=True)
+
+
+def test_options_passed(validator):
+    with pytest.raises(ValidationError, match=r'a\n  Input should be a valid integer'):
+        validator.validate('a=1&b[]=2', format='urlencoded', strict=True)
+
+    v = SchemaValidator(core_schema.int_schema())
+    assert v.validate('1', format='json', collect_warnings=True) == (1, [])
+
+
+def test_decode_error(validator):
+    with pytest.raises(ValidationError) as exc_info:
+        validator.validate('{"a": 1', format='json')
+    assert exc_info.value.errors()[0]['type'] == 'json_invalid'
+
+    with pytest.raises(ValidationError) as exc_info:
+        validator.validate('{"a": 1}', format='msgpack')
+    assert exc_info.value.errors()[0]['type'] == 'msgpack_type'
+
+
+def test_invalid_format(validator):
+    with pytest.raises(ValueError, match="^Invalid format 'xml', expected one of: python, json, msgpack, strings, "):
+        validator.validate('<a>1</a>', format='xml')
+
+
+def test_format_keyword_only(validator):
+    with pytest.raises(TypeError):
+        validator.validate('{"a": 1, "b": []}', 'json')
@@ -0,0 +1,63 @@
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+def test_validate_many():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'id': core_schema.typed_dict_field(core_schema.int_schema()),
+                'name': core_schema.typed_dict_field(core_schema.string_schema()),
+            }
+        )
+    )
+    outputs, errors = v.validate_many(
+        [{'id': 1, 'name': 'a'}, {'id': 'x', 'name': 'b'}, {'id': '3', 'name': 'c'}, 'wrong']
+    )
+    assert outputs == [{'id': 1, 'name': 'a'}, {'id': 3, 'name': 'c'}]
+    assert [index for index, _ in errors] == [1, 3]
+    assert all(isinstance(err, ValidationError) for _, err in errors)
+    assert errors[0][1].errors() == [
+        {
+            'type': 'int_parsing',
+            'loc': ('id',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+    assert errors[1][1].errors()[0]['type'] == 'dict_type'
+
+
+def test_iterator():
+    v = SchemaValidator(core_schema.int_schema())
+    assert v.validate_many(str(i) for i in range(3)) == ([0, 1, 2], [])
+    assert v.validate_many([]) == ([], [])
+
+
+def test_strict():
+    v = SchemaValidator(core_schema.int_schema())
+    outputs, errors = v.validate_many([1, '2'], strict=True)
+    assert outputs == [1]
+    assert [(index, err.errors()[0]['type']) for index, err in errors] == [(1, 'int_type')]
+
+
+def test_recursive():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'ref': 'Branch',
+            'fields': {
+                'sub': {
+                    'schema': {
+                        'type': 'nullable',
+                        'schema': {'type': 'recursive-ref', 'schema_ref': 'Branch'},
+                    }
+                }
+            },
+        }
+    )
+    cyclic = {'sub': None}
+    cyclic['sub'] = cyclic
+    outputs, errors = v.validate_many([{'sub': {'sub': None}}, cyclic, {'sub': None}])
+    assert outputs == [{'sub': {'sub': None}}, {'sub': None}]
+    assert [index for index, _ in errors] == [1]
+    assert errors[0][1].errors()[0]['type'] == 'recursion_loop'
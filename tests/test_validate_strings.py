@@ -0,0 +1,202 @@
+from datetime import date, datetime, time, timedelta
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+@pytest.mark.parametrize(
+    'schema,input_value,expected',
+    [
+        (core_schema.int_schema(), '123', 123),
+        (core_schema.int_schema(), ' 123 ', 123),
+        (core_schema.int_schema(), '1.0', 1),
+        (core_schema.float_schema(), '1.5', 1.5),
+        (core_schema.bool_schema(), 'true', True),
+        (core_schema.bool_schema(), '1', True),
+        (core_schema.bool_schema(), 'off', False),
+        (core_schema.string_schema(), '[1, 2]', '[1, 2]'),
+        (core_schema.bytes_schema(), 'foo', b'foo'),
+        (core_schema.date_schema(), '2020-01-02', date(2020, 1, 2)),
+        (core_schema.time_schema(), '12:13:14', time(12, 13, 14)),
+        (core_schema.datetime_schema(), '2020-01-02T12:13:14', datetime(2020, 1, 2, 12, 13, 14)),
+        (core_schema.timedelta_schema(), 'P1D', timedelta(days=1)),
+        (core_schema.list_schema(core_schema.int_schema()), '[1, 2, "3"]', [1, 2, 3]),
+        (
+            core_schema.tuple_positional_schema(core_schema.int_schema(), core_schema.string_schema()),
+            '[1, "a"]',
+            (1, 'a'),
+        ),
+        (core_schema.set_schema(core_schema.int_schema()), ' [1, 2] ', {1, 2}),
+        (core_schema.frozenset_schema(core_schema.int_schema()), '[1]', frozenset({1})),
+        (core_schema.dict_schema(core_schema.string_schema(), core_schema.int_schema()), '{"a": 1}', {'a': 1}),
+        (core_schema.dict_schema(core_schema.string_schema(), core_schema.int_schema()), {'a': '1'}, {'a': 1}),
+    ],
+)
+def test_scalars_and_containers(schema, input_value, expected):
+    v = SchemaValidator(schema)
+    assert v.validate_strings(input_value) == expected
+
+
+@pytest.mark.parametrize(
+    'schema,input_value',
+    [
+        (core_schema.int_schema(strict=True), '123'),
+        (core_schema.bool_schema(strict=True), 'yes'),
+        (core_schema.date_schema(strict=True), '2020-01-02'),
+        (core_schema.list_schema(core_schema.int_schema(), strict=True), '[1]'),
+    ],
+)
+def test_strict(schema, input_value):
+    v = SchemaValidator(schema)
+    # strings are the only input type, so they're parsed in strict mode too
+    v.validate_strings(input_value)
+    v.validate_strings(input_value, strict=True)
+    # normal validation is unaffected
+    with pytest.raises(ValidationError):
+        v.validate_python(input_value)
+
+
+def test_typed_dict():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'port': core_schema.typed_dict_field(core_schema.int_schema()),
+                'debug': core_schema.typed_dict_field(core_schema.bool_schema()),
+                'hosts': core_schema.typed_dict_field(core_schema.list_schema(core_schema.string_schema())),
+                'db': core_schema.typed_dict_field(
+                    core_schema.typed_dict_schema(
+                        {
+                            'url': core_schema.typed_dict_field(core_schema.string_schema()),
+                            'timeout': core_schema.typed_dict_field(core_schema.float_schema()),
+                        }
+                    ),
+                    validation_alias='DATABASE',
+                ),
+                'log_level': core_schema.typed_dict_field(
+                    core_schema.string_schema(), validation_alias=[['LOGGING', 'LEVEL']]
+                ),
+            }
+        )
+    )
+    assert v.validate_strings(
+        {
+            'port': '8000',
+            'debug': 'false',
+            'hosts': '["a", "b"]',
+            'DATABASE': {'url': 'sqlite://', 'timeout': '2.5'},
+            'LOGGING': {'LEVEL': 'info'},
+        }
+    ) == {
+        'port': 8000,
+        'debug': False,
+        'hosts': ['a', 'b'],
+        'db': {'url': 'sqlite://', 'timeout': 2.5},
+        'log_level': 'info',
+    }
+    # nested mappings can also come from JSON
+    assert v.validate_strings(
+        {'port': '1', 'debug': '1', 'hosts': '[]', 'DATABASE': '{"url": "x", "timeout": 1}', 'LOGGING': {'LEVEL': 'x'}}
+    )['db'] == {'url': 'x', 'timeout': 1.0}
+
+
+def test_tagged_union():
+    v = SchemaValidator(
+        core_schema.tagged_union_schema(
+            {
+                'a': core_schema.typed_dict_schema(
+                    {
+                        'kind': core_schema.typed_dict_field(core_schema.string_schema()),
+                        'x': core_schema.typed_dict_field(core_schema.int_schema()),
+                    }
+                ),
+                'b': core_schema.typed_dict_schema(
+                    {
+                        'kind': core_schema.typed_dict_field(core_schema.string_schema()),
+                        'y': core_schema.typed_dict_field(core_schema.bool_schema()),
+                    }
+                ),
+            },
+            'kind',
+        )
+    )
+    assert v.validate_strings({'kind': 'a', 'x': '1'}) == {'kind': 'a', 'x': 1}
+    assert v.validate_strings({'kind': 'b', 'y': 'yes'}) == {'kind': 'b', 'y': True}
+
+
+def test_errors():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'port': core_schema.typed_dict_field(core_schema.int_schema()),
+                'hosts': core_schema.typed_dict_field(core_schema.list_schema(core_schema.int_schema())),
+                'tags': core_schema.typed_dict_field(core_schema.list_schema(core_schema.string_schema())),
+            }
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_strings({'port': 'x', 'hosts': '[1, "a"]', 'tags': 'a,b'})
+    assert exc_info.value.errors() == [
+        {
+            'type': 'int_parsing',
+            'loc': ('port',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        },
+        {
+            'type': 'int_parsing',
+            'loc': ('hosts', 1),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'a',
+        },
+        {'type': 'list_type', 'loc': ('tags',), 'msg': 'Input should be a valid list/array', 'input': 'a,b'},
+    ]
+
+
+def test_non_string_input():
+    v = SchemaValidator(core_schema.dict_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_strings({'a': {'b': 1}, 2: 'x'})
+    assert exc_info.value.errors() == [
+        {'type': 'string_type', 'loc': ('a', 'b'), 'msg': 'Input should be a valid string', 'input': 1},
+        {'type': 'string_type', 'loc': ('[key]',), 'msg': 'Input should be a valid string', 'input': 2},
+    ]
+
+
+def test_recursion():
+    v = SchemaValidator(core_schema.any_schema())
+    data = {'x': '1'}
+    data['a'] = data
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_strings(data)
+    assert exc_info.value.errors() == [
+        {
+            'type': 'recursion_loop',
+            'loc': ('a',),
+            'msg': 'Recursion error - cyclic reference detected',
+            'input': data,
+        }
+    ]
+
+    deep = nested = {}
+    for _ in range(100_000):
+        nested['a'] = nested = {}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_strings(deep)
+    assert exc_info.value.errors()[0]['type'] == 'recursion_loop'
+
+    # the same dict twice isn't a cycle
+    shared = {'b': '1'}
+    assert v.validate_strings({'x': shared, 'y': shared}) == {'x': {'b': '1'}, 'y': {'b': '1'}}
+
+
+def test_collect_warnings():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {'a': core_schema.typed_dict_field(core_schema.int_schema(), required=False, deprecated=True)}
+        )
+    )
+    assert v.validate_strings({'a': '1'}, collect_warnings=True) == (
+        {'a': 1},
+        [{'type': 'deprecated_field', 'loc': ('a',), 'msg': 'Field is deprecated'}],
+    )
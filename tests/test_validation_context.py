@@ -1,3 +1,5 @@
+import io
+
 import pytest
 
 from pydantic_core import SchemaValidator, ValidationError
@@ -113,5 +115,19 @@ def test_validate_assignment_with_context():
     m1 = v.validate_python({'f1': '1', 'f2': '2'}, None, {'x': 'y'})
     assert m1 == {'f1': "1| context: {'x': 'y', 'f1': '1'}", 'f2': "2| context: {'x': 'y', 'f1': '1', 'f2': '2'}"}
 
-    m2 = v.validate_assignment('f1', '3', m1, None, {'x': 'y'})
+    m2 = v.validate_assignment(m1, 'f1', '3', None, {'x': 'y'})
     assert m2 == {'f1': "3| context: {'x': 'y', 'f1': '3'}", 'f2': "2| context: {'x': 'y', 'f1': '1', 'f2': '2'}"}
+
+
+def test_other_formats():
+    def f(input_value, *, context, **kwargs):
+        return f'{input_value}| context: {context}'
+
+    v = SchemaValidator({'type': 'function', 'mode': 'after', 'function': f, 'schema': {'type': 'int'}})
+
+    assert v.validate_json_lines('1\n2', context='frogspawn') == ['1| context: frogspawn', '2| context: frogspawn']
+    assert list(v.validate_json_stream(io.StringIO('[1, 2]'), context='spam')) == [
+        '1| context: spam',
+        '2| context: spam',
+    ]
+    assert v.validate('3', format='strings', context={'x': 1}) == "3| context: {'x': 1}"
@@ -0,0 +1,107 @@
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+@pytest.fixture(scope='module')
+def deprecated_validator():
+    return SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'name': core_schema.typed_dict_field(core_schema.string_schema()),
+                'old_name': core_schema.typed_dict_field(
+                    core_schema.string_schema(), required=False, deprecated='Use "name" instead'
+                ),
+                'legacy': core_schema.typed_dict_field(core_schema.int_schema(), required=False, deprecated=True),
+            }
+        )
+    )
+
+
+def test_no_warnings_by_default(deprecated_validator: SchemaValidator):
+    assert deprecated_validator.validate_python({'name': 'a', 'old_name': 'b'}) == {'name': 'a', 'old_name': 'b'}
+
+
+def test_deprecated_field(deprecated_validator: SchemaValidator):
+    output, warnings = deprecated_validator.validate_python(
+        {'name': 'a', 'old_name': 'b', 'legacy': 1}, collect_warnings=True
+    )
+    assert output == {'name': 'a', 'old_name': 'b', 'legacy': 1}
+    assert warnings == [
+        {'type': 'deprecated_field', 'loc': ('old_name',), 'msg': 'Use "name" instead'},
+        {'type': 'deprecated_field', 'loc': ('legacy',), 'msg': 'Field is deprecated'},
+    ]
+
+
+def test_deprecated_field_not_used(deprecated_validator: SchemaValidator):
+    assert deprecated_validator.validate_python({'name': 'a'}, collect_warnings=True) == ({'name': 'a'}, [])
+
+
+def test_deprecated_field_json(deprecated_validator: SchemaValidator):
+    output, warnings = deprecated_validator.validate_json('{"name": "a", "legacy": 2}', collect_warnings=True)
+    assert output == {'name': 'a', 'legacy': 2}
+    assert warnings == [{'type': 'deprecated_field', 'loc': ('legacy',), 'msg': 'Field is deprecated'}]
+
+
+def test_errors_still_raised(deprecated_validator: SchemaValidator):
+    with pytest.raises(ValidationError, match='legacy\n  Input should be a valid integer'):
+        deprecated_validator.validate_python({'name': 'a', 'legacy': 'x'}, collect_warnings=True)
+
+
+def test_nested_locations():
+    inner = core_schema.typed_dict_schema(
+        {'a': core_schema.typed_dict_field(core_schema.int_schema(), required=False, deprecated=True)}
+    )
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'items': core_schema.typed_dict_field(core_schema.list_schema(inner)),
+                'mapping': core_schema.typed_dict_field(core_schema.dict_schema(core_schema.string_schema(), inner)),
+                'pair': core_schema.typed_dict_field(core_schema.tuple_positional_schema(inner, inner)),
+            }
+        )
+    )
+    output, warnings = v.validate_python(
+        {'items': [{}, {'a': 1}], 'mapping': {'x': {'a': 2}}, 'pair': ({'a': 3}, {})}, collect_warnings=True
+    )
+    assert output == {'items': [{}, {'a': 1}], 'mapping': {'x': {'a': 2}}, 'pair': ({'a': 3}, {})}
+    assert warnings == [
+        {'type': 'deprecated_field', 'loc': ('items', 1, 'a'), 'msg': 'Field is deprecated'},
+        {'type': 'deprecated_field', 'loc': ('mapping', 'x', 'a'), 'msg': 'Field is deprecated'},
+        {'type': 'deprecated_field', 'loc': ('pair', 0, 'a'), 'msg': 'Field is deprecated'},
+    ]
+
+
+def test_loc_by_alias():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'field_a': core_schema.typed_dict_field(
+                    core_schema.int_schema(), validation_alias='FieldA', deprecated=True
+                )
+            },
+            loc_by_alias=True,
+        )
+    )
+    assert v.validate_python({'FieldA': 1}, collect_warnings=True) == (
+        {'field_a': 1},
+        [{'type': 'deprecated_field', 'loc': ('FieldA',), 'msg': 'Field is deprecated'}],
+    )
+
+
+def test_union_failed_choice_discarded():
+    deprecated_choice = core_schema.typed_dict_schema(
+        {
+            'a': core_schema.typed_dict_field(core_schema.int_schema(), deprecated=True),
+            'b': core_schema.typed_dict_field(core_schema.int_schema()),
+        }
+    )
+    current_choice = core_schema.typed_dict_schema({'a': core_schema.typed_dict_field(core_schema.string_schema())})
+    v = SchemaValidator(core_schema.union_schema(deprecated_choice, current_choice))
+
+    # the first choice fails as "b" is missing, so its warning is dropped
+    assert v.validate_python({'a': 'x'}, collect_warnings=True) == ({'a': 'x'}, [])
+    assert v.validate_python({'a': 1, 'b': 2}, collect_warnings=True) == (
+        {'a': 1, 'b': 2},
+        [{'type': 'deprecated_field', 'loc': ('a',), 'msg': 'Field is deprecated'}],
+    )
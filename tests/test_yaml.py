@@ -0,0 +1,108 @@
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+pytestmark = pytest.mark.skipif(
+    not hasattr(SchemaValidator, 'validate_yaml'), reason='pydantic-core built without the "yaml" feature'
+)
+
+CONFIG = """\
+title: example
+debug: true
+ratio: 0.5
+nothing: null
+server:
+  host: localhost
+  ports:
+    - 8000
+    - 8001
+users:
+  - name: alice
+  - {name: bob}
+"""
+
+
+def test_any():
+    v = SchemaValidator(core_schema.any_schema())
+    assert v.validate_yaml(CONFIG) == {
+        'title': 'example',
+        'debug': True,
+        'ratio': 0.5,
+        'nothing': None,
+        'server': {'host': 'localhost', 'ports': [8000, 8001]},
+        'users': [{'name': 'alice'}, {'name': 'bob'}],
+    }
+
+
+def test_typed_dict():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'title': core_schema.typed_dict_field(core_schema.string_schema()),
+                'ratio': core_schema.typed_dict_field(core_schema.float_schema(strict=True)),
+                'server': core_schema.typed_dict_field(
+                    core_schema.typed_dict_schema(
+                        {'ports': core_schema.typed_dict_field(core_schema.set_schema(core_schema.int_schema()))}
+                    )
+                ),
+            }
+        )
+    )
+    assert v.validate_yaml(CONFIG) == {'title': 'example', 'ratio': 0.5, 'server': {'ports': {8000, 8001}}}
+
+
+def test_keys_and_tags():
+    v = SchemaValidator(core_schema.dict_schema(core_schema.string_schema(), core_schema.any_schema()))
+    assert v.validate_yaml('1: a\ntrue: b\nc: !custom d\nbig: 18446744073709551615') == {
+        '1': 'a',
+        'true': 'b',
+        'c': 'd',
+        'big': 2**64 - 1,
+    }
+
+
+def test_datetime_string():
+    v = SchemaValidator(core_schema.typed_dict_schema({'d': core_schema.typed_dict_field(core_schema.date_schema())}))
+    assert str(v.validate_yaml('d: 2020-01-02')['d']) == '2020-01-02'
+
+
+def test_validation_error():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_yaml('- 1\n- x')
+    assert exc_info.value.errors() == [
+        {
+            'type': 'int_parsing',
+            'loc': (1,),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+
+
+@pytest.mark.parametrize(
+    'input_value,error',
+    [
+        ('a: [1, 2', 'did not find expected'),
+        ('[1]: a', 'mapping keys must be strings, numbers or booleans'),
+    ],
+)
+def test_invalid(input_value, error):
+    v = SchemaValidator(core_schema.any_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_yaml(input_value)
+    errors = exc_info.value.errors()
+    assert len(errors) == 1
+    assert errors[0]['type'] == 'yaml_invalid'
+    assert error in errors[0]['ctx']['error']
+
+
+def test_wrong_type():
+    v = SchemaValidator(core_schema.any_schema())
+    with pytest.raises(ValidationError, match='YAML input should be a string'):
+        v.validate_yaml(b'a: 1')
+
+
+def test_validate_format():
+    v = SchemaValidator(core_schema.dict_schema(values_schema=core_schema.int_schema()))
+    assert v.validate('a: 1\nb: 2', format='yaml') == {'a': 1, 'b': 2}
@@ -801,6 +801,42 @@ def test_alias_populate_by_name(py_and_json: PyAndJson, input_value, expected):
         assert v.validate_test(input_value) == expected
 
 
+def test_loc_by_alias(py_and_json: PyAndJson):
+    v = py_and_json(
+        {
+            'type': 'arguments',
+            'arguments_schema': [
+                {'name': 'a', 'mode': 'positional_or_keyword', 'schema': {'type': 'int'}, 'alias': 'Foo'},
+                {'name': 'b', 'mode': 'keyword_only', 'schema': {'type': 'int'}, 'alias': 'Bar'},
+            ],
+            'loc_by_alias': True,
+        }
+    )
+    assert v.validate_test({'__args__': None, '__kwargs__': {'Foo': 1, 'Bar': 2}}) == ((), {'a': 1, 'b': 2})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'__args__': None, '__kwargs__': {'Foo': 'x'}})
+    # insert_assert(exc_info.value.errors())
+    assert exc_info.value.errors() == [
+        {
+            'type': 'int_parsing',
+            'loc': ('Foo',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        },
+        {
+            'type': 'missing_keyword_argument',
+            'loc': ('Bar',),
+            'msg': 'Missing required keyword argument',
+            'input': {'__args__': None, '__kwargs__': {'Foo': 'x'}},
+        },
+    ]
+
+    # positional arguments are still located by index
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'__args__': ('x',), '__kwargs__': {'Bar': 2}})
+    assert exc_info.value.errors()[0]['loc'] == (0,)
+
+
 def validate(function):
     """
     a demo validation decorator to test arguments
@@ -1008,3 +1044,66 @@ def test_invalid_schema():
                 ],
             }
         )
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        [{'__args__': (1,), '__kwargs__': {'x': '2'}}, ((1,), {'x': 2})],
+        [{'__args__': (1,), '__kwargs__': {'x': 2, 'y': 'hello'}}, ((1,), {'x': 2, 'y': 'hello'})],
+        [{'__args__': None, '__kwargs__': {'a': 1, 'x': 2}}, ((), {'a': 1, 'x': 2})],
+        [
+            {'__args__': (1,), '__kwargs__': {'x': 'wrong', 'y': 2}},
+            Err(
+                '2 validation errors for arguments\n'
+                'x\n'
+                '  Input should be a valid integer, unable to parse string as an integer '
+                "[type=int_parsing, input_value='wrong', input_type=str]\n"
+                'y\n'
+                '  Input should be a valid string [type=string_type, input_value=2, input_type=int]'
+            ),
+        ],
+        [
+            {'__args__': (1,), '__kwargs__': None},
+            Err('x\n  Missing required keyword argument [type=missing_keyword_argument,'),
+        ],
+        [
+            {'__args__': (1,), '__kwargs__': {'x': 1, 'z': 3}},
+            Err('z\n  Unexpected keyword argument [type=unexpected_keyword_argument,'),
+        ],
+    ],
+    ids=repr,
+)
+def test_var_kwargs_unpacked_typed_dict(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(
+        {
+            'type': 'arguments',
+            'arguments_schema': [{'name': 'a', 'mode': 'positional_or_keyword', 'schema': {'type': 'int'}}],
+            'var_kwargs_schema': {
+                'type': 'typed-dict',
+                'fields': {
+                    'x': {'schema': {'type': 'int'}},
+                    'y': {'schema': {'type': 'str'}, 'required': False},
+                },
+                'extra_behavior': 'forbid',
+            },
+            'var_kwargs_mode': 'unpacked-typed-dict',
+        }
+    )
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_var_kwargs_unpacked_typed_dict_invalid():
+    with pytest.raises(SchemaError, match="var_kwargs_mode 'unpacked-typed-dict' requires a typed-dict"):
+        SchemaValidator(
+            {
+                'type': 'arguments',
+                'arguments_schema': [],
+                'var_kwargs_schema': {'type': 'int'},
+                'var_kwargs_mode': 'unpacked-typed-dict',
+            }
+        )
@@ -2,7 +2,7 @@ import re
 
 import pytest
 
-from pydantic_core import SchemaValidator, ValidationError
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
 
 from ..conftest import Err, PyAndJson, plain_repr
 
@@ -76,9 +76,13 @@ def test_bool_error():
 
 def test_bool_repr():
     v = SchemaValidator({'type': 'bool'})
-    assert plain_repr(v) == 'SchemaValidator(name="bool",validator=Bool(BoolValidator{strict:false}),slots=[])'
+    assert plain_repr(v) == (
+        'SchemaValidator(name="bool",validator=Bool(BoolValidator{strict:false,strings:None}),slots=[])'
+    )
     v = SchemaValidator({'type': 'bool', 'strict': True})
-    assert plain_repr(v) == 'SchemaValidator(name="bool",validator=Bool(BoolValidator{strict:true}),slots=[])'
+    assert plain_repr(v) == (
+        'SchemaValidator(name="bool",validator=Bool(BoolValidator{strict:true,strings:None}),slots=[])'
+    )
 
 
 def test_bool_key(py_and_json: PyAndJson):
@@ -88,3 +92,38 @@ def test_bool_key(py_and_json: PyAndJson):
     assert v.validate_test({'true': 1, 'off': 2}, strict=False) == {True: 1, False: 2}
     with pytest.raises(ValidationError, match='Input should be a valid boolean'):
         v.validate_test({'true': 1, 'off': 2}, strict=True)
+
+
+def test_true_false_values(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'bool', 'true_values': ['true', '1'], 'false_values': ['false', '0']})
+    assert v.validate_test('True') is True
+    assert v.validate_test('1') is True
+    assert v.validate_test('FALSE') is False
+    assert v.validate_test(0) is False
+    assert v.validate_test(True) is True
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test('yes')
+    assert exc_info.value.errors() == [
+        {
+            'type': 'bool_parsing',
+            'loc': (),
+            'msg': 'Input should be a valid boolean, unable to interpret input',
+            'input': 'yes',
+        }
+    ]
+    with pytest.raises(ValidationError, match='Input should be a valid boolean'):
+        v.validate_test('true', strict=True)
+
+
+def test_true_values_config():
+    v = SchemaValidator({'type': 'bool'}, {'bool_true_values': ['Enabled']})
+    assert v.validate_python('enabled') is True
+    # the default false values are still used
+    assert v.validate_python('off') is False
+    with pytest.raises(ValidationError, match='Input should be a valid boolean, unable to interpret input'):
+        v.validate_python('on')
+
+
+def test_true_false_values_overlap():
+    with pytest.raises(SchemaError, match="'x' can't be in both `true_values` and `false_values`"):
+        SchemaValidator({'type': 'bool', 'true_values': ['x'], 'false_values': ['X']})
@@ -1,4 +1,5 @@
 import re
+from array import array
 from typing import Any, Dict
 
 import pytest
@@ -44,6 +45,45 @@ def test_lax_bytes_validator():
     ]
 
 
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (memoryview(b'foo'), b'foo'),
+        (memoryview(bytearray(b'foo')), b'foo'),
+        (memoryview(b'abcdef')[1:4], b'bcd'),
+        (memoryview(b'abcdef')[::2], b'ace'),
+        (array('B', b'foo'), b'foo'),
+        (memoryview(b''), b''),
+        (array('i', [1, 2]), Err('Input should be a valid bytes')),
+        (memoryview(array('f', [1.0])), Err('Input should be a valid bytes')),
+    ],
+)
+def test_lax_bytes_buffer(input_value, expected):
+    v = SchemaValidator({'type': 'bytes'})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        output = v.validate_python(input_value)
+        assert output == expected
+        assert type(output) is bytes
+
+
+def test_bytes_buffer_constraints():
+    v = SchemaValidator({'type': 'bytes', 'max_length': 3})
+    assert v.validate_python(memoryview(b'foo')) == b'foo'
+    with pytest.raises(ValidationError, match='Data should have at most 3 bytes'):
+        v.validate_python(array('B', b'foobar'))
+    with pytest.raises(ValidationError, match='Data should have at most 3 bytes'):
+        v.validate_python(memoryview(b'foobarspam')[::2])
+
+
+def test_strict_bytes_buffer():
+    v = SchemaValidator({'type': 'bytes', 'strict': True})
+    with pytest.raises(ValidationError, match='Input should be a valid bytes'):
+        v.validate_python(memoryview(b'foo'))
+
+
 @pytest.mark.parametrize(
     'opts,input,expected',
     [
@@ -105,7 +145,7 @@ def test_length_ctx():
             'loc': (),
             'msg': 'Data should have at least 2 bytes',
             'input': b'1',
-            'ctx': {'min_length': 2},
+            'ctx': {'field_type': 'Bytes', 'min_length': 2, 'actual_length': 1},
         }
     ]
 
@@ -118,6 +158,6 @@ def test_length_ctx():
             'loc': (),
             'msg': 'Data should have at most 3 bytes',
             'input': b'1234',
-            'ctx': {'max_length': 3},
+            'ctx': {'field_type': 'Bytes', 'max_length': 3, 'actual_length': 4},
         }
     ]
@@ -0,0 +1,82 @@
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+from ..conftest import PyAndJson
+
+
+def test_conditional(py_and_json: PyAndJson):
+    v = py_and_json(
+        core_schema.conditional_schema(
+            if_schema=core_schema.string_schema(strict=True),
+            then_schema=core_schema.string_schema(to_upper=True),
+            else_schema=core_schema.int_schema(),
+        )
+    )
+    assert v.validate_test('abc') == 'ABC'
+    assert v.validate_test(123) == 123
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test([1])
+    # errors come from the else schema only
+    assert exc_info.value.errors() == [
+        {'type': 'int_type', 'loc': (), 'msg': 'Input should be a valid integer', 'input': [1]}
+    ]
+
+
+def test_discriminating_literal(py_and_json: PyAndJson):
+    v = py_and_json(
+        {
+            'type': 'conditional',
+            'if_schema': {
+                'type': 'typed-dict',
+                'fields': {'kind': {'schema': {'type': 'literal', 'expected': ['range']}}},
+            },
+            'then_schema': {
+                'type': 'typed-dict',
+                'fields': {'kind': {'schema': {'type': 'str'}}, 'start': {'schema': {'type': 'int'}}},
+            },
+            'else_schema': {
+                'type': 'typed-dict',
+                'fields': {'kind': {'schema': {'type': 'str'}}, 'value': {'schema': {'type': 'int'}}},
+            },
+        }
+    )
+    assert v.validate_test({'kind': 'range', 'start': '1'}) == {'kind': 'range', 'start': 1}
+    assert v.validate_test({'kind': 'other', 'value': '2'}) == {'kind': 'other', 'value': 2}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'kind': 'range', 'value': 3})
+    assert exc_info.value.errors() == [
+        {'type': 'missing', 'loc': ('start',), 'msg': 'Field required', 'input': {'kind': 'range', 'value': 3}}
+    ]
+
+
+def test_if_output_discarded():
+    calls = []
+
+    def f(input_value, **kwargs):
+        calls.append(input_value)
+        return 'changed'
+
+    v = SchemaValidator(
+        core_schema.conditional_schema(
+            if_schema=core_schema.function_after_schema(core_schema.int_schema(), f),
+            then_schema=core_schema.string_schema(),
+            else_schema=core_schema.bytes_schema(),
+        )
+    )
+    assert v.validate_python('123') == '123'
+    assert v.validate_python('abc') == b'abc'
+    assert calls == [123]
+
+
+def test_repr():
+    v = SchemaValidator(
+        core_schema.conditional_schema(
+            if_schema=core_schema.int_schema(),
+            then_schema=core_schema.string_schema(),
+            else_schema=core_schema.bytes_schema(),
+        )
+    )
+    assert repr(v).startswith('SchemaValidator(name="conditional[if=int,then=str,else=bytes]"')
@@ -77,3 +77,62 @@ def test_ask():
     assert exc_info.value.errors() == [
         {'type': 'foobar', 'loc': (), 'msg': 'Hello there', 'input': {'field_a': 'test'}}
     ]
+
+
+def test_custom_error_on_schema(py_and_json: PyAndJson):
+    v = py_and_json(
+        {
+            'type': 'str',
+            'min_length': 3,
+            'custom_error_type': 'username_too_short',
+            'custom_error_message': 'Usernames need at least {min} characters',
+            'custom_error_context': {'min': 3},
+        }
+    )
+    assert v.validate_test('abc') == 'abc'
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test('ab')
+    # insert_assert(exc_info.value.errors())
+    assert exc_info.value.errors() == [
+        {
+            'type': 'username_too_short',
+            'loc': (),
+            'msg': 'Usernames need at least 3 characters',
+            'input': 'ab',
+            'ctx': {'min': 3},
+        }
+    ]
+
+
+def test_custom_error_on_schema_known_type():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'age': core_schema.typed_dict_field(
+                    {'type': 'int', 'gt': 0, 'custom_error_type': 'greater_than', 'custom_error_context': {'gt': 0}}
+                )
+            }
+        )
+    )
+    assert v.validate_python({'age': 1}) == {'age': 1}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'age': 'foobar'})
+    # insert_assert(exc_info.value.errors())
+    assert exc_info.value.errors() == [
+        {
+            'type': 'greater_than',
+            'loc': ('age',),
+            'msg': 'Input should be greater than 0',
+            'input': 'foobar',
+            'ctx': {'gt': 0},
+        }
+    ]
+    # the validator name is unchanged
+    assert 'custom_error[' not in plain_repr(v)
+
+
+def test_custom_error_on_schema_invalid():
+    msg = "custom_error_message should not be provided if 'custom_error_type' matches a known error"
+    with pytest.raises(SchemaError, match=msg):
+        SchemaValidator({'type': 'int', 'custom_error_type': 'int_parsing', 'custom_error_message': 'xxx'})
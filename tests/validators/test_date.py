@@ -283,3 +283,85 @@ def test_date_past_future_today():
 def test_offset_too_large():
     with pytest.raises(SchemaError, match=r'Input should be less than 86400 \[type=less_than,'):
         SchemaValidator(core_schema.date_schema(now_op='past', now_utc_offset=24 * 3600))
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('31/12/2022', date(2022, 12, 31)),
+        ('1/2/2022', date(2022, 2, 1)),
+        ('31/12/99', date(1999, 12, 31)),
+        ('01/01/68', date(2068, 1, 1)),
+        ('01/01/69', date(1969, 1, 1)),
+        ('05-Jan-2021', date(2021, 1, 5)),
+        ('05-JAN-2021', date(2021, 1, 5)),
+        ('2022-12-31', date(2022, 12, 31)),
+        ('29/02/2021', Err('Input should be a valid date or datetime, invalid character in year')),
+        ('31/12/2022 ', Err('Input should be a valid date or datetime, invalid character in year')),
+        ('12/31/2022', Err('Input should be a valid date or datetime, invalid character in year')),
+    ],
+)
+def test_input_formats(input_value, expected):
+    v = SchemaValidator(core_schema.date_schema(input_formats=['%d/%m/%Y', '%d/%m/%y', '%d-%b-%Y']))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+        assert v.validate_json(f'"{input_value}"') == expected
+
+
+def test_input_formats_strict():
+    v = SchemaValidator(core_schema.date_schema(input_formats=['%d/%m/%Y']))
+    with pytest.raises(ValidationError, match='Input should be a valid date'):
+        v.validate_python('31/12/2022', strict=True)
+    v = SchemaValidator(core_schema.date_schema(strict=True, input_formats=['%d/%m/%Y']))
+    with pytest.raises(ValidationError, match='Input should be a valid date'):
+        v.validate_python('31/12/2022')
+    assert v.validate_python('31/12/2022', strict=False) == date(2022, 12, 31)
+
+
+def test_datetime_input_runtime_strict():
+    v = SchemaValidator({'type': 'date'})
+    assert v.validate_python(datetime(2022, 6, 8)) == date(2022, 6, 8)
+    with pytest.raises(ValidationError, match='Input should be a valid date'):
+        v.validate_python(datetime(2022, 6, 8), strict=True)
+    v = SchemaValidator({'type': 'date', 'strict': True})
+    assert v.validate_python(datetime(2022, 6, 8), strict=False) == date(2022, 6, 8)
+
+
+@pytest.mark.parametrize(
+    'input_formats,message',
+    [
+        (['%d/%m/%Q'], 'Invalid input format "%d/%m/%Q": unknown directive "%Q"'),
+        (['%d/%m/%Y%'], 'Invalid input format "%d/%m/%Y%": trailing "%"'),
+        (['%d/%m'], 'Invalid input format "%d/%m": a year, month and day are required'),
+        (['%d/%m/%Y %H'], 'Invalid input format "%d/%m/%Y %H": date formats may not contain time directives'),
+    ],
+)
+def test_input_formats_invalid(input_formats, message):
+    with pytest.raises(SchemaError, match=re.escape(message)):
+        SchemaValidator(core_schema.date_schema(input_formats=input_formats))
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('2022-06-08', date(2022, 6, 8)),
+        ('2022-6-8', Err('Input should be a valid date in the format YYYY-MM-DD, input is not an RFC 3339 date')),
+        ('20220608', Err('input is not an RFC 3339 date')),
+        ('2022-06-08T00:00:00Z', Err('input is not an RFC 3339 date')),
+        ('2022-06-31', Err('day value is outside expected range')),
+    ],
+)
+def test_rfc3339(input_value, expected):
+    v = SchemaValidator(core_schema.date_schema(rfc3339=True))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_json(f'"{input_value}"')
+    else:
+        assert v.validate_python(input_value) == expected
+        assert v.validate_json(f'"{input_value}"') == expected
+    assert v.validate_python(date(2022, 6, 8)) == date(2022, 6, 8)
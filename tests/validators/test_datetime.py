@@ -399,3 +399,263 @@ class TestTZConstraints:
         value = datetime.now(tz=timezone.utc)
         with pytest.raises(ValidationError, match=r'Datetime should not have timezone info'):
             assert self.naive_validator.validate_python(value)
+
+
+class AmbiguousTz(tzinfo):
+    """
+    Fake DST transition: wall-clock times between 01:00 and 02:00 happen twice, first at +02:00 (`fold=0`)
+    then at +01:00 (`fold=1`).
+    """
+
+    def utcoffset(self, dt):
+        if dt.hour == 1 and dt.fold == 1:
+            return timedelta(hours=1)
+        elif dt.hour <= 1:
+            return timedelta(hours=2)
+        else:
+            return timedelta(hours=1)
+
+    def tzname(self, dt):
+        return 'Ambiguous'
+
+    def dst(self, dt):
+        return None
+
+
+def test_fold_preserved():
+    v = SchemaValidator(core_schema.datetime_schema())
+    value = datetime(2022, 10, 30, 1, 30, tzinfo=AmbiguousTz(), fold=1)
+    output = v.validate_python(value)
+    assert output.fold == 1
+    assert output.utcoffset() == timedelta(hours=1)
+
+
+@pytest.mark.parametrize(
+    'fold_mode,fold,expected',
+    [
+        (None, 0, True),
+        (None, 1, False),
+        ('respect', 0, True),
+        ('respect', 1, False),
+        ('earliest', 0, True),
+        ('earliest', 1, True),
+        ('latest', 0, False),
+        ('latest', 1, False),
+    ],
+)
+def test_fold_mode(fold_mode, fold, expected):
+    # 01:30+02:00 == 23:30Z, 01:30+01:00 == 00:30Z
+    schema = core_schema.datetime_schema(lt=datetime(2022, 10, 30, 0, tzinfo=timezone.utc), fold_mode=fold_mode)
+    v = SchemaValidator(schema)
+    value = datetime(2022, 10, 30, 1, 30, tzinfo=AmbiguousTz(), fold=fold)
+    assert v.isinstance_python(value) == expected
+    if expected:
+        output = v.validate_python(value)
+        # the output has the fold which was checked against the constraint
+        expected_fold = {'earliest': 0, 'latest': 1}.get(fold_mode, fold)
+        assert output.fold == expected_fold
+        assert output.tzinfo is value.tzinfo
+        if expected_fold == fold:
+            assert output is value
+
+
+def test_fold_mode_output():
+    v = SchemaValidator(core_schema.datetime_schema(fold_mode='latest'))
+    output = v.validate_python(datetime(2022, 10, 30, 1, 30, tzinfo=AmbiguousTz()))
+    assert output.fold == 1
+    assert output.utcoffset() == timedelta(hours=1)
+
+    output = v.validate_python('2022-10-30T01:30:00')
+    assert output == datetime(2022, 10, 30, 1, 30)
+    assert output.fold == 1
+    assert v.validate_json('"2022-10-30T01:30:00+01:00"').fold == 1
+
+    output = SchemaValidator(core_schema.datetime_schema()).validate_python('2022-10-30T01:30:00')
+    assert output.fold == 0
+
+
+def test_fold_mode_config():
+    schema = core_schema.datetime_schema(lt=datetime(2022, 10, 30, 0, tzinfo=timezone.utc))
+    value = datetime(2022, 10, 30, 1, 30, tzinfo=AmbiguousTz(), fold=1)
+    assert not SchemaValidator(schema).isinstance_python(value)
+    assert SchemaValidator(schema, {'datetime_fold_mode': 'earliest'}).isinstance_python(value)
+    schema['fold_mode'] = 'latest'
+    assert not SchemaValidator(schema, {'datetime_fold_mode': 'earliest'}).isinstance_python(value)
+
+
+def test_fold_mode_invalid():
+    with pytest.raises(SchemaError, match='Invalid fold_mode "foobar"'):
+        SchemaValidator(core_schema.datetime_schema(), {'datetime_fold_mode': 'foobar'})
+
+
+@pytest.mark.parametrize(
+    'mode,input_value,expected',
+    [
+        ('clamp', '2016-12-31T23:59:60', datetime(2016, 12, 31, 23, 59, 59, 999_999)),
+        ('carry', '2016-06-30T12:30:60', datetime(2016, 6, 30, 12, 31)),
+        ('carry', '2016-06-30T23:59:60', datetime(2016, 7, 1)),
+        ('carry', '2016-02-28T23:59:60', datetime(2016, 2, 29)),
+        ('carry', '2015-02-28T23:59:60', datetime(2015, 3, 1)),
+        ('carry', '2016-12-31T23:59:60Z', datetime(2017, 1, 1, tzinfo=timezone.utc)),
+        (
+            'carry',
+            '2016-12-31T23:59:60.5+01:00',
+            datetime(2017, 1, 1, 0, 0, 0, 500_000, tzinfo=timezone(timedelta(hours=1))),
+        ),
+        ('error', '2016-12-31T23:59:60', Err('second value is outside expected range of 0-59')),
+    ],
+)
+def test_leap_second_mode(mode, input_value, expected):
+    v = SchemaValidator(core_schema.datetime_schema(leap_second_mode=mode))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+        assert v.validate_json(f'"{input_value}"') == expected
+
+
+def test_leap_second_mode_invalid():
+    with pytest.raises(SchemaError, match='Invalid leap_second_mode "foobar"'):
+        SchemaValidator(core_schema.datetime_schema(), {'leap_second_mode': 'foobar'})
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('31/12/22 23:15', datetime(2022, 12, 31, 23, 15)),
+        ('31/12/22', datetime(2022, 12, 31)),
+        (
+            'Jan 5 2021 3:04:05.123 PM +0100',
+            datetime(2021, 1, 5, 15, 4, 5, 123_000, tzinfo=timezone(timedelta(hours=1))),
+        ),
+        ('Jan 5 2021 3:04:05.123 am Z', datetime(2021, 1, 5, 3, 4, 5, 123_000, tzinfo=timezone.utc)),
+        ('31/12/22 24:00', Err('Input should be a valid datetime')),
+    ],
+)
+def test_input_formats(input_value, expected):
+    v = SchemaValidator(
+        core_schema.datetime_schema(input_formats=['%d/%m/%y %H:%M', '%d/%m/%y', '%b %d %Y %I:%M:%S.%f %p %z'])
+    )
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+        assert v.validate_json(f'"{input_value}"') == expected
+
+
+def test_input_formats_not_str():
+    v = SchemaValidator(core_schema.datetime_schema(input_formats=['%Y%m%d']))
+    assert v.validate_python('20220101') == datetime(2022, 1, 1)
+    with pytest.raises(ValidationError, match='Input should be a valid datetime'):
+        v.validate_python([20220101])
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('2022-06-08T12:13:14Z', datetime(2022, 6, 8, 12, 13, 14, tzinfo=timezone.utc)),
+        ('2022-06-08t12:13:14z', datetime(2022, 6, 8, 12, 13, 14, tzinfo=timezone.utc)),
+        (
+            '2022-06-08T12:13:14.123+05:30',
+            datetime(2022, 6, 8, 12, 13, 14, 123_000, tzinfo=timezone(timedelta(hours=5, minutes=30))),
+        ),
+        ('2022-06-08 12:13:14Z', Err('Input should be a valid datetime, input is not an RFC 3339 date-time')),
+        ('2022-06-08T12:13Z', Err('input is not an RFC 3339 date-time')),
+        ('2022-06-08T12:13:14', Err('input is not an RFC 3339 date-time')),
+        ('2022-06-08T12:13:14.Z', Err('input is not an RFC 3339 date-time')),
+        ('2022-06-08T12:13:14+0530', Err('input is not an RFC 3339 date-time')),
+        ('2022-06-08', Err('input is not an RFC 3339 date-time')),
+        ('1654690394', Err('input is not an RFC 3339 date-time')),
+        # the format is checked first, then the values as usual
+        ('2022-06-08T25:13:14Z', Err('hour value is outside expected range of 0-23')),
+    ],
+)
+def test_rfc3339(input_value, expected):
+    v = SchemaValidator(core_schema.datetime_schema(rfc3339=True))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_json(f'"{input_value}"')
+    else:
+        assert v.validate_python(input_value) == expected
+        assert v.validate_json(f'"{input_value}"') == expected
+
+
+def test_rfc3339_not_str():
+    v = SchemaValidator(core_schema.datetime_schema(), {'rfc3339': True})
+    assert v.validate_python(datetime(2022, 6, 8, 12, 13, 14)) == datetime(2022, 6, 8, 12, 13, 14)
+    # only strings are checked, timestamps are still allowed in lax mode
+    assert v.validate_python(1654690394) == datetime(2022, 6, 8, 12, 13, 14)
+    with pytest.raises(ValidationError, match='input is not an RFC 3339 date-time'):
+        v.validate_python(b'2022-06-08 12:13:14')
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('2024-07-01 12:00:00 EST', datetime(2024, 7, 1, 12, tzinfo=timezone(timedelta(hours=-5)))),
+        ('2024-07-01T12:00:00.5 pdt', datetime(2024, 7, 1, 12, 0, 0, 500_000, tzinfo=timezone(timedelta(hours=-7)))),
+        ('2024-07-01 12:00:00 GMT', datetime(2024, 7, 1, 12, tzinfo=timezone.utc)),
+        ('2024-07-01 12:00:00 Mars/Olympus_Mons', Err('Input should be a valid datetime, unknown timezone')),
+        # an offset and a timezone name is an error
+        ('2024-07-01 12:00:00+01:00 EST', Err('Input should be a valid datetime, unexpected extra characters')),
+        ('2024-07-01 EST', Err('Input should be a valid datetime')),
+    ],
+)
+def test_named_timezones(input_value, expected):
+    v = SchemaValidator(core_schema.datetime_schema(named_timezones=True))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+        assert v.validate_json(f'"{input_value}"') == expected
+
+
+def test_named_timezones_disabled():
+    v = SchemaValidator(core_schema.datetime_schema())
+    with pytest.raises(ValidationError, match='Input should be a valid datetime, invalid timezone sign'):
+        v.validate_python('2024-07-01 12:00:00 EST')
+
+    v = SchemaValidator(core_schema.datetime_schema(), {'named_timezones': True})
+    est = timezone(timedelta(hours=-5))
+    assert v.validate_python('2024-07-01 12:00:00 EST') == datetime(2024, 7, 1, 12, tzinfo=est)
+    # named timezones are a lax mode feature
+    with pytest.raises(ValidationError, match='Input should be a valid datetime'):
+        v.validate_python('2024-07-01 12:00:00 EST', strict=True)
+
+
+def has_tz_database():
+    v = SchemaValidator(core_schema.datetime_schema(named_timezones=True))
+    try:
+        v.validate_python('2000-01-01 00:00:00 Etc/UTC')
+    except ValidationError:
+        return False
+    else:
+        return True
+
+
+@pytest.mark.skipif(not has_tz_database(), reason='pydantic-core built without the "tz" feature')
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('2024-07-01 12:00:00 Europe/Paris', datetime(2024, 7, 1, 12, tzinfo=timezone(timedelta(hours=2)))),
+        ('2024-01-01 12:00:00 Europe/Paris', datetime(2024, 1, 1, 12, tzinfo=timezone(timedelta(hours=1)))),
+        # ambiguous times use the earlier offset
+        ('2024-10-27 02:30:00 Europe/Paris', datetime(2024, 10, 27, 2, 30, tzinfo=timezone(timedelta(hours=2)))),
+        ('2024-03-31 02:30:00 Europe/Paris', Err('local time does not exist in the timezone due to a DST transition')),
+        ('2024-07-01 12:00:00 America/New_York', datetime(2024, 7, 1, 12, tzinfo=timezone(timedelta(hours=-4)))),
+    ],
+)
+def test_named_timezones_tz_database(input_value, expected):
+    v = SchemaValidator(core_schema.datetime_schema(named_timezones=True))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        output = v.validate_python(input_value)
+        assert output == expected
+        assert output.utcoffset() == expected.utcoffset()
@@ -0,0 +1,174 @@
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
+
+from ..conftest import plain_repr
+
+
+def node_schema(ref: str, next_ref: str):
+    return core_schema.typed_dict_schema(
+        {
+            'value': core_schema.typed_dict_field(core_schema.int_schema()),
+            'next': core_schema.typed_dict_field(
+                core_schema.nullable_schema(core_schema.definition_reference_schema(next_ref))
+            ),
+        },
+        ref=ref,
+    )
+
+
+def test_linked_list():
+    v = SchemaValidator(
+        core_schema.definitions_schema(core_schema.definition_reference_schema('node'), [node_schema('node', 'node')])
+    )
+    assert ',slots=[TypedDict(TypedDictValidator{' in plain_repr(v)
+    assert v.validate_python({'value': '1', 'next': {'value': 2, 'next': None}}) == {
+        'value': 1,
+        'next': {'value': 2, 'next': None},
+    }
+    assert v.validate_json('{"value": 1, "next": {"value": 2, "next": {"value": 3, "next": null}}}') == {
+        'value': 1,
+        'next': {'value': 2, 'next': {'value': 3, 'next': None}},
+    }
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'value': 1, 'next': {'value': 'x', 'next': None}})
+    assert exc_info.value.errors() == [
+        {
+            'type': 'int_parsing',
+            'loc': ('next', 'value'),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+
+
+def test_tree():
+    v = SchemaValidator(
+        core_schema.definitions_schema(
+            core_schema.list_schema(core_schema.definition_reference_schema('tree')),
+            [
+                core_schema.typed_dict_schema(
+                    {
+                        'name': core_schema.typed_dict_field(core_schema.string_schema()),
+                        'children': core_schema.typed_dict_field(
+                            core_schema.list_schema(core_schema.definition_reference_schema('tree'))
+                        ),
+                    },
+                    ref='tree',
+                )
+            ],
+        )
+    )
+    tree = [{'name': 'root', 'children': [{'name': 'a', 'children': []}, {'name': 'b', 'children': []}]}]
+    assert v.validate_python(tree) == tree
+
+
+def test_mutual_recursion_forward_refs():
+    v = SchemaValidator(
+        core_schema.definitions_schema(
+            core_schema.definition_reference_schema('even'),
+            [node_schema('even', 'odd'), node_schema('odd', 'even')],
+        )
+    )
+    assert v.validate_python({'value': 0, 'next': {'value': 1, 'next': {'value': 2, 'next': None}}}) == {
+        'value': 0,
+        'next': {'value': 1, 'next': {'value': 2, 'next': None}},
+    }
+
+
+def test_unused_definition():
+    v = SchemaValidator(core_schema.definitions_schema(core_schema.int_schema(), [core_schema.string_schema(ref='s')]))
+    assert v.validate_python('1') == 1
+
+
+def test_alias_definition():
+    v = SchemaValidator(
+        core_schema.definitions_schema(
+            core_schema.definition_reference_schema('a'),
+            [core_schema.definition_reference_schema('b', ref='a'), core_schema.int_schema(ref='b')],
+        )
+    )
+    assert v.validate_python('1') == 1
+
+
+def test_recursion_loop():
+    v = SchemaValidator(
+        core_schema.definitions_schema(
+            core_schema.definition_reference_schema('list'),
+            [core_schema.list_schema(core_schema.definition_reference_schema('list'), ref='list')],
+        )
+    )
+    data = []
+    data.append(data)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(data)
+    assert exc_info.value.errors()[0]['type'] == 'recursion_loop'
+
+
+def test_same_input_different_definitions():
+    # the same object validated by two different definitions at once isn't a cycle
+    v = SchemaValidator(
+        core_schema.definitions_schema(
+            core_schema.definition_reference_schema('a'),
+            [
+                core_schema.nullable_schema(core_schema.definition_reference_schema('b'), ref='a'),
+                node_schema('b', 'b'),
+            ],
+        )
+    )
+    assert v.validate_python({'value': 1, 'next': {'value': 2, 'next': None}}) == {
+        'value': 1,
+        'next': {'value': 2, 'next': None},
+    }
+
+    data = {'value': 1}
+    data['next'] = data
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(data)
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('recursion_loop', ('next',))]
+
+
+def test_circular_reference():
+    with pytest.raises(SchemaError, match=r'Definitions error: circular reference `a` -> `b` -> `a`'):
+        SchemaValidator(
+            core_schema.definitions_schema(
+                core_schema.definition_reference_schema('a'),
+                [
+                    core_schema.definition_reference_schema('b', ref='a'),
+                    core_schema.definition_reference_schema('a', ref='b'),
+                ],
+            )
+        )
+
+
+def test_self_reference():
+    with pytest.raises(SchemaError, match=r'Definitions error: circular reference `a` -> `a`'):
+        SchemaValidator(
+            core_schema.definitions_schema(
+                core_schema.int_schema(), [core_schema.definition_reference_schema('a', ref='a')]
+            )
+        )
+
+
+def test_missing_definition():
+    with pytest.raises(SchemaError, match="Slots Error: ref 'missing' not found"):
+        SchemaValidator(
+            core_schema.definitions_schema(
+                core_schema.definition_reference_schema('missing'), [core_schema.int_schema(ref='a')]
+            )
+        )
+
+
+def test_duplicate_definition():
+    with pytest.raises(SchemaError, match='Definitions error: duplicate definition `a`'):
+        SchemaValidator(
+            core_schema.definitions_schema(
+                core_schema.int_schema(), [core_schema.int_schema(ref='a'), core_schema.string_schema(ref='a')]
+            )
+        )
+
+
+def test_definition_without_ref():
+    with pytest.raises(SchemaError, match='Definitions error: definitions must have a `ref`'):
+        SchemaValidator(core_schema.definitions_schema(core_schema.int_schema(), [core_schema.int_schema()]))
@@ -120,7 +120,7 @@ def test_mapping():
 
 def test_key_error():
     v = SchemaValidator({'type': 'dict', 'keys_schema': {'type': 'int'}, 'values_schema': {'type': 'int'}})
-    assert v.validate_python({'1': True}) == {1: 1}
+    assert v.validate_python({'1': 2}) == {1: 2}
     with pytest.raises(ValidationError, match=re.escape('x -> [key]\n  Input should be a valid integer')) as exc_info:
         v.validate_python({'x': 1})
     assert exc_info.value.errors() == [
@@ -218,3 +218,84 @@ def test_dict_length_constraints(kwargs: Dict[str, Any], input_value, expected):
             v.validate_python(input_value)
     else:
         assert v.validate_python(input_value) == expected
+
+
+class Slotted:
+    __slots__ = 'a', 'b'
+
+    def __init__(self, a, b):
+        self.a = a
+        self.b = b
+
+    @property
+    def c(self):
+        return '3'
+
+
+def test_dict_from_attributes():
+    v = SchemaValidator(
+        {'type': 'dict', 'keys_schema': {'type': 'str'}, 'values_schema': {'type': 'int'}, 'from_attributes': True}
+    )
+    assert v.validate_python({'a': '1'}) == {'a': 1}
+    assert v.validate_python(Slotted(1, '2')) == {'a': 1, 'b': 2, 'c': 3}
+
+    class Row:
+        def __init__(self):
+            self.x = '4'
+            self._private = 'wrong'
+
+        def method(self):
+            return 'wrong'
+
+    assert v.validate_python(Row()) == {'x': 4}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(Slotted(1, 'wrong'))
+    assert exc_info.value.errors() == [
+        {
+            'type': 'int_parsing',
+            'loc': ('b',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'wrong',
+        }
+    ]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(123)
+    assert exc_info.value.errors() == [
+        {
+            'type': 'dict_attributes_type',
+            'loc': (),
+            'msg': 'Input should be a valid dictionary or instance to extract fields from',
+            'input': 123,
+        }
+    ]
+
+
+def test_dict_from_attributes_disabled():
+    v = SchemaValidator({'type': 'dict', 'keys_schema': {'type': 'str'}, 'values_schema': {'type': 'int'}})
+    with pytest.raises(ValidationError, match='Input should be a valid dictionary'):
+        v.validate_python(Slotted(1, 2))
+
+
+def test_coerce_json_keys():
+    schema = {'type': 'dict', 'keys_schema': {'type': 'int'}, 'values_schema': {'type': 'int'}}
+    v = SchemaValidator(schema, {'strict': True})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"1": 2}')
+    assert exc_info.value.errors()[0]['type'] == 'int_type'
+
+    v = SchemaValidator({**schema, 'coerce_json_keys': True}, {'strict': True})
+    assert v.validate_json('{"1": 2, "3": 4}') == {1: 2, 3: 4}
+    # values are still validated strictly
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"1": "2"}')
+    assert exc_info.value.errors()[0]['loc'] == ('1',)
+    # python input isn't affected
+    with pytest.raises(ValidationError):
+        v.validate_python({'1': 2})
+
+
+def test_coerce_json_keys_config():
+    v = SchemaValidator({'type': 'dict', 'keys_schema': {'type': 'float'}}, {'strict': True, 'coerce_json_keys': True})
+    assert v.validate_json('{"1.5": 2}') == {1.5: 2}
@@ -262,6 +262,8 @@ def test_repr():
         'name="frozenset[any]",'
         'validator=FrozenSet(FrozenSetValidator{'
         'strict:true,item_validator:None,min_length:Some(42),max_length:None,generator_max_length:None,'
+        'coercion:CollectionCoercion{list_from_tuple:true,list_from_set:false,tuple_from_list:true,'
+        'tuple_from_set:false,set_from_list:true,set_duplicates_error:false,from_iter:true,from_str:false},'
         'name:"frozenset[any]"'
         '}),slots=[])'
     )
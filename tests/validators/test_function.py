@@ -1,5 +1,6 @@
 import platform
 import re
+import sys
 from copy import deepcopy
 from typing import Type
 
@@ -56,7 +57,7 @@ def test_function_before_error():
             'loc': (),
             'msg': 'String should have at most 5 characters',
             'input': '12345x',
-            'ctx': {'max_length': 5},
+            'ctx': {'field_type': 'String', 'max_length': 5, 'actual_length': 6},
         }
     ]
     assert repr(exc_info.value).startswith('1 validation error for function-before[my_function(), constrained-str]\n')
@@ -86,7 +87,7 @@ def test_function_before_error_model():
             'loc': ('my_field',),
             'msg': 'String should have at most 5 characters',
             'input': '12345x',
-            'ctx': {'max_length': 5},
+            'ctx': {'field_type': 'String', 'max_length': 5, 'actual_length': 6},
         }
     ]
 
@@ -205,7 +206,13 @@ def test_function_after_data():
     )
 
     assert v.validate_python({'field_a': '123', 'field_b': b'321'}) == {'field_a': 123, 'field_b': '321 Changed'}
-    assert f_kwargs == {'data': {'field_a': 123}, 'config': None, 'context': None}
+    assert f_kwargs == {
+        'data': {'field_a': 123},
+        'config': None,
+        'context': None,
+        'field_name': 'field_b',
+        'mode': 'python',
+    }
 
 
 def test_function_after_config():
@@ -229,7 +236,13 @@ def test_function_after_config():
     )
 
     assert v.validate_python({'test_field': b'321'}) == {'test_field': '321 Changed'}
-    assert f_kwargs == {'data': {}, 'config': {'config_choose_priority': 2}, 'context': None}
+    assert f_kwargs == {
+        'data': {},
+        'config': {'config_choose_priority': 2},
+        'context': None,
+        'field_name': 'test_field',
+        'mode': 'python',
+    }
 
 
 def test_config_no_model():
@@ -243,7 +256,55 @@ def test_config_no_model():
     v = SchemaValidator({'type': 'function', 'mode': 'after', 'function': f, 'schema': {'type': 'str'}})
 
     assert v.validate_python(b'abc') == 'abc Changed'
-    assert f_kwargs == {'data': None, 'config': None, 'context': None}
+    assert f_kwargs == {'data': None, 'config': None, 'context': None, 'field_name': None, 'mode': 'python'}
+
+
+@pytest.mark.parametrize('mode', ['before', 'after', 'wrap', 'plain'])
+def test_field_name_and_data(mode):
+    calls = []
+
+    def f(input_value, **kwargs):
+        calls.append((kwargs['field_name'], dict(kwargs['data']), kwargs['mode']))
+        if mode == 'wrap':
+            return kwargs['validator'](input_value)
+        return input_value
+
+    function_schema = {'type': 'function', 'mode': mode, 'function': f}
+    if mode != 'plain':
+        function_schema['schema'] = {'type': 'int'}
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {'start': {'schema': {'type': 'int'}}, 'end': {'schema': function_schema}},
+        }
+    )
+
+    assert v.validate_python({'start': '1', 'end': 2}) == {'start': 1, 'end': 2}
+    assert v.validate_json('{"start": 1, "end": 2}') == {'start': 1, 'end': 2}
+    assert calls == [('end', {'start': 1}, 'python'), ('end', {'start': 1}, 'json')]
+
+
+def test_cross_field_check():
+    def check_end(input_value, *, data, field_name, **kwargs):
+        if input_value <= data['start']:
+            raise ValueError(f'{field_name} must be after start')
+        return input_value
+
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'start': {'schema': {'type': 'int'}},
+                'end': {
+                    'schema': {'type': 'function', 'mode': 'after', 'function': check_end, 'schema': {'type': 'int'}}
+                },
+            },
+        }
+    )
+
+    assert v.validate_python({'start': 1, 'end': 2}) == {'start': 1, 'end': 2}
+    with pytest.raises(ValidationError, match=r'end\n +Value error, end must be after start'):
+        v.validate_python({'start': 2, 'end': 1})
 
 
 def test_function_plain():
@@ -277,7 +338,97 @@ def test_validate_assignment():
 
     m = {'field_a': 'test', 'more': 'foobar'}
     assert v.validate_python({'field_a': 'test'}) == m
-    assert v.validate_assignment('field_a', b'abc', m) == {'field_a': 'abc', 'more': 'foobar'}
+    assert v.validate_assignment(m, 'field_a', b'abc') == {'field_a': 'abc', 'more': 'foobar'}
+
+
+def test_before_validate_assignment_error_loc():
+    def f(input_value, **kwargs):
+        if input_value == 'wrong':
+            raise ValueError('not allowed')
+        return input_value
+
+    v = SchemaValidator(
+        {
+            'type': 'function',
+            'mode': 'before',
+            'function': f,
+            'schema': {'type': 'typed-dict', 'fields': {'field_a': {'schema': {'type': 'str'}}}},
+        }
+    )
+
+    assert v.validate_assignment({'field_a': 'test'}, 'field_a', 'other') == {'field_a': 'other'}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_assignment({'field_a': 'test'}, 'field_a', 'wrong')
+    assert exc_info.value.errors() == [
+        {
+            'type': 'value_error',
+            'loc': ('field_a',),
+            'msg': 'Value error, not allowed',
+            'input': 'wrong',
+            'ctx': {'error': 'not allowed'},
+        }
+    ]
+
+
+@pytest.mark.parametrize('mode', ['before', 'after', 'wrap'])
+def test_skip_on_assignment(mode):
+    calls = []
+
+    def f(input_value, **kwargs):
+        calls.append(input_value)
+        if mode == 'wrap':
+            return kwargs['validator'](input_value)
+        return input_value
+
+    v = SchemaValidator(
+        {
+            'type': 'function',
+            'mode': mode,
+            'function': f,
+            'skip_on_assignment': True,
+            'schema': {'type': 'typed-dict', 'fields': {'field_a': {'schema': {'type': 'int'}}}},
+        }
+    )
+
+    assert v.validate_python({'field_a': '1'}) == {'field_a': 1}
+    assert len(calls) == 1
+    assert v.validate_assignment({'field_a': 1}, 'field_a', '2') == {'field_a': 2}
+    assert len(calls) == 1
+
+
+@pytest.mark.parametrize('mode', ['before', 'after', 'wrap'])
+def test_skip_from_attributes(mode):
+    calls = []
+
+    def f(input_value, **kwargs):
+        calls.append(input_value)
+        if mode == 'wrap':
+            return kwargs['validator'](input_value)
+        return input_value
+
+    class Cls:
+        def __init__(self, field_a):
+            self.field_a = field_a
+
+    v = SchemaValidator(
+        {
+            'type': 'function',
+            'mode': mode,
+            'function': f,
+            'skip_from_attributes': True,
+            'schema': {
+                'type': 'typed-dict',
+                'from_attributes': True,
+                'fields': {'field_a': {'schema': {'type': 'int'}}},
+            },
+        }
+    )
+
+    assert v.validate_python(Cls(field_a='1')) == {'field_a': 1}
+    assert calls == []
+    assert v.validate_python({'field_a': '2'}) == {'field_a': 2}
+    assert v.validate_json('{"field_a": 3}') == {'field_a': 3}
+    assert len(calls) == 2
 
 
 def test_function_wrong_sig():
@@ -390,3 +541,65 @@ def test_raise_type_error():
 
     with pytest.raises(TypeError, match='^foobar$'):
         v.validate_python('input value')
+
+
+def test_validation_error_cause_default():
+    def f(input_value, **kwargs):
+        raise ValueError('foobar')
+
+    v = SchemaValidator({'type': 'function', 'mode': 'plain', 'function': f})
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('input value')
+    assert exc_info.value.__cause__ is None
+
+
+@pytest.mark.skipif(sys.version_info < (3, 11), reason='ExceptionGroup requires python 3.11')
+@pytest.mark.parametrize('base_error', [ValueError, AssertionError])
+def test_validation_error_cause(base_error: Type[Exception]):
+    def f(input_value, **kwargs):
+        raise base_error(f'bad {input_value}')
+
+    v = SchemaValidator(
+        {
+            'type': 'list',
+            'items_schema': {'type': 'function', 'mode': 'after', 'function': f, 'schema': {'type': 'int'}},
+        },
+        {'validation_error_cause': True},
+    )
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, 2])
+    assert len(exc_info.value.errors()) == 2
+    cause = exc_info.value.__cause__
+    assert isinstance(cause, ExceptionGroup)  # noqa: F821
+    assert str(cause) == '2 error(s) raised in validator functions (2 sub-exceptions)'
+    assert [type(e) for e in cause.exceptions] == [base_error, base_error]
+    assert [str(e) for e in cause.exceptions] == ['bad 1', 'bad 2']
+    # the original traceback is preserved for debugging
+    assert cause.exceptions[0].__traceback__ is not None
+
+
+@pytest.mark.skipif(sys.version_info < (3, 11), reason='ExceptionGroup requires python 3.11')
+def test_validation_error_cause_wrap():
+    def inner(input_value, **kwargs):
+        raise ValueError('inner error')
+
+    def outer(input_value, *, validator, **kwargs):
+        return validator(input_value)
+
+    v = SchemaValidator(
+        {
+            'type': 'function',
+            'mode': 'wrap',
+            'function': outer,
+            'schema': {'type': 'function', 'mode': 'plain', 'function': inner},
+        },
+        {'validation_error_cause': True},
+    )
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('input value')
+    # the cause survives being re-raised through the wrap validator
+    cause = exc_info.value.__cause__
+    assert [str(e) for e in cause.exceptions] == ['inner error']
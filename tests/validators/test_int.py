@@ -4,7 +4,7 @@ from typing import Any, Dict
 
 import pytest
 
-from pydantic_core import SchemaValidator, ValidationError
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
 
 from ..conftest import Err, PyAndJson, plain_repr
 
@@ -12,8 +12,8 @@ from ..conftest import Err, PyAndJson, plain_repr
 @pytest.mark.parametrize(
     'input_value,expected',
     [
-        (False, 0),
-        (True, 1),
+        pytest.param(False, Err('Input should be a valid integer [type=int_type'), id='false'),
+        pytest.param(True, Err('Input should be a valid integer [type=int_type'), id='true'),
         (0, 0),
         ('0', 0),
         (1, 1),
@@ -26,7 +26,7 @@ from ..conftest import Err, PyAndJson, plain_repr
         (int(1e10), int(1e10)),
         pytest.param(
             12.5,
-            Err('Input should be a valid integer, got a number with a fractional part [type=int_from_float'),
+            Err('Input should be a valid integer, got a number with a fractional part [type=int_from_float_inexact'),
             id='float-remainder',
         ),
         pytest.param(
@@ -54,11 +54,14 @@ def test_int_py_and_json(py_and_json: PyAndJson, input_value, expected):
     [
         (Decimal('1'), 1),
         (Decimal('1.0'), 1),
+        (Decimal('1e3'), 1000),
+        (Decimal('123456789012345678901234567890'), 123456789012345678901234567890),
+        pytest.param(Decimal('NaN'), Err('Input should be a finite number [type=finite_number'), id='decimal-nan'),
         pytest.param(
             Decimal('1.001'),
             Err(
                 'Input should be a valid integer, got a number with a fractional part '
-                "[type=int_from_float, input_value=Decimal('1.001'), input_type=Decimal]"
+                "[type=int_from_float_inexact, input_value=Decimal('1.001'), input_type=Decimal]"
             ),
             id='decimal-remainder',
         ),
@@ -192,9 +195,13 @@ def test_union_int_simple(py_and_json: PyAndJson):
 
 def test_int_repr():
     v = SchemaValidator({'type': 'int'})
-    assert plain_repr(v) == 'SchemaValidator(name="int",validator=Int(IntValidator{strict:false}),slots=[])'
+    assert plain_repr(v) == (
+        'SchemaValidator(name="int",validator=Int(IntValidator{strict:false,coerce_bools:false}),slots=[])'
+    )
     v = SchemaValidator({'type': 'int', 'strict': True})
-    assert plain_repr(v) == 'SchemaValidator(name="int",validator=Int(IntValidator{strict:true}),slots=[])'
+    assert plain_repr(v) == (
+        'SchemaValidator(name="int",validator=Int(IntValidator{strict:true,coerce_bools:false}),slots=[])'
+    )
     v = SchemaValidator({'type': 'int', 'multiple_of': 7})
     assert plain_repr(v).startswith('SchemaValidator(name="constrained-int",validator=ConstrainedInt(')
 
@@ -231,3 +238,72 @@ def test_int_key(py_and_json: PyAndJson):
     assert v.validate_test({'1': 1, '2': 2}) == {1: 1, 2: 2}
     with pytest.raises(ValidationError, match='Input should be a valid integer'):
         v.validate_test({'1': 1, '2': 2}, strict=True)
+
+
+def test_big_int():
+    v = SchemaValidator({'type': 'int'})
+    assert v.validate_python(2**64) == 2**64
+    assert v.validate_python(-(2**100)) == -(2**100)
+    assert SchemaValidator({'type': 'int', 'strict': True}).validate_python(2**64) == 2**64
+
+
+@pytest.mark.parametrize(
+    'kwargs,input_value,expected',
+    [
+        ({'gt': 2**64}, 2**64 + 1, 2**64 + 1),
+        ({'gt': 2**64}, 1, Err('Input should be greater than 18446744073709551616 [type=greater_than')),
+        ({'gt': 2**64}, 2**64, Err('Input should be greater than 18446744073709551616 [type=greater_than')),
+        ({'le': 2**64}, 2**64, 2**64),
+        ({'le': 2**64}, 2**64 + 1, Err('Input should be less than or equal to 18446744073709551616 [type=less_than_e')),
+        ({'lt': -(2**64)}, -(2**65), -(2**65)),
+        ({'ge': 10}, 2**100, 2**100),
+        ({'le': 10}, 2**100, Err('Input should be less than or equal to 10 [type=less_than_equal')),
+        ({'multiple_of': 2**64}, 2**65, 2**65),
+        ({'multiple_of': 2**64}, 2**64 + 1, Err('Input should be a multiple of 18446744073709551616')),
+        ({'multiple_of': 3}, 3 * 2**70, 3 * 2**70),
+        ({'multiple_of': 3}, 2**70, Err('Input should be a multiple of 3 [type=multiple_of')),
+        ({'multiple_of': -1}, -(2**63), -(2**63)),
+    ],
+)
+def test_big_int_constraints(kwargs: Dict[str, Any], input_value, expected):
+    v = SchemaValidator({'type': 'int', **kwargs})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+def test_multiple_of_zero():
+    with pytest.raises(SchemaError, match="'multiple_of' must not be zero"):
+        SchemaValidator({'type': 'int', 'multiple_of': 0})
+
+
+def test_big_int_constraint_context():
+    v = SchemaValidator({'type': 'int', 'gt': 2**64})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('1')
+    assert exc_info.value.errors()[0]['ctx'] == {'gt': 2**64}
+
+
+@pytest.mark.parametrize('input_value,expected', [(True, 1), (False, 0), (1, 1)])
+def test_coerce_bools(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json({'type': 'int', 'coerce_bools': True})
+    output = v.validate_test(input_value)
+    assert output == expected
+    assert type(output) == int
+
+
+def test_coerce_bools_config():
+    v = SchemaValidator({'type': 'int', 'gt': 0}, {'coerce_bools': True})
+    assert v.validate_python(True) == 1
+    with pytest.raises(ValidationError, match=r'Input should be greater than 0 \[type=greater_than,'):
+        v.validate_python(False)
+    with pytest.raises(ValidationError, match=r'Input should be a valid integer \[type=int_type,'):
+        v.validate_python(True, strict=True)
+
+
+@pytest.mark.parametrize('input_value,expected', [(1e20, 10**20), (-1e20, -(10**20)), (2.0**63, 2**63)])
+def test_big_float(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json({'type': 'int'})
+    assert v.validate_test(input_value) == expected
@@ -24,12 +24,23 @@ from ..conftest import Err, PyAndJson, plain_repr
                         'loc': (),
                         'msg': 'Invalid JSON: key must be a string at line 1 column 2',
                         'input': '{1: 2}',
-                        'ctx': {'error': 'key must be a string at line 1 column 2'},
+                        'ctx': {
+                            'error': 'key must be a string at line 1 column 2',
+                            'line': 1,
+                            'column': 2,
+                            'offset': 1,
+                        },
                     }
                 ],
             ),
         ),
-        (44, Err('JSON input should be string, bytes or bytearray [type=json_type, input_value=44, input_type=int')),
+        (
+            44,
+            Err(
+                'JSON input should be string, bytes, bytearray or memoryview '
+                '[type=json_type, input_value=44, input_type=int'
+            ),
+        ),
     ],
 )
 def test_any(py_and_json: PyAndJson, input_value, expected):
@@ -51,6 +62,7 @@ def test_any(py_and_json: PyAndJson, input_value, expected):
         ('{"a": 1}', {'a': 1}),
         (b'{"a": 1}', {'a': 1}),
         (bytearray(b'{"a": 1}'), {'a': 1}),
+        (memoryview(b'{"a": 1}'), {'a': 1}),
         (
             'xx',
             Err(
@@ -136,7 +148,7 @@ def test_dict_key(py_and_json: PyAndJson):
             'loc': ('x', '[key]'),
             'msg': 'Invalid JSON: expected value at line 1 column 1',
             'input': 'x',
-            'ctx': {'error': 'expected value at line 1 column 1'},
+            'ctx': {'error': 'expected value at line 1 column 1', 'line': 1, 'column': 1, 'offset': 0},
         }
     ]
 
@@ -1,3 +1,4 @@
+import json
 import platform
 import re
 from collections import deque
@@ -396,3 +397,118 @@ def test_bad_iter(items_schema):
             'ctx': {'error': 'RuntimeError: broken'},
         }
     ]
+
+
+@pytest.mark.parametrize(
+    'items_schema,items,expected',
+    [
+        ({'type': 'int'}, ['1', 2, 3.0], [1, 2, 3]),
+        ({'type': 'int', 'coerce_bools': True}, [True, 2], [1, 2]),
+        ({'type': 'int'}, [1, 2**64], [1, 2**64]),
+        ({'type': 'float'}, [1, '2.5', 3.5], [1.0, 2.5, 3.5]),
+        ({'type': 'bool'}, [True, 'false', 1], [True, False, True]),
+        ({'type': 'bool', 'true_values': ['si'], 'false_values': ['no']}, ['si', 'no'], [True, False]),
+        ({'type': 'str'}, ['a', 'b', 'ç'], ['a', 'b', 'ç']),
+    ],
+)
+def test_parallel(items_schema, items, expected):
+    v = SchemaValidator({'type': 'list', 'items_schema': items_schema, 'parallel': True})
+    assert v.validate_json(json.dumps(items * 1000)) == expected * 1000
+
+
+def test_parallel_errors():
+    v = SchemaValidator({'type': 'list', 'items_schema': {'type': 'int'}, 'parallel': True, 'max_length': 5000})
+    items = list(range(2000))
+    items[1234] = 'x'
+    items[1500] = 1.5
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json(json.dumps(items))
+    # errors are exactly as they are without `parallel`
+    assert exc_info.value.errors() == [
+        {
+            'type': 'int_parsing',
+            'loc': (1234,),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        },
+        {
+            'type': 'int_from_float_inexact',
+            'loc': (1500,),
+            'msg': 'Input should be a valid integer, got a number with a fractional part',
+            'input': 1.5,
+        },
+    ]
+
+    with pytest.raises(ValidationError, match='List should have at most 5000 items after validation, not 6000'):
+        v.validate_json(json.dumps(list(range(6000))))
+
+
+def test_parallel_strict():
+    v = SchemaValidator({'type': 'list', 'items_schema': {'type': 'int'}}, {'strict': True, 'list_parallel': True})
+    assert v.validate_json(json.dumps(list(range(2000)))) == list(range(2000))
+    with pytest.raises(ValidationError, match=r'list\[int\]\n1999\n  Input should be a valid integer \[type=int_type'):
+        v.validate_json(json.dumps(list(range(1999)) + ['1999']))
+
+    schema = {'type': 'list', 'items_schema': {'type': 'float', 'allow_inf_nan': False}}
+    v = SchemaValidator(schema, {'list_parallel': True})
+    assert v.validate_json(json.dumps([1.5] * 2000)) == [1.5] * 2000
+    assert v.validate_json(json.dumps([1.5] * 2000), strict=True) == [1.5] * 2000
+    with pytest.raises(ValidationError, match=r'list\[float\]\n2000\n  Input should be a finite number'):
+        v.validate_json(json.dumps([1.5] * 2000 + ['NaN']))
+
+
+def test_parallel_unsupported_items():
+    # items which might need python, e.g. function validators, are always validated on the current thread
+    v = SchemaValidator(
+        {
+            'type': 'list',
+            'items_schema': {'type': 'function', 'mode': 'plain', 'function': lambda v, **kwargs: v * 2},
+            'parallel': True,
+        }
+    )
+    assert v.validate_json(json.dumps(list(range(2000)))) == [i * 2 for i in range(2000)]
+
+    # python input is always validated on the current thread
+    v = SchemaValidator({'type': 'list', 'items_schema': {'type': 'int'}, 'parallel': True})
+    assert v.validate_python(['1', 2] * 1000) == [1, 2] * 1000
+
+
+@pytest.mark.parametrize(
+    'config,input_value,expected',
+    [
+        ({}, (1, 2), [1, 2]),
+        ({'list_from_tuple': False}, (1, 2), Err('Input should be a valid list/array [type=list_type,')),
+        ({'list_from_tuple': False}, [1, 2], [1, 2]),
+        ({}, {1, 2}, Err('Input should be a valid list/array [type=list_type,')),
+        ({'list_from_set': True}, {1, 2}, [1, 2]),
+        ({'list_from_set': True}, frozenset({1, 2}), [1, 2]),
+        ({}, (i for i in [1, 2]), [1, 2]),
+        ({'collection_from_iter': False}, (i for i in [1, 2]), Err('Input should be a valid list/array')),
+        ({'collection_from_iter': False}, deque([1, 2]), Err('Input should be a valid list/array')),
+        ({'collection_from_iter': False}, {1: 10, 2: 20}.keys(), Err('Input should be a valid list/array')),
+        ({}, '12', Err('Input should be a valid list/array [type=list_type,')),
+        ({'collection_from_str': True}, '12', [1, 2]),
+        ({'collection_from_str': True}, b'\x01\x02', [1, 2]),
+    ],
+)
+def test_list_coercion_config(config, input_value, expected):
+    v = SchemaValidator({'type': 'list', 'items_schema': {'type': 'int'}}, config)
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+def test_list_coercion_config_strict():
+    v = SchemaValidator({'type': 'list', 'strict': True}, {'list_from_set': True, 'collection_from_str': True})
+    with pytest.raises(ValidationError, match='Input should be a valid list/array'):
+        v.validate_python({1, 2})
+    with pytest.raises(ValidationError, match='Input should be a valid list/array'):
+        v.validate_python('ab')
+
+
+def test_list_from_str_json():
+    v = SchemaValidator({'type': 'list', 'items_schema': {'type': 'str'}}, {'collection_from_str': True})
+    assert v.validate_json('"ab"') == ['a', 'b']
+    assert v.validate_json('["ab"]') == ['ab']
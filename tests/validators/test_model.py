@@ -0,0 +1,194 @@
+import re
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
+
+
+class MyModel:
+    # this is not required, but it avoids `__fields_set__` being included in `__dict__`
+    __slots__ = '__dict__', '__fields_set__'
+
+    def __init__(self, **kwargs):
+        raise RuntimeError('__init__ should not be called')
+
+
+def model_schema(cls=MyModel, **kwargs):
+    return core_schema.model_schema(
+        cls,
+        core_schema.typed_dict_schema(
+            {
+                'field_a': core_schema.typed_dict_field(core_schema.string_schema()),
+                'field_b': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(core_schema.int_schema(), default=0), required=False
+                ),
+            },
+            return_fields_set=True,
+        ),
+        **kwargs,
+    )
+
+
+def test_model():
+    v = SchemaValidator(model_schema())
+    m = v.validate_python({'field_a': 'test', 'field_b': '12'})
+    assert isinstance(m, MyModel)
+    assert m.__dict__ == {'field_a': 'test', 'field_b': 12}
+    assert m.__fields_set__ == {'field_a', 'field_b'}
+
+    m2 = v.validate_json('{"field_a": "test"}')
+    assert m2.__dict__ == {'field_a': 'test', 'field_b': 0}
+    assert m2.__fields_set__ == {'field_a'}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'field_b': 'x'})
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [
+        ('missing', ('field_a',)),
+        ('int_parsing', ('field_b',)),
+    ]
+
+
+def test_model_strict():
+    v = SchemaValidator(model_schema(strict=True))
+    m = v.validate_python(v.validate_python({'field_a': 'test'}, strict=False))
+    assert m.__dict__ == {'field_a': 'test', 'field_b': 0}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'field_a': 'test'})
+    assert exc_info.value.errors() == [
+        {
+            'type': 'model_class_type',
+            'loc': (),
+            'msg': 'Input should be an instance of MyModel',
+            'input': {'field_a': 'test'},
+            'ctx': {'class_name': 'MyModel'},
+        }
+    ]
+
+
+class SubModel(MyModel):
+    pass
+
+
+@pytest.mark.parametrize(
+    'revalidate_instances,revalidate_instance,revalidate_subclass',
+    [(None, False, False), ('never', False, False), ('always', True, True), ('subclass-instances', False, True)],
+)
+def test_revalidate_instances(revalidate_instances, revalidate_instance, revalidate_subclass):
+    v = SchemaValidator(model_schema(revalidate_instances=revalidate_instances))
+    m = v.validate_python({'field_a': 'test', 'field_b': 1})
+    m.__fields_set__ = {'field_a'}
+    m2 = v.validate_python(m)
+    assert (m2 is not m) == revalidate_instance
+    assert m2.__dict__ == {'field_a': 'test', 'field_b': 1}
+    assert m2.__fields_set__ == {'field_a'}
+
+    sub = SubModel.__new__(SubModel)
+    sub.__dict__.update(field_a='sub', field_b='2')
+    sub.__fields_set__ = {'field_a', 'field_b'}
+    sub2 = v.validate_python(sub)
+    if revalidate_subclass:
+        # revalidated instances are always of the model class
+        assert type(sub2) is MyModel
+        assert sub2.__dict__ == {'field_a': 'sub', 'field_b': 2}
+        assert sub2.__fields_set__ == {'field_a', 'field_b'}
+    else:
+        assert sub2 is sub
+
+
+def test_forbid_subclass_instances():
+    v = SchemaValidator(model_schema(), {'revalidate_instances': 'forbid-subclass-instances'})
+    m = v.validate_python({'field_a': 'test'})
+    assert v.validate_python(m) is m
+
+    sub = SubModel.__new__(SubModel)
+    sub.__dict__.update(field_a='sub')
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(sub)
+    assert exc_info.value.errors() == [
+        {
+            'type': 'model_class_type',
+            'loc': (),
+            'msg': 'Input should be an instance of MyModel',
+            'input': sub,
+            'ctx': {'class_name': 'MyModel'},
+        }
+    ]
+    # data is still validated as usual
+    assert v.validate_python({'field_a': 'x'}).__dict__ == {'field_a': 'x', 'field_b': 0}
+
+
+def test_revalidate_instances_error():
+    v = SchemaValidator(model_schema(), {'revalidate_instances': 'always'})
+    m = v.validate_python({'field_a': 'test'})
+    m.__dict__['field_b'] = 'not int'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(m)
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('int_parsing', ('field_b',))]
+
+
+def test_revalidate_instances_invalid():
+    with pytest.raises(SchemaError, match="Invalid revalidate_instances value: sometimes, expected 'never'"):
+        SchemaValidator(model_schema(), {'revalidate_instances': 'sometimes'})
+
+
+def test_post_init():
+    calls = []
+
+    class PostInitModel:
+        __slots__ = '__dict__', '__fields_set__'
+
+        def call_me(self, context):
+            calls.append((self.__dict__, context))
+
+    v = SchemaValidator(model_schema(PostInitModel, post_init='call_me', revalidate_instances='always'))
+    m = v.validate_python({'field_a': 'test'}, context={'foo': 'bar'})
+    assert calls == [({'field_a': 'test', 'field_b': 0}, {'foo': 'bar'})]
+    v.validate_python(m)
+    assert calls[1] == ({'field_a': 'test', 'field_b': 0}, None)
+
+
+def test_post_init_error():
+    class PostInitModel:
+        __slots__ = '__dict__', '__fields_set__'
+
+        def call_me(self, context):
+            raise ValueError('this is broken')
+
+    v = SchemaValidator(model_schema(PostInitModel, post_init='call_me'))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'field_a': 'test'})
+    assert [(e['type'], e['msg']) for e in exc_info.value.errors()] == [('value_error', 'Value error, this is broken')]
+
+
+def test_self_instance():
+    calls = []
+
+    class InitModel:
+        __slots__ = '__dict__', '__fields_set__'
+
+        def __init__(self, **data):
+            v.validate_python(data, self_instance=self)
+
+        def call_me(self, context):
+            calls.append(self.__dict__)
+
+    v = SchemaValidator(model_schema(InitModel, post_init='call_me'))
+    m = InitModel(field_a='x')
+    assert m.__dict__ == {'field_a': 'x', 'field_b': 0}
+    assert m.__fields_set__ == {'field_a'}
+    assert calls == [{'field_a': 'x', 'field_b': 0}]
+
+
+def test_validate_assignment():
+    v = SchemaValidator(model_schema())
+    m = v.validate_python({'field_a': 'test'})
+    assert v.validate_assignment(m, 'field_b', '3') is m
+    assert m.__dict__ == {'field_a': 'test', 'field_b': 3}
+    assert m.__fields_set__ == {'field_a', 'field_b'}
+
+    with pytest.raises(ValidationError):
+        v.validate_assignment(m, 'field_b', 'x')
+    assert m.__dict__ == {'field_a': 'test', 'field_b': 3}
+
+    with pytest.raises(TypeError, match=re.escape('validate_assignment expects an instance of MyModel')):
+        v.validate_assignment({'field_a': 'test'}, 'field_b', 3)
@@ -620,3 +620,341 @@ def test_call_after_init_mutate():
     assert m.field_b == 12
     assert m.__fields_set__ == {'field_a'}
     assert m.__dict__ == {'field_a': 'testtest', 'field_b': 12}
+
+
+def test_self_instance():
+    class MyModel:
+        __slots__ = '__dict__', '__fields_set__'
+        field_a: str
+        field_b: int
+
+        def __init__(self, **data):
+            validator.validate_python(data, self_instance=self)
+
+    validator = SchemaValidator(
+        {
+            'type': 'new-class',
+            'cls': MyModel,
+            'schema': {
+                'type': 'typed-dict',
+                'return_fields_set': True,
+                'fields': {
+                    'field_a': {'schema': {'type': 'str'}},
+                    'field_b': {'schema': {'type': 'default', 'schema': {'type': 'int'}, 'default': 1}},
+                },
+            },
+        }
+    )
+    m = MyModel(field_a='test')
+    assert isinstance(m, MyModel)
+    assert m.__dict__ == {'field_a': 'test', 'field_b': 1}
+    assert m.__fields_set__ == {'field_a'}
+
+    m2 = MyModel(field_a='x', field_b='2')
+    assert m2.__dict__ == {'field_a': 'x', 'field_b': 2}
+    assert m.__dict__ == {'field_a': 'test', 'field_b': 1}
+
+    with pytest.raises(ValidationError, match=r'field_b\n  Input should be a valid integer'):
+        MyModel(field_a='x', field_b='wrong')
+
+    # validating from python data still creates a new instance without calling `__init__`
+    m3 = validator.validate_python({'field_a': 'y'})
+    assert m3 is not m
+    assert m3.__dict__ == {'field_a': 'y', 'field_b': 1}
+
+
+def test_self_instance_nested_and_after_init():
+    calls = []
+
+    class Inner:
+        __slots__ = '__dict__', '__fields_set__'
+
+        def __init__(self, **data):
+            raise RuntimeError('should not be called')
+
+    class Outer:
+        __slots__ = '__dict__', '__fields_set__'
+
+        def __post_init__(self, context):
+            calls.append((self.__dict__, context))
+
+    inner_schema = {
+        'type': 'new-class',
+        'cls': Inner,
+        'schema': {'type': 'typed-dict', 'return_fields_set': True, 'fields': {'x': {'schema': {'type': 'int'}}}},
+    }
+    v = SchemaValidator(
+        {
+            'type': 'new-class',
+            'cls': Outer,
+            'call_after_init': '__post_init__',
+            'schema': {
+                'type': 'typed-dict',
+                'return_fields_set': True,
+                'fields': {'inner': {'schema': inner_schema}},
+            },
+        },
+        {'strict': True},
+    )
+    outer = Outer()
+    assert v.validate_python({'inner': Inner.__new__(Inner)}, None, 'ctx', self_instance=outer) is outer
+    assert isinstance(outer.inner, Inner)
+    assert outer.inner is not outer
+    assert calls == [({'inner': outer.inner}, 'ctx')]
+
+
+def test_validate_assignment():
+    class MyModel:
+        __slots__ = '__dict__', '__fields_set__'
+        field_a: str
+        field_b: int
+
+    v = SchemaValidator(
+        {
+            'type': 'new-class',
+            'cls': MyModel,
+            'schema': {
+                'type': 'typed-dict',
+                'return_fields_set': True,
+                'fields': {
+                    'field_a': {'schema': {'type': 'str'}},
+                    'field_b': {'schema': {'type': 'default', 'schema': {'type': 'int'}, 'default': 1}},
+                },
+            },
+        }
+    )
+    m = v.validate_python({'field_a': 'test'})
+    original_dict = m.__dict__
+    assert v.validate_assignment(m, 'field_b', '12') is m
+    assert m.__dict__ == {'field_a': 'test', 'field_b': 12}
+    assert m.__fields_set__ == {'field_a', 'field_b'}
+    assert original_dict == {'field_a': 'test', 'field_b': 1}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_assignment(m, 'field_b', 'wrong')
+    assert exc_info.value.errors() == [
+        {
+            'type': 'int_parsing',
+            'loc': ('field_b',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'wrong',
+        }
+    ]
+    assert m.__dict__ == {'field_a': 'test', 'field_b': 12}
+
+    with pytest.raises(TypeError, match='validate_assignment expects an instance of MyModel'):
+        v.validate_assignment({'field_a': 'test'}, 'field_b', 2)
+
+
+def test_validate_assignment_model_validator():
+    calls = []
+
+    def f(input_value, **kwargs):
+        model_dict, fields_set = input_value
+        calls.append((dict(model_dict), fields_set))
+        model_dict['field_c'] = model_dict['field_a'] * 2
+        return model_dict, fields_set
+
+    class MyModel:
+        __slots__ = '__dict__', '__fields_set__'
+
+    v = SchemaValidator(
+        {
+            'type': 'new-class',
+            'cls': MyModel,
+            'schema': {
+                'type': 'function',
+                'mode': 'after',
+                'function': f,
+                'schema': {
+                    'type': 'typed-dict',
+                    'return_fields_set': True,
+                    'fields': {'field_a': {'schema': {'type': 'str'}}},
+                },
+            },
+        }
+    )
+    m = v.validate_python({'field_a': 'x'})
+    assert m.__dict__ == {'field_a': 'x', 'field_c': 'xx'}
+
+    v.validate_assignment(m, 'field_a', b'y')
+    assert m.__dict__ == {'field_a': 'y', 'field_c': 'yy'}
+    assert m.__fields_set__ == {'field_a'}
+    assert calls == [({'field_a': 'x'}, {'field_a'}), ({'field_a': 'y', 'field_c': 'xx'}, {'field_a'})]
+
+
+def test_validate_assignment_nested():
+    class Inner:
+        __slots__ = '__dict__', '__fields_set__'
+
+    class Outer:
+        __slots__ = '__dict__', '__fields_set__'
+
+    inner_schema = {
+        'type': 'new-class',
+        'cls': Inner,
+        'schema': {'type': 'typed-dict', 'return_fields_set': True, 'fields': {'x': {'schema': {'type': 'int'}}}},
+    }
+    v = SchemaValidator(
+        {
+            'type': 'new-class',
+            'cls': Outer,
+            'schema': {'type': 'typed-dict', 'return_fields_set': True, 'fields': {'inner': {'schema': inner_schema}}},
+        }
+    )
+    m = v.validate_python({'inner': {'x': 1}})
+    old_inner = m.inner
+    v.validate_assignment(m, 'inner', {'x': '2'})
+    assert isinstance(m.inner, Inner)
+    assert m.inner is not old_inner
+    assert m.inner.__dict__ == {'x': 2}
+    assert old_inner.__dict__ == {'x': 1}
+
+
+def test_extra_attr():
+    class MyModel:
+        __slots__ = '__dict__', '__fields_set__'
+
+    v = SchemaValidator(
+        {
+            'type': 'new-class',
+            'cls': MyModel,
+            'schema': {
+                'type': 'typed-dict',
+                'return_fields_set': True,
+                'fields': {'field_a': {'schema': {'type': 'str'}}},
+            },
+            'config': {'typed_dict_extra_behavior': 'allow', 'typed_dict_extra_attr': '__extra__'},
+        }
+    )
+    m = v.validate_python({'field_a': 'test', 'field_b': 1})
+    assert m.field_a == 'test'
+    assert m.__extra__ == {'field_b': 1}
+    assert not hasattr(m, 'field_b')
+    assert m.__fields_set__ == {'field_a', 'field_b'}
+
+    extra = m.__extra__
+    assert v.validate_assignment(m, 'field_c', 2) is m
+    assert m.__extra__ == {'field_b': 1, 'field_c': 2}
+    assert extra == {'field_b': 1}
+    assert m.__fields_set__ == {'field_a', 'field_b', 'field_c'}
+
+
+def test_validate_assignment_frozen_field():
+    class MyModel:
+        __slots__ = '__dict__', '__fields_set__'
+
+    v = SchemaValidator(
+        {
+            'type': 'new-class',
+            'cls': MyModel,
+            'schema': {
+                'type': 'typed-dict',
+                'return_fields_set': True,
+                'fields': {
+                    'field_a': {'schema': {'type': 'str'}, 'frozen': True},
+                    'field_b': {'schema': {'type': 'int'}},
+                },
+            },
+        }
+    )
+    m = v.validate_python({'field_a': 'test', 'field_b': 1})
+    assert v.validate_assignment(m, 'field_b', 2) is m
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_assignment(m, 'field_a', 'changed')
+    assert exc_info.value.errors() == [
+        {'type': 'frozen_field', 'loc': ('field_a',), 'msg': 'Field is frozen', 'input': 'changed'}
+    ]
+    assert m.__dict__ == {'field_a': 'test', 'field_b': 2}
+    assert m.__fields_set__ == {'field_a', 'field_b'}
+
+
+def test_root_model():
+    class RootModel:
+        __slots__ = '__dict__', '__fields_set__'
+
+    v = SchemaValidator(
+        {
+            'type': 'new-class',
+            'cls': RootModel,
+            'root_model': True,
+            'schema': {'type': 'list', 'items_schema': {'type': 'int'}},
+        }
+    )
+    assert 'root_model:true' in plain_repr(v)
+    m = v.validate_python([1, '2', 3])
+    assert isinstance(m, RootModel)
+    assert m.__dict__ == {'__root__': [1, 2, 3]}
+    assert m.__fields_set__ == {'__root__'}
+
+    m2 = v.validate_json('[4, 5]')
+    assert m2.__dict__ == {'__root__': [4, 5]}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, 'x'])
+    assert exc_info.value.errors() == [
+        {
+            'type': 'int_parsing',
+            'loc': (1,),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+    # an instance is returned as is
+    assert v.validate_python(m) is m
+
+
+def test_root_model_scalar_init():
+    class RootModel:
+        __slots__ = '__dict__', '__fields_set__'
+
+        def __init__(self, data):
+            v.validate_python(data, self_instance=self)
+
+    v = SchemaValidator({'type': 'new-class', 'cls': RootModel, 'root_model': True, 'schema': {'type': 'int'}})
+    m = RootModel('42')
+    assert m.__dict__ == {'__root__': 42}
+    assert m.__fields_set__ == {'__root__'}
+
+
+def test_root_model_revalidate():
+    class RootModel:
+        __slots__ = '__dict__', '__fields_set__'
+
+        def __init__(self, root):
+            self.__root__ = root
+
+    v = SchemaValidator(
+        {
+            'type': 'new-class',
+            'cls': RootModel,
+            'root_model': True,
+            'schema': {'type': 'list', 'items_schema': {'type': 'int'}},
+            'config': {'revalidate_models': True},
+        }
+    )
+    m = RootModel(['1', 2])
+    m2 = v.validate_python(m)
+    assert m2 is not m
+    assert m2.__dict__ == {'__root__': [1, 2]}
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_python(RootModel(['x']))
+
+
+def test_root_model_validate_assignment():
+    class RootModel:
+        __slots__ = '__dict__', '__fields_set__'
+
+    v = SchemaValidator({'type': 'new-class', 'cls': RootModel, 'root_model': True, 'schema': {'type': 'str'}})
+    m = v.validate_python('foo')
+    assert v.validate_assignment(m, '__root__', b'bar') is m
+    assert m.__dict__ == {'__root__': 'bar'}
+    assert m.__fields_set__ == {'__root__'}
+
+    with pytest.raises(ValidationError):
+        v.validate_assignment(m, '__root__', 123)
+    assert m.__dict__ == {'__root__': 'bar'}
+
+    with pytest.raises(TypeError, match='validate_assignment on a root model expects the field "__root__"'):
+        v.validate_assignment(m, 'other', 'x')
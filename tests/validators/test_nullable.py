@@ -1,7 +1,11 @@
+import re
+
 import pytest
 
 from pydantic_core import SchemaValidator, ValidationError
 
+from ..conftest import Err, plain_repr
+
 
 def test_nullable():
     v = SchemaValidator({'type': 'nullable', 'schema': {'type': 'int'}})
@@ -33,3 +37,79 @@ def test_union_nullable_bool_int():
     assert v.validate_python(None) is None
     assert v.validate_python(True) is True
     assert v.validate_python(1) == 1
+
+
+def test_nullable_any():
+    v = SchemaValidator({'type': 'nullable', 'schema': {'type': 'any'}})
+    assert plain_repr(v) == 'SchemaValidator(name="any",validator=Any(AnyValidator),slots=[])'
+    assert v.validate_python(None) is None
+    assert v.validate_python([1]) == [1]
+
+
+@pytest.mark.parametrize(
+    'schema,input_value,loc',
+    [
+        ({'type': 'list', 'items_schema': {'type': 'int'}}, [1, None], (1,)),
+        ({'type': 'tuple', 'mode': 'variable', 'items_schema': {'type': 'int'}}, (1, None), (1,)),
+        (
+            {'type': 'tuple', 'mode': 'positional', 'items_schema': [{'type': 'str'}, {'type': 'str'}]},
+            ('a', None),
+            (1,),
+        ),
+        ({'type': 'set', 'items_schema': {'type': 'int'}}, {1, None}, (1,)),
+        ({'type': 'frozenset', 'items_schema': {'type': 'int'}}, frozenset({None}), (0,)),
+        ({'type': 'dict', 'values_schema': {'type': 'date'}}, {'a': None}, ('a',)),
+        ({'type': 'dict', 'keys_schema': {'type': 'int'}}, {None: 1}, ('None', '[key]')),
+    ],
+)
+def test_none_not_allowed_items(schema, input_value, loc):
+    v = SchemaValidator(schema)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value)
+    assert exc_info.value.errors() == [
+        {'type': 'none_not_allowed', 'loc': loc, 'msg': 'Input should not be None/null', 'input': None}
+    ]
+
+
+def test_none_allowed_items():
+    v = SchemaValidator({'type': 'list', 'items_schema': {'type': 'nullable', 'schema': {'type': 'int'}}})
+    assert v.validate_python([1, None]) == [1, None]
+    v = SchemaValidator({'type': 'dict', 'values_schema': {'type': 'default', 'schema': {'type': 'int'}, 'default': 0}})
+    with pytest.raises(ValidationError, match='Input should not be None/null'):
+        v.validate_python({'a': None})
+    # a default used on errors is used for `None` too
+    v = SchemaValidator(
+        {
+            'type': 'dict',
+            'values_schema': {'type': 'default', 'schema': {'type': 'int'}, 'on_error': 'default', 'default': 0},
+        }
+    )
+    assert v.validate_python({'a': None}) == {'a': 0}
+
+
+@pytest.mark.parametrize(
+    'schema_strict,config_strict,input_value,expected',
+    [
+        (True, None, 1, 1),
+        (True, None, '1', Err('Input should be a valid integer [type=int_type')),
+        (False, True, '1', 1),
+        (None, True, '1', Err('Input should be a valid integer [type=int_type')),
+        (None, None, '1', 1),
+    ],
+)
+def test_nullable_strict_override(schema_strict, config_strict, input_value, expected):
+    schema = {'type': 'nullable', 'schema': {'type': 'int'}}
+    if schema_strict is not None:
+        schema['strict'] = schema_strict
+    v = SchemaValidator(schema, None if config_strict is None else {'strict': config_strict})
+    assert v.validate_python(None) is None
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+def test_nullable_strict_inner_wins():
+    v = SchemaValidator({'type': 'nullable', 'schema': {'type': 'int', 'strict': False}, 'strict': True})
+    assert v.validate_python('1') == 1
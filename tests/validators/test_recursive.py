@@ -665,7 +665,7 @@ def test_function_change_id(strict: bool):
 
 
 def test_many_uses_of_ref():
-    # check we can safely exceed BACKUP_GUARD_LIMIT without upsetting the backup recursion guard
+    # check we can safely exceed the recursion limit without upsetting the backup recursion guard
     v = SchemaValidator(
         {
             'type': 'typed-dict',
@@ -713,7 +713,8 @@ def test_error_inside_recursive_wrapper():
             }
         )
     assert str(exc_info.value) == (
-        'Field "sub_branch":\n'
+        'Error building schema at fields -> sub_branch -> schema:\n'
+        '  Field "sub_branch":\n'
         '  SchemaError: Error building "default" validator:\n'
         "  SchemaError: 'default' and 'default_factory' cannot be used together"
     )
@@ -761,3 +762,66 @@ def test_new_class_td_recursive():
     assert f.x == 2
     assert f.y is None
     assert f.__fields_set__ == {'x'}
+
+
+def nested_list_validator(**config) -> SchemaValidator:
+    return SchemaValidator(
+        {'type': 'list', 'ref': 'the-list', 'items_schema': {'type': 'recursive-ref', 'schema_ref': 'the-list'}}, config
+    )
+
+
+def nested_list(depth: int) -> list:
+    data = []
+    for _ in range(depth):
+        data = [data]
+    return data
+
+
+def test_recursion_limit():
+    v = nested_list_validator(recursion_limit=3)
+    assert v.validate_python(nested_list(2)) == [[[]]]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(nested_list(3))
+    assert exc_info.value.errors() == [
+        {
+            'type': 'recursion_loop',
+            'loc': (0, 0, 0),
+            'msg': 'Recursion error - cyclic reference detected',
+            'input': [],
+        }
+    ]
+
+
+def test_recursion_limit_json():
+    v = nested_list_validator(recursion_limit=3)
+    assert v.validate_json('[[[]]]') == [[[]]]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('[[[[]]]]')
+    assert exc_info.value.errors() == [
+        {
+            'type': 'recursion_loop',
+            'loc': (0, 0, 0),
+            'msg': 'Recursion error - cyclic reference detected',
+            'input': [],
+        }
+    ]
+
+
+def test_recursion_limit_default():
+    v = nested_list_validator()
+    assert v.validate_python(nested_list(100)) == nested_list(100)
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(nested_list(1000))
+    assert exc_info.value.errors()[0]['type'] == 'recursion_loop'
+
+
+def test_recursion_limit_reset():
+    # the depth must be reset after each error, not carried over to the next call
+    v = nested_list_validator(recursion_limit=3)
+    for _ in range(5):
+        with pytest.raises(ValidationError, match='recursion_loop'):
+            v.validate_python(nested_list(10))
+    assert v.validate_python(nested_list(2)) == [[[]]]
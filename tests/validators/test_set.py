@@ -5,7 +5,7 @@ from typing import Any, Dict
 
 import pytest
 
-from pydantic_core import SchemaValidator, ValidationError
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
 
 from ..conftest import Err, PyAndJson, infinite_generator
 
@@ -297,3 +297,48 @@ def test_set_any(input_value, expected):
     output = v.validate_python(input_value)
     assert output == expected
     assert isinstance(output, set)
+
+
+@pytest.mark.parametrize(
+    'config,input_value,expected',
+    [
+        ({}, [1, 2, 2], {1, 2}),
+        ({'set_duplicates': 'error'}, [1, 2], {1, 2}),
+        ({'set_duplicates': 'error'}, {1, 2}, {1, 2}),
+        ({'set_duplicates': 'error'}, [1, 2, 2], Err('[type=set_duplicate_item')),
+        ({'set_duplicates': 'error'}, [1, '1'], Err('[type=set_duplicate_item')),
+        ({'set_from_list': False}, [1, 2], Err('Input should be a valid set [type=set_type,')),
+        ({'set_from_list': False}, (1, 2), Err('Input should be a valid set [type=set_type,')),
+        ({'set_from_list': False}, frozenset({1, 2}), {1, 2}),
+        ({'collection_from_iter': False}, (i for i in [1, 2]), Err('Input should be a valid set [type=set_type,')),
+        ({'collection_from_str': True}, '121', {1, 2}),
+    ],
+)
+def test_set_coercion_config(config, input_value, expected):
+    v = SchemaValidator({'type': 'set', 'items_schema': {'type': 'int'}}, config)
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+def test_set_coercion_config_json():
+    v = SchemaValidator(
+        {'type': 'set', 'items_schema': {'type': 'int'}}, {'set_from_list': False, 'set_duplicates': 'error'}
+    )
+    assert v.validate_json('[1, 2]') == {1, 2}
+    with pytest.raises(ValidationError, match=r'Input should not contain duplicate items \[type=set_duplicate_item,'):
+        v.validate_json('[1, 2, 1]')
+
+
+def test_frozenset_duplicates_error():
+    v = SchemaValidator({'type': 'frozenset'}, {'set_duplicates': 'error'})
+    assert v.validate_python([1, 2]) == frozenset({1, 2})
+    with pytest.raises(ValidationError, match='Input should not contain duplicate items'):
+        v.validate_python([1, 1])
+
+
+def test_set_duplicates_invalid():
+    with pytest.raises(SchemaError, match='Invalid set_duplicates: "drop"'):
+        SchemaValidator({'type': 'set'}, {'set_duplicates': 'drop'})
@@ -84,6 +84,24 @@ def test_str_not_json(input_value, expected):
         ({'to_upper': True, 'pattern': 'abc'}, 'abc', 'ABC'),
         ({'strip_whitespace': True, 'pattern': r'\d+$'}, 'foobar 123 ', 'foobar 123'),
         ({'min_length': 1}, '🐈 Hello', '🐈 Hello'),
+        ({'max_length': 3}, 'äöü', 'äöü'),
+        ({'max_length': 3, 'length_unit': 'chars'}, 'äöüß', Err('String should have at most 3 characters')),
+        ({'max_length': 3, 'length_unit': 'bytes'}, 'abc', 'abc'),
+        ({'max_length': 3, 'length_unit': 'bytes'}, 'äbc', Err('String should have at most 3 characters')),
+        ({'min_length': 3, 'length_unit': 'bytes'}, 'ä', Err('String should have at least 3 characters')),
+        # a family emoji is 5 code points joined by zero width joiners, but one grapheme
+        ({'max_length': 1, 'length_unit': 'graphemes'}, '👨\u200d👩\u200d👧', '👨\u200d👩\u200d👧'),
+        ({'max_length': 1}, '👨\u200d👩\u200d👧', Err('String should have at most 1 characters')),
+        ({'max_length': 2, 'length_unit': 'graphemes'}, 'e\u0301e\u0301', 'e\u0301e\u0301'),
+        ({'max_length': 2, 'length_unit': 'graphemes'}, 'abc', Err('String should have at most 2 characters')),
+        ({'unicode_normalize': 'NFC'}, 'e\u0301', '\u00e9'),
+        ({'unicode_normalize': 'NFC'}, '\u00e9', '\u00e9'),
+        ({'unicode_normalize': 'NFC'}, '\ufb01', '\ufb01'),
+        ({'unicode_normalize': 'NFKC'}, '\ufb01x', 'fix'),
+        ({'unicode_normalize': 'NFKC', 'to_upper': True}, '\uff41', 'A'),
+        # normalization comes before length checks
+        ({'unicode_normalize': 'NFC', 'max_length': 1}, 'e\u0301', '\u00e9'),
+        ({'unicode_normalize': 'NFKC', 'pattern': '^fi$'}, '\ufb01', 'fi'),
     ],
 )
 def test_constrained_str(py_and_json: PyAndJson, kwargs: Dict[str, Any], input_value, expected):
@@ -185,7 +203,7 @@ def test_regex_error():
 
 def test_default_validator():
     v = SchemaValidator(core_schema.string_schema(strict=True, to_lower=False), {'str_strip_whitespace': False})
-    assert plain_repr(v) == 'SchemaValidator(name="str",validator=Str(StrValidator{strict:true}),slots=[])'
+    assert plain_repr(v).startswith('SchemaValidator(name="str",validator=Str(StrValidator{strict:true}),slots=[],')
 
 
 @pytest.fixture(scope='session', name='FruitEnum')
@@ -218,6 +236,29 @@ def test_strict_subclass(FruitEnum, kwargs):
     ]
 
 
+@pytest.mark.parametrize('strict', [True, False])
+def test_subclasses_keep(FruitEnum, strict):
+    v = SchemaValidator(core_schema.string_schema(strict=strict, subclasses='keep'))
+    assert v.validate_python('foobar') == 'foobar'
+    assert v.validate_python(FruitEnum.pear) is FruitEnum.pear
+
+
+@pytest.mark.parametrize('strict', [True, False])
+def test_subclasses_coerce(FruitEnum, strict):
+    v = SchemaValidator(core_schema.string_schema(strict=strict, subclasses='coerce'))
+    p = v.validate_python(FruitEnum.pear)
+    assert p == 'pear'
+    assert type(p) is str
+
+
+@pytest.mark.parametrize('strict', [True, False])
+def test_subclasses_reject(FruitEnum, strict):
+    v = SchemaValidator(core_schema.string_schema(strict=strict), {'str_subclasses': 'reject'})
+    assert v.validate_python('foobar') == 'foobar'
+    with pytest.raises(ValidationError, match='type=string_sub_type,'):
+        v.validate_python(FruitEnum.pear)
+
+
 @pytest.mark.parametrize('kwargs', [{}, {'to_lower': True}], ids=repr)
 def test_lax_subclass(FruitEnum, kwargs):
     v = SchemaValidator(core_schema.string_schema(**kwargs))
@@ -227,3 +268,22 @@ def test_lax_subclass(FruitEnum, kwargs):
     assert p == 'pear'
     assert type(p) is str
     assert repr(p) == "'pear'"
+
+
+def test_length_unit_config():
+    v = SchemaValidator({'type': 'str'}, {'str_max_length': 4, 'str_length_unit': 'bytes'})
+    assert v.validate_python('ab') == 'ab'
+    with pytest.raises(ValidationError, match='String should have at most 4 characters'):
+        v.validate_python('äöü')
+
+
+def test_length_unit_invalid():
+    with pytest.raises(SchemaError, match="Input should be 'chars', 'bytes' or 'graphemes'"):
+        SchemaValidator({'type': 'str', 'max_length': 1, 'length_unit': 'words'})
+
+
+def test_unicode_normalize_config():
+    v = SchemaValidator({'type': 'str'}, {'str_unicode_normalize': 'NFC'})
+    assert v.validate_python('cafe\u0301') == 'caf\u00e9'
+    value = 'already normalized'
+    assert v.validate_python(value) is value
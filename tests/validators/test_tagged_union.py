@@ -419,3 +419,24 @@ def test_tag_repeated_invalid():
                 },
             }
         )
+
+
+def test_runtime_strict():
+    v = SchemaValidator(
+        {
+            'type': 'tagged-union',
+            'discriminator': 'foo',
+            'choices': {
+                'apple': {
+                    'type': 'typed-dict',
+                    'fields': {'foo': {'schema': {'type': 'bytes'}}, 'bar': {'schema': {'type': 'int'}}},
+                }
+            },
+        }
+    )
+    assert v.validate_python({'foo': b'apple', 'bar': '1'}) == {'foo': b'apple', 'bar': 1}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'foo': b'apple', 'bar': 1}, strict=True)
+    assert exc_info.value.errors() == [
+        {'type': 'string_type', 'loc': (), 'msg': 'Input should be a valid string', 'input': b'apple'}
+    ]
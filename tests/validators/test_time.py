@@ -1,11 +1,11 @@
 import re
-from datetime import date, datetime, time
+from datetime import date, datetime, time, timedelta, timezone
 from decimal import Decimal
 from typing import Any, Dict
 
 import pytest
 
-from pydantic_core import SchemaError, SchemaValidator, ValidationError
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson
 
@@ -197,3 +197,142 @@ def test_union():
     v = SchemaValidator({'type': 'union', 'choices': [{'type': 'time'}, {'type': 'str'}]})
     assert v.validate_python('12:01:02') == '12:01:02'
     assert v.validate_python(time(12, 1, 2)) == time(12, 1, 2)
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        pytest.param('12:13:14Z', time(12, 13, 14, tzinfo=timezone.utc), id='Z'),
+        pytest.param('12:13:14+05:00', time(12, 13, 14, tzinfo=timezone(timedelta(hours=5))), id='+05:00'),
+        pytest.param('12:13:14-0130', time(12, 13, 14, tzinfo=timezone(-timedelta(hours=1, minutes=30))), id='-0130'),
+        pytest.param('12:13:14+02', time(12, 13, 14, tzinfo=timezone(timedelta(hours=2))), id='+02'),
+        pytest.param(
+            '12:13:14.123\u221205:00',
+            time(12, 13, 14, 123_000, tzinfo=timezone(timedelta(hours=-5))),
+            id='unicode-minus',
+        ),
+        pytest.param('12:13:14+', Err('invalid timezone hour [type=time_parsing'), id='no-offset'),
+        pytest.param('12:13:14+05:0', Err('invalid timezone minute [type=time_parsing'), id='short-minute'),
+        pytest.param('12:13:14+05:60', Err('timezone minute value is outside expected range of 0-59'), id='minute-60'),
+        pytest.param('12:13:14+24:00', Err('timezone offset must be less than 24 hours'), id='too-large'),
+        pytest.param('12:13:14Zx', Err('unexpected extra characters at the end of the input'), id='extra'),
+    ],
+)
+def test_time_offset(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json({'type': 'time'})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        output = v.validate_test(input_value)
+        assert output == expected
+        assert output.utcoffset() == expected.utcoffset()
+
+
+def test_time_offset_tzinfo():
+    v = SchemaValidator({'type': 'time'})
+    output = v.validate_python('12:13:14+05:30')
+    assert output.utcoffset() == timedelta(hours=5, minutes=30)
+    assert output.tzname() == '+05:30'
+    assert output.dst() is None
+    assert output.isoformat() == '12:13:14+05:30'
+
+    output = v.validate_python('12:13:14Z')
+    assert output.tzname() == 'UTC'
+    assert output.isoformat() == '12:13:14+00:00'
+
+
+class TestTZConstraints:
+    aware_validator = SchemaValidator(core_schema.time_schema(tz_constraint='aware'))
+    naive_validator = SchemaValidator(core_schema.time_schema(tz_constraint='naive'))
+
+    def test_raises_schema_error_for_unknown_constraint_kind(self):
+        with pytest.raises(SchemaError, match=r"Input should be 'aware' or 'naive' \[type=literal_error"):
+            SchemaValidator({'type': 'time', 'tz_constraint': 'foo'})
+
+    def test_can_validate_aware_value(self):
+        value = time(12, tzinfo=timezone.utc)
+        assert value is self.aware_validator.validate_python(value)
+        assert self.aware_validator.validate_python('12:00+01:00') == time(12, tzinfo=timezone(timedelta(hours=1)))
+
+    def test_raises_validation_error_when_aware_given_naive(self):
+        with pytest.raises(ValidationError, match=r'Time should have timezone info \[type=time_aware'):
+            self.aware_validator.validate_python(time(12))
+        with pytest.raises(ValidationError, match=r'Time should have timezone info \[type=time_aware'):
+            self.aware_validator.validate_json('"12:00"')
+
+    def test_can_validate_naive_value(self):
+        value = time(12)
+        assert value is self.naive_validator.validate_python(value)
+
+    def test_raises_validation_error_when_naive_given_aware(self):
+        with pytest.raises(ValidationError, match=r'Time should not have timezone info \[type=time_naive'):
+            self.naive_validator.validate_python(time(12, tzinfo=timezone.utc))
+        with pytest.raises(ValidationError, match=r'Time should not have timezone info \[type=time_naive'):
+            self.naive_validator.validate_json('"12:00Z"')
+
+
+@pytest.mark.parametrize(
+    'mode,input_value,expected',
+    [
+        ('clamp', '12:30:60', time(12, 30, 59, 999_999)),
+        ('clamp', '23:59:60.5', time(23, 59, 59, 999_999)),
+        ('carry', '12:30:60', time(12, 31)),
+        ('carry', '12:59:60.25', time(13, 0, 0, 250_000)),
+        ('carry', '23:59:60', time(0)),
+        ('carry', '23:59:60Z', time(0, tzinfo=timezone.utc)),
+        ('clamp', '12:30:59', time(12, 30, 59)),
+        ('clamp', '12:30:61', Err('Input should be in a valid time format, second value is outside expected range')),
+        ('error', '12:30:60', Err('Input should be in a valid time format, second value is outside expected range')),
+    ],
+)
+def test_leap_second_mode(mode, input_value, expected):
+    v = SchemaValidator(core_schema.time_schema(leap_second_mode=mode))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+        assert v.validate_json(f'"{input_value}"') == expected
+
+
+def test_leap_second_mode_config():
+    v = SchemaValidator(core_schema.time_schema(), {'leap_second_mode': 'carry'})
+    assert v.validate_python('10:20:60') == time(10, 21)
+
+
+def test_leap_second_mode_invalid():
+    with pytest.raises(SchemaError, match='Invalid leap_second_mode "foobar"'):
+        SchemaValidator(core_schema.time_schema(), {'leap_second_mode': 'foobar'})
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('1:30 PM', time(13, 30)),
+        ('12:05 am', time(0, 5)),
+        ('12:05 pm', time(12, 5)),
+        ('0930', time(9, 30)),
+        ('09h30m15.5s', time(9, 30, 15, 500_000)),
+        ('13:30 PM', Err('Input should be in a valid time format')),
+        ('2530', Err('Input should be in a valid time format')),
+    ],
+)
+def test_input_formats(input_value, expected):
+    v = SchemaValidator(core_schema.time_schema(input_formats=['%I:%M %p', '%H%M', '%Hh%Mm%S.%fs']))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+        assert v.validate_json(f'"{input_value}"') == expected
+
+
+def test_input_formats_offset():
+    v = SchemaValidator(core_schema.time_schema(input_formats=['%H.%M%z']))
+    assert v.validate_python('10.20+01:00') == time(10, 20, tzinfo=timezone(timedelta(hours=1)))
+
+
+def test_input_formats_invalid():
+    with pytest.raises(SchemaError, match='Invalid input format "%d %H": time formats may not contain date directives'):
+        SchemaValidator(core_schema.time_schema(input_formats=['%d %H']))
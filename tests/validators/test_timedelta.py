@@ -39,6 +39,21 @@ from ..conftest import Err, PyAndJson
         (Decimal('-3601.222222'), timedelta(hours=-2, seconds=3598, microseconds=777778)),
         (Decimal('-3601.2222222'), timedelta(hours=-2, seconds=3598, microseconds=777778)),
         (Decimal('-3601.2222227'), timedelta(hours=-2, seconds=3598, microseconds=777777)),
+        (-0.5, timedelta(seconds=-0.5)),
+        (-1.5, timedelta(seconds=-1.5)),
+        (-0.000001, timedelta(microseconds=-1)),
+        (-86_400.5, timedelta(days=-1, seconds=-0.5)),
+        (0.9999996, timedelta(seconds=1)),
+        (-0.9999996, timedelta(seconds=-1)),
+        (86_400 * 999_999_999, timedelta(days=999_999_999)),
+        (
+            86_400 * 1_000_000_000,
+            Err('Input should be a valid timedelta, durations may not exceed 999,999,999 days'),
+        ),
+        (
+            -86_400 * 1_000_000_000,
+            Err('Input should be a valid timedelta, durations may not exceed 999,999,999 days'),
+        ),
         (float('nan'), Err('Input should be a valid timedelta, NaN values not permitted')),
         (float('inf'), Err('Input should be a valid timedelta, durations may not exceed 999,999,999 days')),
         (float('-inf'), Err('Input should be a valid timedelta, durations may not exceed 999,999,999 days')),
@@ -68,6 +83,9 @@ def test_timedelta(input_value, expected):
         ('-3601.222222', timedelta(hours=-2, seconds=3598, microseconds=777778)),
         ('-3601.2222222', timedelta(hours=-2, seconds=3598, microseconds=777778)),
         ('3600.999999', timedelta(seconds=3600, microseconds=999999)),
+        ('-0.5', timedelta(seconds=-0.5)),
+        ('-0.000001', timedelta(microseconds=-1)),
+        ('-86400.5', timedelta(days=-1, seconds=-0.5)),
     ],
     ids=repr,
 )
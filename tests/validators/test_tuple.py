@@ -495,3 +495,19 @@ def test_length_constraints_omit(input_value, expected):
             v.validate_python(input_value)
     else:
         assert v.validate_python(input_value) == expected
+
+
+@pytest.mark.parametrize(
+    'schema',
+    [
+        {'type': 'tuple', 'items_schema': {'type': 'int'}},
+        {'type': 'tuple', 'mode': 'positional', 'items_schema': [{'type': 'int'}, {'type': 'int'}]},
+    ],
+)
+def test_tuple_coercion_config(schema):
+    v = SchemaValidator(schema, {'tuple_from_list': False, 'tuple_from_set': True})
+    assert v.validate_python((1, 2)) == (1, 2)
+    assert sorted(v.validate_python({1, 2})) == [1, 2]
+    with pytest.raises(ValidationError, match=r'Input should be a valid tuple \[type=tuple_type,'):
+        v.validate_python([1, 2])
+    assert v.validate_json('[1, 2]') == (1, 2)
@@ -8,7 +8,7 @@ from typing import Mapping
 import pytest
 from dirty_equals import FunctionCheck, HasRepr, IsStr
 
-from pydantic_core import CoreConfig, SchemaError, SchemaValidator, ValidationError
+from pydantic_core import CoreConfig, PydanticUndefined, SchemaError, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson
 
@@ -69,6 +69,48 @@ def test_strict():
     ]
 
 
+def test_field_strict_override():
+    int_schema = core_schema.int_schema()
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'strict_field': core_schema.typed_dict_field(int_schema),
+                'lax_field': core_schema.typed_dict_field(int_schema, strict=False),
+                'nested': core_schema.typed_dict_field(
+                    core_schema.list_schema(core_schema.nullable_schema(int_schema)), strict=False
+                ),
+            }
+        ),
+        {'strict': True},
+    )
+
+    assert v.validate_python({'strict_field': 1, 'lax_field': '2', 'nested': ['3', None]}) == {
+        'strict_field': 1,
+        'lax_field': 2,
+        'nested': [3, None],
+    }
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'strict_field': '1', 'lax_field': 'x', 'nested': []})
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [
+        ('int_type', ('strict_field',)),
+        ('int_parsing', ('lax_field',)),
+    ]
+
+
+def test_field_strict_override_strict():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema(), strict=True),
+                'b': core_schema.typed_dict_field(core_schema.int_schema()),
+            }
+        )
+    )
+    assert v.validate_python({'a': 1, 'b': '2'}) == {'a': 1, 'b': 2}
+    with pytest.raises(ValidationError, match=r'a\n  Input should be a valid integer \[type=int_type'):
+        v.validate_python({'a': '1', 'b': '2'})
+
+
 def test_with_default():
     v = SchemaValidator(
         {
@@ -106,6 +148,34 @@ field_b
     )
 
 
+def test_undefined_is_missing():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'return_fields_set': True,
+            'extra_behavior': 'forbid',
+            'fields': {
+                'field_a': {'schema': {'type': 'nullable', 'schema': {'type': 'int'}}, 'required': False},
+                'field_b': {'schema': {'type': 'default', 'schema': {'type': 'int'}, 'default': 666}},
+                'field_c': {'schema': {'type': 'int'}},
+            },
+        }
+    )
+    # absent, null and a value are all distinct
+    assert v.validate_python({'field_a': PydanticUndefined, 'field_b': PydanticUndefined, 'field_c': 1}) == (
+        {'field_b': 666, 'field_c': 1},
+        {'field_c'},
+    )
+    assert v.validate_python({'field_a': None, 'field_c': 1}) == (
+        {'field_a': None, 'field_b': 666, 'field_c': 1},
+        {'field_a', 'field_c'},
+    )
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'field_c': PydanticUndefined})
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('missing', ('field_c',))]
+
+
 @pytest.mark.parametrize(
     'config,input_value,expected',
     [
@@ -216,7 +286,7 @@ def test_allow_extra_validate():
         v.validate_python({'field_a': 'test', 'other_value': 12.5})
     assert exc_info.value.errors() == [
         {
-            'type': 'int_from_float',
+            'type': 'int_from_float_inexact',
             'loc': ('other_value',),
             'msg': 'Input should be a valid integer, got a number with a fractional part',
             'input': 12.5,
@@ -236,6 +306,63 @@ def test_allow_extra_wrong():
         SchemaValidator({'type': 'typed-dict', 'fields': {}}, {'typed_dict_extra_behavior': 'wrong'})
 
 
+def test_forbid_extra_multiple(py_and_json: PyAndJson):
+    v = py_and_json(
+        {'type': 'typed-dict', 'fields': {'field_a': {'schema': {'type': 'str'}}}, 'extra_behavior': 'forbid'}
+    )
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'field_a': 'abc', 'field_b': 1, 'field_c': [2]})
+
+    assert exc_info.value.errors() == [
+        {'type': 'extra_forbidden', 'loc': ('field_b',), 'msg': 'Extra inputs are not permitted', 'input': 1},
+        {'type': 'extra_forbidden', 'loc': ('field_c',), 'msg': 'Extra inputs are not permitted', 'input': [2]},
+    ]
+
+
+def test_extra_attr(py_and_json: PyAndJson):
+    v = py_and_json(
+        {
+            'type': 'typed-dict',
+            'return_fields_set': True,
+            'fields': {'field_a': {'schema': {'type': 'str'}}},
+            'extra_validator': {'type': 'int'},
+            'extra_behavior': 'allow',
+            'extra_attr': '__extra__',
+        }
+    )
+
+    assert v.validate_test({'field_a': 'abc', 'field_b': '1'}) == (
+        {'field_a': 'abc', '__extra__': {'field_b': 1}},
+        {'field_a', 'field_b'},
+    )
+    assert v.validate_test({'field_a': 'abc'}) == ({'field_a': 'abc', '__extra__': {}}, {'field_a'})
+
+
+def test_extra_attr_config():
+    v = SchemaValidator(
+        {'type': 'typed-dict', 'fields': {'field_a': {'schema': {'type': 'str'}}}},
+        {'typed_dict_extra_behavior': 'allow', 'typed_dict_extra_attr': 'extra'},
+    )
+    assert v.validate_python({'field_a': 'abc', 'field_b': 1}) == {'field_a': 'abc', 'extra': {'field_b': 1}}
+
+
+@pytest.mark.parametrize(
+    'schema,message',
+    [
+        ({'extra_behavior': 'ignore'}, 'extra_attr can only be used if extra_behavior=allow'),
+        ({'extra_behavior': 'forbid'}, 'extra_attr can only be used if extra_behavior=allow'),
+        ({}, 'extra_attr can only be used if extra_behavior=allow'),
+        ({'extra_behavior': 'allow', 'extra_attr': 'a'}, 'extra_attr "a" clashes with a field of the same name'),
+    ],
+)
+def test_extra_attr_invalid(schema, message):
+    with pytest.raises(SchemaError, match=message):
+        SchemaValidator(
+            {'type': 'typed-dict', 'fields': {'a': {'schema': {'type': 'int'}}}, 'extra_attr': 'extra', **schema}
+        )
+
+
 def test_str_config():
     v = SchemaValidator(
         {'type': 'typed-dict', 'fields': {'field_a': {'schema': {'type': 'str'}}}}, {'str_max_length': 5}
@@ -253,7 +380,7 @@ def test_validate_assignment():
 
     assert v.validate_python({'field_a': 'test'}) == ({'field_a': 'test'}, {'field_a'})
 
-    assert v.validate_assignment('field_a', b'abc', {'field_a': 'test'}) == ({'field_a': 'abc'}, {'field_a'})
+    assert v.validate_assignment({'field_a': 'test'}, 'field_a', b'abc') == ({'field_a': 'abc'}, {'field_a'})
 
 
 def test_validate_assignment_strict_field():
@@ -268,7 +395,7 @@ def test_validate_assignment_strict_field():
     assert v.validate_python({'field_a': 'test'}) == ({'field_a': 'test'}, {'field_a'})
 
     with pytest.raises(ValidationError) as exc_info:
-        v.validate_assignment('field_a', b'abc', {'field_a': 'test'})
+        v.validate_assignment({'field_a': 'test'}, 'field_a', b'abc')
     assert exc_info.value.errors() == [
         {'input': b'abc', 'type': 'string_type', 'loc': ('field_a',), 'msg': 'Input should be a valid string'}
     ]
@@ -308,7 +435,7 @@ def test_validate_assignment_functions():
     assert calls == ['func_a', 'func_b']
     calls.clear()
 
-    assert v.validate_assignment('field_a', 'new-val', {'field_a': 'testtest', 'field_b': 6}) == (
+    assert v.validate_assignment({'field_a': 'testtest', 'field_b': 6}, 'field_a', 'new-val') == (
         {'field_a': 'new-valnew-val', 'field_b': 6},
         {'field_a'},
     )
@@ -323,7 +450,7 @@ def test_validate_assignment_ignore_extra():
     assert v.validate_python({'field_a': 'test'}) == ({'field_a': 'test'}, {'field_a'})
 
     with pytest.raises(ValidationError) as exc_info:
-        v.validate_assignment('other_field', 456, {'field_a': 'test'})
+        v.validate_assignment({'field_a': 'test'}, 'other_field', 456)
 
     assert exc_info.value.errors() == [
         {'type': 'extra_forbidden', 'loc': ('other_field',), 'msg': 'Extra inputs are not permitted', 'input': 456}
@@ -337,7 +464,25 @@ def test_validate_assignment_allow_extra():
 
     assert v.validate_python({'field_a': 'test'}) == {'field_a': 'test'}
 
-    assert v.validate_assignment('other_field', 456, {'field_a': 'test'}) == {'field_a': 'test', 'other_field': 456}
+    assert v.validate_assignment({'field_a': 'test'}, 'other_field', 456) == {'field_a': 'test', 'other_field': 456}
+
+
+def test_validate_assignment_extra_attr():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {'field_a': {'schema': {'type': 'str'}}},
+            'extra_behavior': 'allow',
+            'extra_attr': 'extra',
+        }
+    )
+
+    data = {'field_a': 'test', 'extra': {'field_b': 1}}
+    assert v.validate_assignment(data, 'field_c', 2) == {'field_a': 'test', 'extra': {'field_b': 1, 'field_c': 2}}
+    assert v.validate_assignment(data, 'field_a', b'new') == {
+        'field_a': 'new',
+        'extra': {'field_b': 1, 'field_c': 2},
+    }
 
 
 def test_validate_assignment_allow_extra_validate():
@@ -350,10 +495,10 @@ def test_validate_assignment_allow_extra_validate():
         }
     )
 
-    assert v.validate_assignment('other_field', '456', {'field_a': 'test'}) == {'field_a': 'test', 'other_field': 456}
+    assert v.validate_assignment({'field_a': 'test'}, 'other_field', '456') == {'field_a': 'test', 'other_field': 456}
 
     with pytest.raises(ValidationError) as exc_info:
-        assert v.validate_assignment('other_field', 'xyz', {'field_a': 'test'})
+        assert v.validate_assignment({'field_a': 'test'}, 'other_field', 'xyz')
     assert exc_info.value.errors() == [
         {
             'type': 'int_parsing',
@@ -372,10 +517,10 @@ def test_validate_assignment_with_strict():
     r = v.validate_python({'x': 'a', 'y': '123'})
     assert r == {'x': 'a', 'y': 123}
 
-    assert v.validate_assignment('y', '124', r) == {'x': 'a', 'y': 124}
+    assert v.validate_assignment(r, 'y', '124') == {'x': 'a', 'y': 124}
 
     with pytest.raises(ValidationError) as exc_info:
-        v.validate_assignment('y', '124', r, True)
+        v.validate_assignment(r, 'y', '124', True)
 
     assert exc_info.value.errors() == [
         {'type': 'int_type', 'loc': ('y',), 'msg': 'Input should be a valid integer', 'input': '124'}
@@ -449,6 +594,83 @@ def test_fields_required_by_default_with_default():
     assert v.validate_python({'x': 'pika'}) == ({'x': 'pika', 'y': 'bulbi'}, {'x'})
 
 
+def test_missing_distinct_from_none(py_and_json: PyAndJson):
+    v = py_and_json(
+        {
+            'type': 'typed-dict',
+            'return_fields_set': True,
+            'fields': {
+                # `int | None` without a default, the key is required but may be `None`
+                'x': {'schema': {'type': 'nullable', 'schema': {'type': 'int'}}},
+                # `Optional[int] = None`
+                'y': {
+                    'schema': {
+                        'type': 'default',
+                        'schema': {'type': 'nullable', 'schema': {'type': 'int'}},
+                        'default': None,
+                    }
+                },
+                # a `NotRequired[int | None]` key, simply omitted when absent
+                'z': {'schema': {'type': 'nullable', 'schema': {'type': 'int'}}, 'required': False},
+            },
+        }
+    )
+
+    assert v.validate_test({'x': None}) == ({'x': None, 'y': None}, {'x'})
+    assert v.validate_test({'x': 1, 'y': 2, 'z': None}) == ({'x': 1, 'y': 2, 'z': None}, {'x', 'y', 'z'})
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'y': None, 'z': 3})
+    assert exc_info.value.errors() == [
+        {'type': 'missing', 'loc': ('x',), 'msg': 'Field required', 'input': {'y': None, 'z': 3}}
+    ]
+
+
+def test_none_not_allowed(py_and_json: PyAndJson):
+    v = py_and_json(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'a': {'schema': {'type': 'int'}},
+                'b': {'schema': {'type': 'nullable', 'schema': {'type': 'int'}}},
+                'c': {'schema': {'type': 'list', 'items_schema': {'type': 'int'}}, 'required': False},
+            },
+        }
+    )
+    assert v.validate_test({'a': 1, 'b': None}) == {'a': 1, 'b': None}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'a': None, 'b': None, 'c': [None]})
+    assert exc_info.value.errors() == [
+        {'type': 'none_not_allowed', 'loc': ('a',), 'msg': 'Input should not be None/null', 'input': None},
+        # items of other containers get the same error
+        {'type': 'none_not_allowed', 'loc': ('c', 0), 'msg': 'Input should not be None/null', 'input': None},
+    ]
+
+
+def test_none_not_allowed_union_and_function():
+    def f(input_value, **kwargs):
+        raise ValueError('no value')
+
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'a': {'schema': {'type': 'union', 'choices': [{'type': 'int'}, {'type': 'str'}]}},
+                'b': {'schema': {'type': 'union', 'choices': [{'type': 'int'}, {'type': 'none'}]}},
+                # the function might accept `None`, so it's called
+                'c': {'schema': {'type': 'function', 'mode': 'before', 'function': f, 'schema': {'type': 'int'}}},
+            },
+        }
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': None, 'b': None, 'c': None})
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [
+        ('none_not_allowed', ('a',)),
+        ('value_error', ('c',)),
+    ]
+
+
 def test_all_optional_fields():
     """By default all fields should be optional if `total` is set to `False`"""
     v = SchemaValidator(
@@ -546,11 +768,45 @@ def test_alias_allow_pop(py_and_json: PyAndJson):
     )
     assert v.validate_test({'FieldA': '123'}) == ({'field_a': 123}, {'field_a'})
     assert v.validate_test({'field_a': '123'}) == ({'field_a': 123}, {'field_a'})
-    assert v.validate_test({'FieldA': '1', 'field_a': '2'}) == ({'field_a': 1}, {'field_a'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'FieldA': '1', 'field_a': '2'})
+    assert exc_info.value.errors() == [
+        {
+            'type': 'alias_and_name_provided',
+            'loc': ('field_a',),
+            'msg': 'Field provided by both its alias and its name',
+            'input': {'FieldA': '1', 'field_a': '2'},
+        }
+    ]
     with pytest.raises(ValidationError, match=r'field_a\n +Field required \[type=missing,'):
         assert v.validate_test({'foobar': '123'})
 
 
+def test_alias_allow_pop_path_extra_forbid(py_and_json: PyAndJson):
+    v = py_and_json(
+        {
+            'type': 'typed-dict',
+            'populate_by_name': True,
+            'extra_behavior': 'forbid',
+            'loc_by_alias': True,
+            'fields': {'field_a': {'validation_alias': ['foo', 'bar'], 'schema': {'type': 'int'}}},
+        }
+    )
+    assert v.validate_test({'foo': {'bar': '1'}}) == {'field_a': 1}
+    assert v.validate_test({'field_a': '2'}) == {'field_a': 2}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'foo': {'bar': '1'}, 'field_a': '2'})
+    # the field name key isn't also reported as an extra input
+    assert exc_info.value.errors() == [
+        {
+            'type': 'alias_and_name_provided',
+            'loc': ('foo', 'bar'),
+            'msg': 'Field provided by both its alias and its name',
+            'input': {'foo': {'bar': '1'}, 'field_a': '2'},
+        }
+    ]
+
+
 @pytest.mark.parametrize(
     'input_value,expected',
     [
@@ -709,6 +965,85 @@ def test_alias_error_loc():
     ]
 
 
+def test_loc_by_alias(py_and_json: PyAndJson):
+    v = py_and_json(
+        {
+            'type': 'typed-dict',
+            'loc_by_alias': True,
+            'fields': {
+                'field_a': {'validation_alias': 'FieldA', 'schema': {'type': 'int'}},
+                'field_b': {'schema': {'type': 'int'}},
+            },
+        }
+    )
+    assert v.validate_test({'FieldA': '1', 'field_b': '2'}) == {'field_a': 1, 'field_b': 2}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'FieldA': 'x'})
+    # insert_assert(exc_info.value.errors())
+    assert exc_info.value.errors() == [
+        {
+            'type': 'int_parsing',
+            'loc': ('FieldA',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        },
+        {'type': 'missing', 'loc': ('field_b',), 'msg': 'Field required', 'input': {'FieldA': 'x'}},
+    ]
+
+
+def test_loc_by_alias_config():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {'field_a': core_schema.typed_dict_field(core_schema.int_schema(), validation_alias='FieldA')},
+            populate_by_name=True,
+        ),
+        {'loc_by_alias': True},
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({})
+    assert exc_info.value.errors() == [{'type': 'missing', 'loc': ('FieldA',), 'msg': 'Field required', 'input': {}}]
+    # the alias is used even when the input was populated by name
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'field_a': 'x'})
+    assert exc_info.value.errors()[0]['loc'] == ('FieldA',)
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_assignment({'field_a': 1}, 'field_a', 'x')
+    assert exc_info.value.errors()[0]['loc'] == ('FieldA',)
+
+
+def test_loc_by_alias_path():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'loc_by_alias': True,
+            'fields': {
+                'field_a': {
+                    'validation_alias': [['foo', 1, 'bar'], ['baz']],
+                    'schema': {'type': 'list', 'items_schema': {'type': 'int'}},
+                }
+            },
+        }
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'foo': [None, {'bar': [1, 'x']}]})
+    # insert_assert(exc_info.value.errors())
+    assert exc_info.value.errors() == [
+        {
+            'type': 'int_parsing',
+            'loc': ('foo', 1, 'bar', 1),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+    # integer indices and string keys stay distinct in locations
+    assert [type(item) for item in exc_info.value.errors()[0]['loc']] == [str, int, str, int]
+    assert exc_info.value.json() == (
+        '[{"type":"int_parsing","loc":["foo",1,"bar",1],'
+        '"msg":"Input should be a valid integer, unable to parse string as an integer","input":"x"}]'
+    )
+
+
 def test_empty_model():
     v = SchemaValidator({'type': 'typed-dict', 'fields': {}, 'return_fields_set': True})
     assert v.validate_python({}) == ({}, set())
@@ -864,6 +1199,8 @@ def test_from_attributes_by_name():
     )
     assert v.validate_python(Cls(a_alias=1)) == ({'a': 1}, {'a'})
     assert v.validate_python(Cls(a=1)) == ({'a': 1}, {'a'})
+    with pytest.raises(ValidationError, match=r'a\n +Field provided by both its alias and its name'):
+        v.validate_python(Cls(a_alias=1, a=2))
 
 
 def test_from_attributes_missing():
@@ -988,6 +1325,43 @@ def test_from_attributes_extra():
     assert v.validate_python(Cls(a=1, b=datetime.now, c=lambda: 42)) == ({'a': 1}, {'a'})
 
 
+def test_from_attributes_slots():
+    class Slotted:
+        __slots__ = 'a', 'b', 'c'
+
+        def __init__(self, a, b):
+            self.a = a
+            self.b = b
+
+        @property
+        def d(self):
+            return 'spam'
+
+    @dataclass
+    class SlottedDataclass:
+        __slots__ = 'a', 'b'
+        a: int
+        b: int
+
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'a': {'schema': {'type': 'int'}},
+                'b': {'schema': {'type': 'int'}},
+                'd': {'schema': {'type': 'str'}, 'required': False},
+            },
+            'from_attributes': True,
+        }
+    )
+    assert v.validate_python(Slotted(1, '2')) == {'a': 1, 'b': 2, 'd': 'spam'}
+    assert v.validate_python(SlottedDataclass(1, 2)) == {'a': 1, 'b': 2}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(Slotted.__new__(Slotted))
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('missing', ('a',)), ('missing', ('b',))]
+
+
 def foobar():
     pass
 
@@ -1414,10 +1788,158 @@ def test_frozen_field():
     )
     r1 = v.validate_python({'name': 'Samuel', 'age': '36'})
     assert r1 == {'name': 'Samuel', 'age': 36, 'is_developer': True}
-    r2 = v.validate_assignment('age', '35', r1)
+    r2 = v.validate_assignment(r1, 'age', '35')
     assert r2 == {'name': 'Samuel', 'age': 35, 'is_developer': True}
     with pytest.raises(ValidationError) as exc_info:
-        v.validate_assignment('is_developer', False, r2)
+        v.validate_assignment(r2, 'is_developer', False)
     assert exc_info.value.errors() == [
-        {'type': 'frozen', 'loc': ('is_developer',), 'msg': 'Field is frozen', 'input': False}
+        {'type': 'frozen_field', 'loc': ('is_developer',), 'msg': 'Field is frozen', 'input': False}
     ]
+
+
+def test_validate_python_return_fields_set():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(core_schema.int_schema(), default=0), required=False
+                ),
+                'sub': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(
+                        core_schema.typed_dict_schema({'x': core_schema.typed_dict_field(core_schema.int_schema())}),
+                        default=None,
+                    ),
+                    required=False,
+                ),
+            }
+        )
+    )
+    assert v.validate_python({'a': 1}) == {'a': 1, 'b': 0, 'sub': None}
+    assert v.validate_python({'a': 1}, return_fields_set=True) == ({'a': 1, 'b': 0, 'sub': None}, {'a'})
+    # only fields of the outer typed dict are included, nested typed dicts return a dict as usual
+    assert v.validate_python({'a': '1', 'sub': {'x': 2}}, return_fields_set=True) == (
+        {'a': 1, 'b': 0, 'sub': {'x': 2}},
+        {'a', 'sub'},
+    )
+    with pytest.raises(ValidationError, match='Field required'):
+        v.validate_python({'b': 1}, return_fields_set=True)
+
+
+def test_validate_python_return_fields_set_not_typed_dict():
+    v = SchemaValidator(core_schema.int_schema())
+    with pytest.raises(TypeError, match='`return_fields_set` requires a typed-dict schema'):
+        v.validate_python(1, return_fields_set=True)
+
+
+@pytest.fixture(scope='module')
+def include_exclude_validator():
+    return SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.string_schema()),
+                'sub': core_schema.typed_dict_field(
+                    core_schema.typed_dict_schema(
+                        {
+                            'x': core_schema.typed_dict_field(core_schema.int_schema()),
+                            'y': core_schema.typed_dict_field(core_schema.int_schema()),
+                        }
+                    )
+                ),
+            },
+            extra_behavior='forbid',
+        )
+    )
+
+
+@pytest.mark.parametrize(
+    'include,exclude,expected',
+    [
+        (None, None, {'a': 1, 'b': 'x', 'sub': {'x': 1, 'y': 2}}),
+        ({'a'}, None, {'a': 1}),
+        (None, {'b', 'sub'}, {'a': 1}),
+        ({'a', 'b'}, {'b'}, {'a': 1}),
+        ({'a': None, 'sub': {'y'}}, None, {'a': 1, 'sub': {'y': 2}}),
+        (None, {'sub': {'x'}}, {'a': 1, 'b': 'x', 'sub': {'y': 2}}),
+    ],
+)
+def test_include_exclude(include_exclude_validator, include, exclude, expected):
+    input_value = {'a': 1, 'b': 'x', 'sub': {'x': 1, 'y': 2}}
+    assert include_exclude_validator.validate_python(input_value, include=include, exclude=exclude) == expected
+
+
+def test_include_exclude_skip_validation(include_exclude_validator):
+    # excluded fields aren't validated, so they can be missing or invalid, and aren't extra
+    v = include_exclude_validator
+    assert v.validate_python({'a': 1, 'b': 123, 'sub': 'wrong'}, exclude={'b', 'sub'}) == {'a': 1}
+    assert v.validate_python({'sub': {'x': 1}}, include={'sub': {'x'}}) == {'sub': {'x': 1}}
+    assert v.validate_python({'a': 1, 'b': 'x', 'sub': {'x': 1, 'y': 2}}, return_fields_set=True, exclude={'b'}) == (
+        {'a': 1, 'sub': {'x': 1, 'y': 2}},
+        {'a', 'sub'},
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'x', 'b': 123}, include={'a'})
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('int_parsing', ('a',))]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 1, 'c': 2}, include={'a'})
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('extra_forbidden', ('c',))]
+
+
+def test_include_exclude_invalid(include_exclude_validator):
+    with pytest.raises(TypeError, match='`exclude` argument must a set or dict.'):
+        include_exclude_validator.validate_python({'a': 1}, exclude=['a'])
+
+
+@pytest.fixture(scope='module')
+def include_exclude_items_validator():
+    item = core_schema.typed_dict_schema(
+        {
+            'a': core_schema.typed_dict_field(core_schema.int_schema()),
+            'b': core_schema.typed_dict_field(core_schema.int_schema()),
+        }
+    )
+    return SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'items': core_schema.typed_dict_field(core_schema.list_schema(item)),
+                'pair': core_schema.typed_dict_field(core_schema.tuple_positional_schema(item, item)),
+                'mapping': core_schema.typed_dict_field(core_schema.dict_schema(core_schema.string_schema(), item)),
+            }
+        )
+    )
+
+
+@pytest.mark.parametrize(
+    'include,exclude,expected',
+    [
+        (None, {'items': {'__all__': {'b'}}}, {'items': [{'a': 1}, {'a': 3}]}),
+        (None, {'items': {0: {'b'}}}, {'items': [{'a': 1}, {'a': 3, 'b': 4}]}),
+        (None, {'items': {'__all__': {'b'}, 0: {'a'}}}, {'items': [{}, {'a': 3}]}),
+        (None, {'items': {0}}, {'items': [{'a': 3, 'b': 4}]}),
+        ({'items': {1}}, None, {'items': [{'a': 3, 'b': 4}]}),
+        ({'items': {'__all__': {'a'}}}, None, {'items': [{'a': 1}, {'a': 3}]}),
+        ({'pair': {1: {'a'}}}, None, {'pair': ({'a': 3},)}),
+        (None, {'pair': {'__all__': {'a'}}}, {'pair': ({'b': 2}, {'b': 4})}),
+        (None, {'mapping': {'x': {'a'}}}, {'mapping': {'x': {'b': 2}, 'y': {'a': 3, 'b': 4}}}),
+        ({'mapping': {'__all__': {'b'}}}, None, {'mapping': {'x': {'b': 2}, 'y': {'b': 4}}}),
+        ({'mapping': {'y'}}, None, {'mapping': {'y': {'a': 3, 'b': 4}}}),
+    ],
+)
+def test_include_exclude_items(include_exclude_items_validator, include, exclude, expected):
+    items = [{'a': 1, 'b': 2}, {'a': 3, 'b': 4}]
+    input_value = {'items': items, 'pair': tuple(items), 'mapping': dict(zip('xy', items))}
+    if include is None:
+        # leave out the other containers
+        exclude = {**{k: None for k in input_value if k not in exclude}, **exclude}
+    assert include_exclude_items_validator.validate_python(input_value, include=include, exclude=exclude) == expected
+
+
+def test_include_exclude_items_skip_validation(include_exclude_items_validator):
+    v = include_exclude_items_validator
+    # excluded items and fields aren't validated
+    assert v.validate_python(
+        {'items': [{'a': 1, 'b': 'x'}, 'wrong']},
+        include={'items': None},
+        exclude={'items': {'__all__': {'b'}, 1: None}},
+    ) == {'items': [{'a': 1}]}
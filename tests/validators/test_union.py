@@ -2,6 +2,8 @@ import pytest
 
 from pydantic_core import SchemaError, SchemaValidator, ValidationError
 
+from ..conftest import plain_repr
+
 
 @pytest.mark.parametrize(
     'input_value,expected_value',
@@ -296,3 +298,203 @@ def test_custom_error_type_context():
     assert exc_info.value.errors() == [
         {'type': 'less_than', 'loc': (), 'msg': 'Input should be less than 42', 'input': 123, 'ctx': {'lt': 42.0}}
     ]
+
+
+@pytest.mark.parametrize('strict', [False, True])
+def test_single_choice(strict):
+    # a union with one choice isn't collapsed into that choice, so the error loc is the same in both modes
+    v = SchemaValidator({'type': 'union', 'choices': [{'type': 'int'}], 'strict': strict})
+    assert plain_repr(v).startswith('SchemaValidator(name="union[int]",validator=Union(UnionValidator{')
+    assert v.validate_python(1) == 1
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('x')
+    assert exc_info.value.errors()[0]['loc'] == ('int',)
+
+
+def test_single_choice_summary():
+    v = SchemaValidator({'type': 'union', 'choices': [{'type': 'int'}], 'error_mode': 'summary'})
+    assert v.validate_python(1) == 1
+    with pytest.raises(ValidationError, match='Input does not match any union member, expected int'):
+        v.validate_python('x')
+
+
+@pytest.fixture(scope='module')
+def model_choices():
+    return [
+        {
+            'type': 'typed-dict',
+            'fields': {'a': {'schema': {'type': 'int'}}, 'b': {'schema': {'type': 'int'}}},
+            'ref': 'ab',
+        },
+        {'type': 'typed-dict', 'fields': {'a': {'schema': {'type': 'int'}}, 'c': {'schema': {'type': 'str'}}}},
+        {'type': 'int'},
+    ]
+
+
+def test_error_mode_all(model_choices):
+    v = SchemaValidator({'type': 'union', 'choices': model_choices, 'error_mode': 'all'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 1, 'b': 'x'})
+    assert [e['loc'] for e in exc_info.value.errors()] == [
+        ('typed-dict', 'b'),
+        ('typed-dict', 'c'),
+        ('int',),
+    ]
+
+
+def test_error_mode_best(model_choices):
+    v = SchemaValidator({'type': 'union', 'choices': model_choices, 'error_mode': 'best'})
+    assert v.validate_python({'a': 1, 'c': 'x'}) == {'a': 1, 'c': 'x'}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 1, 'b': 'x'})
+    assert exc_info.value.errors() == [
+        {
+            'type': 'int_parsing',
+            'loc': ('typed-dict', 'b'),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+
+    # with the same number of errors, the deepest wins
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'x', 'b': 1, 'c': 'y'})
+    assert [e['loc'] for e in exc_info.value.errors()] == [('typed-dict', 'a')]
+
+    # otherwise the first choice wins
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('x')
+    assert [e['loc'] for e in exc_info.value.errors()] == [('typed-dict',)]
+
+
+def test_error_mode_summary(model_choices):
+    v = SchemaValidator({'type': 'union', 'choices': model_choices, 'error_mode': 'summary'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 1, 'b': 'x'})
+    assert exc_info.value.errors() == [
+        {
+            'type': 'union_no_match',
+            'loc': (),
+            'msg': 'Input does not match any union member, expected typed-dict, typed-dict, int',
+            'input': {'a': 1, 'b': 'x'},
+            'ctx': {'expected': 'typed-dict, typed-dict, int'},
+        }
+    ]
+
+
+def test_strict_override_lax():
+    v = SchemaValidator(
+        {'type': 'union', 'choices': [{'type': 'int'}, {'type': 'bool'}], 'strict': False}, {'strict': True}
+    )
+    assert v.validate_python('1') == 1
+    assert v.validate_python('true') is True
+
+
+def test_strict_override_strict():
+    v = SchemaValidator({'type': 'union', 'choices': [{'type': 'int'}, {'type': 'str'}], 'strict': True})
+    assert v.validate_python(1) == 1
+    assert v.validate_python('1') == '1'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(1.5)
+    assert [e['type'] for e in exc_info.value.errors()] == ['int_type', 'string_type']
+
+
+def test_strict_override_tagged_union():
+    v = SchemaValidator(
+        {
+            'type': 'tagged-union',
+            'discriminator': 'kind',
+            'choices': {
+                'a': {
+                    'type': 'typed-dict',
+                    'fields': {'kind': {'schema': {'type': 'str'}}, 'x': {'schema': {'type': 'int'}}},
+                }
+            },
+            'strict': False,
+        },
+        {'strict': True},
+    )
+    assert v.validate_python({'kind': 'a', 'x': '1'}) == {'kind': 'a', 'x': 1}
+
+
+@pytest.mark.parametrize('config', [None, {'union_lazy': True}])
+def test_lazy(config):
+    schema = {
+        'type': 'union',
+        'choices': [
+            {'type': 'int'},
+            {'type': 'typed-dict', 'fields': {'x': {'schema': {'type': 'str'}}}},
+            {'type': 'list', 'items_schema': {'type': 'int'}},
+        ],
+    }
+    if config is None:
+        schema['lazy'] = True
+    v = SchemaValidator(schema, config)
+    assert v.validate_python('1') == 1
+    assert v.validate_python({'x': 'a'}) == {'x': 'a'}
+    assert v.validate_python(['1', 2]) == [1, 2]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('x')
+    assert [e['loc'] for e in exc_info.value.errors()] == [('int',), ('typed-dict',), ('list',)]
+
+
+def test_lazy_schema_error():
+    v = SchemaValidator(
+        {
+            'type': 'union',
+            'choices': [{'type': 'int'}, {'type': 'default', 'schema': {'type': 'str'}, 'on_error': 'default'}],
+            'lazy': True,
+        }
+    )
+    assert v.validate_python(1) == 1
+    # errors building a choice are only raised when it's used
+    with pytest.raises(SchemaError, match="'on_error = default' requires a `default` or `default_factory`"):
+        v.validate_python('x')
+
+
+def test_lazy_recursive():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'ref': 'Branch',
+            'fields': {
+                'sub': {
+                    'schema': {
+                        'type': 'union',
+                        'choices': [{'type': 'none'}, {'type': 'recursive-ref', 'schema_ref': 'Branch'}],
+                        'lazy': True,
+                    }
+                }
+            },
+        }
+    )
+    assert v.validate_python({'sub': {'sub': None}}) == {'sub': {'sub': None}}
+
+
+def test_choice_titles():
+    v = SchemaValidator(
+        {
+            'type': 'union',
+            'choices': [
+                {'type': 'datetime', 'title': 'Start Date'},
+                {'type': 'int', 'title': 'Timestamp'},
+                {'type': 'bool'},
+            ],
+        }
+    )
+    assert 'name:"union[StartDate,Timestamp,bool]"' in plain_repr(v)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('foobar')
+    assert exc_info.value.title == 'union[Start Date,Timestamp,bool]'
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [
+        ('datetime_parsing', ('Start Date',)),
+        ('int_parsing', ('Timestamp',)),
+        ('bool_parsing', ('bool',)),
+    ]
+
+    v = SchemaValidator(
+        {'type': 'union', 'choices': [{'type': 'int', 'title': 'Timestamp'}, {'type': 'bool'}], 'error_mode': 'summary'}
+    )
+    with pytest.raises(ValidationError, match='Input does not match any union member, expected Timestamp, bool'):
+        v.validate_python('foobar')
@@ -296,6 +296,80 @@ def test_url_defaults_multi_host_url(validator_kwargs, url, expected):
     test_url_cases(s, url, expected)
 
 
+def test_multi_host_default_port():
+    v = SchemaValidator(core_schema.multi_host_url_schema(default_port=5432))
+    url = v.validate_python('postgres://user:pass@h1,h2:5433,h3/db')
+    assert str(url) == 'postgres://user:pass@h1:5432,h2:5433,h3:5432/db'
+    assert [(h['host'], h['port']) for h in url.hosts()] == [('h1', 5432), ('h2', 5433), ('h3', 5432)]
+    assert url.path == '/db'
+    # the canonical form is stable
+    assert str(v.validate_python(str(url))) == str(url)
+
+
+def test_multi_host_dsn_schemes():
+    v = SchemaValidator(core_schema.multi_host_url_schema(allowed_schemes=['postgres', 'postgresql']))
+    url = v.validate_python('postgres://h1:5432,h2:5432/db')
+    assert str(url) == 'postgres://h1:5432,h2:5432/db'
+    assert url.hosts() == [
+        {'username': None, 'password': None, 'host': 'h1', 'port': 5432},
+        {'username': None, 'password': None, 'host': 'h2', 'port': 5432},
+    ]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('mysql://h1:3306,h2:3306/db')
+    assert exc_info.value.errors()[0]['type'] == 'url_scheme'
+
+
+@pytest.mark.parametrize(
+    'validator_kwargs,url,expected',
+    [
+        ({}, 'redis://EXAMPLE.org/0', 'redis://EXAMPLE.org/0'),
+        (dict(normalize_host=True), 'redis://EXAMPLE.org/0', 'redis://example.org/0'),
+        (dict(normalize_host=True), 'redis://bücher.de', 'redis://xn--bcher-kva.de'),
+        (dict(normalize_host=True), 'https://EXAMPLE.org', 'https://example.org/'),
+        ({}, 'https://example.com/%7euser/a%2fb?q=%41%3d#%7e', 'https://example.com/%7euser/a%2fb?q=%41%3d#%7e'),
+        (
+            dict(normalize_percent_encoding=True),
+            'https://example.com/%7euser/a%2fb?q=%41%3d#%7e',
+            'https://example.com/~user/a%2Fb?q=A%3D#~',
+        ),
+        (dict(trailing_slash='keep'), 'https://example.com/foo/', 'https://example.com/foo/'),
+        (dict(trailing_slash='strip'), 'https://example.com/foo/', 'https://example.com/foo'),
+        (dict(trailing_slash='strip'), 'https://example.com/', 'https://example.com/'),
+        (dict(trailing_slash='strip'), 'redis://localhost/', 'redis://localhost'),
+        (dict(trailing_slash='add'), 'https://example.com/foo', 'https://example.com/foo/'),
+        (dict(trailing_slash='add'), 'redis://localhost', 'redis://localhost/'),
+        (dict(trailing_slash='add'), 'mailto:foo@example.com', 'mailto:foo@example.com'),
+    ],
+)
+@pytest.mark.parametrize('validator_type', ['Url', 'MultiHostUrl'])
+def test_url_normalization(validator_type, validator_kwargs, url, expected):
+    if validator_type == 'Url':
+        schema = core_schema.url_schema(**validator_kwargs)
+    else:
+        schema = core_schema.multi_host_url_schema(**validator_kwargs)
+    v = SchemaValidator(schema)
+    output_url = v.validate_python(url)
+    assert str(output_url) == expected
+    # normalization is idempotent
+    assert str(v.validate_python(str(output_url))) == expected
+
+
+def test_url_normalization_equal():
+    v = SchemaValidator(
+        core_schema.url_schema(normalize_host=True, normalize_percent_encoding=True, trailing_slash='strip')
+    )
+    urls = ['foo://Example.COM/a%7eb/', 'foo://example.com/a~b', 'FOO://EXAMPLE.com/a%7Eb//']
+    assert {str(v.validate_python(url)) for url in urls} == {'foo://example.com/a~b'}
+
+
+def test_multi_host_url_normalization():
+    v = SchemaValidator(core_schema.multi_host_url_schema(normalize_host=True, trailing_slash='strip'))
+    url = v.validate_python('redis://A.com:6379,bücher.de:6380/0/')
+    assert str(url) == 'redis://a.com:6379,xn--bcher-kva.de:6380/0'
+    assert [h['host'] for h in url.hosts()] == ['a.com', 'xn--bcher-kva.de']
+
+
 def test_multi_host_default_host_no_comma():
     with pytest.raises(SchemaError, match='default_host cannot contain a comma, see pydantic-core#326'):
         SchemaValidator(core_schema.multi_host_url_schema(default_host='foo,bar'))
@@ -2,7 +2,7 @@ from collections import deque
 
 import pytest
 
-from pydantic_core import SchemaError, SchemaValidator
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import PyAndJson, plain_repr
 
@@ -298,3 +298,123 @@ def test_model_class():
     assert m.field_a == '[default-a]'
     assert m.field_b == '[default-b]'
     assert m.__fields_set__ == set()
+
+
+def test_no_default_collapsed():
+    v = SchemaValidator({'type': 'default', 'schema': {'type': 'int'}})
+    assert plain_repr(v) == (
+        'SchemaValidator(name="int",validator=Int(IntValidator{strict:false,coerce_bools:false}),slots=[])'
+    )
+    assert v.validate_python('1') == 1
+
+    v = SchemaValidator({'type': 'default', 'schema': {'type': 'int'}, 'on_error': 'omit'})
+    assert plain_repr(v).startswith('SchemaValidator(name="default[int]",validator=WithDefault(')
+
+
+def test_strict_override():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'x': {'schema': {'type': 'default', 'schema': {'type': 'int'}, 'default': 1, 'strict': False}},
+                'y': {'schema': {'type': 'default', 'schema': {'type': 'int'}, 'default': 2}},
+            },
+        },
+        {'strict': True},
+    )
+    assert v.validate_python({'x': '3'}) == {'x': 3, 'y': 2}
+    with pytest.raises(ValidationError, match=r'y\n  Input should be a valid integer \[type=int_type'):
+        v.validate_python({'x': '3', 'y': '4'})
+
+
+def test_validate_default():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'x': core_schema.typed_dict_field(core_schema.string_schema()),
+                'y': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(core_schema.int_schema(), default='42', validate_default=True)
+                ),
+                'z': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(core_schema.int_schema(), default='43')
+                ),
+            }
+        )
+    )
+    assert v.validate_python({'x': 'a'}) == {'x': 'a', 'y': 42, 'z': '43'}
+    assert v.validate_python({'x': 'a', 'y': '1'}) == {'x': 'a', 'y': 1, 'z': '43'}
+
+
+def test_validate_default_error():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'x': core_schema.typed_dict_field(core_schema.string_schema()),
+                'y': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(core_schema.int_schema(), default='wrong', validate_default=True)
+                ),
+            }
+        )
+    )
+    assert v.validate_python({'x': 'a', 'y': 1}) == {'x': 'a', 'y': 1}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({})
+    assert exc_info.value.errors() == [
+        {'type': 'missing', 'loc': ('x',), 'msg': 'Field required', 'input': {}},
+        {
+            'type': 'int_parsing',
+            'loc': ('y',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'wrong',
+        },
+    ]
+
+
+def test_validate_default_factory():
+    calls = []
+
+    def factory():
+        calls.append(1)
+        return ['1', 2]
+
+    v = SchemaValidator(
+        core_schema.tuple_positional_schema(
+            core_schema.int_schema(),
+            core_schema.with_default_schema(core_schema.list_schema(core_schema.int_schema()), default_factory=factory),
+        ),
+        {'validate_default': True},
+    )
+    assert v.validate_python((1,)) == (1, [1, 2])
+    assert v.validate_python((1,)) == (1, [1, 2])
+    assert len(calls) == 2
+
+
+def test_validate_default_on_error():
+    v = SchemaValidator(
+        core_schema.with_default_schema(
+            core_schema.int_schema(), default='7', on_error='default', validate_default=True
+        )
+    )
+    assert v.validate_python('1') == 1
+    assert v.validate_python('wrong') == 7
+
+
+def test_validate_default_arguments():
+    v = SchemaValidator(
+        core_schema.arguments_schema(
+            core_schema.arguments_parameter(
+                'a', core_schema.with_default_schema(core_schema.int_schema(), default='x', validate_default=True)
+            ),
+            core_schema.arguments_parameter(
+                'b',
+                core_schema.with_default_schema(core_schema.int_schema(), default='2', validate_default=True),
+                mode='keyword_only',
+            ),
+        )
+    )
+    assert v.validate_python((1,)) == ((1,), {'b': 2})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'__args__': (), '__kwargs__': {}})
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [
+        ('int_parsing', ('a',)),
+    ]